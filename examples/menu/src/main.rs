@@ -5,7 +5,7 @@ use iced::widget::{
 use iced::widget::{column as col, vertical_space};
 use iced::{alignment, theme, Application, Border, Color, Element, Length, Pixels, Size};
 
-use iced_aw::menu::{self, Item, Menu, StyleSheet};
+use iced_aw::menu::{self, Menu, StyleSheet};
 use iced_aw::style::MenuBarStyle;
 use iced_aw::{menu_bar, menu_items};
 use iced_aw::{quad, widgets::InnerBounds};
@@ -144,6 +144,17 @@ impl Application for App {
 
         #[rustfmt::skip]
         let mb = menu_bar!(
+            ("Entries" [
+                ("New" => Message::Debug("New".into()), shortcut: "Ctrl+N")
+                ("Open" => Message::Debug("Open".into()), shortcut: "Ctrl+O")
+                ("Open Recent" [
+                    ("notes.txt" => Message::Debug("notes.txt".into()))
+                    ("todo.txt" => Message::Debug("todo.txt".into()))
+                ])
+                (---)
+                ("Checked" => Message::CheckChange(!self.check), checked: self.check)
+                ("Toggled" => Message::ToggleChange(!self.toggle), checked: self.toggle)
+            ])
             (debug_button_s("Nested Menus"), {
                 let sub5 = menu_tpl_2(menu_items!(
                     (debug_button("Item"))
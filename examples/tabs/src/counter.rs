@@ -38,7 +38,7 @@ impl Tab for CounterTab {
 
     fn tab_label(&self) -> TabLabel {
         //TabLabel::Text(self.title())
-        TabLabel::IconText(Icon::Calc.into(), self.title())
+        TabLabel::IconHandleText(Icon::Calc.into(), self.title())
     }
 
     fn content(&self) -> Element<'_, Self::Message> {
@@ -37,7 +37,7 @@ impl Tab for FerrisTab {
     }
 
     fn tab_label(&self) -> TabLabel {
-        TabLabel::IconText(Icon::Heart.into(), self.title())
+        TabLabel::IconHandleText(Icon::Heart.into(), self.title())
     }
 
     fn content(&self) -> Element<'_, Self::Message> {
@@ -50,7 +50,7 @@ impl Tab for LoginTab {
 
     fn tab_label(&self) -> TabLabel {
         //TabLabel::Text(self.title())
-        TabLabel::IconText(Icon::User.into(), self.title())
+        TabLabel::IconHandleText(Icon::User.into(), self.title())
     }
 
     fn content(&self) -> Element<'_, Self::Message> {
@@ -5,7 +5,7 @@ use iced::{
     widget::{container, text, Column, Container, Text},
     Application, Command, Element, Font, Length, Settings, Theme,
 };
-use iced_aw::{TabLabel, Tabs};
+use iced_aw::{IconHandle, TabLabel, Tabs};
 use login::{LoginMessage, LoginTab};
 
 mod ferris;
@@ -29,14 +29,16 @@ enum Icon {
     CogAlt,
 }
 
-impl From<Icon> for char {
+impl From<Icon> for IconHandle {
     fn from(icon: Icon) -> Self {
-        match icon {
+        let glyph = match icon {
             Icon::User => '\u{E800}',
             Icon::Heart => '\u{E801}',
             Icon::Calc => '\u{F1EC}',
             Icon::CogAlt => '\u{E802}',
-        }
+        };
+
+        IconHandle::glyph(glyph, ICON)
     }
 }
 
@@ -93,7 +93,7 @@ impl Tab for SettingsTab {
 
     fn tab_label(&self) -> TabLabel {
         //TabLabel::Text(self.title())
-        TabLabel::IconText(Icon::CogAlt.into(), self.title())
+        TabLabel::IconHandleText(Icon::CogAlt.into(), self.title())
     }
 
     fn content(&self) -> Element<'_, Self::Message> {
@@ -1,7 +1,11 @@
 //! `iced_aw_core`.
 use cfg_if::cfg_if;
 
-#[cfg(feature = "date_picker")]
+#[cfg(any(
+    feature = "date_picker",
+    feature = "month_calendar",
+    feature = "heatmap_calendar"
+))]
 pub mod date;
 
 #[cfg(feature = "time_picker")]
@@ -10,13 +14,62 @@ pub mod clock;
 #[cfg(feature = "color_picker")]
 pub mod color;
 
+#[cfg(feature = "dock")]
+pub mod dock;
+
+#[cfg(feature = "hotkey_input")]
+pub mod hotkey;
+
+#[cfg(feature = "json_tree")]
+pub mod json_tree;
+
+#[cfg(feature = "log_view")]
+pub mod log;
+
+#[cfg(feature = "masked_input")]
+pub mod mask;
+
+#[cfg(feature = "num_pad")]
+pub mod num_pad;
+
+#[cfg(feature = "property_grid")]
+pub mod property;
+
+#[cfg(feature = "password_input")]
+pub mod password;
+
+#[cfg(feature = "tree_table")]
+pub mod tree_table;
+
+pub mod accessibility;
+
+pub mod animation;
+
+pub mod focus;
+
+#[cfg(any(feature = "menu", feature = "tab_bar"))]
+pub(crate) mod hit_test;
+
+#[cfg(any(feature = "grid", feature = "menu", feature = "selection_list"))]
+pub(crate) mod layout_cache;
+
+#[cfg(any(feature = "anchored_overlay", feature = "popover"))]
+pub(crate) mod lazy_content;
+
 pub mod overlay;
 
 pub mod renderer;
 
+pub mod settings;
+
+pub mod theme;
+
 #[cfg(feature = "time_picker")]
 pub mod time;
 
+#[cfg(feature = "wheel_picker")]
+pub mod wheel;
+
 #[cfg(feature = "drop_down")]
 pub mod offset;
 
@@ -27,8 +80,11 @@ pub mod icons;
 
 cfg_if! {
     if #[cfg(feature = "icons")] {
-        pub use icons::{BOOTSTRAP_FONT, BOOTSTRAP_FONT_BYTES, NERD_FONT, NERD_FONT_BYTES, SF_UI_ROUNDED_BYTES, SF_UI_ROUNDED, Bootstrap, Nerd};
+        pub use icons::{BOOTSTRAP_FONT, BOOTSTRAP_FONT_BYTES, SF_UI_ROUNDED_BYTES, SF_UI_ROUNDED, Bootstrap};
     } else {
         pub use icons::{BOOTSTRAP_FONT, BOOTSTRAP_FONT_BYTES, Bootstrap};
     }
 }
+
+#[cfg(feature = "nerd_icons")]
+pub use icons::{Nerd, NERD_FONT, NERD_FONT_BYTES};
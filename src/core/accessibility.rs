@@ -0,0 +1,239 @@
+//! Describe the widgets to assistive technologies.
+//!
+//! The [`TabBar`](crate::widgets::TabBar), the
+//! [`MenuBar`](crate::widgets::menu::MenuBar), the
+//! [`SelectionList`](crate::widgets::SelectionList), the
+//! [`Modal`](crate::widgets::Modal) and the pickers report a [`Node`] with
+//! their role, name, states and actions to the operations they take part in,
+//! as the custom state of [`Operation::custom`]. An accessibility backend, like
+//! a bridge to `AccessKit`, can gather them with [`describe`] and announce the
+//! widgets to screen readers, then operate them with the commands of the
+//! widgets, like [`focus`](crate::focus::focus) or
+//! [`tab_bar::scroll_to`](crate::widgets::tab_bar::scroll_to).
+//!
+//! # Example
+//! ```ignore
+//! # use iced::Command;
+//! # use iced_aw::accessibility::{self, Node};
+//! # use iced_aw::focus::Id;
+//! #
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Described(Vec<(Option<Id>, Node)>),
+//! }
+//!
+//! fn announce() -> Command<Message> {
+//!     accessibility::describe(Message::Described)
+//! }
+//! ```
+
+use iced::{
+    advanced::widget::{
+        operation::{Operation, Outcome},
+        Id,
+    },
+    Command, Rectangle,
+};
+use std::any::Any;
+
+/// The role of a widget for assistive technologies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A [`TabBar`](crate::widgets::TabBar).
+    TabList,
+    /// A tab of a [`TabBar`](crate::widgets::TabBar).
+    Tab,
+    /// A [`MenuBar`](crate::widgets::menu::MenuBar).
+    MenuBar,
+    /// An [`Entry`](crate::widgets::menu::Entry) of a menu.
+    MenuItem,
+    /// A [`SelectionList`](crate::widgets::SelectionList).
+    ListBox,
+    /// An option of a [`SelectionList`](crate::widgets::SelectionList).
+    ListItem,
+    /// The overlay of a [`Modal`](crate::widgets::Modal).
+    Dialog,
+    /// A [`DatePicker`](crate::widgets::DatePicker).
+    DatePicker,
+    /// A [`TimePicker`](crate::widgets::TimePicker).
+    TimePicker,
+    /// A [`ColorPicker`](crate::widgets::ColorPicker).
+    ColorPicker,
+}
+
+/// An action assistive technologies can perform on a widget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Gives the keyboard focus to the widget.
+    Focus,
+    /// Clicks the widget.
+    Click,
+    /// Selects the widget, like a tab or an option.
+    Select,
+    /// Opens the popup of the widget.
+    Expand,
+    /// Closes the popup of the widget.
+    Collapse,
+    /// Closes the widget, like a tab or a dialog.
+    Close,
+}
+
+/// The description of a widget for assistive technologies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Node {
+    /// The role of the widget.
+    pub role: Role,
+    /// The name announced for the widget, if any.
+    pub name: Option<String>,
+    /// Whether the widget is selected, if it can be.
+    pub selected: Option<bool>,
+    /// Whether the popup of the widget is open, if it has one.
+    pub expanded: Option<bool>,
+    /// Whether the widget is checked, if it can be.
+    pub checked: Option<bool>,
+    /// Whether the widget is disabled.
+    pub disabled: bool,
+    /// The actions that can be performed on the widget.
+    pub actions: Vec<Action>,
+    /// The parts of the widget, like the tabs of a tab bar.
+    pub children: Vec<Self>,
+}
+
+impl Node {
+    /// Creates a new enabled [`Node`] with the given [`Role`].
+    #[must_use]
+    pub const fn new(role: Role) -> Self {
+        Self {
+            role,
+            name: None,
+            selected: None,
+            expanded: None,
+            checked: None,
+            disabled: false,
+            actions: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the name of the [`Node`].
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets whether the [`Node`] is selected.
+    #[must_use]
+    pub const fn selected(mut self, selected: bool) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    /// Sets whether the popup of the [`Node`] is open.
+    #[must_use]
+    pub const fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = Some(expanded);
+        self
+    }
+
+    /// Sets whether the [`Node`] is checked.
+    #[must_use]
+    pub const fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Sets whether the [`Node`] is disabled.
+    #[must_use]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Adds an [`Action`] that can be performed on the [`Node`].
+    #[must_use]
+    pub fn action(mut self, action: Action) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Sets the parts of the [`Node`].
+    #[must_use]
+    pub fn children(mut self, children: Vec<Self>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+/// Gathers the [`Node`]s of the widgets, with their [`Id`], in layout order,
+/// producing a message with them.
+pub fn describe<Message: 'static>(
+    f: impl Fn(Vec<(Option<Id>, Node)>) -> Message + 'static,
+) -> Command<Message> {
+    Command::widget(request(f))
+}
+
+/// Returns the [`Operation`] gathering the [`Node`]s of the widgets.
+pub(crate) fn request<T>(f: impl Fn(Vec<(Option<Id>, Node)>) -> T) -> impl Operation<T> {
+    struct Describe<F> {
+        nodes: Vec<(Option<Id>, Node)>,
+        f: F,
+    }
+
+    impl<T, F> Operation<T> for Describe<F>
+    where
+        F: Fn(Vec<(Option<Id>, Node)>) -> T,
+    {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            if let Some(node) = state.downcast_ref::<Node>() {
+                self.nodes.push((id.cloned(), node.clone()));
+            }
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            Outcome::Some((self.f)(self.nodes.clone()))
+        }
+    }
+
+    Describe {
+        nodes: Vec::new(),
+        f,
+    }
+}
+
+/// Returns the [`Node`]s reported by the widgets `operate` runs the
+/// [`Operation`] it is given on, like the entries of a menu bar.
+#[cfg(feature = "menu")]
+pub(crate) fn gather<T>(operate: impl FnOnce(&mut dyn Operation<T>)) -> Vec<Node> {
+    struct Gather(Vec<Node>);
+
+    impl<T> Operation<T> for Gather {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+            if let Some(node) = state.downcast_ref::<Node>() {
+                self.0.push(node.clone());
+            }
+        }
+    }
+
+    let mut gather = Gather(Vec::new());
+    operate(&mut gather);
+    gather.0
+}
@@ -0,0 +1,541 @@
+//! Drive the animations of the widgets and of the application.
+//!
+//! An [`Animation`] measures the progress of a transition from the time it is
+//! started, eased by an [`Easing`] curve. A [`Timeline`] moves a progress
+//! toward a target or around a loop as the frames are drawn, and a [`Spring`]
+//! moves a value toward a target with the motion of a damped spring. All of
+//! them complete at once when the [`settings`](crate::settings) ask for
+//! reduced motion.
+//!
+//! While an animation runs, the application listens to the [`frames`] of the
+//! window to redraw at each of them, and a widget asks for the next frame with
+//! [`request_frame`] when it handles a redraw.
+//!
+//! The frames asked for by the widgets follow a shared clock: every widget
+//! animating during a frame asks for the same next one, so the window is
+//! redrawn once for all of them, and no frame is asked for once they are all
+//! idle. [`is_animating`] tells whether a widget is still animating.
+//!
+//! # Example
+//! ```ignore
+//! # use iced::Subscription;
+//! # use iced_aw::animation::{self, Animation};
+//! # use std::time::{Duration, Instant};
+//! #
+//! let mut fade = Animation::new(Duration::from_millis(200));
+//! fade.start(Instant::now());
+//!
+//! fn subscription(&self) -> Subscription<Message> {
+//!     animation::frames(self.fade.is_running(Instant::now())).map(Message::Frame)
+//! }
+//! ```
+//!
+//! In a custom widget, keeping a [`Timeline`] in its state:
+//! ```ignore
+//! # use iced::{event, window, Event};
+//! # use iced_aw::animation;
+//! #
+//! if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+//!     let state = tree.state.downcast_mut::<State>();
+//!
+//!     if state.slide.advance_to(target, Duration::from_millis(250), now) {
+//!         animation::request_frame(shell, now);
+//!     }
+//! }
+//! ```
+
+use crate::core::settings;
+
+use iced::{advanced::Shell, window, Subscription};
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        OnceLock,
+    },
+    time::{Duration, Instant},
+};
+
+/// The interval between two frames of the animations of the widgets.
+pub const FRAME_INTERVAL: Duration = Duration::from_millis(1000 / 60);
+
+/// The longest time a [`Spring`] is advanced by at once, to keep it stable
+/// when frames are skipped.
+const MAX_SPRING_STEP: f32 = 1.0 / 240.0;
+
+/// The distance and speed under which a [`Spring`] is at rest.
+const SPRING_REST: f32 = 1e-3;
+
+/// An easing curve, applied to the linear progress of an animation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// A constant speed.
+    #[default]
+    Linear,
+    /// Starts slowly and speeds up.
+    EaseIn,
+    /// Starts quickly and slows down.
+    EaseOut,
+    /// Starts and ends slowly.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Applies the curve to the progress `t`, between 0 and 1.
+    #[must_use]
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0f32).mul_add(t, 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// A transition of a given duration, started at some point in time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Animation {
+    /// The time the animation started, if it did.
+    start: Option<Instant>,
+    /// The duration of the animation.
+    duration: Duration,
+    /// The easing curve of the animation.
+    easing: Easing,
+}
+
+impl Animation {
+    /// Creates a new [`Animation`] of the given `duration`, complete until
+    /// it is started.
+    #[must_use]
+    pub const fn new(duration: Duration) -> Self {
+        Self {
+            start: None,
+            duration,
+            easing: Easing::EaseInOut,
+        }
+    }
+
+    /// Sets the [`Easing`] curve of the [`Animation`].
+    #[must_use]
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the duration of the [`Animation`].
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Starts the [`Animation`] at the time `now`, over again if it was
+    /// running.
+    pub fn start(&mut self, now: Instant) {
+        self.start = Some(now);
+    }
+
+    /// Returns the eased progress of the [`Animation`] at the time `now`,
+    /// from 0 when it starts to 1 when it completes.
+    #[must_use]
+    pub fn progress(&self, now: Instant) -> f32 {
+        if settings::reduced_motion() {
+            1.0
+        } else {
+            self.easing.apply(self.linear_progress(now))
+        }
+    }
+
+    /// Returns the progress of the [`Animation`] at the time `now`, without
+    /// easing and regardless of the [`settings`](crate::settings).
+    fn linear_progress(&self, now: Instant) -> f32 {
+        match self.start {
+            Some(start) if !self.duration.is_zero() => {
+                let t = now.saturating_duration_since(start).as_secs_f32()
+                    / self.duration.as_secs_f32();
+
+                t.clamp(0.0, 1.0)
+            }
+            _ => 1.0,
+        }
+    }
+
+    /// Returns whether the [`Animation`] has started and not completed at
+    /// the time `now`.
+    #[must_use]
+    pub fn is_running(&self, now: Instant) -> bool {
+        self.progress(now) < 1.0
+    }
+}
+
+/// A progress between 0 and 1 advanced as the frames are drawn, toward a
+/// target or around a loop.
+///
+/// A widget keeps it in its state and advances it each time it handles a
+/// redraw, from the time of the previous one.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Timeline {
+    /// The current progress.
+    progress: f32,
+    /// The time of the last frame the progress was advanced at, while it
+    /// moves.
+    last_update: Option<Instant>,
+}
+
+impl Timeline {
+    /// Creates a new [`Timeline`] at rest at the given `progress`.
+    #[must_use]
+    pub const fn new(progress: f32) -> Self {
+        Self {
+            progress,
+            last_update: None,
+        }
+    }
+
+    /// Returns the current progress of the [`Timeline`].
+    #[must_use]
+    pub const fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    /// Moves the [`Timeline`] to the given `progress` at once, like when the
+    /// user drags what it animates, and stops it.
+    pub fn set(&mut self, progress: f32) {
+        self.progress = progress;
+        self.stop();
+    }
+
+    /// Stops the [`Timeline`], so that it resumes from its current progress
+    /// the next time it is advanced.
+    pub fn stop(&mut self) {
+        self.last_update = None;
+    }
+
+    /// Advances the [`Timeline`] toward the `target` progress at the frame
+    /// drawn at `now`, going through the whole progress in `duration`.
+    ///
+    /// Returns whether the progress moved, and the next frame should be
+    /// requested.
+    pub fn advance_to(&mut self, target: f32, duration: Duration, now: Instant) -> bool {
+        if (self.progress - target).abs() <= f32::EPSILON {
+            self.stop();
+            return false;
+        }
+
+        let step = if settings::reduced_motion() || duration.is_zero() {
+            1.0
+        } else {
+            self.elapsed(now) / duration.as_secs_f32()
+        };
+
+        self.last_update = Some(now);
+        self.step_toward(target, step);
+
+        true
+    }
+
+    /// Advances the [`Timeline`] around a loop of the given `period` at the
+    /// frame drawn at `now`, starting over from 0 when it reaches 1.
+    pub fn cycle(&mut self, period: Duration, now: Instant) {
+        let elapsed = self.elapsed(now);
+
+        self.last_update = Some(now);
+        if !period.is_zero() {
+            self.progress = (self.progress + elapsed / period.as_secs_f32()).fract();
+        }
+    }
+
+    /// Returns the seconds elapsed from the last frame to `now`, or 0 when
+    /// the [`Timeline`] was stopped.
+    fn elapsed(&self, now: Instant) -> f32 {
+        self.last_update.map_or(0.0, |last| {
+            now.saturating_duration_since(last).as_secs_f32()
+        })
+    }
+
+    /// Moves the progress by `step` toward the `target`, without passing it.
+    fn step_toward(&mut self, target: f32, step: f32) {
+        self.progress = if self.progress < target {
+            (self.progress + step).min(target)
+        } else {
+            (self.progress - step).max(target)
+        };
+    }
+}
+
+/// A value moving toward a target with the motion of a damped spring, which
+/// keeps its speed when the target changes on the way.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spring {
+    /// The current value.
+    position: f32,
+    /// The current speed, in units per second.
+    velocity: f32,
+    /// The value the spring moves toward.
+    target: f32,
+    /// The force pulling the value toward the target.
+    stiffness: f32,
+    /// The friction slowing the value down.
+    damping: f32,
+    /// The time of the last frame the value was advanced at, while it moves.
+    last_update: Option<Instant>,
+}
+
+impl Spring {
+    /// The default stiffness of a [`Spring`].
+    pub const DEFAULT_STIFFNESS: f32 = 300.0;
+
+    /// The default damping of a [`Spring`], slightly overshooting the target.
+    pub const DEFAULT_DAMPING: f32 = 30.0;
+
+    /// Creates a new [`Spring`] at rest at the given `position`.
+    #[must_use]
+    pub const fn new(position: f32) -> Self {
+        Self {
+            position,
+            velocity: 0.0,
+            target: position,
+            stiffness: Self::DEFAULT_STIFFNESS,
+            damping: Self::DEFAULT_DAMPING,
+            last_update: None,
+        }
+    }
+
+    /// Sets the stiffness of the [`Spring`]; a stiffer spring moves faster.
+    #[must_use]
+    pub const fn stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness;
+        self
+    }
+
+    /// Sets the damping of the [`Spring`]; a spring damped with less than
+    /// twice the square root of its stiffness overshoots its target.
+    #[must_use]
+    pub const fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Returns the current value of the [`Spring`].
+    #[must_use]
+    pub const fn position(&self) -> f32 {
+        self.position
+    }
+
+    /// Returns the value the [`Spring`] moves toward.
+    #[must_use]
+    pub const fn target(&self) -> f32 {
+        self.target
+    }
+
+    /// Sets the value the [`Spring`] moves toward.
+    pub fn set_target(&mut self, target: f32) {
+        self.target = target;
+    }
+
+    /// Moves the [`Spring`] to the given `position` at once, and leaves it
+    /// at rest there.
+    pub fn jump(&mut self, position: f32) {
+        self.position = position;
+        self.target = position;
+        self.velocity = 0.0;
+        self.last_update = None;
+    }
+
+    /// Returns whether the [`Spring`] is at rest at its target.
+    #[must_use]
+    pub fn is_settled(&self) -> bool {
+        (self.position - self.target).abs() < SPRING_REST && self.velocity.abs() < SPRING_REST
+    }
+
+    /// Advances the [`Spring`] at the frame drawn at `now`.
+    ///
+    /// Returns whether it still moves, and the next frame should be
+    /// requested.
+    pub fn update(&mut self, now: Instant) -> bool {
+        if settings::reduced_motion() {
+            self.jump(self.target);
+            return false;
+        }
+
+        let elapsed = self.last_update.map_or(0.0, |last| {
+            now.saturating_duration_since(last).as_secs_f32()
+        });
+
+        self.integrate(elapsed.min(0.1));
+
+        if self.is_settled() {
+            self.jump(self.target);
+            false
+        } else {
+            self.last_update = Some(now);
+            true
+        }
+    }
+
+    /// Moves the [`Spring`] by `elapsed` seconds, in small steps.
+    fn integrate(&mut self, mut elapsed: f32) {
+        loop {
+            let dt = elapsed.min(MAX_SPRING_STEP);
+            if dt <= 0.0 {
+                break;
+            }
+
+            let acceleration = (-self.stiffness)
+                .mul_add(self.position - self.target, -self.damping * self.velocity);
+
+            self.velocity += acceleration * dt;
+            self.position += self.velocity * dt;
+            elapsed -= dt;
+        }
+    }
+}
+
+/// Produces the time of each frame of the window while an animation is
+/// `running`, and nothing otherwise.
+pub fn frames(running: bool) -> Subscription<Instant> {
+    if running {
+        window::frames()
+    } else {
+        Subscription::none()
+    }
+}
+
+/// The time the frames of the shared clock are counted from.
+static EPOCH: OnceLock<Instant> = OnceLock::new();
+
+/// The last frame asked for with [`request_frame`], in nanoseconds since the
+/// [`EPOCH`].
+static REQUESTED: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the time of `time` since the [`EPOCH`], in nanoseconds.
+fn nanos_since_epoch(time: Instant) -> u64 {
+    let epoch = *EPOCH.get_or_init(|| time);
+
+    u64::try_from(time.saturating_duration_since(epoch).as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Returns the time of the frame of the shared clock following `now`.
+///
+/// The frames are [`FRAME_INTERVAL`] apart, so the animations asking for the
+/// next frame at slightly different times are redrawn together.
+#[must_use]
+pub fn next_frame(now: Instant) -> Instant {
+    let interval = u64::try_from(FRAME_INTERVAL.as_nanos()).unwrap_or(u64::MAX);
+    let frame = nanos_since_epoch(now) / interval + 1;
+
+    *EPOCH.get_or_init(|| now) + Duration::from_nanos(frame.saturating_mul(interval))
+}
+
+/// Asks the `shell` to redraw the window at the next frame of an animation,
+/// after the frame drawn at `now`.
+///
+/// The frame is the [`next_frame`] of the shared clock, the same for all the
+/// animations running at `now`.
+pub fn request_frame<Message>(shell: &mut Shell<'_, Message>, now: Instant) {
+    let frame = next_frame(now);
+
+    let _ = REQUESTED.fetch_max(nanos_since_epoch(frame), Ordering::Relaxed);
+    shell.request_redraw(window::RedrawRequest::At(frame));
+}
+
+/// Returns whether a widget asked for a frame with [`request_frame`] that is
+/// still to be drawn at `now`, or was due during the last frame.
+///
+/// Once all the animations of the widgets are idle, they stop asking for
+/// frames and this returns `false`.
+#[must_use]
+pub fn is_animating(now: Instant) -> bool {
+    let interval = u64::try_from(FRAME_INTERVAL.as_nanos()).unwrap_or(u64::MAX);
+    let requested = REQUESTED.load(Ordering::Relaxed);
+
+    // The frames follow the epoch, so none was asked for while it is zero.
+    requested != 0 && requested.saturating_add(interval) >= nanos_since_epoch(now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{next_frame, Animation, Easing, Spring, Timeline, FRAME_INTERVAL};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn progress_is_eased_and_bounded() {
+        let now = Instant::now();
+        let mut animation = Animation::new(Duration::from_millis(100));
+
+        assert!((animation.linear_progress(now) - 1.0).abs() < f32::EPSILON);
+
+        animation.start(now);
+        assert!(animation.linear_progress(now).abs() < f32::EPSILON);
+        assert!((animation.linear_progress(now + Duration::from_millis(25)) - 0.25).abs() < 0.01);
+        assert!(
+            (animation.linear_progress(now + Duration::from_secs(1)) - 1.0).abs() < f32::EPSILON
+        );
+
+        let ease = Easing::EaseInOut;
+        assert!((ease.apply(0.5) - 0.5).abs() < f32::EPSILON);
+        assert!(ease.apply(0.25) < 0.25);
+        assert!(ease.apply(0.75) > 0.75);
+
+        assert!(Easing::EaseIn.apply(0.5) < 0.5);
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+        assert!((Easing::Linear.apply(0.3) - 0.3).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn timelines_move_toward_their_target_and_loop() {
+        let mut timeline = Timeline::new(0.0);
+
+        timeline.step_toward(1.0, 0.25);
+        assert!((timeline.progress() - 0.25).abs() < f32::EPSILON);
+        timeline.step_toward(1.0, 1.0);
+        assert!((timeline.progress() - 1.0).abs() < f32::EPSILON);
+        timeline.step_toward(0.0, 0.5);
+        assert!((timeline.progress() - 0.5).abs() < f32::EPSILON);
+
+        let now = Instant::now();
+        let mut pulse = Timeline::new(0.0);
+
+        pulse.cycle(Duration::from_millis(100), now);
+        assert!(pulse.progress().abs() < f32::EPSILON);
+        pulse.cycle(Duration::from_millis(100), now + Duration::from_millis(150));
+        assert!((pulse.progress() - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn springs_settle_on_their_target() {
+        let mut spring = Spring::new(0.0);
+        spring.set_target(1.0);
+        assert!(!spring.is_settled());
+
+        let mut overshoot = 0.0f32;
+        for _ in 0..120 {
+            spring.integrate(1.0 / 60.0);
+            overshoot = overshoot.max(spring.position() - 1.0);
+        }
+
+        assert!(overshoot > 0.0);
+        assert!(spring.is_settled());
+        assert!((spring.target() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn frames_are_shared() {
+        let now = Instant::now();
+        let frame = next_frame(now);
+
+        assert!(frame > now);
+        assert!(frame <= now + FRAME_INTERVAL);
+        assert_eq!(next_frame(now), frame);
+        assert_eq!(
+            next_frame(frame),
+            next_frame(frame + Duration::from_millis(1))
+        );
+        assert_eq!(next_frame(frame), frame + FRAME_INTERVAL);
+    }
+}
@@ -0,0 +1,749 @@
+//! The layout of the panels of a [`DockArea`](crate::widgets::dock::DockArea).
+//!
+//! *This API requires the following crate features to be activated: `dock`*
+use iced::Rectangle;
+use std::{
+    fmt::{self, Display, Write},
+    iter::Peekable,
+    str::{Chars, FromStr},
+};
+
+/// The smallest share of a split given to one of its nodes.
+const MIN_RATIO: f32 = 0.1;
+
+/// The axis along which a [`DockNode::Split`] places its nodes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+    /// The nodes are placed side by side, the first one on the left.
+    Horizontal,
+    /// The nodes are placed one above the other, the first one on top.
+    Vertical,
+}
+
+/// An edge of a dock area or of a group of tabs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Edge {
+    /// The left edge.
+    Left,
+    /// The right edge.
+    Right,
+    /// The top edge.
+    Top,
+    /// The bottom edge.
+    Bottom,
+}
+
+impl Edge {
+    /// Returns the axis of a split putting a node on this edge.
+    #[must_use]
+    pub const fn axis(self) -> Axis {
+        match self {
+            Self::Left | Self::Right => Axis::Horizontal,
+            Self::Top | Self::Bottom => Axis::Vertical,
+        }
+    }
+
+    /// Whether a node put on this edge is the first one of its split.
+    const fn is_first(self) -> bool {
+        matches!(self, Self::Left | Self::Top)
+    }
+}
+
+/// A node of a [`DockLayout`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum DockNode {
+    /// Two nodes sharing the space along an axis.
+    Split {
+        /// The axis along which the nodes are placed.
+        axis: Axis,
+        /// The share of the space given to the first node.
+        ratio: f32,
+        /// The first node, on the left or on top.
+        first: Box<Self>,
+        /// The second node, on the right or at the bottom.
+        second: Box<Self>,
+    },
+    /// Panels stacked in a group of tabs, showing the active one.
+    Tabs {
+        /// The names of the panels, never empty.
+        panels: Vec<String>,
+        /// The index of the shown panel.
+        active: usize,
+    },
+}
+
+impl DockNode {
+    /// Creates a group of tabs holding a single panel.
+    fn tab(panel: String) -> Self {
+        Self::Tabs {
+            panels: vec![panel],
+            active: 0,
+        }
+    }
+
+    /// Puts `node` on the `edge` of this node.
+    fn split(self, node: Self, edge: Edge) -> Self {
+        let (first, second) = if edge.is_first() {
+            (node, self)
+        } else {
+            (self, node)
+        };
+
+        Self::Split {
+            axis: edge.axis(),
+            ratio: 0.5,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// Returns the group of tabs holding `panel`.
+    fn group_mut(&mut self, panel: &str) -> Option<&mut Self> {
+        match self {
+            Self::Tabs { panels, .. } if panels.iter().any(|p| p == panel) => Some(self),
+            Self::Tabs { .. } => None,
+            Self::Split { first, second, .. } => {
+                first.group_mut(panel).or_else(|| second.group_mut(panel))
+            }
+        }
+    }
+
+    /// Returns the node without `panel`, or `None` if nothing is left. A split
+    /// losing one of its nodes is replaced by the other one.
+    fn without(self, panel: &str) -> Option<Self> {
+        match self {
+            Self::Tabs { mut panels, active } => {
+                let Some(index) = panels.iter().position(|p| p == panel) else {
+                    return Some(Self::Tabs { panels, active });
+                };
+                let _ = panels.remove(index);
+
+                (!panels.is_empty()).then(|| Self::Tabs {
+                    active: if index < active {
+                        active - 1
+                    } else {
+                        active.min(panels.len() - 1)
+                    },
+                    panels,
+                })
+            }
+            Self::Split {
+                axis,
+                ratio,
+                first,
+                second,
+            } => match (first.without(panel), second.without(panel)) {
+                (Some(first), Some(second)) => Some(Self::Split {
+                    axis,
+                    ratio,
+                    first: Box::new(first),
+                    second: Box::new(second),
+                }),
+                (Some(node), None) | (None, Some(node)) => Some(node),
+                (None, None) => None,
+            },
+        }
+    }
+
+    /// Calls `f` with the names of the panels of this node.
+    fn for_each_panel<'a>(&'a self, f: &mut impl FnMut(&'a str)) {
+        match self {
+            Self::Tabs { panels, .. } => panels.iter().for_each(|panel| f(panel)),
+            Self::Split { first, second, .. } => {
+                first.for_each_panel(f);
+                second.for_each_panel(f);
+            }
+        }
+    }
+}
+
+/// A panel floating over the docked ones.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FloatingPanel {
+    /// The name of the panel.
+    pub panel: String,
+    /// The bounds of the panel, relative to the dock area.
+    pub bounds: Rectangle,
+}
+
+/// Where [`DockLayout::dock`] puts a panel.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DockTarget {
+    /// On an edge of the whole dock area.
+    Area(Edge),
+    /// On an edge of the group of tabs holding the named panel.
+    Beside(String, Edge),
+    /// In the group of tabs holding the named panel.
+    With(String),
+    /// Floating over the docked panels with the given bounds.
+    Float(Rectangle),
+}
+
+/// The layout of the panels of a [`DockArea`](crate::widgets::dock::DockArea):
+/// a tree of splits and groups of tabs, and the panels floating over them.
+///
+/// The panels are named, each name appearing once in the layout. A layout is
+/// saved as text with its [`Display`] implementation and restored with its
+/// [`FromStr`] implementation, which are also used to serialize it as a
+/// string with the `serde` feature.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::dock::{DockLayout, DockTarget, Edge};
+/// #
+/// let mut layout = DockLayout::new("editor");
+/// layout.dock("files", DockTarget::Area(Edge::Left));
+/// layout.dock("outline", DockTarget::With(String::from("files")));
+///
+/// let saved = layout.to_string();
+/// assert_eq!(saved.parse::<DockLayout>(), Ok(layout));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DockLayout {
+    /// The tree of the docked panels.
+    root: Option<DockNode>,
+    /// The floating panels, from the bottom one to the top one.
+    floating: Vec<FloatingPanel>,
+}
+
+impl DockLayout {
+    /// Creates a new [`DockLayout`] with a single docked panel.
+    pub fn new(panel: impl Into<String>) -> Self {
+        Self {
+            root: Some(DockNode::tab(panel.into())),
+            floating: Vec::new(),
+        }
+    }
+
+    /// Returns the tree of the docked panels, if any.
+    #[must_use]
+    pub fn root(&self) -> Option<&DockNode> {
+        self.root.as_ref()
+    }
+
+    /// Returns the floating panels, from the bottom one to the top one.
+    #[must_use]
+    pub fn floating(&self) -> &[FloatingPanel] {
+        &self.floating
+    }
+
+    /// Returns the names of the panels of the layout, docked ones first.
+    #[must_use]
+    pub fn panels(&self) -> Vec<&str> {
+        let mut panels = Vec::new();
+
+        if let Some(root) = &self.root {
+            root.for_each_panel(&mut |panel| panels.push(panel));
+        }
+        panels.extend(self.floating.iter().map(|floating| floating.panel.as_str()));
+
+        panels
+    }
+
+    /// Whether the layout holds the named panel.
+    #[must_use]
+    pub fn contains(&self, panel: &str) -> bool {
+        self.panels().contains(&panel)
+    }
+
+    /// Moves the named panel to `target`, adding it if the layout does not
+    /// hold it yet.
+    ///
+    /// A panel docked next to a panel missing from the layout is docked on
+    /// the right edge of the area instead.
+    pub fn dock(&mut self, panel: impl Into<String>, target: DockTarget) {
+        let panel = panel.into();
+
+        // A panel docked next to itself is docked next to the other panels of
+        // its group, if there are any.
+        let target = match target {
+            DockTarget::With(other) if other == panel => return,
+            DockTarget::Beside(other, edge) if other == panel => {
+                let Some(DockNode::Tabs { panels, .. }) =
+                    self.root.as_mut().and_then(|root| root.group_mut(&panel))
+                else {
+                    return;
+                };
+
+                match panels.iter().find(|p| **p != panel) {
+                    Some(other) => DockTarget::Beside(other.clone(), edge),
+                    None => return,
+                }
+            }
+            target => target,
+        };
+
+        let _ = self.remove(&panel);
+
+        match target {
+            DockTarget::Area(edge) => self.dock_on_area(panel, edge),
+            DockTarget::Beside(other, edge) => {
+                match self.root.as_mut().and_then(|root| root.group_mut(&other)) {
+                    Some(group) => {
+                        let node = std::mem::replace(group, DockNode::tab(String::new()));
+                        *group = node.split(DockNode::tab(panel), edge);
+                    }
+                    None => self.dock_on_area(panel, Edge::Right),
+                }
+            }
+            DockTarget::With(other) => {
+                match self.root.as_mut().and_then(|root| root.group_mut(&other)) {
+                    Some(DockNode::Tabs { panels, active }) => {
+                        panels.push(panel);
+                        *active = panels.len() - 1;
+                    }
+                    _ => self.dock_on_area(panel, Edge::Right),
+                }
+            }
+            DockTarget::Float(bounds) => self.floating.push(FloatingPanel { panel, bounds }),
+        }
+    }
+
+    /// Docks `panel` on the `edge` of the area.
+    fn dock_on_area(&mut self, panel: String, edge: Edge) {
+        self.root = Some(match self.root.take() {
+            Some(root) => root.split(DockNode::tab(panel), edge),
+            None => DockNode::tab(panel),
+        });
+    }
+
+    /// Removes the named panel from the layout, returning whether it held it.
+    pub fn remove(&mut self, panel: &str) -> bool {
+        if let Some(index) = self.floating.iter().position(|f| f.panel == panel) {
+            let _ = self.floating.remove(index);
+            return true;
+        }
+
+        let contained = self.contains(panel);
+        self.root = self.root.take().and_then(|root| root.without(panel));
+        contained
+    }
+
+    /// Shows the named panel in its group of tabs, or raises it above the
+    /// other floating panels.
+    pub fn select(&mut self, panel: &str) {
+        if let Some(index) = self.floating.iter().position(|f| f.panel == panel) {
+            let floating = self.floating.remove(index);
+            self.floating.push(floating);
+        } else if let Some(DockNode::Tabs { panels, active }) =
+            self.root.as_mut().and_then(|root| root.group_mut(panel))
+        {
+            if let Some(index) = panels.iter().position(|p| p == panel) {
+                *active = index;
+            }
+        }
+    }
+
+    /// Sets the ratio of the split reached by following `path` from the root,
+    /// `false` going to the first node and `true` to the second one.
+    pub(crate) fn resize(&mut self, path: &[bool], ratio: f32) {
+        let mut node = self.root.as_mut();
+
+        for &second in path {
+            node = match node {
+                Some(DockNode::Split {
+                    first, second: s, ..
+                }) => Some(if second { s.as_mut() } else { first.as_mut() }),
+                _ => None,
+            };
+        }
+
+        if let Some(DockNode::Split { ratio: r, .. }) = node {
+            *r = ratio.clamp(MIN_RATIO, 1.0 - MIN_RATIO);
+        }
+    }
+}
+
+/// The characters preceded by a backslash in the names of the saved panels.
+const ESCAPED: &[char] = &['\\', '(', ')', ',', ':', '|'];
+
+/// Writes the name of a panel, escaping the characters of the format.
+fn write_name(f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+    for c in name.chars() {
+        if ESCAPED.contains(&c) {
+            f.write_char('\\')?;
+        }
+        f.write_char(c)?;
+    }
+    Ok(())
+}
+
+impl Display for DockNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Split {
+                axis,
+                ratio,
+                first,
+                second,
+            } => {
+                let axis = match axis {
+                    Axis::Horizontal => 'h',
+                    Axis::Vertical => 'v',
+                };
+                write!(f, "split({axis}{ratio},{first},{second})")
+            }
+            Self::Tabs { panels, active } => {
+                write!(f, "tabs({active}:")?;
+                for (i, panel) in panels.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_name(f, panel)?;
+                }
+                f.write_char(')')
+            }
+        }
+    }
+}
+
+/// Saves the layout as text, restored with [`FromStr`].
+///
+/// The docked panels are written first, followed by the floating ones:
+/// `split(h0.25,tabs(0:files,outline),tabs(0:editor))|float(40,40,320,240:terminal)`.
+impl Display for DockLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(root) = &self.root {
+            write!(f, "{root}")?;
+        }
+
+        for floating in &self.floating {
+            let Rectangle {
+                x,
+                y,
+                width,
+                height,
+            } = floating.bounds;
+            write!(f, "|float({x},{y},{width},{height}:")?;
+            write_name(f, &floating.panel)?;
+            f.write_char(')')?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The error returned when restoring a [`DockLayout`] from invalid text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseLayoutError;
+
+impl Display for ParseLayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid dock layout")
+    }
+}
+
+impl std::error::Error for ParseLayoutError {}
+
+/// Reads a saved [`DockLayout`].
+struct Parser<'a> {
+    /// The characters left to read.
+    chars: Peekable<Chars<'a>>,
+}
+
+impl Parser<'_> {
+    /// Reads `expected`.
+    fn expect(&mut self, expected: &str) -> Result<(), ParseLayoutError> {
+        expected.chars().try_for_each(|c| {
+            if self.chars.next() == Some(c) {
+                Ok(())
+            } else {
+                Err(ParseLayoutError)
+            }
+        })
+    }
+
+    /// Reads a number, up to the next character of the format.
+    fn number<T: FromStr>(&mut self) -> Result<T, ParseLayoutError> {
+        let mut number = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if ESCAPED.contains(&c) {
+                break;
+            }
+            number.push(c);
+            let _ = self.chars.next();
+        }
+        number.parse().ok().ok_or(ParseLayoutError)
+    }
+
+    /// Reads the name of a panel.
+    fn name(&mut self) -> Result<String, ParseLayoutError> {
+        let mut name = String::new();
+        while let Some(&c) = self.chars.peek() {
+            match c {
+                '\\' => {
+                    let _ = self.chars.next();
+                    name.push(self.chars.next().ok_or(ParseLayoutError)?);
+                }
+                c if ESCAPED.contains(&c) => break,
+                c => {
+                    name.push(c);
+                    let _ = self.chars.next();
+                }
+            }
+        }
+        Ok(name)
+    }
+
+    /// Reads a node.
+    fn node(&mut self) -> Result<DockNode, ParseLayoutError> {
+        match self.chars.peek() {
+            Some('s') => {
+                self.expect("split(")?;
+                let axis = match self.chars.next() {
+                    Some('h') => Axis::Horizontal,
+                    Some('v') => Axis::Vertical,
+                    _ => return Err(ParseLayoutError),
+                };
+                let ratio: f32 = self.number()?;
+                self.expect(",")?;
+                let first = self.node()?;
+                self.expect(",")?;
+                let second = self.node()?;
+                self.expect(")")?;
+
+                Ok(DockNode::Split {
+                    axis,
+                    ratio: if ratio.is_finite() {
+                        ratio.clamp(MIN_RATIO, 1.0 - MIN_RATIO)
+                    } else {
+                        0.5
+                    },
+                    first: Box::new(first),
+                    second: Box::new(second),
+                })
+            }
+            Some('t') => {
+                self.expect("tabs(")?;
+                let active: usize = self.number()?;
+                self.expect(":")?;
+                let mut panels = vec![self.name()?];
+                while self.chars.peek() == Some(&',') {
+                    let _ = self.chars.next();
+                    panels.push(self.name()?);
+                }
+                self.expect(")")?;
+
+                Ok(DockNode::Tabs {
+                    active: active.min(panels.len() - 1),
+                    panels,
+                })
+            }
+            _ => Err(ParseLayoutError),
+        }
+    }
+
+    /// Reads a floating panel.
+    fn floating(&mut self) -> Result<FloatingPanel, ParseLayoutError> {
+        self.expect("float(")?;
+        let x = self.number()?;
+        self.expect(",")?;
+        let y = self.number()?;
+        self.expect(",")?;
+        let width = self.number()?;
+        self.expect(",")?;
+        let height = self.number()?;
+        self.expect(":")?;
+        let panel = self.name()?;
+        self.expect(")")?;
+
+        Ok(FloatingPanel {
+            panel,
+            bounds: Rectangle {
+                x,
+                y,
+                width,
+                height,
+            },
+        })
+    }
+}
+
+/// Restores a layout saved with [`Display`].
+impl FromStr for DockLayout {
+    type Err = ParseLayoutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            chars: s.chars().peekable(),
+        };
+        let root = match parser.chars.peek() {
+            None | Some('|') => None,
+            Some(_) => Some(parser.node()?),
+        };
+
+        let mut floating = Vec::new();
+        while parser.chars.next_if_eq(&'|').is_some() {
+            floating.push(parser.floating()?);
+        }
+
+        if parser.chars.next().is_some() {
+            return Err(ParseLayoutError);
+        }
+
+        Ok(Self { root, floating })
+    }
+}
+
+/// Serializes a layout as the text of its [`Display`] implementation.
+#[cfg(feature = "serde")]
+impl serde::Serialize for DockLayout {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes a layout from the text of its [`Display`] implementation.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DockLayout {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DockLayout, DockNode, DockTarget, Edge, ParseLayoutError};
+    use iced::Rectangle;
+
+    fn ide() -> DockLayout {
+        let mut layout = DockLayout::new("editor");
+        layout.dock("files", DockTarget::Area(Edge::Left));
+        layout.dock("outline", DockTarget::With(String::from("files")));
+        layout.dock(
+            "terminal",
+            DockTarget::Beside(String::from("editor"), Edge::Bottom),
+        );
+        layout.dock(
+            "search (all, files)",
+            DockTarget::Float(Rectangle {
+                x: 40.0,
+                y: 40.0,
+                width: 320.0,
+                height: 240.5,
+            }),
+        );
+        layout
+    }
+
+    #[test]
+    fn dock() {
+        let layout = ide();
+
+        assert_eq!(
+            layout.panels(),
+            vec![
+                "files",
+                "outline",
+                "editor",
+                "terminal",
+                "search (all, files)"
+            ]
+        );
+        assert_eq!(
+            layout.to_string(),
+            "split(h0.5,tabs(1:files,outline),split(v0.5,tabs(0:editor),tabs(0:terminal)))\
+             |float(40,40,320,240.5:search \\(all\\, files\\))"
+        );
+    }
+
+    #[test]
+    fn remove() {
+        let mut layout = ide();
+
+        assert!(layout.remove("terminal"));
+        assert!(!layout.remove("terminal"));
+        assert!(layout.remove("search (all, files)"));
+        assert_eq!(
+            layout.to_string(),
+            "split(h0.5,tabs(1:files,outline),tabs(0:editor))"
+        );
+
+        assert!(layout.remove("outline"));
+        assert!(layout.remove("files"));
+        assert_eq!(layout.root(), Some(&DockNode::tab(String::from("editor"))));
+
+        assert!(layout.remove("editor"));
+        assert_eq!(layout, DockLayout::default());
+    }
+
+    #[test]
+    fn dock_beside_itself() {
+        let mut layout = ide();
+
+        layout.dock(
+            "outline",
+            DockTarget::Beside(String::from("outline"), Edge::Top),
+        );
+        assert!(layout
+            .to_string()
+            .starts_with("split(h0.5,split(v0.5,tabs(0:outline),tabs(0:files)),split(v0.5,"));
+
+        let before = layout.clone();
+        layout.dock(
+            "editor",
+            DockTarget::Beside(String::from("editor"), Edge::Left),
+        );
+        assert_eq!(layout, before);
+    }
+
+    #[test]
+    fn select() {
+        let mut layout = ide();
+        layout.select("files");
+
+        assert!(layout
+            .to_string()
+            .starts_with("split(h0.5,tabs(0:files,outline),"));
+    }
+
+    #[test]
+    fn restore() {
+        let layout = ide();
+
+        assert_eq!(layout.to_string().parse::<DockLayout>(), Ok(layout));
+        assert_eq!("".parse::<DockLayout>(), Ok(DockLayout::default()));
+        assert_eq!(
+            "|float(0,0,10,10:a)"
+                .parse::<DockLayout>()
+                .map(|layout| layout.panels().len()),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn restore_invalid() {
+        for invalid in [
+            "tabs(0:a",
+            "tabs(x:a)",
+            "split(x0.5,tabs(0:a),tabs(0:b))",
+            "split(h0.5,tabs(0:a))",
+            "tabs(0:a)tabs(0:b)",
+            "|float(0,0,10:a)",
+        ] {
+            assert_eq!(
+                invalid.parse::<DockLayout>(),
+                Err(ParseLayoutError),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let layout = ide();
+        let json = serde_json::to_string(&layout).ok();
+
+        assert_eq!(json, Some(format!("{:?}", layout.to_string())));
+        assert_eq!(
+            json.and_then(|json| serde_json::from_str::<DockLayout>(&json).ok()),
+            Some(layout)
+        );
+        assert!(serde_json::from_str::<DockLayout>("\"tabs(0:a\"").is_err());
+    }
+}
@@ -0,0 +1,223 @@
+//! Move the keyboard focus between the widgets.
+//!
+//! The widgets of this crate accepting the keyboard focus take part in the
+//! focus operations of iced, next to the widgets of iced like the
+//! [`TextInput`](iced::widget::TextInput): they can be given an [`Id`] to be
+//! focused with [`focus`], and are visited in layout order by [`focus_next`]
+//! and [`focus_previous`]. A [`Modal`](crate::widgets::Modal) showing its
+//! overlay keeps the focus inside of it.
+//!
+//! The widgets made of several interactive parts, like the pickers or the
+//! [`NumberInput`](crate::widgets::NumberInput), take a tab order choosing
+//! which of their parts are reached with Tab, and in which order.
+//!
+//! # Example
+//! ```ignore
+//! # use iced::{Command, Subscription};
+//! # use iced_aw::focus::{self, Direction};
+//! #
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Traverse(Direction),
+//! }
+//!
+//! fn subscription() -> Subscription<Message> {
+//!     focus::subscription().map(Message::Traverse)
+//! }
+//!
+//! fn update(message: Message) -> Command<Message> {
+//!     match message {
+//!         Message::Traverse(direction) => focus::traverse(direction),
+//!     }
+//! }
+//! ```
+
+use iced::{
+    advanced::widget::operation::{self, Focusable, Operation},
+    keyboard::{self, key::Named, Key, Modifiers},
+    Command, Rectangle, Subscription,
+};
+
+pub use iced::advanced::widget::Id;
+
+/// The direction in which the focus moves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// To the next widget, with Tab.
+    Next,
+    /// To the previous widget, with Shift+Tab.
+    Previous,
+}
+
+impl Direction {
+    /// Returns the direction in which the focus moves when the `key` is
+    /// pressed with the `modifiers`, if any.
+    #[must_use]
+    pub fn from_key(key: &Key, modifiers: Modifiers) -> Option<Self> {
+        match key {
+            Key::Named(Named::Tab) if modifiers.shift() => Some(Self::Previous),
+            Key::Named(Named::Tab) => Some(Self::Next),
+            _ => None,
+        }
+    }
+}
+
+/// The place of an internal part of a widget, like the close button of a
+/// [`Card`](crate::widgets::Card), in the tab order of the widget.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TabStop {
+    /// The part is not reached with the keyboard.
+    #[default]
+    Skipped,
+    /// The part is reached before the content of the widget.
+    First,
+    /// The part is reached after the content of the widget.
+    Last,
+}
+
+/// Returns the part following `current` in the tab `order` of a widget in
+/// the given [`Direction`], wrapping around.
+///
+/// The first part, or the last one when going backwards, follows a
+/// `current` part missing from the order. Returns `None` when the order is
+/// empty.
+#[must_use]
+pub fn cycle<T: Copy + PartialEq>(order: &[T], current: T, direction: Direction) -> Option<T> {
+    let position = order.iter().position(|part| *part == current);
+    let index = match (position, direction) {
+        (Some(index), Direction::Next) => (index + 1) % order.len(),
+        (Some(index), Direction::Previous) => (index + order.len() - 1) % order.len(),
+        (None, Direction::Next) => 0,
+        (None, Direction::Previous) => order.len().checked_sub(1)?,
+    };
+
+    order.get(index).copied()
+}
+
+/// Listens to Tab and Shift+Tab when they are not captured by a widget,
+/// producing the [`Direction`] in which the focus moves.
+pub fn subscription() -> Subscription<Direction> {
+    keyboard::on_key_press(|key, modifiers| Direction::from_key(&key, modifiers))
+}
+
+/// Focuses the widget with the given [`Id`], unfocusing the others.
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id))
+}
+
+/// Focuses the next widget, or the first one when none is focused.
+pub fn focus_next<Message: 'static>() -> Command<Message> {
+    Command::widget(operation::focusable::focus_next())
+}
+
+/// Focuses the previous widget, or the last one when none is focused.
+pub fn focus_previous<Message: 'static>() -> Command<Message> {
+    Command::widget(operation::focusable::focus_previous())
+}
+
+/// Moves the focus in the given [`Direction`].
+pub fn traverse<Message: 'static>(direction: Direction) -> Command<Message> {
+    match direction {
+        Direction::Next => focus_next(),
+        Direction::Previous => focus_previous(),
+    }
+}
+
+/// Unfocuses all the widgets.
+pub fn unfocus<Message: 'static>() -> Command<Message> {
+    Command::widget(Unfocus)
+}
+
+/// The [`Operation`] unfocusing all the widgets.
+struct Unfocus;
+
+impl<T> Operation<T> for Unfocus {
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        state.unfocus();
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cycle, Direction, Focusable, Operation, Unfocus};
+    use iced::{
+        keyboard::{key::Named, Key, Modifiers},
+        Rectangle,
+    };
+
+    struct State(bool);
+
+    impl Focusable for State {
+        fn is_focused(&self) -> bool {
+            self.0
+        }
+
+        fn focus(&mut self) {
+            self.0 = true;
+        }
+
+        fn unfocus(&mut self) {
+            self.0 = false;
+        }
+    }
+
+    #[test]
+    fn tab_moves_the_focus() {
+        let tab = Key::Named(Named::Tab);
+
+        assert_eq!(
+            Direction::from_key(&tab, Modifiers::empty()),
+            Some(Direction::Next)
+        );
+        assert_eq!(
+            Direction::from_key(&tab, Modifiers::SHIFT),
+            Some(Direction::Previous)
+        );
+        assert_eq!(
+            Direction::from_key(&Key::Named(Named::Enter), Modifiers::empty()),
+            None
+        );
+    }
+
+    #[test]
+    fn cycle_follows_the_order() {
+        let order = [3, 1, 2];
+
+        assert_eq!(cycle(&order, 3, Direction::Next), Some(1));
+        assert_eq!(cycle(&order, 2, Direction::Next), Some(3));
+        assert_eq!(cycle(&order, 3, Direction::Previous), Some(2));
+        assert_eq!(cycle(&order, 0, Direction::Next), Some(3));
+        assert_eq!(cycle(&order, 0, Direction::Previous), Some(2));
+        assert_eq!(cycle(&[], 0, Direction::Next), None);
+        assert_eq!(cycle(&[], 0, Direction::Previous), None);
+    }
+
+    #[test]
+    fn unfocus_reaches_nested_widgets() {
+        let mut outer = State(true);
+        let mut inner = State(true);
+
+        let mut operation = Unfocus;
+        Operation::<()>::focusable(&mut operation, &mut outer, None);
+        Operation::<()>::container(
+            &mut operation,
+            None,
+            Rectangle::default(),
+            &mut |operation| {
+                operation.focusable(&mut inner, None);
+            },
+        );
+
+        assert!(!outer.is_focused());
+        assert!(!inner.is_focused());
+    }
+}
@@ -0,0 +1,74 @@
+//! Find the part of a widget under the cursor among parts laid out in order
+//! along an axis, with a binary search instead of testing every part.
+use iced::{Point, Rectangle};
+use std::ops::Range;
+
+/// Returns the range of the `parts`, ordered from left to right, whose
+/// `bounds` span the horizontal position `x`.
+///
+/// Both the left and the right edges of the bounds must be ordered, which is
+/// the case of parts of the same width or that do not overlap.
+pub fn range_x<T>(parts: &[T], bounds: impl Fn(&T) -> Rectangle, x: f32) -> Range<usize> {
+    let start = parts.partition_point(|part| {
+        let bounds = bounds(part);
+        bounds.x + bounds.width <= x
+    });
+    let end = start + parts[start..].partition_point(|part| bounds(part).x <= x);
+
+    start..end
+}
+
+/// Returns the index of the part containing `position` among the `parts`
+/// ordered from left to right without overlapping.
+pub fn find_x<T>(parts: &[T], bounds: impl Fn(&T) -> Rectangle, position: Point) -> Option<usize> {
+    range_x(parts, &bounds, position.x).find(|&index| bounds(&parts[index]).contains(position))
+}
+
+/// Returns the index of the part containing `position` among the `parts`
+/// ordered from top to bottom without overlapping.
+pub fn find_y<T>(parts: &[T], bounds: impl Fn(&T) -> Rectangle, position: Point) -> Option<usize> {
+    let start = parts.partition_point(|part| {
+        let bounds = bounds(part);
+        bounds.y + bounds.height <= position.y
+    });
+
+    (start..parts.len())
+        .take_while(|&index| bounds(&parts[index]).y <= position.y)
+        .find(|&index| bounds(&parts[index]).contains(position))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_x, find_y, range_x};
+    use iced::{Point, Rectangle};
+
+    #[test]
+    fn parts_are_found_in_order() {
+        let row: Vec<Rectangle> = (0..5)
+            .map(|i| Rectangle::new(Point::new(i as f32 * 12.0, 0.0), [10.0, 20.0].into()))
+            .collect();
+        let column: Vec<Rectangle> = row
+            .iter()
+            .map(|part| Rectangle::new(Point::new(part.y, part.x), [20.0, 10.0].into()))
+            .collect();
+
+        assert_eq!(find_x(&row, |part| *part, Point::new(25.0, 5.0)), Some(2));
+        assert_eq!(find_x(&row, |part| *part, Point::new(0.0, 5.0)), Some(0));
+        assert_eq!(find_x(&row, |part| *part, Point::new(11.0, 5.0)), None);
+        assert_eq!(find_x(&row, |part| *part, Point::new(25.0, 25.0)), None);
+        assert_eq!(find_x(&row, |part| *part, Point::new(90.0, 5.0)), None);
+        assert_eq!(
+            find_y(&column, |part| *part, Point::new(5.0, 49.0)),
+            Some(4)
+        );
+        assert_eq!(find_y(&column, |part| *part, Point::new(5.0, 35.0)), None);
+
+        let wide: Vec<Rectangle> = row
+            .iter()
+            .map(|part| Rectangle::new(Point::new(part.x - 4.0, 0.0), [18.0, 20.0].into()))
+            .collect();
+
+        assert_eq!(range_x(&wide, |part| *part, 23.0), 1..3);
+        assert_eq!(range_x(&wide, |part| *part, -10.0), 0..0);
+    }
+}
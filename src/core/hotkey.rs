@@ -0,0 +1,168 @@
+//! Describe key combinations, like the shortcuts of an application.
+//!
+//! *This API requires the following crate features to be activated: `hotkey_input`*
+
+use iced::keyboard::{key::Named, Key, Modifiers};
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
+/// A key combination: a key pressed while holding some modifiers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hotkey {
+    /// The modifiers held while pressing the key.
+    pub modifiers: Modifiers,
+    /// The pressed key. Characters are lowercase.
+    pub key: Key,
+}
+
+impl Hotkey {
+    /// Creates a new [`Hotkey`] pressing `key` while holding `modifiers`.
+    #[must_use]
+    pub fn new(key: Key, modifiers: Modifiers) -> Self {
+        let key = match key {
+            Key::Character(c) => Key::Character(c.to_lowercase().as_str().into()),
+            key => key,
+        };
+
+        Self { modifiers, key }
+    }
+
+    /// Creates the [`Hotkey`] of a key press, or `None` when the pressed key
+    /// is a modifier, which only completes a combination with another key.
+    #[must_use]
+    pub fn from_key_press(key: &Key, modifiers: Modifiers) -> Option<Self> {
+        match key {
+            Key::Named(named) if is_modifier(*named) => None,
+            Key::Unidentified => None,
+            key => Some(Self::new(key.clone(), modifiers)),
+        }
+    }
+
+    /// Returns whether a press of `key` while holding `modifiers` triggers
+    /// the [`Hotkey`].
+    #[must_use]
+    pub fn matches(&self, key: &Key, modifiers: Modifiers) -> bool {
+        Self::from_key_press(key, modifiers).is_some_and(|hotkey| &hotkey == self)
+    }
+}
+
+impl Hash for Hotkey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.modifiers.bits().hash(state);
+        self.key.hash(state);
+    }
+}
+
+impl Display for Hotkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            modifiers_label(self.modifiers),
+            key_label(&self.key)
+        )
+    }
+}
+
+/// Returns the names of the held `modifiers`, each followed by a `+`, like
+/// `Ctrl+Shift+`.
+#[must_use]
+pub fn modifiers_label(modifiers: Modifiers) -> String {
+    [
+        (modifiers.control(), "Ctrl+"),
+        (modifiers.alt(), "Alt+"),
+        (modifiers.shift(), "Shift+"),
+        (modifiers.logo(), "Super+"),
+    ]
+    .into_iter()
+    .filter_map(|(held, label)| held.then_some(label))
+    .collect()
+}
+
+/// Returns whether the named key is a modifier.
+fn is_modifier(named: Named) -> bool {
+    matches!(
+        named,
+        Named::Alt
+            | Named::AltGraph
+            | Named::Control
+            | Named::Shift
+            | Named::Super
+            | Named::Meta
+            | Named::Hyper
+            | Named::Fn
+    )
+}
+
+/// Returns the name of `key` as shown to the user.
+#[allow(clippy::use_debug)]
+fn key_label(key: &Key) -> String {
+    match key {
+        Key::Character(c) => c.to_uppercase(),
+        Key::Named(named) => match named {
+            Named::Escape => "Esc".to_owned(),
+            Named::Delete => "Del".to_owned(),
+            Named::ArrowUp => "Up".to_owned(),
+            Named::ArrowDown => "Down".to_owned(),
+            Named::ArrowLeft => "Left".to_owned(),
+            Named::ArrowRight => "Right".to_owned(),
+            Named::PageUp => "PgUp".to_owned(),
+            Named::PageDown => "PgDn".to_owned(),
+            named => format!("{named:?}"),
+        },
+        Key::Unidentified => "?".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{modifiers_label, Hotkey};
+    use iced::keyboard::{key::Named, Key, Modifiers};
+
+    #[test]
+    fn display_test() {
+        let hotkey = Hotkey::new(
+            Key::Character("p".into()),
+            Modifiers::CTRL | Modifiers::SHIFT,
+        );
+        assert_eq!(hotkey.to_string(), "Ctrl+Shift+P");
+
+        let hotkey = Hotkey::new(Key::Named(Named::F5), Modifiers::empty());
+        assert_eq!(hotkey.to_string(), "F5");
+
+        let hotkey = Hotkey::new(Key::Named(Named::ArrowUp), Modifiers::ALT);
+        assert_eq!(hotkey.to_string(), "Alt+Up");
+
+        assert_eq!(
+            modifiers_label(Modifiers::LOGO | Modifiers::CTRL),
+            "Ctrl+Super+"
+        );
+    }
+
+    #[test]
+    fn from_key_press_test() {
+        assert_eq!(
+            Hotkey::from_key_press(&Key::Named(Named::Control), Modifiers::CTRL),
+            None
+        );
+
+        let hotkey = Hotkey::from_key_press(&Key::Character("P".into()), Modifiers::SHIFT);
+        assert_eq!(
+            hotkey,
+            Some(Hotkey::new(Key::Character("p".into()), Modifiers::SHIFT))
+        );
+    }
+
+    #[test]
+    fn matches_test() {
+        let hotkey = Hotkey::new(Key::Character("s".into()), Modifiers::CTRL);
+
+        assert!(hotkey.matches(&Key::Character("s".into()), Modifiers::CTRL));
+        assert!(hotkey.matches(&Key::Character("S".into()), Modifiers::CTRL));
+        assert!(!hotkey.matches(&Key::Character("s".into()), Modifiers::empty()));
+        assert!(!hotkey.matches(
+            &Key::Character("s".into()),
+            Modifiers::CTRL | Modifiers::SHIFT
+        ));
+    }
+}
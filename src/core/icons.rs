@@ -3,22 +3,22 @@
 use cfg_if::cfg_if;
 use iced::Font;
 
+mod handle;
+pub mod registry;
+
+pub use handle::{IconHandle, IconRenderer};
+pub use registry::{icon_font, icon_fonts, load_icon_fonts, register_icon_font, IconFont};
+
 cfg_if! {
     if #[cfg(feature = "icons")] {
         pub mod bootstrap;
-        pub mod nerd;
 
         pub use bootstrap::Bootstrap;
-        pub use nerd::Nerd;
         /// The default icon font bytes for loading the font into iced.
         pub const BOOTSTRAP_FONT_BYTES: &[u8] = include_bytes!("./fonts/bootstrap-icons.ttf");
-        /// the icon font that has all nerd fonts.
-        pub const NERD_FONT_BYTES: &[u8] = include_bytes!("./fonts/nerd-icons.ttf");
 
         /// The bootstrap icon font.
         pub const BOOTSTRAP_FONT: Font = Font::with_name("bootstrap-icons");
-        /// The nerd icon font.
-        pub const NERD_FONT: Font = Font::with_name("Symbols Nerd Font");
 
         /// The default cupertino font bytes for loading the font into the system.
         pub const SF_UI_ROUNDED_BYTES: &[u8] = include_bytes!("./fonts/SFUIRounded.ttf");
@@ -27,6 +27,7 @@ cfg_if! {
         pub const SF_UI_ROUNDED: iced::Font = iced::Font::with_name(".SF UI Rounded");
     } else {
         pub mod required;
+        pub use required::Bootstrap;
         // pub use required::{Bootstrap, icon_to_char, icon_to_string};
         /// The default icon font bytes for loading the font into iced.
         pub const BOOTSTRAP_FONT_BYTES: &[u8] = include_bytes!("./fonts/required-icons.ttf");
@@ -35,3 +36,68 @@ cfg_if! {
     }
 
 }
+
+cfg_if! {
+    if #[cfg(feature = "nerd_icons")] {
+        pub mod nerd;
+
+        pub use nerd::Nerd;
+        /// the icon font that has all nerd fonts.
+        pub const NERD_FONT_BYTES: &[u8] = include_bytes!("./fonts/nerd-icons.ttf");
+
+        /// The nerd icon font.
+        pub const NERD_FONT: Font = Font::with_name("Symbols Nerd Font");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "icons")]
+    use super::bootstrap::{icon_to_char, Bootstrap};
+    #[cfg(not(feature = "icons"))]
+    use super::required::{icon_to_char, Bootstrap};
+    use super::{icon_font, register_icon_font, IconFont, IconHandle, BOOTSTRAP_FONT};
+
+    #[test]
+    fn icons_are_found_by_name() {
+        assert_eq!(Bootstrap::from_name("check"), Some(Bootstrap::Check));
+        assert_eq!(Bootstrap::from_name("not-an-icon"), None);
+
+        for icon in Bootstrap::all() {
+            assert_eq!(Bootstrap::from_name(icon.name()), Some(icon));
+        }
+
+        let mut chars: Vec<char> = Bootstrap::all().map(icon_to_char).collect();
+        let count = chars.len();
+        chars.sort_unstable();
+        chars.dedup();
+        assert_eq!(chars.len(), count);
+    }
+
+    #[test]
+    fn icon_fonts_are_registered_by_name() {
+        assert_eq!(IconFont::BOOTSTRAP.font(), BOOTSTRAP_FONT);
+        assert_eq!(
+            icon_font(IconFont::BOOTSTRAP.name()),
+            Some(IconFont::BOOTSTRAP)
+        );
+
+        let symbols = IconFont::new("test-symbols", &[]);
+        assert_eq!(icon_font("test-symbols"), None);
+
+        register_icon_font(symbols);
+        register_icon_font(symbols);
+        assert_eq!(icon_font("test-symbols"), Some(symbols));
+        assert_eq!(
+            super::icon_fonts()
+                .iter()
+                .filter(|icon_font| **icon_font == symbols)
+                .count(),
+            1
+        );
+        assert_eq!(
+            symbols.glyph('a'),
+            IconHandle::Glyph('a', Some(symbols.font()))
+        );
+    }
+}
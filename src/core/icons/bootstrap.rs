@@ -10,7 +10,7 @@ use std::{
 use iced::widget::{text, Text};
 
 /// Holds all glyphs of the Bootstrap font
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Bootstrap {
     /// Activity 
     Activity,
@@ -4110,6 +4110,4242 @@ pub enum Bootstrap {
     ZoomOut,
 }
 
+/// The names of all the glyphs of the Bootstrap font, sorted, with their icon.
+static NAMES: [(&str, Bootstrap); 2048] = [
+    ("0-circle", Bootstrap::ZeroCircle),
+    ("0-circle-fill", Bootstrap::ZeroCircleFill),
+    ("0-square", Bootstrap::ZeroSquare),
+    ("0-square-fill", Bootstrap::ZeroSquareFill),
+    ("1-circle", Bootstrap::OneCircle),
+    ("1-circle-fill", Bootstrap::OneCircleFill),
+    ("1-square", Bootstrap::OneSquare),
+    ("1-square-fill", Bootstrap::OneSquareFill),
+    ("123", Bootstrap::Onetwothree),
+    ("2-circle", Bootstrap::TwoCircle),
+    ("2-circle-fill", Bootstrap::TwoCircleFill),
+    ("2-square", Bootstrap::TwoSquare),
+    ("2-square-fill", Bootstrap::TwoSquareFill),
+    ("3-circle", Bootstrap::ThreeCircle),
+    ("3-circle-fill", Bootstrap::ThreeCircleFill),
+    ("3-square", Bootstrap::ThreeSquare),
+    ("3-square-fill", Bootstrap::ThreeSquareFill),
+    ("4-circle", Bootstrap::FourCircle),
+    ("4-circle-fill", Bootstrap::FourCircleFill),
+    ("4-square", Bootstrap::FourSquare),
+    ("4-square-fill", Bootstrap::FourSquareFill),
+    ("5-circle", Bootstrap::FiveCircle),
+    ("5-circle-fill", Bootstrap::FiveCircleFill),
+    ("5-square", Bootstrap::FiveSquare),
+    ("5-square-fill", Bootstrap::FiveSquareFill),
+    ("6-circle", Bootstrap::SixCircle),
+    ("6-circle-fill", Bootstrap::SixCircleFill),
+    ("6-square", Bootstrap::SixSquare),
+    ("6-square-fill", Bootstrap::SixSquareFill),
+    ("7-circle", Bootstrap::SevenCircle),
+    ("7-circle-fill", Bootstrap::SevenCircleFill),
+    ("7-square", Bootstrap::SevenSquare),
+    ("7-square-fill", Bootstrap::SevenSquareFill),
+    ("8-circle", Bootstrap::EightCircle),
+    ("8-circle-fill", Bootstrap::EightCircleFill),
+    ("8-square", Bootstrap::EightSquare),
+    ("8-square-fill", Bootstrap::EightSquareFill),
+    ("9-circle", Bootstrap::NineCircle),
+    ("9-circle-fill", Bootstrap::NineCircleFill),
+    ("9-square", Bootstrap::NineSquare),
+    ("9-square-fill", Bootstrap::NineSquareFill),
+    ("activity", Bootstrap::Activity),
+    ("airplane", Bootstrap::Airplane),
+    ("airplane-engines", Bootstrap::AirplaneEngines),
+    ("airplane-engines-fill", Bootstrap::AirplaneEnginesFill),
+    ("airplane-fill", Bootstrap::AirplaneFill),
+    ("alarm", Bootstrap::Alarm),
+    ("alarm-fill", Bootstrap::AlarmFill),
+    ("alexa", Bootstrap::Alexa),
+    ("align-bottom", Bootstrap::AlignBottom),
+    ("align-center", Bootstrap::AlignCenter),
+    ("align-end", Bootstrap::AlignEnd),
+    ("align-middle", Bootstrap::AlignMiddle),
+    ("align-start", Bootstrap::AlignStart),
+    ("align-top", Bootstrap::AlignTop),
+    ("alipay", Bootstrap::Alipay),
+    ("alphabet", Bootstrap::Alphabet),
+    ("alphabet-uppercase", Bootstrap::AlphabetUppercase),
+    ("alt", Bootstrap::Alt),
+    ("amazon", Bootstrap::Amazon),
+    ("amd", Bootstrap::Amd),
+    ("android", Bootstrap::Android),
+    ("android2", Bootstrap::Androidtwo),
+    ("app", Bootstrap::App),
+    ("app-indicator", Bootstrap::AppIndicator),
+    ("apple", Bootstrap::Apple),
+    ("archive", Bootstrap::Archive),
+    ("archive-fill", Bootstrap::ArchiveFill),
+    ("arrow-90deg-down", Bootstrap::ArrowNinezerodegDown),
+    ("arrow-90deg-left", Bootstrap::ArrowNinezerodegLeft),
+    ("arrow-90deg-right", Bootstrap::ArrowNinezerodegRight),
+    ("arrow-90deg-up", Bootstrap::ArrowNinezerodegUp),
+    ("arrow-bar-down", Bootstrap::ArrowBarDown),
+    ("arrow-bar-left", Bootstrap::ArrowBarLeft),
+    ("arrow-bar-right", Bootstrap::ArrowBarRight),
+    ("arrow-bar-up", Bootstrap::ArrowBarUp),
+    ("arrow-clockwise", Bootstrap::ArrowClockwise),
+    ("arrow-counterclockwise", Bootstrap::ArrowCounterclockwise),
+    ("arrow-down", Bootstrap::ArrowDown),
+    ("arrow-down-circle", Bootstrap::ArrowDownCircle),
+    ("arrow-down-circle-fill", Bootstrap::ArrowDownCircleFill),
+    ("arrow-down-left", Bootstrap::ArrowDownLeft),
+    ("arrow-down-left-circle", Bootstrap::ArrowDownLeftCircle),
+    (
+        "arrow-down-left-circle-fill",
+        Bootstrap::ArrowDownLeftCircleFill,
+    ),
+    ("arrow-down-left-square", Bootstrap::ArrowDownLeftSquare),
+    (
+        "arrow-down-left-square-fill",
+        Bootstrap::ArrowDownLeftSquareFill,
+    ),
+    ("arrow-down-right", Bootstrap::ArrowDownRight),
+    ("arrow-down-right-circle", Bootstrap::ArrowDownRightCircle),
+    (
+        "arrow-down-right-circle-fill",
+        Bootstrap::ArrowDownRightCircleFill,
+    ),
+    ("arrow-down-right-square", Bootstrap::ArrowDownRightSquare),
+    (
+        "arrow-down-right-square-fill",
+        Bootstrap::ArrowDownRightSquareFill,
+    ),
+    ("arrow-down-short", Bootstrap::ArrowDownShort),
+    ("arrow-down-square", Bootstrap::ArrowDownSquare),
+    ("arrow-down-square-fill", Bootstrap::ArrowDownSquareFill),
+    ("arrow-down-up", Bootstrap::ArrowDownUp),
+    ("arrow-left", Bootstrap::ArrowLeft),
+    ("arrow-left-circle", Bootstrap::ArrowLeftCircle),
+    ("arrow-left-circle-fill", Bootstrap::ArrowLeftCircleFill),
+    ("arrow-left-right", Bootstrap::ArrowLeftRight),
+    ("arrow-left-short", Bootstrap::ArrowLeftShort),
+    ("arrow-left-square", Bootstrap::ArrowLeftSquare),
+    ("arrow-left-square-fill", Bootstrap::ArrowLeftSquareFill),
+    ("arrow-repeat", Bootstrap::ArrowRepeat),
+    ("arrow-return-left", Bootstrap::ArrowReturnLeft),
+    ("arrow-return-right", Bootstrap::ArrowReturnRight),
+    ("arrow-right", Bootstrap::ArrowRight),
+    ("arrow-right-circle", Bootstrap::ArrowRightCircle),
+    ("arrow-right-circle-fill", Bootstrap::ArrowRightCircleFill),
+    ("arrow-right-short", Bootstrap::ArrowRightShort),
+    ("arrow-right-square", Bootstrap::ArrowRightSquare),
+    ("arrow-right-square-fill", Bootstrap::ArrowRightSquareFill),
+    ("arrow-through-heart", Bootstrap::ArrowThroughHeart),
+    ("arrow-through-heart-fill", Bootstrap::ArrowThroughHeartFill),
+    ("arrow-up", Bootstrap::ArrowUp),
+    ("arrow-up-circle", Bootstrap::ArrowUpCircle),
+    ("arrow-up-circle-fill", Bootstrap::ArrowUpCircleFill),
+    ("arrow-up-left", Bootstrap::ArrowUpLeft),
+    ("arrow-up-left-circle", Bootstrap::ArrowUpLeftCircle),
+    (
+        "arrow-up-left-circle-fill",
+        Bootstrap::ArrowUpLeftCircleFill,
+    ),
+    ("arrow-up-left-square", Bootstrap::ArrowUpLeftSquare),
+    (
+        "arrow-up-left-square-fill",
+        Bootstrap::ArrowUpLeftSquareFill,
+    ),
+    ("arrow-up-right", Bootstrap::ArrowUpRight),
+    ("arrow-up-right-circle", Bootstrap::ArrowUpRightCircle),
+    (
+        "arrow-up-right-circle-fill",
+        Bootstrap::ArrowUpRightCircleFill,
+    ),
+    ("arrow-up-right-square", Bootstrap::ArrowUpRightSquare),
+    (
+        "arrow-up-right-square-fill",
+        Bootstrap::ArrowUpRightSquareFill,
+    ),
+    ("arrow-up-short", Bootstrap::ArrowUpShort),
+    ("arrow-up-square", Bootstrap::ArrowUpSquare),
+    ("arrow-up-square-fill", Bootstrap::ArrowUpSquareFill),
+    ("arrows", Bootstrap::Arrows),
+    ("arrows-angle-contract", Bootstrap::ArrowsAngleContract),
+    ("arrows-angle-expand", Bootstrap::ArrowsAngleExpand),
+    ("arrows-collapse", Bootstrap::ArrowsCollapse),
+    (
+        "arrows-collapse-vertical",
+        Bootstrap::ArrowsCollapseVertical,
+    ),
+    ("arrows-expand", Bootstrap::ArrowsExpand),
+    ("arrows-expand-vertical", Bootstrap::ArrowsExpandVertical),
+    ("arrows-fullscreen", Bootstrap::ArrowsFullscreen),
+    ("arrows-move", Bootstrap::ArrowsMove),
+    ("arrows-vertical", Bootstrap::ArrowsVertical),
+    ("aspect-ratio", Bootstrap::AspectRatio),
+    ("aspect-ratio-fill", Bootstrap::AspectRatioFill),
+    ("asterisk", Bootstrap::Asterisk),
+    ("at", Bootstrap::At),
+    ("award", Bootstrap::Award),
+    ("award-fill", Bootstrap::AwardFill),
+    ("back", Bootstrap::Back),
+    ("backpack", Bootstrap::Backpack),
+    ("backpack-fill", Bootstrap::BackpackFill),
+    ("backpack2", Bootstrap::Backpacktwo),
+    ("backpack2-fill", Bootstrap::BackpacktwoFill),
+    ("backpack3", Bootstrap::Backpackthree),
+    ("backpack3-fill", Bootstrap::BackpackthreeFill),
+    ("backpack4", Bootstrap::Backpackfour),
+    ("backpack4-fill", Bootstrap::BackpackfourFill),
+    ("backspace", Bootstrap::Backspace),
+    ("backspace-fill", Bootstrap::BackspaceFill),
+    ("backspace-reverse", Bootstrap::BackspaceReverse),
+    ("backspace-reverse-fill", Bootstrap::BackspaceReverseFill),
+    ("badge-3d", Bootstrap::BadgeThreed),
+    ("badge-3d-fill", Bootstrap::BadgeThreedFill),
+    ("badge-4k", Bootstrap::BadgeFourk),
+    ("badge-4k-fill", Bootstrap::BadgeFourkFill),
+    ("badge-8k", Bootstrap::BadgeEightk),
+    ("badge-8k-fill", Bootstrap::BadgeEightkFill),
+    ("badge-ad", Bootstrap::BadgeAd),
+    ("badge-ad-fill", Bootstrap::BadgeAdFill),
+    ("badge-ar", Bootstrap::BadgeAr),
+    ("badge-ar-fill", Bootstrap::BadgeArFill),
+    ("badge-cc", Bootstrap::BadgeCc),
+    ("badge-cc-fill", Bootstrap::BadgeCcFill),
+    ("badge-hd", Bootstrap::BadgeHd),
+    ("badge-hd-fill", Bootstrap::BadgeHdFill),
+    ("badge-sd", Bootstrap::BadgeSd),
+    ("badge-sd-fill", Bootstrap::BadgeSdFill),
+    ("badge-tm", Bootstrap::BadgeTm),
+    ("badge-tm-fill", Bootstrap::BadgeTmFill),
+    ("badge-vo", Bootstrap::BadgeVo),
+    ("badge-vo-fill", Bootstrap::BadgeVoFill),
+    ("badge-vr", Bootstrap::BadgeVr),
+    ("badge-vr-fill", Bootstrap::BadgeVrFill),
+    ("badge-wc", Bootstrap::BadgeWc),
+    ("badge-wc-fill", Bootstrap::BadgeWcFill),
+    ("bag", Bootstrap::Bag),
+    ("bag-check", Bootstrap::BagCheck),
+    ("bag-check-fill", Bootstrap::BagCheckFill),
+    ("bag-dash", Bootstrap::BagDash),
+    ("bag-dash-fill", Bootstrap::BagDashFill),
+    ("bag-fill", Bootstrap::BagFill),
+    ("bag-heart", Bootstrap::BagHeart),
+    ("bag-heart-fill", Bootstrap::BagHeartFill),
+    ("bag-plus", Bootstrap::BagPlus),
+    ("bag-plus-fill", Bootstrap::BagPlusFill),
+    ("bag-x", Bootstrap::BagX),
+    ("bag-x-fill", Bootstrap::BagXFill),
+    ("balloon", Bootstrap::Balloon),
+    ("balloon-fill", Bootstrap::BalloonFill),
+    ("balloon-heart", Bootstrap::BalloonHeart),
+    ("balloon-heart-fill", Bootstrap::BalloonHeartFill),
+    ("ban", Bootstrap::Ban),
+    ("ban-fill", Bootstrap::BanFill),
+    ("bandaid", Bootstrap::Bandaid),
+    ("bandaid-fill", Bootstrap::BandaidFill),
+    ("bank", Bootstrap::Bank),
+    ("bank2", Bootstrap::Banktwo),
+    ("bar-chart", Bootstrap::BarChart),
+    ("bar-chart-fill", Bootstrap::BarChartFill),
+    ("bar-chart-line", Bootstrap::BarChartLine),
+    ("bar-chart-line-fill", Bootstrap::BarChartLineFill),
+    ("bar-chart-steps", Bootstrap::BarChartSteps),
+    ("basket", Bootstrap::Basket),
+    ("basket-fill", Bootstrap::BasketFill),
+    ("basket2", Bootstrap::Baskettwo),
+    ("basket2-fill", Bootstrap::BaskettwoFill),
+    ("basket3", Bootstrap::Basketthree),
+    ("basket3-fill", Bootstrap::BasketthreeFill),
+    ("battery", Bootstrap::Battery),
+    ("battery-charging", Bootstrap::BatteryCharging),
+    ("battery-full", Bootstrap::BatteryFull),
+    ("battery-half", Bootstrap::BatteryHalf),
+    ("behance", Bootstrap::Behance),
+    ("bell", Bootstrap::Bell),
+    ("bell-fill", Bootstrap::BellFill),
+    ("bell-slash", Bootstrap::BellSlash),
+    ("bell-slash-fill", Bootstrap::BellSlashFill),
+    ("bezier", Bootstrap::Bezier),
+    ("bezier2", Bootstrap::Beziertwo),
+    ("bicycle", Bootstrap::Bicycle),
+    ("bing", Bootstrap::Bing),
+    ("binoculars", Bootstrap::Binoculars),
+    ("binoculars-fill", Bootstrap::BinocularsFill),
+    ("blockquote-left", Bootstrap::BlockquoteLeft),
+    ("blockquote-right", Bootstrap::BlockquoteRight),
+    ("bluetooth", Bootstrap::Bluetooth),
+    ("body-text", Bootstrap::BodyText),
+    ("book", Bootstrap::Book),
+    ("book-fill", Bootstrap::BookFill),
+    ("book-half", Bootstrap::BookHalf),
+    ("bookmark", Bootstrap::Bookmark),
+    ("bookmark-check", Bootstrap::BookmarkCheck),
+    ("bookmark-check-fill", Bootstrap::BookmarkCheckFill),
+    ("bookmark-dash", Bootstrap::BookmarkDash),
+    ("bookmark-dash-fill", Bootstrap::BookmarkDashFill),
+    ("bookmark-fill", Bootstrap::BookmarkFill),
+    ("bookmark-heart", Bootstrap::BookmarkHeart),
+    ("bookmark-heart-fill", Bootstrap::BookmarkHeartFill),
+    ("bookmark-plus", Bootstrap::BookmarkPlus),
+    ("bookmark-plus-fill", Bootstrap::BookmarkPlusFill),
+    ("bookmark-star", Bootstrap::BookmarkStar),
+    ("bookmark-star-fill", Bootstrap::BookmarkStarFill),
+    ("bookmark-x", Bootstrap::BookmarkX),
+    ("bookmark-x-fill", Bootstrap::BookmarkXFill),
+    ("bookmarks", Bootstrap::Bookmarks),
+    ("bookmarks-fill", Bootstrap::BookmarksFill),
+    ("bookshelf", Bootstrap::Bookshelf),
+    ("boombox", Bootstrap::Boombox),
+    ("boombox-fill", Bootstrap::BoomboxFill),
+    ("bootstrap", Bootstrap::Bootstrap),
+    ("bootstrap-fill", Bootstrap::BootstrapFill),
+    ("bootstrap-reboot", Bootstrap::BootstrapReboot),
+    ("border", Bootstrap::Border),
+    ("border-all", Bootstrap::BorderAll),
+    ("border-bottom", Bootstrap::BorderBottom),
+    ("border-center", Bootstrap::BorderCenter),
+    ("border-inner", Bootstrap::BorderInner),
+    ("border-left", Bootstrap::BorderLeft),
+    ("border-middle", Bootstrap::BorderMiddle),
+    ("border-outer", Bootstrap::BorderOuter),
+    ("border-right", Bootstrap::BorderRight),
+    ("border-style", Bootstrap::BorderStyle),
+    ("border-top", Bootstrap::BorderTop),
+    ("border-width", Bootstrap::BorderWidth),
+    ("bounding-box", Bootstrap::BoundingBox),
+    ("bounding-box-circles", Bootstrap::BoundingBoxCircles),
+    ("box", Bootstrap::Box),
+    ("box-arrow-down", Bootstrap::BoxArrowDown),
+    ("box-arrow-down-left", Bootstrap::BoxArrowDownLeft),
+    ("box-arrow-down-right", Bootstrap::BoxArrowDownRight),
+    ("box-arrow-in-down", Bootstrap::BoxArrowInDown),
+    ("box-arrow-in-down-left", Bootstrap::BoxArrowInDownLeft),
+    ("box-arrow-in-down-right", Bootstrap::BoxArrowInDownRight),
+    ("box-arrow-in-left", Bootstrap::BoxArrowInLeft),
+    ("box-arrow-in-right", Bootstrap::BoxArrowInRight),
+    ("box-arrow-in-up", Bootstrap::BoxArrowInUp),
+    ("box-arrow-in-up-left", Bootstrap::BoxArrowInUpLeft),
+    ("box-arrow-in-up-right", Bootstrap::BoxArrowInUpRight),
+    ("box-arrow-left", Bootstrap::BoxArrowLeft),
+    ("box-arrow-right", Bootstrap::BoxArrowRight),
+    ("box-arrow-up", Bootstrap::BoxArrowUp),
+    ("box-arrow-up-left", Bootstrap::BoxArrowUpLeft),
+    ("box-arrow-up-right", Bootstrap::BoxArrowUpRight),
+    ("box-fill", Bootstrap::BoxFill),
+    ("box-seam", Bootstrap::BoxSeam),
+    ("box-seam-fill", Bootstrap::BoxSeamFill),
+    ("box2", Bootstrap::Boxtwo),
+    ("box2-fill", Bootstrap::BoxtwoFill),
+    ("box2-heart", Bootstrap::BoxtwoHeart),
+    ("box2-heart-fill", Bootstrap::BoxtwoHeartFill),
+    ("boxes", Bootstrap::Boxes),
+    ("braces", Bootstrap::Braces),
+    ("braces-asterisk", Bootstrap::BracesAsterisk),
+    ("bricks", Bootstrap::Bricks),
+    ("briefcase", Bootstrap::Briefcase),
+    ("briefcase-fill", Bootstrap::BriefcaseFill),
+    ("brightness-alt-high", Bootstrap::BrightnessAltHigh),
+    ("brightness-alt-high-fill", Bootstrap::BrightnessAltHighFill),
+    ("brightness-alt-low", Bootstrap::BrightnessAltLow),
+    ("brightness-alt-low-fill", Bootstrap::BrightnessAltLowFill),
+    ("brightness-high", Bootstrap::BrightnessHigh),
+    ("brightness-high-fill", Bootstrap::BrightnessHighFill),
+    ("brightness-low", Bootstrap::BrightnessLow),
+    ("brightness-low-fill", Bootstrap::BrightnessLowFill),
+    ("brilliance", Bootstrap::Brilliance),
+    ("broadcast", Bootstrap::Broadcast),
+    ("broadcast-pin", Bootstrap::BroadcastPin),
+    ("browser-chrome", Bootstrap::BrowserChrome),
+    ("browser-edge", Bootstrap::BrowserEdge),
+    ("browser-firefox", Bootstrap::BrowserFirefox),
+    ("browser-safari", Bootstrap::BrowserSafari),
+    ("brush", Bootstrap::Brush),
+    ("brush-fill", Bootstrap::BrushFill),
+    ("bucket", Bootstrap::Bucket),
+    ("bucket-fill", Bootstrap::BucketFill),
+    ("bug", Bootstrap::Bug),
+    ("bug-fill", Bootstrap::BugFill),
+    ("building", Bootstrap::Building),
+    ("building-add", Bootstrap::BuildingAdd),
+    ("building-check", Bootstrap::BuildingCheck),
+    ("building-dash", Bootstrap::BuildingDash),
+    ("building-down", Bootstrap::BuildingDown),
+    ("building-exclamation", Bootstrap::BuildingExclamation),
+    ("building-fill", Bootstrap::BuildingFill),
+    ("building-fill-add", Bootstrap::BuildingFillAdd),
+    ("building-fill-check", Bootstrap::BuildingFillCheck),
+    ("building-fill-dash", Bootstrap::BuildingFillDash),
+    ("building-fill-down", Bootstrap::BuildingFillDown),
+    (
+        "building-fill-exclamation",
+        Bootstrap::BuildingFillExclamation,
+    ),
+    ("building-fill-gear", Bootstrap::BuildingFillGear),
+    ("building-fill-lock", Bootstrap::BuildingFillLock),
+    ("building-fill-slash", Bootstrap::BuildingFillSlash),
+    ("building-fill-up", Bootstrap::BuildingFillUp),
+    ("building-fill-x", Bootstrap::BuildingFillX),
+    ("building-gear", Bootstrap::BuildingGear),
+    ("building-lock", Bootstrap::BuildingLock),
+    ("building-slash", Bootstrap::BuildingSlash),
+    ("building-up", Bootstrap::BuildingUp),
+    ("building-x", Bootstrap::BuildingX),
+    ("buildings", Bootstrap::Buildings),
+    ("buildings-fill", Bootstrap::BuildingsFill),
+    ("bullseye", Bootstrap::Bullseye),
+    ("bus-front", Bootstrap::BusFront),
+    ("bus-front-fill", Bootstrap::BusFrontFill),
+    ("c-circle", Bootstrap::CCircle),
+    ("c-circle-fill", Bootstrap::CCircleFill),
+    ("c-square", Bootstrap::CSquare),
+    ("c-square-fill", Bootstrap::CSquareFill),
+    ("cake", Bootstrap::Cake),
+    ("cake-fill", Bootstrap::CakeFill),
+    ("cake2", Bootstrap::Caketwo),
+    ("cake2-fill", Bootstrap::CaketwoFill),
+    ("calculator", Bootstrap::Calculator),
+    ("calculator-fill", Bootstrap::CalculatorFill),
+    ("calendar", Bootstrap::Calendar),
+    ("calendar-check", Bootstrap::CalendarCheck),
+    ("calendar-check-fill", Bootstrap::CalendarCheckFill),
+    ("calendar-date", Bootstrap::CalendarDate),
+    ("calendar-date-fill", Bootstrap::CalendarDateFill),
+    ("calendar-day", Bootstrap::CalendarDay),
+    ("calendar-day-fill", Bootstrap::CalendarDayFill),
+    ("calendar-event", Bootstrap::CalendarEvent),
+    ("calendar-event-fill", Bootstrap::CalendarEventFill),
+    ("calendar-fill", Bootstrap::CalendarFill),
+    ("calendar-heart", Bootstrap::CalendarHeart),
+    ("calendar-heart-fill", Bootstrap::CalendarHeartFill),
+    ("calendar-minus", Bootstrap::CalendarMinus),
+    ("calendar-minus-fill", Bootstrap::CalendarMinusFill),
+    ("calendar-month", Bootstrap::CalendarMonth),
+    ("calendar-month-fill", Bootstrap::CalendarMonthFill),
+    ("calendar-plus", Bootstrap::CalendarPlus),
+    ("calendar-plus-fill", Bootstrap::CalendarPlusFill),
+    ("calendar-range", Bootstrap::CalendarRange),
+    ("calendar-range-fill", Bootstrap::CalendarRangeFill),
+    ("calendar-week", Bootstrap::CalendarWeek),
+    ("calendar-week-fill", Bootstrap::CalendarWeekFill),
+    ("calendar-x", Bootstrap::CalendarX),
+    ("calendar-x-fill", Bootstrap::CalendarXFill),
+    ("calendar2", Bootstrap::Calendartwo),
+    ("calendar2-check", Bootstrap::CalendartwoCheck),
+    ("calendar2-check-fill", Bootstrap::CalendartwoCheckFill),
+    ("calendar2-date", Bootstrap::CalendartwoDate),
+    ("calendar2-date-fill", Bootstrap::CalendartwoDateFill),
+    ("calendar2-day", Bootstrap::CalendartwoDay),
+    ("calendar2-day-fill", Bootstrap::CalendartwoDayFill),
+    ("calendar2-event", Bootstrap::CalendartwoEvent),
+    ("calendar2-event-fill", Bootstrap::CalendartwoEventFill),
+    ("calendar2-fill", Bootstrap::CalendartwoFill),
+    ("calendar2-heart", Bootstrap::CalendartwoHeart),
+    ("calendar2-heart-fill", Bootstrap::CalendartwoHeartFill),
+    ("calendar2-minus", Bootstrap::CalendartwoMinus),
+    ("calendar2-minus-fill", Bootstrap::CalendartwoMinusFill),
+    ("calendar2-month", Bootstrap::CalendartwoMonth),
+    ("calendar2-month-fill", Bootstrap::CalendartwoMonthFill),
+    ("calendar2-plus", Bootstrap::CalendartwoPlus),
+    ("calendar2-plus-fill", Bootstrap::CalendartwoPlusFill),
+    ("calendar2-range", Bootstrap::CalendartwoRange),
+    ("calendar2-range-fill", Bootstrap::CalendartwoRangeFill),
+    ("calendar2-week", Bootstrap::CalendartwoWeek),
+    ("calendar2-week-fill", Bootstrap::CalendartwoWeekFill),
+    ("calendar2-x", Bootstrap::CalendartwoX),
+    ("calendar2-x-fill", Bootstrap::CalendartwoXFill),
+    ("calendar3", Bootstrap::Calendarthree),
+    ("calendar3-event", Bootstrap::CalendarthreeEvent),
+    ("calendar3-event-fill", Bootstrap::CalendarthreeEventFill),
+    ("calendar3-fill", Bootstrap::CalendarthreeFill),
+    ("calendar3-range", Bootstrap::CalendarthreeRange),
+    ("calendar3-range-fill", Bootstrap::CalendarthreeRangeFill),
+    ("calendar3-week", Bootstrap::CalendarthreeWeek),
+    ("calendar3-week-fill", Bootstrap::CalendarthreeWeekFill),
+    ("calendar4", Bootstrap::Calendarfour),
+    ("calendar4-event", Bootstrap::CalendarfourEvent),
+    ("calendar4-range", Bootstrap::CalendarfourRange),
+    ("calendar4-week", Bootstrap::CalendarfourWeek),
+    ("camera", Bootstrap::Camera),
+    ("camera-fill", Bootstrap::CameraFill),
+    ("camera-reels", Bootstrap::CameraReels),
+    ("camera-reels-fill", Bootstrap::CameraReelsFill),
+    ("camera-video", Bootstrap::CameraVideo),
+    ("camera-video-fill", Bootstrap::CameraVideoFill),
+    ("camera-video-off", Bootstrap::CameraVideoOff),
+    ("camera-video-off-fill", Bootstrap::CameraVideoOffFill),
+    ("camera2", Bootstrap::Cameratwo),
+    ("capslock", Bootstrap::Capslock),
+    ("capslock-fill", Bootstrap::CapslockFill),
+    ("capsule", Bootstrap::Capsule),
+    ("capsule-pill", Bootstrap::CapsulePill),
+    ("car-front", Bootstrap::CarFront),
+    ("car-front-fill", Bootstrap::CarFrontFill),
+    ("card-checklist", Bootstrap::CardChecklist),
+    ("card-heading", Bootstrap::CardHeading),
+    ("card-image", Bootstrap::CardImage),
+    ("card-list", Bootstrap::CardList),
+    ("card-text", Bootstrap::CardText),
+    ("caret-down", Bootstrap::CaretDown),
+    ("caret-down-fill", Bootstrap::CaretDownFill),
+    ("caret-down-square", Bootstrap::CaretDownSquare),
+    ("caret-down-square-fill", Bootstrap::CaretDownSquareFill),
+    ("caret-left", Bootstrap::CaretLeft),
+    ("caret-left-fill", Bootstrap::CaretLeftFill),
+    ("caret-left-square", Bootstrap::CaretLeftSquare),
+    ("caret-left-square-fill", Bootstrap::CaretLeftSquareFill),
+    ("caret-right", Bootstrap::CaretRight),
+    ("caret-right-fill", Bootstrap::CaretRightFill),
+    ("caret-right-square", Bootstrap::CaretRightSquare),
+    ("caret-right-square-fill", Bootstrap::CaretRightSquareFill),
+    ("caret-up", Bootstrap::CaretUp),
+    ("caret-up-fill", Bootstrap::CaretUpFill),
+    ("caret-up-square", Bootstrap::CaretUpSquare),
+    ("caret-up-square-fill", Bootstrap::CaretUpSquareFill),
+    ("cart", Bootstrap::Cart),
+    ("cart-check", Bootstrap::CartCheck),
+    ("cart-check-fill", Bootstrap::CartCheckFill),
+    ("cart-dash", Bootstrap::CartDash),
+    ("cart-dash-fill", Bootstrap::CartDashFill),
+    ("cart-fill", Bootstrap::CartFill),
+    ("cart-plus", Bootstrap::CartPlus),
+    ("cart-plus-fill", Bootstrap::CartPlusFill),
+    ("cart-x", Bootstrap::CartX),
+    ("cart-x-fill", Bootstrap::CartXFill),
+    ("cart2", Bootstrap::Carttwo),
+    ("cart3", Bootstrap::Cartthree),
+    ("cart4", Bootstrap::Cartfour),
+    ("cash", Bootstrap::Cash),
+    ("cash-coin", Bootstrap::CashCoin),
+    ("cash-stack", Bootstrap::CashStack),
+    ("cassette", Bootstrap::Cassette),
+    ("cassette-fill", Bootstrap::CassetteFill),
+    ("cast", Bootstrap::Cast),
+    ("cc-circle", Bootstrap::CcCircle),
+    ("cc-circle-fill", Bootstrap::CcCircleFill),
+    ("cc-square", Bootstrap::CcSquare),
+    ("cc-square-fill", Bootstrap::CcSquareFill),
+    ("chat", Bootstrap::Chat),
+    ("chat-dots", Bootstrap::ChatDots),
+    ("chat-dots-fill", Bootstrap::ChatDotsFill),
+    ("chat-fill", Bootstrap::ChatFill),
+    ("chat-heart", Bootstrap::ChatHeart),
+    ("chat-heart-fill", Bootstrap::ChatHeartFill),
+    ("chat-left", Bootstrap::ChatLeft),
+    ("chat-left-dots", Bootstrap::ChatLeftDots),
+    ("chat-left-dots-fill", Bootstrap::ChatLeftDotsFill),
+    ("chat-left-fill", Bootstrap::ChatLeftFill),
+    ("chat-left-heart", Bootstrap::ChatLeftHeart),
+    ("chat-left-heart-fill", Bootstrap::ChatLeftHeartFill),
+    ("chat-left-quote", Bootstrap::ChatLeftQuote),
+    ("chat-left-quote-fill", Bootstrap::ChatLeftQuoteFill),
+    ("chat-left-text", Bootstrap::ChatLeftText),
+    ("chat-left-text-fill", Bootstrap::ChatLeftTextFill),
+    ("chat-quote", Bootstrap::ChatQuote),
+    ("chat-quote-fill", Bootstrap::ChatQuoteFill),
+    ("chat-right", Bootstrap::ChatRight),
+    ("chat-right-dots", Bootstrap::ChatRightDots),
+    ("chat-right-dots-fill", Bootstrap::ChatRightDotsFill),
+    ("chat-right-fill", Bootstrap::ChatRightFill),
+    ("chat-right-heart", Bootstrap::ChatRightHeart),
+    ("chat-right-heart-fill", Bootstrap::ChatRightHeartFill),
+    ("chat-right-quote", Bootstrap::ChatRightQuote),
+    ("chat-right-quote-fill", Bootstrap::ChatRightQuoteFill),
+    ("chat-right-text", Bootstrap::ChatRightText),
+    ("chat-right-text-fill", Bootstrap::ChatRightTextFill),
+    ("chat-square", Bootstrap::ChatSquare),
+    ("chat-square-dots", Bootstrap::ChatSquareDots),
+    ("chat-square-dots-fill", Bootstrap::ChatSquareDotsFill),
+    ("chat-square-fill", Bootstrap::ChatSquareFill),
+    ("chat-square-heart", Bootstrap::ChatSquareHeart),
+    ("chat-square-heart-fill", Bootstrap::ChatSquareHeartFill),
+    ("chat-square-quote", Bootstrap::ChatSquareQuote),
+    ("chat-square-quote-fill", Bootstrap::ChatSquareQuoteFill),
+    ("chat-square-text", Bootstrap::ChatSquareText),
+    ("chat-square-text-fill", Bootstrap::ChatSquareTextFill),
+    ("chat-text", Bootstrap::ChatText),
+    ("chat-text-fill", Bootstrap::ChatTextFill),
+    ("check", Bootstrap::Check),
+    ("check-all", Bootstrap::CheckAll),
+    ("check-circle", Bootstrap::CheckCircle),
+    ("check-circle-fill", Bootstrap::CheckCircleFill),
+    ("check-lg", Bootstrap::CheckLg),
+    ("check-square", Bootstrap::CheckSquare),
+    ("check-square-fill", Bootstrap::CheckSquareFill),
+    ("check2", Bootstrap::Checktwo),
+    ("check2-all", Bootstrap::ChecktwoAll),
+    ("check2-circle", Bootstrap::ChecktwoCircle),
+    ("check2-square", Bootstrap::ChecktwoSquare),
+    ("chevron-bar-contract", Bootstrap::ChevronBarContract),
+    ("chevron-bar-down", Bootstrap::ChevronBarDown),
+    ("chevron-bar-expand", Bootstrap::ChevronBarExpand),
+    ("chevron-bar-left", Bootstrap::ChevronBarLeft),
+    ("chevron-bar-right", Bootstrap::ChevronBarRight),
+    ("chevron-bar-up", Bootstrap::ChevronBarUp),
+    ("chevron-compact-down", Bootstrap::ChevronCompactDown),
+    ("chevron-compact-left", Bootstrap::ChevronCompactLeft),
+    ("chevron-compact-right", Bootstrap::ChevronCompactRight),
+    ("chevron-compact-up", Bootstrap::ChevronCompactUp),
+    ("chevron-contract", Bootstrap::ChevronContract),
+    ("chevron-double-down", Bootstrap::ChevronDoubleDown),
+    ("chevron-double-left", Bootstrap::ChevronDoubleLeft),
+    ("chevron-double-right", Bootstrap::ChevronDoubleRight),
+    ("chevron-double-up", Bootstrap::ChevronDoubleUp),
+    ("chevron-down", Bootstrap::ChevronDown),
+    ("chevron-expand", Bootstrap::ChevronExpand),
+    ("chevron-left", Bootstrap::ChevronLeft),
+    ("chevron-right", Bootstrap::ChevronRight),
+    ("chevron-up", Bootstrap::ChevronUp),
+    ("circle", Bootstrap::Circle),
+    ("circle-fill", Bootstrap::CircleFill),
+    ("circle-half", Bootstrap::CircleHalf),
+    ("circle-square", Bootstrap::CircleSquare),
+    ("clipboard", Bootstrap::Clipboard),
+    ("clipboard-check", Bootstrap::ClipboardCheck),
+    ("clipboard-check-fill", Bootstrap::ClipboardCheckFill),
+    ("clipboard-data", Bootstrap::ClipboardData),
+    ("clipboard-data-fill", Bootstrap::ClipboardDataFill),
+    ("clipboard-fill", Bootstrap::ClipboardFill),
+    ("clipboard-heart", Bootstrap::ClipboardHeart),
+    ("clipboard-heart-fill", Bootstrap::ClipboardHeartFill),
+    ("clipboard-minus", Bootstrap::ClipboardMinus),
+    ("clipboard-minus-fill", Bootstrap::ClipboardMinusFill),
+    ("clipboard-plus", Bootstrap::ClipboardPlus),
+    ("clipboard-plus-fill", Bootstrap::ClipboardPlusFill),
+    ("clipboard-pulse", Bootstrap::ClipboardPulse),
+    ("clipboard-x", Bootstrap::ClipboardX),
+    ("clipboard-x-fill", Bootstrap::ClipboardXFill),
+    ("clipboard2", Bootstrap::Clipboardtwo),
+    ("clipboard2-check", Bootstrap::ClipboardtwoCheck),
+    ("clipboard2-check-fill", Bootstrap::ClipboardtwoCheckFill),
+    ("clipboard2-data", Bootstrap::ClipboardtwoData),
+    ("clipboard2-data-fill", Bootstrap::ClipboardtwoDataFill),
+    ("clipboard2-fill", Bootstrap::ClipboardtwoFill),
+    ("clipboard2-heart", Bootstrap::ClipboardtwoHeart),
+    ("clipboard2-heart-fill", Bootstrap::ClipboardtwoHeartFill),
+    ("clipboard2-minus", Bootstrap::ClipboardtwoMinus),
+    ("clipboard2-minus-fill", Bootstrap::ClipboardtwoMinusFill),
+    ("clipboard2-plus", Bootstrap::ClipboardtwoPlus),
+    ("clipboard2-plus-fill", Bootstrap::ClipboardtwoPlusFill),
+    ("clipboard2-pulse", Bootstrap::ClipboardtwoPulse),
+    ("clipboard2-pulse-fill", Bootstrap::ClipboardtwoPulseFill),
+    ("clipboard2-x", Bootstrap::ClipboardtwoX),
+    ("clipboard2-x-fill", Bootstrap::ClipboardtwoXFill),
+    ("clock", Bootstrap::Clock),
+    ("clock-fill", Bootstrap::ClockFill),
+    ("clock-history", Bootstrap::ClockHistory),
+    ("cloud", Bootstrap::Cloud),
+    ("cloud-arrow-down", Bootstrap::CloudArrowDown),
+    ("cloud-arrow-down-fill", Bootstrap::CloudArrowDownFill),
+    ("cloud-arrow-up", Bootstrap::CloudArrowUp),
+    ("cloud-arrow-up-fill", Bootstrap::CloudArrowUpFill),
+    ("cloud-check", Bootstrap::CloudCheck),
+    ("cloud-check-fill", Bootstrap::CloudCheckFill),
+    ("cloud-download", Bootstrap::CloudDownload),
+    ("cloud-download-fill", Bootstrap::CloudDownloadFill),
+    ("cloud-drizzle", Bootstrap::CloudDrizzle),
+    ("cloud-drizzle-fill", Bootstrap::CloudDrizzleFill),
+    ("cloud-fill", Bootstrap::CloudFill),
+    ("cloud-fog", Bootstrap::CloudFog),
+    ("cloud-fog-fill", Bootstrap::CloudFogFill),
+    ("cloud-fog2", Bootstrap::CloudFogtwo),
+    ("cloud-fog2-fill", Bootstrap::CloudFogtwoFill),
+    ("cloud-hail", Bootstrap::CloudHail),
+    ("cloud-hail-fill", Bootstrap::CloudHailFill),
+    ("cloud-haze", Bootstrap::CloudHaze),
+    ("cloud-haze-fill", Bootstrap::CloudHazeFill),
+    ("cloud-haze2", Bootstrap::CloudHazetwo),
+    ("cloud-haze2-fill", Bootstrap::CloudHazetwoFill),
+    ("cloud-lightning", Bootstrap::CloudLightning),
+    ("cloud-lightning-fill", Bootstrap::CloudLightningFill),
+    ("cloud-lightning-rain", Bootstrap::CloudLightningRain),
+    (
+        "cloud-lightning-rain-fill",
+        Bootstrap::CloudLightningRainFill,
+    ),
+    ("cloud-minus", Bootstrap::CloudMinus),
+    ("cloud-minus-fill", Bootstrap::CloudMinusFill),
+    ("cloud-moon", Bootstrap::CloudMoon),
+    ("cloud-moon-fill", Bootstrap::CloudMoonFill),
+    ("cloud-plus", Bootstrap::CloudPlus),
+    ("cloud-plus-fill", Bootstrap::CloudPlusFill),
+    ("cloud-rain", Bootstrap::CloudRain),
+    ("cloud-rain-fill", Bootstrap::CloudRainFill),
+    ("cloud-rain-heavy", Bootstrap::CloudRainHeavy),
+    ("cloud-rain-heavy-fill", Bootstrap::CloudRainHeavyFill),
+    ("cloud-slash", Bootstrap::CloudSlash),
+    ("cloud-slash-fill", Bootstrap::CloudSlashFill),
+    ("cloud-sleet", Bootstrap::CloudSleet),
+    ("cloud-sleet-fill", Bootstrap::CloudSleetFill),
+    ("cloud-snow", Bootstrap::CloudSnow),
+    ("cloud-snow-fill", Bootstrap::CloudSnowFill),
+    ("cloud-sun", Bootstrap::CloudSun),
+    ("cloud-sun-fill", Bootstrap::CloudSunFill),
+    ("cloud-upload", Bootstrap::CloudUpload),
+    ("cloud-upload-fill", Bootstrap::CloudUploadFill),
+    ("clouds", Bootstrap::Clouds),
+    ("clouds-fill", Bootstrap::CloudsFill),
+    ("cloudy", Bootstrap::Cloudy),
+    ("cloudy-fill", Bootstrap::CloudyFill),
+    ("code", Bootstrap::Code),
+    ("code-slash", Bootstrap::CodeSlash),
+    ("code-square", Bootstrap::CodeSquare),
+    ("coin", Bootstrap::Coin),
+    ("collection", Bootstrap::Collection),
+    ("collection-fill", Bootstrap::CollectionFill),
+    ("collection-play", Bootstrap::CollectionPlay),
+    ("collection-play-fill", Bootstrap::CollectionPlayFill),
+    ("columns", Bootstrap::Columns),
+    ("columns-gap", Bootstrap::ColumnsGap),
+    ("command", Bootstrap::Command),
+    ("compass", Bootstrap::Compass),
+    ("compass-fill", Bootstrap::CompassFill),
+    ("cone", Bootstrap::Cone),
+    ("cone-striped", Bootstrap::ConeStriped),
+    ("controller", Bootstrap::Controller),
+    ("cookie", Bootstrap::Cookie),
+    ("copy", Bootstrap::Copy),
+    ("cpu", Bootstrap::Cpu),
+    ("cpu-fill", Bootstrap::CpuFill),
+    ("credit-card", Bootstrap::CreditCard),
+    ("credit-card-2-back", Bootstrap::CreditCardTwoBack),
+    ("credit-card-2-back-fill", Bootstrap::CreditCardTwoBackFill),
+    ("credit-card-2-front", Bootstrap::CreditCardTwoFront),
+    (
+        "credit-card-2-front-fill",
+        Bootstrap::CreditCardTwoFrontFill,
+    ),
+    ("credit-card-fill", Bootstrap::CreditCardFill),
+    ("crop", Bootstrap::Crop),
+    ("crosshair", Bootstrap::Crosshair),
+    ("crosshair2", Bootstrap::Crosshairtwo),
+    ("cup", Bootstrap::Cup),
+    ("cup-fill", Bootstrap::CupFill),
+    ("cup-hot", Bootstrap::CupHot),
+    ("cup-hot-fill", Bootstrap::CupHotFill),
+    ("cup-straw", Bootstrap::CupStraw),
+    ("currency-bitcoin", Bootstrap::CurrencyBitcoin),
+    ("currency-dollar", Bootstrap::CurrencyDollar),
+    ("currency-euro", Bootstrap::CurrencyEuro),
+    ("currency-exchange", Bootstrap::CurrencyExchange),
+    ("currency-pound", Bootstrap::CurrencyPound),
+    ("currency-rupee", Bootstrap::CurrencyRupee),
+    ("currency-yen", Bootstrap::CurrencyYen),
+    ("cursor", Bootstrap::Cursor),
+    ("cursor-fill", Bootstrap::CursorFill),
+    ("cursor-text", Bootstrap::CursorText),
+    ("dash", Bootstrap::Dash),
+    ("dash-circle", Bootstrap::DashCircle),
+    ("dash-circle-dotted", Bootstrap::DashCircleDotted),
+    ("dash-circle-fill", Bootstrap::DashCircleFill),
+    ("dash-lg", Bootstrap::DashLg),
+    ("dash-square", Bootstrap::DashSquare),
+    ("dash-square-dotted", Bootstrap::DashSquareDotted),
+    ("dash-square-fill", Bootstrap::DashSquareFill),
+    ("database", Bootstrap::Database),
+    ("database-add", Bootstrap::DatabaseAdd),
+    ("database-check", Bootstrap::DatabaseCheck),
+    ("database-dash", Bootstrap::DatabaseDash),
+    ("database-down", Bootstrap::DatabaseDown),
+    ("database-exclamation", Bootstrap::DatabaseExclamation),
+    ("database-fill", Bootstrap::DatabaseFill),
+    ("database-fill-add", Bootstrap::DatabaseFillAdd),
+    ("database-fill-check", Bootstrap::DatabaseFillCheck),
+    ("database-fill-dash", Bootstrap::DatabaseFillDash),
+    ("database-fill-down", Bootstrap::DatabaseFillDown),
+    (
+        "database-fill-exclamation",
+        Bootstrap::DatabaseFillExclamation,
+    ),
+    ("database-fill-gear", Bootstrap::DatabaseFillGear),
+    ("database-fill-lock", Bootstrap::DatabaseFillLock),
+    ("database-fill-slash", Bootstrap::DatabaseFillSlash),
+    ("database-fill-up", Bootstrap::DatabaseFillUp),
+    ("database-fill-x", Bootstrap::DatabaseFillX),
+    ("database-gear", Bootstrap::DatabaseGear),
+    ("database-lock", Bootstrap::DatabaseLock),
+    ("database-slash", Bootstrap::DatabaseSlash),
+    ("database-up", Bootstrap::DatabaseUp),
+    ("database-x", Bootstrap::DatabaseX),
+    ("device-hdd", Bootstrap::DeviceHdd),
+    ("device-hdd-fill", Bootstrap::DeviceHddFill),
+    ("device-ssd", Bootstrap::DeviceSsd),
+    ("device-ssd-fill", Bootstrap::DeviceSsdFill),
+    ("diagram-2", Bootstrap::DiagramTwo),
+    ("diagram-2-fill", Bootstrap::DiagramTwoFill),
+    ("diagram-3", Bootstrap::DiagramThree),
+    ("diagram-3-fill", Bootstrap::DiagramThreeFill),
+    ("diamond", Bootstrap::Diamond),
+    ("diamond-fill", Bootstrap::DiamondFill),
+    ("diamond-half", Bootstrap::DiamondHalf),
+    ("dice-1", Bootstrap::DiceOne),
+    ("dice-1-fill", Bootstrap::DiceOneFill),
+    ("dice-2", Bootstrap::DiceTwo),
+    ("dice-2-fill", Bootstrap::DiceTwoFill),
+    ("dice-3", Bootstrap::DiceThree),
+    ("dice-3-fill", Bootstrap::DiceThreeFill),
+    ("dice-4", Bootstrap::DiceFour),
+    ("dice-4-fill", Bootstrap::DiceFourFill),
+    ("dice-5", Bootstrap::DiceFive),
+    ("dice-5-fill", Bootstrap::DiceFiveFill),
+    ("dice-6", Bootstrap::DiceSix),
+    ("dice-6-fill", Bootstrap::DiceSixFill),
+    ("disc", Bootstrap::Disc),
+    ("disc-fill", Bootstrap::DiscFill),
+    ("discord", Bootstrap::Discord),
+    ("display", Bootstrap::Display),
+    ("display-fill", Bootstrap::DisplayFill),
+    ("displayport", Bootstrap::Displayport),
+    ("displayport-fill", Bootstrap::DisplayportFill),
+    ("distribute-horizontal", Bootstrap::DistributeHorizontal),
+    ("distribute-vertical", Bootstrap::DistributeVertical),
+    ("door-closed", Bootstrap::DoorClosed),
+    ("door-closed-fill", Bootstrap::DoorClosedFill),
+    ("door-open", Bootstrap::DoorOpen),
+    ("door-open-fill", Bootstrap::DoorOpenFill),
+    ("dot", Bootstrap::Dot),
+    ("download", Bootstrap::Download),
+    ("dpad", Bootstrap::Dpad),
+    ("dpad-fill", Bootstrap::DpadFill),
+    ("dribbble", Bootstrap::Dribbble),
+    ("dropbox", Bootstrap::Dropbox),
+    ("droplet", Bootstrap::Droplet),
+    ("droplet-fill", Bootstrap::DropletFill),
+    ("droplet-half", Bootstrap::DropletHalf),
+    ("duffle", Bootstrap::Duffle),
+    ("duffle-fill", Bootstrap::DuffleFill),
+    ("ear", Bootstrap::Ear),
+    ("ear-fill", Bootstrap::EarFill),
+    ("earbuds", Bootstrap::Earbuds),
+    ("easel", Bootstrap::Easel),
+    ("easel-fill", Bootstrap::EaselFill),
+    ("easel2", Bootstrap::Easeltwo),
+    ("easel2-fill", Bootstrap::EaseltwoFill),
+    ("easel3", Bootstrap::Easelthree),
+    ("easel3-fill", Bootstrap::EaselthreeFill),
+    ("egg", Bootstrap::Egg),
+    ("egg-fill", Bootstrap::EggFill),
+    ("egg-fried", Bootstrap::EggFried),
+    ("eject", Bootstrap::Eject),
+    ("eject-fill", Bootstrap::EjectFill),
+    ("emoji-angry", Bootstrap::EmojiAngry),
+    ("emoji-angry-fill", Bootstrap::EmojiAngryFill),
+    ("emoji-astonished", Bootstrap::EmojiAstonished),
+    ("emoji-astonished-fill", Bootstrap::EmojiAstonishedFill),
+    ("emoji-dizzy", Bootstrap::EmojiDizzy),
+    ("emoji-dizzy-fill", Bootstrap::EmojiDizzyFill),
+    ("emoji-expressionless", Bootstrap::EmojiExpressionless),
+    (
+        "emoji-expressionless-fill",
+        Bootstrap::EmojiExpressionlessFill,
+    ),
+    ("emoji-frown", Bootstrap::EmojiFrown),
+    ("emoji-frown-fill", Bootstrap::EmojiFrownFill),
+    ("emoji-grimace", Bootstrap::EmojiGrimace),
+    ("emoji-grimace-fill", Bootstrap::EmojiGrimaceFill),
+    ("emoji-grin", Bootstrap::EmojiGrin),
+    ("emoji-grin-fill", Bootstrap::EmojiGrinFill),
+    ("emoji-heart-eyes", Bootstrap::EmojiHeartEyes),
+    ("emoji-heart-eyes-fill", Bootstrap::EmojiHeartEyesFill),
+    ("emoji-kiss", Bootstrap::EmojiKiss),
+    ("emoji-kiss-fill", Bootstrap::EmojiKissFill),
+    ("emoji-laughing", Bootstrap::EmojiLaughing),
+    ("emoji-laughing-fill", Bootstrap::EmojiLaughingFill),
+    ("emoji-neutral", Bootstrap::EmojiNeutral),
+    ("emoji-neutral-fill", Bootstrap::EmojiNeutralFill),
+    ("emoji-smile", Bootstrap::EmojiSmile),
+    ("emoji-smile-fill", Bootstrap::EmojiSmileFill),
+    ("emoji-smile-upside-down", Bootstrap::EmojiSmileUpsideDown),
+    (
+        "emoji-smile-upside-down-fill",
+        Bootstrap::EmojiSmileUpsideDownFill,
+    ),
+    ("emoji-sunglasses", Bootstrap::EmojiSunglasses),
+    ("emoji-sunglasses-fill", Bootstrap::EmojiSunglassesFill),
+    ("emoji-surprise", Bootstrap::EmojiSurprise),
+    ("emoji-surprise-fill", Bootstrap::EmojiSurpriseFill),
+    ("emoji-tear", Bootstrap::EmojiTear),
+    ("emoji-tear-fill", Bootstrap::EmojiTearFill),
+    ("emoji-wink", Bootstrap::EmojiWink),
+    ("emoji-wink-fill", Bootstrap::EmojiWinkFill),
+    ("envelope", Bootstrap::Envelope),
+    ("envelope-arrow-down", Bootstrap::EnvelopeArrowDown),
+    ("envelope-arrow-down-fill", Bootstrap::EnvelopeArrowDownFill),
+    ("envelope-arrow-up", Bootstrap::EnvelopeArrowUp),
+    ("envelope-arrow-up-fill", Bootstrap::EnvelopeArrowUpFill),
+    ("envelope-at", Bootstrap::EnvelopeAt),
+    ("envelope-at-fill", Bootstrap::EnvelopeAtFill),
+    ("envelope-check", Bootstrap::EnvelopeCheck),
+    ("envelope-check-fill", Bootstrap::EnvelopeCheckFill),
+    ("envelope-dash", Bootstrap::EnvelopeDash),
+    ("envelope-dash-fill", Bootstrap::EnvelopeDashFill),
+    ("envelope-exclamation", Bootstrap::EnvelopeExclamation),
+    (
+        "envelope-exclamation-fill",
+        Bootstrap::EnvelopeExclamationFill,
+    ),
+    ("envelope-fill", Bootstrap::EnvelopeFill),
+    ("envelope-heart", Bootstrap::EnvelopeHeart),
+    ("envelope-heart-fill", Bootstrap::EnvelopeHeartFill),
+    ("envelope-open", Bootstrap::EnvelopeOpen),
+    ("envelope-open-fill", Bootstrap::EnvelopeOpenFill),
+    ("envelope-open-heart", Bootstrap::EnvelopeOpenHeart),
+    ("envelope-open-heart-fill", Bootstrap::EnvelopeOpenHeartFill),
+    ("envelope-paper", Bootstrap::EnvelopePaper),
+    ("envelope-paper-fill", Bootstrap::EnvelopePaperFill),
+    ("envelope-paper-heart", Bootstrap::EnvelopePaperHeart),
+    (
+        "envelope-paper-heart-fill",
+        Bootstrap::EnvelopePaperHeartFill,
+    ),
+    ("envelope-plus", Bootstrap::EnvelopePlus),
+    ("envelope-plus-fill", Bootstrap::EnvelopePlusFill),
+    ("envelope-slash", Bootstrap::EnvelopeSlash),
+    ("envelope-slash-fill", Bootstrap::EnvelopeSlashFill),
+    ("envelope-x", Bootstrap::EnvelopeX),
+    ("envelope-x-fill", Bootstrap::EnvelopeXFill),
+    ("eraser", Bootstrap::Eraser),
+    ("eraser-fill", Bootstrap::EraserFill),
+    ("escape", Bootstrap::Escape),
+    ("ethernet", Bootstrap::Ethernet),
+    ("ev-front", Bootstrap::EvFront),
+    ("ev-front-fill", Bootstrap::EvFrontFill),
+    ("ev-station", Bootstrap::EvStation),
+    ("ev-station-fill", Bootstrap::EvStationFill),
+    ("exclamation", Bootstrap::Exclamation),
+    ("exclamation-circle", Bootstrap::ExclamationCircle),
+    ("exclamation-circle-fill", Bootstrap::ExclamationCircleFill),
+    ("exclamation-diamond", Bootstrap::ExclamationDiamond),
+    (
+        "exclamation-diamond-fill",
+        Bootstrap::ExclamationDiamondFill,
+    ),
+    ("exclamation-lg", Bootstrap::ExclamationLg),
+    ("exclamation-octagon", Bootstrap::ExclamationOctagon),
+    (
+        "exclamation-octagon-fill",
+        Bootstrap::ExclamationOctagonFill,
+    ),
+    ("exclamation-square", Bootstrap::ExclamationSquare),
+    ("exclamation-square-fill", Bootstrap::ExclamationSquareFill),
+    ("exclamation-triangle", Bootstrap::ExclamationTriangle),
+    (
+        "exclamation-triangle-fill",
+        Bootstrap::ExclamationTriangleFill,
+    ),
+    ("exclude", Bootstrap::Exclude),
+    ("explicit", Bootstrap::Explicit),
+    ("explicit-fill", Bootstrap::ExplicitFill),
+    ("exposure", Bootstrap::Exposure),
+    ("eye", Bootstrap::Eye),
+    ("eye-fill", Bootstrap::EyeFill),
+    ("eye-slash", Bootstrap::EyeSlash),
+    ("eye-slash-fill", Bootstrap::EyeSlashFill),
+    ("eyedropper", Bootstrap::Eyedropper),
+    ("eyeglasses", Bootstrap::Eyeglasses),
+    ("facebook", Bootstrap::Facebook),
+    ("fan", Bootstrap::Fan),
+    ("fast-forward", Bootstrap::FastForward),
+    ("fast-forward-btn", Bootstrap::FastForwardBtn),
+    ("fast-forward-btn-fill", Bootstrap::FastForwardBtnFill),
+    ("fast-forward-circle", Bootstrap::FastForwardCircle),
+    ("fast-forward-circle-fill", Bootstrap::FastForwardCircleFill),
+    ("fast-forward-fill", Bootstrap::FastForwardFill),
+    ("feather", Bootstrap::Feather),
+    ("feather2", Bootstrap::Feathertwo),
+    ("file", Bootstrap::File),
+    ("file-arrow-down", Bootstrap::FileArrowDown),
+    ("file-arrow-down-fill", Bootstrap::FileArrowDownFill),
+    ("file-arrow-up", Bootstrap::FileArrowUp),
+    ("file-arrow-up-fill", Bootstrap::FileArrowUpFill),
+    ("file-bar-graph", Bootstrap::FileBarGraph),
+    ("file-bar-graph-fill", Bootstrap::FileBarGraphFill),
+    ("file-binary", Bootstrap::FileBinary),
+    ("file-binary-fill", Bootstrap::FileBinaryFill),
+    ("file-break", Bootstrap::FileBreak),
+    ("file-break-fill", Bootstrap::FileBreakFill),
+    ("file-check", Bootstrap::FileCheck),
+    ("file-check-fill", Bootstrap::FileCheckFill),
+    ("file-code", Bootstrap::FileCode),
+    ("file-code-fill", Bootstrap::FileCodeFill),
+    ("file-diff", Bootstrap::FileDiff),
+    ("file-diff-fill", Bootstrap::FileDiffFill),
+    ("file-earmark", Bootstrap::FileEarmark),
+    ("file-earmark-arrow-down", Bootstrap::FileEarmarkArrowDown),
+    (
+        "file-earmark-arrow-down-fill",
+        Bootstrap::FileEarmarkArrowDownFill,
+    ),
+    ("file-earmark-arrow-up", Bootstrap::FileEarmarkArrowUp),
+    (
+        "file-earmark-arrow-up-fill",
+        Bootstrap::FileEarmarkArrowUpFill,
+    ),
+    ("file-earmark-bar-graph", Bootstrap::FileEarmarkBarGraph),
+    (
+        "file-earmark-bar-graph-fill",
+        Bootstrap::FileEarmarkBarGraphFill,
+    ),
+    ("file-earmark-binary", Bootstrap::FileEarmarkBinary),
+    ("file-earmark-binary-fill", Bootstrap::FileEarmarkBinaryFill),
+    ("file-earmark-break", Bootstrap::FileEarmarkBreak),
+    ("file-earmark-break-fill", Bootstrap::FileEarmarkBreakFill),
+    ("file-earmark-check", Bootstrap::FileEarmarkCheck),
+    ("file-earmark-check-fill", Bootstrap::FileEarmarkCheckFill),
+    ("file-earmark-code", Bootstrap::FileEarmarkCode),
+    ("file-earmark-code-fill", Bootstrap::FileEarmarkCodeFill),
+    ("file-earmark-diff", Bootstrap::FileEarmarkDiff),
+    ("file-earmark-diff-fill", Bootstrap::FileEarmarkDiffFill),
+    ("file-earmark-easel", Bootstrap::FileEarmarkEasel),
+    ("file-earmark-easel-fill", Bootstrap::FileEarmarkEaselFill),
+    ("file-earmark-excel", Bootstrap::FileEarmarkExcel),
+    ("file-earmark-excel-fill", Bootstrap::FileEarmarkExcelFill),
+    ("file-earmark-fill", Bootstrap::FileEarmarkFill),
+    ("file-earmark-font", Bootstrap::FileEarmarkFont),
+    ("file-earmark-font-fill", Bootstrap::FileEarmarkFontFill),
+    ("file-earmark-image", Bootstrap::FileEarmarkImage),
+    ("file-earmark-image-fill", Bootstrap::FileEarmarkImageFill),
+    ("file-earmark-lock", Bootstrap::FileEarmarkLock),
+    ("file-earmark-lock-fill", Bootstrap::FileEarmarkLockFill),
+    ("file-earmark-lock2", Bootstrap::FileEarmarkLocktwo),
+    ("file-earmark-lock2-fill", Bootstrap::FileEarmarkLocktwoFill),
+    ("file-earmark-medical", Bootstrap::FileEarmarkMedical),
+    (
+        "file-earmark-medical-fill",
+        Bootstrap::FileEarmarkMedicalFill,
+    ),
+    ("file-earmark-minus", Bootstrap::FileEarmarkMinus),
+    ("file-earmark-minus-fill", Bootstrap::FileEarmarkMinusFill),
+    ("file-earmark-music", Bootstrap::FileEarmarkMusic),
+    ("file-earmark-music-fill", Bootstrap::FileEarmarkMusicFill),
+    ("file-earmark-pdf", Bootstrap::FileEarmarkPdf),
+    ("file-earmark-pdf-fill", Bootstrap::FileEarmarkPdfFill),
+    ("file-earmark-person", Bootstrap::FileEarmarkPerson),
+    ("file-earmark-person-fill", Bootstrap::FileEarmarkPersonFill),
+    ("file-earmark-play", Bootstrap::FileEarmarkPlay),
+    ("file-earmark-play-fill", Bootstrap::FileEarmarkPlayFill),
+    ("file-earmark-plus", Bootstrap::FileEarmarkPlus),
+    ("file-earmark-plus-fill", Bootstrap::FileEarmarkPlusFill),
+    ("file-earmark-post", Bootstrap::FileEarmarkPost),
+    ("file-earmark-post-fill", Bootstrap::FileEarmarkPostFill),
+    ("file-earmark-ppt", Bootstrap::FileEarmarkPpt),
+    ("file-earmark-ppt-fill", Bootstrap::FileEarmarkPptFill),
+    ("file-earmark-richtext", Bootstrap::FileEarmarkRichtext),
+    (
+        "file-earmark-richtext-fill",
+        Bootstrap::FileEarmarkRichtextFill,
+    ),
+    ("file-earmark-ruled", Bootstrap::FileEarmarkRuled),
+    ("file-earmark-ruled-fill", Bootstrap::FileEarmarkRuledFill),
+    ("file-earmark-slides", Bootstrap::FileEarmarkSlides),
+    ("file-earmark-slides-fill", Bootstrap::FileEarmarkSlidesFill),
+    (
+        "file-earmark-spreadsheet",
+        Bootstrap::FileEarmarkSpreadsheet,
+    ),
+    (
+        "file-earmark-spreadsheet-fill",
+        Bootstrap::FileEarmarkSpreadsheetFill,
+    ),
+    ("file-earmark-text", Bootstrap::FileEarmarkText),
+    ("file-earmark-text-fill", Bootstrap::FileEarmarkTextFill),
+    ("file-earmark-word", Bootstrap::FileEarmarkWord),
+    ("file-earmark-word-fill", Bootstrap::FileEarmarkWordFill),
+    ("file-earmark-x", Bootstrap::FileEarmarkX),
+    ("file-earmark-x-fill", Bootstrap::FileEarmarkXFill),
+    ("file-earmark-zip", Bootstrap::FileEarmarkZip),
+    ("file-earmark-zip-fill", Bootstrap::FileEarmarkZipFill),
+    ("file-easel", Bootstrap::FileEasel),
+    ("file-easel-fill", Bootstrap::FileEaselFill),
+    ("file-excel", Bootstrap::FileExcel),
+    ("file-excel-fill", Bootstrap::FileExcelFill),
+    ("file-fill", Bootstrap::FileFill),
+    ("file-font", Bootstrap::FileFont),
+    ("file-font-fill", Bootstrap::FileFontFill),
+    ("file-image", Bootstrap::FileImage),
+    ("file-image-fill", Bootstrap::FileImageFill),
+    ("file-lock", Bootstrap::FileLock),
+    ("file-lock-fill", Bootstrap::FileLockFill),
+    ("file-lock2", Bootstrap::FileLocktwo),
+    ("file-lock2-fill", Bootstrap::FileLocktwoFill),
+    ("file-medical", Bootstrap::FileMedical),
+    ("file-medical-fill", Bootstrap::FileMedicalFill),
+    ("file-minus", Bootstrap::FileMinus),
+    ("file-minus-fill", Bootstrap::FileMinusFill),
+    ("file-music", Bootstrap::FileMusic),
+    ("file-music-fill", Bootstrap::FileMusicFill),
+    ("file-pdf", Bootstrap::FilePdf),
+    ("file-pdf-fill", Bootstrap::FilePdfFill),
+    ("file-person", Bootstrap::FilePerson),
+    ("file-person-fill", Bootstrap::FilePersonFill),
+    ("file-play", Bootstrap::FilePlay),
+    ("file-play-fill", Bootstrap::FilePlayFill),
+    ("file-plus", Bootstrap::FilePlus),
+    ("file-plus-fill", Bootstrap::FilePlusFill),
+    ("file-post", Bootstrap::FilePost),
+    ("file-post-fill", Bootstrap::FilePostFill),
+    ("file-ppt", Bootstrap::FilePpt),
+    ("file-ppt-fill", Bootstrap::FilePptFill),
+    ("file-richtext", Bootstrap::FileRichtext),
+    ("file-richtext-fill", Bootstrap::FileRichtextFill),
+    ("file-ruled", Bootstrap::FileRuled),
+    ("file-ruled-fill", Bootstrap::FileRuledFill),
+    ("file-slides", Bootstrap::FileSlides),
+    ("file-slides-fill", Bootstrap::FileSlidesFill),
+    ("file-spreadsheet", Bootstrap::FileSpreadsheet),
+    ("file-spreadsheet-fill", Bootstrap::FileSpreadsheetFill),
+    ("file-text", Bootstrap::FileText),
+    ("file-text-fill", Bootstrap::FileTextFill),
+    ("file-word", Bootstrap::FileWord),
+    ("file-word-fill", Bootstrap::FileWordFill),
+    ("file-x", Bootstrap::FileX),
+    ("file-x-fill", Bootstrap::FileXFill),
+    ("file-zip", Bootstrap::FileZip),
+    ("file-zip-fill", Bootstrap::FileZipFill),
+    ("files", Bootstrap::Files),
+    ("files-alt", Bootstrap::FilesAlt),
+    ("filetype-aac", Bootstrap::FiletypeAac),
+    ("filetype-ai", Bootstrap::FiletypeAi),
+    ("filetype-bmp", Bootstrap::FiletypeBmp),
+    ("filetype-cs", Bootstrap::FiletypeCs),
+    ("filetype-css", Bootstrap::FiletypeCss),
+    ("filetype-csv", Bootstrap::FiletypeCsv),
+    ("filetype-doc", Bootstrap::FiletypeDoc),
+    ("filetype-docx", Bootstrap::FiletypeDocx),
+    ("filetype-exe", Bootstrap::FiletypeExe),
+    ("filetype-gif", Bootstrap::FiletypeGif),
+    ("filetype-heic", Bootstrap::FiletypeHeic),
+    ("filetype-html", Bootstrap::FiletypeHtml),
+    ("filetype-java", Bootstrap::FiletypeJava),
+    ("filetype-jpg", Bootstrap::FiletypeJpg),
+    ("filetype-js", Bootstrap::FiletypeJs),
+    ("filetype-json", Bootstrap::FiletypeJson),
+    ("filetype-jsx", Bootstrap::FiletypeJsx),
+    ("filetype-key", Bootstrap::FiletypeKey),
+    ("filetype-m4p", Bootstrap::FiletypeMfourp),
+    ("filetype-md", Bootstrap::FiletypeMd),
+    ("filetype-mdx", Bootstrap::FiletypeMdx),
+    ("filetype-mov", Bootstrap::FiletypeMov),
+    ("filetype-mp3", Bootstrap::FiletypeMpthree),
+    ("filetype-mp4", Bootstrap::FiletypeMpfour),
+    ("filetype-otf", Bootstrap::FiletypeOtf),
+    ("filetype-pdf", Bootstrap::FiletypePdf),
+    ("filetype-php", Bootstrap::FiletypePhp),
+    ("filetype-png", Bootstrap::FiletypePng),
+    ("filetype-ppt", Bootstrap::FiletypePpt),
+    ("filetype-pptx", Bootstrap::FiletypePptx),
+    ("filetype-psd", Bootstrap::FiletypePsd),
+    ("filetype-py", Bootstrap::FiletypePy),
+    ("filetype-raw", Bootstrap::FiletypeRaw),
+    ("filetype-rb", Bootstrap::FiletypeRb),
+    ("filetype-sass", Bootstrap::FiletypeSass),
+    ("filetype-scss", Bootstrap::FiletypeScss),
+    ("filetype-sh", Bootstrap::FiletypeSh),
+    ("filetype-sql", Bootstrap::FiletypeSql),
+    ("filetype-svg", Bootstrap::FiletypeSvg),
+    ("filetype-tiff", Bootstrap::FiletypeTiff),
+    ("filetype-tsx", Bootstrap::FiletypeTsx),
+    ("filetype-ttf", Bootstrap::FiletypeTtf),
+    ("filetype-txt", Bootstrap::FiletypeTxt),
+    ("filetype-wav", Bootstrap::FiletypeWav),
+    ("filetype-woff", Bootstrap::FiletypeWoff),
+    ("filetype-xls", Bootstrap::FiletypeXls),
+    ("filetype-xlsx", Bootstrap::FiletypeXlsx),
+    ("filetype-xml", Bootstrap::FiletypeXml),
+    ("filetype-yml", Bootstrap::FiletypeYml),
+    ("film", Bootstrap::Film),
+    ("filter", Bootstrap::Filter),
+    ("filter-circle", Bootstrap::FilterCircle),
+    ("filter-circle-fill", Bootstrap::FilterCircleFill),
+    ("filter-left", Bootstrap::FilterLeft),
+    ("filter-right", Bootstrap::FilterRight),
+    ("filter-square", Bootstrap::FilterSquare),
+    ("filter-square-fill", Bootstrap::FilterSquareFill),
+    ("fingerprint", Bootstrap::Fingerprint),
+    ("fire", Bootstrap::Fire),
+    ("flag", Bootstrap::Flag),
+    ("flag-fill", Bootstrap::FlagFill),
+    ("floppy", Bootstrap::Floppy),
+    ("floppy-fill", Bootstrap::FloppyFill),
+    ("floppy2", Bootstrap::Floppytwo),
+    ("floppy2-fill", Bootstrap::FloppytwoFill),
+    ("flower1", Bootstrap::Flowerone),
+    ("flower2", Bootstrap::Flowertwo),
+    ("flower3", Bootstrap::Flowerthree),
+    ("folder", Bootstrap::Folder),
+    ("folder-check", Bootstrap::FolderCheck),
+    ("folder-fill", Bootstrap::FolderFill),
+    ("folder-minus", Bootstrap::FolderMinus),
+    ("folder-plus", Bootstrap::FolderPlus),
+    ("folder-symlink", Bootstrap::FolderSymlink),
+    ("folder-symlink-fill", Bootstrap::FolderSymlinkFill),
+    ("folder-x", Bootstrap::FolderX),
+    ("folder2", Bootstrap::Foldertwo),
+    ("folder2-open", Bootstrap::FoldertwoOpen),
+    ("fonts", Bootstrap::Fonts),
+    ("forward", Bootstrap::Forward),
+    ("forward-fill", Bootstrap::ForwardFill),
+    ("front", Bootstrap::Front),
+    ("fuel-pump", Bootstrap::FuelPump),
+    ("fuel-pump-diesel", Bootstrap::FuelPumpDiesel),
+    ("fuel-pump-diesel-fill", Bootstrap::FuelPumpDieselFill),
+    ("fuel-pump-fill", Bootstrap::FuelPumpFill),
+    ("fullscreen", Bootstrap::Fullscreen),
+    ("fullscreen-exit", Bootstrap::FullscreenExit),
+    ("funnel", Bootstrap::Funnel),
+    ("funnel-fill", Bootstrap::FunnelFill),
+    ("gear", Bootstrap::Gear),
+    ("gear-fill", Bootstrap::GearFill),
+    ("gear-wide", Bootstrap::GearWide),
+    ("gear-wide-connected", Bootstrap::GearWideConnected),
+    ("gem", Bootstrap::Gem),
+    ("gender-ambiguous", Bootstrap::GenderAmbiguous),
+    ("gender-female", Bootstrap::GenderFemale),
+    ("gender-male", Bootstrap::GenderMale),
+    ("gender-neuter", Bootstrap::GenderNeuter),
+    ("gender-trans", Bootstrap::GenderTrans),
+    ("geo", Bootstrap::Geo),
+    ("geo-alt", Bootstrap::GeoAlt),
+    ("geo-alt-fill", Bootstrap::GeoAltFill),
+    ("geo-fill", Bootstrap::GeoFill),
+    ("gift", Bootstrap::Gift),
+    ("gift-fill", Bootstrap::GiftFill),
+    ("git", Bootstrap::Git),
+    ("github", Bootstrap::Github),
+    ("gitlab", Bootstrap::Gitlab),
+    ("globe", Bootstrap::Globe),
+    ("globe-americas", Bootstrap::GlobeAmericas),
+    ("globe-asia-australia", Bootstrap::GlobeAsiaAustralia),
+    ("globe-central-south-asia", Bootstrap::GlobeCentralSouthAsia),
+    ("globe-europe-africa", Bootstrap::GlobeEuropeAfrica),
+    ("globe2", Bootstrap::Globetwo),
+    ("google", Bootstrap::Google),
+    ("google-play", Bootstrap::GooglePlay),
+    ("gpu-card", Bootstrap::GpuCard),
+    ("graph-down", Bootstrap::GraphDown),
+    ("graph-down-arrow", Bootstrap::GraphDownArrow),
+    ("graph-up", Bootstrap::GraphUp),
+    ("graph-up-arrow", Bootstrap::GraphUpArrow),
+    ("grid", Bootstrap::Grid),
+    ("grid-1x2", Bootstrap::GridOnextwo),
+    ("grid-1x2-fill", Bootstrap::GridOnextwoFill),
+    ("grid-3x2", Bootstrap::GridThreextwo),
+    ("grid-3x2-gap", Bootstrap::GridThreextwoGap),
+    ("grid-3x2-gap-fill", Bootstrap::GridThreextwoGapFill),
+    ("grid-3x3", Bootstrap::GridThreexthree),
+    ("grid-3x3-gap", Bootstrap::GridThreexthreeGap),
+    ("grid-3x3-gap-fill", Bootstrap::GridThreexthreeGapFill),
+    ("grid-fill", Bootstrap::GridFill),
+    ("grip-horizontal", Bootstrap::GripHorizontal),
+    ("grip-vertical", Bootstrap::GripVertical),
+    ("h-circle", Bootstrap::HCircle),
+    ("h-circle-fill", Bootstrap::HCircleFill),
+    ("h-square", Bootstrap::HSquare),
+    ("h-square-fill", Bootstrap::HSquareFill),
+    ("hammer", Bootstrap::Hammer),
+    ("hand-index", Bootstrap::HandIndex),
+    ("hand-index-fill", Bootstrap::HandIndexFill),
+    ("hand-index-thumb", Bootstrap::HandIndexThumb),
+    ("hand-index-thumb-fill", Bootstrap::HandIndexThumbFill),
+    ("hand-thumbs-down", Bootstrap::HandThumbsDown),
+    ("hand-thumbs-down-fill", Bootstrap::HandThumbsDownFill),
+    ("hand-thumbs-up", Bootstrap::HandThumbsUp),
+    ("hand-thumbs-up-fill", Bootstrap::HandThumbsUpFill),
+    ("handbag", Bootstrap::Handbag),
+    ("handbag-fill", Bootstrap::HandbagFill),
+    ("hash", Bootstrap::Hash),
+    ("hdd", Bootstrap::Hdd),
+    ("hdd-fill", Bootstrap::HddFill),
+    ("hdd-network", Bootstrap::HddNetwork),
+    ("hdd-network-fill", Bootstrap::HddNetworkFill),
+    ("hdd-rack", Bootstrap::HddRack),
+    ("hdd-rack-fill", Bootstrap::HddRackFill),
+    ("hdd-stack", Bootstrap::HddStack),
+    ("hdd-stack-fill", Bootstrap::HddStackFill),
+    ("hdmi", Bootstrap::Hdmi),
+    ("hdmi-fill", Bootstrap::HdmiFill),
+    ("headphones", Bootstrap::Headphones),
+    ("headset", Bootstrap::Headset),
+    ("headset-vr", Bootstrap::HeadsetVr),
+    ("heart", Bootstrap::Heart),
+    ("heart-arrow", Bootstrap::HeartArrow),
+    ("heart-fill", Bootstrap::HeartFill),
+    ("heart-half", Bootstrap::HeartHalf),
+    ("heart-pulse", Bootstrap::HeartPulse),
+    ("heart-pulse-fill", Bootstrap::HeartPulseFill),
+    ("heartbreak", Bootstrap::Heartbreak),
+    ("heartbreak-fill", Bootstrap::HeartbreakFill),
+    ("hearts", Bootstrap::Hearts),
+    ("heptagon", Bootstrap::Heptagon),
+    ("heptagon-fill", Bootstrap::HeptagonFill),
+    ("heptagon-half", Bootstrap::HeptagonHalf),
+    ("hexagon", Bootstrap::Hexagon),
+    ("hexagon-fill", Bootstrap::HexagonFill),
+    ("hexagon-half", Bootstrap::HexagonHalf),
+    ("highlighter", Bootstrap::Highlighter),
+    ("highlights", Bootstrap::Highlights),
+    ("hospital", Bootstrap::Hospital),
+    ("hospital-fill", Bootstrap::HospitalFill),
+    ("hourglass", Bootstrap::Hourglass),
+    ("hourglass-bottom", Bootstrap::HourglassBottom),
+    ("hourglass-split", Bootstrap::HourglassSplit),
+    ("hourglass-top", Bootstrap::HourglassTop),
+    ("house", Bootstrap::House),
+    ("house-add", Bootstrap::HouseAdd),
+    ("house-add-fill", Bootstrap::HouseAddFill),
+    ("house-check", Bootstrap::HouseCheck),
+    ("house-check-fill", Bootstrap::HouseCheckFill),
+    ("house-dash", Bootstrap::HouseDash),
+    ("house-dash-fill", Bootstrap::HouseDashFill),
+    ("house-door", Bootstrap::HouseDoor),
+    ("house-door-fill", Bootstrap::HouseDoorFill),
+    ("house-down", Bootstrap::HouseDown),
+    ("house-down-fill", Bootstrap::HouseDownFill),
+    ("house-exclamation", Bootstrap::HouseExclamation),
+    ("house-exclamation-fill", Bootstrap::HouseExclamationFill),
+    ("house-fill", Bootstrap::HouseFill),
+    ("house-gear", Bootstrap::HouseGear),
+    ("house-gear-fill", Bootstrap::HouseGearFill),
+    ("house-heart", Bootstrap::HouseHeart),
+    ("house-heart-fill", Bootstrap::HouseHeartFill),
+    ("house-lock", Bootstrap::HouseLock),
+    ("house-lock-fill", Bootstrap::HouseLockFill),
+    ("house-slash", Bootstrap::HouseSlash),
+    ("house-slash-fill", Bootstrap::HouseSlashFill),
+    ("house-up", Bootstrap::HouseUp),
+    ("house-up-fill", Bootstrap::HouseUpFill),
+    ("house-x", Bootstrap::HouseX),
+    ("house-x-fill", Bootstrap::HouseXFill),
+    ("houses", Bootstrap::Houses),
+    ("houses-fill", Bootstrap::HousesFill),
+    ("hr", Bootstrap::Hr),
+    ("hurricane", Bootstrap::Hurricane),
+    ("hypnotize", Bootstrap::Hypnotize),
+    ("image", Bootstrap::Image),
+    ("image-alt", Bootstrap::ImageAlt),
+    ("image-fill", Bootstrap::ImageFill),
+    ("images", Bootstrap::Images),
+    ("inbox", Bootstrap::Inbox),
+    ("inbox-fill", Bootstrap::InboxFill),
+    ("inboxes", Bootstrap::Inboxes),
+    ("inboxes-fill", Bootstrap::InboxesFill),
+    ("incognito", Bootstrap::Incognito),
+    ("indent", Bootstrap::Indent),
+    ("infinity", Bootstrap::Infinity),
+    ("info", Bootstrap::Info),
+    ("info-circle", Bootstrap::InfoCircle),
+    ("info-circle-fill", Bootstrap::InfoCircleFill),
+    ("info-lg", Bootstrap::InfoLg),
+    ("info-square", Bootstrap::InfoSquare),
+    ("info-square-fill", Bootstrap::InfoSquareFill),
+    ("input-cursor", Bootstrap::InputCursor),
+    ("input-cursor-text", Bootstrap::InputCursorText),
+    ("instagram", Bootstrap::Instagram),
+    ("intersect", Bootstrap::Intersect),
+    ("journal", Bootstrap::Journal),
+    ("journal-album", Bootstrap::JournalAlbum),
+    ("journal-arrow-down", Bootstrap::JournalArrowDown),
+    ("journal-arrow-up", Bootstrap::JournalArrowUp),
+    ("journal-bookmark", Bootstrap::JournalBookmark),
+    ("journal-bookmark-fill", Bootstrap::JournalBookmarkFill),
+    ("journal-check", Bootstrap::JournalCheck),
+    ("journal-code", Bootstrap::JournalCode),
+    ("journal-medical", Bootstrap::JournalMedical),
+    ("journal-minus", Bootstrap::JournalMinus),
+    ("journal-plus", Bootstrap::JournalPlus),
+    ("journal-richtext", Bootstrap::JournalRichtext),
+    ("journal-text", Bootstrap::JournalText),
+    ("journal-x", Bootstrap::JournalX),
+    ("journals", Bootstrap::Journals),
+    ("joystick", Bootstrap::Joystick),
+    ("justify", Bootstrap::Justify),
+    ("justify-left", Bootstrap::JustifyLeft),
+    ("justify-right", Bootstrap::JustifyRight),
+    ("kanban", Bootstrap::Kanban),
+    ("kanban-fill", Bootstrap::KanbanFill),
+    ("key", Bootstrap::Key),
+    ("key-fill", Bootstrap::KeyFill),
+    ("keyboard", Bootstrap::Keyboard),
+    ("keyboard-fill", Bootstrap::KeyboardFill),
+    ("ladder", Bootstrap::Ladder),
+    ("lamp", Bootstrap::Lamp),
+    ("lamp-fill", Bootstrap::LampFill),
+    ("laptop", Bootstrap::Laptop),
+    ("laptop-fill", Bootstrap::LaptopFill),
+    ("layer-backward", Bootstrap::LayerBackward),
+    ("layer-forward", Bootstrap::LayerForward),
+    ("layers", Bootstrap::Layers),
+    ("layers-fill", Bootstrap::LayersFill),
+    ("layers-half", Bootstrap::LayersHalf),
+    ("layout-sidebar", Bootstrap::LayoutSidebar),
+    ("layout-sidebar-inset", Bootstrap::LayoutSidebarInset),
+    (
+        "layout-sidebar-inset-reverse",
+        Bootstrap::LayoutSidebarInsetReverse,
+    ),
+    ("layout-sidebar-reverse", Bootstrap::LayoutSidebarReverse),
+    ("layout-split", Bootstrap::LayoutSplit),
+    ("layout-text-sidebar", Bootstrap::LayoutTextSidebar),
+    (
+        "layout-text-sidebar-reverse",
+        Bootstrap::LayoutTextSidebarReverse,
+    ),
+    ("layout-text-window", Bootstrap::LayoutTextWindow),
+    (
+        "layout-text-window-reverse",
+        Bootstrap::LayoutTextWindowReverse,
+    ),
+    ("layout-three-columns", Bootstrap::LayoutThreeColumns),
+    ("layout-wtf", Bootstrap::LayoutWtf),
+    ("life-preserver", Bootstrap::LifePreserver),
+    ("lightbulb", Bootstrap::Lightbulb),
+    ("lightbulb-fill", Bootstrap::LightbulbFill),
+    ("lightbulb-off", Bootstrap::LightbulbOff),
+    ("lightbulb-off-fill", Bootstrap::LightbulbOffFill),
+    ("lightning", Bootstrap::Lightning),
+    ("lightning-charge", Bootstrap::LightningCharge),
+    ("lightning-charge-fill", Bootstrap::LightningChargeFill),
+    ("lightning-fill", Bootstrap::LightningFill),
+    ("line", Bootstrap::Line),
+    ("link", Bootstrap::Link),
+    ("link-45deg", Bootstrap::LinkFourfivedeg),
+    ("linkedin", Bootstrap::Linkedin),
+    ("list", Bootstrap::List),
+    ("list-check", Bootstrap::ListCheck),
+    ("list-columns", Bootstrap::ListColumns),
+    ("list-columns-reverse", Bootstrap::ListColumnsReverse),
+    ("list-nested", Bootstrap::ListNested),
+    ("list-ol", Bootstrap::ListOl),
+    ("list-stars", Bootstrap::ListStars),
+    ("list-task", Bootstrap::ListTask),
+    ("list-ul", Bootstrap::ListUl),
+    ("lock", Bootstrap::Lock),
+    ("lock-fill", Bootstrap::LockFill),
+    ("luggage", Bootstrap::Luggage),
+    ("luggage-fill", Bootstrap::LuggageFill),
+    ("lungs", Bootstrap::Lungs),
+    ("lungs-fill", Bootstrap::LungsFill),
+    ("magic", Bootstrap::Magic),
+    ("magnet", Bootstrap::Magnet),
+    ("magnet-fill", Bootstrap::MagnetFill),
+    ("mailbox", Bootstrap::Mailbox),
+    ("mailbox-flag", Bootstrap::MailboxFlag),
+    ("mailbox2", Bootstrap::Mailboxtwo),
+    ("mailbox2-flag", Bootstrap::MailboxtwoFlag),
+    ("map", Bootstrap::Map),
+    ("map-fill", Bootstrap::MapFill),
+    ("markdown", Bootstrap::Markdown),
+    ("markdown-fill", Bootstrap::MarkdownFill),
+    ("marker-tip", Bootstrap::MarkerTip),
+    ("mask", Bootstrap::Mask),
+    ("mastodon", Bootstrap::Mastodon),
+    ("medium", Bootstrap::Medium),
+    ("megaphone", Bootstrap::Megaphone),
+    ("megaphone-fill", Bootstrap::MegaphoneFill),
+    ("memory", Bootstrap::Memory),
+    ("menu-app", Bootstrap::MenuApp),
+    ("menu-app-fill", Bootstrap::MenuAppFill),
+    ("menu-button", Bootstrap::MenuButton),
+    ("menu-button-fill", Bootstrap::MenuButtonFill),
+    ("menu-button-wide", Bootstrap::MenuButtonWide),
+    ("menu-button-wide-fill", Bootstrap::MenuButtonWideFill),
+    ("menu-down", Bootstrap::MenuDown),
+    ("menu-up", Bootstrap::MenuUp),
+    ("messenger", Bootstrap::Messenger),
+    ("meta", Bootstrap::Meta),
+    ("mic", Bootstrap::Mic),
+    ("mic-fill", Bootstrap::MicFill),
+    ("mic-mute", Bootstrap::MicMute),
+    ("mic-mute-fill", Bootstrap::MicMuteFill),
+    ("microsoft", Bootstrap::Microsoft),
+    ("microsoft-teams", Bootstrap::MicrosoftTeams),
+    ("minecart", Bootstrap::Minecart),
+    ("minecart-loaded", Bootstrap::MinecartLoaded),
+    ("modem", Bootstrap::Modem),
+    ("modem-fill", Bootstrap::ModemFill),
+    ("moisture", Bootstrap::Moisture),
+    ("moon", Bootstrap::Moon),
+    ("moon-fill", Bootstrap::MoonFill),
+    ("moon-stars", Bootstrap::MoonStars),
+    ("moon-stars-fill", Bootstrap::MoonStarsFill),
+    ("mortarboard", Bootstrap::Mortarboard),
+    ("mortarboard-fill", Bootstrap::MortarboardFill),
+    ("motherboard", Bootstrap::Motherboard),
+    ("motherboard-fill", Bootstrap::MotherboardFill),
+    ("mouse", Bootstrap::Mouse),
+    ("mouse-fill", Bootstrap::MouseFill),
+    ("mouse2", Bootstrap::Mousetwo),
+    ("mouse2-fill", Bootstrap::MousetwoFill),
+    ("mouse3", Bootstrap::Mousethree),
+    ("mouse3-fill", Bootstrap::MousethreeFill),
+    ("music-note", Bootstrap::MusicNote),
+    ("music-note-beamed", Bootstrap::MusicNoteBeamed),
+    ("music-note-list", Bootstrap::MusicNoteList),
+    ("music-player", Bootstrap::MusicPlayer),
+    ("music-player-fill", Bootstrap::MusicPlayerFill),
+    ("newspaper", Bootstrap::Newspaper),
+    ("nintendo-switch", Bootstrap::NintendoSwitch),
+    ("node-minus", Bootstrap::NodeMinus),
+    ("node-minus-fill", Bootstrap::NodeMinusFill),
+    ("node-plus", Bootstrap::NodePlus),
+    ("node-plus-fill", Bootstrap::NodePlusFill),
+    ("noise-reduction", Bootstrap::NoiseReduction),
+    ("nut", Bootstrap::Nut),
+    ("nut-fill", Bootstrap::NutFill),
+    ("nvidia", Bootstrap::Nvidia),
+    ("nvme", Bootstrap::Nvme),
+    ("nvme-fill", Bootstrap::NvmeFill),
+    ("octagon", Bootstrap::Octagon),
+    ("octagon-fill", Bootstrap::OctagonFill),
+    ("octagon-half", Bootstrap::OctagonHalf),
+    ("opencollective", Bootstrap::Opencollective),
+    ("optical-audio", Bootstrap::OpticalAudio),
+    ("optical-audio-fill", Bootstrap::OpticalAudioFill),
+    ("option", Bootstrap::Option),
+    ("outlet", Bootstrap::Outlet),
+    ("p-circle", Bootstrap::PCircle),
+    ("p-circle-fill", Bootstrap::PCircleFill),
+    ("p-square", Bootstrap::PSquare),
+    ("p-square-fill", Bootstrap::PSquareFill),
+    ("paint-bucket", Bootstrap::PaintBucket),
+    ("palette", Bootstrap::Palette),
+    ("palette-fill", Bootstrap::PaletteFill),
+    ("palette2", Bootstrap::Palettetwo),
+    ("paperclip", Bootstrap::Paperclip),
+    ("paragraph", Bootstrap::Paragraph),
+    ("pass", Bootstrap::Pass),
+    ("pass-fill", Bootstrap::PassFill),
+    ("passport", Bootstrap::Passport),
+    ("passport-fill", Bootstrap::PassportFill),
+    ("patch-check", Bootstrap::PatchCheck),
+    ("patch-check-fill", Bootstrap::PatchCheckFill),
+    ("patch-exclamation", Bootstrap::PatchExclamation),
+    ("patch-exclamation-fill", Bootstrap::PatchExclamationFill),
+    ("patch-minus", Bootstrap::PatchMinus),
+    ("patch-minus-fill", Bootstrap::PatchMinusFill),
+    ("patch-plus", Bootstrap::PatchPlus),
+    ("patch-plus-fill", Bootstrap::PatchPlusFill),
+    ("patch-question", Bootstrap::PatchQuestion),
+    ("patch-question-fill", Bootstrap::PatchQuestionFill),
+    ("pause", Bootstrap::Pause),
+    ("pause-btn", Bootstrap::PauseBtn),
+    ("pause-btn-fill", Bootstrap::PauseBtnFill),
+    ("pause-circle", Bootstrap::PauseCircle),
+    ("pause-circle-fill", Bootstrap::PauseCircleFill),
+    ("pause-fill", Bootstrap::PauseFill),
+    ("paypal", Bootstrap::Paypal),
+    ("pc", Bootstrap::Pc),
+    ("pc-display", Bootstrap::PcDisplay),
+    ("pc-display-horizontal", Bootstrap::PcDisplayHorizontal),
+    ("pc-horizontal", Bootstrap::PcHorizontal),
+    ("pci-card", Bootstrap::PciCard),
+    ("pci-card-network", Bootstrap::PciCardNetwork),
+    ("pci-card-sound", Bootstrap::PciCardSound),
+    ("peace", Bootstrap::Peace),
+    ("peace-fill", Bootstrap::PeaceFill),
+    ("pen", Bootstrap::Pen),
+    ("pen-fill", Bootstrap::PenFill),
+    ("pencil", Bootstrap::Pencil),
+    ("pencil-fill", Bootstrap::PencilFill),
+    ("pencil-square", Bootstrap::PencilSquare),
+    ("pentagon", Bootstrap::Pentagon),
+    ("pentagon-fill", Bootstrap::PentagonFill),
+    ("pentagon-half", Bootstrap::PentagonHalf),
+    ("people", Bootstrap::People),
+    ("people-fill", Bootstrap::PeopleFill),
+    ("percent", Bootstrap::Percent),
+    ("person", Bootstrap::Person),
+    ("person-add", Bootstrap::PersonAdd),
+    ("person-arms-up", Bootstrap::PersonArmsUp),
+    ("person-badge", Bootstrap::PersonBadge),
+    ("person-badge-fill", Bootstrap::PersonBadgeFill),
+    ("person-bounding-box", Bootstrap::PersonBoundingBox),
+    ("person-check", Bootstrap::PersonCheck),
+    ("person-check-fill", Bootstrap::PersonCheckFill),
+    ("person-circle", Bootstrap::PersonCircle),
+    ("person-dash", Bootstrap::PersonDash),
+    ("person-dash-fill", Bootstrap::PersonDashFill),
+    ("person-down", Bootstrap::PersonDown),
+    ("person-exclamation", Bootstrap::PersonExclamation),
+    ("person-fill", Bootstrap::PersonFill),
+    ("person-fill-add", Bootstrap::PersonFillAdd),
+    ("person-fill-check", Bootstrap::PersonFillCheck),
+    ("person-fill-dash", Bootstrap::PersonFillDash),
+    ("person-fill-down", Bootstrap::PersonFillDown),
+    ("person-fill-exclamation", Bootstrap::PersonFillExclamation),
+    ("person-fill-gear", Bootstrap::PersonFillGear),
+    ("person-fill-lock", Bootstrap::PersonFillLock),
+    ("person-fill-slash", Bootstrap::PersonFillSlash),
+    ("person-fill-up", Bootstrap::PersonFillUp),
+    ("person-fill-x", Bootstrap::PersonFillX),
+    ("person-gear", Bootstrap::PersonGear),
+    ("person-heart", Bootstrap::PersonHeart),
+    ("person-hearts", Bootstrap::PersonHearts),
+    ("person-lines-fill", Bootstrap::PersonLinesFill),
+    ("person-lock", Bootstrap::PersonLock),
+    ("person-plus", Bootstrap::PersonPlus),
+    ("person-plus-fill", Bootstrap::PersonPlusFill),
+    ("person-raised-hand", Bootstrap::PersonRaisedHand),
+    ("person-rolodex", Bootstrap::PersonRolodex),
+    ("person-slash", Bootstrap::PersonSlash),
+    ("person-square", Bootstrap::PersonSquare),
+    ("person-standing", Bootstrap::PersonStanding),
+    ("person-standing-dress", Bootstrap::PersonStandingDress),
+    ("person-up", Bootstrap::PersonUp),
+    ("person-vcard", Bootstrap::PersonVcard),
+    ("person-vcard-fill", Bootstrap::PersonVcardFill),
+    ("person-video", Bootstrap::PersonVideo),
+    ("person-video2", Bootstrap::PersonVideotwo),
+    ("person-video3", Bootstrap::PersonVideothree),
+    ("person-walking", Bootstrap::PersonWalking),
+    ("person-wheelchair", Bootstrap::PersonWheelchair),
+    ("person-workspace", Bootstrap::PersonWorkspace),
+    ("person-x", Bootstrap::PersonX),
+    ("person-x-fill", Bootstrap::PersonXFill),
+    ("phone", Bootstrap::Phone),
+    ("phone-fill", Bootstrap::PhoneFill),
+    ("phone-flip", Bootstrap::PhoneFlip),
+    ("phone-landscape", Bootstrap::PhoneLandscape),
+    ("phone-landscape-fill", Bootstrap::PhoneLandscapeFill),
+    ("phone-vibrate", Bootstrap::PhoneVibrate),
+    ("phone-vibrate-fill", Bootstrap::PhoneVibrateFill),
+    ("pie-chart", Bootstrap::PieChart),
+    ("pie-chart-fill", Bootstrap::PieChartFill),
+    ("piggy-bank", Bootstrap::PiggyBank),
+    ("piggy-bank-fill", Bootstrap::PiggyBankFill),
+    ("pin", Bootstrap::Pin),
+    ("pin-angle", Bootstrap::PinAngle),
+    ("pin-angle-fill", Bootstrap::PinAngleFill),
+    ("pin-fill", Bootstrap::PinFill),
+    ("pin-map", Bootstrap::PinMap),
+    ("pin-map-fill", Bootstrap::PinMapFill),
+    ("pinterest", Bootstrap::Pinterest),
+    ("pip", Bootstrap::Pip),
+    ("pip-fill", Bootstrap::PipFill),
+    ("play", Bootstrap::Play),
+    ("play-btn", Bootstrap::PlayBtn),
+    ("play-btn-fill", Bootstrap::PlayBtnFill),
+    ("play-circle", Bootstrap::PlayCircle),
+    ("play-circle-fill", Bootstrap::PlayCircleFill),
+    ("play-fill", Bootstrap::PlayFill),
+    ("playstation", Bootstrap::Playstation),
+    ("plug", Bootstrap::Plug),
+    ("plug-fill", Bootstrap::PlugFill),
+    ("plugin", Bootstrap::Plugin),
+    ("plus", Bootstrap::Plus),
+    ("plus-circle", Bootstrap::PlusCircle),
+    ("plus-circle-dotted", Bootstrap::PlusCircleDotted),
+    ("plus-circle-fill", Bootstrap::PlusCircleFill),
+    ("plus-lg", Bootstrap::PlusLg),
+    ("plus-slash-minus", Bootstrap::PlusSlashMinus),
+    ("plus-square", Bootstrap::PlusSquare),
+    ("plus-square-dotted", Bootstrap::PlusSquareDotted),
+    ("plus-square-fill", Bootstrap::PlusSquareFill),
+    ("postage", Bootstrap::Postage),
+    ("postage-fill", Bootstrap::PostageFill),
+    ("postage-heart", Bootstrap::PostageHeart),
+    ("postage-heart-fill", Bootstrap::PostageHeartFill),
+    ("postcard", Bootstrap::Postcard),
+    ("postcard-fill", Bootstrap::PostcardFill),
+    ("postcard-heart", Bootstrap::PostcardHeart),
+    ("postcard-heart-fill", Bootstrap::PostcardHeartFill),
+    ("power", Bootstrap::Power),
+    ("prescription", Bootstrap::Prescription),
+    ("prescription2", Bootstrap::Prescriptiontwo),
+    ("printer", Bootstrap::Printer),
+    ("printer-fill", Bootstrap::PrinterFill),
+    ("projector", Bootstrap::Projector),
+    ("projector-fill", Bootstrap::ProjectorFill),
+    ("puzzle", Bootstrap::Puzzle),
+    ("puzzle-fill", Bootstrap::PuzzleFill),
+    ("qr-code", Bootstrap::QrCode),
+    ("qr-code-scan", Bootstrap::QrCodeScan),
+    ("question", Bootstrap::Question),
+    ("question-circle", Bootstrap::QuestionCircle),
+    ("question-circle-fill", Bootstrap::QuestionCircleFill),
+    ("question-diamond", Bootstrap::QuestionDiamond),
+    ("question-diamond-fill", Bootstrap::QuestionDiamondFill),
+    ("question-lg", Bootstrap::QuestionLg),
+    ("question-octagon", Bootstrap::QuestionOctagon),
+    ("question-octagon-fill", Bootstrap::QuestionOctagonFill),
+    ("question-square", Bootstrap::QuestionSquare),
+    ("question-square-fill", Bootstrap::QuestionSquareFill),
+    ("quora", Bootstrap::Quora),
+    ("quote", Bootstrap::Quote),
+    ("r-circle", Bootstrap::RCircle),
+    ("r-circle-fill", Bootstrap::RCircleFill),
+    ("r-square", Bootstrap::RSquare),
+    ("r-square-fill", Bootstrap::RSquareFill),
+    ("radar", Bootstrap::Radar),
+    ("radioactive", Bootstrap::Radioactive),
+    ("rainbow", Bootstrap::Rainbow),
+    ("receipt", Bootstrap::Receipt),
+    ("receipt-cutoff", Bootstrap::ReceiptCutoff),
+    ("reception-0", Bootstrap::ReceptionZero),
+    ("reception-1", Bootstrap::ReceptionOne),
+    ("reception-2", Bootstrap::ReceptionTwo),
+    ("reception-3", Bootstrap::ReceptionThree),
+    ("reception-4", Bootstrap::ReceptionFour),
+    ("record", Bootstrap::Record),
+    ("record-btn", Bootstrap::RecordBtn),
+    ("record-btn-fill", Bootstrap::RecordBtnFill),
+    ("record-circle", Bootstrap::RecordCircle),
+    ("record-circle-fill", Bootstrap::RecordCircleFill),
+    ("record-fill", Bootstrap::RecordFill),
+    ("record2", Bootstrap::Recordtwo),
+    ("record2-fill", Bootstrap::RecordtwoFill),
+    ("recycle", Bootstrap::Recycle),
+    ("reddit", Bootstrap::Reddit),
+    ("regex", Bootstrap::Regex),
+    ("repeat", Bootstrap::Repeat),
+    ("repeat-1", Bootstrap::RepeatOne),
+    ("reply", Bootstrap::Reply),
+    ("reply-all", Bootstrap::ReplyAll),
+    ("reply-all-fill", Bootstrap::ReplyAllFill),
+    ("reply-fill", Bootstrap::ReplyFill),
+    ("rewind", Bootstrap::Rewind),
+    ("rewind-btn", Bootstrap::RewindBtn),
+    ("rewind-btn-fill", Bootstrap::RewindBtnFill),
+    ("rewind-circle", Bootstrap::RewindCircle),
+    ("rewind-circle-fill", Bootstrap::RewindCircleFill),
+    ("rewind-fill", Bootstrap::RewindFill),
+    ("robot", Bootstrap::Robot),
+    ("rocket", Bootstrap::Rocket),
+    ("rocket-fill", Bootstrap::RocketFill),
+    ("rocket-takeoff", Bootstrap::RocketTakeoff),
+    ("rocket-takeoff-fill", Bootstrap::RocketTakeoffFill),
+    ("router", Bootstrap::Router),
+    ("router-fill", Bootstrap::RouterFill),
+    ("rss", Bootstrap::Rss),
+    ("rss-fill", Bootstrap::RssFill),
+    ("rulers", Bootstrap::Rulers),
+    ("safe", Bootstrap::Safe),
+    ("safe-fill", Bootstrap::SafeFill),
+    ("safe2", Bootstrap::Safetwo),
+    ("safe2-fill", Bootstrap::SafetwoFill),
+    ("save", Bootstrap::Save),
+    ("save-fill", Bootstrap::SaveFill),
+    ("save2", Bootstrap::Savetwo),
+    ("save2-fill", Bootstrap::SavetwoFill),
+    ("scissors", Bootstrap::Scissors),
+    ("scooter", Bootstrap::Scooter),
+    ("screwdriver", Bootstrap::Screwdriver),
+    ("sd-card", Bootstrap::SdCard),
+    ("sd-card-fill", Bootstrap::SdCardFill),
+    ("search", Bootstrap::Search),
+    ("search-heart", Bootstrap::SearchHeart),
+    ("search-heart-fill", Bootstrap::SearchHeartFill),
+    ("segmented-nav", Bootstrap::SegmentedNav),
+    ("send", Bootstrap::Send),
+    ("send-arrow-down", Bootstrap::SendArrowDown),
+    ("send-arrow-down-fill", Bootstrap::SendArrowDownFill),
+    ("send-arrow-up", Bootstrap::SendArrowUp),
+    ("send-arrow-up-fill", Bootstrap::SendArrowUpFill),
+    ("send-check", Bootstrap::SendCheck),
+    ("send-check-fill", Bootstrap::SendCheckFill),
+    ("send-dash", Bootstrap::SendDash),
+    ("send-dash-fill", Bootstrap::SendDashFill),
+    ("send-exclamation", Bootstrap::SendExclamation),
+    ("send-exclamation-fill", Bootstrap::SendExclamationFill),
+    ("send-fill", Bootstrap::SendFill),
+    ("send-plus", Bootstrap::SendPlus),
+    ("send-plus-fill", Bootstrap::SendPlusFill),
+    ("send-slash", Bootstrap::SendSlash),
+    ("send-slash-fill", Bootstrap::SendSlashFill),
+    ("send-x", Bootstrap::SendX),
+    ("send-x-fill", Bootstrap::SendXFill),
+    ("server", Bootstrap::Server),
+    ("shadows", Bootstrap::Shadows),
+    ("share", Bootstrap::Share),
+    ("share-fill", Bootstrap::ShareFill),
+    ("shield", Bootstrap::Shield),
+    ("shield-check", Bootstrap::ShieldCheck),
+    ("shield-exclamation", Bootstrap::ShieldExclamation),
+    ("shield-fill", Bootstrap::ShieldFill),
+    ("shield-fill-check", Bootstrap::ShieldFillCheck),
+    ("shield-fill-exclamation", Bootstrap::ShieldFillExclamation),
+    ("shield-fill-minus", Bootstrap::ShieldFillMinus),
+    ("shield-fill-plus", Bootstrap::ShieldFillPlus),
+    ("shield-fill-x", Bootstrap::ShieldFillX),
+    ("shield-lock", Bootstrap::ShieldLock),
+    ("shield-lock-fill", Bootstrap::ShieldLockFill),
+    ("shield-minus", Bootstrap::ShieldMinus),
+    ("shield-plus", Bootstrap::ShieldPlus),
+    ("shield-shaded", Bootstrap::ShieldShaded),
+    ("shield-slash", Bootstrap::ShieldSlash),
+    ("shield-slash-fill", Bootstrap::ShieldSlashFill),
+    ("shield-x", Bootstrap::ShieldX),
+    ("shift", Bootstrap::Shift),
+    ("shift-fill", Bootstrap::ShiftFill),
+    ("shop", Bootstrap::Shop),
+    ("shop-window", Bootstrap::ShopWindow),
+    ("shuffle", Bootstrap::Shuffle),
+    ("sign-dead-end", Bootstrap::SignDeadEnd),
+    ("sign-dead-end-fill", Bootstrap::SignDeadEndFill),
+    ("sign-do-not-enter", Bootstrap::SignDoNotEnter),
+    ("sign-do-not-enter-fill", Bootstrap::SignDoNotEnterFill),
+    ("sign-intersection", Bootstrap::SignIntersection),
+    ("sign-intersection-fill", Bootstrap::SignIntersectionFill),
+    ("sign-intersection-side", Bootstrap::SignIntersectionSide),
+    (
+        "sign-intersection-side-fill",
+        Bootstrap::SignIntersectionSideFill,
+    ),
+    ("sign-intersection-t", Bootstrap::SignIntersectionT),
+    ("sign-intersection-t-fill", Bootstrap::SignIntersectionTFill),
+    ("sign-intersection-y", Bootstrap::SignIntersectionY),
+    ("sign-intersection-y-fill", Bootstrap::SignIntersectionYFill),
+    ("sign-merge-left", Bootstrap::SignMergeLeft),
+    ("sign-merge-left-fill", Bootstrap::SignMergeLeftFill),
+    ("sign-merge-right", Bootstrap::SignMergeRight),
+    ("sign-merge-right-fill", Bootstrap::SignMergeRightFill),
+    ("sign-no-left-turn", Bootstrap::SignNoLeftTurn),
+    ("sign-no-left-turn-fill", Bootstrap::SignNoLeftTurnFill),
+    ("sign-no-parking", Bootstrap::SignNoParking),
+    ("sign-no-parking-fill", Bootstrap::SignNoParkingFill),
+    ("sign-no-right-turn", Bootstrap::SignNoRightTurn),
+    ("sign-no-right-turn-fill", Bootstrap::SignNoRightTurnFill),
+    ("sign-railroad", Bootstrap::SignRailroad),
+    ("sign-railroad-fill", Bootstrap::SignRailroadFill),
+    ("sign-stop", Bootstrap::SignStop),
+    ("sign-stop-fill", Bootstrap::SignStopFill),
+    ("sign-stop-lights", Bootstrap::SignStopLights),
+    ("sign-stop-lights-fill", Bootstrap::SignStopLightsFill),
+    ("sign-turn-left", Bootstrap::SignTurnLeft),
+    ("sign-turn-left-fill", Bootstrap::SignTurnLeftFill),
+    ("sign-turn-right", Bootstrap::SignTurnRight),
+    ("sign-turn-right-fill", Bootstrap::SignTurnRightFill),
+    ("sign-turn-slight-left", Bootstrap::SignTurnSlightLeft),
+    (
+        "sign-turn-slight-left-fill",
+        Bootstrap::SignTurnSlightLeftFill,
+    ),
+    ("sign-turn-slight-right", Bootstrap::SignTurnSlightRight),
+    (
+        "sign-turn-slight-right-fill",
+        Bootstrap::SignTurnSlightRightFill,
+    ),
+    ("sign-yield", Bootstrap::SignYield),
+    ("sign-yield-fill", Bootstrap::SignYieldFill),
+    ("signal", Bootstrap::Signal),
+    ("signpost", Bootstrap::Signpost),
+    ("signpost-2", Bootstrap::SignpostTwo),
+    ("signpost-2-fill", Bootstrap::SignpostTwoFill),
+    ("signpost-fill", Bootstrap::SignpostFill),
+    ("signpost-split", Bootstrap::SignpostSplit),
+    ("signpost-split-fill", Bootstrap::SignpostSplitFill),
+    ("sim", Bootstrap::Sim),
+    ("sim-fill", Bootstrap::SimFill),
+    ("sim-slash", Bootstrap::SimSlash),
+    ("sim-slash-fill", Bootstrap::SimSlashFill),
+    ("sina-weibo", Bootstrap::SinaWeibo),
+    ("skip-backward", Bootstrap::SkipBackward),
+    ("skip-backward-btn", Bootstrap::SkipBackwardBtn),
+    ("skip-backward-btn-fill", Bootstrap::SkipBackwardBtnFill),
+    ("skip-backward-circle", Bootstrap::SkipBackwardCircle),
+    (
+        "skip-backward-circle-fill",
+        Bootstrap::SkipBackwardCircleFill,
+    ),
+    ("skip-backward-fill", Bootstrap::SkipBackwardFill),
+    ("skip-end", Bootstrap::SkipEnd),
+    ("skip-end-btn", Bootstrap::SkipEndBtn),
+    ("skip-end-btn-fill", Bootstrap::SkipEndBtnFill),
+    ("skip-end-circle", Bootstrap::SkipEndCircle),
+    ("skip-end-circle-fill", Bootstrap::SkipEndCircleFill),
+    ("skip-end-fill", Bootstrap::SkipEndFill),
+    ("skip-forward", Bootstrap::SkipForward),
+    ("skip-forward-btn", Bootstrap::SkipForwardBtn),
+    ("skip-forward-btn-fill", Bootstrap::SkipForwardBtnFill),
+    ("skip-forward-circle", Bootstrap::SkipForwardCircle),
+    ("skip-forward-circle-fill", Bootstrap::SkipForwardCircleFill),
+    ("skip-forward-fill", Bootstrap::SkipForwardFill),
+    ("skip-start", Bootstrap::SkipStart),
+    ("skip-start-btn", Bootstrap::SkipStartBtn),
+    ("skip-start-btn-fill", Bootstrap::SkipStartBtnFill),
+    ("skip-start-circle", Bootstrap::SkipStartCircle),
+    ("skip-start-circle-fill", Bootstrap::SkipStartCircleFill),
+    ("skip-start-fill", Bootstrap::SkipStartFill),
+    ("skype", Bootstrap::Skype),
+    ("slack", Bootstrap::Slack),
+    ("slash", Bootstrap::Slash),
+    ("slash-circle", Bootstrap::SlashCircle),
+    ("slash-circle-fill", Bootstrap::SlashCircleFill),
+    ("slash-lg", Bootstrap::SlashLg),
+    ("slash-square", Bootstrap::SlashSquare),
+    ("slash-square-fill", Bootstrap::SlashSquareFill),
+    ("sliders", Bootstrap::Sliders),
+    ("sliders2", Bootstrap::Sliderstwo),
+    ("sliders2-vertical", Bootstrap::SliderstwoVertical),
+    ("smartwatch", Bootstrap::Smartwatch),
+    ("snapchat", Bootstrap::Snapchat),
+    ("snow", Bootstrap::Snow),
+    ("snow2", Bootstrap::Snowtwo),
+    ("snow3", Bootstrap::Snowthree),
+    ("sort-alpha-down", Bootstrap::SortAlphaDown),
+    ("sort-alpha-down-alt", Bootstrap::SortAlphaDownAlt),
+    ("sort-alpha-up", Bootstrap::SortAlphaUp),
+    ("sort-alpha-up-alt", Bootstrap::SortAlphaUpAlt),
+    ("sort-down", Bootstrap::SortDown),
+    ("sort-down-alt", Bootstrap::SortDownAlt),
+    ("sort-numeric-down", Bootstrap::SortNumericDown),
+    ("sort-numeric-down-alt", Bootstrap::SortNumericDownAlt),
+    ("sort-numeric-up", Bootstrap::SortNumericUp),
+    ("sort-numeric-up-alt", Bootstrap::SortNumericUpAlt),
+    ("sort-up", Bootstrap::SortUp),
+    ("sort-up-alt", Bootstrap::SortUpAlt),
+    ("soundwave", Bootstrap::Soundwave),
+    ("sourceforge", Bootstrap::Sourceforge),
+    ("speaker", Bootstrap::Speaker),
+    ("speaker-fill", Bootstrap::SpeakerFill),
+    ("speedometer", Bootstrap::Speedometer),
+    ("speedometer2", Bootstrap::Speedometertwo),
+    ("spellcheck", Bootstrap::Spellcheck),
+    ("spotify", Bootstrap::Spotify),
+    ("square", Bootstrap::Square),
+    ("square-fill", Bootstrap::SquareFill),
+    ("square-half", Bootstrap::SquareHalf),
+    ("stack", Bootstrap::Stack),
+    ("stack-overflow", Bootstrap::StackOverflow),
+    ("star", Bootstrap::Star),
+    ("star-fill", Bootstrap::StarFill),
+    ("star-half", Bootstrap::StarHalf),
+    ("stars", Bootstrap::Stars),
+    ("steam", Bootstrap::Steam),
+    ("stickies", Bootstrap::Stickies),
+    ("stickies-fill", Bootstrap::StickiesFill),
+    ("sticky", Bootstrap::Sticky),
+    ("sticky-fill", Bootstrap::StickyFill),
+    ("stop", Bootstrap::Stop),
+    ("stop-btn", Bootstrap::StopBtn),
+    ("stop-btn-fill", Bootstrap::StopBtnFill),
+    ("stop-circle", Bootstrap::StopCircle),
+    ("stop-circle-fill", Bootstrap::StopCircleFill),
+    ("stop-fill", Bootstrap::StopFill),
+    ("stoplights", Bootstrap::Stoplights),
+    ("stoplights-fill", Bootstrap::StoplightsFill),
+    ("stopwatch", Bootstrap::Stopwatch),
+    ("stopwatch-fill", Bootstrap::StopwatchFill),
+    ("strava", Bootstrap::Strava),
+    ("stripe", Bootstrap::Stripe),
+    ("subscript", Bootstrap::Subscript),
+    ("substack", Bootstrap::Substack),
+    ("suit-club", Bootstrap::SuitClub),
+    ("suit-club-fill", Bootstrap::SuitClubFill),
+    ("suit-diamond", Bootstrap::SuitDiamond),
+    ("suit-diamond-fill", Bootstrap::SuitDiamondFill),
+    ("suit-heart", Bootstrap::SuitHeart),
+    ("suit-heart-fill", Bootstrap::SuitHeartFill),
+    ("suit-spade", Bootstrap::SuitSpade),
+    ("suit-spade-fill", Bootstrap::SuitSpadeFill),
+    ("suitcase", Bootstrap::Suitcase),
+    ("suitcase-fill", Bootstrap::SuitcaseFill),
+    ("suitcase-lg", Bootstrap::SuitcaseLg),
+    ("suitcase-lg-fill", Bootstrap::SuitcaseLgFill),
+    ("suitcase2", Bootstrap::Suitcasetwo),
+    ("suitcase2-fill", Bootstrap::SuitcasetwoFill),
+    ("sun-fill", Bootstrap::SunFill),
+    ("sunglasses", Bootstrap::Sunglasses),
+    ("sunrise", Bootstrap::Sunrise),
+    ("sunrise-fill", Bootstrap::SunriseFill),
+    ("sunset", Bootstrap::Sunset),
+    ("sunset-fill", Bootstrap::SunsetFill),
+    ("superscript", Bootstrap::Superscript),
+    ("symmetry-horizontal", Bootstrap::SymmetryHorizontal),
+    ("symmetry-vertical", Bootstrap::SymmetryVertical),
+    ("table", Bootstrap::Table),
+    ("tablet", Bootstrap::Tablet),
+    ("tablet-fill", Bootstrap::TabletFill),
+    ("tablet-landscape", Bootstrap::TabletLandscape),
+    ("tablet-landscape-fill", Bootstrap::TabletLandscapeFill),
+    ("tag", Bootstrap::Tag),
+    ("tag-fill", Bootstrap::TagFill),
+    ("tags", Bootstrap::Tags),
+    ("tags-fill", Bootstrap::TagsFill),
+    ("taxi-front", Bootstrap::TaxiFront),
+    ("taxi-front-fill", Bootstrap::TaxiFrontFill),
+    ("telegram", Bootstrap::Telegram),
+    ("telephone", Bootstrap::Telephone),
+    ("telephone-fill", Bootstrap::TelephoneFill),
+    ("telephone-forward", Bootstrap::TelephoneForward),
+    ("telephone-forward-fill", Bootstrap::TelephoneForwardFill),
+    ("telephone-inbound", Bootstrap::TelephoneInbound),
+    ("telephone-inbound-fill", Bootstrap::TelephoneInboundFill),
+    ("telephone-minus", Bootstrap::TelephoneMinus),
+    ("telephone-minus-fill", Bootstrap::TelephoneMinusFill),
+    ("telephone-outbound", Bootstrap::TelephoneOutbound),
+    ("telephone-outbound-fill", Bootstrap::TelephoneOutboundFill),
+    ("telephone-plus", Bootstrap::TelephonePlus),
+    ("telephone-plus-fill", Bootstrap::TelephonePlusFill),
+    ("telephone-x", Bootstrap::TelephoneX),
+    ("telephone-x-fill", Bootstrap::TelephoneXFill),
+    ("tencent-qq", Bootstrap::TencentQq),
+    ("terminal", Bootstrap::Terminal),
+    ("terminal-dash", Bootstrap::TerminalDash),
+    ("terminal-fill", Bootstrap::TerminalFill),
+    ("terminal-plus", Bootstrap::TerminalPlus),
+    ("terminal-split", Bootstrap::TerminalSplit),
+    ("terminal-x", Bootstrap::TerminalX),
+    ("text-center", Bootstrap::TextCenter),
+    ("text-indent-left", Bootstrap::TextIndentLeft),
+    ("text-indent-right", Bootstrap::TextIndentRight),
+    ("text-left", Bootstrap::TextLeft),
+    ("text-paragraph", Bootstrap::TextParagraph),
+    ("text-right", Bootstrap::TextRight),
+    ("text-wrap", Bootstrap::TextWrap),
+    ("textarea", Bootstrap::Textarea),
+    ("textarea-resize", Bootstrap::TextareaResize),
+    ("textarea-t", Bootstrap::TextareaT),
+    ("thermometer", Bootstrap::Thermometer),
+    ("thermometer-half", Bootstrap::ThermometerHalf),
+    ("thermometer-high", Bootstrap::ThermometerHigh),
+    ("thermometer-low", Bootstrap::ThermometerLow),
+    ("thermometer-snow", Bootstrap::ThermometerSnow),
+    ("thermometer-sun", Bootstrap::ThermometerSun),
+    ("threads", Bootstrap::Threads),
+    ("threads-fill", Bootstrap::ThreadsFill),
+    ("three-dots", Bootstrap::ThreeDots),
+    ("three-dots-vertical", Bootstrap::ThreeDotsVertical),
+    ("thunderbolt", Bootstrap::Thunderbolt),
+    ("thunderbolt-fill", Bootstrap::ThunderboltFill),
+    ("ticket", Bootstrap::Ticket),
+    ("ticket-detailed", Bootstrap::TicketDetailed),
+    ("ticket-detailed-fill", Bootstrap::TicketDetailedFill),
+    ("ticket-fill", Bootstrap::TicketFill),
+    ("ticket-perforated", Bootstrap::TicketPerforated),
+    ("ticket-perforated-fill", Bootstrap::TicketPerforatedFill),
+    ("tiktok", Bootstrap::Tiktok),
+    ("toggle-off", Bootstrap::ToggleOff),
+    ("toggle-on", Bootstrap::ToggleOn),
+    ("toggle2-off", Bootstrap::ToggletwoOff),
+    ("toggle2-on", Bootstrap::ToggletwoOn),
+    ("toggles", Bootstrap::Toggles),
+    ("toggles2", Bootstrap::Togglestwo),
+    ("tools", Bootstrap::Tools),
+    ("tornado", Bootstrap::Tornado),
+    ("train-freight-front", Bootstrap::TrainFreightFront),
+    ("train-freight-front-fill", Bootstrap::TrainFreightFrontFill),
+    ("train-front", Bootstrap::TrainFront),
+    ("train-front-fill", Bootstrap::TrainFrontFill),
+    ("train-lightrail-front", Bootstrap::TrainLightrailFront),
+    (
+        "train-lightrail-front-fill",
+        Bootstrap::TrainLightrailFrontFill,
+    ),
+    ("translate", Bootstrap::Translate),
+    ("transparency", Bootstrap::Transparency),
+    ("trash", Bootstrap::Trash),
+    ("trash-fill", Bootstrap::TrashFill),
+    ("trash2", Bootstrap::Trashtwo),
+    ("trash2-fill", Bootstrap::TrashtwoFill),
+    ("trash3", Bootstrap::Trashthree),
+    ("trash3-fill", Bootstrap::TrashthreeFill),
+    ("tree", Bootstrap::Tree),
+    ("tree-fill", Bootstrap::TreeFill),
+    ("trello", Bootstrap::Trello),
+    ("triangle", Bootstrap::Triangle),
+    ("triangle-fill", Bootstrap::TriangleFill),
+    ("triangle-half", Bootstrap::TriangleHalf),
+    ("trophy", Bootstrap::Trophy),
+    ("trophy-fill", Bootstrap::TrophyFill),
+    ("tropical-storm", Bootstrap::TropicalStorm),
+    ("truck", Bootstrap::Truck),
+    ("truck-flatbed", Bootstrap::TruckFlatbed),
+    ("truck-front", Bootstrap::TruckFront),
+    ("truck-front-fill", Bootstrap::TruckFrontFill),
+    ("tsunami", Bootstrap::Tsunami),
+    ("tv", Bootstrap::Tv),
+    ("tv-fill", Bootstrap::TvFill),
+    ("twitch", Bootstrap::Twitch),
+    ("twitter", Bootstrap::Twitter),
+    ("twitter-x", Bootstrap::TwitterX),
+    ("type", Bootstrap::Type),
+    ("type-bold", Bootstrap::TypeBold),
+    ("type-h1", Bootstrap::TypeHone),
+    ("type-h2", Bootstrap::TypeHtwo),
+    ("type-h3", Bootstrap::TypeHthree),
+    ("type-h4", Bootstrap::TypeHfour),
+    ("type-h5", Bootstrap::TypeHfive),
+    ("type-h6", Bootstrap::TypeHsix),
+    ("type-italic", Bootstrap::TypeItalic),
+    ("type-strikethrough", Bootstrap::TypeStrikethrough),
+    ("type-underline", Bootstrap::TypeUnderline),
+    ("ubuntu", Bootstrap::Ubuntu),
+    ("ui-checks", Bootstrap::UiChecks),
+    ("ui-checks-grid", Bootstrap::UiChecksGrid),
+    ("ui-radios", Bootstrap::UiRadios),
+    ("ui-radios-grid", Bootstrap::UiRadiosGrid),
+    ("umbrella", Bootstrap::Umbrella),
+    ("umbrella-fill", Bootstrap::UmbrellaFill),
+    ("unindent", Bootstrap::Unindent),
+    ("union", Bootstrap::Union),
+    ("unity", Bootstrap::Unity),
+    ("universal-access", Bootstrap::UniversalAccess),
+    ("universal-access-circle", Bootstrap::UniversalAccessCircle),
+    ("unlock", Bootstrap::Unlock),
+    ("unlock-fill", Bootstrap::UnlockFill),
+    ("upc", Bootstrap::Upc),
+    ("upc-scan", Bootstrap::UpcScan),
+    ("upload", Bootstrap::Upload),
+    ("usb", Bootstrap::Usb),
+    ("usb-c", Bootstrap::UsbC),
+    ("usb-c-fill", Bootstrap::UsbCFill),
+    ("usb-drive", Bootstrap::UsbDrive),
+    ("usb-drive-fill", Bootstrap::UsbDriveFill),
+    ("usb-fill", Bootstrap::UsbFill),
+    ("usb-micro", Bootstrap::UsbMicro),
+    ("usb-micro-fill", Bootstrap::UsbMicroFill),
+    ("usb-mini", Bootstrap::UsbMini),
+    ("usb-mini-fill", Bootstrap::UsbMiniFill),
+    ("usb-plug", Bootstrap::UsbPlug),
+    ("usb-plug-fill", Bootstrap::UsbPlugFill),
+    ("usb-symbol", Bootstrap::UsbSymbol),
+    ("valentine", Bootstrap::Valentine),
+    ("valentine2", Bootstrap::Valentinetwo),
+    ("vector-pen", Bootstrap::VectorPen),
+    ("view-list", Bootstrap::ViewList),
+    ("view-stacked", Bootstrap::ViewStacked),
+    ("vignette", Bootstrap::Vignette),
+    ("vimeo", Bootstrap::Vimeo),
+    ("vinyl", Bootstrap::Vinyl),
+    ("vinyl-fill", Bootstrap::VinylFill),
+    ("virus", Bootstrap::Virus),
+    ("virus2", Bootstrap::Virustwo),
+    ("voicemail", Bootstrap::Voicemail),
+    ("volume-down", Bootstrap::VolumeDown),
+    ("volume-down-fill", Bootstrap::VolumeDownFill),
+    ("volume-mute", Bootstrap::VolumeMute),
+    ("volume-mute-fill", Bootstrap::VolumeMuteFill),
+    ("volume-off", Bootstrap::VolumeOff),
+    ("volume-off-fill", Bootstrap::VolumeOffFill),
+    ("volume-up", Bootstrap::VolumeUp),
+    ("volume-up-fill", Bootstrap::VolumeUpFill),
+    ("vr", Bootstrap::Vr),
+    ("wallet", Bootstrap::Wallet),
+    ("wallet-fill", Bootstrap::WalletFill),
+    ("wallet2", Bootstrap::Wallettwo),
+    ("watch", Bootstrap::Watch),
+    ("water", Bootstrap::Water),
+    ("webcam", Bootstrap::Webcam),
+    ("webcam-fill", Bootstrap::WebcamFill),
+    ("wechat", Bootstrap::Wechat),
+    ("whatsapp", Bootstrap::Whatsapp),
+    ("wifi", Bootstrap::Wifi),
+    ("wifi-1", Bootstrap::WifiOne),
+    ("wifi-2", Bootstrap::WifiTwo),
+    ("wifi-off", Bootstrap::WifiOff),
+    ("wikipedia", Bootstrap::Wikipedia),
+    ("wind", Bootstrap::Wind),
+    ("window", Bootstrap::Window),
+    ("window-dash", Bootstrap::WindowDash),
+    ("window-desktop", Bootstrap::WindowDesktop),
+    ("window-dock", Bootstrap::WindowDock),
+    ("window-fullscreen", Bootstrap::WindowFullscreen),
+    ("window-plus", Bootstrap::WindowPlus),
+    ("window-sidebar", Bootstrap::WindowSidebar),
+    ("window-split", Bootstrap::WindowSplit),
+    ("window-stack", Bootstrap::WindowStack),
+    ("window-x", Bootstrap::WindowX),
+    ("windows", Bootstrap::Windows),
+    ("wordpress", Bootstrap::Wordpress),
+    ("wrench", Bootstrap::Wrench),
+    ("wrench-adjustable", Bootstrap::WrenchAdjustable),
+    (
+        "wrench-adjustable-circle",
+        Bootstrap::WrenchAdjustableCircle,
+    ),
+    (
+        "wrench-adjustable-circle-fill",
+        Bootstrap::WrenchAdjustableCircleFill,
+    ),
+    ("x", Bootstrap::X),
+    ("x-circle", Bootstrap::XCircle),
+    ("x-circle-fill", Bootstrap::XCircleFill),
+    ("x-diamond", Bootstrap::XDiamond),
+    ("x-diamond-fill", Bootstrap::XDiamondFill),
+    ("x-lg", Bootstrap::XLg),
+    ("x-octagon", Bootstrap::XOctagon),
+    ("x-octagon-fill", Bootstrap::XOctagonFill),
+    ("x-square", Bootstrap::XSquare),
+    ("x-square-fill", Bootstrap::XSquareFill),
+    ("xbox", Bootstrap::Xbox),
+    ("yelp", Bootstrap::Yelp),
+    ("yin-yang", Bootstrap::YinYang),
+    ("youtube", Bootstrap::Youtube),
+    ("zoom-in", Bootstrap::ZoomIn),
+    ("zoom-out", Bootstrap::ZoomOut),
+];
+
+impl Bootstrap {
+    /// Returns the icon with the given name in the Bootstrap Icons set, like
+    /// `"arrow-90deg-left"`, if there is one.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMES
+            .binary_search_by(|(other, _)| (*other).cmp(name))
+            .ok()
+            .map(|index| NAMES[index].1)
+    }
+
+    /// Returns the name of the icon in the Bootstrap Icons set.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Activity => "activity",
+            Self::Airplane => "airplane",
+            Self::AirplaneEngines => "airplane-engines",
+            Self::AirplaneEnginesFill => "airplane-engines-fill",
+            Self::AirplaneFill => "airplane-fill",
+            Self::Alarm => "alarm",
+            Self::AlarmFill => "alarm-fill",
+            Self::Alexa => "alexa",
+            Self::AlignBottom => "align-bottom",
+            Self::AlignCenter => "align-center",
+            Self::AlignEnd => "align-end",
+            Self::AlignMiddle => "align-middle",
+            Self::AlignStart => "align-start",
+            Self::AlignTop => "align-top",
+            Self::Alipay => "alipay",
+            Self::Alphabet => "alphabet",
+            Self::AlphabetUppercase => "alphabet-uppercase",
+            Self::Alt => "alt",
+            Self::Amazon => "amazon",
+            Self::Amd => "amd",
+            Self::Android => "android",
+            Self::Androidtwo => "android2",
+            Self::App => "app",
+            Self::AppIndicator => "app-indicator",
+            Self::Apple => "apple",
+            Self::Archive => "archive",
+            Self::ArchiveFill => "archive-fill",
+            Self::ArrowBarDown => "arrow-bar-down",
+            Self::ArrowBarLeft => "arrow-bar-left",
+            Self::ArrowBarRight => "arrow-bar-right",
+            Self::ArrowBarUp => "arrow-bar-up",
+            Self::ArrowClockwise => "arrow-clockwise",
+            Self::ArrowCounterclockwise => "arrow-counterclockwise",
+            Self::ArrowDown => "arrow-down",
+            Self::ArrowDownCircle => "arrow-down-circle",
+            Self::ArrowDownCircleFill => "arrow-down-circle-fill",
+            Self::ArrowDownLeft => "arrow-down-left",
+            Self::ArrowDownLeftCircle => "arrow-down-left-circle",
+            Self::ArrowDownLeftCircleFill => "arrow-down-left-circle-fill",
+            Self::ArrowDownLeftSquare => "arrow-down-left-square",
+            Self::ArrowDownLeftSquareFill => "arrow-down-left-square-fill",
+            Self::ArrowDownRight => "arrow-down-right",
+            Self::ArrowDownRightCircle => "arrow-down-right-circle",
+            Self::ArrowDownRightCircleFill => "arrow-down-right-circle-fill",
+            Self::ArrowDownRightSquare => "arrow-down-right-square",
+            Self::ArrowDownRightSquareFill => "arrow-down-right-square-fill",
+            Self::ArrowDownShort => "arrow-down-short",
+            Self::ArrowDownSquare => "arrow-down-square",
+            Self::ArrowDownSquareFill => "arrow-down-square-fill",
+            Self::ArrowDownUp => "arrow-down-up",
+            Self::ArrowLeft => "arrow-left",
+            Self::ArrowLeftCircle => "arrow-left-circle",
+            Self::ArrowLeftCircleFill => "arrow-left-circle-fill",
+            Self::ArrowLeftRight => "arrow-left-right",
+            Self::ArrowLeftShort => "arrow-left-short",
+            Self::ArrowLeftSquare => "arrow-left-square",
+            Self::ArrowLeftSquareFill => "arrow-left-square-fill",
+            Self::ArrowNinezerodegDown => "arrow-90deg-down",
+            Self::ArrowNinezerodegLeft => "arrow-90deg-left",
+            Self::ArrowNinezerodegRight => "arrow-90deg-right",
+            Self::ArrowNinezerodegUp => "arrow-90deg-up",
+            Self::ArrowRepeat => "arrow-repeat",
+            Self::ArrowReturnLeft => "arrow-return-left",
+            Self::ArrowReturnRight => "arrow-return-right",
+            Self::ArrowRight => "arrow-right",
+            Self::ArrowRightCircle => "arrow-right-circle",
+            Self::ArrowRightCircleFill => "arrow-right-circle-fill",
+            Self::ArrowRightShort => "arrow-right-short",
+            Self::ArrowRightSquare => "arrow-right-square",
+            Self::ArrowRightSquareFill => "arrow-right-square-fill",
+            Self::ArrowThroughHeart => "arrow-through-heart",
+            Self::ArrowThroughHeartFill => "arrow-through-heart-fill",
+            Self::ArrowUp => "arrow-up",
+            Self::ArrowUpCircle => "arrow-up-circle",
+            Self::ArrowUpCircleFill => "arrow-up-circle-fill",
+            Self::ArrowUpLeft => "arrow-up-left",
+            Self::ArrowUpLeftCircle => "arrow-up-left-circle",
+            Self::ArrowUpLeftCircleFill => "arrow-up-left-circle-fill",
+            Self::ArrowUpLeftSquare => "arrow-up-left-square",
+            Self::ArrowUpLeftSquareFill => "arrow-up-left-square-fill",
+            Self::ArrowUpRight => "arrow-up-right",
+            Self::ArrowUpRightCircle => "arrow-up-right-circle",
+            Self::ArrowUpRightCircleFill => "arrow-up-right-circle-fill",
+            Self::ArrowUpRightSquare => "arrow-up-right-square",
+            Self::ArrowUpRightSquareFill => "arrow-up-right-square-fill",
+            Self::ArrowUpShort => "arrow-up-short",
+            Self::ArrowUpSquare => "arrow-up-square",
+            Self::ArrowUpSquareFill => "arrow-up-square-fill",
+            Self::Arrows => "arrows",
+            Self::ArrowsAngleContract => "arrows-angle-contract",
+            Self::ArrowsAngleExpand => "arrows-angle-expand",
+            Self::ArrowsCollapse => "arrows-collapse",
+            Self::ArrowsCollapseVertical => "arrows-collapse-vertical",
+            Self::ArrowsExpand => "arrows-expand",
+            Self::ArrowsExpandVertical => "arrows-expand-vertical",
+            Self::ArrowsFullscreen => "arrows-fullscreen",
+            Self::ArrowsMove => "arrows-move",
+            Self::ArrowsVertical => "arrows-vertical",
+            Self::AspectRatio => "aspect-ratio",
+            Self::AspectRatioFill => "aspect-ratio-fill",
+            Self::Asterisk => "asterisk",
+            Self::At => "at",
+            Self::Award => "award",
+            Self::AwardFill => "award-fill",
+            Self::Back => "back",
+            Self::Backpack => "backpack",
+            Self::BackpackFill => "backpack-fill",
+            Self::Backpackfour => "backpack4",
+            Self::BackpackfourFill => "backpack4-fill",
+            Self::Backpackthree => "backpack3",
+            Self::BackpackthreeFill => "backpack3-fill",
+            Self::Backpacktwo => "backpack2",
+            Self::BackpacktwoFill => "backpack2-fill",
+            Self::Backspace => "backspace",
+            Self::BackspaceFill => "backspace-fill",
+            Self::BackspaceReverse => "backspace-reverse",
+            Self::BackspaceReverseFill => "backspace-reverse-fill",
+            Self::BadgeAd => "badge-ad",
+            Self::BadgeAdFill => "badge-ad-fill",
+            Self::BadgeAr => "badge-ar",
+            Self::BadgeArFill => "badge-ar-fill",
+            Self::BadgeCc => "badge-cc",
+            Self::BadgeCcFill => "badge-cc-fill",
+            Self::BadgeEightk => "badge-8k",
+            Self::BadgeEightkFill => "badge-8k-fill",
+            Self::BadgeFourk => "badge-4k",
+            Self::BadgeFourkFill => "badge-4k-fill",
+            Self::BadgeHd => "badge-hd",
+            Self::BadgeHdFill => "badge-hd-fill",
+            Self::BadgeSd => "badge-sd",
+            Self::BadgeSdFill => "badge-sd-fill",
+            Self::BadgeThreed => "badge-3d",
+            Self::BadgeThreedFill => "badge-3d-fill",
+            Self::BadgeTm => "badge-tm",
+            Self::BadgeTmFill => "badge-tm-fill",
+            Self::BadgeVo => "badge-vo",
+            Self::BadgeVoFill => "badge-vo-fill",
+            Self::BadgeVr => "badge-vr",
+            Self::BadgeVrFill => "badge-vr-fill",
+            Self::BadgeWc => "badge-wc",
+            Self::BadgeWcFill => "badge-wc-fill",
+            Self::Bag => "bag",
+            Self::BagCheck => "bag-check",
+            Self::BagCheckFill => "bag-check-fill",
+            Self::BagDash => "bag-dash",
+            Self::BagDashFill => "bag-dash-fill",
+            Self::BagFill => "bag-fill",
+            Self::BagHeart => "bag-heart",
+            Self::BagHeartFill => "bag-heart-fill",
+            Self::BagPlus => "bag-plus",
+            Self::BagPlusFill => "bag-plus-fill",
+            Self::BagX => "bag-x",
+            Self::BagXFill => "bag-x-fill",
+            Self::Balloon => "balloon",
+            Self::BalloonFill => "balloon-fill",
+            Self::BalloonHeart => "balloon-heart",
+            Self::BalloonHeartFill => "balloon-heart-fill",
+            Self::Ban => "ban",
+            Self::BanFill => "ban-fill",
+            Self::Bandaid => "bandaid",
+            Self::BandaidFill => "bandaid-fill",
+            Self::Bank => "bank",
+            Self::Banktwo => "bank2",
+            Self::BarChart => "bar-chart",
+            Self::BarChartFill => "bar-chart-fill",
+            Self::BarChartLine => "bar-chart-line",
+            Self::BarChartLineFill => "bar-chart-line-fill",
+            Self::BarChartSteps => "bar-chart-steps",
+            Self::Basket => "basket",
+            Self::BasketFill => "basket-fill",
+            Self::Basketthree => "basket3",
+            Self::BasketthreeFill => "basket3-fill",
+            Self::Baskettwo => "basket2",
+            Self::BaskettwoFill => "basket2-fill",
+            Self::Battery => "battery",
+            Self::BatteryCharging => "battery-charging",
+            Self::BatteryFull => "battery-full",
+            Self::BatteryHalf => "battery-half",
+            Self::Behance => "behance",
+            Self::Bell => "bell",
+            Self::BellFill => "bell-fill",
+            Self::BellSlash => "bell-slash",
+            Self::BellSlashFill => "bell-slash-fill",
+            Self::Bezier => "bezier",
+            Self::Beziertwo => "bezier2",
+            Self::Bicycle => "bicycle",
+            Self::Bing => "bing",
+            Self::Binoculars => "binoculars",
+            Self::BinocularsFill => "binoculars-fill",
+            Self::BlockquoteLeft => "blockquote-left",
+            Self::BlockquoteRight => "blockquote-right",
+            Self::Bluetooth => "bluetooth",
+            Self::BodyText => "body-text",
+            Self::Book => "book",
+            Self::BookFill => "book-fill",
+            Self::BookHalf => "book-half",
+            Self::Bookmark => "bookmark",
+            Self::BookmarkCheck => "bookmark-check",
+            Self::BookmarkCheckFill => "bookmark-check-fill",
+            Self::BookmarkDash => "bookmark-dash",
+            Self::BookmarkDashFill => "bookmark-dash-fill",
+            Self::BookmarkFill => "bookmark-fill",
+            Self::BookmarkHeart => "bookmark-heart",
+            Self::BookmarkHeartFill => "bookmark-heart-fill",
+            Self::BookmarkPlus => "bookmark-plus",
+            Self::BookmarkPlusFill => "bookmark-plus-fill",
+            Self::BookmarkStar => "bookmark-star",
+            Self::BookmarkStarFill => "bookmark-star-fill",
+            Self::BookmarkX => "bookmark-x",
+            Self::BookmarkXFill => "bookmark-x-fill",
+            Self::Bookmarks => "bookmarks",
+            Self::BookmarksFill => "bookmarks-fill",
+            Self::Bookshelf => "bookshelf",
+            Self::Boombox => "boombox",
+            Self::BoomboxFill => "boombox-fill",
+            Self::Bootstrap => "bootstrap",
+            Self::BootstrapFill => "bootstrap-fill",
+            Self::BootstrapReboot => "bootstrap-reboot",
+            Self::Border => "border",
+            Self::BorderAll => "border-all",
+            Self::BorderBottom => "border-bottom",
+            Self::BorderCenter => "border-center",
+            Self::BorderInner => "border-inner",
+            Self::BorderLeft => "border-left",
+            Self::BorderMiddle => "border-middle",
+            Self::BorderOuter => "border-outer",
+            Self::BorderRight => "border-right",
+            Self::BorderStyle => "border-style",
+            Self::BorderTop => "border-top",
+            Self::BorderWidth => "border-width",
+            Self::BoundingBox => "bounding-box",
+            Self::BoundingBoxCircles => "bounding-box-circles",
+            Self::Box => "box",
+            Self::BoxArrowDown => "box-arrow-down",
+            Self::BoxArrowDownLeft => "box-arrow-down-left",
+            Self::BoxArrowDownRight => "box-arrow-down-right",
+            Self::BoxArrowInDown => "box-arrow-in-down",
+            Self::BoxArrowInDownLeft => "box-arrow-in-down-left",
+            Self::BoxArrowInDownRight => "box-arrow-in-down-right",
+            Self::BoxArrowInLeft => "box-arrow-in-left",
+            Self::BoxArrowInRight => "box-arrow-in-right",
+            Self::BoxArrowInUp => "box-arrow-in-up",
+            Self::BoxArrowInUpLeft => "box-arrow-in-up-left",
+            Self::BoxArrowInUpRight => "box-arrow-in-up-right",
+            Self::BoxArrowLeft => "box-arrow-left",
+            Self::BoxArrowRight => "box-arrow-right",
+            Self::BoxArrowUp => "box-arrow-up",
+            Self::BoxArrowUpLeft => "box-arrow-up-left",
+            Self::BoxArrowUpRight => "box-arrow-up-right",
+            Self::BoxFill => "box-fill",
+            Self::BoxSeam => "box-seam",
+            Self::BoxSeamFill => "box-seam-fill",
+            Self::Boxes => "boxes",
+            Self::Boxtwo => "box2",
+            Self::BoxtwoFill => "box2-fill",
+            Self::BoxtwoHeart => "box2-heart",
+            Self::BoxtwoHeartFill => "box2-heart-fill",
+            Self::Braces => "braces",
+            Self::BracesAsterisk => "braces-asterisk",
+            Self::Bricks => "bricks",
+            Self::Briefcase => "briefcase",
+            Self::BriefcaseFill => "briefcase-fill",
+            Self::BrightnessAltHigh => "brightness-alt-high",
+            Self::BrightnessAltHighFill => "brightness-alt-high-fill",
+            Self::BrightnessAltLow => "brightness-alt-low",
+            Self::BrightnessAltLowFill => "brightness-alt-low-fill",
+            Self::BrightnessHigh => "brightness-high",
+            Self::BrightnessHighFill => "brightness-high-fill",
+            Self::BrightnessLow => "brightness-low",
+            Self::BrightnessLowFill => "brightness-low-fill",
+            Self::Brilliance => "brilliance",
+            Self::Broadcast => "broadcast",
+            Self::BroadcastPin => "broadcast-pin",
+            Self::BrowserChrome => "browser-chrome",
+            Self::BrowserEdge => "browser-edge",
+            Self::BrowserFirefox => "browser-firefox",
+            Self::BrowserSafari => "browser-safari",
+            Self::Brush => "brush",
+            Self::BrushFill => "brush-fill",
+            Self::Bucket => "bucket",
+            Self::BucketFill => "bucket-fill",
+            Self::Bug => "bug",
+            Self::BugFill => "bug-fill",
+            Self::Building => "building",
+            Self::BuildingAdd => "building-add",
+            Self::BuildingCheck => "building-check",
+            Self::BuildingDash => "building-dash",
+            Self::BuildingDown => "building-down",
+            Self::BuildingExclamation => "building-exclamation",
+            Self::BuildingFill => "building-fill",
+            Self::BuildingFillAdd => "building-fill-add",
+            Self::BuildingFillCheck => "building-fill-check",
+            Self::BuildingFillDash => "building-fill-dash",
+            Self::BuildingFillDown => "building-fill-down",
+            Self::BuildingFillExclamation => "building-fill-exclamation",
+            Self::BuildingFillGear => "building-fill-gear",
+            Self::BuildingFillLock => "building-fill-lock",
+            Self::BuildingFillSlash => "building-fill-slash",
+            Self::BuildingFillUp => "building-fill-up",
+            Self::BuildingFillX => "building-fill-x",
+            Self::BuildingGear => "building-gear",
+            Self::BuildingLock => "building-lock",
+            Self::BuildingSlash => "building-slash",
+            Self::BuildingUp => "building-up",
+            Self::BuildingX => "building-x",
+            Self::Buildings => "buildings",
+            Self::BuildingsFill => "buildings-fill",
+            Self::Bullseye => "bullseye",
+            Self::BusFront => "bus-front",
+            Self::BusFrontFill => "bus-front-fill",
+            Self::CCircle => "c-circle",
+            Self::CCircleFill => "c-circle-fill",
+            Self::CSquare => "c-square",
+            Self::CSquareFill => "c-square-fill",
+            Self::Cake => "cake",
+            Self::CakeFill => "cake-fill",
+            Self::Caketwo => "cake2",
+            Self::CaketwoFill => "cake2-fill",
+            Self::Calculator => "calculator",
+            Self::CalculatorFill => "calculator-fill",
+            Self::Calendar => "calendar",
+            Self::CalendarCheck => "calendar-check",
+            Self::CalendarCheckFill => "calendar-check-fill",
+            Self::CalendarDate => "calendar-date",
+            Self::CalendarDateFill => "calendar-date-fill",
+            Self::CalendarDay => "calendar-day",
+            Self::CalendarDayFill => "calendar-day-fill",
+            Self::CalendarEvent => "calendar-event",
+            Self::CalendarEventFill => "calendar-event-fill",
+            Self::CalendarFill => "calendar-fill",
+            Self::CalendarHeart => "calendar-heart",
+            Self::CalendarHeartFill => "calendar-heart-fill",
+            Self::CalendarMinus => "calendar-minus",
+            Self::CalendarMinusFill => "calendar-minus-fill",
+            Self::CalendarMonth => "calendar-month",
+            Self::CalendarMonthFill => "calendar-month-fill",
+            Self::CalendarPlus => "calendar-plus",
+            Self::CalendarPlusFill => "calendar-plus-fill",
+            Self::CalendarRange => "calendar-range",
+            Self::CalendarRangeFill => "calendar-range-fill",
+            Self::CalendarWeek => "calendar-week",
+            Self::CalendarWeekFill => "calendar-week-fill",
+            Self::CalendarX => "calendar-x",
+            Self::CalendarXFill => "calendar-x-fill",
+            Self::Calendarfour => "calendar4",
+            Self::CalendarfourEvent => "calendar4-event",
+            Self::CalendarfourRange => "calendar4-range",
+            Self::CalendarfourWeek => "calendar4-week",
+            Self::Calendarthree => "calendar3",
+            Self::CalendarthreeEvent => "calendar3-event",
+            Self::CalendarthreeEventFill => "calendar3-event-fill",
+            Self::CalendarthreeFill => "calendar3-fill",
+            Self::CalendarthreeRange => "calendar3-range",
+            Self::CalendarthreeRangeFill => "calendar3-range-fill",
+            Self::CalendarthreeWeek => "calendar3-week",
+            Self::CalendarthreeWeekFill => "calendar3-week-fill",
+            Self::Calendartwo => "calendar2",
+            Self::CalendartwoCheck => "calendar2-check",
+            Self::CalendartwoCheckFill => "calendar2-check-fill",
+            Self::CalendartwoDate => "calendar2-date",
+            Self::CalendartwoDateFill => "calendar2-date-fill",
+            Self::CalendartwoDay => "calendar2-day",
+            Self::CalendartwoDayFill => "calendar2-day-fill",
+            Self::CalendartwoEvent => "calendar2-event",
+            Self::CalendartwoEventFill => "calendar2-event-fill",
+            Self::CalendartwoFill => "calendar2-fill",
+            Self::CalendartwoHeart => "calendar2-heart",
+            Self::CalendartwoHeartFill => "calendar2-heart-fill",
+            Self::CalendartwoMinus => "calendar2-minus",
+            Self::CalendartwoMinusFill => "calendar2-minus-fill",
+            Self::CalendartwoMonth => "calendar2-month",
+            Self::CalendartwoMonthFill => "calendar2-month-fill",
+            Self::CalendartwoPlus => "calendar2-plus",
+            Self::CalendartwoPlusFill => "calendar2-plus-fill",
+            Self::CalendartwoRange => "calendar2-range",
+            Self::CalendartwoRangeFill => "calendar2-range-fill",
+            Self::CalendartwoWeek => "calendar2-week",
+            Self::CalendartwoWeekFill => "calendar2-week-fill",
+            Self::CalendartwoX => "calendar2-x",
+            Self::CalendartwoXFill => "calendar2-x-fill",
+            Self::Camera => "camera",
+            Self::CameraFill => "camera-fill",
+            Self::CameraReels => "camera-reels",
+            Self::CameraReelsFill => "camera-reels-fill",
+            Self::CameraVideo => "camera-video",
+            Self::CameraVideoFill => "camera-video-fill",
+            Self::CameraVideoOff => "camera-video-off",
+            Self::CameraVideoOffFill => "camera-video-off-fill",
+            Self::Cameratwo => "camera2",
+            Self::Capslock => "capslock",
+            Self::CapslockFill => "capslock-fill",
+            Self::Capsule => "capsule",
+            Self::CapsulePill => "capsule-pill",
+            Self::CarFront => "car-front",
+            Self::CarFrontFill => "car-front-fill",
+            Self::CardChecklist => "card-checklist",
+            Self::CardHeading => "card-heading",
+            Self::CardImage => "card-image",
+            Self::CardList => "card-list",
+            Self::CardText => "card-text",
+            Self::CaretDown => "caret-down",
+            Self::CaretDownFill => "caret-down-fill",
+            Self::CaretDownSquare => "caret-down-square",
+            Self::CaretDownSquareFill => "caret-down-square-fill",
+            Self::CaretLeft => "caret-left",
+            Self::CaretLeftFill => "caret-left-fill",
+            Self::CaretLeftSquare => "caret-left-square",
+            Self::CaretLeftSquareFill => "caret-left-square-fill",
+            Self::CaretRight => "caret-right",
+            Self::CaretRightFill => "caret-right-fill",
+            Self::CaretRightSquare => "caret-right-square",
+            Self::CaretRightSquareFill => "caret-right-square-fill",
+            Self::CaretUp => "caret-up",
+            Self::CaretUpFill => "caret-up-fill",
+            Self::CaretUpSquare => "caret-up-square",
+            Self::CaretUpSquareFill => "caret-up-square-fill",
+            Self::Cart => "cart",
+            Self::CartCheck => "cart-check",
+            Self::CartCheckFill => "cart-check-fill",
+            Self::CartDash => "cart-dash",
+            Self::CartDashFill => "cart-dash-fill",
+            Self::CartFill => "cart-fill",
+            Self::CartPlus => "cart-plus",
+            Self::CartPlusFill => "cart-plus-fill",
+            Self::CartX => "cart-x",
+            Self::CartXFill => "cart-x-fill",
+            Self::Cartfour => "cart4",
+            Self::Cartthree => "cart3",
+            Self::Carttwo => "cart2",
+            Self::Cash => "cash",
+            Self::CashCoin => "cash-coin",
+            Self::CashStack => "cash-stack",
+            Self::Cassette => "cassette",
+            Self::CassetteFill => "cassette-fill",
+            Self::Cast => "cast",
+            Self::CcCircle => "cc-circle",
+            Self::CcCircleFill => "cc-circle-fill",
+            Self::CcSquare => "cc-square",
+            Self::CcSquareFill => "cc-square-fill",
+            Self::Chat => "chat",
+            Self::ChatDots => "chat-dots",
+            Self::ChatDotsFill => "chat-dots-fill",
+            Self::ChatFill => "chat-fill",
+            Self::ChatHeart => "chat-heart",
+            Self::ChatHeartFill => "chat-heart-fill",
+            Self::ChatLeft => "chat-left",
+            Self::ChatLeftDots => "chat-left-dots",
+            Self::ChatLeftDotsFill => "chat-left-dots-fill",
+            Self::ChatLeftFill => "chat-left-fill",
+            Self::ChatLeftHeart => "chat-left-heart",
+            Self::ChatLeftHeartFill => "chat-left-heart-fill",
+            Self::ChatLeftQuote => "chat-left-quote",
+            Self::ChatLeftQuoteFill => "chat-left-quote-fill",
+            Self::ChatLeftText => "chat-left-text",
+            Self::ChatLeftTextFill => "chat-left-text-fill",
+            Self::ChatQuote => "chat-quote",
+            Self::ChatQuoteFill => "chat-quote-fill",
+            Self::ChatRight => "chat-right",
+            Self::ChatRightDots => "chat-right-dots",
+            Self::ChatRightDotsFill => "chat-right-dots-fill",
+            Self::ChatRightFill => "chat-right-fill",
+            Self::ChatRightHeart => "chat-right-heart",
+            Self::ChatRightHeartFill => "chat-right-heart-fill",
+            Self::ChatRightQuote => "chat-right-quote",
+            Self::ChatRightQuoteFill => "chat-right-quote-fill",
+            Self::ChatRightText => "chat-right-text",
+            Self::ChatRightTextFill => "chat-right-text-fill",
+            Self::ChatSquare => "chat-square",
+            Self::ChatSquareDots => "chat-square-dots",
+            Self::ChatSquareDotsFill => "chat-square-dots-fill",
+            Self::ChatSquareFill => "chat-square-fill",
+            Self::ChatSquareHeart => "chat-square-heart",
+            Self::ChatSquareHeartFill => "chat-square-heart-fill",
+            Self::ChatSquareQuote => "chat-square-quote",
+            Self::ChatSquareQuoteFill => "chat-square-quote-fill",
+            Self::ChatSquareText => "chat-square-text",
+            Self::ChatSquareTextFill => "chat-square-text-fill",
+            Self::ChatText => "chat-text",
+            Self::ChatTextFill => "chat-text-fill",
+            Self::Check => "check",
+            Self::CheckAll => "check-all",
+            Self::CheckCircle => "check-circle",
+            Self::CheckCircleFill => "check-circle-fill",
+            Self::CheckLg => "check-lg",
+            Self::CheckSquare => "check-square",
+            Self::CheckSquareFill => "check-square-fill",
+            Self::Checktwo => "check2",
+            Self::ChecktwoAll => "check2-all",
+            Self::ChecktwoCircle => "check2-circle",
+            Self::ChecktwoSquare => "check2-square",
+            Self::ChevronBarContract => "chevron-bar-contract",
+            Self::ChevronBarDown => "chevron-bar-down",
+            Self::ChevronBarExpand => "chevron-bar-expand",
+            Self::ChevronBarLeft => "chevron-bar-left",
+            Self::ChevronBarRight => "chevron-bar-right",
+            Self::ChevronBarUp => "chevron-bar-up",
+            Self::ChevronCompactDown => "chevron-compact-down",
+            Self::ChevronCompactLeft => "chevron-compact-left",
+            Self::ChevronCompactRight => "chevron-compact-right",
+            Self::ChevronCompactUp => "chevron-compact-up",
+            Self::ChevronContract => "chevron-contract",
+            Self::ChevronDoubleDown => "chevron-double-down",
+            Self::ChevronDoubleLeft => "chevron-double-left",
+            Self::ChevronDoubleRight => "chevron-double-right",
+            Self::ChevronDoubleUp => "chevron-double-up",
+            Self::ChevronDown => "chevron-down",
+            Self::ChevronExpand => "chevron-expand",
+            Self::ChevronLeft => "chevron-left",
+            Self::ChevronRight => "chevron-right",
+            Self::ChevronUp => "chevron-up",
+            Self::Circle => "circle",
+            Self::CircleFill => "circle-fill",
+            Self::CircleHalf => "circle-half",
+            Self::CircleSquare => "circle-square",
+            Self::Clipboard => "clipboard",
+            Self::ClipboardCheck => "clipboard-check",
+            Self::ClipboardCheckFill => "clipboard-check-fill",
+            Self::ClipboardData => "clipboard-data",
+            Self::ClipboardDataFill => "clipboard-data-fill",
+            Self::ClipboardFill => "clipboard-fill",
+            Self::ClipboardHeart => "clipboard-heart",
+            Self::ClipboardHeartFill => "clipboard-heart-fill",
+            Self::ClipboardMinus => "clipboard-minus",
+            Self::ClipboardMinusFill => "clipboard-minus-fill",
+            Self::ClipboardPlus => "clipboard-plus",
+            Self::ClipboardPlusFill => "clipboard-plus-fill",
+            Self::ClipboardPulse => "clipboard-pulse",
+            Self::ClipboardX => "clipboard-x",
+            Self::ClipboardXFill => "clipboard-x-fill",
+            Self::Clipboardtwo => "clipboard2",
+            Self::ClipboardtwoCheck => "clipboard2-check",
+            Self::ClipboardtwoCheckFill => "clipboard2-check-fill",
+            Self::ClipboardtwoData => "clipboard2-data",
+            Self::ClipboardtwoDataFill => "clipboard2-data-fill",
+            Self::ClipboardtwoFill => "clipboard2-fill",
+            Self::ClipboardtwoHeart => "clipboard2-heart",
+            Self::ClipboardtwoHeartFill => "clipboard2-heart-fill",
+            Self::ClipboardtwoMinus => "clipboard2-minus",
+            Self::ClipboardtwoMinusFill => "clipboard2-minus-fill",
+            Self::ClipboardtwoPlus => "clipboard2-plus",
+            Self::ClipboardtwoPlusFill => "clipboard2-plus-fill",
+            Self::ClipboardtwoPulse => "clipboard2-pulse",
+            Self::ClipboardtwoPulseFill => "clipboard2-pulse-fill",
+            Self::ClipboardtwoX => "clipboard2-x",
+            Self::ClipboardtwoXFill => "clipboard2-x-fill",
+            Self::Clock => "clock",
+            Self::ClockFill => "clock-fill",
+            Self::ClockHistory => "clock-history",
+            Self::Cloud => "cloud",
+            Self::CloudArrowDown => "cloud-arrow-down",
+            Self::CloudArrowDownFill => "cloud-arrow-down-fill",
+            Self::CloudArrowUp => "cloud-arrow-up",
+            Self::CloudArrowUpFill => "cloud-arrow-up-fill",
+            Self::CloudCheck => "cloud-check",
+            Self::CloudCheckFill => "cloud-check-fill",
+            Self::CloudDownload => "cloud-download",
+            Self::CloudDownloadFill => "cloud-download-fill",
+            Self::CloudDrizzle => "cloud-drizzle",
+            Self::CloudDrizzleFill => "cloud-drizzle-fill",
+            Self::CloudFill => "cloud-fill",
+            Self::CloudFog => "cloud-fog",
+            Self::CloudFogFill => "cloud-fog-fill",
+            Self::CloudFogtwo => "cloud-fog2",
+            Self::CloudFogtwoFill => "cloud-fog2-fill",
+            Self::CloudHail => "cloud-hail",
+            Self::CloudHailFill => "cloud-hail-fill",
+            Self::CloudHaze => "cloud-haze",
+            Self::CloudHazeFill => "cloud-haze-fill",
+            Self::CloudHazetwo => "cloud-haze2",
+            Self::CloudHazetwoFill => "cloud-haze2-fill",
+            Self::CloudLightning => "cloud-lightning",
+            Self::CloudLightningFill => "cloud-lightning-fill",
+            Self::CloudLightningRain => "cloud-lightning-rain",
+            Self::CloudLightningRainFill => "cloud-lightning-rain-fill",
+            Self::CloudMinus => "cloud-minus",
+            Self::CloudMinusFill => "cloud-minus-fill",
+            Self::CloudMoon => "cloud-moon",
+            Self::CloudMoonFill => "cloud-moon-fill",
+            Self::CloudPlus => "cloud-plus",
+            Self::CloudPlusFill => "cloud-plus-fill",
+            Self::CloudRain => "cloud-rain",
+            Self::CloudRainFill => "cloud-rain-fill",
+            Self::CloudRainHeavy => "cloud-rain-heavy",
+            Self::CloudRainHeavyFill => "cloud-rain-heavy-fill",
+            Self::CloudSlash => "cloud-slash",
+            Self::CloudSlashFill => "cloud-slash-fill",
+            Self::CloudSleet => "cloud-sleet",
+            Self::CloudSleetFill => "cloud-sleet-fill",
+            Self::CloudSnow => "cloud-snow",
+            Self::CloudSnowFill => "cloud-snow-fill",
+            Self::CloudSun => "cloud-sun",
+            Self::CloudSunFill => "cloud-sun-fill",
+            Self::CloudUpload => "cloud-upload",
+            Self::CloudUploadFill => "cloud-upload-fill",
+            Self::Clouds => "clouds",
+            Self::CloudsFill => "clouds-fill",
+            Self::Cloudy => "cloudy",
+            Self::CloudyFill => "cloudy-fill",
+            Self::Code => "code",
+            Self::CodeSlash => "code-slash",
+            Self::CodeSquare => "code-square",
+            Self::Coin => "coin",
+            Self::Collection => "collection",
+            Self::CollectionFill => "collection-fill",
+            Self::CollectionPlay => "collection-play",
+            Self::CollectionPlayFill => "collection-play-fill",
+            Self::Columns => "columns",
+            Self::ColumnsGap => "columns-gap",
+            Self::Command => "command",
+            Self::Compass => "compass",
+            Self::CompassFill => "compass-fill",
+            Self::Cone => "cone",
+            Self::ConeStriped => "cone-striped",
+            Self::Controller => "controller",
+            Self::Cookie => "cookie",
+            Self::Copy => "copy",
+            Self::Cpu => "cpu",
+            Self::CpuFill => "cpu-fill",
+            Self::CreditCard => "credit-card",
+            Self::CreditCardFill => "credit-card-fill",
+            Self::CreditCardTwoBack => "credit-card-2-back",
+            Self::CreditCardTwoBackFill => "credit-card-2-back-fill",
+            Self::CreditCardTwoFront => "credit-card-2-front",
+            Self::CreditCardTwoFrontFill => "credit-card-2-front-fill",
+            Self::Crop => "crop",
+            Self::Crosshair => "crosshair",
+            Self::Crosshairtwo => "crosshair2",
+            Self::Cup => "cup",
+            Self::CupFill => "cup-fill",
+            Self::CupHot => "cup-hot",
+            Self::CupHotFill => "cup-hot-fill",
+            Self::CupStraw => "cup-straw",
+            Self::CurrencyBitcoin => "currency-bitcoin",
+            Self::CurrencyDollar => "currency-dollar",
+            Self::CurrencyEuro => "currency-euro",
+            Self::CurrencyExchange => "currency-exchange",
+            Self::CurrencyPound => "currency-pound",
+            Self::CurrencyRupee => "currency-rupee",
+            Self::CurrencyYen => "currency-yen",
+            Self::Cursor => "cursor",
+            Self::CursorFill => "cursor-fill",
+            Self::CursorText => "cursor-text",
+            Self::Dash => "dash",
+            Self::DashCircle => "dash-circle",
+            Self::DashCircleDotted => "dash-circle-dotted",
+            Self::DashCircleFill => "dash-circle-fill",
+            Self::DashLg => "dash-lg",
+            Self::DashSquare => "dash-square",
+            Self::DashSquareDotted => "dash-square-dotted",
+            Self::DashSquareFill => "dash-square-fill",
+            Self::Database => "database",
+            Self::DatabaseAdd => "database-add",
+            Self::DatabaseCheck => "database-check",
+            Self::DatabaseDash => "database-dash",
+            Self::DatabaseDown => "database-down",
+            Self::DatabaseExclamation => "database-exclamation",
+            Self::DatabaseFill => "database-fill",
+            Self::DatabaseFillAdd => "database-fill-add",
+            Self::DatabaseFillCheck => "database-fill-check",
+            Self::DatabaseFillDash => "database-fill-dash",
+            Self::DatabaseFillDown => "database-fill-down",
+            Self::DatabaseFillExclamation => "database-fill-exclamation",
+            Self::DatabaseFillGear => "database-fill-gear",
+            Self::DatabaseFillLock => "database-fill-lock",
+            Self::DatabaseFillSlash => "database-fill-slash",
+            Self::DatabaseFillUp => "database-fill-up",
+            Self::DatabaseFillX => "database-fill-x",
+            Self::DatabaseGear => "database-gear",
+            Self::DatabaseLock => "database-lock",
+            Self::DatabaseSlash => "database-slash",
+            Self::DatabaseUp => "database-up",
+            Self::DatabaseX => "database-x",
+            Self::DeviceHdd => "device-hdd",
+            Self::DeviceHddFill => "device-hdd-fill",
+            Self::DeviceSsd => "device-ssd",
+            Self::DeviceSsdFill => "device-ssd-fill",
+            Self::DiagramThree => "diagram-3",
+            Self::DiagramThreeFill => "diagram-3-fill",
+            Self::DiagramTwo => "diagram-2",
+            Self::DiagramTwoFill => "diagram-2-fill",
+            Self::Diamond => "diamond",
+            Self::DiamondFill => "diamond-fill",
+            Self::DiamondHalf => "diamond-half",
+            Self::DiceFive => "dice-5",
+            Self::DiceFiveFill => "dice-5-fill",
+            Self::DiceFour => "dice-4",
+            Self::DiceFourFill => "dice-4-fill",
+            Self::DiceOne => "dice-1",
+            Self::DiceOneFill => "dice-1-fill",
+            Self::DiceSix => "dice-6",
+            Self::DiceSixFill => "dice-6-fill",
+            Self::DiceThree => "dice-3",
+            Self::DiceThreeFill => "dice-3-fill",
+            Self::DiceTwo => "dice-2",
+            Self::DiceTwoFill => "dice-2-fill",
+            Self::Disc => "disc",
+            Self::DiscFill => "disc-fill",
+            Self::Discord => "discord",
+            Self::Display => "display",
+            Self::DisplayFill => "display-fill",
+            Self::Displayport => "displayport",
+            Self::DisplayportFill => "displayport-fill",
+            Self::DistributeHorizontal => "distribute-horizontal",
+            Self::DistributeVertical => "distribute-vertical",
+            Self::DoorClosed => "door-closed",
+            Self::DoorClosedFill => "door-closed-fill",
+            Self::DoorOpen => "door-open",
+            Self::DoorOpenFill => "door-open-fill",
+            Self::Dot => "dot",
+            Self::Download => "download",
+            Self::Dpad => "dpad",
+            Self::DpadFill => "dpad-fill",
+            Self::Dribbble => "dribbble",
+            Self::Dropbox => "dropbox",
+            Self::Droplet => "droplet",
+            Self::DropletFill => "droplet-fill",
+            Self::DropletHalf => "droplet-half",
+            Self::Duffle => "duffle",
+            Self::DuffleFill => "duffle-fill",
+            Self::Ear => "ear",
+            Self::EarFill => "ear-fill",
+            Self::Earbuds => "earbuds",
+            Self::Easel => "easel",
+            Self::EaselFill => "easel-fill",
+            Self::Easelthree => "easel3",
+            Self::EaselthreeFill => "easel3-fill",
+            Self::Easeltwo => "easel2",
+            Self::EaseltwoFill => "easel2-fill",
+            Self::Egg => "egg",
+            Self::EggFill => "egg-fill",
+            Self::EggFried => "egg-fried",
+            Self::EightCircle => "8-circle",
+            Self::EightCircleFill => "8-circle-fill",
+            Self::EightSquare => "8-square",
+            Self::EightSquareFill => "8-square-fill",
+            Self::Eject => "eject",
+            Self::EjectFill => "eject-fill",
+            Self::EmojiAngry => "emoji-angry",
+            Self::EmojiAngryFill => "emoji-angry-fill",
+            Self::EmojiAstonished => "emoji-astonished",
+            Self::EmojiAstonishedFill => "emoji-astonished-fill",
+            Self::EmojiDizzy => "emoji-dizzy",
+            Self::EmojiDizzyFill => "emoji-dizzy-fill",
+            Self::EmojiExpressionless => "emoji-expressionless",
+            Self::EmojiExpressionlessFill => "emoji-expressionless-fill",
+            Self::EmojiFrown => "emoji-frown",
+            Self::EmojiFrownFill => "emoji-frown-fill",
+            Self::EmojiGrimace => "emoji-grimace",
+            Self::EmojiGrimaceFill => "emoji-grimace-fill",
+            Self::EmojiGrin => "emoji-grin",
+            Self::EmojiGrinFill => "emoji-grin-fill",
+            Self::EmojiHeartEyes => "emoji-heart-eyes",
+            Self::EmojiHeartEyesFill => "emoji-heart-eyes-fill",
+            Self::EmojiKiss => "emoji-kiss",
+            Self::EmojiKissFill => "emoji-kiss-fill",
+            Self::EmojiLaughing => "emoji-laughing",
+            Self::EmojiLaughingFill => "emoji-laughing-fill",
+            Self::EmojiNeutral => "emoji-neutral",
+            Self::EmojiNeutralFill => "emoji-neutral-fill",
+            Self::EmojiSmile => "emoji-smile",
+            Self::EmojiSmileFill => "emoji-smile-fill",
+            Self::EmojiSmileUpsideDown => "emoji-smile-upside-down",
+            Self::EmojiSmileUpsideDownFill => "emoji-smile-upside-down-fill",
+            Self::EmojiSunglasses => "emoji-sunglasses",
+            Self::EmojiSunglassesFill => "emoji-sunglasses-fill",
+            Self::EmojiSurprise => "emoji-surprise",
+            Self::EmojiSurpriseFill => "emoji-surprise-fill",
+            Self::EmojiTear => "emoji-tear",
+            Self::EmojiTearFill => "emoji-tear-fill",
+            Self::EmojiWink => "emoji-wink",
+            Self::EmojiWinkFill => "emoji-wink-fill",
+            Self::Envelope => "envelope",
+            Self::EnvelopeArrowDown => "envelope-arrow-down",
+            Self::EnvelopeArrowDownFill => "envelope-arrow-down-fill",
+            Self::EnvelopeArrowUp => "envelope-arrow-up",
+            Self::EnvelopeArrowUpFill => "envelope-arrow-up-fill",
+            Self::EnvelopeAt => "envelope-at",
+            Self::EnvelopeAtFill => "envelope-at-fill",
+            Self::EnvelopeCheck => "envelope-check",
+            Self::EnvelopeCheckFill => "envelope-check-fill",
+            Self::EnvelopeDash => "envelope-dash",
+            Self::EnvelopeDashFill => "envelope-dash-fill",
+            Self::EnvelopeExclamation => "envelope-exclamation",
+            Self::EnvelopeExclamationFill => "envelope-exclamation-fill",
+            Self::EnvelopeFill => "envelope-fill",
+            Self::EnvelopeHeart => "envelope-heart",
+            Self::EnvelopeHeartFill => "envelope-heart-fill",
+            Self::EnvelopeOpen => "envelope-open",
+            Self::EnvelopeOpenFill => "envelope-open-fill",
+            Self::EnvelopeOpenHeart => "envelope-open-heart",
+            Self::EnvelopeOpenHeartFill => "envelope-open-heart-fill",
+            Self::EnvelopePaper => "envelope-paper",
+            Self::EnvelopePaperFill => "envelope-paper-fill",
+            Self::EnvelopePaperHeart => "envelope-paper-heart",
+            Self::EnvelopePaperHeartFill => "envelope-paper-heart-fill",
+            Self::EnvelopePlus => "envelope-plus",
+            Self::EnvelopePlusFill => "envelope-plus-fill",
+            Self::EnvelopeSlash => "envelope-slash",
+            Self::EnvelopeSlashFill => "envelope-slash-fill",
+            Self::EnvelopeX => "envelope-x",
+            Self::EnvelopeXFill => "envelope-x-fill",
+            Self::Eraser => "eraser",
+            Self::EraserFill => "eraser-fill",
+            Self::Escape => "escape",
+            Self::Ethernet => "ethernet",
+            Self::EvFront => "ev-front",
+            Self::EvFrontFill => "ev-front-fill",
+            Self::EvStation => "ev-station",
+            Self::EvStationFill => "ev-station-fill",
+            Self::Exclamation => "exclamation",
+            Self::ExclamationCircle => "exclamation-circle",
+            Self::ExclamationCircleFill => "exclamation-circle-fill",
+            Self::ExclamationDiamond => "exclamation-diamond",
+            Self::ExclamationDiamondFill => "exclamation-diamond-fill",
+            Self::ExclamationLg => "exclamation-lg",
+            Self::ExclamationOctagon => "exclamation-octagon",
+            Self::ExclamationOctagonFill => "exclamation-octagon-fill",
+            Self::ExclamationSquare => "exclamation-square",
+            Self::ExclamationSquareFill => "exclamation-square-fill",
+            Self::ExclamationTriangle => "exclamation-triangle",
+            Self::ExclamationTriangleFill => "exclamation-triangle-fill",
+            Self::Exclude => "exclude",
+            Self::Explicit => "explicit",
+            Self::ExplicitFill => "explicit-fill",
+            Self::Exposure => "exposure",
+            Self::Eye => "eye",
+            Self::EyeFill => "eye-fill",
+            Self::EyeSlash => "eye-slash",
+            Self::EyeSlashFill => "eye-slash-fill",
+            Self::Eyedropper => "eyedropper",
+            Self::Eyeglasses => "eyeglasses",
+            Self::Facebook => "facebook",
+            Self::Fan => "fan",
+            Self::FastForward => "fast-forward",
+            Self::FastForwardBtn => "fast-forward-btn",
+            Self::FastForwardBtnFill => "fast-forward-btn-fill",
+            Self::FastForwardCircle => "fast-forward-circle",
+            Self::FastForwardCircleFill => "fast-forward-circle-fill",
+            Self::FastForwardFill => "fast-forward-fill",
+            Self::Feather => "feather",
+            Self::Feathertwo => "feather2",
+            Self::File => "file",
+            Self::FileArrowDown => "file-arrow-down",
+            Self::FileArrowDownFill => "file-arrow-down-fill",
+            Self::FileArrowUp => "file-arrow-up",
+            Self::FileArrowUpFill => "file-arrow-up-fill",
+            Self::FileBarGraph => "file-bar-graph",
+            Self::FileBarGraphFill => "file-bar-graph-fill",
+            Self::FileBinary => "file-binary",
+            Self::FileBinaryFill => "file-binary-fill",
+            Self::FileBreak => "file-break",
+            Self::FileBreakFill => "file-break-fill",
+            Self::FileCheck => "file-check",
+            Self::FileCheckFill => "file-check-fill",
+            Self::FileCode => "file-code",
+            Self::FileCodeFill => "file-code-fill",
+            Self::FileDiff => "file-diff",
+            Self::FileDiffFill => "file-diff-fill",
+            Self::FileEarmark => "file-earmark",
+            Self::FileEarmarkArrowDown => "file-earmark-arrow-down",
+            Self::FileEarmarkArrowDownFill => "file-earmark-arrow-down-fill",
+            Self::FileEarmarkArrowUp => "file-earmark-arrow-up",
+            Self::FileEarmarkArrowUpFill => "file-earmark-arrow-up-fill",
+            Self::FileEarmarkBarGraph => "file-earmark-bar-graph",
+            Self::FileEarmarkBarGraphFill => "file-earmark-bar-graph-fill",
+            Self::FileEarmarkBinary => "file-earmark-binary",
+            Self::FileEarmarkBinaryFill => "file-earmark-binary-fill",
+            Self::FileEarmarkBreak => "file-earmark-break",
+            Self::FileEarmarkBreakFill => "file-earmark-break-fill",
+            Self::FileEarmarkCheck => "file-earmark-check",
+            Self::FileEarmarkCheckFill => "file-earmark-check-fill",
+            Self::FileEarmarkCode => "file-earmark-code",
+            Self::FileEarmarkCodeFill => "file-earmark-code-fill",
+            Self::FileEarmarkDiff => "file-earmark-diff",
+            Self::FileEarmarkDiffFill => "file-earmark-diff-fill",
+            Self::FileEarmarkEasel => "file-earmark-easel",
+            Self::FileEarmarkEaselFill => "file-earmark-easel-fill",
+            Self::FileEarmarkExcel => "file-earmark-excel",
+            Self::FileEarmarkExcelFill => "file-earmark-excel-fill",
+            Self::FileEarmarkFill => "file-earmark-fill",
+            Self::FileEarmarkFont => "file-earmark-font",
+            Self::FileEarmarkFontFill => "file-earmark-font-fill",
+            Self::FileEarmarkImage => "file-earmark-image",
+            Self::FileEarmarkImageFill => "file-earmark-image-fill",
+            Self::FileEarmarkLock => "file-earmark-lock",
+            Self::FileEarmarkLockFill => "file-earmark-lock-fill",
+            Self::FileEarmarkLocktwo => "file-earmark-lock2",
+            Self::FileEarmarkLocktwoFill => "file-earmark-lock2-fill",
+            Self::FileEarmarkMedical => "file-earmark-medical",
+            Self::FileEarmarkMedicalFill => "file-earmark-medical-fill",
+            Self::FileEarmarkMinus => "file-earmark-minus",
+            Self::FileEarmarkMinusFill => "file-earmark-minus-fill",
+            Self::FileEarmarkMusic => "file-earmark-music",
+            Self::FileEarmarkMusicFill => "file-earmark-music-fill",
+            Self::FileEarmarkPdf => "file-earmark-pdf",
+            Self::FileEarmarkPdfFill => "file-earmark-pdf-fill",
+            Self::FileEarmarkPerson => "file-earmark-person",
+            Self::FileEarmarkPersonFill => "file-earmark-person-fill",
+            Self::FileEarmarkPlay => "file-earmark-play",
+            Self::FileEarmarkPlayFill => "file-earmark-play-fill",
+            Self::FileEarmarkPlus => "file-earmark-plus",
+            Self::FileEarmarkPlusFill => "file-earmark-plus-fill",
+            Self::FileEarmarkPost => "file-earmark-post",
+            Self::FileEarmarkPostFill => "file-earmark-post-fill",
+            Self::FileEarmarkPpt => "file-earmark-ppt",
+            Self::FileEarmarkPptFill => "file-earmark-ppt-fill",
+            Self::FileEarmarkRichtext => "file-earmark-richtext",
+            Self::FileEarmarkRichtextFill => "file-earmark-richtext-fill",
+            Self::FileEarmarkRuled => "file-earmark-ruled",
+            Self::FileEarmarkRuledFill => "file-earmark-ruled-fill",
+            Self::FileEarmarkSlides => "file-earmark-slides",
+            Self::FileEarmarkSlidesFill => "file-earmark-slides-fill",
+            Self::FileEarmarkSpreadsheet => "file-earmark-spreadsheet",
+            Self::FileEarmarkSpreadsheetFill => "file-earmark-spreadsheet-fill",
+            Self::FileEarmarkText => "file-earmark-text",
+            Self::FileEarmarkTextFill => "file-earmark-text-fill",
+            Self::FileEarmarkWord => "file-earmark-word",
+            Self::FileEarmarkWordFill => "file-earmark-word-fill",
+            Self::FileEarmarkX => "file-earmark-x",
+            Self::FileEarmarkXFill => "file-earmark-x-fill",
+            Self::FileEarmarkZip => "file-earmark-zip",
+            Self::FileEarmarkZipFill => "file-earmark-zip-fill",
+            Self::FileEasel => "file-easel",
+            Self::FileEaselFill => "file-easel-fill",
+            Self::FileExcel => "file-excel",
+            Self::FileExcelFill => "file-excel-fill",
+            Self::FileFill => "file-fill",
+            Self::FileFont => "file-font",
+            Self::FileFontFill => "file-font-fill",
+            Self::FileImage => "file-image",
+            Self::FileImageFill => "file-image-fill",
+            Self::FileLock => "file-lock",
+            Self::FileLockFill => "file-lock-fill",
+            Self::FileLocktwo => "file-lock2",
+            Self::FileLocktwoFill => "file-lock2-fill",
+            Self::FileMedical => "file-medical",
+            Self::FileMedicalFill => "file-medical-fill",
+            Self::FileMinus => "file-minus",
+            Self::FileMinusFill => "file-minus-fill",
+            Self::FileMusic => "file-music",
+            Self::FileMusicFill => "file-music-fill",
+            Self::FilePdf => "file-pdf",
+            Self::FilePdfFill => "file-pdf-fill",
+            Self::FilePerson => "file-person",
+            Self::FilePersonFill => "file-person-fill",
+            Self::FilePlay => "file-play",
+            Self::FilePlayFill => "file-play-fill",
+            Self::FilePlus => "file-plus",
+            Self::FilePlusFill => "file-plus-fill",
+            Self::FilePost => "file-post",
+            Self::FilePostFill => "file-post-fill",
+            Self::FilePpt => "file-ppt",
+            Self::FilePptFill => "file-ppt-fill",
+            Self::FileRichtext => "file-richtext",
+            Self::FileRichtextFill => "file-richtext-fill",
+            Self::FileRuled => "file-ruled",
+            Self::FileRuledFill => "file-ruled-fill",
+            Self::FileSlides => "file-slides",
+            Self::FileSlidesFill => "file-slides-fill",
+            Self::FileSpreadsheet => "file-spreadsheet",
+            Self::FileSpreadsheetFill => "file-spreadsheet-fill",
+            Self::FileText => "file-text",
+            Self::FileTextFill => "file-text-fill",
+            Self::FileWord => "file-word",
+            Self::FileWordFill => "file-word-fill",
+            Self::FileX => "file-x",
+            Self::FileXFill => "file-x-fill",
+            Self::FileZip => "file-zip",
+            Self::FileZipFill => "file-zip-fill",
+            Self::Files => "files",
+            Self::FilesAlt => "files-alt",
+            Self::FiletypeAac => "filetype-aac",
+            Self::FiletypeAi => "filetype-ai",
+            Self::FiletypeBmp => "filetype-bmp",
+            Self::FiletypeCs => "filetype-cs",
+            Self::FiletypeCss => "filetype-css",
+            Self::FiletypeCsv => "filetype-csv",
+            Self::FiletypeDoc => "filetype-doc",
+            Self::FiletypeDocx => "filetype-docx",
+            Self::FiletypeExe => "filetype-exe",
+            Self::FiletypeGif => "filetype-gif",
+            Self::FiletypeHeic => "filetype-heic",
+            Self::FiletypeHtml => "filetype-html",
+            Self::FiletypeJava => "filetype-java",
+            Self::FiletypeJpg => "filetype-jpg",
+            Self::FiletypeJs => "filetype-js",
+            Self::FiletypeJson => "filetype-json",
+            Self::FiletypeJsx => "filetype-jsx",
+            Self::FiletypeKey => "filetype-key",
+            Self::FiletypeMd => "filetype-md",
+            Self::FiletypeMdx => "filetype-mdx",
+            Self::FiletypeMfourp => "filetype-m4p",
+            Self::FiletypeMov => "filetype-mov",
+            Self::FiletypeMpfour => "filetype-mp4",
+            Self::FiletypeMpthree => "filetype-mp3",
+            Self::FiletypeOtf => "filetype-otf",
+            Self::FiletypePdf => "filetype-pdf",
+            Self::FiletypePhp => "filetype-php",
+            Self::FiletypePng => "filetype-png",
+            Self::FiletypePpt => "filetype-ppt",
+            Self::FiletypePptx => "filetype-pptx",
+            Self::FiletypePsd => "filetype-psd",
+            Self::FiletypePy => "filetype-py",
+            Self::FiletypeRaw => "filetype-raw",
+            Self::FiletypeRb => "filetype-rb",
+            Self::FiletypeSass => "filetype-sass",
+            Self::FiletypeScss => "filetype-scss",
+            Self::FiletypeSh => "filetype-sh",
+            Self::FiletypeSql => "filetype-sql",
+            Self::FiletypeSvg => "filetype-svg",
+            Self::FiletypeTiff => "filetype-tiff",
+            Self::FiletypeTsx => "filetype-tsx",
+            Self::FiletypeTtf => "filetype-ttf",
+            Self::FiletypeTxt => "filetype-txt",
+            Self::FiletypeWav => "filetype-wav",
+            Self::FiletypeWoff => "filetype-woff",
+            Self::FiletypeXls => "filetype-xls",
+            Self::FiletypeXlsx => "filetype-xlsx",
+            Self::FiletypeXml => "filetype-xml",
+            Self::FiletypeYml => "filetype-yml",
+            Self::Film => "film",
+            Self::Filter => "filter",
+            Self::FilterCircle => "filter-circle",
+            Self::FilterCircleFill => "filter-circle-fill",
+            Self::FilterLeft => "filter-left",
+            Self::FilterRight => "filter-right",
+            Self::FilterSquare => "filter-square",
+            Self::FilterSquareFill => "filter-square-fill",
+            Self::Fingerprint => "fingerprint",
+            Self::Fire => "fire",
+            Self::FiveCircle => "5-circle",
+            Self::FiveCircleFill => "5-circle-fill",
+            Self::FiveSquare => "5-square",
+            Self::FiveSquareFill => "5-square-fill",
+            Self::Flag => "flag",
+            Self::FlagFill => "flag-fill",
+            Self::Floppy => "floppy",
+            Self::FloppyFill => "floppy-fill",
+            Self::Floppytwo => "floppy2",
+            Self::FloppytwoFill => "floppy2-fill",
+            Self::Flowerone => "flower1",
+            Self::Flowerthree => "flower3",
+            Self::Flowertwo => "flower2",
+            Self::Folder => "folder",
+            Self::FolderCheck => "folder-check",
+            Self::FolderFill => "folder-fill",
+            Self::FolderMinus => "folder-minus",
+            Self::FolderPlus => "folder-plus",
+            Self::FolderSymlink => "folder-symlink",
+            Self::FolderSymlinkFill => "folder-symlink-fill",
+            Self::FolderX => "folder-x",
+            Self::Foldertwo => "folder2",
+            Self::FoldertwoOpen => "folder2-open",
+            Self::Fonts => "fonts",
+            Self::Forward => "forward",
+            Self::ForwardFill => "forward-fill",
+            Self::FourCircle => "4-circle",
+            Self::FourCircleFill => "4-circle-fill",
+            Self::FourSquare => "4-square",
+            Self::FourSquareFill => "4-square-fill",
+            Self::Front => "front",
+            Self::FuelPump => "fuel-pump",
+            Self::FuelPumpDiesel => "fuel-pump-diesel",
+            Self::FuelPumpDieselFill => "fuel-pump-diesel-fill",
+            Self::FuelPumpFill => "fuel-pump-fill",
+            Self::Fullscreen => "fullscreen",
+            Self::FullscreenExit => "fullscreen-exit",
+            Self::Funnel => "funnel",
+            Self::FunnelFill => "funnel-fill",
+            Self::Gear => "gear",
+            Self::GearFill => "gear-fill",
+            Self::GearWide => "gear-wide",
+            Self::GearWideConnected => "gear-wide-connected",
+            Self::Gem => "gem",
+            Self::GenderAmbiguous => "gender-ambiguous",
+            Self::GenderFemale => "gender-female",
+            Self::GenderMale => "gender-male",
+            Self::GenderNeuter => "gender-neuter",
+            Self::GenderTrans => "gender-trans",
+            Self::Geo => "geo",
+            Self::GeoAlt => "geo-alt",
+            Self::GeoAltFill => "geo-alt-fill",
+            Self::GeoFill => "geo-fill",
+            Self::Gift => "gift",
+            Self::GiftFill => "gift-fill",
+            Self::Git => "git",
+            Self::Github => "github",
+            Self::Gitlab => "gitlab",
+            Self::Globe => "globe",
+            Self::GlobeAmericas => "globe-americas",
+            Self::GlobeAsiaAustralia => "globe-asia-australia",
+            Self::GlobeCentralSouthAsia => "globe-central-south-asia",
+            Self::GlobeEuropeAfrica => "globe-europe-africa",
+            Self::Globetwo => "globe2",
+            Self::Google => "google",
+            Self::GooglePlay => "google-play",
+            Self::GpuCard => "gpu-card",
+            Self::GraphDown => "graph-down",
+            Self::GraphDownArrow => "graph-down-arrow",
+            Self::GraphUp => "graph-up",
+            Self::GraphUpArrow => "graph-up-arrow",
+            Self::Grid => "grid",
+            Self::GridFill => "grid-fill",
+            Self::GridOnextwo => "grid-1x2",
+            Self::GridOnextwoFill => "grid-1x2-fill",
+            Self::GridThreexthree => "grid-3x3",
+            Self::GridThreexthreeGap => "grid-3x3-gap",
+            Self::GridThreexthreeGapFill => "grid-3x3-gap-fill",
+            Self::GridThreextwo => "grid-3x2",
+            Self::GridThreextwoGap => "grid-3x2-gap",
+            Self::GridThreextwoGapFill => "grid-3x2-gap-fill",
+            Self::GripHorizontal => "grip-horizontal",
+            Self::GripVertical => "grip-vertical",
+            Self::HCircle => "h-circle",
+            Self::HCircleFill => "h-circle-fill",
+            Self::HSquare => "h-square",
+            Self::HSquareFill => "h-square-fill",
+            Self::Hammer => "hammer",
+            Self::HandIndex => "hand-index",
+            Self::HandIndexFill => "hand-index-fill",
+            Self::HandIndexThumb => "hand-index-thumb",
+            Self::HandIndexThumbFill => "hand-index-thumb-fill",
+            Self::HandThumbsDown => "hand-thumbs-down",
+            Self::HandThumbsDownFill => "hand-thumbs-down-fill",
+            Self::HandThumbsUp => "hand-thumbs-up",
+            Self::HandThumbsUpFill => "hand-thumbs-up-fill",
+            Self::Handbag => "handbag",
+            Self::HandbagFill => "handbag-fill",
+            Self::Hash => "hash",
+            Self::Hdd => "hdd",
+            Self::HddFill => "hdd-fill",
+            Self::HddNetwork => "hdd-network",
+            Self::HddNetworkFill => "hdd-network-fill",
+            Self::HddRack => "hdd-rack",
+            Self::HddRackFill => "hdd-rack-fill",
+            Self::HddStack => "hdd-stack",
+            Self::HddStackFill => "hdd-stack-fill",
+            Self::Hdmi => "hdmi",
+            Self::HdmiFill => "hdmi-fill",
+            Self::Headphones => "headphones",
+            Self::Headset => "headset",
+            Self::HeadsetVr => "headset-vr",
+            Self::Heart => "heart",
+            Self::HeartArrow => "heart-arrow",
+            Self::HeartFill => "heart-fill",
+            Self::HeartHalf => "heart-half",
+            Self::HeartPulse => "heart-pulse",
+            Self::HeartPulseFill => "heart-pulse-fill",
+            Self::Heartbreak => "heartbreak",
+            Self::HeartbreakFill => "heartbreak-fill",
+            Self::Hearts => "hearts",
+            Self::Heptagon => "heptagon",
+            Self::HeptagonFill => "heptagon-fill",
+            Self::HeptagonHalf => "heptagon-half",
+            Self::Hexagon => "hexagon",
+            Self::HexagonFill => "hexagon-fill",
+            Self::HexagonHalf => "hexagon-half",
+            Self::Highlighter => "highlighter",
+            Self::Highlights => "highlights",
+            Self::Hospital => "hospital",
+            Self::HospitalFill => "hospital-fill",
+            Self::Hourglass => "hourglass",
+            Self::HourglassBottom => "hourglass-bottom",
+            Self::HourglassSplit => "hourglass-split",
+            Self::HourglassTop => "hourglass-top",
+            Self::House => "house",
+            Self::HouseAdd => "house-add",
+            Self::HouseAddFill => "house-add-fill",
+            Self::HouseCheck => "house-check",
+            Self::HouseCheckFill => "house-check-fill",
+            Self::HouseDash => "house-dash",
+            Self::HouseDashFill => "house-dash-fill",
+            Self::HouseDoor => "house-door",
+            Self::HouseDoorFill => "house-door-fill",
+            Self::HouseDown => "house-down",
+            Self::HouseDownFill => "house-down-fill",
+            Self::HouseExclamation => "house-exclamation",
+            Self::HouseExclamationFill => "house-exclamation-fill",
+            Self::HouseFill => "house-fill",
+            Self::HouseGear => "house-gear",
+            Self::HouseGearFill => "house-gear-fill",
+            Self::HouseHeart => "house-heart",
+            Self::HouseHeartFill => "house-heart-fill",
+            Self::HouseLock => "house-lock",
+            Self::HouseLockFill => "house-lock-fill",
+            Self::HouseSlash => "house-slash",
+            Self::HouseSlashFill => "house-slash-fill",
+            Self::HouseUp => "house-up",
+            Self::HouseUpFill => "house-up-fill",
+            Self::HouseX => "house-x",
+            Self::HouseXFill => "house-x-fill",
+            Self::Houses => "houses",
+            Self::HousesFill => "houses-fill",
+            Self::Hr => "hr",
+            Self::Hurricane => "hurricane",
+            Self::Hypnotize => "hypnotize",
+            Self::Image => "image",
+            Self::ImageAlt => "image-alt",
+            Self::ImageFill => "image-fill",
+            Self::Images => "images",
+            Self::Inbox => "inbox",
+            Self::InboxFill => "inbox-fill",
+            Self::Inboxes => "inboxes",
+            Self::InboxesFill => "inboxes-fill",
+            Self::Incognito => "incognito",
+            Self::Indent => "indent",
+            Self::Infinity => "infinity",
+            Self::Info => "info",
+            Self::InfoCircle => "info-circle",
+            Self::InfoCircleFill => "info-circle-fill",
+            Self::InfoLg => "info-lg",
+            Self::InfoSquare => "info-square",
+            Self::InfoSquareFill => "info-square-fill",
+            Self::InputCursor => "input-cursor",
+            Self::InputCursorText => "input-cursor-text",
+            Self::Instagram => "instagram",
+            Self::Intersect => "intersect",
+            Self::Journal => "journal",
+            Self::JournalAlbum => "journal-album",
+            Self::JournalArrowDown => "journal-arrow-down",
+            Self::JournalArrowUp => "journal-arrow-up",
+            Self::JournalBookmark => "journal-bookmark",
+            Self::JournalBookmarkFill => "journal-bookmark-fill",
+            Self::JournalCheck => "journal-check",
+            Self::JournalCode => "journal-code",
+            Self::JournalMedical => "journal-medical",
+            Self::JournalMinus => "journal-minus",
+            Self::JournalPlus => "journal-plus",
+            Self::JournalRichtext => "journal-richtext",
+            Self::JournalText => "journal-text",
+            Self::JournalX => "journal-x",
+            Self::Journals => "journals",
+            Self::Joystick => "joystick",
+            Self::Justify => "justify",
+            Self::JustifyLeft => "justify-left",
+            Self::JustifyRight => "justify-right",
+            Self::Kanban => "kanban",
+            Self::KanbanFill => "kanban-fill",
+            Self::Key => "key",
+            Self::KeyFill => "key-fill",
+            Self::Keyboard => "keyboard",
+            Self::KeyboardFill => "keyboard-fill",
+            Self::Ladder => "ladder",
+            Self::Lamp => "lamp",
+            Self::LampFill => "lamp-fill",
+            Self::Laptop => "laptop",
+            Self::LaptopFill => "laptop-fill",
+            Self::LayerBackward => "layer-backward",
+            Self::LayerForward => "layer-forward",
+            Self::Layers => "layers",
+            Self::LayersFill => "layers-fill",
+            Self::LayersHalf => "layers-half",
+            Self::LayoutSidebar => "layout-sidebar",
+            Self::LayoutSidebarInset => "layout-sidebar-inset",
+            Self::LayoutSidebarInsetReverse => "layout-sidebar-inset-reverse",
+            Self::LayoutSidebarReverse => "layout-sidebar-reverse",
+            Self::LayoutSplit => "layout-split",
+            Self::LayoutTextSidebar => "layout-text-sidebar",
+            Self::LayoutTextSidebarReverse => "layout-text-sidebar-reverse",
+            Self::LayoutTextWindow => "layout-text-window",
+            Self::LayoutTextWindowReverse => "layout-text-window-reverse",
+            Self::LayoutThreeColumns => "layout-three-columns",
+            Self::LayoutWtf => "layout-wtf",
+            Self::LifePreserver => "life-preserver",
+            Self::Lightbulb => "lightbulb",
+            Self::LightbulbFill => "lightbulb-fill",
+            Self::LightbulbOff => "lightbulb-off",
+            Self::LightbulbOffFill => "lightbulb-off-fill",
+            Self::Lightning => "lightning",
+            Self::LightningCharge => "lightning-charge",
+            Self::LightningChargeFill => "lightning-charge-fill",
+            Self::LightningFill => "lightning-fill",
+            Self::Line => "line",
+            Self::Link => "link",
+            Self::LinkFourfivedeg => "link-45deg",
+            Self::Linkedin => "linkedin",
+            Self::List => "list",
+            Self::ListCheck => "list-check",
+            Self::ListColumns => "list-columns",
+            Self::ListColumnsReverse => "list-columns-reverse",
+            Self::ListNested => "list-nested",
+            Self::ListOl => "list-ol",
+            Self::ListStars => "list-stars",
+            Self::ListTask => "list-task",
+            Self::ListUl => "list-ul",
+            Self::Lock => "lock",
+            Self::LockFill => "lock-fill",
+            Self::Luggage => "luggage",
+            Self::LuggageFill => "luggage-fill",
+            Self::Lungs => "lungs",
+            Self::LungsFill => "lungs-fill",
+            Self::Magic => "magic",
+            Self::Magnet => "magnet",
+            Self::MagnetFill => "magnet-fill",
+            Self::Mailbox => "mailbox",
+            Self::MailboxFlag => "mailbox-flag",
+            Self::Mailboxtwo => "mailbox2",
+            Self::MailboxtwoFlag => "mailbox2-flag",
+            Self::Map => "map",
+            Self::MapFill => "map-fill",
+            Self::Markdown => "markdown",
+            Self::MarkdownFill => "markdown-fill",
+            Self::MarkerTip => "marker-tip",
+            Self::Mask => "mask",
+            Self::Mastodon => "mastodon",
+            Self::Medium => "medium",
+            Self::Megaphone => "megaphone",
+            Self::MegaphoneFill => "megaphone-fill",
+            Self::Memory => "memory",
+            Self::MenuApp => "menu-app",
+            Self::MenuAppFill => "menu-app-fill",
+            Self::MenuButton => "menu-button",
+            Self::MenuButtonFill => "menu-button-fill",
+            Self::MenuButtonWide => "menu-button-wide",
+            Self::MenuButtonWideFill => "menu-button-wide-fill",
+            Self::MenuDown => "menu-down",
+            Self::MenuUp => "menu-up",
+            Self::Messenger => "messenger",
+            Self::Meta => "meta",
+            Self::Mic => "mic",
+            Self::MicFill => "mic-fill",
+            Self::MicMute => "mic-mute",
+            Self::MicMuteFill => "mic-mute-fill",
+            Self::Microsoft => "microsoft",
+            Self::MicrosoftTeams => "microsoft-teams",
+            Self::Minecart => "minecart",
+            Self::MinecartLoaded => "minecart-loaded",
+            Self::Modem => "modem",
+            Self::ModemFill => "modem-fill",
+            Self::Moisture => "moisture",
+            Self::Moon => "moon",
+            Self::MoonFill => "moon-fill",
+            Self::MoonStars => "moon-stars",
+            Self::MoonStarsFill => "moon-stars-fill",
+            Self::Mortarboard => "mortarboard",
+            Self::MortarboardFill => "mortarboard-fill",
+            Self::Motherboard => "motherboard",
+            Self::MotherboardFill => "motherboard-fill",
+            Self::Mouse => "mouse",
+            Self::MouseFill => "mouse-fill",
+            Self::Mousethree => "mouse3",
+            Self::MousethreeFill => "mouse3-fill",
+            Self::Mousetwo => "mouse2",
+            Self::MousetwoFill => "mouse2-fill",
+            Self::MusicNote => "music-note",
+            Self::MusicNoteBeamed => "music-note-beamed",
+            Self::MusicNoteList => "music-note-list",
+            Self::MusicPlayer => "music-player",
+            Self::MusicPlayerFill => "music-player-fill",
+            Self::Newspaper => "newspaper",
+            Self::NineCircle => "9-circle",
+            Self::NineCircleFill => "9-circle-fill",
+            Self::NineSquare => "9-square",
+            Self::NineSquareFill => "9-square-fill",
+            Self::NintendoSwitch => "nintendo-switch",
+            Self::NodeMinus => "node-minus",
+            Self::NodeMinusFill => "node-minus-fill",
+            Self::NodePlus => "node-plus",
+            Self::NodePlusFill => "node-plus-fill",
+            Self::NoiseReduction => "noise-reduction",
+            Self::Nut => "nut",
+            Self::NutFill => "nut-fill",
+            Self::Nvidia => "nvidia",
+            Self::Nvme => "nvme",
+            Self::NvmeFill => "nvme-fill",
+            Self::Octagon => "octagon",
+            Self::OctagonFill => "octagon-fill",
+            Self::OctagonHalf => "octagon-half",
+            Self::OneCircle => "1-circle",
+            Self::OneCircleFill => "1-circle-fill",
+            Self::OneSquare => "1-square",
+            Self::OneSquareFill => "1-square-fill",
+            Self::Onetwothree => "123",
+            Self::Opencollective => "opencollective",
+            Self::OpticalAudio => "optical-audio",
+            Self::OpticalAudioFill => "optical-audio-fill",
+            Self::Option => "option",
+            Self::Outlet => "outlet",
+            Self::PCircle => "p-circle",
+            Self::PCircleFill => "p-circle-fill",
+            Self::PSquare => "p-square",
+            Self::PSquareFill => "p-square-fill",
+            Self::PaintBucket => "paint-bucket",
+            Self::Palette => "palette",
+            Self::PaletteFill => "palette-fill",
+            Self::Palettetwo => "palette2",
+            Self::Paperclip => "paperclip",
+            Self::Paragraph => "paragraph",
+            Self::Pass => "pass",
+            Self::PassFill => "pass-fill",
+            Self::Passport => "passport",
+            Self::PassportFill => "passport-fill",
+            Self::PatchCheck => "patch-check",
+            Self::PatchCheckFill => "patch-check-fill",
+            Self::PatchExclamation => "patch-exclamation",
+            Self::PatchExclamationFill => "patch-exclamation-fill",
+            Self::PatchMinus => "patch-minus",
+            Self::PatchMinusFill => "patch-minus-fill",
+            Self::PatchPlus => "patch-plus",
+            Self::PatchPlusFill => "patch-plus-fill",
+            Self::PatchQuestion => "patch-question",
+            Self::PatchQuestionFill => "patch-question-fill",
+            Self::Pause => "pause",
+            Self::PauseBtn => "pause-btn",
+            Self::PauseBtnFill => "pause-btn-fill",
+            Self::PauseCircle => "pause-circle",
+            Self::PauseCircleFill => "pause-circle-fill",
+            Self::PauseFill => "pause-fill",
+            Self::Paypal => "paypal",
+            Self::Pc => "pc",
+            Self::PcDisplay => "pc-display",
+            Self::PcDisplayHorizontal => "pc-display-horizontal",
+            Self::PcHorizontal => "pc-horizontal",
+            Self::PciCard => "pci-card",
+            Self::PciCardNetwork => "pci-card-network",
+            Self::PciCardSound => "pci-card-sound",
+            Self::Peace => "peace",
+            Self::PeaceFill => "peace-fill",
+            Self::Pen => "pen",
+            Self::PenFill => "pen-fill",
+            Self::Pencil => "pencil",
+            Self::PencilFill => "pencil-fill",
+            Self::PencilSquare => "pencil-square",
+            Self::Pentagon => "pentagon",
+            Self::PentagonFill => "pentagon-fill",
+            Self::PentagonHalf => "pentagon-half",
+            Self::People => "people",
+            Self::PeopleFill => "people-fill",
+            Self::Percent => "percent",
+            Self::Person => "person",
+            Self::PersonAdd => "person-add",
+            Self::PersonArmsUp => "person-arms-up",
+            Self::PersonBadge => "person-badge",
+            Self::PersonBadgeFill => "person-badge-fill",
+            Self::PersonBoundingBox => "person-bounding-box",
+            Self::PersonCheck => "person-check",
+            Self::PersonCheckFill => "person-check-fill",
+            Self::PersonCircle => "person-circle",
+            Self::PersonDash => "person-dash",
+            Self::PersonDashFill => "person-dash-fill",
+            Self::PersonDown => "person-down",
+            Self::PersonExclamation => "person-exclamation",
+            Self::PersonFill => "person-fill",
+            Self::PersonFillAdd => "person-fill-add",
+            Self::PersonFillCheck => "person-fill-check",
+            Self::PersonFillDash => "person-fill-dash",
+            Self::PersonFillDown => "person-fill-down",
+            Self::PersonFillExclamation => "person-fill-exclamation",
+            Self::PersonFillGear => "person-fill-gear",
+            Self::PersonFillLock => "person-fill-lock",
+            Self::PersonFillSlash => "person-fill-slash",
+            Self::PersonFillUp => "person-fill-up",
+            Self::PersonFillX => "person-fill-x",
+            Self::PersonGear => "person-gear",
+            Self::PersonHeart => "person-heart",
+            Self::PersonHearts => "person-hearts",
+            Self::PersonLinesFill => "person-lines-fill",
+            Self::PersonLock => "person-lock",
+            Self::PersonPlus => "person-plus",
+            Self::PersonPlusFill => "person-plus-fill",
+            Self::PersonRaisedHand => "person-raised-hand",
+            Self::PersonRolodex => "person-rolodex",
+            Self::PersonSlash => "person-slash",
+            Self::PersonSquare => "person-square",
+            Self::PersonStanding => "person-standing",
+            Self::PersonStandingDress => "person-standing-dress",
+            Self::PersonUp => "person-up",
+            Self::PersonVcard => "person-vcard",
+            Self::PersonVcardFill => "person-vcard-fill",
+            Self::PersonVideo => "person-video",
+            Self::PersonVideothree => "person-video3",
+            Self::PersonVideotwo => "person-video2",
+            Self::PersonWalking => "person-walking",
+            Self::PersonWheelchair => "person-wheelchair",
+            Self::PersonWorkspace => "person-workspace",
+            Self::PersonX => "person-x",
+            Self::PersonXFill => "person-x-fill",
+            Self::Phone => "phone",
+            Self::PhoneFill => "phone-fill",
+            Self::PhoneFlip => "phone-flip",
+            Self::PhoneLandscape => "phone-landscape",
+            Self::PhoneLandscapeFill => "phone-landscape-fill",
+            Self::PhoneVibrate => "phone-vibrate",
+            Self::PhoneVibrateFill => "phone-vibrate-fill",
+            Self::PieChart => "pie-chart",
+            Self::PieChartFill => "pie-chart-fill",
+            Self::PiggyBank => "piggy-bank",
+            Self::PiggyBankFill => "piggy-bank-fill",
+            Self::Pin => "pin",
+            Self::PinAngle => "pin-angle",
+            Self::PinAngleFill => "pin-angle-fill",
+            Self::PinFill => "pin-fill",
+            Self::PinMap => "pin-map",
+            Self::PinMapFill => "pin-map-fill",
+            Self::Pinterest => "pinterest",
+            Self::Pip => "pip",
+            Self::PipFill => "pip-fill",
+            Self::Play => "play",
+            Self::PlayBtn => "play-btn",
+            Self::PlayBtnFill => "play-btn-fill",
+            Self::PlayCircle => "play-circle",
+            Self::PlayCircleFill => "play-circle-fill",
+            Self::PlayFill => "play-fill",
+            Self::Playstation => "playstation",
+            Self::Plug => "plug",
+            Self::PlugFill => "plug-fill",
+            Self::Plugin => "plugin",
+            Self::Plus => "plus",
+            Self::PlusCircle => "plus-circle",
+            Self::PlusCircleDotted => "plus-circle-dotted",
+            Self::PlusCircleFill => "plus-circle-fill",
+            Self::PlusLg => "plus-lg",
+            Self::PlusSlashMinus => "plus-slash-minus",
+            Self::PlusSquare => "plus-square",
+            Self::PlusSquareDotted => "plus-square-dotted",
+            Self::PlusSquareFill => "plus-square-fill",
+            Self::Postage => "postage",
+            Self::PostageFill => "postage-fill",
+            Self::PostageHeart => "postage-heart",
+            Self::PostageHeartFill => "postage-heart-fill",
+            Self::Postcard => "postcard",
+            Self::PostcardFill => "postcard-fill",
+            Self::PostcardHeart => "postcard-heart",
+            Self::PostcardHeartFill => "postcard-heart-fill",
+            Self::Power => "power",
+            Self::Prescription => "prescription",
+            Self::Prescriptiontwo => "prescription2",
+            Self::Printer => "printer",
+            Self::PrinterFill => "printer-fill",
+            Self::Projector => "projector",
+            Self::ProjectorFill => "projector-fill",
+            Self::Puzzle => "puzzle",
+            Self::PuzzleFill => "puzzle-fill",
+            Self::QrCode => "qr-code",
+            Self::QrCodeScan => "qr-code-scan",
+            Self::Question => "question",
+            Self::QuestionCircle => "question-circle",
+            Self::QuestionCircleFill => "question-circle-fill",
+            Self::QuestionDiamond => "question-diamond",
+            Self::QuestionDiamondFill => "question-diamond-fill",
+            Self::QuestionLg => "question-lg",
+            Self::QuestionOctagon => "question-octagon",
+            Self::QuestionOctagonFill => "question-octagon-fill",
+            Self::QuestionSquare => "question-square",
+            Self::QuestionSquareFill => "question-square-fill",
+            Self::Quora => "quora",
+            Self::Quote => "quote",
+            Self::RCircle => "r-circle",
+            Self::RCircleFill => "r-circle-fill",
+            Self::RSquare => "r-square",
+            Self::RSquareFill => "r-square-fill",
+            Self::Radar => "radar",
+            Self::Radioactive => "radioactive",
+            Self::Rainbow => "rainbow",
+            Self::Receipt => "receipt",
+            Self::ReceiptCutoff => "receipt-cutoff",
+            Self::ReceptionFour => "reception-4",
+            Self::ReceptionOne => "reception-1",
+            Self::ReceptionThree => "reception-3",
+            Self::ReceptionTwo => "reception-2",
+            Self::ReceptionZero => "reception-0",
+            Self::Record => "record",
+            Self::RecordBtn => "record-btn",
+            Self::RecordBtnFill => "record-btn-fill",
+            Self::RecordCircle => "record-circle",
+            Self::RecordCircleFill => "record-circle-fill",
+            Self::RecordFill => "record-fill",
+            Self::Recordtwo => "record2",
+            Self::RecordtwoFill => "record2-fill",
+            Self::Recycle => "recycle",
+            Self::Reddit => "reddit",
+            Self::Regex => "regex",
+            Self::Repeat => "repeat",
+            Self::RepeatOne => "repeat-1",
+            Self::Reply => "reply",
+            Self::ReplyAll => "reply-all",
+            Self::ReplyAllFill => "reply-all-fill",
+            Self::ReplyFill => "reply-fill",
+            Self::Rewind => "rewind",
+            Self::RewindBtn => "rewind-btn",
+            Self::RewindBtnFill => "rewind-btn-fill",
+            Self::RewindCircle => "rewind-circle",
+            Self::RewindCircleFill => "rewind-circle-fill",
+            Self::RewindFill => "rewind-fill",
+            Self::Robot => "robot",
+            Self::Rocket => "rocket",
+            Self::RocketFill => "rocket-fill",
+            Self::RocketTakeoff => "rocket-takeoff",
+            Self::RocketTakeoffFill => "rocket-takeoff-fill",
+            Self::Router => "router",
+            Self::RouterFill => "router-fill",
+            Self::Rss => "rss",
+            Self::RssFill => "rss-fill",
+            Self::Rulers => "rulers",
+            Self::Safe => "safe",
+            Self::SafeFill => "safe-fill",
+            Self::Safetwo => "safe2",
+            Self::SafetwoFill => "safe2-fill",
+            Self::Save => "save",
+            Self::SaveFill => "save-fill",
+            Self::Savetwo => "save2",
+            Self::SavetwoFill => "save2-fill",
+            Self::Scissors => "scissors",
+            Self::Scooter => "scooter",
+            Self::Screwdriver => "screwdriver",
+            Self::SdCard => "sd-card",
+            Self::SdCardFill => "sd-card-fill",
+            Self::Search => "search",
+            Self::SearchHeart => "search-heart",
+            Self::SearchHeartFill => "search-heart-fill",
+            Self::SegmentedNav => "segmented-nav",
+            Self::Send => "send",
+            Self::SendArrowDown => "send-arrow-down",
+            Self::SendArrowDownFill => "send-arrow-down-fill",
+            Self::SendArrowUp => "send-arrow-up",
+            Self::SendArrowUpFill => "send-arrow-up-fill",
+            Self::SendCheck => "send-check",
+            Self::SendCheckFill => "send-check-fill",
+            Self::SendDash => "send-dash",
+            Self::SendDashFill => "send-dash-fill",
+            Self::SendExclamation => "send-exclamation",
+            Self::SendExclamationFill => "send-exclamation-fill",
+            Self::SendFill => "send-fill",
+            Self::SendPlus => "send-plus",
+            Self::SendPlusFill => "send-plus-fill",
+            Self::SendSlash => "send-slash",
+            Self::SendSlashFill => "send-slash-fill",
+            Self::SendX => "send-x",
+            Self::SendXFill => "send-x-fill",
+            Self::Server => "server",
+            Self::SevenCircle => "7-circle",
+            Self::SevenCircleFill => "7-circle-fill",
+            Self::SevenSquare => "7-square",
+            Self::SevenSquareFill => "7-square-fill",
+            Self::Shadows => "shadows",
+            Self::Share => "share",
+            Self::ShareFill => "share-fill",
+            Self::Shield => "shield",
+            Self::ShieldCheck => "shield-check",
+            Self::ShieldExclamation => "shield-exclamation",
+            Self::ShieldFill => "shield-fill",
+            Self::ShieldFillCheck => "shield-fill-check",
+            Self::ShieldFillExclamation => "shield-fill-exclamation",
+            Self::ShieldFillMinus => "shield-fill-minus",
+            Self::ShieldFillPlus => "shield-fill-plus",
+            Self::ShieldFillX => "shield-fill-x",
+            Self::ShieldLock => "shield-lock",
+            Self::ShieldLockFill => "shield-lock-fill",
+            Self::ShieldMinus => "shield-minus",
+            Self::ShieldPlus => "shield-plus",
+            Self::ShieldShaded => "shield-shaded",
+            Self::ShieldSlash => "shield-slash",
+            Self::ShieldSlashFill => "shield-slash-fill",
+            Self::ShieldX => "shield-x",
+            Self::Shift => "shift",
+            Self::ShiftFill => "shift-fill",
+            Self::Shop => "shop",
+            Self::ShopWindow => "shop-window",
+            Self::Shuffle => "shuffle",
+            Self::SignDeadEnd => "sign-dead-end",
+            Self::SignDeadEndFill => "sign-dead-end-fill",
+            Self::SignDoNotEnter => "sign-do-not-enter",
+            Self::SignDoNotEnterFill => "sign-do-not-enter-fill",
+            Self::SignIntersection => "sign-intersection",
+            Self::SignIntersectionFill => "sign-intersection-fill",
+            Self::SignIntersectionSide => "sign-intersection-side",
+            Self::SignIntersectionSideFill => "sign-intersection-side-fill",
+            Self::SignIntersectionT => "sign-intersection-t",
+            Self::SignIntersectionTFill => "sign-intersection-t-fill",
+            Self::SignIntersectionY => "sign-intersection-y",
+            Self::SignIntersectionYFill => "sign-intersection-y-fill",
+            Self::SignMergeLeft => "sign-merge-left",
+            Self::SignMergeLeftFill => "sign-merge-left-fill",
+            Self::SignMergeRight => "sign-merge-right",
+            Self::SignMergeRightFill => "sign-merge-right-fill",
+            Self::SignNoLeftTurn => "sign-no-left-turn",
+            Self::SignNoLeftTurnFill => "sign-no-left-turn-fill",
+            Self::SignNoParking => "sign-no-parking",
+            Self::SignNoParkingFill => "sign-no-parking-fill",
+            Self::SignNoRightTurn => "sign-no-right-turn",
+            Self::SignNoRightTurnFill => "sign-no-right-turn-fill",
+            Self::SignRailroad => "sign-railroad",
+            Self::SignRailroadFill => "sign-railroad-fill",
+            Self::SignStop => "sign-stop",
+            Self::SignStopFill => "sign-stop-fill",
+            Self::SignStopLights => "sign-stop-lights",
+            Self::SignStopLightsFill => "sign-stop-lights-fill",
+            Self::SignTurnLeft => "sign-turn-left",
+            Self::SignTurnLeftFill => "sign-turn-left-fill",
+            Self::SignTurnRight => "sign-turn-right",
+            Self::SignTurnRightFill => "sign-turn-right-fill",
+            Self::SignTurnSlightLeft => "sign-turn-slight-left",
+            Self::SignTurnSlightLeftFill => "sign-turn-slight-left-fill",
+            Self::SignTurnSlightRight => "sign-turn-slight-right",
+            Self::SignTurnSlightRightFill => "sign-turn-slight-right-fill",
+            Self::SignYield => "sign-yield",
+            Self::SignYieldFill => "sign-yield-fill",
+            Self::Signal => "signal",
+            Self::Signpost => "signpost",
+            Self::SignpostFill => "signpost-fill",
+            Self::SignpostSplit => "signpost-split",
+            Self::SignpostSplitFill => "signpost-split-fill",
+            Self::SignpostTwo => "signpost-2",
+            Self::SignpostTwoFill => "signpost-2-fill",
+            Self::Sim => "sim",
+            Self::SimFill => "sim-fill",
+            Self::SimSlash => "sim-slash",
+            Self::SimSlashFill => "sim-slash-fill",
+            Self::SinaWeibo => "sina-weibo",
+            Self::SixCircle => "6-circle",
+            Self::SixCircleFill => "6-circle-fill",
+            Self::SixSquare => "6-square",
+            Self::SixSquareFill => "6-square-fill",
+            Self::SkipBackward => "skip-backward",
+            Self::SkipBackwardBtn => "skip-backward-btn",
+            Self::SkipBackwardBtnFill => "skip-backward-btn-fill",
+            Self::SkipBackwardCircle => "skip-backward-circle",
+            Self::SkipBackwardCircleFill => "skip-backward-circle-fill",
+            Self::SkipBackwardFill => "skip-backward-fill",
+            Self::SkipEnd => "skip-end",
+            Self::SkipEndBtn => "skip-end-btn",
+            Self::SkipEndBtnFill => "skip-end-btn-fill",
+            Self::SkipEndCircle => "skip-end-circle",
+            Self::SkipEndCircleFill => "skip-end-circle-fill",
+            Self::SkipEndFill => "skip-end-fill",
+            Self::SkipForward => "skip-forward",
+            Self::SkipForwardBtn => "skip-forward-btn",
+            Self::SkipForwardBtnFill => "skip-forward-btn-fill",
+            Self::SkipForwardCircle => "skip-forward-circle",
+            Self::SkipForwardCircleFill => "skip-forward-circle-fill",
+            Self::SkipForwardFill => "skip-forward-fill",
+            Self::SkipStart => "skip-start",
+            Self::SkipStartBtn => "skip-start-btn",
+            Self::SkipStartBtnFill => "skip-start-btn-fill",
+            Self::SkipStartCircle => "skip-start-circle",
+            Self::SkipStartCircleFill => "skip-start-circle-fill",
+            Self::SkipStartFill => "skip-start-fill",
+            Self::Skype => "skype",
+            Self::Slack => "slack",
+            Self::Slash => "slash",
+            Self::SlashCircle => "slash-circle",
+            Self::SlashCircleFill => "slash-circle-fill",
+            Self::SlashLg => "slash-lg",
+            Self::SlashSquare => "slash-square",
+            Self::SlashSquareFill => "slash-square-fill",
+            Self::Sliders => "sliders",
+            Self::Sliderstwo => "sliders2",
+            Self::SliderstwoVertical => "sliders2-vertical",
+            Self::Smartwatch => "smartwatch",
+            Self::Snapchat => "snapchat",
+            Self::Snow => "snow",
+            Self::Snowthree => "snow3",
+            Self::Snowtwo => "snow2",
+            Self::SortAlphaDown => "sort-alpha-down",
+            Self::SortAlphaDownAlt => "sort-alpha-down-alt",
+            Self::SortAlphaUp => "sort-alpha-up",
+            Self::SortAlphaUpAlt => "sort-alpha-up-alt",
+            Self::SortDown => "sort-down",
+            Self::SortDownAlt => "sort-down-alt",
+            Self::SortNumericDown => "sort-numeric-down",
+            Self::SortNumericDownAlt => "sort-numeric-down-alt",
+            Self::SortNumericUp => "sort-numeric-up",
+            Self::SortNumericUpAlt => "sort-numeric-up-alt",
+            Self::SortUp => "sort-up",
+            Self::SortUpAlt => "sort-up-alt",
+            Self::Soundwave => "soundwave",
+            Self::Sourceforge => "sourceforge",
+            Self::Speaker => "speaker",
+            Self::SpeakerFill => "speaker-fill",
+            Self::Speedometer => "speedometer",
+            Self::Speedometertwo => "speedometer2",
+            Self::Spellcheck => "spellcheck",
+            Self::Spotify => "spotify",
+            Self::Square => "square",
+            Self::SquareFill => "square-fill",
+            Self::SquareHalf => "square-half",
+            Self::Stack => "stack",
+            Self::StackOverflow => "stack-overflow",
+            Self::Star => "star",
+            Self::StarFill => "star-fill",
+            Self::StarHalf => "star-half",
+            Self::Stars => "stars",
+            Self::Steam => "steam",
+            Self::Stickies => "stickies",
+            Self::StickiesFill => "stickies-fill",
+            Self::Sticky => "sticky",
+            Self::StickyFill => "sticky-fill",
+            Self::Stop => "stop",
+            Self::StopBtn => "stop-btn",
+            Self::StopBtnFill => "stop-btn-fill",
+            Self::StopCircle => "stop-circle",
+            Self::StopCircleFill => "stop-circle-fill",
+            Self::StopFill => "stop-fill",
+            Self::Stoplights => "stoplights",
+            Self::StoplightsFill => "stoplights-fill",
+            Self::Stopwatch => "stopwatch",
+            Self::StopwatchFill => "stopwatch-fill",
+            Self::Strava => "strava",
+            Self::Stripe => "stripe",
+            Self::Subscript => "subscript",
+            Self::Substack => "substack",
+            Self::SuitClub => "suit-club",
+            Self::SuitClubFill => "suit-club-fill",
+            Self::SuitDiamond => "suit-diamond",
+            Self::SuitDiamondFill => "suit-diamond-fill",
+            Self::SuitHeart => "suit-heart",
+            Self::SuitHeartFill => "suit-heart-fill",
+            Self::SuitSpade => "suit-spade",
+            Self::SuitSpadeFill => "suit-spade-fill",
+            Self::Suitcase => "suitcase",
+            Self::SuitcaseFill => "suitcase-fill",
+            Self::SuitcaseLg => "suitcase-lg",
+            Self::SuitcaseLgFill => "suitcase-lg-fill",
+            Self::Suitcasetwo => "suitcase2",
+            Self::SuitcasetwoFill => "suitcase2-fill",
+            Self::SunFill => "sun-fill",
+            Self::Sunglasses => "sunglasses",
+            Self::Sunrise => "sunrise",
+            Self::SunriseFill => "sunrise-fill",
+            Self::Sunset => "sunset",
+            Self::SunsetFill => "sunset-fill",
+            Self::Superscript => "superscript",
+            Self::SymmetryHorizontal => "symmetry-horizontal",
+            Self::SymmetryVertical => "symmetry-vertical",
+            Self::Table => "table",
+            Self::Tablet => "tablet",
+            Self::TabletFill => "tablet-fill",
+            Self::TabletLandscape => "tablet-landscape",
+            Self::TabletLandscapeFill => "tablet-landscape-fill",
+            Self::Tag => "tag",
+            Self::TagFill => "tag-fill",
+            Self::Tags => "tags",
+            Self::TagsFill => "tags-fill",
+            Self::TaxiFront => "taxi-front",
+            Self::TaxiFrontFill => "taxi-front-fill",
+            Self::Telegram => "telegram",
+            Self::Telephone => "telephone",
+            Self::TelephoneFill => "telephone-fill",
+            Self::TelephoneForward => "telephone-forward",
+            Self::TelephoneForwardFill => "telephone-forward-fill",
+            Self::TelephoneInbound => "telephone-inbound",
+            Self::TelephoneInboundFill => "telephone-inbound-fill",
+            Self::TelephoneMinus => "telephone-minus",
+            Self::TelephoneMinusFill => "telephone-minus-fill",
+            Self::TelephoneOutbound => "telephone-outbound",
+            Self::TelephoneOutboundFill => "telephone-outbound-fill",
+            Self::TelephonePlus => "telephone-plus",
+            Self::TelephonePlusFill => "telephone-plus-fill",
+            Self::TelephoneX => "telephone-x",
+            Self::TelephoneXFill => "telephone-x-fill",
+            Self::TencentQq => "tencent-qq",
+            Self::Terminal => "terminal",
+            Self::TerminalDash => "terminal-dash",
+            Self::TerminalFill => "terminal-fill",
+            Self::TerminalPlus => "terminal-plus",
+            Self::TerminalSplit => "terminal-split",
+            Self::TerminalX => "terminal-x",
+            Self::TextCenter => "text-center",
+            Self::TextIndentLeft => "text-indent-left",
+            Self::TextIndentRight => "text-indent-right",
+            Self::TextLeft => "text-left",
+            Self::TextParagraph => "text-paragraph",
+            Self::TextRight => "text-right",
+            Self::TextWrap => "text-wrap",
+            Self::Textarea => "textarea",
+            Self::TextareaResize => "textarea-resize",
+            Self::TextareaT => "textarea-t",
+            Self::Thermometer => "thermometer",
+            Self::ThermometerHalf => "thermometer-half",
+            Self::ThermometerHigh => "thermometer-high",
+            Self::ThermometerLow => "thermometer-low",
+            Self::ThermometerSnow => "thermometer-snow",
+            Self::ThermometerSun => "thermometer-sun",
+            Self::Threads => "threads",
+            Self::ThreadsFill => "threads-fill",
+            Self::ThreeCircle => "3-circle",
+            Self::ThreeCircleFill => "3-circle-fill",
+            Self::ThreeDots => "three-dots",
+            Self::ThreeDotsVertical => "three-dots-vertical",
+            Self::ThreeSquare => "3-square",
+            Self::ThreeSquareFill => "3-square-fill",
+            Self::Thunderbolt => "thunderbolt",
+            Self::ThunderboltFill => "thunderbolt-fill",
+            Self::Ticket => "ticket",
+            Self::TicketDetailed => "ticket-detailed",
+            Self::TicketDetailedFill => "ticket-detailed-fill",
+            Self::TicketFill => "ticket-fill",
+            Self::TicketPerforated => "ticket-perforated",
+            Self::TicketPerforatedFill => "ticket-perforated-fill",
+            Self::Tiktok => "tiktok",
+            Self::ToggleOff => "toggle-off",
+            Self::ToggleOn => "toggle-on",
+            Self::Toggles => "toggles",
+            Self::Togglestwo => "toggles2",
+            Self::ToggletwoOff => "toggle2-off",
+            Self::ToggletwoOn => "toggle2-on",
+            Self::Tools => "tools",
+            Self::Tornado => "tornado",
+            Self::TrainFreightFront => "train-freight-front",
+            Self::TrainFreightFrontFill => "train-freight-front-fill",
+            Self::TrainFront => "train-front",
+            Self::TrainFrontFill => "train-front-fill",
+            Self::TrainLightrailFront => "train-lightrail-front",
+            Self::TrainLightrailFrontFill => "train-lightrail-front-fill",
+            Self::Translate => "translate",
+            Self::Transparency => "transparency",
+            Self::Trash => "trash",
+            Self::TrashFill => "trash-fill",
+            Self::Trashthree => "trash3",
+            Self::TrashthreeFill => "trash3-fill",
+            Self::Trashtwo => "trash2",
+            Self::TrashtwoFill => "trash2-fill",
+            Self::Tree => "tree",
+            Self::TreeFill => "tree-fill",
+            Self::Trello => "trello",
+            Self::Triangle => "triangle",
+            Self::TriangleFill => "triangle-fill",
+            Self::TriangleHalf => "triangle-half",
+            Self::Trophy => "trophy",
+            Self::TrophyFill => "trophy-fill",
+            Self::TropicalStorm => "tropical-storm",
+            Self::Truck => "truck",
+            Self::TruckFlatbed => "truck-flatbed",
+            Self::TruckFront => "truck-front",
+            Self::TruckFrontFill => "truck-front-fill",
+            Self::Tsunami => "tsunami",
+            Self::Tv => "tv",
+            Self::TvFill => "tv-fill",
+            Self::Twitch => "twitch",
+            Self::Twitter => "twitter",
+            Self::TwitterX => "twitter-x",
+            Self::TwoCircle => "2-circle",
+            Self::TwoCircleFill => "2-circle-fill",
+            Self::TwoSquare => "2-square",
+            Self::TwoSquareFill => "2-square-fill",
+            Self::Type => "type",
+            Self::TypeBold => "type-bold",
+            Self::TypeHfive => "type-h5",
+            Self::TypeHfour => "type-h4",
+            Self::TypeHone => "type-h1",
+            Self::TypeHsix => "type-h6",
+            Self::TypeHthree => "type-h3",
+            Self::TypeHtwo => "type-h2",
+            Self::TypeItalic => "type-italic",
+            Self::TypeStrikethrough => "type-strikethrough",
+            Self::TypeUnderline => "type-underline",
+            Self::Ubuntu => "ubuntu",
+            Self::UiChecks => "ui-checks",
+            Self::UiChecksGrid => "ui-checks-grid",
+            Self::UiRadios => "ui-radios",
+            Self::UiRadiosGrid => "ui-radios-grid",
+            Self::Umbrella => "umbrella",
+            Self::UmbrellaFill => "umbrella-fill",
+            Self::Unindent => "unindent",
+            Self::Union => "union",
+            Self::Unity => "unity",
+            Self::UniversalAccess => "universal-access",
+            Self::UniversalAccessCircle => "universal-access-circle",
+            Self::Unlock => "unlock",
+            Self::UnlockFill => "unlock-fill",
+            Self::Upc => "upc",
+            Self::UpcScan => "upc-scan",
+            Self::Upload => "upload",
+            Self::Usb => "usb",
+            Self::UsbC => "usb-c",
+            Self::UsbCFill => "usb-c-fill",
+            Self::UsbDrive => "usb-drive",
+            Self::UsbDriveFill => "usb-drive-fill",
+            Self::UsbFill => "usb-fill",
+            Self::UsbMicro => "usb-micro",
+            Self::UsbMicroFill => "usb-micro-fill",
+            Self::UsbMini => "usb-mini",
+            Self::UsbMiniFill => "usb-mini-fill",
+            Self::UsbPlug => "usb-plug",
+            Self::UsbPlugFill => "usb-plug-fill",
+            Self::UsbSymbol => "usb-symbol",
+            Self::Valentine => "valentine",
+            Self::Valentinetwo => "valentine2",
+            Self::VectorPen => "vector-pen",
+            Self::ViewList => "view-list",
+            Self::ViewStacked => "view-stacked",
+            Self::Vignette => "vignette",
+            Self::Vimeo => "vimeo",
+            Self::Vinyl => "vinyl",
+            Self::VinylFill => "vinyl-fill",
+            Self::Virus => "virus",
+            Self::Virustwo => "virus2",
+            Self::Voicemail => "voicemail",
+            Self::VolumeDown => "volume-down",
+            Self::VolumeDownFill => "volume-down-fill",
+            Self::VolumeMute => "volume-mute",
+            Self::VolumeMuteFill => "volume-mute-fill",
+            Self::VolumeOff => "volume-off",
+            Self::VolumeOffFill => "volume-off-fill",
+            Self::VolumeUp => "volume-up",
+            Self::VolumeUpFill => "volume-up-fill",
+            Self::Vr => "vr",
+            Self::Wallet => "wallet",
+            Self::WalletFill => "wallet-fill",
+            Self::Wallettwo => "wallet2",
+            Self::Watch => "watch",
+            Self::Water => "water",
+            Self::Webcam => "webcam",
+            Self::WebcamFill => "webcam-fill",
+            Self::Wechat => "wechat",
+            Self::Whatsapp => "whatsapp",
+            Self::Wifi => "wifi",
+            Self::WifiOff => "wifi-off",
+            Self::WifiOne => "wifi-1",
+            Self::WifiTwo => "wifi-2",
+            Self::Wikipedia => "wikipedia",
+            Self::Wind => "wind",
+            Self::Window => "window",
+            Self::WindowDash => "window-dash",
+            Self::WindowDesktop => "window-desktop",
+            Self::WindowDock => "window-dock",
+            Self::WindowFullscreen => "window-fullscreen",
+            Self::WindowPlus => "window-plus",
+            Self::WindowSidebar => "window-sidebar",
+            Self::WindowSplit => "window-split",
+            Self::WindowStack => "window-stack",
+            Self::WindowX => "window-x",
+            Self::Windows => "windows",
+            Self::Wordpress => "wordpress",
+            Self::Wrench => "wrench",
+            Self::WrenchAdjustable => "wrench-adjustable",
+            Self::WrenchAdjustableCircle => "wrench-adjustable-circle",
+            Self::WrenchAdjustableCircleFill => "wrench-adjustable-circle-fill",
+            Self::X => "x",
+            Self::XCircle => "x-circle",
+            Self::XCircleFill => "x-circle-fill",
+            Self::XDiamond => "x-diamond",
+            Self::XDiamondFill => "x-diamond-fill",
+            Self::XLg => "x-lg",
+            Self::XOctagon => "x-octagon",
+            Self::XOctagonFill => "x-octagon-fill",
+            Self::XSquare => "x-square",
+            Self::XSquareFill => "x-square-fill",
+            Self::Xbox => "xbox",
+            Self::Yelp => "yelp",
+            Self::YinYang => "yin-yang",
+            Self::Youtube => "youtube",
+            Self::ZeroCircle => "0-circle",
+            Self::ZeroCircleFill => "0-circle-fill",
+            Self::ZeroSquare => "0-square",
+            Self::ZeroSquareFill => "0-square-fill",
+            Self::ZoomIn => "zoom-in",
+            Self::ZoomOut => "zoom-out",
+        }
+    }
+
+    /// Returns an iterator over all the icons of the Bootstrap font, sorted
+    /// by name.
+    pub fn all() -> impl Iterator<Item = Self> {
+        NAMES.iter().map(|(_, icon)| *icon)
+    }
+}
+
 /// Converts an [`Bootstrap`] into a [`char`]
 #[must_use]
 pub const fn icon_to_char(icon: Bootstrap) -> char {
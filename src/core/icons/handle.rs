@@ -0,0 +1,189 @@
+//! An icon of a widget, drawn from a glyph of an icon font or from an SVG.
+
+#[cfg(feature = "svg")]
+use iced::advanced::svg;
+use iced::{
+    advanced::text::{self, Paragraph, Text},
+    alignment::{Horizontal, Vertical},
+    Color, Font, Pixels, Rectangle, Size,
+};
+use std::hash::{Hash, Hasher};
+
+/// A renderer able to draw an [`IconHandle`].
+///
+/// Without the `svg` feature, any text renderer can draw the glyphs of the
+/// icons. With it, the renderer must also be able to draw SVGs.
+#[cfg(feature = "svg")]
+pub trait IconRenderer: text::Renderer<Font = Font> + svg::Renderer {}
+
+#[cfg(feature = "svg")]
+impl<Renderer> IconRenderer for Renderer where Renderer: text::Renderer<Font = Font> + svg::Renderer {}
+
+/// A renderer able to draw an [`IconHandle`].
+///
+/// Without the `svg` feature, any text renderer can draw the glyphs of the
+/// icons. With it, the renderer must also be able to draw SVGs.
+#[cfg(not(feature = "svg"))]
+pub trait IconRenderer: text::Renderer<Font = Font> {}
+
+#[cfg(not(feature = "svg"))]
+impl<Renderer> IconRenderer for Renderer where Renderer: text::Renderer<Font = Font> {}
+
+/// The icon of a widget, either a glyph of an icon font or an SVG.
+///
+/// A [`char`] is drawn with the icon font of the widget showing it, while the
+/// icons of the crate, like a [`Bootstrap`](crate::Bootstrap) icon, carry
+/// their own font.
+///
+/// *The SVG icons require the following crate features to be activated: `svg`*
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{Bootstrap, IconHandle, TabLabel};
+/// #
+/// let home = TabLabel::IconHandle(Bootstrap::House.into());
+/// let logo = TabLabel::IconHandleText(IconHandle::svg("logo.svg"), String::from("About"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IconHandle {
+    /// A glyph, drawn with the given font or with the icon font of the widget.
+    Glyph(char, Option<Font>),
+
+    /// An SVG, drawn with the icon color of the widget.
+    #[cfg(feature = "svg")]
+    Svg(svg::Handle),
+}
+
+impl IconHandle {
+    /// Creates an [`IconHandle`] drawing the glyph with the given font.
+    #[must_use]
+    pub fn glyph(glyph: impl Into<char>, font: Font) -> Self {
+        Self::Glyph(glyph.into(), Some(font))
+    }
+
+    /// Creates an [`IconHandle`] drawing the SVG of the given handle, like
+    /// the path of a file.
+    #[cfg(feature = "svg")]
+    #[must_use]
+    pub fn svg(handle: impl Into<svg::Handle>) -> Self {
+        Self::Svg(handle.into())
+    }
+
+    /// Returns the size of the icon drawn at the given size, with `font` if
+    /// it is a glyph without its own font.
+    pub(crate) fn measure<Renderer>(&self, size: f32, font: Font) -> Size
+    where
+        Renderer: IconRenderer,
+    {
+        match self {
+            Self::Glyph(glyph, own_font) => Renderer::Paragraph::with_text(Self::text(
+                &glyph.to_string(),
+                Size::INFINITY,
+                size,
+                own_font.unwrap_or(font),
+            ))
+            .min_bounds(),
+            #[cfg(feature = "svg")]
+            Self::Svg(_) => Size::new(size, size),
+        }
+    }
+
+    /// Draws the icon at the given size, centered in `bounds`, with `font` if
+    /// it is a glyph without its own font.
+    pub(crate) fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        size: f32,
+        font: Font,
+        color: Color,
+    ) where
+        Renderer: IconRenderer,
+    {
+        match self {
+            Self::Glyph(glyph, own_font) => renderer.fill_text(
+                Self::text(
+                    &glyph.to_string(),
+                    bounds.size(),
+                    size,
+                    own_font.unwrap_or(font),
+                ),
+                bounds.center(),
+                color,
+                bounds,
+            ),
+            #[cfg(feature = "svg")]
+            Self::Svg(handle) => {
+                let extent = size.min(bounds.width).min(bounds.height);
+                svg::Renderer::draw(
+                    renderer,
+                    handle.clone(),
+                    Some(color),
+                    Rectangle {
+                        x: bounds.center_x() - extent / 2.0,
+                        y: bounds.center_y() - extent / 2.0,
+                        width: extent,
+                        height: extent,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Returns the centered text of a glyph.
+    fn text(content: &str, bounds: Size, size: f32, font: Font) -> Text<'_, Font> {
+        Text {
+            content,
+            bounds,
+            size: Pixels(size),
+            line_height: text::LineHeight::default(),
+            font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        }
+    }
+}
+
+impl Hash for IconHandle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Glyph(glyph, font) => {
+                0.hash(state);
+                glyph.hash(state);
+                font.hash(state);
+            }
+            #[cfg(feature = "svg")]
+            Self::Svg(handle) => {
+                1.hash(state);
+                handle.id().hash(state);
+            }
+        }
+    }
+}
+
+impl From<char> for IconHandle {
+    fn from(glyph: char) -> Self {
+        Self::Glyph(glyph, None)
+    }
+}
+
+impl From<super::Bootstrap> for IconHandle {
+    fn from(icon: super::Bootstrap) -> Self {
+        Self::glyph(icon, super::BOOTSTRAP_FONT)
+    }
+}
+
+#[cfg(feature = "nerd_icons")]
+impl From<super::Nerd> for IconHandle {
+    fn from(icon: super::Nerd) -> Self {
+        Self::glyph(icon, super::NERD_FONT)
+    }
+}
+
+#[cfg(feature = "svg")]
+impl From<svg::Handle> for IconHandle {
+    fn from(handle: svg::Handle) -> Self {
+        Self::Svg(handle)
+    }
+}
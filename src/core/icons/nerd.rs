@@ -1,7 +1,7 @@
 //! This file was automatically generated
 //! by [Mamba Bronze](https://github.com/Redhawk18/mamba-bronze)
 
-use crate::NERD_FONT;
+use super::NERD_FONT;
 use std::{
     fmt::{Display, Formatter, Result},
     string::String,
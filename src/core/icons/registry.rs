@@ -0,0 +1,145 @@
+//! The icon fonts registered with the crate, loaded together and looked up by
+//! name.
+//!
+//! The fonts bundled with the crate are always registered. An application
+//! registers its own icon fonts once, loads them all with
+//! [`load_icon_fonts`], and references their glyphs as an
+//! [`IconHandle`] carrying the font, or by selecting the font of the icons of
+//! a widget.
+//!
+//! # Example
+//! ```ignore
+//! # use iced::{Command, Font};
+//! # use iced_aw::{register_icon_font, IconFont, TabLabel};
+//! #
+//! const SYMBOLS: IconFont = IconFont::new("symbols", include_bytes!("symbols.ttf"));
+//!
+//! register_icon_font(SYMBOLS);
+//! let command: Command<_> = iced_aw::load_icon_fonts();
+//!
+//! let label = TabLabel::IconHandle(SYMBOLS.glyph('\u{E800}'));
+//! ```
+
+use super::IconHandle;
+use iced::{font, Command, Font};
+use std::sync::{PoisonError, RwLock};
+
+/// The icon fonts registered by the application.
+static REGISTERED: RwLock<Vec<IconFont>> = RwLock::new(Vec::new());
+
+/// An icon font, with its family name and the bytes to load it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IconFont {
+    /// The family name of the font.
+    name: &'static str,
+    /// The bytes of the font file.
+    bytes: &'static [u8],
+}
+
+impl IconFont {
+    /// The Bootstrap icon font bundled with the crate.
+    #[cfg(feature = "icons")]
+    pub const BOOTSTRAP: Self = Self::new("bootstrap-icons", super::BOOTSTRAP_FONT_BYTES);
+
+    /// The subset of the Bootstrap icon font needed by the widgets, bundled
+    /// with the crate.
+    #[cfg(not(feature = "icons"))]
+    pub const BOOTSTRAP: Self = Self::new("required-icons", super::BOOTSTRAP_FONT_BYTES);
+
+    /// The Nerd icon font bundled with the crate.
+    #[cfg(feature = "nerd_icons")]
+    pub const NERD: Self = Self::new("Symbols Nerd Font", super::NERD_FONT_BYTES);
+
+    /// Creates a new [`IconFont`] with the family name of the font and the
+    /// bytes of its file.
+    #[must_use]
+    pub const fn new(name: &'static str, bytes: &'static [u8]) -> Self {
+        Self { name, bytes }
+    }
+
+    /// Returns the family name of the [`IconFont`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the bytes of the file of the [`IconFont`].
+    #[must_use]
+    pub const fn bytes(self) -> &'static [u8] {
+        self.bytes
+    }
+
+    /// Returns the [`Font`] drawing the glyphs of the [`IconFont`], to
+    /// select it as the icon font of a widget.
+    #[must_use]
+    pub const fn font(self) -> Font {
+        Font::with_name(self.name)
+    }
+
+    /// Returns the [`IconHandle`] of the given glyph of the [`IconFont`].
+    #[must_use]
+    pub fn glyph(self, glyph: impl Into<char>) -> IconHandle {
+        IconHandle::glyph(glyph, self.font())
+    }
+
+    /// Returns the icon fonts bundled with the crate.
+    fn bundled() -> impl Iterator<Item = Self> {
+        let fonts: &[Self] = &[
+            Self::BOOTSTRAP,
+            #[cfg(feature = "nerd_icons")]
+            Self::NERD,
+        ];
+
+        fonts.iter().copied()
+    }
+}
+
+impl From<IconFont> for Font {
+    fn from(icon_font: IconFont) -> Self {
+        icon_font.font()
+    }
+}
+
+/// Registers an icon font with the crate, replacing the font registered with
+/// the same name, if any.
+pub fn register_icon_font(icon_font: IconFont) {
+    let mut registered = REGISTERED.write().unwrap_or_else(PoisonError::into_inner);
+
+    registered.retain(|registered| registered.name != icon_font.name);
+    registered.push(icon_font);
+}
+
+/// Returns the icon font with the given family name, among the fonts bundled
+/// with the crate and the registered fonts.
+#[must_use]
+pub fn icon_font(name: &str) -> Option<IconFont> {
+    icon_fonts()
+        .into_iter()
+        .find(|icon_font| icon_font.name == name)
+}
+
+/// Returns the icon fonts bundled with the crate, followed by the registered
+/// fonts in the order of their registration.
+#[must_use]
+pub fn icon_fonts() -> Vec<IconFont> {
+    let registered = REGISTERED.read().unwrap_or_else(PoisonError::into_inner);
+
+    IconFont::bundled()
+        .filter(|bundled| {
+            registered
+                .iter()
+                .all(|icon_font| icon_font.name != bundled.name)
+        })
+        .chain(registered.iter().copied())
+        .collect()
+}
+
+/// Returns the [`Command`] loading all the [`icon_fonts`] into the
+/// application.
+pub fn load_icon_fonts() -> Command<Result<(), font::Error>> {
+    Command::batch(
+        icon_fonts()
+            .into_iter()
+            .map(|icon_font| font::load(icon_font.bytes)),
+    )
+}
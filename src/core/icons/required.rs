@@ -2,7 +2,7 @@
 //! Machine generated code. Do not change!
 
 /// Bootstrap RequiredIcons
-#[derive(Copy, Clone, Debug, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Bootstrap {
     /// caret-down-fill
     CaretDownFill,
@@ -18,6 +18,47 @@ pub enum Bootstrap {
     X,
 }
 
+/// The names of the required glyphs of the Bootstrap font, sorted, with their
+/// icon.
+static NAMES: [(&str, Bootstrap); 6] = [
+    ("caret-down-fill", Bootstrap::CaretDownFill),
+    ("caret-left-fill", Bootstrap::CaretLeftFill),
+    ("caret-right-fill", Bootstrap::CaretRightFill),
+    ("caret-up-fill", Bootstrap::CaretUpFill),
+    ("check", Bootstrap::Check),
+    ("x", Bootstrap::X),
+];
+
+impl Bootstrap {
+    /// Returns the icon with the given name in the Bootstrap Icons set, like
+    /// `"caret-down-fill"`, if it is one of the required icons.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMES
+            .binary_search_by(|(other, _)| (*other).cmp(name))
+            .ok()
+            .map(|index| NAMES[index].1)
+    }
+
+    /// Returns the name of the icon in the Bootstrap Icons set.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::CaretDownFill => "caret-down-fill",
+            Self::CaretLeftFill => "caret-left-fill",
+            Self::CaretRightFill => "caret-right-fill",
+            Self::CaretUpFill => "caret-up-fill",
+            Self::Check => "check",
+            Self::X => "x",
+        }
+    }
+
+    /// Returns an iterator over the required icons, sorted by name.
+    pub fn all() -> impl Iterator<Item = Self> {
+        NAMES.iter().map(|(_, icon)| *icon)
+    }
+}
+
 /// Converts an Bootstrap into a char.
 #[must_use]
 pub const fn icon_to_char(icon: Bootstrap) -> char {
@@ -0,0 +1,329 @@
+//! Describe nested data, like JSON values, shown by a tree.
+//!
+//! *This API requires the following crate features to be activated: `json_tree`*
+
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// The kind of a value of nested data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ValueKind {
+    /// The absence of a value.
+    Null,
+    /// A boolean.
+    Bool,
+    /// A number.
+    Number,
+    /// A string.
+    String,
+    /// An ordered list of values.
+    Array,
+    /// A map of keys to values.
+    Object,
+}
+
+impl ValueKind {
+    /// Returns whether the values of this kind hold other values.
+    #[must_use]
+    pub fn is_container(self) -> bool {
+        matches!(self, Self::Array | Self::Object)
+    }
+}
+
+/// Nested data that can be shown by a tree, like a JSON value.
+///
+/// # Example
+/// Implementing it for `serde_json::Value`:
+/// ```ignore
+/// use iced_aw::json_tree::{PathSegment, TreeData, ValueKind};
+/// use serde_json::Value;
+///
+/// struct Json(Value);
+///
+/// impl TreeData for Json { /* ... */ }
+/// ```
+pub trait TreeData {
+    /// Returns the kind of the value.
+    fn kind(&self) -> ValueKind;
+
+    /// Returns the text of a value that is not a container, like `42` or
+    /// `true`. Strings are returned without quotes.
+    fn label(&self) -> String;
+
+    /// Returns the values held by a container with their key or index, in
+    /// order. Other values return no children.
+    fn children(&self) -> Vec<(PathSegment, &Self)>;
+}
+
+/// A step of a [`Path`]: the key of a value in an object or its index in an
+/// array.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PathSegment {
+    /// The key of a value in an object.
+    Key(String),
+    /// The index of a value in an array.
+    Index(usize),
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Key(key) => write!(f, "{key}"),
+            Self::Index(index) => write!(f, "{index}"),
+        }
+    }
+}
+
+/// The path from the root of nested data to one of its values.
+///
+/// It is displayed in the `JSONPath` notation, like `$.users[0].name`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Path(pub Vec<PathSegment>);
+
+impl Path {
+    /// Returns the path of the child at `segment` of the value at this path.
+    #[must_use]
+    pub fn join(&self, segment: PathSegment) -> Self {
+        let mut segments = self.0.clone();
+        segments.push(segment);
+        Self(segments)
+    }
+
+    /// Returns the number of steps from the root.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl Display for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "$")?;
+
+        for segment in &self.0 {
+            match segment {
+                PathSegment::Key(key)
+                    if !key.is_empty() && key.chars().all(|c| c.is_alphanumeric() || c == '_') =>
+                {
+                    write!(f, ".{key}")?;
+                }
+                PathSegment::Key(key) => {
+                    write!(
+                        f,
+                        "[\"{}\"]",
+                        key.replace('\\', "\\\\").replace('"', "\\\"")
+                    )?;
+                }
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A visible row of a tree.
+#[derive(Debug)]
+pub(crate) struct Row<'a, T> {
+    /// The path of the value of the row.
+    pub(crate) path: Path,
+    /// The value of the row.
+    pub(crate) value: &'a T,
+    /// Whether the children of the value are shown.
+    pub(crate) expanded: bool,
+    /// Whether the key or the value matches the searched text.
+    pub(crate) matches: bool,
+}
+
+/// Returns the visible rows of the tree of `root`, whose `expanded` values
+/// show their children. A non-empty `query` also shows the values matching
+/// it, case-insensitively, and marks them.
+pub(crate) fn rows<'a, T: TreeData>(
+    root: &'a T,
+    expanded: &HashSet<Path>,
+    query: &str,
+) -> Vec<Row<'a, T>> {
+    let query = query.to_lowercase();
+    let mut rows = Vec::new();
+    let _ = push_rows(root, &Path::default(), expanded, &query, &mut rows);
+    rows
+}
+
+/// Pushes the rows of `value` at `path` and of its visible descendants, and
+/// returns whether the value or one of its descendants matches `query`.
+fn push_rows<'a, T: TreeData>(
+    value: &'a T,
+    path: &Path,
+    expanded: &HashSet<Path>,
+    query: &str,
+    rows: &mut Vec<Row<'a, T>>,
+) -> bool {
+    let matches = !query.is_empty()
+        && (path
+            .0
+            .last()
+            .is_some_and(|segment| segment.to_string().to_lowercase().contains(query))
+            || (!value.kind().is_container() && value.label().to_lowercase().contains(query)));
+
+    let index = rows.len();
+    rows.push(Row {
+        path: path.clone(),
+        value,
+        expanded: false,
+        matches,
+    });
+
+    if !value.kind().is_container() {
+        return matches;
+    }
+
+    let mut descendants = Vec::new();
+    let mut found = false;
+    for (segment, child) in value.children() {
+        found |= push_rows(
+            child,
+            &path.join(segment),
+            expanded,
+            query,
+            &mut descendants,
+        );
+    }
+
+    // A value is expanded by the user, or to reveal the matches of the search.
+    let expanded = expanded.contains(path) || found;
+    rows[index].expanded = expanded;
+
+    if expanded {
+        rows.extend(descendants);
+    }
+
+    matches || found
+}
+
+/// Returns the paths of the containers of the tree of `root` down to
+/// `depth`, the root being at depth 0.
+pub(crate) fn paths_to_depth<T: TreeData>(root: &T, depth: usize) -> HashSet<Path> {
+    let mut paths = HashSet::new();
+    let mut stack = vec![(Path::default(), root)];
+
+    while let Some((path, value)) = stack.pop() {
+        if path.depth() >= depth || !value.kind().is_container() {
+            continue;
+        }
+
+        for (segment, child) in value.children() {
+            stack.push((path.join(segment), child));
+        }
+        let _ = paths.insert(path);
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{paths_to_depth, rows, Path, PathSegment, TreeData, ValueKind};
+    use std::collections::HashSet;
+
+    enum Value {
+        Number(i32),
+        String(&'static str),
+        Array(Vec<Self>),
+        Object(Vec<(&'static str, Self)>),
+    }
+
+    impl TreeData for Value {
+        fn kind(&self) -> ValueKind {
+            match self {
+                Self::Number(_) => ValueKind::Number,
+                Self::String(_) => ValueKind::String,
+                Self::Array(_) => ValueKind::Array,
+                Self::Object(_) => ValueKind::Object,
+            }
+        }
+
+        fn label(&self) -> String {
+            match self {
+                Self::Number(number) => number.to_string(),
+                Self::String(string) => (*string).to_owned(),
+                Self::Array(_) | Self::Object(_) => String::new(),
+            }
+        }
+
+        fn children(&self) -> Vec<(PathSegment, &Self)> {
+            match self {
+                Self::Array(values) => values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| (PathSegment::Index(index), value))
+                    .collect(),
+                Self::Object(entries) => entries
+                    .iter()
+                    .map(|(key, value)| (PathSegment::Key((*key).to_owned()), value))
+                    .collect(),
+                Self::Number(_) | Self::String(_) => Vec::new(),
+            }
+        }
+    }
+
+    fn sample() -> Value {
+        Value::Object(vec![
+            (
+                "users",
+                Value::Array(vec![Value::Object(vec![
+                    ("name", Value::String("Ada")),
+                    ("age", Value::Number(36)),
+                ])]),
+            ),
+            ("total count", Value::Number(1)),
+        ])
+    }
+
+    #[test]
+    fn path_display_test() {
+        let path = Path::default()
+            .join(PathSegment::Key("users".to_owned()))
+            .join(PathSegment::Index(0))
+            .join(PathSegment::Key("name".to_owned()));
+        assert_eq!(path.to_string(), "$.users[0].name");
+
+        let path = Path::default().join(PathSegment::Key("total \"count\"".to_owned()));
+        assert_eq!(path.to_string(), "$[\"total \\\"count\\\"\"]");
+    }
+
+    #[test]
+    fn rows_test() {
+        let root = sample();
+
+        let collapsed = rows(&root, &HashSet::new(), "");
+        assert_eq!(collapsed.len(), 1);
+        assert!(!collapsed[0].expanded);
+
+        let expanded = rows(&root, &paths_to_depth(&root, 1), "");
+        let paths: Vec<String> = expanded.iter().map(|row| row.path.to_string()).collect();
+        assert_eq!(paths, vec!["$", "$.users", "$[\"total count\"]"]);
+    }
+
+    #[test]
+    fn search_test() {
+        let root = sample();
+
+        let found = rows(&root, &HashSet::new(), "ada");
+        let paths: Vec<String> = found.iter().map(|row| row.path.to_string()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "$",
+                "$.users",
+                "$.users[0]",
+                "$.users[0].name",
+                "$.users[0].age",
+                "$[\"total count\"]"
+            ]
+        );
+        let matching: Vec<bool> = found.iter().map(|row| row.matches).collect();
+        assert_eq!(matching, vec![false, false, false, true, false, false]);
+    }
+}
@@ -0,0 +1,70 @@
+//! Reuse the layout of a widget while it is laid out again with the same
+//! limits and the same content.
+use iced::advanced::layout::{Limits, Node};
+
+/// The last layout of a widget and the limits it was computed with.
+///
+/// The widget invalidates it when its content may have changed: when it is
+/// diffed with the widget of a new view, or when one of its children
+/// invalidates the layout while handling an event.
+#[derive(Clone, Debug, Default)]
+pub struct LayoutCache {
+    /// The limits of the last layout and its node.
+    last: Option<(Limits, Node)>,
+}
+
+impl LayoutCache {
+    /// Returns the last layout if it was computed for the same limits, or
+    /// computes and keeps the layout with `layout`.
+    pub fn get_or_layout(&mut self, limits: &Limits, layout: impl FnOnce() -> Node) -> &Node {
+        if !matches!(&self.last, Some((last, _)) if last == limits) {
+            self.last = None;
+        }
+
+        &self.last.get_or_insert_with(|| (*limits, layout())).1
+    }
+
+    /// Returns the last layout, if it was not invalidated since.
+    pub fn node(&self) -> Option<&Node> {
+        self.last.as_ref().map(|(_, node)| node)
+    }
+
+    /// Drops the last layout, so that the next one is computed again.
+    pub fn invalidate(&mut self) {
+        self.last = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LayoutCache;
+    use iced::{
+        advanced::layout::{Limits, Node},
+        Size,
+    };
+
+    #[test]
+    fn reuse() {
+        let mut cache = LayoutCache::default();
+        let mut count = 0;
+        let mut layout = |cache: &mut LayoutCache, limits: &Limits| {
+            cache
+                .get_or_layout(limits, || {
+                    count += 1;
+                    Node::new(limits.max())
+                })
+                .clone()
+        };
+        let small = Limits::new(Size::ZERO, Size::new(100.0, 50.0));
+        let large = Limits::new(Size::ZERO, Size::new(200.0, 50.0));
+
+        assert_eq!(layout(&mut cache, &small).size(), small.max());
+        let _ = layout(&mut cache, &small);
+        assert_eq!(layout(&mut cache, &large).size(), large.max());
+
+        cache.invalidate();
+        let _ = layout(&mut cache, &large);
+
+        assert_eq!(count, 3);
+    }
+}
@@ -0,0 +1,64 @@
+//! The content of an overlay that is only built once the overlay is open.
+use iced::{advanced::widget::Tree, Element};
+
+/// A closure building the content of an overlay.
+type View<'a, Message, Theme, Renderer> =
+    Box<dyn FnOnce() -> Element<'a, Message, Theme, Renderer> + 'a>;
+
+/// The content of an overlay, either built with the view or built from a
+/// closure the first time the overlay is shown.
+pub struct LazyContent<'a, Message, Theme, Renderer> {
+    /// The built content.
+    element: Option<Element<'a, Message, Theme, Renderer>>,
+    /// The closure building the content, until it is called.
+    view: Option<View<'a, Message, Theme, Renderer>>,
+}
+
+impl<'a, Message, Theme, Renderer> LazyContent<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a [`LazyContent`] from content built with the view.
+    pub fn new(element: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            element: Some(element.into()),
+            view: None,
+        }
+    }
+
+    /// Creates a [`LazyContent`] built by `view` when the overlay is shown.
+    pub fn lazy(view: impl FnOnce() -> Element<'a, Message, Theme, Renderer> + 'a) -> Self {
+        Self {
+            element: None,
+            view: Some(Box::new(view)),
+        }
+    }
+
+    /// Returns the state of the content, empty until it is built.
+    pub fn tree(&self) -> Tree {
+        self.element.as_ref().map_or_else(Tree::empty, Tree::new)
+    }
+
+    /// Diffs the state of the content if it is already built. Lazy content
+    /// is diffed when it is built.
+    pub fn diff(&self, tree: &mut Tree) {
+        if let Some(element) = &self.element {
+            tree.diff(element);
+        }
+    }
+
+    /// Returns the content, building and diffing it with `tree` first if
+    /// needed.
+    pub fn get_or_build(
+        &mut self,
+        tree: &mut Tree,
+    ) -> Option<&mut Element<'a, Message, Theme, Renderer>> {
+        if let Some(view) = self.view.take() {
+            let element = view();
+            tree.diff(&element);
+            self.element = Some(element);
+        }
+
+        self.element.as_mut()
+    }
+}
@@ -0,0 +1,198 @@
+//! Store the lines of a log in a ring buffer.
+//!
+//! *This API requires the following crate features to be activated: `log_view`*
+
+use std::collections::VecDeque;
+
+/// The severity of a line of a log, from the least to the most severe.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// A detailed trace of the execution.
+    Trace,
+    /// A message helping to debug.
+    Debug,
+    /// A normal message.
+    #[default]
+    Info,
+    /// A problem that does not stop the execution.
+    Warn,
+    /// A failure.
+    Error,
+}
+
+impl Severity {
+    /// Detects the severity of `line` from the first level it names, like
+    /// `ERROR` or `warn`, or returns [`Severity::Info`].
+    #[must_use]
+    pub fn detect(line: &str) -> Self {
+        line.split(|c: char| !c.is_ascii_alphabetic())
+            .find_map(|word| match word.to_ascii_uppercase().as_str() {
+                "TRACE" => Some(Self::Trace),
+                "DEBUG" => Some(Self::Debug),
+                "INFO" => Some(Self::Info),
+                "WARN" | "WARNING" => Some(Self::Warn),
+                "ERROR" | "ERR" | "FATAL" | "CRITICAL" => Some(Self::Error),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// A line of a log.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogLine {
+    /// The text of the line.
+    pub text: String,
+    /// The severity of the line.
+    pub severity: Severity,
+}
+
+/// The lines of a log, dropping the oldest ones past its capacity.
+#[derive(Clone, Debug)]
+pub struct LogBuffer {
+    /// The kept lines, from the oldest.
+    lines: VecDeque<LogLine>,
+    /// The largest number of kept lines.
+    capacity: usize,
+    /// The number of lines dropped since the creation of the buffer.
+    dropped: u64,
+}
+
+impl LogBuffer {
+    /// Creates a new [`LogBuffer`] keeping at most `capacity` lines.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+
+        Self {
+            lines: VecDeque::with_capacity(capacity.min(4096)),
+            capacity,
+            dropped: 0,
+        }
+    }
+
+    /// Appends `line`, detecting its severity from its text.
+    pub fn push(&mut self, line: impl Into<String>) {
+        let text = line.into();
+        let severity = Severity::detect(&text);
+
+        self.push_with(severity, text);
+    }
+
+    /// Appends `line` with the given severity.
+    pub fn push_with(&mut self, severity: Severity, line: impl Into<String>) {
+        if self.lines.len() == self.capacity {
+            let _ = self.lines.pop_front();
+            self.dropped += 1;
+        }
+
+        self.lines.push_back(LogLine {
+            text: line.into(),
+            severity,
+        });
+    }
+
+    /// Removes every line.
+    pub fn clear(&mut self) {
+        self.dropped += self.lines.len() as u64;
+        self.lines.clear();
+    }
+
+    /// Returns the number of kept lines.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns whether no line is kept.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Returns the largest number of kept lines.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of lines dropped since the creation of the buffer.
+    #[must_use]
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Returns the kept line at `index`, from the oldest.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&LogLine> {
+        self.lines.get(index)
+    }
+
+    /// Returns the kept lines, from the oldest.
+    pub fn iter(&self) -> impl Iterator<Item = &LogLine> {
+        self.lines.iter()
+    }
+
+    /// Returns the indices of the kept lines containing `query`,
+    /// case-insensitively.
+    #[must_use]
+    pub fn find(&self, query: &str) -> Vec<usize> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let query = query.to_lowercase();
+        self.lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.text.to_lowercase().contains(&query))
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+impl<S: Into<String>> Extend<S> for LogBuffer {
+    fn extend<T: IntoIterator<Item = S>>(&mut self, lines: T) {
+        for line in lines {
+            self.push(line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LogBuffer, Severity};
+
+    #[test]
+    fn detect_test() {
+        assert_eq!(Severity::detect("[ERROR] disk full"), Severity::Error);
+        assert_eq!(Severity::detect("2024-01-01 warn: slow"), Severity::Warn);
+        assert_eq!(Severity::detect("DEBUG x=1"), Severity::Debug);
+        assert_eq!(Severity::detect("started the server"), Severity::Info);
+        assert_eq!(Severity::detect("errors=0"), Severity::Info);
+    }
+
+    #[test]
+    fn ring_buffer_test() {
+        let mut buffer = LogBuffer::new(3);
+        buffer.extend(["a", "b", "c", "d"]);
+
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.dropped(), 1);
+        let texts: Vec<&str> = buffer.iter().map(|line| line.text.as_str()).collect();
+        assert_eq!(texts, vec!["b", "c", "d"]);
+
+        buffer.clear();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.dropped(), 4);
+    }
+
+    #[test]
+    fn find_test() {
+        let mut buffer = LogBuffer::new(10);
+        buffer.extend(["Connected", "timeout", "Reconnected"]);
+
+        assert_eq!(buffer.find("connect"), vec![0, 2]);
+        assert!(buffer.find("").is_empty());
+    }
+}
@@ -0,0 +1,233 @@
+//! Format masks restricting the text typed in a field.
+//!
+//! *This API requires the following crate features to be activated: `masked_input`*
+
+use std::fmt::{self, Display};
+
+/// A position of a [`Mask`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Slot {
+    /// A digit typed by the user, written `#`.
+    Digit,
+    /// A letter typed by the user, written `A`.
+    Letter,
+    /// A digit or a letter typed by the user, written `*`.
+    Alphanumeric,
+    /// A character inserted automatically.
+    Literal(char),
+}
+
+impl Slot {
+    /// Whether `c` can be typed in this slot.
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Alphanumeric => c.is_alphanumeric(),
+            Self::Literal(_) => false,
+        }
+    }
+}
+
+/// A format mask like `(###) ###-####` or `##/##/####`.
+///
+/// In the pattern `#` stands for a digit, `A` for a letter and `*` for a digit
+/// or a letter. Every other character is a literal inserted automatically,
+/// and a backslash makes the next character a literal.
+///
+/// The characters typed in the slots make the raw value, and the raw value
+/// with the literals before each typed character makes the formatted value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mask {
+    /// The positions of the mask.
+    slots: Vec<Slot>,
+}
+
+impl Mask {
+    /// Creates a new [`Mask`] from its pattern.
+    #[must_use]
+    pub fn new(pattern: &str) -> Self {
+        let mut chars = pattern.chars();
+        let mut slots = Vec::new();
+
+        while let Some(c) = chars.next() {
+            slots.push(match c {
+                '#' => Slot::Digit,
+                'A' => Slot::Letter,
+                '*' => Slot::Alphanumeric,
+                '\\' => Slot::Literal(chars.next().unwrap_or('\\')),
+                c => Slot::Literal(c),
+            });
+        }
+
+        Self { slots }
+    }
+
+    /// Returns the number of characters the user can type.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| !matches!(slot, Slot::Literal(_)))
+            .count()
+    }
+
+    /// Returns the raw value typed in `text`, skipping the literals and the
+    /// characters that do not fit the next slot.
+    #[must_use]
+    pub fn extract(&self, text: &str) -> String {
+        let mut slots = self
+            .slots
+            .iter()
+            .filter(|slot| !matches!(slot, Slot::Literal(_)))
+            .peekable();
+        let mut raw = String::new();
+
+        for c in text.chars() {
+            let Some(slot) = slots.peek() else {
+                break;
+            };
+
+            if slot.accepts(c) {
+                raw.push(c);
+                let _ = slots.next();
+            }
+        }
+
+        raw
+    }
+
+    /// Returns the formatted value of the `raw` value, where the literals
+    /// before each typed character are inserted.
+    #[must_use]
+    pub fn format(&self, raw: &str) -> String {
+        let raw = self.extract(raw);
+        let mut chars = raw.chars().peekable();
+        let mut formatted = String::new();
+        let mut literals = String::new();
+
+        for slot in &self.slots {
+            if chars.peek().is_none() {
+                break;
+            }
+
+            if let Slot::Literal(c) = slot {
+                literals.push(*c);
+            } else {
+                formatted.push_str(&literals);
+                literals.clear();
+                formatted.extend(chars.next());
+            }
+        }
+
+        formatted
+    }
+
+    /// Whether every slot of the mask is filled by the `raw` value.
+    #[must_use]
+    pub fn is_complete(&self, raw: &str) -> bool {
+        self.extract(raw).chars().count() == self.capacity()
+    }
+
+    /// Returns the index of the character following the first `count` typed
+    /// characters in a formatted value.
+    #[must_use]
+    pub fn position(&self, count: usize) -> usize {
+        if count == 0 {
+            return 0;
+        }
+
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| !matches!(slot, Slot::Literal(_)))
+            .nth(count - 1)
+            .map_or(self.slots.len(), |(index, _)| index + 1)
+    }
+
+    /// Returns the pattern with every slot written as `placeholder`.
+    #[must_use]
+    pub fn template(&self, placeholder: char) -> String {
+        self.slots
+            .iter()
+            .map(|slot| match slot {
+                Slot::Literal(c) => *c,
+                _ => placeholder,
+            })
+            .collect()
+    }
+}
+
+impl From<&str> for Mask {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl Display for Mask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for slot in &self.slots {
+            match slot {
+                Slot::Digit => write!(f, "#")?,
+                Slot::Letter => write!(f, "A")?,
+                Slot::Alphanumeric => write!(f, "*")?,
+                Slot::Literal(c @ ('#' | 'A' | '*' | '\\')) => write!(f, "\\{c}")?,
+                Slot::Literal(c) => write!(f, "{c}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mask;
+
+    #[test]
+    fn extract() {
+        let mask = Mask::new("(###) ###-####");
+
+        assert_eq!(mask.capacity(), 10);
+        assert_eq!(mask.extract("(555) 123-4567"), "5551234567");
+        assert_eq!(mask.extract("5a5(5"), "555");
+        assert_eq!(mask.extract("555123456789"), "5551234567");
+    }
+
+    #[test]
+    fn format() {
+        let mask = Mask::new("(###) ###-####");
+
+        assert_eq!(mask.format(""), "");
+        assert_eq!(mask.format("5"), "(5");
+        assert_eq!(mask.format("5551"), "(555) 1");
+        assert_eq!(mask.format("5551234567"), "(555) 123-4567");
+        assert!(mask.is_complete("5551234567"));
+        assert!(!mask.is_complete("555"));
+
+        let mask = Mask::new("AA-##");
+        assert_eq!(mask.format("ab12"), "ab-12");
+        assert_eq!(mask.format("a1"), "a");
+    }
+
+    #[test]
+    fn position() {
+        let mask = Mask::new("##/##/####");
+
+        assert_eq!(mask.position(0), 0);
+        assert_eq!(mask.position(2), 2);
+        assert_eq!(mask.position(3), 4);
+        assert_eq!(mask.position(8), 10);
+        assert_eq!(mask.position(9), 10);
+    }
+
+    #[test]
+    fn escape() {
+        let mask = Mask::new("\\#####");
+
+        assert_eq!(mask.capacity(), 4);
+        assert_eq!(mask.format("12"), "#12");
+        assert_eq!(mask.template('_'), "#____");
+        assert_eq!(mask.to_string(), "\\#####");
+    }
+}
@@ -0,0 +1,104 @@
+//! Describe the keys of a number pad and how they edit a value.
+//!
+//! *This API requires the following crate features to be activated: `num_pad`*
+
+/// A key of a [`NumPad`](crate::widgets::num_pad::NumPad).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NumPadKey {
+    /// A digit, from 0 to 9.
+    Digit(u8),
+    /// The decimal separator.
+    Decimal,
+    /// Removes the last character.
+    Backspace,
+    /// Confirms the value.
+    Confirm,
+}
+
+impl NumPadKey {
+    /// The keys of the grid of a number pad, row by row, as on a
+    /// phone. The confirm key spans the row under the grid.
+    pub(crate) const GRID: [[Self; 3]; 4] = [
+        [Self::Digit(7), Self::Digit(8), Self::Digit(9)],
+        [Self::Digit(4), Self::Digit(5), Self::Digit(6)],
+        [Self::Digit(1), Self::Digit(2), Self::Digit(3)],
+        [Self::Decimal, Self::Digit(0), Self::Backspace],
+    ];
+}
+
+/// Returns `value` edited by `key`, or `None` if the key leaves it
+/// unchanged. The value keeps at most one decimal separator, only if
+/// `decimal` is allowed, and at most `max_length` characters.
+pub(crate) fn apply(
+    value: &str,
+    key: NumPadKey,
+    decimal: bool,
+    max_length: Option<usize>,
+) -> Option<String> {
+    let full = max_length.is_some_and(|max_length| value.chars().count() >= max_length);
+
+    match key {
+        // A leading zero is replaced by the digit typed after it.
+        NumPadKey::Digit(digit) if value == "0" => Some(digit.min(9).to_string()),
+        NumPadKey::Digit(_) if full => None,
+        NumPadKey::Digit(digit) => Some(format!("{value}{}", digit.min(9))),
+        NumPadKey::Decimal if !decimal || full || value.contains('.') => None,
+        NumPadKey::Decimal if value.is_empty() => Some(String::from("0.")),
+        NumPadKey::Decimal => Some(format!("{value}.")),
+        NumPadKey::Backspace => {
+            let mut chars = value.chars();
+            chars.next_back().map(|_| chars.as_str().to_owned())
+        }
+        NumPadKey::Confirm => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply, NumPadKey};
+
+    #[test]
+    fn digits_are_appended() {
+        assert_eq!(
+            apply("", NumPadKey::Digit(4), true, None).as_deref(),
+            Some("4")
+        );
+        assert_eq!(
+            apply("4", NumPadKey::Digit(2), true, None).as_deref(),
+            Some("42")
+        );
+        assert_eq!(
+            apply("0", NumPadKey::Digit(7), true, None).as_deref(),
+            Some("7")
+        );
+        assert_eq!(apply("12", NumPadKey::Digit(3), true, Some(2)), None);
+    }
+
+    #[test]
+    fn decimal_is_added_once() {
+        assert_eq!(
+            apply("", NumPadKey::Decimal, true, None).as_deref(),
+            Some("0.")
+        );
+        assert_eq!(
+            apply("3", NumPadKey::Decimal, true, None).as_deref(),
+            Some("3.")
+        );
+        assert_eq!(apply("3.1", NumPadKey::Decimal, true, None), None);
+        assert_eq!(apply("3", NumPadKey::Decimal, false, None), None);
+        assert_eq!(
+            apply("0.", NumPadKey::Digit(5), true, None).as_deref(),
+            Some("0.5")
+        );
+    }
+
+    #[test]
+    fn backspace_removes_the_last_character() {
+        assert_eq!(
+            apply("3.1", NumPadKey::Backspace, true, None).as_deref(),
+            Some("3.")
+        );
+        assert_eq!(apply("", NumPadKey::Backspace, true, None), None);
+        assert_eq!(apply("3", NumPadKey::Confirm, true, None), None);
+    }
+}
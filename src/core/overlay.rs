@@ -1,6 +1,95 @@
 //! Helper functions for overlays
 
-use iced::{advanced::layout, Point, Size};
+use iced::{advanced::layout, Point, Rectangle, Size};
+
+/// The side of a target an anchored overlay is shown on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Placement {
+    /// Above the target.
+    Top,
+    /// Below the target.
+    #[default]
+    Bottom,
+    /// Left of the target.
+    Left,
+    /// Right of the target.
+    Right,
+}
+
+impl Placement {
+    /// Returns the opposite side.
+    #[must_use]
+    pub const fn flipped(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    /// Returns the position of an overlay of the given `size` on this side
+    /// of the `target`, `gap` away from it and centered along it.
+    #[must_use]
+    pub fn position(self, target: Rectangle, size: Size, gap: f32) -> Point {
+        match self {
+            Self::Top => Point::new(
+                target.center_x() - size.width / 2.0,
+                target.y - gap - size.height,
+            ),
+            Self::Bottom => Point::new(
+                target.center_x() - size.width / 2.0,
+                target.y + target.height + gap,
+            ),
+            Self::Left => Point::new(
+                target.x - gap - size.width,
+                target.center_y() - size.height / 2.0,
+            ),
+            Self::Right => Point::new(
+                target.x + target.width + gap,
+                target.center_y() - size.height / 2.0,
+            ),
+        }
+    }
+}
+
+/// Returns the position of an overlay of the given `size` anchored to the
+/// `target`, in a window of the given `bounds`.
+///
+/// The overlay is placed on the `placement` side of the target, `gap` away
+/// from it. It flips to the opposite side when it does not fit on its side
+/// but fits there, then is kept inside the window.
+#[must_use]
+pub fn anchor(
+    target: Rectangle,
+    size: Size,
+    placement: Placement,
+    gap: f32,
+    bounds: Size,
+) -> Point {
+    let fits = |position: Point| match placement {
+        Placement::Top | Placement::Bottom => {
+            position.y >= 0.0 && position.y + size.height <= bounds.height
+        }
+        Placement::Left | Placement::Right => {
+            position.x >= 0.0 && position.x + size.width <= bounds.width
+        }
+    };
+    let preferred = placement.position(target, size, gap);
+    let flipped = placement.flipped().position(target, size, gap);
+    let position = if fits(preferred) || !fits(flipped) {
+        preferred
+    } else {
+        flipped
+    };
+
+    Point::new(
+        position.x.clamp(0.0, (bounds.width - size.width).max(0.0)),
+        position
+            .y
+            .clamp(0.0, (bounds.height - size.height).max(0.0)),
+    )
+}
 
 /// Trait containing functions for positioning of nodes.
 pub trait Position {
@@ -36,3 +125,52 @@ impl Position for layout::Node {
         ));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{anchor, Placement};
+    use iced::{Point, Rectangle, Size};
+
+    const WINDOW: Size = Size::new(400.0, 300.0);
+
+    #[test]
+    fn overlay_is_placed_on_its_side() {
+        let target = Rectangle::new(Point::new(100.0, 100.0), Size::new(40.0, 20.0));
+        let size = Size::new(60.0, 30.0);
+
+        assert_eq!(
+            anchor(target, size, Placement::Bottom, 4.0, WINDOW),
+            Point::new(90.0, 124.0)
+        );
+        assert_eq!(
+            anchor(target, size, Placement::Top, 4.0, WINDOW),
+            Point::new(90.0, 66.0)
+        );
+        assert_eq!(
+            anchor(target, size, Placement::Right, 4.0, WINDOW),
+            Point::new(144.0, 95.0)
+        );
+    }
+
+    #[test]
+    fn overlay_flips_when_it_does_not_fit() {
+        let target = Rectangle::new(Point::new(100.0, 260.0), Size::new(40.0, 20.0));
+        let size = Size::new(60.0, 30.0);
+
+        assert_eq!(
+            anchor(target, size, Placement::Bottom, 4.0, WINDOW),
+            Point::new(90.0, 226.0)
+        );
+    }
+
+    #[test]
+    fn overlay_is_kept_inside_the_window() {
+        let target = Rectangle::new(Point::new(0.0, 100.0), Size::new(20.0, 20.0));
+        let size = Size::new(60.0, 400.0);
+
+        assert_eq!(
+            anchor(target, size, Placement::Bottom, 4.0, WINDOW),
+            Point::new(0.0, 0.0)
+        );
+    }
+}
@@ -0,0 +1,86 @@
+//! Estimate the strength of passwords.
+//!
+//! *This API requires the following crate features to be activated: `password_input`*
+
+/// The strength of a password, from the weakest to the strongest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Strength {
+    /// A password that is easily guessed.
+    Weak,
+    /// A password that resists simple guesses.
+    Fair,
+    /// A password that resists most guesses.
+    Good,
+    /// A password that is hard to guess.
+    Strong,
+}
+
+impl Strength {
+    /// The shortest password that is not [`Strength::Weak`].
+    const MIN_LENGTH: usize = 6;
+
+    /// Estimates the strength of `password` from its length and from the
+    /// kinds of characters it mixes: lowercase and uppercase letters, digits
+    /// and symbols.
+    #[must_use]
+    pub fn estimate(password: &str) -> Self {
+        let length = password.chars().count();
+
+        if length < Self::MIN_LENGTH {
+            return Self::Weak;
+        }
+
+        let has = |test: fn(&char) -> bool| password.chars().any(|c| test(&c));
+        let score = [
+            length >= 8,
+            length >= 12,
+            has(char::is_ascii_lowercase) && has(char::is_ascii_uppercase),
+            has(char::is_ascii_digit),
+            has(|c| !c.is_alphanumeric() && !c.is_whitespace()),
+        ]
+        .into_iter()
+        .filter(|passed| *passed)
+        .count();
+
+        match score {
+            0 | 1 => Self::Weak,
+            2 => Self::Fair,
+            3 => Self::Good,
+            _ => Self::Strong,
+        }
+    }
+
+    /// Returns the part of a strength meter filled for this strength.
+    #[must_use]
+    pub fn ratio(self) -> f32 {
+        match self {
+            Self::Weak => 0.25,
+            Self::Fair => 0.5,
+            Self::Good => 0.75,
+            Self::Strong => 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Strength;
+
+    #[test]
+    fn estimate() {
+        assert_eq!(Strength::estimate(""), Strength::Weak);
+        assert_eq!(Strength::estimate("Ab1!"), Strength::Weak);
+        assert_eq!(Strength::estimate("password"), Strength::Weak);
+        assert_eq!(Strength::estimate("password1"), Strength::Fair);
+        assert_eq!(Strength::estimate("Password1"), Strength::Good);
+        assert_eq!(Strength::estimate("Password1!"), Strength::Strong);
+        assert_eq!(Strength::estimate("correct horse battery"), Strength::Fair);
+    }
+
+    #[test]
+    fn ratio() {
+        assert!(Strength::Weak.ratio() < Strength::Fair.ratio());
+        assert!(Strength::Good.ratio() < Strength::Strong.ratio());
+        assert!((Strength::Strong.ratio() - 1.0).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,262 @@
+//! Describe the properties edited by a property grid.
+//!
+//! *This API requires the following crate features to be activated: `property_grid`*
+
+use iced::Color;
+use std::collections::HashSet;
+
+/// The value of a [`Property`], choosing its editor.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropertyValue {
+    /// A text, edited by a text input.
+    Text(String),
+    /// A number, edited by a text input accepting numbers.
+    Number(f64),
+    /// A boolean, edited by a checkbox.
+    Bool(bool),
+    /// A color, edited by a text input accepting hexadecimal colors.
+    Color(Color),
+    /// One of several options, edited by a drop down list.
+    Choice {
+        /// The options to choose from.
+        options: Vec<String>,
+        /// The index of the chosen option.
+        selected: usize,
+    },
+}
+
+impl PropertyValue {
+    /// Creates a [`PropertyValue::Choice`] of the `options`, choosing the
+    /// one at `selected`.
+    pub fn choice<T: ToString>(options: impl IntoIterator<Item = T>, selected: usize) -> Self {
+        Self::Choice {
+            options: options
+                .into_iter()
+                .map(|option| option.to_string())
+                .collect(),
+            selected,
+        }
+    }
+
+    /// Returns the text edited by the text input of a number or a color.
+    #[must_use]
+    pub fn to_text(&self) -> Option<String> {
+        match self {
+            Self::Number(number) => Some(number.to_string()),
+            Self::Color(color) => Some(color_to_hex(*color)),
+            _ => None,
+        }
+    }
+
+    /// Parses `text` as a value of the same kind as this number or color.
+    #[must_use]
+    pub fn parse(&self, text: &str) -> Option<Self> {
+        match self {
+            Self::Number(_) => text
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|number| number.is_finite())
+                .map(Self::Number),
+            Self::Color(_) => color_from_hex(text).map(Self::Color),
+            _ => None,
+        }
+    }
+}
+
+/// A labeled value edited by a property grid, optionally in a group.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Property {
+    /// The label of the property.
+    pub label: String,
+    /// The group listing the property, if any.
+    pub group: Option<String>,
+    /// The value of the property.
+    pub value: PropertyValue,
+}
+
+impl Property {
+    /// Creates a new [`Property`] with the given label and value.
+    pub fn new(label: impl Into<String>, value: PropertyValue) -> Self {
+        Self {
+            label: label.into(),
+            group: None,
+            value,
+        }
+    }
+
+    /// Lists the [`Property`] in the given group.
+    #[must_use]
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+}
+
+/// A row of a property grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Item<'a> {
+    /// The header of a group, and whether it is collapsed.
+    Group(&'a str, bool),
+    /// The property at the index.
+    Property(usize),
+}
+
+/// Returns the rows showing the `properties`: the ones without a group
+/// first, then each group in the order of its first property.
+///
+/// Only the properties whose label contains `query`, case-insensitively, are
+/// listed, and the groups of the matching properties are never collapsed.
+pub(crate) fn items<'a>(
+    properties: &'a [Property],
+    query: &str,
+    collapsed: &HashSet<String>,
+) -> Vec<Item<'a>> {
+    let query = query.to_lowercase();
+    let shown = |property: &&Property| property.label.to_lowercase().contains(&query);
+
+    let mut items: Vec<Item<'a>> = properties
+        .iter()
+        .enumerate()
+        .filter(|(_, property)| property.group.is_none() && shown(property))
+        .map(|(index, _)| Item::Property(index))
+        .collect();
+
+    let mut groups: Vec<&str> = Vec::new();
+    for group in properties
+        .iter()
+        .filter_map(|property| property.group.as_deref())
+    {
+        if !groups.contains(&group) {
+            groups.push(group);
+        }
+    }
+
+    for group in groups {
+        let listed: Vec<usize> = properties
+            .iter()
+            .enumerate()
+            .filter(|(_, property)| property.group.as_deref() == Some(group) && shown(property))
+            .map(|(index, _)| index)
+            .collect();
+        if listed.is_empty() {
+            continue;
+        }
+
+        let is_collapsed = query.is_empty() && collapsed.contains(group);
+        items.push(Item::Group(group, is_collapsed));
+        if !is_collapsed {
+            items.extend(listed.into_iter().map(Item::Property));
+        }
+    }
+
+    items
+}
+
+/// Formats `color` like `#rrggbb`, or `#rrggbbaa` when it is translucent.
+fn color_to_hex(color: Color) -> String {
+    let [r, g, b, a] = color.into_rgba8();
+
+    if a == u8::MAX {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    }
+}
+
+/// Parses a color like `#rrggbb` or `#rrggbbaa`, the `#` being optional.
+fn color_from_hex(text: &str) -> Option<Color> {
+    let hex = text.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if !matches!(hex.len(), 6 | 8) || !hex.is_ascii() {
+        return None;
+    }
+
+    let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+    let alpha = if hex.len() == 8 { channel(6)? } else { u8::MAX };
+
+    Some(Color::from_rgba8(
+        channel(0)?,
+        channel(2)?,
+        channel(4)?,
+        f32::from(alpha) / 255.0,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{items, Item, Property, PropertyValue};
+    use iced::Color;
+    use std::collections::HashSet;
+
+    fn properties() -> Vec<Property> {
+        vec![
+            Property::new("Width", PropertyValue::Number(10.0)).group("Layout"),
+            Property::new("Name", PropertyValue::Text(String::from("button"))),
+            Property::new("Color", PropertyValue::Color(Color::BLACK)).group("Style"),
+            Property::new("Height", PropertyValue::Number(4.0)).group("Layout"),
+        ]
+    }
+
+    #[test]
+    fn items_test() {
+        let properties = properties();
+        let mut collapsed = HashSet::new();
+
+        assert_eq!(
+            items(&properties, "", &collapsed),
+            vec![
+                Item::Property(1),
+                Item::Group("Layout", false),
+                Item::Property(0),
+                Item::Property(3),
+                Item::Group("Style", false),
+                Item::Property(2),
+            ]
+        );
+
+        let _ = collapsed.insert(String::from("Layout"));
+        assert_eq!(
+            items(&properties, "", &collapsed),
+            vec![
+                Item::Property(1),
+                Item::Group("Layout", true),
+                Item::Group("Style", false),
+                Item::Property(2),
+            ]
+        );
+    }
+
+    #[test]
+    fn search_test() {
+        let properties = properties();
+        let collapsed = HashSet::from([String::from("Layout")]);
+
+        assert_eq!(
+            items(&properties, "HEIGHT", &collapsed),
+            vec![Item::Group("Layout", false), Item::Property(3)]
+        );
+        assert!(items(&properties, "depth", &collapsed).is_empty());
+    }
+
+    #[test]
+    fn parse_test() {
+        let number = PropertyValue::Number(0.0);
+        assert_eq!(number.parse(" 2.5"), Some(PropertyValue::Number(2.5)));
+        assert_eq!(number.parse("-"), None);
+        assert_eq!(number.parse("inf"), None);
+
+        let color = PropertyValue::Color(Color::BLACK);
+        let red = Color::from_rgb8(255, 0, 0);
+        assert_eq!(color.parse("#ff0000"), Some(PropertyValue::Color(red)));
+        assert_eq!(color.parse("#ff00"), None);
+        assert_eq!(
+            PropertyValue::Color(red).to_text(),
+            Some(String::from("#ff0000"))
+        );
+        assert_eq!(
+            PropertyValue::Color(Color { a: 0.0, ..red }).to_text(),
+            Some(String::from("#ff000000"))
+        );
+    }
+}
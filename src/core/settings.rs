@@ -0,0 +1,561 @@
+//! The settings shared by all the widgets of the crate.
+//!
+//! They cover the animations, the scale and [`Density`] applied to the
+//! default text sizes, icon sizes, paddings and spacings of the widgets, the
+//! [`Corners`] and default font of the widgets, and the size of the hit areas
+//! of their small parts.
+
+use iced::{Font, Point, Rectangle};
+use std::{
+    cell::Cell,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, AtomicU8, Ordering},
+        PoisonError, RwLock,
+    },
+};
+
+/// The minimum width and height of the hit areas of the small parts of the
+/// widgets when the [`touch_targets`](Settings::touch_targets) are enforced,
+/// in logical pixels.
+pub const MIN_TOUCH_TARGET: f32 = 44.0;
+
+/// Whether the animations are shortened or disabled.
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+/// Whether the hit areas of the small parts are expanded.
+static TOUCH_TARGETS: AtomicBool = AtomicBool::new(false);
+
+/// The bits of the scale factor of the default sizes, 1 at first.
+static SCALE: AtomicU32 = AtomicU32::new(0x3F80_0000);
+
+/// The [`Density`] of the widgets, as its discriminant.
+static DENSITY: AtomicU8 = AtomicU8::new(Density::Comfortable as u8);
+
+/// The [`Corners`] of the widgets, as its discriminant.
+static CORNERS: AtomicU8 = AtomicU8::new(Corners::Rounded as u8);
+
+/// The default font of the text of the widgets.
+static FONT: RwLock<Font> = RwLock::new(Font::DEFAULT);
+
+thread_local! {
+    /// The settings overridden on this thread by the innermost [`Scope`]
+    /// entered.
+    static SCOPE: Cell<Scope> = const {
+        Cell::new(Scope {
+            scale: None,
+            density: None,
+            corners: None,
+            font: None,
+            reduced_motion: None,
+            touch_targets: None,
+        })
+    };
+}
+
+/// The corner radius of the [`Corners::Pill`] shape, larger than any widget.
+/// The renderers reduce it to half the smaller side of what they draw.
+const PILL_RADIUS: f32 = 10_000.0;
+
+/// How much space the widgets leave around and between their parts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Density {
+    /// Smaller paddings and spacings, to show more at once.
+    Compact,
+    /// The default paddings and spacings.
+    #[default]
+    Comfortable,
+    /// Larger paddings and spacings, for easier pointing.
+    Spacious,
+}
+
+impl Density {
+    /// Returns the factor applied to the default paddings and spacings.
+    #[must_use]
+    pub const fn factor(self) -> f32 {
+        match self {
+            Self::Compact => 0.75,
+            Self::Comfortable => 1.0,
+            Self::Spacious => 1.25,
+        }
+    }
+
+    /// Returns the [`Density`] with the given discriminant.
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Compact,
+            2 => Self::Spacious,
+            _ => Self::Comfortable,
+        }
+    }
+}
+
+/// The shape of the corners of the widgets, applied to the default corner
+/// radii of their appearances.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Corners {
+    /// Square corners.
+    Sharp,
+    /// The default corner radii of each widget.
+    #[default]
+    Rounded,
+    /// Fully rounded ends.
+    Pill,
+}
+
+impl Corners {
+    /// Returns the [`Corners`] with the given discriminant.
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Sharp,
+            2 => Self::Pill,
+            _ => Self::Rounded,
+        }
+    }
+}
+
+/// The settings shared by all the widgets of the crate, returned by
+/// [`settings`].
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{Corners, Density};
+/// #
+/// // For example, when the system asks for reduced motion and larger text.
+/// iced_aw::settings()
+///     .reduced_motion(true)
+///     .scale(1.25)
+///     .density(Density::Spacious)
+///     .corners(Corners::Pill);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Settings(());
+
+/// Returns the [`Settings`] shared by all the widgets of the crate.
+#[must_use]
+pub fn settings() -> Settings {
+    Settings(())
+}
+
+impl Settings {
+    /// Sets whether the animations of the widgets are shortened or disabled,
+    /// for the users who need it.
+    ///
+    /// The transitions of the [`Accordion`](crate::widgets::Accordion), the
+    /// [`Carousel`](crate::widgets::Carousel), the
+    /// [`Drawer`](crate::widgets::Drawer), the [`Toast`](crate::widgets::Toast)s
+    /// and the Cupertino widgets complete at once, the pulse of the
+    /// [`StatusDot`](crate::widgets::StatusDot) is not shown and the
+    /// [`Spinner`](crate::widgets::Spinner) turns slower.
+    #[allow(clippy::unused_self, clippy::return_self_not_must_use)]
+    pub fn reduced_motion(self, reduced_motion: bool) -> Self {
+        REDUCED_MOTION.store(reduced_motion, Ordering::Relaxed);
+        self
+    }
+
+    /// Returns whether the animations of the widgets are shortened or
+    /// disabled.
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn is_reduced_motion(self) -> bool {
+        reduced_motion()
+    }
+
+    /// Sets the factor scaling the default text sizes, icon sizes, paddings
+    /// and spacings of the widgets, 1 by default.
+    ///
+    /// Like the [`density`](Self::density), it applies to the widgets
+    /// created afterwards, and not to the sizes given to their builders.
+    #[allow(clippy::unused_self, clippy::return_self_not_must_use)]
+    pub fn scale(self, scale: f32) -> Self {
+        SCALE.store(scale.max(0.0).to_bits(), Ordering::Relaxed);
+        self
+    }
+
+    /// Returns the factor scaling the default sizes of the widgets.
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn scale_factor(self) -> f32 {
+        f32::from_bits(SCALE.load(Ordering::Relaxed))
+    }
+
+    /// Sets the [`Density`] of the widgets, scaling their default paddings
+    /// and spacings, and the heights of their rows.
+    #[allow(clippy::unused_self, clippy::return_self_not_must_use)]
+    pub fn density(self, density: Density) -> Self {
+        DENSITY.store(density as u8, Ordering::Relaxed);
+        self
+    }
+
+    /// Returns the [`Density`] of the widgets.
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn current_density(self) -> Density {
+        Density::from_u8(DENSITY.load(Ordering::Relaxed))
+    }
+
+    /// Sets the [`Corners`] of the widgets, changing the default corner radii
+    /// of their appearances.
+    ///
+    /// The radii set by the style of a widget are kept.
+    #[allow(clippy::unused_self, clippy::return_self_not_must_use)]
+    pub fn corners(self, corners: Corners) -> Self {
+        CORNERS.store(corners as u8, Ordering::Relaxed);
+        self
+    }
+
+    /// Returns the [`Corners`] of the widgets.
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn current_corners(self) -> Corners {
+        Corners::from_u8(CORNERS.load(Ordering::Relaxed))
+    }
+
+    /// Sets the default font of the text of the widgets.
+    ///
+    /// The fonts set on a widget are kept.
+    #[allow(clippy::unused_self, clippy::return_self_not_must_use)]
+    pub fn font(self, font: Font) -> Self {
+        *FONT.write().unwrap_or_else(PoisonError::into_inner) = font;
+        self
+    }
+
+    /// Returns the default font of the text of the widgets.
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn current_font(self) -> Font {
+        *FONT.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Sets whether the hit areas of the small interactive parts of the
+    /// widgets are expanded to at least [`MIN_TOUCH_TARGET`] on each side,
+    /// for touch devices.
+    ///
+    /// The close crosses of the [`TabBar`](crate::widgets::TabBar), the
+    /// delete button of the [`Chip`](crate::widgets::Chip), the close button
+    /// of the [`Card`](crate::widgets::Card) and the buttons of the
+    /// [`NumberInput`](crate::widgets::NumberInput) are drawn the same, but
+    /// react around them. Where two hit areas meet, the part closest to the
+    /// cursor wins.
+    #[allow(clippy::unused_self, clippy::return_self_not_must_use)]
+    pub fn touch_targets(self, touch_targets: bool) -> Self {
+        TOUCH_TARGETS.store(touch_targets, Ordering::Relaxed);
+        self
+    }
+
+    /// Returns whether the hit areas of the small parts of the widgets are
+    /// expanded.
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn has_touch_targets(self) -> bool {
+        TOUCH_TARGETS.load(Ordering::Relaxed)
+    }
+}
+
+/// The settings overridden while a part of the interface is built or handled,
+/// on the thread doing it.
+///
+/// The settings left unset are inherited from the enclosing scope, or from the
+/// crate-wide [`Settings`] outside any scope.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Scope {
+    /// The scale of the default sizes.
+    pub(crate) scale: Option<f32>,
+    /// The density of the paddings and spacings.
+    pub(crate) density: Option<Density>,
+    /// The shape of the corners.
+    pub(crate) corners: Option<Corners>,
+    /// The default font.
+    pub(crate) font: Option<Font>,
+    /// Whether the animations are shortened or disabled.
+    pub(crate) reduced_motion: Option<bool>,
+    /// Whether the hit areas of the small parts are expanded.
+    pub(crate) touch_targets: Option<bool>,
+}
+
+impl Scope {
+    /// Enters the [`Scope`] on the current thread, until the returned guard is
+    /// dropped, even by a panic.
+    #[must_use]
+    pub(crate) fn enter(self) -> ScopeGuard {
+        let enclosing = SCOPE.with(Cell::get);
+
+        SCOPE.with(|scope| {
+            scope.set(Self {
+                scale: self.scale.or(enclosing.scale),
+                density: self.density.or(enclosing.density),
+                corners: self.corners.or(enclosing.corners),
+                font: self.font.or(enclosing.font),
+                reduced_motion: self.reduced_motion.or(enclosing.reduced_motion),
+                touch_targets: self.touch_targets.or(enclosing.touch_targets),
+            });
+        });
+
+        ScopeGuard {
+            enclosing,
+            thread: PhantomData,
+        }
+    }
+
+    /// Returns the [`Scope`] entered on the current thread.
+    fn current() -> Self {
+        SCOPE.with(Cell::get)
+    }
+}
+
+/// Restores the enclosing [`Scope`] when dropped.
+#[derive(Debug)]
+pub(crate) struct ScopeGuard {
+    /// The scope entered before.
+    enclosing: Scope,
+    /// Keeps the guard on the thread of the scope.
+    thread: PhantomData<*const ()>,
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        SCOPE.with(|scope| scope.set(self.enclosing));
+    }
+}
+
+/// Returns whether the animations of the widgets are shortened or disabled.
+pub(crate) fn reduced_motion() -> bool {
+    Scope::current()
+        .reduced_motion
+        .unwrap_or_else(|| REDUCED_MOTION.load(Ordering::Relaxed))
+}
+
+/// Returns the default text or icon `size` of a widget, scaled.
+pub(crate) fn scaled(size: f32) -> f32 {
+    size * Scope::current()
+        .scale
+        .unwrap_or_else(|| settings().scale_factor())
+}
+
+/// Returns the default padding, spacing or row height `space` of a widget,
+/// scaled and adjusted to the [`Density`].
+pub(crate) fn spaced(space: f32) -> f32 {
+    let density = Scope::current()
+        .density
+        .unwrap_or_else(|| settings().current_density());
+
+    scaled(space) * density.factor()
+}
+
+/// Returns the default font of a widget.
+pub(crate) fn font() -> Font {
+    Scope::current()
+        .font
+        .unwrap_or_else(|| settings().current_font())
+}
+
+/// Returns the [`Corners`] of the widgets.
+fn corners() -> Corners {
+    Scope::current()
+        .corners
+        .unwrap_or_else(|| settings().current_corners())
+}
+
+/// Returns the default corner `radius` of a widget, adjusted to the
+/// [`Corners`].
+pub(crate) fn corner_radius(radius: f32) -> f32 {
+    match corners() {
+        Corners::Sharp => 0.0,
+        Corners::Rounded => radius,
+        Corners::Pill => PILL_RADIUS,
+    }
+}
+
+/// Returns the default corner radius of a widget computing it from its size
+/// when it is not set, adjusted to the [`Corners`].
+pub(crate) fn optional_corner_radius() -> Option<f32> {
+    match corners() {
+        Corners::Rounded => None,
+        Corners::Sharp | Corners::Pill => Some(corner_radius(0.0)),
+    }
+}
+
+/// Returns the hit area of a small part of a widget drawn in the `bounds`,
+/// expanded around its center if the touch targets are enforced.
+pub(crate) fn hit_area(bounds: Rectangle) -> Rectangle {
+    let touch_targets = Scope::current()
+        .touch_targets
+        .unwrap_or_else(|| settings().has_touch_targets());
+
+    if !touch_targets {
+        return bounds;
+    }
+
+    let width = bounds.width.max(MIN_TOUCH_TARGET);
+    let height = bounds.height.max(MIN_TOUCH_TARGET);
+
+    Rectangle {
+        x: bounds.center_x() - width / 2.0,
+        y: bounds.center_y() - height / 2.0,
+        width,
+        height,
+    }
+}
+
+/// Returns the index of the small part, among the `parts` drawn in the given
+/// bounds, whose [`hit_area`] contains the `position`, picking the one with
+/// the closest center when several do.
+pub(crate) fn hit_part(parts: &[Rectangle], position: Point) -> Option<usize> {
+    parts
+        .iter()
+        .enumerate()
+        .filter(|(_, bounds)| hit_area(**bounds).contains(position))
+        .min_by(|(_, a), (_, b)| {
+            a.center()
+                .distance(position)
+                .total_cmp(&b.center().distance(position))
+        })
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        corner_radius, font, hit_area, hit_part, optional_corner_radius, reduced_motion, scaled,
+        spaced, Corners, Density, Scope, MIN_TOUCH_TARGET,
+    };
+    use iced::{Font, Point, Rectangle, Size};
+
+    // The settings are overridden with a `Scope` rather than set crate-wide,
+    // for the tests running at the same time on other threads.
+
+    #[test]
+    fn reduced_motion_can_be_requested() {
+        assert!(!reduced_motion());
+
+        let scope = Scope {
+            reduced_motion: Some(true),
+            ..Scope::default()
+        }
+        .enter();
+        assert!(reduced_motion());
+
+        drop(scope);
+        assert!(!reduced_motion());
+    }
+
+    #[test]
+    fn scale_and_density_adjust_the_default_sizes() {
+        assert!((scaled(16.0) - 16.0).abs() < f32::EPSILON);
+        assert!((spaced(8.0) - 8.0).abs() < f32::EPSILON);
+
+        let scope = Scope {
+            scale: Some(1.5),
+            density: Some(Density::Compact),
+            ..Scope::default()
+        }
+        .enter();
+        assert!((scaled(16.0) - 24.0).abs() < f32::EPSILON);
+        assert!((spaced(8.0) - 9.0).abs() < f32::EPSILON);
+
+        drop(scope);
+        assert!((spaced(8.0) - 8.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn touch_targets_expand_the_hit_areas() {
+        let increase = Rectangle::new(Point::new(100.0, 0.0), Size::new(16.0, 10.0));
+        let decrease = Rectangle::new(Point::new(100.0, 11.0), Size::new(16.0, 10.0));
+        let near = Point::new(95.0, 4.0);
+
+        assert_eq!(hit_area(increase), increase);
+        assert_eq!(hit_part(&[increase, decrease], near), None);
+
+        let scope = Scope {
+            touch_targets: Some(true),
+            ..Scope::default()
+        }
+        .enter();
+        let area = hit_area(increase);
+        assert!((area.width - MIN_TOUCH_TARGET).abs() < f32::EPSILON);
+        assert!((area.height - MIN_TOUCH_TARGET).abs() < f32::EPSILON);
+        assert_eq!(area.center(), increase.center());
+        assert_eq!(hit_part(&[increase, decrease], near), Some(0));
+        assert_eq!(
+            hit_part(&[increase, decrease], Point::new(95.0, 18.0)),
+            Some(1)
+        );
+
+        drop(scope);
+        assert_eq!(hit_area(increase), increase);
+    }
+
+    #[test]
+    fn default_font_can_be_changed() {
+        assert_eq!(font(), Font::DEFAULT);
+
+        let scope = Scope {
+            font: Some(Font::MONOSPACE),
+            ..Scope::default()
+        }
+        .enter();
+        assert_eq!(font(), Font::MONOSPACE);
+
+        drop(scope);
+        assert_eq!(font(), Font::DEFAULT);
+    }
+
+    #[test]
+    fn corners_adjust_the_default_radii() {
+        assert!((corner_radius(4.0) - 4.0).abs() < f32::EPSILON);
+        assert_eq!(optional_corner_radius(), None);
+
+        let sharp = Scope {
+            corners: Some(Corners::Sharp),
+            ..Scope::default()
+        }
+        .enter();
+        assert!(corner_radius(4.0).abs() < f32::EPSILON);
+        assert_eq!(optional_corner_radius(), Some(0.0));
+
+        let pill = Scope {
+            corners: Some(Corners::Pill),
+            ..Scope::default()
+        }
+        .enter();
+        assert!(corner_radius(4.0) > 1000.0);
+
+        drop(pill);
+        drop(sharp);
+        assert!((corner_radius(4.0) - 4.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn scopes_override_the_settings_on_their_thread() {
+        let outer = Scope {
+            scale: Some(3.0),
+            density: Some(Density::Spacious),
+            corners: Some(Corners::Sharp),
+            font: Some(Font::MONOSPACE),
+            ..Scope::default()
+        }
+        .enter();
+
+        assert!((scaled(2.0) - 6.0).abs() < f32::EPSILON);
+        assert!((spaced(4.0) - 15.0).abs() < f32::EPSILON);
+        assert!(corner_radius(4.0).abs() < f32::EPSILON);
+        assert_eq!(font(), Font::MONOSPACE);
+
+        let other = std::thread::spawn(|| scaled(2.0)).join();
+        assert!(other.is_ok_and(|size| size < 6.0));
+
+        let inner = Scope {
+            density: Some(Density::Compact),
+            ..Scope::default()
+        }
+        .enter();
+        assert!((spaced(4.0) - 9.0).abs() < f32::EPSILON);
+        assert_eq!(font(), Font::MONOSPACE);
+
+        drop(inner);
+        assert!((spaced(4.0) - 15.0).abs() < f32::EPSILON);
+
+        drop(outer);
+        assert_eq!(Scope::current(), Scope::default());
+    }
+}
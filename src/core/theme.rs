@@ -0,0 +1,451 @@
+//! High-contrast themes for the widgets, and transitions between themes.
+//!
+//! The [`high_contrast_light`] and [`high_contrast_dark`] themes are selected
+//! like any other [`Theme`] of iced. Their palettes meet the contrast ratios
+//! of the WCAG: 7:1 for the text on each of their backgrounds, and 3:1 for
+//! the colored parts of the widgets against the background. The style sheets
+//! of this crate drawing with fixed colors use the palette instead when
+//! [`is_high_contrast`] returns true.
+//!
+//! # Example
+//! ```ignore
+//! # use iced::Theme;
+//! #
+//! fn theme(&self) -> Theme {
+//!     iced_aw::theme::high_contrast_dark()
+//! }
+//! ```
+//!
+//! A [`ThemeTransition`] switches between two themes over a short duration,
+//! by blending their palettes: the widgets drawing with the colors of the
+//! palette fade from one theme to the other.
+//!
+//! # Example
+//! ```ignore
+//! # use iced::{Subscription, Theme};
+//! # use iced_aw::theme::ThemeTransition;
+//! # use std::time::Instant;
+//! #
+//! fn update(&mut self, message: Message) {
+//!     match message {
+//!         Message::ToggleDark => self.transition.switch(Theme::Dark, Instant::now()),
+//!         Message::Frame(_now) => {}
+//!     }
+//! }
+//!
+//! fn theme(&self) -> Theme {
+//!     self.transition.theme(Instant::now())
+//! }
+//!
+//! fn subscription(&self) -> Subscription<Message> {
+//!     self.transition.subscription().map(Message::Frame)
+//! }
+//! ```
+
+use crate::core::animation::{self, Animation};
+
+use iced::{
+    theme::{
+        palette::{Background, Danger, Extended, Pair, Primary, Secondary, Success},
+        Palette,
+    },
+    Color, Subscription, Theme,
+};
+use std::time::{Duration, Instant};
+
+/// The name of the [`high_contrast_light`] theme.
+pub const HIGH_CONTRAST_LIGHT: &str = "High Contrast Light";
+
+/// The name of the [`high_contrast_dark`] theme.
+pub const HIGH_CONTRAST_DARK: &str = "High Contrast Dark";
+
+/// The contrast ratio of the text on a background, for the WCAG level AAA.
+pub const TEXT_CONTRAST_RATIO: f32 = 7.0;
+
+/// The contrast ratio of the parts of a widget against the background, for
+/// the WCAG level AA.
+pub const NON_TEXT_CONTRAST_RATIO: f32 = 3.0;
+
+/// The palette of the [`high_contrast_light`] theme.
+const LIGHT: Palette = Palette {
+    background: Color::WHITE,
+    text: Color::BLACK,
+    primary: rgb(0x00, 0x38, 0xA8),
+    success: rgb(0x00, 0x5C, 0x26),
+    danger: rgb(0xA8, 0x00, 0x00),
+};
+
+/// The palette of the [`high_contrast_dark`] theme.
+const DARK: Palette = Palette {
+    background: Color::BLACK,
+    text: Color::WHITE,
+    primary: rgb(0x8C, 0xB4, 0xFF),
+    success: rgb(0x5C, 0xE6, 0x8A),
+    danger: rgb(0xFF, 0x8C, 0x8C),
+};
+
+/// Creates a [`Color`] from its 8-bit RGB components.
+#[allow(clippy::cast_lossless)]
+pub(crate) const fn rgb(r: u8, g: u8, b: u8) -> Color {
+    Color::from_rgb(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0)
+}
+
+/// Returns the high-contrast light [`Theme`]: black text on white.
+#[must_use]
+pub fn high_contrast_light() -> Theme {
+    Theme::custom_with_fn(HIGH_CONTRAST_LIGHT.to_owned(), LIGHT, |palette| {
+        extended(
+            palette,
+            false,
+            [rgb(0xEB, 0xEB, 0xEB), rgb(0x4D, 0x4D, 0x4D)],
+            [rgb(0xDC, 0xE5, 0xFF), rgb(0x00, 0x23, 0x6B)],
+            [rgb(0x33, 0x33, 0x33), rgb(0xE0, 0xE0, 0xE0), Color::BLACK],
+            [rgb(0xD6, 0xF5, 0xE0), rgb(0x00, 0x3D, 0x19)],
+            [rgb(0xFF, 0xE0, 0xE0), rgb(0x70, 0x00, 0x00)],
+        )
+    })
+}
+
+/// Returns the high-contrast dark [`Theme`]: white text on black.
+#[must_use]
+pub fn high_contrast_dark() -> Theme {
+    Theme::custom_with_fn(HIGH_CONTRAST_DARK.to_owned(), DARK, |palette| {
+        extended(
+            palette,
+            true,
+            [rgb(0x1A, 0x1A, 0x1A), rgb(0xB3, 0xB3, 0xB3)],
+            [rgb(0x0A, 0x21, 0x52), rgb(0xC2, 0xD6, 0xFF)],
+            [rgb(0xCC, 0xCC, 0xCC), rgb(0x26, 0x26, 0x26), Color::WHITE],
+            [rgb(0x0A, 0x33, 0x19), rgb(0xA8, 0xF5, 0xC2)],
+            [rgb(0x4D, 0x0A, 0x0A), rgb(0xFF, 0xC2, 0xC2)],
+        )
+    })
+}
+
+/// Returns whether the `theme` is one of the high-contrast themes.
+#[must_use]
+pub fn is_high_contrast(theme: &Theme) -> bool {
+    matches!(theme, Theme::Custom(_))
+        && matches!(
+            theme.to_string().as_str(),
+            HIGH_CONTRAST_LIGHT | HIGH_CONTRAST_DARK
+        )
+}
+
+/// Returns the contrast ratio between two colors, from 1:1 to 21:1, as
+/// defined by the WCAG. The alpha of the colors is ignored.
+#[must_use]
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Returns the relative luminance of a color, as defined by the WCAG.
+fn relative_luminance(color: Color) -> f32 {
+    let linear = |channel: f32| {
+        if channel <= 0.039_28 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.0722f32.mul_add(
+        linear(color.b),
+        0.2126f32.mul_add(linear(color.r), 0.7152 * linear(color.g)),
+    )
+}
+
+/// Generates the [`Extended`] palette of a high-contrast theme, whose pairs
+/// of colors are picked by hand rather than mixed.
+///
+/// The weak and strong colors are given in this order, with the base color
+/// of the secondary colors first.
+fn extended(
+    palette: Palette,
+    is_dark: bool,
+    [background_weak, background_strong]: [Color; 2],
+    [primary_weak, primary_strong]: [Color; 2],
+    [secondary, secondary_weak, secondary_strong]: [Color; 3],
+    [success_weak, success_strong]: [Color; 2],
+    [danger_weak, danger_strong]: [Color; 2],
+) -> Extended {
+    let (text, inverse) = (palette.text, palette.background);
+
+    Extended {
+        background: Background {
+            base: Pair::new(palette.background, text),
+            weak: Pair::new(background_weak, text),
+            strong: Pair::new(background_strong, inverse),
+        },
+        primary: Primary {
+            base: Pair::new(palette.primary, inverse),
+            weak: Pair::new(primary_weak, text),
+            strong: Pair::new(primary_strong, inverse),
+        },
+        secondary: Secondary {
+            base: Pair::new(secondary, inverse),
+            weak: Pair::new(secondary_weak, text),
+            strong: Pair::new(secondary_strong, inverse),
+        },
+        success: Success {
+            base: Pair::new(palette.success, inverse),
+            weak: Pair::new(success_weak, text),
+            strong: Pair::new(success_strong, inverse),
+        },
+        danger: Danger {
+            base: Pair::new(palette.danger, inverse),
+            weak: Pair::new(danger_weak, text),
+            strong: Pair::new(danger_strong, inverse),
+        },
+        is_dark,
+    }
+}
+
+/// A switch between two themes, blending their palettes over a short
+/// duration.
+///
+/// The colors of the palettes are blended, and the widgets styled with fixed
+/// colors or depending on the name of the theme, like the high-contrast
+/// ones, switch at the end.
+#[derive(Clone, Debug)]
+pub struct ThemeTransition {
+    /// The theme switched from.
+    from: Theme,
+    /// The theme switched to.
+    to: Theme,
+    /// The animation of the switch.
+    animation: Animation,
+}
+
+impl ThemeTransition {
+    /// The default duration of a [`ThemeTransition`].
+    pub const DURATION: Duration = Duration::from_millis(250);
+
+    /// Creates a new [`ThemeTransition`] showing the `theme`.
+    #[must_use]
+    pub fn new(theme: Theme) -> Self {
+        Self {
+            from: theme.clone(),
+            to: theme,
+            animation: Animation::new(Self::DURATION),
+        }
+    }
+
+    /// Sets the duration of the switches of the [`ThemeTransition`].
+    #[must_use]
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.animation = Animation::new(duration);
+        self
+    }
+
+    /// Switches to the `theme` from the time `now`, starting from the theme
+    /// shown at that time.
+    pub fn switch(&mut self, theme: Theme, now: Instant) {
+        self.from = self.theme(now);
+        self.to = theme;
+        self.animation.start(now);
+    }
+
+    /// Returns the theme switched to.
+    #[must_use]
+    pub fn target(&self) -> &Theme {
+        &self.to
+    }
+
+    /// Returns the theme shown at the time `now`.
+    #[must_use]
+    pub fn theme(&self, now: Instant) -> Theme {
+        let t = self.animation.progress(now);
+
+        if t >= 1.0 {
+            self.to.clone()
+        } else {
+            blend(&self.from, &self.to, t)
+        }
+    }
+
+    /// Returns whether the [`ThemeTransition`] is switching at the time
+    /// `now`.
+    #[must_use]
+    pub fn is_running(&self, now: Instant) -> bool {
+        self.animation.is_running(now)
+    }
+
+    /// Produces the time of each frame of the window while the
+    /// [`ThemeTransition`] is switching, for the application to redraw.
+    pub fn subscription(&self) -> Subscription<Instant> {
+        animation::frames(self.is_running(Instant::now()))
+    }
+}
+
+/// Returns a theme whose palette is between the ones of `from` and `to`, at
+/// the progress `t`.
+fn blend(from: &Theme, to: &Theme, t: f32) -> Theme {
+    let mix = |a: Color, b: Color| {
+        Color::from_rgba(
+            (b.r - a.r).mul_add(t, a.r),
+            (b.g - a.g).mul_add(t, a.g),
+            (b.b - a.b).mul_add(t, a.b),
+            (b.a - a.a).mul_add(t, a.a),
+        )
+    };
+    let pair = |a: Pair, b: Pair| Pair {
+        color: mix(a.color, b.color),
+        text: mix(a.text, b.text),
+    };
+
+    let (a, b) = (from.palette(), to.palette());
+    let palette = Palette {
+        background: mix(a.background, b.background),
+        text: mix(a.text, b.text),
+        primary: mix(a.primary, b.primary),
+        success: mix(a.success, b.success),
+        danger: mix(a.danger, b.danger),
+    };
+
+    let (a, b) = (from.extended_palette(), to.extended_palette());
+    let extended = Extended {
+        background: Background {
+            base: pair(a.background.base, b.background.base),
+            weak: pair(a.background.weak, b.background.weak),
+            strong: pair(a.background.strong, b.background.strong),
+        },
+        primary: Primary {
+            base: pair(a.primary.base, b.primary.base),
+            weak: pair(a.primary.weak, b.primary.weak),
+            strong: pair(a.primary.strong, b.primary.strong),
+        },
+        secondary: Secondary {
+            base: pair(a.secondary.base, b.secondary.base),
+            weak: pair(a.secondary.weak, b.secondary.weak),
+            strong: pair(a.secondary.strong, b.secondary.strong),
+        },
+        success: Success {
+            base: pair(a.success.base, b.success.base),
+            weak: pair(a.success.weak, b.success.weak),
+            strong: pair(a.success.strong, b.success.strong),
+        },
+        danger: Danger {
+            base: pair(a.danger.base, b.danger.base),
+            weak: pair(a.danger.weak, b.danger.weak),
+            strong: pair(a.danger.strong, b.danger.strong),
+        },
+        is_dark: if t < 0.5 { a.is_dark } else { b.is_dark },
+    };
+
+    Theme::custom_with_fn(format!("{from} to {to}"), palette, move |_| extended)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        blend, contrast_ratio, high_contrast_dark, high_contrast_light, is_high_contrast,
+        ThemeTransition, NON_TEXT_CONTRAST_RATIO, TEXT_CONTRAST_RATIO,
+    };
+    use iced::{theme::palette::Pair, Color, Theme};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn contrast_ratio_bounds() {
+        assert!((contrast_ratio(Color::BLACK, Color::WHITE) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(Color::WHITE, Color::BLACK) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(Color::WHITE, Color::WHITE) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn high_contrast_themes_meet_the_ratios() {
+        for theme in [high_contrast_light(), high_contrast_dark()] {
+            let palette = theme.extended_palette();
+            let background = palette.background.base.color;
+
+            let pairs: [(&str, Pair); 15] = [
+                ("background.base", palette.background.base),
+                ("background.weak", palette.background.weak),
+                ("background.strong", palette.background.strong),
+                ("primary.base", palette.primary.base),
+                ("primary.weak", palette.primary.weak),
+                ("primary.strong", palette.primary.strong),
+                ("secondary.base", palette.secondary.base),
+                ("secondary.weak", palette.secondary.weak),
+                ("secondary.strong", palette.secondary.strong),
+                ("success.base", palette.success.base),
+                ("success.weak", palette.success.weak),
+                ("success.strong", palette.success.strong),
+                ("danger.base", palette.danger.base),
+                ("danger.weak", palette.danger.weak),
+                ("danger.strong", palette.danger.strong),
+            ];
+
+            for (name, pair) in pairs {
+                assert!(
+                    contrast_ratio(pair.color, pair.text) >= TEXT_CONTRAST_RATIO,
+                    "{theme}: text on {name}"
+                );
+            }
+
+            for (name, color) in [
+                ("background.strong", palette.background.strong.color),
+                ("primary.base", palette.primary.base.color),
+                ("primary.strong", palette.primary.strong.color),
+                ("secondary.base", palette.secondary.base.color),
+                ("success.base", palette.success.base.color),
+                ("danger.base", palette.danger.base.color),
+            ] {
+                assert!(
+                    contrast_ratio(color, background) >= NON_TEXT_CONTRAST_RATIO,
+                    "{theme}: {name} on the background"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn high_contrast_themes_are_recognized() {
+        assert!(is_high_contrast(&high_contrast_light()));
+        assert!(is_high_contrast(&high_contrast_dark()));
+        assert!(!is_high_contrast(&Theme::Light));
+        assert!(!is_high_contrast(&Theme::custom(
+            "Light".to_owned(),
+            Theme::Light.palette()
+        )));
+    }
+
+    #[test]
+    fn blend_mixes_the_palettes() {
+        let half = blend(&Theme::Light, &Theme::Dark, 0.5);
+        let (light, dark) = (Theme::Light.palette(), Theme::Dark.palette());
+
+        assert!(
+            (half.palette().background.r - f32::midpoint(light.background.r, dark.background.r))
+                .abs()
+                < 0.001
+        );
+        assert_eq!(
+            blend(&Theme::Light, &Theme::Dark, 0.0).extended_palette(),
+            Theme::Light.extended_palette()
+        );
+        assert!(half.extended_palette().is_dark);
+        assert!(!is_high_contrast(&blend(
+            &Theme::Light,
+            &high_contrast_dark(),
+            0.5
+        )));
+    }
+
+    #[test]
+    fn transition_ends_on_the_target() {
+        let now = Instant::now();
+        let mut transition =
+            ThemeTransition::new(Theme::Light).duration(Duration::from_millis(100));
+
+        transition.switch(Theme::Dark, now);
+        assert_eq!(transition.target(), &Theme::Dark);
+        assert_eq!(
+            transition.theme(now + Duration::from_millis(100)),
+            Theme::Dark
+        );
+        assert!(!transition.is_running(now + Duration::from_millis(100)));
+    }
+}
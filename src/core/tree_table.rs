@@ -0,0 +1,198 @@
+//! Describe hierarchical records shown by a tree table.
+//!
+//! *This API requires the following crate features to be activated: `tree_table`*
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+/// A record of a tree table, with the records nested under it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNode<T> {
+    /// The record of the node.
+    pub value: T,
+    /// The nodes nested under the node.
+    pub children: Vec<Self>,
+}
+
+impl<T> TreeNode<T> {
+    /// Creates a new [`TreeNode`] of the `value`, without children.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the nodes nested under the [`TreeNode`].
+    #[must_use]
+    pub fn children(mut self, children: Vec<Self>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Adds a node nested under the [`TreeNode`].
+    #[must_use]
+    pub fn push(mut self, child: Self) -> Self {
+        self.children.push(child);
+        self
+    }
+}
+
+/// The function ordering two sibling records.
+pub(crate) type Compare<'a, T> = dyn Fn(&T, &T) -> Ordering + 'a;
+
+/// A visible row of a tree table.
+#[derive(Debug)]
+pub(crate) struct VisibleNode<'a, T> {
+    /// The indices of the node and of its ancestors among their siblings, as
+    /// given by the application, from the root.
+    pub(crate) path: Vec<usize>,
+    /// The node of the row.
+    pub(crate) node: &'a TreeNode<T>,
+    /// Whether the children of the node are shown.
+    pub(crate) expanded: bool,
+}
+
+impl<T> VisibleNode<'_, T> {
+    /// Returns the number of ancestors of the node.
+    pub(crate) fn depth(&self) -> usize {
+        self.path.len().saturating_sub(1)
+    }
+}
+
+/// Returns the visible rows of the trees of `roots`, whose `expanded` nodes
+/// show their children. The siblings are ordered by `compare`, if any,
+/// keeping the order of the equal ones.
+pub(crate) fn visible_nodes<'a, T>(
+    roots: &'a [TreeNode<T>],
+    expanded: &HashSet<Vec<usize>>,
+    compare: Option<&Compare<'_, T>>,
+) -> Vec<VisibleNode<'a, T>> {
+    let mut rows = Vec::new();
+    push_siblings(roots, &[], expanded, compare, &mut rows);
+    rows
+}
+
+/// Pushes the rows of the `siblings` under the node at `parent`, and of
+/// their visible descendants.
+fn push_siblings<'a, T>(
+    siblings: &'a [TreeNode<T>],
+    parent: &[usize],
+    expanded: &HashSet<Vec<usize>>,
+    compare: Option<&Compare<'_, T>>,
+    rows: &mut Vec<VisibleNode<'a, T>>,
+) {
+    let mut order: Vec<usize> = (0..siblings.len()).collect();
+    if let Some(compare) = compare {
+        order.sort_by(|&a, &b| compare(&siblings[a].value, &siblings[b].value));
+    }
+
+    for index in order {
+        let node = &siblings[index];
+        let mut path = parent.to_vec();
+        path.push(index);
+
+        let shown = !node.children.is_empty() && expanded.contains(&path);
+        rows.push(VisibleNode {
+            path: path.clone(),
+            node,
+            expanded: shown,
+        });
+
+        if shown {
+            push_siblings(&node.children, &path, expanded, compare, rows);
+        }
+    }
+}
+
+/// Returns the paths of the nodes having children of the trees of `roots`
+/// down to `depth` levels, a depth of 1 expanding the roots only.
+pub(crate) fn paths_to_depth<T>(roots: &[TreeNode<T>], depth: usize) -> HashSet<Vec<usize>> {
+    let mut paths = HashSet::new();
+    let mut stack: Vec<(Vec<usize>, &TreeNode<T>)> = roots
+        .iter()
+        .enumerate()
+        .map(|(index, node)| (vec![index], node))
+        .collect();
+
+    while let Some((path, node)) = stack.pop() {
+        if path.len() > depth || node.children.is_empty() {
+            continue;
+        }
+
+        for (index, child) in node.children.iter().enumerate() {
+            let mut child_path = path.clone();
+            child_path.push(index);
+            stack.push((child_path, child));
+        }
+        let _ = paths.insert(path);
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{paths_to_depth, visible_nodes, TreeNode, VisibleNode};
+    use std::collections::HashSet;
+
+    fn forest() -> Vec<TreeNode<(&'static str, u32)>> {
+        vec![
+            TreeNode::new(("src", 30)).children(vec![
+                TreeNode::new(("lib.rs", 10)),
+                TreeNode::new(("core", 15)).push(TreeNode::new(("date.rs", 15))),
+                TreeNode::new(("build.rs", 5)),
+            ]),
+            TreeNode::new(("Cargo.toml", 2)),
+        ]
+    }
+
+    fn names(rows: &[VisibleNode<'_, (&'static str, u32)>]) -> Vec<&'static str> {
+        rows.iter().map(|row| row.node.value.0).collect()
+    }
+
+    #[test]
+    fn collapsed_nodes_hide_their_children() {
+        let roots = forest();
+        let rows = visible_nodes(&roots, &HashSet::new(), None);
+        assert_eq!(names(&rows), vec!["src", "Cargo.toml"]);
+        assert!(!rows[0].expanded);
+
+        let expanded = HashSet::from([vec![0]]);
+        let rows = visible_nodes(&roots, &expanded, None);
+        assert_eq!(
+            names(&rows),
+            vec!["src", "lib.rs", "core", "build.rs", "Cargo.toml"]
+        );
+        assert_eq!(rows[2].path, vec![0, 1]);
+        assert_eq!(rows[2].depth(), 1);
+        assert!(!rows[2].expanded);
+    }
+
+    #[test]
+    fn sorting_keeps_children_under_their_parent() {
+        let roots = forest();
+        let expanded = HashSet::from([vec![0], vec![0, 1]]);
+        let by_size = |a: &(&str, u32), b: &(&str, u32)| a.1.cmp(&b.1);
+        let rows = visible_nodes(&roots, &expanded, Some(&by_size));
+
+        assert_eq!(
+            names(&rows),
+            vec!["Cargo.toml", "src", "build.rs", "lib.rs", "core", "date.rs"]
+        );
+        // The paths keep the indices given by the application.
+        assert_eq!(rows[2].path, vec![0, 2]);
+        assert_eq!(rows[5].path, vec![0, 1, 0]);
+    }
+
+    #[test]
+    fn expands_to_depth() {
+        let roots = forest();
+        assert!(paths_to_depth(&roots, 0).is_empty());
+        assert_eq!(paths_to_depth(&roots, 1), HashSet::from([vec![0]]));
+        assert_eq!(
+            paths_to_depth(&roots, 2),
+            HashSet::from([vec![0], vec![0, 1]])
+        );
+    }
+}
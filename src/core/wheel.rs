@@ -0,0 +1,71 @@
+//! Map the rows of a wheel picker to its items, wrapping around when it
+//! loops.
+//!
+//! *This API requires the following crate features to be activated: `wheel_picker`*
+
+/// Returns the index of the item shown at `row` of a wheel of `count`
+/// items, wrapping around when `looping`.
+pub(crate) fn item_at(row: i64, count: usize, looping: bool) -> Option<usize> {
+    let count = i64::try_from(count).ok().filter(|&count| count > 0)?;
+
+    if looping {
+        usize::try_from(row.rem_euclid(count)).ok()
+    } else {
+        usize::try_from(row)
+            .ok()
+            .filter(|&index| (index as i64) < count)
+    }
+}
+
+/// Clamps the `offset` of a wheel of `count` items to its rows, unless it
+/// loops.
+pub(crate) fn clamp_offset(offset: f32, count: usize, looping: bool) -> f32 {
+    if looping {
+        offset
+    } else {
+        offset.clamp(0.0, count.saturating_sub(1) as f32)
+    }
+}
+
+/// Returns the row closest to `offset` showing the item at `index` of a
+/// wheel of `count` items.
+pub(crate) fn nearest_row(offset: f32, index: usize, count: usize, looping: bool) -> f32 {
+    if !looping || count == 0 {
+        return index as f32;
+    }
+
+    let count = count as f32;
+    let turns = ((offset - index as f32) / count).round();
+
+    index as f32 + turns * count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clamp_offset, item_at, nearest_row};
+
+    #[test]
+    fn rows_wrap_around_when_looping() {
+        assert_eq!(item_at(-1, 12, true), Some(11));
+        assert_eq!(item_at(25, 12, true), Some(1));
+        assert_eq!(item_at(-1, 12, false), None);
+        assert_eq!(item_at(12, 12, false), None);
+        assert_eq!(item_at(3, 12, false), Some(3));
+        assert_eq!(item_at(0, 0, true), None);
+    }
+
+    #[test]
+    fn offset_is_clamped_unless_looping() {
+        assert!((clamp_offset(-2.5, 5, false) - 0.0).abs() < f32::EPSILON);
+        assert!((clamp_offset(7.0, 5, false) - 4.0).abs() < f32::EPSILON);
+        assert!((clamp_offset(-2.5, 5, true) + 2.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn nearest_row_takes_the_shortest_way() {
+        // From December, January is one row down rather than eleven up.
+        assert!((nearest_row(11.0, 0, 12, true) - 12.0).abs() < f32::EPSILON);
+        assert!((nearest_row(-1.0, 10, 12, true) + 2.0).abs() < f32::EPSILON);
+        assert!((nearest_row(11.0, 0, 12, false) - 0.0).abs() < f32::EPSILON);
+    }
+}
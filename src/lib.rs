@@ -51,23 +51,55 @@ pub mod widgets;
 pub mod core;
 pub mod style;
 
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
 pub use iced::Element;
 
 /// Exports for all platforms that are not WASM32.
 mod platform {
-    pub use crate::widgets::helpers;
+    pub use crate::{
+        core::{
+            accessibility, animation, focus,
+            icons::{
+                icon_font, icon_fonts, load_icon_fonts, register_icon_font, IconFont, IconHandle,
+                IconRenderer,
+            },
+            settings::{settings, Corners, Density, Settings},
+            theme,
+        },
+        widgets::helpers,
+    };
 
     cfg_if::cfg_if! {
         if #[cfg(feature = "icons")] {
             pub use
                 crate::core::icons::{
-                    Bootstrap, BOOTSTRAP_FONT, BOOTSTRAP_FONT_BYTES, Nerd, NERD_FONT, NERD_FONT_BYTES,SF_UI_ROUNDED_BYTES, SF_UI_ROUNDED,
+                    Bootstrap, BOOTSTRAP_FONT, BOOTSTRAP_FONT_BYTES, SF_UI_ROUNDED_BYTES, SF_UI_ROUNDED,
                 };
         } else {
             pub use crate::core::icons::{Bootstrap, BOOTSTRAP_FONT, BOOTSTRAP_FONT_BYTES};
         }
     }
 
+    #[cfg(feature = "nerd_icons")]
+    pub use crate::core::icons::{Nerd, NERD_FONT, NERD_FONT_BYTES};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "accordion")]
+    pub use {
+        crate::style::AccordionStyles,
+        crate::widgets::accordion,
+        accordion::{Accordion, Section},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "anchored_overlay")]
+    pub use {
+        crate::widgets::anchored_overlay,
+        anchored_overlay::{AnchoredOverlay, Placement},
+    };
+
     #[doc(no_inline)]
     #[cfg(feature = "badge")]
     pub use {crate::style::BadgeStyles, crate::widgets::badge, badge::Badge};
@@ -76,6 +108,10 @@ mod platform {
     #[cfg(feature = "card")]
     pub use {crate::style::CardStyles, crate::widgets::card, card::Card};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "carousel")]
+    pub use {crate::style::CarouselStyles, crate::widgets::carousel, carousel::Carousel};
+
     #[doc(no_inline)]
     #[cfg(feature = "color_picker")]
     pub use {crate::widgets::color_picker, color_picker::ColorPicker};
@@ -84,6 +120,32 @@ mod platform {
     #[cfg(feature = "date_picker")]
     pub use {crate::widgets::date_picker, date_picker::DatePicker};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "dock")]
+    pub use {
+        crate::style::DockStyles,
+        crate::widgets::dock,
+        dock::{DockArea, DockLayout, DockPanel},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "drag_and_drop")]
+    pub use {
+        crate::style::DragAndDropStyles,
+        crate::widgets::drag_and_drop,
+        drag_and_drop::{Draggable, DropTarget},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "drawer")]
+    pub use {crate::style::DrawerStyles, crate::widgets::drawer, drawer::Drawer};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "file_browser")]
+    pub use {
+        crate::style::FileBrowserStyles, crate::widgets::file_browser, file_browser::FileBrowser,
+    };
+
     #[doc(no_inline)]
     #[cfg(feature = "floating_element")]
     pub use {crate::widgets::floating_element, floating_element::FloatingElement};
@@ -96,6 +158,63 @@ mod platform {
     #[cfg(feature = "modal")]
     pub use {crate::style::ModalStyles, crate::widgets::modal, modal::Modal};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "month_calendar")]
+    pub use {
+        crate::style::MonthCalendarStyles, crate::widgets::month_calendar,
+        month_calendar::MonthCalendar,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "navigation_rail")]
+    pub use {
+        crate::style::NavigationRailStyles, crate::widgets::navigation_rail,
+        navigation_rail::NavigationRail,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "popover")]
+    pub use {crate::style::PopoverStyles, crate::widgets::popover, popover::Popover};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "table")]
+    pub use {
+        crate::style::TableStyles,
+        crate::widgets::table,
+        table::{Column, ColumnWidth, SortOrder, Table},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tag_input")]
+    pub use {crate::style::TagInputStyles, crate::widgets::tag_input, tag_input::TagInput};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "search_bar")]
+    pub use {crate::style::SearchBarStyles, crate::widgets::search_bar, search_bar::SearchBar};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "chip")]
+    pub use {crate::style::ChipStyles, crate::widgets::chip, chip::Chip};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "pin_input")]
+    pub use {crate::style::PinInputStyles, crate::widgets::pin_input, pin_input::PinInput};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "masked_input")]
+    pub use {
+        crate::widgets::masked_input,
+        masked_input::{Mask, MaskedInput},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "password_input")]
+    pub use {
+        crate::style::PasswordInputStyles,
+        crate::widgets::password_input,
+        password_input::{PasswordInput, Strength},
+    };
+
     #[doc(no_inline)]
     #[cfg(feature = "tab_bar")]
     pub use {
@@ -115,6 +234,20 @@ mod platform {
     #[cfg(feature = "time_picker")]
     pub use {crate::widgets::time_picker, time_picker::TimePicker};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "toast")]
+    pub use {
+        crate::style::ToastStyles,
+        crate::widgets::toast,
+        toast::{Toast, ToastManager},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "virtual_list")]
+    pub use {
+        crate::style::VirtualListStyles, crate::widgets::virtual_list, virtual_list::VirtualList,
+    };
+
     #[doc(no_inline)]
     #[cfg(feature = "wrap")]
     pub use {crate::widgets::wrap, wrap::direction, wrap::Wrap};
@@ -146,18 +279,157 @@ mod platform {
 
     #[doc(no_inline)]
     #[cfg(feature = "spinner")]
-    pub use {crate::style::SpinnerStyle, crate::widgets::spinner, spinner::Spinner};
+    pub use {
+        crate::style::SpinnerStyle,
+        crate::widgets::spinner,
+        spinner::{Easing, Spinner, SpinnerKind},
+    };
 
     #[doc(no_inline)]
     #[cfg(feature = "slide_bar")]
     pub use crate::widgets::SlideBar;
 
+    #[doc(no_inline)]
+    #[cfg(feature = "range_slider")]
+    pub use {
+        crate::style::RangeSliderStyles, crate::widgets::range_slider, range_slider::RangeSlider,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "knob")]
+    pub use {
+        crate::style::KnobStyles,
+        crate::widgets::knob,
+        knob::{DragMode, Knob},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "icon")]
+    pub use {crate::widgets::icon, icon::Icon};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "image_viewer")]
+    pub use {
+        crate::style::ImageViewerStyles,
+        crate::widgets::image_viewer,
+        image_viewer::{ImageViewer, Viewport},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "heatmap_calendar")]
+    pub use {
+        crate::style::HeatmapCalendarStyles, crate::widgets::heatmap_calendar,
+        heatmap_calendar::HeatmapCalendar,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "hotkey_input")]
+    pub use {
+        crate::style::HotkeyInputStyles,
+        crate::widgets::hotkey_input,
+        hotkey_input::{Hotkey, HotkeyInput},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "json_tree")]
+    pub use {
+        crate::style::JsonTreeStyles,
+        crate::widgets::json_tree,
+        json_tree::{JsonTree, TreeData},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "log_view")]
+    pub use {
+        crate::style::LogViewStyles,
+        crate::widgets::log_view,
+        log_view::{LogBuffer, LogView, Severity},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "transfer_list")]
+    pub use {crate::widgets::transfer_list, transfer_list::TransferList};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "property_grid")]
+    pub use {
+        crate::style::PropertyGridStyles,
+        crate::widgets::property_grid,
+        property_grid::{Property, PropertyGrid, PropertyValue},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tick_slider")]
+    pub use {
+        crate::style::TickSliderStyles, crate::widgets::tick_slider, tick_slider::TickSlider,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "multi_pick_list")]
+    pub use {
+        crate::style::MultiPickListStyles,
+        crate::widgets::multi_pick_list,
+        multi_pick_list::{MultiPickList, SelectionDisplay},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tour")]
+    pub use {
+        crate::style::TourStyles,
+        crate::widgets::tour,
+        tour::{Tour, TourLabels, TourStep},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tree_table")]
+    pub use {
+        crate::widgets::tree_table,
+        tree_table::{TreeNode, TreeTable},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "num_pad")]
+    pub use {
+        crate::style::NumPadStyles,
+        crate::widgets::num_pad,
+        num_pad::{NumPad, NumPadKey},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "wheel_picker")]
+    pub use {
+        crate::style::WheelPickerStyles, crate::widgets::wheel_picker, wheel_picker::WheelPicker,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "status_dot")]
+    pub use {
+        crate::style::StatusDotStyles,
+        crate::widgets::status_dot,
+        status_dot::{Status, StatusDot},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "style_scope")]
+    pub use {
+        crate::widgets::style_scope,
+        style_scope::{Overrides, StyleScope},
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "minimap")]
+    pub use {crate::style::MinimapStyles, crate::widgets::minimap, minimap::Minimap};
+
     #[doc(no_inline)]
     #[cfg(feature = "context_menu")]
     pub use {
         crate::style::ContextMenuStyle, crate::widgets::context_menu, context_menu::ContextMenu,
     };
 
+    #[doc(no_inline)]
+    #[cfg(feature = "segmented_button")]
+    pub use {crate::widgets::segmented_button, segmented_button::SegmentedButtons};
+
     #[doc(no_inline)]
     #[cfg(feature = "drop_down")]
     pub use {crate::widgets::drop_down, drop_down::DropDown};
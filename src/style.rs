@@ -1,7 +1,36 @@
 //! The appearance of the widgets
+//!
+//! Each widget is styled with the enum of its module, like
+//! [`BadgeStyles`](badge::BadgeStyles), or with a closure returning its
+//! appearance for the given theme and [`Status`].
+//!
+//! # Example
+//! ```ignore
+//! # use iced::Theme;
+//! # use iced_aw::{style::{badge::Appearance, Status}, Badge};
+//! #
+//! let badge = Badge::new("New").style(|theme: &Theme, status| Appearance {
+//!     background: theme.extended_palette().primary.base.color.into(),
+//!     border_width: if status == Status::Hovered { 2.0 } else { 1.0 },
+//!     ..Appearance::default()
+//! });
+//! ```
 
 pub mod colors;
+pub mod status;
 pub mod style_state;
+pub use status::Status;
+
+#[cfg(feature = "serde")]
+pub mod theme_file;
+
+#[cfg(feature = "material")]
+pub mod material;
+
+#[cfg(feature = "accordion")]
+pub mod accordion;
+#[cfg(feature = "accordion")]
+pub use accordion::AccordionStyles;
 
 #[cfg(feature = "badge")]
 pub mod badge;
@@ -13,6 +42,11 @@ pub mod card;
 #[cfg(feature = "card")]
 pub use card::CardStyles;
 
+#[cfg(feature = "carousel")]
+pub mod carousel;
+#[cfg(feature = "carousel")]
+pub use carousel::CarouselStyles;
+
 #[cfg(feature = "color_picker")]
 pub mod color_picker;
 #[cfg(feature = "color_picker")]
@@ -23,11 +57,151 @@ pub mod date_picker;
 #[cfg(feature = "date_picker")]
 pub use date_picker::DatePickerStyle;
 
+#[cfg(feature = "dock")]
+pub mod dock;
+#[cfg(feature = "dock")]
+pub use dock::DockStyles;
+
+#[cfg(feature = "drag_and_drop")]
+pub mod drag_and_drop;
+#[cfg(feature = "drag_and_drop")]
+pub use drag_and_drop::DragAndDropStyles;
+
+#[cfg(feature = "drawer")]
+pub mod drawer;
+#[cfg(feature = "drawer")]
+pub use drawer::DrawerStyles;
+
+#[cfg(feature = "file_browser")]
+pub mod file_browser;
+#[cfg(feature = "file_browser")]
+pub use file_browser::FileBrowserStyles;
+
 #[cfg(feature = "modal")]
 pub mod modal;
 #[cfg(feature = "modal")]
 pub use modal::ModalStyles;
 
+#[cfg(feature = "month_calendar")]
+pub mod month_calendar;
+#[cfg(feature = "month_calendar")]
+pub use month_calendar::MonthCalendarStyles;
+
+#[cfg(feature = "navigation_rail")]
+pub mod navigation_rail;
+#[cfg(feature = "navigation_rail")]
+pub use navigation_rail::NavigationRailStyles;
+
+#[cfg(feature = "popover")]
+pub mod popover;
+#[cfg(feature = "popover")]
+pub use popover::PopoverStyles;
+
+#[cfg(feature = "range_slider")]
+pub mod range_slider;
+#[cfg(feature = "range_slider")]
+pub use range_slider::RangeSliderStyles;
+
+#[cfg(feature = "knob")]
+pub mod knob;
+#[cfg(feature = "knob")]
+pub use knob::KnobStyles;
+
+#[cfg(feature = "image_viewer")]
+pub mod image_viewer;
+#[cfg(feature = "image_viewer")]
+pub use image_viewer::ImageViewerStyles;
+
+#[cfg(feature = "heatmap_calendar")]
+pub mod heatmap_calendar;
+#[cfg(feature = "heatmap_calendar")]
+pub use heatmap_calendar::HeatmapCalendarStyles;
+
+#[cfg(feature = "hotkey_input")]
+pub mod hotkey_input;
+#[cfg(feature = "hotkey_input")]
+pub use hotkey_input::HotkeyInputStyles;
+
+#[cfg(feature = "json_tree")]
+pub mod json_tree;
+#[cfg(feature = "json_tree")]
+pub use json_tree::JsonTreeStyles;
+
+#[cfg(feature = "log_view")]
+pub mod log_view;
+#[cfg(feature = "log_view")]
+pub use log_view::LogViewStyles;
+
+#[cfg(feature = "property_grid")]
+pub mod property_grid;
+#[cfg(feature = "property_grid")]
+pub use property_grid::PropertyGridStyles;
+
+#[cfg(feature = "tick_slider")]
+pub mod tick_slider;
+#[cfg(feature = "tick_slider")]
+pub use tick_slider::TickSliderStyles;
+
+#[cfg(feature = "multi_pick_list")]
+pub mod multi_pick_list;
+#[cfg(feature = "multi_pick_list")]
+pub use multi_pick_list::MultiPickListStyles;
+
+#[cfg(feature = "tour")]
+pub mod tour;
+#[cfg(feature = "tour")]
+pub use tour::TourStyles;
+
+#[cfg(feature = "num_pad")]
+pub mod num_pad;
+#[cfg(feature = "num_pad")]
+pub use num_pad::NumPadStyles;
+
+#[cfg(feature = "wheel_picker")]
+pub mod wheel_picker;
+#[cfg(feature = "wheel_picker")]
+pub use wheel_picker::WheelPickerStyles;
+
+#[cfg(feature = "status_dot")]
+pub mod status_dot;
+#[cfg(feature = "status_dot")]
+pub use status_dot::StatusDotStyles;
+
+#[cfg(feature = "minimap")]
+pub mod minimap;
+#[cfg(feature = "minimap")]
+pub use minimap::MinimapStyles;
+
+#[cfg(feature = "table")]
+pub mod table;
+#[cfg(feature = "table")]
+pub use table::TableStyles;
+
+#[cfg(feature = "tag_input")]
+pub mod tag_input;
+#[cfg(feature = "tag_input")]
+pub use tag_input::TagInputStyles;
+
+#[cfg(feature = "search_bar")]
+pub mod search_bar;
+#[cfg(feature = "search_bar")]
+pub use search_bar::SearchBarStyles;
+
+#[cfg(feature = "chip")]
+pub mod chip;
+#[cfg(feature = "chip")]
+pub use chip::ChipStyles;
+
+#[cfg(feature = "pin_input")]
+pub mod pin_input;
+#[cfg(feature = "pin_input")]
+pub use pin_input::PinInputStyles;
+
+#[cfg(feature = "password_input")]
+pub mod password_input;
+#[cfg(feature = "password_input")]
+pub use password_input::PasswordInputStyles;
+
 #[cfg(feature = "tab_bar")]
 pub mod tab_bar;
 #[cfg(feature = "tab_bar")]
@@ -38,6 +212,16 @@ pub mod time_picker;
 #[cfg(feature = "time_picker")]
 pub use time_picker::TimePickerStyle;
 
+#[cfg(feature = "toast")]
+pub mod toast;
+#[cfg(feature = "toast")]
+pub use toast::ToastStyles;
+
+#[cfg(feature = "virtual_list")]
+pub mod virtual_list;
+#[cfg(feature = "virtual_list")]
+pub use virtual_list::VirtualListStyles;
+
 #[cfg(feature = "number_input")]
 pub mod number_input;
 #[cfg(feature = "number_input")]
@@ -0,0 +1,142 @@
+//! Stack titled sections that expand and collapse.
+//!
+//! *This API requires the following crate features to be activated: accordion*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of an [`Accordion`](crate::native::accordion::Accordion).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the [`Accordion`](crate::native::accordion::Accordion).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the [`Accordion`](crate::native::accordion::Accordion).
+    pub border_radius: f32,
+
+    /// The border width of the [`Accordion`](crate::native::accordion::Accordion).
+    pub border_width: f32,
+
+    /// The border color of the [`Accordion`](crate::native::accordion::Accordion).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The background of the headers of the sections.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub header_background: Background,
+
+    /// The background of the hovered header.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub header_hovered_background: Background,
+
+    /// The text color of the headers of the sections.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub header_text_color: Color,
+
+    /// The text color of the content of the sections.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub text_color: Color,
+
+    /// The color of the expansion icon of the headers.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub icon_color: Color,
+
+    /// The color of the lines between the sections.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub divider_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color(Color::WHITE),
+            border_radius: settings::corner_radius(4.0),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            header_background: Background::Color([0.95, 0.95, 0.95].into()),
+            header_hovered_background: Background::Color([0.9, 0.9, 0.9].into()),
+            header_text_color: Color::BLACK,
+            text_color: Color::BLACK,
+            icon_color: [0.4, 0.4, 0.4].into(),
+            divider_color: [0.8, 0.8, 0.8].into(),
+        }
+    }
+}
+
+/// The appearance of an [`Accordion`](crate::native::accordion::Accordion).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of an [`Accordion`](crate::native::accordion::Accordion).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of an [`Accordion`](crate::native::accordion::Accordion).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum AccordionStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl AccordionStyles {
+    /// Creates a custom [`AccordionStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for AccordionStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = AccordionStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let AccordionStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            header_background: palette.background.weak.color.into(),
+            header_hovered_background: palette.background.strong.color.into(),
+            header_text_color: palette.background.weak.text,
+            text_color: palette.background.base.text,
+            icon_color: palette.background.weak.text,
+            divider_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
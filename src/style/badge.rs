@@ -2,13 +2,27 @@
 //!
 //! *This API requires the following crate features to be activated: badge*
 use super::colors;
+use crate::core::settings;
 
+use super::{
+    status::{faded, faded_background},
+    Status,
+};
 use iced::{Background, Color, Theme};
 
 /// The appearance of a [`Badge`](crate::native::badge::Badge).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Appearance {
     /// The background of the [`Badge`](crate::native::badge::Badge).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub background: Background,
 
     /// The border radius of the [`Badge`](crate::native::badge::Badge).
@@ -19,9 +33,14 @@ pub struct Appearance {
     pub border_width: f32,
 
     /// The border color of the [`Badge`](crate::native::badge::Badge).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_color")
+    )]
     pub border_color: Option<Color>,
 
     /// The default text color of the [`Badge`](crate::native::badge::Badge).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub text_color: Color,
 }
 
@@ -36,13 +55,18 @@ pub trait StyleSheet {
     fn hovered(&self, style: &Self::Style) -> Appearance {
         self.active(style)
     }
+
+    /// The appearance when the [`Badge`](crate::native::badge::Badge) is disabled.
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 impl std::default::Default for Appearance {
     fn default() -> Self {
         Self {
             background: Background::Color([0.87, 0.87, 0.87].into()),
-            border_radius: None,
+            border_radius: settings::optional_corner_radius(),
             border_width: 1.0,
             border_color: Some([0.8, 0.8, 0.8].into()),
             text_color: Color::BLACK,
@@ -75,6 +99,28 @@ impl BadgeStyles {
     }
 }
 
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for BadgeStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Disabled)
+    }
+}
+
 impl StyleSheet for Theme {
     type Style = BadgeStyles;
 
@@ -96,7 +142,16 @@ impl StyleSheet for Theme {
             BadgeStyles::Light => from_colors(colors::LIGHT, colors::BLACK),
             BadgeStyles::Dark => from_colors(colors::DARK, colors::WHITE),
             BadgeStyles::White => from_colors(colors::WHITE, colors::BLACK),
-            BadgeStyles::Default => Appearance::default(),
+            BadgeStyles::Default => {
+                let palette = self.extended_palette();
+
+                Appearance {
+                    background: palette.background.weak.color.into(),
+                    border_color: Some(palette.background.strong.color),
+                    text_color: palette.background.weak.text,
+                    ..Appearance::default()
+                }
+            }
             BadgeStyles::Custom(custom) => custom.active(self),
         }
     }
@@ -108,4 +163,19 @@ impl StyleSheet for Theme {
 
         self.active(style)
     }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        if let BadgeStyles::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let active = self.active(style);
+
+        Appearance {
+            background: faded_background(active.background),
+            border_color: active.border_color.map(faded),
+            text_color: faded(active.text_color),
+            ..active
+        }
+    }
 }
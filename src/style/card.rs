@@ -3,12 +3,27 @@
 //! *This API requires the following crate features to be activated: card*
 
 use super::colors;
+use super::{
+    status::{faded, faded_background},
+    Status,
+};
+use crate::core::settings;
+use crate::core::theme::is_high_contrast;
 use iced::{Background, Color, Theme};
 
 /// The appearance of a [`Card`](crate::native::card::Card).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Appearance {
     /// The background of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub background: Background,
 
     /// The border radius of the [`Card`](crate::native::card::Card).
@@ -18,27 +33,44 @@ pub struct Appearance {
     pub border_width: f32,
 
     /// The border color of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub border_color: Color,
 
     /// The background of the head of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub head_background: Background,
 
     /// The text color of the head of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub head_text_color: Color,
 
     /// The background of the body of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub body_background: Background,
 
     /// The text color of the body of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub body_text_color: Color,
 
     /// The background of the foot of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub foot_background: Background,
 
     /// The text color of the foot of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub foot_text_color: Color,
 
     /// The color of the close icon of the [`Card`](crate::native::card::Card).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub close_color: Color,
 }
 
@@ -48,6 +80,11 @@ pub trait StyleSheet {
     type Style: Default;
     /// The normal appearance of a [`Card`](crate::native::card::Card).
     fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance when the [`Card`](crate::native::card::Card) is disabled.
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 #[derive(Default)]
@@ -75,6 +112,24 @@ impl CardStyles {
     }
 }
 
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for CardStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Disabled)
+    }
+}
+
 impl StyleSheet for Theme {
     type Style = CardStyles;
 
@@ -112,17 +167,46 @@ impl StyleSheet for Theme {
             CardStyles::Light => backing_only(colors::LIGHT),
             CardStyles::Dark => backing_with_text(colors::DARK, colors::WHITE),
             CardStyles::White => backing_only(colors::WHITE),
-            CardStyles::Default => backing_only([0.87, 0.87, 0.87].into()),
+            CardStyles::Default => {
+                let head = if is_high_contrast(self) {
+                    palette.background.strong
+                } else {
+                    palette.background.weak
+                };
+
+                backing_with_text(head.color, head.text)
+            }
             CardStyles::Custom(custom) => custom.active(self),
         }
     }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        if let CardStyles::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let active = self.active(style);
+
+        Appearance {
+            background: faded_background(active.background),
+            border_color: faded(active.border_color),
+            head_background: faded_background(active.head_background),
+            head_text_color: faded(active.head_text_color),
+            body_background: faded_background(active.body_background),
+            body_text_color: faded(active.body_text_color),
+            foot_background: faded_background(active.foot_background),
+            foot_text_color: faded(active.foot_text_color),
+            close_color: faded(active.close_color),
+            ..active
+        }
+    }
 }
 
 impl Default for Appearance {
     fn default() -> Self {
         Self {
             background: Color::WHITE.into(),
-            border_radius: 10.0,
+            border_radius: settings::corner_radius(10.0),
             border_width: 1.0,
             border_color: [0.87, 0.87, 0.87].into(),
             head_background: Background::Color([0.87, 0.87, 0.87].into()),
@@ -0,0 +1,118 @@
+//! Page through elements one at a time.
+//!
+//! *This API requires the following crate features to be activated: carousel*
+use super::Status;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`Carousel`](crate::native::carousel::Carousel).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the [`Carousel`](crate::native::carousel::Carousel).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_background")
+    )]
+    pub background: Option<Background>,
+
+    /// The background of the arrow buttons.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub arrow_background: Background,
+
+    /// The color of the arrows of the arrow buttons.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub arrow_color: Color,
+
+    /// The color of the dots of the pages.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub dot_color: Color,
+
+    /// The color of the dot of the current page.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub active_dot_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: None,
+            arrow_background: Background::Color(Color::from_rgba(1.0, 1.0, 1.0, 0.8)),
+            arrow_color: Color::BLACK,
+            dot_color: Color::from_rgba(0.5, 0.5, 0.5, 0.5),
+            active_dot_color: [0.2, 0.2, 0.2].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Carousel`](crate::native::carousel::Carousel).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Carousel`](crate::native::carousel::Carousel).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Carousel`](crate::native::carousel::Carousel).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum CarouselStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl CarouselStyles {
+    /// Creates a custom [`CarouselStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for CarouselStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = CarouselStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let CarouselStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            arrow_background: Color {
+                a: 0.8,
+                ..palette.background.base.color
+            }
+            .into(),
+            arrow_color: palette.background.base.text,
+            dot_color: Color {
+                a: 0.5,
+                ..palette.background.strong.color
+            },
+            active_dot_color: palette.primary.base.color,
+            ..Appearance::default()
+        }
+    }
+}
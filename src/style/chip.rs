@@ -0,0 +1,162 @@
+//! Show compact labels that can be pressed, toggled or deleted.
+//!
+//! *This API requires the following crate features to be activated: `chip`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`Chip`](crate::native::chip::Chip).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the chip.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the chip. If no radius is given the ends of the
+    /// chip are rounded.
+    pub border_radius: Option<f32>,
+
+    /// The border width of the chip.
+    pub border_width: f32,
+
+    /// The border color of the chip.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The color of the label.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub text_color: Color,
+
+    /// The color of the leading icon and of the delete cross.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub icon_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.93, 0.93, 0.93).into(),
+            border_radius: settings::optional_corner_radius(),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            text_color: Color::BLACK,
+            icon_color: [0.4, 0.4, 0.4].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Chip`](crate::native::chip::Chip).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`Chip`](crate::native::chip::Chip).
+    ///
+    /// `is_selected` is true if the chip is selected.
+    fn active(&self, style: &Self::Style, is_selected: bool) -> Appearance;
+
+    /// The appearance of a hovered [`Chip`](crate::native::chip::Chip) that
+    /// can be pressed.
+    ///
+    /// `is_selected` is true if the chip is selected.
+    fn hovered(&self, style: &Self::Style, is_selected: bool) -> Appearance {
+        self.active(style, is_selected)
+    }
+}
+
+/// The default appearance of a [`Chip`](crate::native::chip::Chip).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ChipStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ChipStyles {
+    /// Creates a custom [`ChipStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status, bool) -> Appearance + 'static> From<F> for ChipStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status, bool) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style, is_selected: bool) -> Appearance {
+        self(style, Status::Active, is_selected)
+    }
+
+    fn hovered(&self, style: &Self::Style, is_selected: bool) -> Appearance {
+        self(style, Status::Hovered, is_selected)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ChipStyles;
+
+    fn active(&self, style: &Self::Style, is_selected: bool) -> Appearance {
+        if let ChipStyles::Custom(custom) = style {
+            return custom.active(self, is_selected);
+        }
+
+        let palette = self.extended_palette();
+
+        if is_selected {
+            Appearance {
+                background: palette.primary.weak.color.into(),
+                border_color: palette.primary.base.color,
+                text_color: palette.primary.weak.text,
+                icon_color: palette.primary.weak.text,
+                ..Appearance::default()
+            }
+        } else {
+            Appearance {
+                background: palette.background.weak.color.into(),
+                border_color: palette.background.strong.color,
+                text_color: palette.background.weak.text,
+                icon_color: palette.background.strong.text,
+                ..Appearance::default()
+            }
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style, is_selected: bool) -> Appearance {
+        if let ChipStyles::Custom(custom) = style {
+            return custom.hovered(self, is_selected);
+        }
+
+        let palette = self.extended_palette();
+        let active = self.active(style, is_selected);
+
+        if is_selected {
+            Appearance {
+                background: palette.primary.base.color.into(),
+                text_color: palette.primary.base.text,
+                icon_color: palette.primary.base.text,
+                ..active
+            }
+        } else {
+            Appearance {
+                background: palette.background.strong.color.into(),
+                text_color: palette.background.strong.text,
+                ..active
+            }
+        }
+    }
+}
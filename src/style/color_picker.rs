@@ -2,14 +2,25 @@
 //!
 //! *This API requires the following crate features to be activated: `color_picker`*
 
+use crate::core::settings;
 use std::rc::Rc;
 
+use super::Status;
 use iced::{Background, Color, Theme};
 
 /// The appearance of a [`ColorPicker`](crate::native::ColorPicker).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Appearance {
     /// The background of the [`ColorPicker`](crate::native::ColorPicker).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub background: Background,
 
     /// The border radius of the [`ColorPicker`](crate::native::ColorPicker).
@@ -19,6 +30,7 @@ pub struct Appearance {
     pub border_width: f32,
 
     /// The border color of the [`ColorPicker`](crate::native::ColorPicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub border_color: Color,
 
     /// The border radius of the bars of the [`ColorPicker`](crate::native::ColorPicker).
@@ -28,9 +40,16 @@ pub struct Appearance {
     pub bar_border_width: f32,
 
     /// The border color of the bars of the [`ColorPicker`](crate::native::ColorPicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub bar_border_color: Color,
 }
 
+impl Default for Appearance {
+    fn default() -> Self {
+        Theme::default().active(&ColorPickerStyles::Default)
+    }
+}
+
 /// The appearance of a [`ColorPicker`](crate::native::ColorPicker).
 pub trait StyleSheet {
     /// The style type of this stylesheet
@@ -67,6 +86,32 @@ impl ColorPickerStyles {
     }
 }
 
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for ColorPickerStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn selected(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Selected)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Focused)
+    }
+}
+
 impl StyleSheet for Theme {
     type Style = ColorPickerStyles;
 
@@ -80,10 +125,10 @@ impl StyleSheet for Theme {
 
         Appearance {
             background: palette.background.base.color.into(),
-            border_radius: 15.0,
+            border_radius: settings::corner_radius(15.0),
             border_width: 1.0,
             border_color: foreground.text,
-            bar_border_radius: 5.0,
+            bar_border_radius: settings::corner_radius(5.0),
             bar_border_width: 1.0,
             bar_border_color: foreground.text,
         }
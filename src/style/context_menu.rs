@@ -1,16 +1,26 @@
-//! Use a badge for color highlighting important information.
+//! A context menu for showing actions on right click.
 //!
-//! *This API requires the following crate features to be activated: badge*
+//! *This API requires the following crate features to be activated: `context_menu`*
 use std::rc::Rc;
 
+use super::Status;
 use iced::{Background, Color, Theme};
 
 /// The appearance of a [`ContextMenu`](crate::native::ContextMenu).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Appearance {
     /// The background of the [`ContextMenu`](crate::native::ContextMenu).
     ///
     /// This is used to color the backdrop of the modal.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub background: Background,
 }
 
@@ -46,10 +56,28 @@ impl ContextMenuStyle {
     }
 }
 
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for ContextMenuStyle {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
 impl StyleSheet for Theme {
     type Style = ContextMenuStyle;
 
-    fn active(&self, _style: &Self::Style) -> Appearance {
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let ContextMenuStyle::Custom(custom) = style {
+            return custom.active(self);
+        }
+
         let palette = self.extended_palette();
 
         Appearance {
@@ -2,14 +2,26 @@
 //!
 //! *This API requires the following crate features to be activated: `date_picker`*
 
+use crate::core::settings;
 use std::rc::Rc;
 
+use super::Status;
+use crate::core::theme::is_high_contrast;
 use iced::{Background, Color, Theme};
 
 /// The appearance of a [`DatePicker`](crate::native::DatePicker).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Appearance {
     /// The background of the [`DatePicker`](crate::native::DatePicker).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub background: Background,
 
     /// The border radius of the [`DatePicker`](crate::native::DatePicker).
@@ -19,20 +31,33 @@ pub struct Appearance {
     pub border_width: f32,
 
     /// The border color of the [`DatePicker`](crate::native::DatePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub border_color: Color,
 
     /// The text color of the [`DatePicker`](crate::native::DatePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub text_color: Color,
 
     /// The attenuated color of the days which are not in the selected month
     /// of the [`DatePicker`](crate::native::DatePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub text_attenuated_color: Color,
 
     /// The background of the days in the calender of the
     /// [`DatePicker`](crate::native::DatePicker).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub day_background: Background,
 }
 
+impl Default for Appearance {
+    fn default() -> Self {
+        Theme::default().active(&DatePickerStyle::Default)
+    }
+}
+
 /// The appearance of a [`DatePicker`](crate::native::DatePicker).
 pub trait StyleSheet {
     /// The style type of this stylesheet
@@ -69,6 +94,32 @@ impl DatePickerStyle {
     }
 }
 
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for DatePickerStyle {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn selected(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Selected)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Focused)
+    }
+}
+
 impl StyleSheet for Theme {
     type Style = DatePickerStyle;
 
@@ -82,13 +133,17 @@ impl StyleSheet for Theme {
 
         Appearance {
             background: palette.background.base.color.into(),
-            border_radius: 15.0,
+            border_radius: settings::corner_radius(15.0),
             border_width: 1.0,
             border_color: foreground.text,
             text_color: foreground.text,
-            text_attenuated_color: Color {
-                a: foreground.text.a * 0.5,
-                ..foreground.text
+            text_attenuated_color: if is_high_contrast(self) {
+                palette.background.strong.color
+            } else {
+                Color {
+                    a: foreground.text.a * 0.5,
+                    ..foreground.text
+                }
             },
             day_background: palette.background.base.color.into(),
         }
@@ -128,7 +183,11 @@ impl StyleSheet for Theme {
         }
 
         Appearance {
-            border_color: Color::from_rgb(0.5, 0.5, 0.5),
+            border_color: if is_high_contrast(self) {
+                self.extended_palette().primary.strong.color
+            } else {
+                Color::from_rgb(0.5, 0.5, 0.5)
+            },
             ..self.active(style)
         }
     }
@@ -0,0 +1,187 @@
+//! Dock panels on the edges of an area, stack them in tabs or float them.
+//!
+//! *This API requires the following crate features to be activated: `dock`*
+use super::Status;
+use iced::{Background, Color, Shadow, Theme, Vector};
+use std::rc::Rc;
+
+/// The appearance of a [`DockArea`](crate::native::dock::DockArea).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the area, if any.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_background")
+    )]
+    pub background: Option<Background>,
+
+    /// The background of the panels.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub panel_background: Background,
+
+    /// The border width of the panels.
+    pub border_width: f32,
+
+    /// The border color of the panels.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The background of the splitters between the docked panels.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub splitter_background: Background,
+
+    /// The background of the title bars of the floating panels.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub title_bar_background: Background,
+
+    /// The color of the titles of the floating panels.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub title_color: Color,
+
+    /// The shadow of the floating panels.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::shadow"))]
+    pub shadow: Shadow,
+
+    /// The background of the indicators docking a dragged panel.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub indicator_background: Background,
+
+    /// The border color of the indicators docking a dragged panel.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub indicator_border_color: Color,
+
+    /// The background of the space a dragged panel takes once dropped.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub preview_background: Background,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: None,
+            panel_background: Color::WHITE.into(),
+            border_width: 1.0,
+            border_color: Color::from_rgb(0.8, 0.8, 0.8),
+            splitter_background: Color::TRANSPARENT.into(),
+            title_bar_background: Color::from_rgb(0.9, 0.9, 0.9).into(),
+            title_color: Color::BLACK,
+            shadow: Shadow::default(),
+            indicator_background: Color::WHITE.into(),
+            indicator_border_color: Color::from_rgb(0.5, 0.5, 0.5),
+            preview_background: Color::from_rgba(0.0, 0.0, 1.0, 0.15).into(),
+        }
+    }
+}
+
+/// The appearance of a [`DockArea`](crate::native::dock::DockArea).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`DockArea`](crate::native::dock::DockArea).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a [`DockArea`](crate::native::dock::DockArea) while
+    /// one of its splitters is hovered or dragged.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`DockArea`](crate::native::dock::DockArea).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum DockStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl DockStyles {
+    /// Creates a custom [`DockStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for DockStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = DockStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let DockStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: Some(palette.background.weak.color.into()),
+            panel_background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            title_bar_background: palette.background.weak.color.into(),
+            title_color: palette.background.base.text,
+            shadow: Shadow {
+                color: Color::from_rgba(0.0, 0.0, 0.0, 0.25),
+                offset: Vector::new(0.0, 4.0),
+                blur_radius: 12.0,
+            },
+            indicator_background: palette.background.base.color.into(),
+            indicator_border_color: palette.primary.base.color,
+            preview_background: Color {
+                a: 0.25,
+                ..palette.primary.base.color
+            }
+            .into(),
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let DockStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            splitter_background: palette.primary.weak.color.into(),
+            ..self.active(style)
+        }
+    }
+}
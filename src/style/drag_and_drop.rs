@@ -0,0 +1,181 @@
+//! Drag payloads from draggable elements and drop them on targets.
+//!
+//! *This API requires the following crate features to be activated: `drag_and_drop`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Shadow, Theme, Vector};
+use std::rc::Rc;
+
+/// The appearance of a [`DropTarget`](crate::native::drag_and_drop::DropTarget)
+/// or of the preview of a [`Draggable`](crate::native::drag_and_drop::Draggable).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background drawn behind the content, if any.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_background")
+    )]
+    pub background: Option<Background>,
+
+    /// The border radius.
+    pub border_radius: f32,
+
+    /// The border width.
+    pub border_width: f32,
+
+    /// The border color.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The shadow.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::shadow"))]
+    pub shadow: Shadow,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: None,
+            border_radius: settings::corner_radius(4.0),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
+        }
+    }
+}
+
+/// The appearance of the drag and drop widgets.
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The appearance of a [`DropTarget`](crate::native::drag_and_drop::DropTarget)
+    /// while nothing it accepts is dragged.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a [`DropTarget`](crate::native::drag_and_drop::DropTarget)
+    /// while a payload it accepts is dragged elsewhere.
+    fn valid(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a [`DropTarget`](crate::native::drag_and_drop::DropTarget)
+    /// while a payload it accepts is dragged over it.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of the preview of a [`Draggable`](crate::native::drag_and_drop::Draggable)
+    /// following the cursor.
+    fn preview(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of the drag and drop widgets.
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum DragAndDropStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl DragAndDropStyles {
+    /// Creates a custom [`DragAndDropStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for DragAndDropStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn valid(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Valid)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+
+    fn preview(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Preview)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = DragAndDropStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let DragAndDropStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        Appearance::default()
+    }
+
+    fn valid(&self, style: &Self::Style) -> Appearance {
+        if let DragAndDropStyles::Custom(custom) = style {
+            return custom.valid(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_width: 1.0,
+            border_color: palette.primary.weak.color,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let DragAndDropStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: Some(
+                Color {
+                    a: 0.15,
+                    ..palette.primary.base.color
+                }
+                .into(),
+            ),
+            border_width: 2.0,
+            border_color: palette.primary.strong.color,
+            ..Appearance::default()
+        }
+    }
+
+    fn preview(&self, style: &Self::Style) -> Appearance {
+        if let DragAndDropStyles::Custom(custom) = style {
+            return custom.preview(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: Some(palette.background.base.color.into()),
+            border_width: 1.0,
+            border_color: palette.background.strong.color,
+            shadow: Shadow {
+                color: Color::from_rgba(0.0, 0.0, 0.0, 0.25),
+                offset: Vector::new(0.0, 4.0),
+                blur_radius: 12.0,
+            },
+            ..Appearance::default()
+        }
+    }
+}
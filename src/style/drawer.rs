@@ -0,0 +1,105 @@
+//! Slide a panel in from an edge of the content.
+//!
+//! *This API requires the following crate features to be activated: drawer*
+use super::Status;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`Drawer`](crate::native::drawer::Drawer).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the panel.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The color of the border between the panel and the content.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The width of the border between the panel and the content.
+    pub border_width: f32,
+
+    /// The color of the shadow of the panel shown over the content.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub shadow_color: Color,
+
+    /// The backdrop covering the content under the panel, faded in with it.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub backdrop: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color(Color::WHITE),
+            border_color: [0.85, 0.85, 0.85].into(),
+            border_width: 1.0,
+            shadow_color: Color::from_rgba(0.0, 0.0, 0.0, 0.2),
+            backdrop: Color::from_rgba(0.0, 0.0, 0.0, 0.4),
+        }
+    }
+}
+
+/// The appearance of a [`Drawer`](crate::native::drawer::Drawer).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Drawer`](crate::native::drawer::Drawer).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Drawer`](crate::native::drawer::Drawer).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum DrawerStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl DrawerStyles {
+    /// Creates a custom [`DrawerStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for DrawerStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = DrawerStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let DrawerStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Browse the file system and select files.
+//!
+//! *This API requires the following crate features to be activated: `file_browser`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`FileBrowser`](crate::native::file_browser::FileBrowser).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the browser.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the browser.
+    pub border_radius: f32,
+
+    /// The border width of the browser.
+    pub border_width: f32,
+
+    /// The border color of the browser.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The background of the path and of the filter above the entries.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub toolbar_background: Background,
+
+    /// The color of the names of the entries and of the path.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub text_color: Color,
+
+    /// The color of the separators of the path, of the hint of the filter and
+    /// of the errors.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub muted_text_color: Color,
+
+    /// The color of the marker of the directories.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub directory_color: Color,
+
+    /// The background of the hovered entry.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub hovered_background: Background,
+
+    /// The background of the selected entries.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub selected_background: Background,
+
+    /// The color of the names of the selected entries.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub selected_text_color: Color,
+
+    /// The background of the button showing the hidden files while they are shown.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub toggle_background: Background,
+
+    /// The color of the scrollbar.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub scrollbar_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: settings::corner_radius(4.0),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            toolbar_background: Color::from_rgb(0.96, 0.96, 0.96).into(),
+            text_color: Color::BLACK,
+            muted_text_color: [0.5, 0.5, 0.5].into(),
+            directory_color: [0.85, 0.65, 0.2].into(),
+            hovered_background: Color::from_rgba(0.2, 0.5, 0.8, 0.1).into(),
+            selected_background: Color::from_rgb(0.2, 0.5, 0.8).into(),
+            selected_text_color: Color::WHITE,
+            toggle_background: Color::from_rgba(0.2, 0.5, 0.8, 0.25).into(),
+            scrollbar_color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+        }
+    }
+}
+
+/// The appearance of a [`FileBrowser`](crate::native::file_browser::FileBrowser).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`FileBrowser`](crate::native::file_browser::FileBrowser).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a focused [`FileBrowser`](crate::native::file_browser::FileBrowser).
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`FileBrowser`](crate::native::file_browser::FileBrowser).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum FileBrowserStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl FileBrowserStyles {
+    /// Creates a custom [`FileBrowserStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for FileBrowserStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Focused)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = FileBrowserStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let FileBrowserStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            toolbar_background: palette.background.weak.color.into(),
+            text_color: palette.background.base.text,
+            muted_text_color: palette.background.strong.color,
+            hovered_background: palette.background.weak.color.into(),
+            selected_background: palette.primary.base.color.into(),
+            selected_text_color: palette.primary.base.text,
+            toggle_background: palette.primary.weak.color.into(),
+            scrollbar_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        if let FileBrowserStyles::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.primary.strong.color,
+            ..self.active(style)
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Show a value per day of a year as colored cells.
+//!
+//! *This API requires the following crate features to be activated: `heatmap_calendar`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`HeatmapCalendar`](crate::native::heatmap_calendar::HeatmapCalendar).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the calendar.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The colors of the days by level, from the days without a value to the
+    /// days with the highest values.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::colors"))]
+    pub level_colors: [Color; 5],
+
+    /// The border radius of a day.
+    pub cell_border_radius: f32,
+
+    /// The border color of the hovered day.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub hovered_border_color: Color,
+
+    /// The color of the labels of the months and of the weekdays.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub label_color: Color,
+
+    /// The background of the tooltip.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub tooltip_background: Background,
+
+    /// The border radius of the tooltip.
+    pub tooltip_border_radius: f32,
+
+    /// The color of the text of the tooltip.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub tooltip_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            level_colors: [
+                [0.92, 0.93, 0.94].into(),
+                [0.61, 0.91, 0.66].into(),
+                [0.25, 0.77, 0.39].into(),
+                [0.19, 0.63, 0.31].into(),
+                [0.13, 0.43, 0.22].into(),
+            ],
+            cell_border_radius: settings::corner_radius(2.0),
+            hovered_border_color: Color::BLACK,
+            label_color: [0.4, 0.4, 0.4].into(),
+            tooltip_background: Color::from_rgb(0.15, 0.15, 0.15).into(),
+            tooltip_border_radius: settings::corner_radius(4.0),
+            tooltip_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`HeatmapCalendar`](crate::native::heatmap_calendar::HeatmapCalendar).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`HeatmapCalendar`](crate::native::heatmap_calendar::HeatmapCalendar).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`HeatmapCalendar`](crate::native::heatmap_calendar::HeatmapCalendar).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum HeatmapCalendarStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl HeatmapCalendarStyles {
+    /// Creates a custom [`HeatmapCalendarStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for HeatmapCalendarStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = HeatmapCalendarStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let HeatmapCalendarStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+        let level = |alpha| Color {
+            a: alpha,
+            ..palette.success.base.color
+        };
+
+        Appearance {
+            level_colors: [
+                palette.background.weak.color,
+                level(0.3),
+                level(0.55),
+                level(0.8),
+                level(1.0),
+            ],
+            hovered_border_color: palette.background.base.text,
+            label_color: palette.background.strong.text,
+            tooltip_background: palette.background.base.text.into(),
+            tooltip_text_color: palette.background.base.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Record a key combination by pressing it.
+//!
+//! *This API requires the following crate features to be activated: `hotkey_input`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`HotkeyInput`](crate::native::hotkey_input::HotkeyInput).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the field.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the field.
+    pub border_radius: f32,
+
+    /// The border width of the field.
+    pub border_width: f32,
+
+    /// The border color of the field.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The color of the recorded key combination.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub text_color: Color,
+
+    /// The color of the placeholder and of the hint shown while recording.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub placeholder_color: Color,
+
+    /// The color of the button clearing the key combination.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub icon_color: Color,
+
+    /// The color of the hovered button clearing the key combination.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub hovered_icon_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: settings::corner_radius(4.0),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            placeholder_color: [0.6, 0.6, 0.6].into(),
+            icon_color: [0.6, 0.6, 0.6].into(),
+            hovered_icon_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`HotkeyInput`](crate::native::hotkey_input::HotkeyInput).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`HotkeyInput`](crate::native::hotkey_input::HotkeyInput).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a [`HotkeyInput`](crate::native::hotkey_input::HotkeyInput)
+    /// recording a key combination.
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`HotkeyInput`](crate::native::hotkey_input::HotkeyInput).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum HotkeyInputStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl HotkeyInputStyles {
+    /// Creates a custom [`HotkeyInputStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for HotkeyInputStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Focused)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = HotkeyInputStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let HotkeyInputStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            placeholder_color: palette.background.strong.color,
+            icon_color: palette.background.strong.color,
+            hovered_icon_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        if let HotkeyInputStyles::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.primary.strong.color,
+            border_width: 2.0,
+            ..self.active(style)
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Zoom and pan an image.
+//!
+//! *This API requires the following crate features to be activated: `image_viewer`*
+use super::Status;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of an [`ImageViewer`](crate::native::image_viewer::ImageViewer).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background around the image.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The border width of the viewer.
+    pub border_width: f32,
+
+    /// The border color of the viewer.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The color of the light squares of the checkerboard behind the image.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub checker_light: Color,
+
+    /// The color of the dark squares of the checkerboard behind the image.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub checker_dark: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.2, 0.2, 0.2).into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            checker_light: Color::from_rgb(0.8, 0.8, 0.8),
+            checker_dark: Color::from_rgb(0.6, 0.6, 0.6),
+        }
+    }
+}
+
+/// The appearance of an [`ImageViewer`](crate::native::image_viewer::ImageViewer).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of an [`ImageViewer`](crate::native::image_viewer::ImageViewer).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of an [`ImageViewer`](crate::native::image_viewer::ImageViewer).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ImageViewerStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ImageViewerStyles {
+    /// Creates a custom [`ImageViewerStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for ImageViewerStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ImageViewerStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let ImageViewerStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            checker_light: palette.background.weak.color,
+            checker_dark: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Show nested data, like JSON values, as an expandable tree.
+//!
+//! *This API requires the following crate features to be activated: `json_tree`*
+use super::Status;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`JsonTree`](crate::native::json_tree::JsonTree).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the tree.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The color of the keys and of the indices.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub key_color: Color,
+
+    /// The color of the strings.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub string_color: Color,
+
+    /// The color of the numbers.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub number_color: Color,
+
+    /// The color of the booleans.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub bool_color: Color,
+
+    /// The color of the null values.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub null_color: Color,
+
+    /// The color of the summaries of the arrays and of the objects.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub summary_color: Color,
+
+    /// The color of the arrows expanding and collapsing the rows.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub toggle_color: Color,
+
+    /// The background of the hovered row.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub hovered_background: Background,
+
+    /// The background of the rows matching the searched text.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub match_background: Background,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT.into(),
+            key_color: [0.55, 0.2, 0.6].into(),
+            string_color: [0.1, 0.5, 0.2].into(),
+            number_color: [0.1, 0.35, 0.75].into(),
+            bool_color: [0.75, 0.4, 0.0].into(),
+            null_color: [0.5, 0.5, 0.5].into(),
+            summary_color: [0.5, 0.5, 0.5].into(),
+            toggle_color: [0.4, 0.4, 0.4].into(),
+            hovered_background: Color::from_rgba(0.0, 0.0, 0.0, 0.05).into(),
+            match_background: Color::from_rgba(1.0, 0.85, 0.0, 0.35).into(),
+        }
+    }
+}
+
+/// The appearance of a [`JsonTree`](crate::native::json_tree::JsonTree).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`JsonTree`](crate::native::json_tree::JsonTree).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`JsonTree`](crate::native::json_tree::JsonTree).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum JsonTreeStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl JsonTreeStyles {
+    /// Creates a custom [`JsonTreeStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for JsonTreeStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = JsonTreeStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let JsonTreeStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            key_color: palette.primary.strong.color,
+            string_color: palette.success.base.color,
+            number_color: palette.primary.base.color,
+            bool_color: palette.danger.base.color,
+            null_color: palette.background.strong.color,
+            summary_color: palette.background.strong.color,
+            toggle_color: palette.background.strong.text,
+            hovered_background: palette.background.weak.color.into(),
+            match_background: Color {
+                a: 0.3,
+                ..palette.primary.weak.color
+            }
+            .into(),
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Turn a rotary knob to select a value.
+//!
+//! *This API requires the following crate features to be activated: `knob`*
+use super::Status;
+use iced::{Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`Knob`](crate::native::knob::Knob).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The color of the body of the knob.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub background: Color,
+
+    /// The border width of the body of the knob.
+    pub border_width: f32,
+
+    /// The border color of the body of the knob.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The color of the arc showing the whole range.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub track_color: Color,
+
+    /// The color of the arc showing the value.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub value_color: Color,
+
+    /// The thickness of the arcs.
+    pub arc_width: f32,
+
+    /// The color of the line pointing to the value.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub indicator_color: Color,
+
+    /// The color of the tick marks.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub tick_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE,
+            border_width: 1.0,
+            border_color: [0.6, 0.6, 0.6].into(),
+            track_color: [0.85, 0.85, 0.85].into(),
+            value_color: [0.2, 0.5, 0.8].into(),
+            arc_width: 3.0,
+            indicator_color: [0.2, 0.2, 0.2].into(),
+            tick_color: [0.6, 0.6, 0.6].into(),
+        }
+    }
+}
+
+/// The appearance of a [`Knob`](crate::native::knob::Knob).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`Knob`](crate::native::knob::Knob).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a hovered [`Knob`](crate::native::knob::Knob).
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a [`Knob`](crate::native::knob::Knob) being turned.
+    fn dragging(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+}
+
+/// The default appearance of a [`Knob`](crate::native::knob::Knob).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum KnobStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl KnobStyles {
+    /// Creates a custom [`KnobStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for KnobStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+
+    fn dragging(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Dragging)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = KnobStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let KnobStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color,
+            border_color: palette.background.strong.color,
+            track_color: palette.background.strong.color,
+            value_color: palette.primary.base.color,
+            indicator_color: palette.background.base.text,
+            tick_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let KnobStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.primary.base.color,
+            ..self.active(style)
+        }
+    }
+
+    fn dragging(&self, style: &Self::Style) -> Appearance {
+        if let KnobStyles::Custom(custom) = style {
+            return custom.dragging(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            value_color: palette.primary.strong.color,
+            ..self.hovered(style)
+        }
+    }
+}
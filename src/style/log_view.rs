@@ -0,0 +1,166 @@
+//! Show the lines of a log, following the appended ones.
+//!
+//! *This API requires the following crate features to be activated: `log_view`*
+use super::Status;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`LogView`](crate::native::log_view::LogView).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the log.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The color of the trace lines.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub trace_color: Color,
+
+    /// The color of the debug lines.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub debug_color: Color,
+
+    /// The color of the info lines.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub info_color: Color,
+
+    /// The color of the warning lines.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub warn_color: Color,
+
+    /// The color of the error lines.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub error_color: Color,
+
+    /// The background of the lines matching the searched text.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub match_background: Background,
+
+    /// The background of the scrollbar.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub scrollbar_background: Background,
+
+    /// The color of the scroller moving along the scrollbar.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub scroller_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.1, 0.1, 0.1).into(),
+            trace_color: [0.45, 0.45, 0.45].into(),
+            debug_color: [0.6, 0.6, 0.6].into(),
+            info_color: [0.9, 0.9, 0.9].into(),
+            warn_color: [0.95, 0.75, 0.2].into(),
+            error_color: [0.95, 0.35, 0.3].into(),
+            match_background: Color::from_rgba(1.0, 0.85, 0.0, 0.25).into(),
+            scrollbar_background: Color::TRANSPARENT.into(),
+            scroller_color: Color::from_rgba(1.0, 1.0, 1.0, 0.3),
+        }
+    }
+}
+
+/// The appearance of a [`LogView`](crate::native::log_view::LogView).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`LogView`](crate::native::log_view::LogView).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a [`LogView`](crate::native::log_view::LogView)
+    /// while its scrollbar is hovered or dragged.
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`LogView`](crate::native::log_view::LogView).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum LogViewStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl LogViewStyles {
+    /// Creates a custom [`LogViewStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for LogViewStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = LogViewStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let LogViewStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            trace_color: palette.background.strong.color,
+            debug_color: palette.background.strong.text,
+            info_color: palette.background.base.text,
+            error_color: palette.danger.base.color,
+            match_background: Color {
+                a: 0.3,
+                ..palette.primary.weak.color
+            }
+            .into(),
+            scroller_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let LogViewStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            scrollbar_background: palette.background.weak.color.into(),
+            scroller_color: palette.primary.strong.color,
+            ..self.active(style)
+        }
+    }
+}
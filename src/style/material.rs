@@ -0,0 +1,686 @@
+//! Material Design 3 themes for the widgets.
+//!
+//! A [`Scheme`] holds the color roles of Material Design 3. Turned into a
+//! [`Theme`] with [`Scheme::theme`], or through the [`light`] and [`dark`]
+//! baseline themes, it gives its colors to every widget of the crate, whose
+//! default appearances follow the palette of the theme. The style functions
+//! of this module, like [`card`] or [`tab_bar`], also apply the [`Shape`] and
+//! [`Elevation`] tokens of Material Design 3, and its color roles for the
+//! containers and surfaces.
+//!
+//! *This API requires the following crate features to be activated: `material`*
+//!
+//! # Example
+//! ```ignore
+//! # use iced_aw::{style::material, Card};
+//! #
+//! fn theme(&self) -> iced::Theme {
+//!     material::dark()
+//! }
+//!
+//! let card = Card::new("Head", "Body").style(material::card);
+//! ```
+
+use super::Status;
+use crate::core::theme::rgb;
+use iced::{
+    theme::{
+        palette::{Background, Danger, Extended, Pair, Primary, Secondary, Success},
+        Palette,
+    },
+    Color, Shadow, Theme, Vector,
+};
+
+/// The name of the [`light`] theme.
+pub const MATERIAL_LIGHT: &str = "Material Light";
+
+/// The name of the [`dark`] theme.
+pub const MATERIAL_DARK: &str = "Material Dark";
+
+/// The opacity of the scrim behind the modal surfaces.
+const SCRIM_OPACITY: f32 = 0.32;
+
+/// The color roles of Material Design 3.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Scheme {
+    /// The color of the prominent parts, like the selected items.
+    pub primary: Color,
+    /// The color of the content on [`primary`](Self::primary).
+    pub on_primary: Color,
+    /// The color of the containers standing out less than the primary parts.
+    pub primary_container: Color,
+    /// The color of the content on
+    /// [`primary_container`](Self::primary_container).
+    pub on_primary_container: Color,
+    /// The color of the less prominent parts, like the filter chips.
+    pub secondary: Color,
+    /// The color of the content on [`secondary`](Self::secondary).
+    pub on_secondary: Color,
+    /// The color of the secondary containers, like the selection indicators.
+    pub secondary_container: Color,
+    /// The color of the content on
+    /// [`secondary_container`](Self::secondary_container).
+    pub on_secondary_container: Color,
+    /// The color contrasting with the primary and secondary colors.
+    pub tertiary: Color,
+    /// The color of the content on [`tertiary`](Self::tertiary).
+    pub on_tertiary: Color,
+    /// The color of the tertiary containers.
+    pub tertiary_container: Color,
+    /// The color of the content on
+    /// [`tertiary_container`](Self::tertiary_container).
+    pub on_tertiary_container: Color,
+    /// The color of the errors.
+    pub error: Color,
+    /// The color of the content on [`error`](Self::error).
+    pub on_error: Color,
+    /// The color of the error containers.
+    pub error_container: Color,
+    /// The color of the content on
+    /// [`error_container`](Self::error_container).
+    pub on_error_container: Color,
+    /// The color of the background.
+    pub surface: Color,
+    /// The color of the content on [`surface`](Self::surface).
+    pub on_surface: Color,
+    /// The color of the less emphasized content on the surfaces.
+    pub on_surface_variant: Color,
+    /// The color of the containers slightly apart from the surface.
+    pub surface_container_low: Color,
+    /// The color of the containers, like the menus.
+    pub surface_container: Color,
+    /// The color of the containers standing out from the surface.
+    pub surface_container_high: Color,
+    /// The color of the surfaces contrasting with the others, like the
+    /// snackbars.
+    pub inverse_surface: Color,
+    /// The color of the content on [`inverse_surface`](Self::inverse_surface).
+    pub inverse_on_surface: Color,
+    /// The primary color on [`inverse_surface`](Self::inverse_surface).
+    pub inverse_primary: Color,
+    /// The color of the important borders.
+    pub outline: Color,
+    /// The color of the decorative borders and dividers.
+    pub outline_variant: Color,
+    /// The color of the shadows.
+    pub shadow: Color,
+    /// The color of the scrim behind the modal surfaces.
+    pub scrim: Color,
+    /// Whether the scheme is dark.
+    pub is_dark: bool,
+}
+
+impl Scheme {
+    /// The baseline light scheme of Material Design 3.
+    pub const LIGHT: Self = Self {
+        primary: rgb(0x67, 0x50, 0xA4),
+        on_primary: Color::WHITE,
+        primary_container: rgb(0xEA, 0xDD, 0xFF),
+        on_primary_container: rgb(0x21, 0x00, 0x5D),
+        secondary: rgb(0x62, 0x5B, 0x71),
+        on_secondary: Color::WHITE,
+        secondary_container: rgb(0xE8, 0xDE, 0xF8),
+        on_secondary_container: rgb(0x1D, 0x19, 0x2B),
+        tertiary: rgb(0x7D, 0x52, 0x60),
+        on_tertiary: Color::WHITE,
+        tertiary_container: rgb(0xFF, 0xD8, 0xE4),
+        on_tertiary_container: rgb(0x31, 0x11, 0x1D),
+        error: rgb(0xB3, 0x26, 0x1E),
+        on_error: Color::WHITE,
+        error_container: rgb(0xF9, 0xDE, 0xDC),
+        on_error_container: rgb(0x41, 0x0E, 0x0B),
+        surface: rgb(0xFE, 0xF7, 0xFF),
+        on_surface: rgb(0x1D, 0x1B, 0x20),
+        on_surface_variant: rgb(0x49, 0x45, 0x4F),
+        surface_container_low: rgb(0xF7, 0xF2, 0xFA),
+        surface_container: rgb(0xF3, 0xED, 0xF7),
+        surface_container_high: rgb(0xEC, 0xE6, 0xF0),
+        inverse_surface: rgb(0x32, 0x2F, 0x35),
+        inverse_on_surface: rgb(0xF5, 0xEF, 0xF7),
+        inverse_primary: rgb(0xD0, 0xBC, 0xFF),
+        outline: rgb(0x79, 0x74, 0x7E),
+        outline_variant: rgb(0xCA, 0xC4, 0xD0),
+        shadow: Color::BLACK,
+        scrim: Color::BLACK,
+        is_dark: false,
+    };
+
+    /// The baseline dark scheme of Material Design 3.
+    pub const DARK: Self = Self {
+        primary: rgb(0xD0, 0xBC, 0xFF),
+        on_primary: rgb(0x38, 0x1E, 0x72),
+        primary_container: rgb(0x4F, 0x37, 0x8B),
+        on_primary_container: rgb(0xEA, 0xDD, 0xFF),
+        secondary: rgb(0xCC, 0xC2, 0xDC),
+        on_secondary: rgb(0x33, 0x2D, 0x41),
+        secondary_container: rgb(0x4A, 0x44, 0x58),
+        on_secondary_container: rgb(0xE8, 0xDE, 0xF8),
+        tertiary: rgb(0xEF, 0xB8, 0xC8),
+        on_tertiary: rgb(0x49, 0x25, 0x32),
+        tertiary_container: rgb(0x63, 0x3B, 0x48),
+        on_tertiary_container: rgb(0xFF, 0xD8, 0xE4),
+        error: rgb(0xF2, 0xB8, 0xB5),
+        on_error: rgb(0x60, 0x14, 0x10),
+        error_container: rgb(0x8C, 0x1D, 0x18),
+        on_error_container: rgb(0xF9, 0xDE, 0xDC),
+        surface: rgb(0x14, 0x12, 0x18),
+        on_surface: rgb(0xE6, 0xE0, 0xE9),
+        on_surface_variant: rgb(0xCA, 0xC4, 0xD0),
+        surface_container_low: rgb(0x1D, 0x1B, 0x20),
+        surface_container: rgb(0x21, 0x1F, 0x26),
+        surface_container_high: rgb(0x2B, 0x29, 0x30),
+        inverse_surface: rgb(0xE6, 0xE0, 0xE9),
+        inverse_on_surface: rgb(0x32, 0x2F, 0x35),
+        inverse_primary: rgb(0x67, 0x50, 0xA4),
+        outline: rgb(0x93, 0x8F, 0x99),
+        outline_variant: rgb(0x49, 0x45, 0x4F),
+        shadow: Color::BLACK,
+        scrim: Color::BLACK,
+        is_dark: true,
+    };
+
+    /// Returns the [`Scheme`] of the `theme`.
+    ///
+    /// The [`light`] and [`dark`] themes give their baseline schemes. The
+    /// roles of the other themes are taken from their palette, the
+    /// containers being its weak colors.
+    #[must_use]
+    pub fn of(theme: &Theme) -> Self {
+        if matches!(theme, Theme::Custom(_)) {
+            match theme.to_string().as_str() {
+                MATERIAL_LIGHT => return Self::LIGHT,
+                MATERIAL_DARK => return Self::DARK,
+                _ => {}
+            }
+        }
+
+        let palette = theme.extended_palette();
+        let background = palette.background;
+
+        Self {
+            primary: palette.primary.base.color,
+            on_primary: palette.primary.base.text,
+            primary_container: palette.primary.weak.color,
+            on_primary_container: palette.primary.weak.text,
+            secondary: palette.secondary.base.color,
+            on_secondary: palette.secondary.base.text,
+            secondary_container: palette.secondary.weak.color,
+            on_secondary_container: palette.secondary.weak.text,
+            tertiary: palette.success.base.color,
+            on_tertiary: palette.success.base.text,
+            tertiary_container: palette.success.weak.color,
+            on_tertiary_container: palette.success.weak.text,
+            error: palette.danger.base.color,
+            on_error: palette.danger.base.text,
+            error_container: palette.danger.weak.color,
+            on_error_container: palette.danger.weak.text,
+            surface: background.base.color,
+            on_surface: background.base.text,
+            on_surface_variant: background.weak.text,
+            surface_container_low: background.base.color,
+            surface_container: background.weak.color,
+            surface_container_high: background.weak.color,
+            inverse_surface: background.base.text,
+            inverse_on_surface: background.base.color,
+            inverse_primary: palette.primary.strong.color,
+            outline: background.strong.color,
+            outline_variant: background.weak.color,
+            shadow: Color::BLACK,
+            scrim: Color::BLACK,
+            is_dark: palette.is_dark,
+        }
+    }
+
+    /// Returns a [`Theme`] with the given `name` whose palette is made of
+    /// the roles of the [`Scheme`].
+    ///
+    /// The tertiary roles stand for the success colors, which Material
+    /// Design 3 does not have.
+    #[must_use]
+    pub fn theme(self, name: impl Into<String>) -> Theme {
+        let palette = Palette {
+            background: self.surface,
+            text: self.on_surface,
+            primary: self.primary,
+            success: self.tertiary,
+            danger: self.error,
+        };
+
+        Theme::custom_with_fn(name.into(), palette, move |_| self.extended())
+    }
+
+    /// Returns the [`Extended`] palette made of the roles of the [`Scheme`].
+    fn extended(self) -> Extended {
+        Extended {
+            background: Background {
+                base: Pair::new(self.surface, self.on_surface),
+                weak: Pair::new(self.surface_container_high, self.on_surface_variant),
+                strong: Pair::new(self.outline, self.surface),
+            },
+            primary: Primary {
+                base: Pair::new(self.primary, self.on_primary),
+                weak: Pair::new(self.primary_container, self.on_primary_container),
+                strong: Pair::new(self.on_primary_container, self.primary_container),
+            },
+            secondary: Secondary {
+                base: Pair::new(self.secondary, self.on_secondary),
+                weak: Pair::new(self.secondary_container, self.on_secondary_container),
+                strong: Pair::new(self.on_secondary_container, self.secondary_container),
+            },
+            success: Success {
+                base: Pair::new(self.tertiary, self.on_tertiary),
+                weak: Pair::new(self.tertiary_container, self.on_tertiary_container),
+                strong: Pair::new(self.on_tertiary_container, self.tertiary_container),
+            },
+            danger: Danger {
+                base: Pair::new(self.error, self.on_error),
+                weak: Pair::new(self.error_container, self.on_error_container),
+                strong: Pair::new(self.on_error_container, self.error_container),
+            },
+            is_dark: self.is_dark,
+        }
+    }
+
+    /// Returns the color of the scrim behind the modal surfaces.
+    fn backdrop(self) -> Color {
+        Color {
+            a: SCRIM_OPACITY,
+            ..self.scrim
+        }
+    }
+}
+
+/// Returns the light [`Theme`] of the baseline scheme of Material Design 3.
+#[must_use]
+pub fn light() -> Theme {
+    Scheme::LIGHT.theme(MATERIAL_LIGHT)
+}
+
+/// Returns the dark [`Theme`] of the baseline scheme of Material Design 3.
+#[must_use]
+pub fn dark() -> Theme {
+    Scheme::DARK.theme(MATERIAL_DARK)
+}
+
+/// The corner shapes of Material Design 3.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Shape {
+    /// Square corners.
+    None,
+    /// The corners of the small parts, like the menus and the snackbars.
+    ExtraSmall,
+    /// The corners of the chips.
+    Small,
+    /// The corners of the cards.
+    Medium,
+    /// The corners of the drawers.
+    Large,
+    /// The corners of the dialogs.
+    ExtraLarge,
+}
+
+impl Shape {
+    /// Returns the corner radius of the [`Shape`].
+    #[must_use]
+    pub const fn radius(self) -> f32 {
+        match self {
+            Self::None => 0.0,
+            Self::ExtraSmall => 4.0,
+            Self::Small => 8.0,
+            Self::Medium => 12.0,
+            Self::Large => 16.0,
+            Self::ExtraLarge => 28.0,
+        }
+    }
+}
+
+/// The elevation levels of Material Design 3, giving the shadows of the
+/// surfaces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Elevation {
+    /// No shadow.
+    Level0,
+    /// The level of the elevated cards.
+    Level1,
+    /// The level of the menus.
+    Level2,
+    /// The level of the dialogs and the snackbars.
+    Level3,
+    /// The level of the hovered surfaces of level 3.
+    Level4,
+    /// The highest level.
+    Level5,
+}
+
+impl Elevation {
+    /// Returns the shadow of the [`Elevation`], of the given `color`.
+    #[must_use]
+    pub fn shadow(self, color: Color) -> Shadow {
+        let (offset, blur_radius) = match self {
+            Self::Level0 => return Shadow::default(),
+            Self::Level1 => (1.0, 3.0),
+            Self::Level2 => (2.0, 6.0),
+            Self::Level3 => (4.0, 8.0),
+            Self::Level4 => (6.0, 10.0),
+            Self::Level5 => (8.0, 12.0),
+        };
+
+        Shadow {
+            color: Color { a: 0.3, ..color },
+            offset: Vector::new(0.0, offset),
+            blur_radius,
+        }
+    }
+}
+
+/// The Material Design 3 appearance of a [`Badge`](crate::widgets::Badge).
+#[cfg(feature = "badge")]
+#[must_use]
+pub fn badge(theme: &Theme, _status: Status) -> super::badge::Appearance {
+    let scheme = Scheme::of(theme);
+
+    super::badge::Appearance {
+        background: scheme.error.into(),
+        border_radius: None,
+        border_width: 0.0,
+        border_color: None,
+        text_color: scheme.on_error,
+    }
+}
+
+/// The Material Design 3 appearance of an outlined
+/// [`Card`](crate::widgets::Card).
+#[cfg(feature = "card")]
+#[must_use]
+pub fn card(theme: &Theme, _status: Status) -> super::card::Appearance {
+    let scheme = Scheme::of(theme);
+
+    super::card::Appearance {
+        background: scheme.surface.into(),
+        border_radius: Shape::Medium.radius(),
+        border_width: 1.0,
+        border_color: scheme.outline_variant,
+        head_background: scheme.surface_container_high.into(),
+        head_text_color: scheme.on_surface,
+        body_background: scheme.surface.into(),
+        body_text_color: scheme.on_surface_variant,
+        foot_background: scheme.surface.into(),
+        foot_text_color: scheme.on_surface_variant,
+        close_color: scheme.on_surface_variant,
+    }
+}
+
+/// The Material Design 3 appearance of a filter
+/// [`Chip`](crate::widgets::Chip).
+#[cfg(feature = "chip")]
+#[must_use]
+pub fn chip(theme: &Theme, status: Status, is_selected: bool) -> super::chip::Appearance {
+    let scheme = Scheme::of(theme);
+    let hovered = status == Status::Hovered;
+
+    if is_selected {
+        super::chip::Appearance {
+            background: scheme.secondary_container.into(),
+            border_radius: Some(Shape::Small.radius()),
+            border_width: 0.0,
+            border_color: scheme.secondary_container,
+            text_color: scheme.on_secondary_container,
+            icon_color: scheme.on_secondary_container,
+        }
+    } else {
+        super::chip::Appearance {
+            background: if hovered {
+                scheme.surface_container_high
+            } else {
+                scheme.surface
+            }
+            .into(),
+            border_radius: Some(Shape::Small.radius()),
+            border_width: 1.0,
+            border_color: scheme.outline,
+            text_color: scheme.on_surface_variant,
+            icon_color: scheme.primary,
+        }
+    }
+}
+
+/// The Material Design 3 appearance of a
+/// [`Drawer`](crate::widgets::Drawer).
+#[cfg(feature = "drawer")]
+#[must_use]
+pub fn drawer(theme: &Theme, _status: Status) -> super::drawer::Appearance {
+    let scheme = Scheme::of(theme);
+
+    super::drawer::Appearance {
+        background: scheme.surface_container_low.into(),
+        border_color: scheme.outline_variant,
+        border_width: 0.0,
+        shadow_color: Elevation::Level1.shadow(scheme.shadow).color,
+        backdrop: scheme.backdrop(),
+    }
+}
+
+/// The Material Design 3 appearance of the menus of a
+/// [`MenuBar`](crate::widgets::menu::MenuBar).
+#[cfg(feature = "menu")]
+#[must_use]
+pub fn menu_bar(theme: &Theme) -> super::menu_bar::Appearance {
+    let scheme = Scheme::of(theme);
+    let border = |radius: f32| iced::Border {
+        radius: radius.into(),
+        ..iced::Border::default()
+    };
+
+    super::menu_bar::Appearance {
+        bar_background: scheme.surface_container.into(),
+        bar_border: border(Shape::ExtraSmall.radius()),
+        bar_shadow: Shadow::default(),
+        bar_background_expand: [4; 4].into(),
+        menu_background: scheme.surface_container.into(),
+        menu_border: border(Shape::ExtraSmall.radius()),
+        menu_shadow: Elevation::Level2.shadow(scheme.shadow),
+        menu_background_expand: [8, 0].into(),
+        path: scheme.secondary_container.into(),
+        path_border: border(Shape::ExtraSmall.radius()),
+    }
+}
+
+/// The Material Design 3 appearance of a [`Modal`](crate::widgets::Modal).
+#[cfg(feature = "modal")]
+#[must_use]
+pub fn modal(theme: &Theme, _status: Status) -> super::modal::Appearance {
+    super::modal::Appearance {
+        background: Scheme::of(theme).backdrop().into(),
+    }
+}
+
+/// The Material Design 3 appearance of a
+/// [`NavigationRail`](crate::widgets::NavigationRail).
+#[cfg(feature = "navigation_rail")]
+#[must_use]
+pub fn navigation_rail(theme: &Theme, _status: Status) -> super::navigation_rail::Appearance {
+    let scheme = Scheme::of(theme);
+
+    super::navigation_rail::Appearance {
+        background: scheme.surface.into(),
+        border_color: scheme.outline_variant,
+        border_width: 0.0,
+        hovered_background: scheme.surface_container_high.into(),
+        indicator_background: scheme.secondary_container.into(),
+        icon_color: scheme.on_surface_variant,
+        text_color: scheme.on_surface_variant,
+        selected_icon_color: scheme.on_secondary_container,
+        selected_text_color: scheme.on_surface,
+    }
+}
+
+/// The Material Design 3 appearance of a rich tooltip shown by a
+/// [`Popover`](crate::widgets::Popover).
+#[cfg(feature = "popover")]
+#[must_use]
+pub fn popover(theme: &Theme, _status: Status) -> super::popover::Appearance {
+    let scheme = Scheme::of(theme);
+
+    super::popover::Appearance {
+        background: scheme.surface_container,
+        text_color: scheme.on_surface_variant,
+        border_radius: Shape::Medium.radius(),
+        border_width: 0.0,
+        border_color: scheme.outline_variant,
+        shadow: Elevation::Level2.shadow(scheme.shadow),
+    }
+}
+
+/// The Material Design 3 appearance of a
+/// [`SegmentedButton`](crate::widgets::SegmentedButton).
+#[cfg(feature = "segmented_button")]
+#[must_use]
+pub fn segmented_button(theme: &Theme, status: Status) -> super::segmented_button::Appearance {
+    let scheme = Scheme::of(theme);
+    let (background, text_color) = match status {
+        Status::Selected => (scheme.secondary_container, scheme.on_secondary_container),
+        Status::Hovered => (scheme.surface_container_high, scheme.on_surface),
+        _ => (scheme.surface, scheme.on_surface),
+    };
+
+    super::segmented_button::Appearance {
+        background: background.into(),
+        selected_color: scheme.secondary_container,
+        border_radius: Some(Shape::Large.radius()),
+        border_width: 1.0,
+        border_color: Some(scheme.outline),
+        text_color,
+    }
+}
+
+/// The Material Design 3 appearance of the secondary tabs of a
+/// [`TabBar`](crate::widgets::TabBar).
+#[cfg(feature = "tab_bar")]
+#[must_use]
+pub fn tab_bar(theme: &Theme, status: Status, is_active: bool) -> super::tab_bar::Appearance {
+    let scheme = Scheme::of(theme);
+    let background = if status == Status::Hovered {
+        scheme.surface_container_high
+    } else {
+        scheme.surface
+    };
+    let content = if is_active {
+        scheme.primary
+    } else {
+        scheme.on_surface_variant
+    };
+
+    super::tab_bar::Appearance {
+        background: Some(scheme.surface.into()),
+        border_color: Some(scheme.outline_variant),
+        border_width: 1.0,
+        tab_label_background: background.into(),
+        tab_label_border_color: if status == Status::Focused {
+            scheme.secondary
+        } else {
+            background
+        },
+        tab_label_border_width: if status == Status::Focused { 2.0 } else { 0.0 },
+        icon_color: content,
+        icon_background: None,
+        icon_border_radius: Shape::ExtraSmall.radius().into(),
+        text_color: content,
+        indicator_color: Some(scheme.primary),
+    }
+}
+
+/// The Material Design 3 appearance of a snackbar shown by a
+/// [`Toast`](crate::widgets::Toast).
+#[cfg(feature = "toast")]
+#[must_use]
+pub fn toast(theme: &Theme, _status: Status) -> super::toast::Appearance {
+    let scheme = Scheme::of(theme);
+
+    super::toast::Appearance {
+        background: scheme.inverse_surface.into(),
+        border_radius: Shape::ExtraSmall.radius(),
+        border_width: 0.0,
+        border_color: scheme.inverse_surface,
+        title_color: scheme.inverse_on_surface,
+        body_color: scheme.inverse_on_surface,
+        info_color: scheme.inverse_primary,
+        success_color: scheme.tertiary_container,
+        warning_color: scheme.secondary_container,
+        error_color: scheme.error_container,
+    }
+}
+
+/// The Material Design 3 appearance of a [`Tour`](crate::widgets::Tour),
+/// whose steps are shown as dialogs.
+#[cfg(feature = "tour")]
+#[must_use]
+pub fn tour(theme: &Theme, _status: Status) -> super::tour::Appearance {
+    let scheme = Scheme::of(theme);
+
+    super::tour::Appearance {
+        backdrop_color: scheme.backdrop(),
+        highlight_border_color: scheme.primary,
+        highlight_border_width: 2.0,
+        highlight_border_radius: Shape::Medium.radius(),
+        background: scheme.surface_container_high.into(),
+        border_radius: Shape::ExtraLarge.radius(),
+        title_color: scheme.on_surface,
+        text_color: scheme.on_surface_variant,
+        secondary_text_color: scheme.on_surface_variant,
+        button_background: scheme.primary.into(),
+        button_text_color: scheme.on_primary,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dark, light, Elevation, Scheme, Shape};
+    use crate::core::theme::{contrast_ratio, TEXT_CONTRAST_RATIO};
+    use iced::{Shadow, Theme};
+
+    #[test]
+    fn baseline_themes_keep_their_schemes() {
+        assert_eq!(Scheme::of(&light()), Scheme::LIGHT);
+        assert_eq!(Scheme::of(&dark()), Scheme::DARK);
+
+        let theme = dark();
+        let palette = theme.extended_palette();
+        assert_eq!(palette.primary.weak.color, Scheme::DARK.primary_container);
+        assert_eq!(palette.danger.base.color, Scheme::DARK.error);
+        assert!(palette.is_dark);
+
+        let scheme = Scheme::of(&Theme::Light);
+        assert_eq!(scheme.primary, Theme::Light.palette().primary);
+        assert!(!scheme.is_dark);
+    }
+
+    #[test]
+    fn baseline_content_is_readable() {
+        for scheme in [Scheme::LIGHT, Scheme::DARK] {
+            for (color, on) in [
+                (scheme.primary, scheme.on_primary),
+                (scheme.primary_container, scheme.on_primary_container),
+                (scheme.secondary_container, scheme.on_secondary_container),
+                (scheme.error, scheme.on_error),
+                (scheme.surface, scheme.on_surface),
+                (scheme.inverse_surface, scheme.inverse_on_surface),
+            ] {
+                assert!(contrast_ratio(color, on) >= TEXT_CONTRAST_RATIO / 2.0);
+            }
+        }
+    }
+
+    #[test]
+    fn tokens_follow_the_specification() {
+        assert!((Shape::Medium.radius() - 12.0).abs() < f32::EPSILON);
+        assert!((Shape::ExtraLarge.radius() - 28.0).abs() < f32::EPSILON);
+
+        let shadow = Elevation::Level2.shadow(Scheme::LIGHT.shadow);
+        assert!((shadow.offset.y - 2.0).abs() < f32::EPSILON);
+        assert!((shadow.blur_radius - 6.0).abs() < f32::EPSILON);
+        assert_eq!(
+            Elevation::Level0.shadow(Scheme::LIGHT.shadow),
+            Shadow::default()
+        );
+    }
+}
@@ -1,30 +1,55 @@
 //! Change the appearance of menu bars and their menus.
+use crate::core::settings;
 use iced::{Background, Border, Color, Padding, Shadow, Theme, Vector};
 
 /// The appearance of a menu bar and its menus.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Appearance {
     /// The background of the menu bar.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub bar_background: Background,
     /// The border of the menu bar.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::border"))]
     pub bar_border: Border,
     /// The shadow of the menu bar.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::shadow"))]
     pub bar_shadow: Shadow,
     /// Expand the menu bar background
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::padding"))]
     pub bar_background_expand: Padding,
 
     /// The background of the menus.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub menu_background: Background,
     /// The border of the menus.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::border"))]
     pub menu_border: Border,
     /// The shadow of the menus
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::shadow"))]
     pub menu_shadow: Shadow,
     /// Expand the menu background
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::padding"))]
     pub menu_background_expand: Padding,
 
     /// The backgraound of the path
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub path: Background,
     /// The border of the path
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::border"))]
     pub path_border: Border,
 }
 impl std::default::Default for Appearance {
@@ -32,7 +57,7 @@ impl std::default::Default for Appearance {
         Self {
             bar_background: Color::from([0.85; 3]).into(),
             bar_border: Border {
-                radius: [8.0; 4].into(),
+                radius: settings::corner_radius(8.0).into(),
                 ..Default::default()
             },
             bar_shadow: Shadow::default(),
@@ -40,7 +65,7 @@ impl std::default::Default for Appearance {
 
             menu_background: Color::from([0.85; 3]).into(),
             menu_border: Border {
-                radius: [8.0; 4].into(),
+                radius: settings::corner_radius(8.0).into(),
                 ..Default::default()
             },
             menu_shadow: Shadow {
@@ -51,7 +76,7 @@ impl std::default::Default for Appearance {
             menu_background_expand: [5; 4].into(),
             path: Color::from([0.3; 3]).into(),
             path_border: Border {
-                radius: [6.0; 4].into(),
+                radius: settings::corner_radius(6.0).into(),
                 ..Default::default()
             },
         }
@@ -65,6 +90,12 @@ pub trait StyleSheet {
 
     /// Produces the [`Appearance`] of a menu bar and its menus.
     fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of a menu bar focused with the keyboard,
+    /// whose path border is drawn as a focus ring around the active root.
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.appearance(style)
+    }
 }
 
 /// The style of a menu bar and its menus
@@ -108,4 +139,20 @@ impl StyleSheet for Theme {
             MenuBarStyle::Custom(c) => c.appearance(self),
         }
     }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        let appearance = self.appearance(style);
+
+        match style {
+            MenuBarStyle::Default => Appearance {
+                path_border: Border {
+                    width: 2.0,
+                    color: self.extended_palette().primary.strong.color,
+                    ..appearance.path_border
+                },
+                ..appearance
+            },
+            MenuBarStyle::Custom(c) => c.focused(self),
+        }
+    }
 }
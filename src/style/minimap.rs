@@ -0,0 +1,154 @@
+//! Show a scaled-down overview of scrollable content.
+//!
+//! *This API requires the following crate features to be activated: `minimap`*
+use super::Status;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`Minimap`](crate::native::minimap::Minimap).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the minimap.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the minimap.
+    pub border_radius: f32,
+
+    /// The border width of the minimap.
+    pub border_width: f32,
+
+    /// The border color of the minimap.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The background of the rectangle showing the visible part of the content.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub viewport_background: Background,
+
+    /// The border width of the rectangle showing the visible part of the content.
+    pub viewport_border_width: f32,
+
+    /// The border color of the rectangle showing the visible part of the content.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub viewport_border_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color([0.95, 0.95, 0.95].into()),
+            border_radius: 0.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            viewport_background: Background::Color(Color::from_rgba(0.2, 0.5, 0.8, 0.15)),
+            viewport_border_width: 1.0,
+            viewport_border_color: Color::from_rgba(0.2, 0.5, 0.8, 0.6),
+        }
+    }
+}
+
+/// The appearance of a [`Minimap`](crate::native::minimap::Minimap).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`Minimap`](crate::native::minimap::Minimap).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a [`Minimap`](crate::native::minimap::Minimap)
+    /// whose viewport rectangle is hovered or dragged.
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`Minimap`](crate::native::minimap::Minimap).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum MinimapStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl MinimapStyles {
+    /// Creates a custom [`MinimapStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for MinimapStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = MinimapStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let MinimapStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            viewport_background: Color {
+                a: 0.15,
+                ..palette.primary.base.color
+            }
+            .into(),
+            viewport_border_color: Color {
+                a: 0.6,
+                ..palette.primary.base.color
+            },
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let MinimapStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            viewport_background: Color {
+                a: 0.25,
+                ..palette.primary.base.color
+            }
+            .into(),
+            viewport_border_color: palette.primary.base.color,
+            ..self.active(style)
+        }
+    }
+}
@@ -3,14 +3,24 @@
 //! *This API requires the following crate features to be activated: badge*
 use std::rc::Rc;
 
+use super::Status;
 use iced::{Background, Color, Theme};
 
 /// The appearance of a [`Modal`](crate::native::Modal).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Appearance {
     /// The background of the [`Modal`](crate::native::Modal).
     ///
     /// This is used to color the backdrop of the modal.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub background: Background,
 }
 
@@ -45,6 +55,20 @@ impl ModalStyles {
     }
 }
 
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for ModalStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
 impl StyleSheet for Theme {
     type Style = ModalStyles;
 
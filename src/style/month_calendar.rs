@@ -0,0 +1,172 @@
+//! Show the events of a month on a calendar grid.
+//!
+//! *This API requires the following crate features to be activated: `month_calendar`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`MonthCalendar`](crate::native::month_calendar::MonthCalendar).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the calendar.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the calendar.
+    pub border_radius: f32,
+
+    /// The border width of the calendar.
+    pub border_width: f32,
+
+    /// The border color of the calendar.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The color of the lines between the days.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub grid_color: Color,
+
+    /// The color of the month title and of the navigation arrows.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub header_text_color: Color,
+
+    /// The color of the labels of the weekdays.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub weekday_text_color: Color,
+
+    /// The color of the numbers of the days of the month.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub day_text_color: Color,
+
+    /// The color of the numbers of the days of the surrounding months.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub outside_day_text_color: Color,
+
+    /// The background behind the number of the current day.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub today_background: Background,
+
+    /// The color of the number of the current day.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub today_text_color: Color,
+
+    /// The background of the hovered day.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub hovered_day_background: Background,
+
+    /// The color of the bars of the events without a color of their own.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub event_color: Color,
+
+    /// The color of the titles of the events.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub event_text_color: Color,
+
+    /// The color of the count of the events not fitting in a day.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub more_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: settings::corner_radius(4.0),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            grid_color: [0.9, 0.9, 0.9].into(),
+            header_text_color: Color::BLACK,
+            weekday_text_color: [0.4, 0.4, 0.4].into(),
+            day_text_color: Color::BLACK,
+            outside_day_text_color: [0.7, 0.7, 0.7].into(),
+            today_background: Color::from_rgb(0.2, 0.5, 0.8).into(),
+            today_text_color: Color::WHITE,
+            hovered_day_background: Color::from_rgba(0.2, 0.5, 0.8, 0.08).into(),
+            event_color: [0.2, 0.5, 0.8].into(),
+            event_text_color: Color::WHITE,
+            more_text_color: [0.4, 0.4, 0.4].into(),
+        }
+    }
+}
+
+/// The appearance of a [`MonthCalendar`](crate::native::month_calendar::MonthCalendar).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`MonthCalendar`](crate::native::month_calendar::MonthCalendar).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`MonthCalendar`](crate::native::month_calendar::MonthCalendar).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum MonthCalendarStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl MonthCalendarStyles {
+    /// Creates a custom [`MonthCalendarStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for MonthCalendarStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = MonthCalendarStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let MonthCalendarStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            grid_color: palette.background.weak.color,
+            header_text_color: palette.background.base.text,
+            weekday_text_color: palette.background.strong.text,
+            day_text_color: palette.background.base.text,
+            outside_day_text_color: palette.background.strong.color,
+            today_background: palette.primary.base.color.into(),
+            today_text_color: palette.primary.base.text,
+            hovered_day_background: palette.background.weak.color.into(),
+            event_color: palette.primary.strong.color,
+            event_text_color: palette.primary.strong.text,
+            more_text_color: palette.background.strong.text,
+            ..Appearance::default()
+        }
+    }
+}
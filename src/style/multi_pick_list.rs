@@ -0,0 +1,199 @@
+//! Choose several options from a drop down checklist.
+//!
+//! *This API requires the following crate features to be activated: `multi_pick_list`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`MultiPickList`](crate::native::multi_pick_list::MultiPickList).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the field.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The radius of the field, of the chips and of the list.
+    pub border_radius: f32,
+
+    /// The border width of the field and of the list.
+    pub border_width: f32,
+
+    /// The border color of the field and of the list.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The color of the summary of the chosen options.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub text_color: Color,
+
+    /// The color of the placeholder.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub placeholder_color: Color,
+
+    /// The color of the arrow of the field.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub handle_color: Color,
+
+    /// The background of the chips of the chosen options.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub chip_background: Background,
+
+    /// The color of the text of the chips.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub chip_text_color: Color,
+
+    /// The background of the list of options.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub menu_background: Background,
+
+    /// The color of the options of the list.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub option_text_color: Color,
+
+    /// The background of the option under the cursor.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub highlighted_background: Background,
+
+    /// The color of the option under the cursor.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub highlighted_text_color: Color,
+
+    /// The color of the check boxes of the options.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub check_color: Color,
+
+    /// The color of the check marks of the chosen options.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub check_mark_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: settings::corner_radius(2.0),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            placeholder_color: [0.6, 0.6, 0.6].into(),
+            handle_color: [0.3, 0.3, 0.3].into(),
+            chip_background: Color::from_rgb(0.88, 0.92, 0.98).into(),
+            chip_text_color: Color::BLACK,
+            menu_background: Color::WHITE.into(),
+            option_text_color: Color::BLACK,
+            highlighted_background: Color::from_rgb(0.3, 0.5, 0.9).into(),
+            highlighted_text_color: Color::WHITE,
+            check_color: [0.2, 0.5, 0.8].into(),
+            check_mark_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`MultiPickList`](crate::native::multi_pick_list::MultiPickList).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`MultiPickList`](crate::native::multi_pick_list::MultiPickList).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a hovered or opened
+    /// [`MultiPickList`](crate::native::multi_pick_list::MultiPickList).
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`MultiPickList`](crate::native::multi_pick_list::MultiPickList).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum MultiPickListStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl MultiPickListStyles {
+    /// Creates a custom [`MultiPickListStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for MultiPickListStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = MultiPickListStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let MultiPickListStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.weak.text,
+            placeholder_color: palette.background.strong.color,
+            handle_color: palette.background.weak.text,
+            chip_background: palette.primary.weak.color.into(),
+            chip_text_color: palette.primary.weak.text,
+            menu_background: palette.background.weak.color.into(),
+            option_text_color: palette.background.weak.text,
+            highlighted_background: palette.primary.base.color.into(),
+            highlighted_text_color: palette.primary.base.text,
+            check_color: palette.primary.strong.color,
+            check_mark_color: palette.primary.strong.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let MultiPickListStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.primary.strong.color,
+            ..self.active(style)
+        }
+    }
+}
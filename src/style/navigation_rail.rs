@@ -0,0 +1,137 @@
+//! Navigate between the destinations of an application.
+//!
+//! *This API requires the following crate features to be activated: `navigation_rail`*
+use super::Status;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`NavigationRail`](crate::native::navigation_rail::NavigationRail).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the rail.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The color of the border on the trailing edge of the rail.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The width of the border on the trailing edge of the rail.
+    pub border_width: f32,
+
+    /// The background of the hovered destination.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub hovered_background: Background,
+
+    /// The background of the indicator behind the selected destination.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub indicator_background: Background,
+
+    /// The color of the icons of the destinations.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub icon_color: Color,
+
+    /// The color of the labels of the destinations.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub text_color: Color,
+
+    /// The color of the icon of the selected destination.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub selected_icon_color: Color,
+
+    /// The color of the label of the selected destination.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub selected_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color([0.97, 0.97, 0.97].into()),
+            border_color: [0.85, 0.85, 0.85].into(),
+            border_width: 1.0,
+            hovered_background: Background::Color([0.92, 0.92, 0.92].into()),
+            indicator_background: Background::Color([0.82, 0.88, 0.97].into()),
+            icon_color: [0.3, 0.3, 0.3].into(),
+            text_color: [0.3, 0.3, 0.3].into(),
+            selected_icon_color: Color::BLACK,
+            selected_text_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`NavigationRail`](crate::native::navigation_rail::NavigationRail).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`NavigationRail`](crate::native::navigation_rail::NavigationRail).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`NavigationRail`](crate::native::navigation_rail::NavigationRail).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum NavigationRailStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl NavigationRailStyles {
+    /// Creates a custom [`NavigationRailStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for NavigationRailStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = NavigationRailStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let NavigationRailStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.weak.color.into(),
+            border_color: palette.background.strong.color,
+            hovered_background: palette.background.strong.color.into(),
+            indicator_background: palette.primary.weak.color.into(),
+            icon_color: palette.background.weak.text,
+            text_color: palette.background.weak.text,
+            selected_icon_color: palette.primary.weak.text,
+            selected_text_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+}
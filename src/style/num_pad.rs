@@ -0,0 +1,178 @@
+//! Enter numbers with an on-screen number pad.
+//!
+//! *This API requires the following crate features to be activated: `num_pad`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`NumPad`](crate::native::num_pad::NumPad).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background behind the keys.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_background")
+    )]
+    pub background: Option<Background>,
+
+    /// The background of the keys.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub key_background: Background,
+
+    /// The color of the labels of the keys.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub key_text_color: Color,
+
+    /// The radius of the keys.
+    pub key_border_radius: f32,
+
+    /// The border width of the keys.
+    pub key_border_width: f32,
+
+    /// The border color of the keys.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub key_border_color: Color,
+
+    /// The background of the confirm key.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub confirm_background: Background,
+
+    /// The color of the label of the confirm key.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub confirm_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: None,
+            key_background: Background::Color([0.93, 0.93, 0.93].into()),
+            key_text_color: Color::BLACK,
+            key_border_radius: settings::corner_radius(8.0),
+            key_border_width: 1.0,
+            key_border_color: [0.8, 0.8, 0.8].into(),
+            confirm_background: Background::Color([0.2, 0.5, 0.8].into()),
+            confirm_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`NumPad`](crate::native::num_pad::NumPad).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The appearance of the keys of a [`NumPad`](crate::native::num_pad::NumPad).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a hovered key of a [`NumPad`](crate::native::num_pad::NumPad).
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a pressed key of a [`NumPad`](crate::native::num_pad::NumPad).
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+}
+
+/// The default appearance of a [`NumPad`](crate::native::num_pad::NumPad).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum NumPadStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl NumPadStyles {
+    /// Creates a custom [`NumPadStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for NumPadStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Pressed)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = NumPadStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let NumPadStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            key_background: palette.background.weak.color.into(),
+            key_text_color: palette.background.weak.text,
+            key_border_color: palette.background.strong.color,
+            confirm_background: palette.primary.strong.color.into(),
+            confirm_text_color: palette.primary.strong.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let NumPadStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            key_border_color: palette.primary.base.color,
+            confirm_background: palette.primary.base.color.into(),
+            ..self.active(style)
+        }
+    }
+
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        if let NumPadStyles::Custom(custom) = style {
+            return custom.pressed(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            key_background: palette.background.strong.color.into(),
+            key_text_color: palette.background.strong.text,
+            confirm_background: palette.primary.weak.color.into(),
+            confirm_text_color: palette.primary.weak.text,
+            ..self.hovered(style)
+        }
+    }
+}
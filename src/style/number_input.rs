@@ -2,15 +2,31 @@
 //!
 //! *This API requires the following crate features to be activated: `number_input`*
 
+use super::Status;
 use iced::{Background, Color, Theme};
 
 /// The appearance of a [`NumberInput`](crate::native::number_input::NumberInput).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Appearance {
     /// The background of the [`NumberInput`](crate::native::number_input::NumberInput).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_background")
+    )]
     pub button_background: Option<Background>,
     /// The Color of the arrows of [`NumberInput`](crate::native::number_input::NumberInput).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub icon_color: Color,
+    /// The border width of the buttons of [`NumberInput`](crate::native::number_input::NumberInput).
+    pub button_border_width: f32,
+    /// The border color of the buttons of [`NumberInput`](crate::native::number_input::NumberInput).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub button_border_color: Color,
 }
 
 impl Default for Appearance {
@@ -18,6 +34,8 @@ impl Default for Appearance {
         Self {
             button_background: None,
             icon_color: Color::BLACK,
+            button_border_width: 0.0,
+            button_border_color: Color::TRANSPARENT,
         }
     }
 }
@@ -34,6 +52,12 @@ pub trait StyleSheet {
 
     /// The appearance when the [`NumberInput`](crate::native::number_input::NumberInput) is disabled.
     fn disabled(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance when the [`NumberInput`](crate::native::number_input::NumberInput) is
+    /// focused, whose button border is drawn as a focus ring.
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 #[derive(Default)]
@@ -52,6 +76,32 @@ impl NumberInputStyles {
     }
 }
 
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for NumberInputStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Pressed)
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Disabled)
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Focused)
+    }
+}
+
 impl StyleSheet for Theme {
     type Style = NumberInputStyles;
 
@@ -65,6 +115,7 @@ impl StyleSheet for Theme {
         Appearance {
             button_background: Some(palette.primary.strong.color.into()),
             icon_color: palette.primary.strong.text,
+            ..Appearance::default()
         }
     }
 
@@ -95,6 +146,20 @@ impl StyleSheet for Theme {
                 a: active.icon_color.a * 0.5,
                 ..active.icon_color
             },
+            ..active
+        }
+    }
+
+    /// The appearance when the [`NumberInput`](crate::native::number_input::NumberInput) is focused.
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        if let NumberInputStyles::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        Appearance {
+            button_border_width: 2.0,
+            button_border_color: self.extended_palette().background.base.text,
+            ..self.active(style)
         }
     }
 }
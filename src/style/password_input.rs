@@ -0,0 +1,150 @@
+//! Type a password and see how strong it is.
+//!
+//! *This API requires the following crate features to be activated: `password_input`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`PasswordInput`](crate::native::password_input::PasswordInput).
+///
+/// The field itself is drawn with the style of the text inputs of the theme.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The color of the button revealing the password.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub icon_color: Color,
+
+    /// The color of the button revealing the password while it is hovered.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub hovered_icon_color: Color,
+
+    /// The background of the strength meter.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub meter_background: Background,
+
+    /// The border radius of the strength meter.
+    pub meter_border_radius: f32,
+
+    /// The color of the strength meter for a weak password.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub weak_color: Color,
+
+    /// The color of the strength meter for a fair password.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub fair_color: Color,
+
+    /// The color of the strength meter for a good password.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub good_color: Color,
+
+    /// The color of the strength meter for a strong password.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub strong_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            icon_color: [0.6, 0.6, 0.6].into(),
+            hovered_icon_color: Color::BLACK,
+            meter_background: Color::from_rgb(0.9, 0.9, 0.9).into(),
+            meter_border_radius: settings::corner_radius(2.0),
+            weak_color: Color::from_rgb(0.85, 0.2, 0.2),
+            fair_color: Color::from_rgb(0.95, 0.6, 0.1),
+            good_color: Color::from_rgb(0.6, 0.8, 0.2),
+            strong_color: Color::from_rgb(0.2, 0.7, 0.3),
+        }
+    }
+}
+
+/// The appearance of a [`PasswordInput`](crate::native::password_input::PasswordInput).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`PasswordInput`](crate::native::password_input::PasswordInput).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a focused [`PasswordInput`](crate::native::password_input::PasswordInput).
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`PasswordInput`](crate::native::password_input::PasswordInput).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum PasswordInputStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl PasswordInputStyles {
+    /// Creates a custom [`PasswordInputStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for PasswordInputStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Focused)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = PasswordInputStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let PasswordInputStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            icon_color: palette.background.strong.color,
+            hovered_icon_color: palette.background.base.text,
+            meter_background: palette.background.weak.color.into(),
+            weak_color: palette.danger.base.color,
+            good_color: palette.success.weak.color,
+            strong_color: palette.success.base.color,
+            ..Appearance::default()
+        }
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        if let PasswordInputStyles::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            icon_color: palette.primary.strong.color,
+            ..self.active(style)
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Show rich content next to a target widget.
+//!
+//! *This API requires the following crate features to be activated: `popover`*
+use super::Status;
+use crate::core::settings;
+use iced::{Color, Shadow, Theme, Vector};
+use std::rc::Rc;
+
+/// The appearance of a [`Popover`](crate::native::popover::Popover).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background color of the popover and of its arrow.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub background: Color,
+
+    /// The default color of the text of the content.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub text_color: Color,
+
+    /// The border radius of the popover.
+    pub border_radius: f32,
+
+    /// The border width of the popover.
+    pub border_width: f32,
+
+    /// The border color of the popover.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The shadow of the popover.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::shadow"))]
+    pub shadow: Shadow,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE,
+            text_color: Color::BLACK,
+            border_radius: settings::corner_radius(6.0),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            shadow: Shadow {
+                color: Color::from_rgba(0.0, 0.0, 0.0, 0.2),
+                offset: Vector::new(0.0, 2.0),
+                blur_radius: 8.0,
+            },
+        }
+    }
+}
+
+/// The appearance of a [`Popover`](crate::native::popover::Popover).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Popover`](crate::native::popover::Popover).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Popover`](crate::native::popover::Popover).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum PopoverStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl PopoverStyles {
+    /// Creates a custom [`PopoverStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for PopoverStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = PopoverStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let PopoverStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color,
+            text_color: palette.background.base.text,
+            border_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
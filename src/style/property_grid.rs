@@ -0,0 +1,163 @@
+//! Edit labeled properties, grouped and searched.
+//!
+//! *This API requires the following crate features to be activated: `property_grid`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`PropertyGrid`](crate::native::property_grid::PropertyGrid).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the grid.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The background of the headers of the groups.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub header_background: Background,
+
+    /// The color of the text of the headers of the groups.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub header_text_color: Color,
+
+    /// The color of the labels of the properties.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub label_color: Color,
+
+    /// The color of the lines between the properties.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub separator_color: Color,
+
+    /// The background of the drop down lists of the choices.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub field_background: Background,
+
+    /// The color of the border of the drop down lists and of the color swatches.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub field_border_color: Color,
+
+    /// The radius of the drop down lists and of the color swatches.
+    pub field_border_radius: f32,
+
+    /// The color of the chosen options.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub text_color: Color,
+
+    /// The background of the opened list of options.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub menu_background: Background,
+
+    /// The background of the option under the cursor.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub highlighted_background: Background,
+
+    /// The color of the option under the cursor.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub highlighted_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            header_background: Color::from_rgb(0.92, 0.92, 0.92).into(),
+            header_text_color: Color::BLACK,
+            label_color: [0.3, 0.3, 0.3].into(),
+            separator_color: [0.9, 0.9, 0.9].into(),
+            field_background: Color::WHITE.into(),
+            field_border_color: [0.7, 0.7, 0.7].into(),
+            field_border_radius: settings::corner_radius(2.0),
+            text_color: Color::BLACK,
+            menu_background: Color::WHITE.into(),
+            highlighted_background: Color::from_rgb(0.3, 0.5, 0.9).into(),
+            highlighted_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`PropertyGrid`](crate::native::property_grid::PropertyGrid).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`PropertyGrid`](crate::native::property_grid::PropertyGrid).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`PropertyGrid`](crate::native::property_grid::PropertyGrid).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum PropertyGridStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl PropertyGridStyles {
+    /// Creates a custom [`PropertyGridStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for PropertyGridStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = PropertyGridStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let PropertyGridStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            header_background: palette.background.weak.color.into(),
+            header_text_color: palette.background.weak.text,
+            label_color: palette.background.base.text,
+            separator_color: palette.background.weak.color,
+            field_background: palette.background.base.color.into(),
+            field_border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            menu_background: palette.background.base.color.into(),
+            highlighted_background: palette.primary.base.color.into(),
+            highlighted_text_color: palette.primary.base.text,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Select an interval of values with two handles.
+//!
+//! *This API requires the following crate features to be activated: `range_slider`*
+use super::Status;
+use iced::{Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`RangeSlider`](crate::native::range_slider::RangeSlider).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The color of the rail.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub rail_color: Color,
+
+    /// The color of the rail between the handles.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub range_color: Color,
+
+    /// The thickness of the rail.
+    pub rail_width: f32,
+
+    /// The radius of the handles.
+    pub handle_radius: f32,
+
+    /// The color of the handles.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub handle_color: Color,
+
+    /// The border width of the handles.
+    pub handle_border_width: f32,
+
+    /// The border color of the handles.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub handle_border_color: Color,
+
+    /// The color of the ring around the handle focused for the keyboard.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub focus_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            rail_color: [0.85, 0.85, 0.85].into(),
+            range_color: [0.2, 0.5, 0.8].into(),
+            rail_width: 4.0,
+            handle_radius: 8.0,
+            handle_color: Color::WHITE,
+            handle_border_width: 1.0,
+            handle_border_color: [0.6, 0.6, 0.6].into(),
+            focus_color: Color::from_rgba(0.2, 0.5, 0.8, 0.4),
+        }
+    }
+}
+
+/// The appearance of a [`RangeSlider`](crate::native::range_slider::RangeSlider).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`RangeSlider`](crate::native::range_slider::RangeSlider).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a hovered [`RangeSlider`](crate::native::range_slider::RangeSlider).
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a [`RangeSlider`](crate::native::range_slider::RangeSlider)
+    /// whose handle is dragged.
+    fn dragging(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+}
+
+/// The default appearance of a [`RangeSlider`](crate::native::range_slider::RangeSlider).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum RangeSliderStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl RangeSliderStyles {
+    /// Creates a custom [`RangeSliderStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for RangeSliderStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+
+    fn dragging(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Dragging)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = RangeSliderStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let RangeSliderStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            rail_color: palette.background.strong.color,
+            range_color: palette.primary.base.color,
+            handle_color: palette.background.base.color,
+            handle_border_color: palette.background.strong.color,
+            focus_color: Color {
+                a: 0.4,
+                ..palette.primary.base.color
+            },
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let RangeSliderStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            handle_border_color: palette.primary.base.color,
+            ..self.active(style)
+        }
+    }
+
+    fn dragging(&self, style: &Self::Style) -> Appearance {
+        if let RangeSliderStyles::Custom(custom) = style {
+            return custom.dragging(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            handle_color: palette.primary.weak.color,
+            ..self.hovered(style)
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Type a search query and pick one of the suggested results.
+//!
+//! *This API requires the following crate features to be activated: `search_bar`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`SearchBar`](crate::native::search_bar::SearchBar).
+///
+/// The field itself is drawn with the style of the text inputs of the theme.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The color of the search icon and of the clear button.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub icon_color: Color,
+
+    /// The color of the clear button while it is hovered.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub hovered_icon_color: Color,
+
+    /// The border radius of the list of suggestions.
+    pub border_radius: f32,
+
+    /// The border width of the list of suggestions.
+    pub border_width: f32,
+
+    /// The background of the list of suggestions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub suggestions_background: Background,
+
+    /// The border color of the list of suggestions.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub suggestions_border_color: Color,
+
+    /// The color of the text of the suggestions.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub suggestion_text_color: Color,
+
+    /// The background of the highlighted suggestion.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub highlighted_suggestion_background: Background,
+
+    /// The color of the text of the highlighted suggestion.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub highlighted_suggestion_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            icon_color: [0.6, 0.6, 0.6].into(),
+            hovered_icon_color: Color::BLACK,
+            border_radius: settings::corner_radius(2.0),
+            border_width: 1.0,
+            suggestions_background: Color::WHITE.into(),
+            suggestions_border_color: [0.7, 0.7, 0.7].into(),
+            suggestion_text_color: Color::BLACK,
+            highlighted_suggestion_background: Color::from_rgb(0.2, 0.5, 0.8).into(),
+            highlighted_suggestion_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`SearchBar`](crate::native::search_bar::SearchBar).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`SearchBar`](crate::native::search_bar::SearchBar).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a focused [`SearchBar`](crate::native::search_bar::SearchBar).
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`SearchBar`](crate::native::search_bar::SearchBar).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum SearchBarStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl SearchBarStyles {
+    /// Creates a custom [`SearchBarStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for SearchBarStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Focused)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = SearchBarStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let SearchBarStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            icon_color: palette.background.strong.color,
+            hovered_icon_color: palette.background.base.text,
+            suggestions_background: palette.background.base.color.into(),
+            suggestions_border_color: palette.background.strong.color,
+            suggestion_text_color: palette.background.base.text,
+            highlighted_suggestion_background: palette.primary.base.color.into(),
+            highlighted_suggestion_text_color: palette.primary.base.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        if let SearchBarStyles::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            icon_color: palette.primary.strong.color,
+            ..self.active(style)
+        }
+    }
+}
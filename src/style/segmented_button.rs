@@ -1,13 +1,26 @@
 //! Use a `segmented_button` as an alternative to radio button.
 
+use super::Status;
+use crate::core::settings;
+use crate::core::theme::is_high_contrast;
 use iced::{Background, Color, Theme};
 
 /// The appearance of a [`SegmentedButton`]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Appearance {
     /// The background of the [`SegmentedButton`]
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub background: Background,
     /// selection hightlight color
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub selected_color: Color,
 
     /// The border radius of the [`SegmentedButton`]
@@ -18,9 +31,14 @@ pub struct Appearance {
     pub border_width: f32,
 
     /// The border color of the [`SegmentedButton`]
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_color")
+    )]
     pub border_color: Option<Color>,
 
     /// The default text color of the [`SegmentedButton`]
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub text_color: Color,
 }
 
@@ -41,6 +59,17 @@ pub trait StyleSheet {
             ..active
         }
     }
+
+    /// The appearance of a selected segment of [`SegmentedButtons`](crate::native::segmented_button::SegmentedButtons).
+    fn selected(&self, style: &Self::Style) -> Appearance {
+        let active = self.active(style);
+
+        Appearance {
+            background: Background::Color(active.selected_color),
+            text_color: Color::WHITE,
+            ..active
+        }
+    }
 }
 
 impl std::default::Default for Appearance {
@@ -52,7 +81,7 @@ impl std::default::Default for Appearance {
                 0x7C as f32 / 255.0,
                 0xE2 as f32 / 255.0,
             ),
-            border_radius: None,
+            border_radius: settings::optional_corner_radius(),
             border_width: 1.0,
             border_color: Some([0.8, 0.8, 0.8].into()),
             text_color: Color::BLACK,
@@ -76,12 +105,49 @@ impl SegmentedButton {
     }
 }
 
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for SegmentedButton {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+
+    fn selected(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Selected)
+    }
+}
+
 impl StyleSheet for Theme {
     type Style = SegmentedButton;
 
     fn active(&self, style: &Self::Style) -> Appearance {
         match style {
-            SegmentedButton::Default => Appearance::default(),
+            SegmentedButton::Default => {
+                let palette = self.extended_palette();
+                let segment = if is_high_contrast(self) {
+                    palette.background.base
+                } else {
+                    palette.background.weak
+                };
+
+                Appearance {
+                    background: segment.color.into(),
+                    selected_color: palette.primary.base.color,
+                    border_color: Some(palette.background.strong.color),
+                    text_color: segment.text,
+                    ..Appearance::default()
+                }
+            }
             SegmentedButton::Custom(custom) => custom.active(self),
         }
     }
@@ -93,4 +159,18 @@ impl StyleSheet for Theme {
 
         self.active(style)
     }
+
+    fn selected(&self, style: &Self::Style) -> Appearance {
+        if let SegmentedButton::Custom(custom) = style {
+            return custom.selected(self);
+        }
+
+        let active = self.active(style);
+
+        Appearance {
+            background: Background::Color(active.selected_color),
+            text_color: self.extended_palette().primary.base.text,
+            ..active
+        }
+    }
 }
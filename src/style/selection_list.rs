@@ -4,27 +4,67 @@
 
 use std::rc::Rc;
 
+use super::{
+    status::{faded, faded_background},
+    Status,
+};
 use iced::{Background, Color, Theme};
 
 /// The appearance of a menu.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Appearance {
     /// The List Label Text Color
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub text_color: Color,
     /// The background
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub background: Background,
     /// The container Border width
     pub border_width: f32,
     /// The container Border color
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub border_color: Color,
     /// The List Label Text Select Color
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub hovered_text_color: Color,
     /// The List Label Text Select Background Color
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub hovered_background: Background,
     /// The List Label Text Select Color
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub selected_text_color: Color,
     /// The List Label Text Select Background Color
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub selected_background: Background,
+    /// The Disabled List Label Text Color
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub disabled_text_color: Color,
+    /// The Group Header Text Color
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub header_text_color: Color,
+    /// The Group Header Background Color
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub header_background: Background,
+    /// The color of the line showing where a dragged option is dropped
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub drop_indicator_color: Color,
 }
 
 impl std::default::Default for Appearance {
@@ -38,6 +78,10 @@ impl std::default::Default for Appearance {
             hovered_background: Background::Color([0.0, 0.5, 1.0].into()),
             selected_text_color: Color::WHITE,
             selected_background: Background::Color([0.2, 0.5, 0.8].into()),
+            disabled_text_color: [0.6, 0.6, 0.6].into(),
+            header_text_color: [0.3, 0.3, 0.3].into(),
+            header_background: Background::Color([0.8, 0.8, 0.8].into()),
+            drop_indicator_color: [0.2, 0.5, 0.8].into(),
         }
     }
 }
@@ -48,6 +92,17 @@ pub trait StyleSheet {
     type Style: Default + Clone;
     /// Produces the style of a container.
     fn style(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a list focused for the keyboard, whose border
+    /// is drawn as a focus ring around the current option.
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.style(style)
+    }
+
+    /// Produces the style of a disabled list.
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.style(style)
+    }
 }
 
 #[derive(Clone, Default)]
@@ -66,6 +121,28 @@ impl SelectionListStyles {
     }
 }
 
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for SelectionListStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn style(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Focused)
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Disabled)
+    }
+}
+
 impl StyleSheet for Theme {
     type Style = SelectionListStyles;
     fn style(&self, style: &Self::Style) -> Appearance {
@@ -84,7 +161,45 @@ impl StyleSheet for Theme {
             hovered_background: palette.primary.weak.color.into(),
             selected_text_color: palette.primary.strong.text,
             selected_background: palette.primary.strong.color.into(),
+            disabled_text_color: Color {
+                a: 0.5,
+                ..foreground.text
+            },
+            header_text_color: palette.background.strong.text,
+            header_background: palette.background.strong.color.into(),
+            drop_indicator_color: palette.primary.strong.color,
             ..Appearance::default()
         }
     }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        if let SelectionListStyles::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        Appearance {
+            border_width: 2.0,
+            border_color: self.extended_palette().primary.strong.color,
+            ..self.style(style)
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        if let SelectionListStyles::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let active = self.style(style);
+
+        Appearance {
+            text_color: active.disabled_text_color,
+            background: faded_background(active.background),
+            border_color: faded(active.border_color),
+            selected_text_color: faded(active.selected_text_color),
+            selected_background: faded_background(active.selected_background),
+            header_text_color: faded(active.header_text_color),
+            header_background: faded_background(active.header_background),
+            ..active
+        }
+    }
 }
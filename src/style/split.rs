@@ -2,26 +2,53 @@
 //!
 //! *This API requires the following crate features to be activated: split*
 
+use super::{
+    status::{faded, faded_background},
+    Status,
+};
 use iced::{Background, Color, Theme};
 
 /// The appearance of a [`Split`](crate::native::split::Split).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Appearance {
     /// The optional background of the [`Split`](crate::native::split::Split).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_background")
+    )]
     pub background: Option<Background>,
     /// The optional background of the first element of the [`Split`](crate::native::split::Split).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_background")
+    )]
     pub first_background: Option<Background>,
     /// The optional background of the second element of the [`Split`](crate::native::split::Split).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_background")
+    )]
     pub second_background: Option<Background>,
     /// The border width of the [`Split`](crate::native::split::Split).
     pub border_width: f32,
     /// The border color of the [`Split`](crate::native::split::Split).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub border_color: Color,
     /// The background of the divider of the [`Split`](crate::native::split::Split).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub divider_background: Background,
     /// The border width of the divider of the [`Split`](crate::native::split::Split).
     pub divider_border_width: f32,
     /// The border color of the divider of the [`Split`](crate::native::split::Split).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub divider_border_color: Color,
 }
 
@@ -37,6 +64,17 @@ pub trait StyleSheet {
 
     /// The appearance when the divider of the [`Split`](crate::native::split::Split) is dragged
     fn dragged(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance when the divider of the [`Split`](crate::native::split::Split) is
+    /// focused, whose border is drawn as a focus ring.
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when the [`Split`](crate::native::split::Split) is disabled.
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
 }
 
 /// The default appearance of the [`Split`](crate::native::split::Split).
@@ -56,6 +94,36 @@ impl SplitStyles {
     }
 }
 
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for SplitStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+
+    fn dragged(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Dragging)
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Focused)
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Disabled)
+    }
+}
+
 impl std::default::Default for Appearance {
     fn default() -> Self {
         Self {
@@ -115,4 +183,37 @@ impl StyleSheet for Theme {
             ..active
         }
     }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        if let SplitStyles::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            divider_border_width: 2.0,
+            divider_border_color: palette.primary.strong.color,
+            ..active
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        if let SplitStyles::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let active = self.active(style);
+
+        Appearance {
+            background: active.background.map(faded_background),
+            first_background: active.first_background.map(faded_background),
+            second_background: active.second_background.map(faded_background),
+            border_color: faded(active.border_color),
+            divider_background: faded_background(active.divider_background),
+            divider_border_color: faded(active.divider_border_color),
+            ..active
+        }
+    }
 }
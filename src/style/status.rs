@@ -0,0 +1,57 @@
+//! The status of a widget passed to the closures styling it.
+
+use iced::{Background, Color, Gradient};
+
+/// The status of a widget, telling a closure given to the `style` method of
+/// a widget which of its appearances is asked for.
+///
+/// Each widget only asks for some of them: the hovered appearance of a
+/// [`Badge`](crate::widgets::Badge), the dragging appearance of a
+/// [`Knob`](crate::widgets::Knob), and so on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The widget is idle.
+    Active,
+    /// The cursor is over the widget.
+    Hovered,
+    /// The widget is being pressed.
+    Pressed,
+    /// The widget, or a part of it, is selected.
+    Selected,
+    /// The widget has the keyboard focus.
+    Focused,
+    /// A part of the widget is being dragged.
+    Dragging,
+    /// The widget does not react to the user.
+    Disabled,
+    /// A dragged item can be dropped on the widget.
+    Valid,
+    /// The widget shows the preview of a drop.
+    Preview,
+}
+
+/// The opacity of the colors of a disabled widget, relative to its active
+/// colors.
+const DISABLED_OPACITY: f32 = 0.5;
+
+/// Returns the `color` faded for the disabled appearance of a widget.
+pub(crate) fn faded(color: Color) -> Color {
+    Color {
+        a: color.a * DISABLED_OPACITY,
+        ..color
+    }
+}
+
+/// Returns the `background` faded for the disabled appearance of a widget.
+pub(crate) fn faded_background(background: Background) -> Background {
+    match background {
+        Background::Color(color) => Background::Color(faded(color)),
+        Background::Gradient(Gradient::Linear(mut linear)) => {
+            for stop in linear.stops.iter_mut().flatten() {
+                stop.color = faded(stop.color);
+            }
+
+            Background::Gradient(Gradient::Linear(linear))
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Show the status of something, like a user or a service, with a colored dot.
+//!
+//! *This API requires the following crate features to be activated: `status_dot`*
+use crate::core::theme::is_high_contrast;
+use iced::{Color, Theme};
+use std::rc::Rc;
+
+/// The status shown by a [`StatusDot`](crate::native::status_dot::StatusDot).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// Available.
+    Online,
+    /// Idle or temporarily away.
+    Away,
+    /// Available but not to be disturbed.
+    Busy,
+    /// Failing.
+    Error,
+    /// Not available.
+    #[default]
+    Offline,
+}
+
+/// The appearance of a [`StatusDot`](crate::native::status_dot::StatusDot).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The color of the dot.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub dot_color: Color,
+
+    /// The border width of the dot.
+    pub border_width: f32,
+
+    /// The border color of the dot.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The color of the ring pulsing around the dot.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub pulse_color: Color,
+
+    /// The color of the label.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub label_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            dot_color: [0.6, 0.6, 0.6].into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            pulse_color: Color::from_rgba(0.6, 0.6, 0.6, 0.5),
+            label_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`StatusDot`](crate::native::status_dot::StatusDot).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The appearance of a [`StatusDot`](crate::native::status_dot::StatusDot)
+    /// showing the `status`.
+    fn active(&self, style: &Self::Style, status: Status) -> Appearance;
+}
+
+/// The default appearance of a [`StatusDot`](crate::native::status_dot::StatusDot).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum StatusDotStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl StatusDotStyles {
+    /// Creates a custom [`StatusDotStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for StatusDotStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style, status: Status) -> Appearance {
+        self(style, status)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = StatusDotStyles;
+
+    fn active(&self, style: &Self::Style, status: Status) -> Appearance {
+        if let StatusDotStyles::Custom(custom) = style {
+            return custom.active(self, status);
+        }
+
+        let palette = self.extended_palette();
+        let dot_color = match status {
+            Status::Online => palette.success.base.color,
+            Status::Away if is_high_contrast(self) && !palette.is_dark => {
+                Color::from_rgb(0.54, 0.29, 0.0)
+            }
+            Status::Away => Color::from_rgb(0.95, 0.65, 0.1),
+            Status::Busy => palette.danger.base.color,
+            Status::Error => palette.danger.strong.color,
+            Status::Offline => palette.background.strong.color,
+        };
+
+        Appearance {
+            dot_color,
+            pulse_color: Color {
+                a: 0.5,
+                ..dot_color
+            },
+            label_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+}
@@ -6,42 +6,81 @@
 //!
 //! *This API requires the following crate features to be activated: `tab_bar`*
 
+use crate::core::settings;
 use std::rc::Rc;
 
+use super::Status;
+use crate::core::theme::is_high_contrast;
 use iced::{border::Radius, Background, Color, Theme};
 
+/// The width of the focus ring of the default styles.
+const FOCUS_RING_WIDTH: f32 = 2.0;
+
 /// The appearance of a [`TabBar`](crate::native::tab_bar::TabBar).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Appearance {
     /// The background of the tab bar.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_background")
+    )]
     pub background: Option<Background>,
 
     /// The border color of the tab bar.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_color")
+    )]
     pub border_color: Option<Color>,
 
     /// The border width of the tab bar.
     pub border_width: f32,
 
     /// The background of the tab labels.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub tab_label_background: Background,
 
     /// The border color of the tab labels.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub tab_label_border_color: Color,
 
     /// The border with of the tab labels.
     pub tab_label_border_width: f32,
 
     /// The icon color of the tab labels.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub icon_color: Color,
 
     /// The color of the closing icon border
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_background")
+    )]
     pub icon_background: Option<Background>,
 
     /// How soft/hard the corners of the icon border are
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::radius"))]
     pub icon_border_radius: Radius,
 
     /// The text color of the tab labels.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub text_color: Color,
+
+    /// The color of the indicator drawn under the active tab, which slides
+    /// to the next active tab, if any.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_color")
+    )]
+    pub indicator_color: Option<Color>,
 }
 
 /// The appearance of a [`TabBar`](crate::native::tab_bar::TabBar).
@@ -58,6 +97,14 @@ pub trait StyleSheet {
     ///
     /// `is_active` is true if the tab is selected.
     fn hovered(&self, style: &Self::Style, is_active: bool) -> Appearance;
+
+    /// The appearance of a tab label focused for the keyboard, whose border
+    /// is drawn as a focus ring.
+    ///
+    /// `is_active` is true if the tab is selected.
+    fn focused(&self, style: &Self::Style, is_active: bool) -> Appearance {
+        self.hovered(style, is_active)
+    }
 }
 
 impl Default for Appearance {
@@ -71,8 +118,9 @@ impl Default for Appearance {
             tab_label_border_width: 1.0,
             icon_color: Color::BLACK,
             icon_background: Some(Background::Color(Color::TRANSPARENT)),
-            icon_border_radius: 4.0.into(),
+            icon_border_radius: settings::corner_radius(4.0).into(),
             text_color: Color::BLACK,
+            indicator_color: None,
         }
     }
 }
@@ -98,6 +146,28 @@ impl TabBarStyles {
     }
 }
 
+impl<F: Fn(&Theme, Status, bool) -> Appearance + 'static> From<F> for TabBarStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status, bool) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style, is_active: bool) -> Appearance {
+        self(style, Status::Active, is_active)
+    }
+
+    fn hovered(&self, style: &Self::Style, is_active: bool) -> Appearance {
+        self(style, Status::Hovered, is_active)
+    }
+
+    fn focused(&self, style: &Self::Style, is_active: bool) -> Appearance {
+        self(style, Status::Focused, is_active)
+    }
+}
+
 impl StyleSheet for Theme {
     type Style = TabBarStyles;
 
@@ -107,12 +177,20 @@ impl StyleSheet for Theme {
 
         match style {
             TabBarStyles::Default => {
-                appearance.tab_label_background = if is_active {
-                    Background::Color(palette.primary.base.color)
+                let pair = if is_active {
+                    palette.primary.base
+                } else {
+                    palette.background.strong
+                };
+
+                appearance.tab_label_background = Background::Color(pair.color);
+                appearance.tab_label_border_color = if is_high_contrast(self) {
+                    palette.background.base.text
                 } else {
-                    Background::Color(palette.background.strong.color)
+                    palette.background.strong.color
                 };
-                appearance.text_color = palette.background.base.text;
+                appearance.icon_color = pair.text;
+                appearance.text_color = pair.text;
             }
             TabBarStyles::Dark => {
                 appearance.tab_label_background = if is_active {
@@ -192,6 +270,8 @@ impl StyleSheet for Theme {
         match style {
             TabBarStyles::Default => Appearance {
                 tab_label_background: Background::Color(palette.primary.strong.color),
+                icon_color: palette.primary.strong.text,
+                text_color: palette.primary.strong.text,
                 ..self.active(style, is_active)
             },
             TabBarStyles::Dark => Appearance {
@@ -226,4 +306,16 @@ impl StyleSheet for Theme {
             TabBarStyles::Custom(custom) => custom.hovered(self, is_active),
         }
     }
+
+    fn focused(&self, style: &Self::Style, is_active: bool) -> Appearance {
+        if let TabBarStyles::Custom(custom) = style {
+            return custom.focused(self, is_active);
+        }
+
+        Appearance {
+            tab_label_border_color: self.extended_palette().background.base.text,
+            tab_label_border_width: FOCUS_RING_WIDTH,
+            ..self.hovered(style, is_active)
+        }
+    }
 }
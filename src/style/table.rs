@@ -0,0 +1,144 @@
+//! Display records in a table with sortable columns.
+//!
+//! *This API requires the following crate features to be activated: table*
+
+use std::rc::Rc;
+
+use super::Status;
+use iced::{Background, Color, Theme};
+
+/// The appearance of a [`Table`](crate::native::table::Table).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the rows.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+    /// The text color of the rows.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub text_color: Color,
+    /// The background of the header row.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub header_background: Background,
+    /// The text color of the header row.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub header_text_color: Color,
+    /// The background of the hovered row.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub hovered_background: Background,
+    /// The background of the selected row.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub selected_background: Background,
+    /// The text color of the selected row.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub selected_text_color: Color,
+    /// The color of the lines between the rows and the columns.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub divider_color: Color,
+    /// The width of the border of the table.
+    pub border_width: f32,
+    /// The color of the border of the table.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+    /// The color of the scrollbar.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub scrollbar_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color(Color::WHITE),
+            text_color: Color::BLACK,
+            header_background: Background::Color([0.87, 0.87, 0.87].into()),
+            header_text_color: Color::BLACK,
+            hovered_background: Background::Color([0.93, 0.93, 0.93].into()),
+            selected_background: Background::Color([0.2, 0.5, 0.8].into()),
+            selected_text_color: Color::WHITE,
+            divider_color: [0.85, 0.85, 0.85].into(),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            scrollbar_color: [0.6, 0.6, 0.6].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a [`Table`](crate::native::table::Table).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+    /// Produces the style of a [`Table`](crate::native::table::Table).
+    fn style(&self, style: &Self::Style) -> Appearance;
+}
+
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+/// Default Prebuilt ``Table`` Styles
+pub enum TableStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl TableStyles {
+    /// Creates a custom [`TableStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for TableStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn style(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = TableStyles;
+
+    fn style(&self, style: &Self::Style) -> Appearance {
+        if let TableStyles::Custom(custom) = style {
+            return custom.style(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            text_color: palette.background.base.text,
+            header_background: palette.background.strong.color.into(),
+            header_text_color: palette.background.strong.text,
+            hovered_background: palette.background.weak.color.into(),
+            selected_background: palette.primary.strong.color.into(),
+            selected_text_color: palette.primary.strong.text,
+            divider_color: palette.background.weak.color,
+            border_color: palette.background.strong.color,
+            scrollbar_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Type tags and show them as removable chips.
+//!
+//! *This API requires the following crate features to be activated: `tag_input`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`TagInput`](crate::native::tag_input::TagInput).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the field.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the field.
+    pub border_radius: f32,
+
+    /// The border width of the field.
+    pub border_width: f32,
+
+    /// The border color of the field.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The color of the typed text and of the caret.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub text_color: Color,
+
+    /// The color of the placeholder.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub placeholder_color: Color,
+
+    /// The background of the chips.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub chip_background: Background,
+
+    /// The color of the text of the chips.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub chip_text_color: Color,
+
+    /// The border radius of the chips.
+    pub chip_border_radius: f32,
+
+    /// The background of the list of suggestions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub suggestions_background: Background,
+
+    /// The border color of the list of suggestions.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub suggestions_border_color: Color,
+
+    /// The color of the text of the suggestions.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub suggestion_text_color: Color,
+
+    /// The background of the highlighted suggestion.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub highlighted_suggestion_background: Background,
+
+    /// The color of the text of the highlighted suggestion.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub highlighted_suggestion_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_radius: settings::corner_radius(2.0),
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            placeholder_color: [0.6, 0.6, 0.6].into(),
+            chip_background: Color::from_rgb(0.87, 0.91, 0.96).into(),
+            chip_text_color: Color::BLACK,
+            chip_border_radius: settings::corner_radius(4.0),
+            suggestions_background: Color::WHITE.into(),
+            suggestions_border_color: [0.7, 0.7, 0.7].into(),
+            suggestion_text_color: Color::BLACK,
+            highlighted_suggestion_background: Color::from_rgb(0.2, 0.5, 0.8).into(),
+            highlighted_suggestion_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`TagInput`](crate::native::tag_input::TagInput).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`TagInput`](crate::native::tag_input::TagInput).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a focused [`TagInput`](crate::native::tag_input::TagInput).
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`TagInput`](crate::native::tag_input::TagInput).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TagInputStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl TagInputStyles {
+    /// Creates a custom [`TagInputStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for TagInputStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Focused)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = TagInputStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let TagInputStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            placeholder_color: palette.background.strong.color,
+            chip_background: palette.primary.weak.color.into(),
+            chip_text_color: palette.primary.weak.text,
+            suggestions_background: palette.background.base.color.into(),
+            suggestions_border_color: palette.background.strong.color,
+            suggestion_text_color: palette.background.base.text,
+            highlighted_suggestion_background: palette.primary.base.color.into(),
+            highlighted_suggestion_text_color: palette.primary.base.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        if let TagInputStyles::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.primary.strong.color,
+            ..self.active(style)
+        }
+    }
+}
@@ -0,0 +1,1024 @@
+//! Load and save the appearances of the widgets from and to theme files.
+//!
+//! A [`Theme`] holds the [`Appearance`](crate::style::badge::Appearance)s of
+//! every widget in each of their states, and can be written to and read from
+//! TOML or JSON files. The appearances of a widget are used as its style
+//! sheet, so that the theme can be edited by the users and swapped at
+//! runtime by loading another file and building the view again.
+//!
+//! The colors are written as `#rrggbb` or `#rrggbbaa`, a background as a
+//! color or as a linear gradient with an `angle` in radians and its `stops`,
+//! and the paddings and the corner radii as arrays of 4 numbers, starting
+//! from the top and from the top left. Everything missing from a file keeps
+//! its default value, and a missing state of a widget takes the appearance of
+//! its active state.
+//!
+//! *This API requires the following crate features to be activated: serde*
+//!
+//! # Example
+//! ```ignore
+//! # use iced_aw::{style::{theme_file::Theme, BadgeStyles}, Badge};
+//! #
+//! // Start from the appearances of a built-in theme.
+//! let theme = Theme::from(&iced::Theme::Dark);
+//! theme.save("theme.toml")?;
+//!
+//! // And later on, after the users edited it.
+//! let theme = Theme::load("theme.toml")?;
+//! let badge = Badge::new(text("3")).style(BadgeStyles::custom(theme.badge.clone()));
+//! ```
+
+use crate::style;
+
+use iced::{
+    gradient::{ColorStop, Linear},
+    Background, Border, Color, Gradient, Padding, Radians, Shadow, Vector,
+};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+};
+
+/// The appearances of a widget in each of its states.
+///
+/// Only the states of the widget are used, and those missing take the
+/// `active` appearance.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default, bound(deserialize = "A: Deserialize<'de> + Default"))]
+pub struct Appearances<A> {
+    /// The appearance of the widget at rest.
+    pub active: A,
+    /// The appearance of the hovered widget, or of its hovered part.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hovered: Option<Box<A>>,
+    /// The appearance of the pressed widget, or of its pressed part.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pressed: Option<Box<A>>,
+    /// The appearance of the selected part of the widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected: Option<Box<A>>,
+    /// The appearance of the widget focused with the keyboard.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focused: Option<Box<A>>,
+    /// The appearance of the widget, or of its handle, while dragged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dragging: Option<Box<A>>,
+    /// The appearance of the disabled widget, or of its disabled parts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<Box<A>>,
+    /// The appearance of a drop target accepting the dragged item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid: Option<Box<A>>,
+    /// The appearance of the preview of the dragged item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview: Option<Box<A>>,
+}
+
+/// The appearances of a widget whose parts are selected or not, like the
+/// tabs of a tab bar.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default, bound(deserialize = "A: Deserialize<'de> + Default"))]
+pub struct Selectable<A> {
+    /// The appearances of the parts which are not selected.
+    pub unselected: Appearances<A>,
+    /// The appearances of the selected parts.
+    pub selected: Appearances<A>,
+}
+
+impl<A> Selectable<A> {
+    /// Returns the appearances of the parts selected or not.
+    fn get(&self, is_selected: bool) -> &Appearances<A> {
+        if is_selected {
+            &self.selected
+        } else {
+            &self.unselected
+        }
+    }
+}
+
+/// The appearances of a status dot for each of its statuses.
+#[cfg(feature = "status_dot")]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatusAppearances {
+    /// The appearance of the online status.
+    pub online: style::status_dot::Appearance,
+    /// The appearance of the away status.
+    pub away: style::status_dot::Appearance,
+    /// The appearance of the busy status.
+    pub busy: style::status_dot::Appearance,
+    /// The appearance of the error status.
+    pub error: style::status_dot::Appearance,
+    /// The appearance of the offline status.
+    pub offline: style::status_dot::Appearance,
+}
+
+/// The appearances of all the widgets, read from or written to a theme file.
+///
+/// Its fields are used as the custom style sheets of the widgets. The
+/// spinner and the Cupertino widgets have no appearance to change.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// The appearances of the accordions.
+    #[cfg(feature = "accordion")]
+    pub accordion: Appearances<style::accordion::Appearance>,
+    /// The appearances of the badges.
+    #[cfg(feature = "badge")]
+    pub badge: Appearances<style::badge::Appearance>,
+    /// The appearances of the cards.
+    #[cfg(feature = "card")]
+    pub card: Appearances<style::card::Appearance>,
+    /// The appearances of the carousels.
+    #[cfg(feature = "carousel")]
+    pub carousel: Appearances<style::carousel::Appearance>,
+    /// The appearances of the chips, selected or not.
+    #[cfg(feature = "chip")]
+    pub chip: Selectable<style::chip::Appearance>,
+    /// The appearances of the color pickers.
+    #[cfg(feature = "color_picker")]
+    pub color_picker: Appearances<style::color_picker::Appearance>,
+    /// The appearances of the context menus.
+    #[cfg(feature = "context_menu")]
+    pub context_menu: Appearances<style::context_menu::Appearance>,
+    /// The appearances of the date pickers.
+    #[cfg(feature = "date_picker")]
+    pub date_picker: Appearances<style::date_picker::Appearance>,
+    /// The appearances of the docks.
+    #[cfg(feature = "dock")]
+    pub dock: Appearances<style::dock::Appearance>,
+    /// The appearances of the drag and drop areas.
+    #[cfg(feature = "drag_and_drop")]
+    pub drag_and_drop: Appearances<style::drag_and_drop::Appearance>,
+    /// The appearances of the drawers.
+    #[cfg(feature = "drawer")]
+    pub drawer: Appearances<style::drawer::Appearance>,
+    /// The appearances of the file browsers.
+    #[cfg(feature = "file_browser")]
+    pub file_browser: Appearances<style::file_browser::Appearance>,
+    /// The appearances of the heatmap calendars.
+    #[cfg(feature = "heatmap_calendar")]
+    pub heatmap_calendar: Appearances<style::heatmap_calendar::Appearance>,
+    /// The appearances of the hotkey inputs.
+    #[cfg(feature = "hotkey_input")]
+    pub hotkey_input: Appearances<style::hotkey_input::Appearance>,
+    /// The appearances of the image viewers.
+    #[cfg(feature = "image_viewer")]
+    pub image_viewer: Appearances<style::image_viewer::Appearance>,
+    /// The appearances of the JSON trees.
+    #[cfg(feature = "json_tree")]
+    pub json_tree: Appearances<style::json_tree::Appearance>,
+    /// The appearances of the knobs.
+    #[cfg(feature = "knob")]
+    pub knob: Appearances<style::knob::Appearance>,
+    /// The appearances of the log views.
+    #[cfg(feature = "log_view")]
+    pub log_view: Appearances<style::log_view::Appearance>,
+    /// The appearances of the menu bars and their menus.
+    #[cfg(feature = "menu")]
+    pub menu_bar: Appearances<style::menu_bar::Appearance>,
+    /// The appearances of the minimaps.
+    #[cfg(feature = "minimap")]
+    pub minimap: Appearances<style::minimap::Appearance>,
+    /// The appearances of the modals.
+    #[cfg(feature = "modal")]
+    pub modal: Appearances<style::modal::Appearance>,
+    /// The appearances of the month calendars.
+    #[cfg(feature = "month_calendar")]
+    pub month_calendar: Appearances<style::month_calendar::Appearance>,
+    /// The appearances of the multi pick lists.
+    #[cfg(feature = "multi_pick_list")]
+    pub multi_pick_list: Appearances<style::multi_pick_list::Appearance>,
+    /// The appearances of the navigation rails.
+    #[cfg(feature = "navigation_rail")]
+    pub navigation_rail: Appearances<style::navigation_rail::Appearance>,
+    /// The appearances of the numeric keypads.
+    #[cfg(feature = "num_pad")]
+    pub num_pad: Appearances<style::num_pad::Appearance>,
+    /// The appearances of the number inputs.
+    #[cfg(feature = "number_input")]
+    pub number_input: Appearances<style::number_input::Appearance>,
+    /// The appearances of the password inputs.
+    #[cfg(feature = "password_input")]
+    pub password_input: Appearances<style::password_input::Appearance>,
+    /// The appearances of the PIN inputs.
+    #[cfg(feature = "pin_input")]
+    pub pin_input: Appearances<style::pin_input::Appearance>,
+    /// The appearances of the popovers.
+    #[cfg(feature = "popover")]
+    pub popover: Appearances<style::popover::Appearance>,
+    /// The appearances of the property grids.
+    #[cfg(feature = "property_grid")]
+    pub property_grid: Appearances<style::property_grid::Appearance>,
+    /// The appearances of the range sliders.
+    #[cfg(feature = "range_slider")]
+    pub range_slider: Appearances<style::range_slider::Appearance>,
+    /// The appearances of the search bars.
+    #[cfg(feature = "search_bar")]
+    pub search_bar: Appearances<style::search_bar::Appearance>,
+    /// The appearances of the segmented buttons.
+    #[cfg(feature = "segmented_button")]
+    pub segmented_button: Appearances<style::segmented_button::Appearance>,
+    /// The appearances of the selection lists.
+    #[cfg(feature = "selection_list")]
+    pub selection_list: Appearances<style::selection_list::Appearance>,
+    /// The appearances of the splits.
+    #[cfg(feature = "split")]
+    pub split: Appearances<style::split::Appearance>,
+    /// The appearances of the status dots, by status.
+    #[cfg(feature = "status_dot")]
+    pub status_dot: StatusAppearances,
+    /// The appearances of the tab bars, for the active tab or not.
+    #[cfg(feature = "tab_bar")]
+    pub tab_bar: Selectable<style::tab_bar::Appearance>,
+    /// The appearances of the tables.
+    #[cfg(feature = "table")]
+    pub table: Appearances<style::table::Appearance>,
+    /// The appearances of the tag inputs.
+    #[cfg(feature = "tag_input")]
+    pub tag_input: Appearances<style::tag_input::Appearance>,
+    /// The appearances of the tick sliders.
+    #[cfg(feature = "tick_slider")]
+    pub tick_slider: Appearances<style::tick_slider::Appearance>,
+    /// The appearances of the time pickers.
+    #[cfg(feature = "time_picker")]
+    pub time_picker: Appearances<style::time_picker::Appearance>,
+    /// The appearances of the toasts.
+    #[cfg(feature = "toast")]
+    pub toast: Appearances<style::toast::Appearance>,
+    /// The appearances of the tours.
+    #[cfg(feature = "tour")]
+    pub tour: Appearances<style::tour::Appearance>,
+    /// The appearances of the virtual lists.
+    #[cfg(feature = "virtual_list")]
+    pub virtual_list: Appearances<style::virtual_list::Appearance>,
+    /// The appearances of the wheel pickers.
+    #[cfg(feature = "wheel_picker")]
+    pub wheel_picker: Appearances<style::wheel_picker::Appearance>,
+}
+
+impl Theme {
+    /// Reads a [`Theme`] from the content of a TOML file.
+    ///
+    /// # Errors
+    /// Returns an [`Error`] if the content is not a valid theme.
+    pub fn from_toml(content: &str) -> Result<Self, Error> {
+        toml::from_str(content).map_err(|error| Error::Parse(error.to_string()))
+    }
+
+    /// Writes the [`Theme`] as the content of a TOML file.
+    ///
+    /// # Errors
+    /// Returns an [`Error`] if a value cannot be written in TOML.
+    pub fn to_toml(&self) -> Result<String, Error> {
+        toml::to_string_pretty(self).map_err(|error| Error::Write(error.to_string()))
+    }
+
+    /// Reads a [`Theme`] from the content of a JSON file.
+    ///
+    /// # Errors
+    /// Returns an [`Error`] if the content is not a valid theme.
+    pub fn from_json(content: &str) -> Result<Self, Error> {
+        serde_json::from_str(content).map_err(|error| Error::Parse(error.to_string()))
+    }
+
+    /// Writes the [`Theme`] as the content of a JSON file.
+    ///
+    /// # Errors
+    /// Returns an [`Error`] if a value cannot be written in JSON.
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self).map_err(|error| Error::Write(error.to_string()))
+    }
+
+    /// Loads a [`Theme`] from a TOML or JSON file, depending on the
+    /// extension of its `path`.
+    ///
+    /// # Errors
+    /// Returns an [`Error`] if the file cannot be read, has another extension
+    /// or is not a valid theme.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let format = Format::of(path)?;
+        let content = fs::read_to_string(path).map_err(Error::Io)?;
+
+        match format {
+            Format::Toml => Self::from_toml(&content),
+            Format::Json => Self::from_json(&content),
+        }
+    }
+
+    /// Saves the [`Theme`] to a TOML or JSON file, depending on the extension
+    /// of its `path`.
+    ///
+    /// # Errors
+    /// Returns an [`Error`] if the file has another extension or cannot be
+    /// written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let content = match Format::of(path)? {
+            Format::Toml => self.to_toml()?,
+            Format::Json => self.to_json()?,
+        };
+
+        fs::write(path, content).map_err(Error::Io)
+    }
+}
+
+/// Captures the appearances of the default styles of all the widgets on a
+/// theme of iced, to be saved as a starting point.
+impl From<&iced::Theme> for Theme {
+    fn from(theme: &iced::Theme) -> Self {
+        Self {
+            #[cfg(feature = "accordion")]
+            accordion: theme.into(),
+            #[cfg(feature = "badge")]
+            badge: theme.into(),
+            #[cfg(feature = "card")]
+            card: theme.into(),
+            #[cfg(feature = "carousel")]
+            carousel: theme.into(),
+            #[cfg(feature = "chip")]
+            chip: theme.into(),
+            #[cfg(feature = "color_picker")]
+            color_picker: theme.into(),
+            #[cfg(feature = "context_menu")]
+            context_menu: theme.into(),
+            #[cfg(feature = "date_picker")]
+            date_picker: theme.into(),
+            #[cfg(feature = "dock")]
+            dock: theme.into(),
+            #[cfg(feature = "drag_and_drop")]
+            drag_and_drop: theme.into(),
+            #[cfg(feature = "drawer")]
+            drawer: theme.into(),
+            #[cfg(feature = "file_browser")]
+            file_browser: theme.into(),
+            #[cfg(feature = "heatmap_calendar")]
+            heatmap_calendar: theme.into(),
+            #[cfg(feature = "hotkey_input")]
+            hotkey_input: theme.into(),
+            #[cfg(feature = "image_viewer")]
+            image_viewer: theme.into(),
+            #[cfg(feature = "json_tree")]
+            json_tree: theme.into(),
+            #[cfg(feature = "knob")]
+            knob: theme.into(),
+            #[cfg(feature = "log_view")]
+            log_view: theme.into(),
+            #[cfg(feature = "menu")]
+            menu_bar: theme.into(),
+            #[cfg(feature = "minimap")]
+            minimap: theme.into(),
+            #[cfg(feature = "modal")]
+            modal: theme.into(),
+            #[cfg(feature = "month_calendar")]
+            month_calendar: theme.into(),
+            #[cfg(feature = "multi_pick_list")]
+            multi_pick_list: theme.into(),
+            #[cfg(feature = "navigation_rail")]
+            navigation_rail: theme.into(),
+            #[cfg(feature = "num_pad")]
+            num_pad: theme.into(),
+            #[cfg(feature = "number_input")]
+            number_input: theme.into(),
+            #[cfg(feature = "password_input")]
+            password_input: theme.into(),
+            #[cfg(feature = "pin_input")]
+            pin_input: theme.into(),
+            #[cfg(feature = "popover")]
+            popover: theme.into(),
+            #[cfg(feature = "property_grid")]
+            property_grid: theme.into(),
+            #[cfg(feature = "range_slider")]
+            range_slider: theme.into(),
+            #[cfg(feature = "search_bar")]
+            search_bar: theme.into(),
+            #[cfg(feature = "segmented_button")]
+            segmented_button: theme.into(),
+            #[cfg(feature = "selection_list")]
+            selection_list: theme.into(),
+            #[cfg(feature = "split")]
+            split: theme.into(),
+            #[cfg(feature = "status_dot")]
+            status_dot: theme.into(),
+            #[cfg(feature = "tab_bar")]
+            tab_bar: theme.into(),
+            #[cfg(feature = "table")]
+            table: theme.into(),
+            #[cfg(feature = "tag_input")]
+            tag_input: theme.into(),
+            #[cfg(feature = "tick_slider")]
+            tick_slider: theme.into(),
+            #[cfg(feature = "time_picker")]
+            time_picker: theme.into(),
+            #[cfg(feature = "toast")]
+            toast: theme.into(),
+            #[cfg(feature = "tour")]
+            tour: theme.into(),
+            #[cfg(feature = "virtual_list")]
+            virtual_list: theme.into(),
+            #[cfg(feature = "wheel_picker")]
+            wheel_picker: theme.into(),
+        }
+    }
+}
+
+/// Implements the style sheet of the widgets of a style `module` for their
+/// [`Appearances`], and captures them from a theme of iced. The methods of
+/// the style sheet are given with the states of the [`Appearances`] they
+/// return.
+macro_rules! appearances {
+    ($($feature:literal $module:ident { $active:ident $(, $method:ident => $state:ident)* })*) => {$(
+        #[cfg(feature = $feature)]
+        impl style::$module::StyleSheet for Appearances<style::$module::Appearance> {
+            type Style = iced::Theme;
+
+            fn $active(&self, _style: &Self::Style) -> style::$module::Appearance {
+                self.active
+            }
+            $(
+            fn $method(&self, _style: &Self::Style) -> style::$module::Appearance {
+                self.$state.as_deref().copied().unwrap_or(self.active)
+            }
+            )*
+        }
+
+        #[cfg(feature = $feature)]
+        impl From<&iced::Theme> for Appearances<style::$module::Appearance> {
+            fn from(theme: &iced::Theme) -> Self {
+                use style::$module::StyleSheet;
+
+                let style = <iced::Theme as StyleSheet>::Style::default();
+
+                Self {
+                    active: theme.$active(&style),
+                    $($state: Some(Box::new(theme.$method(&style))),)*
+                    ..Self::default()
+                }
+            }
+        }
+    )*};
+}
+
+appearances! {
+    "accordion" accordion { active }
+    "badge" badge { active, hovered => hovered, disabled => disabled }
+    "card" card { active, disabled => disabled }
+    "carousel" carousel { active }
+    "color_picker" color_picker { active, selected => selected, hovered => hovered, focused => focused }
+    "context_menu" context_menu { active }
+    "date_picker" date_picker { active, selected => selected, hovered => hovered, focused => focused }
+    "dock" dock { active, hovered => hovered }
+    "drag_and_drop" drag_and_drop { active, valid => valid, hovered => hovered, preview => preview }
+    "drawer" drawer { active }
+    "file_browser" file_browser { active, focused => focused }
+    "heatmap_calendar" heatmap_calendar { active }
+    "hotkey_input" hotkey_input { active, focused => focused }
+    "image_viewer" image_viewer { active }
+    "json_tree" json_tree { active }
+    "knob" knob { active, hovered => hovered, dragging => dragging }
+    "log_view" log_view { active, hovered => hovered }
+    "menu" menu_bar { appearance, focused => focused }
+    "minimap" minimap { active, hovered => hovered }
+    "modal" modal { active }
+    "month_calendar" month_calendar { active }
+    "multi_pick_list" multi_pick_list { active, hovered => hovered }
+    "navigation_rail" navigation_rail { active }
+    "num_pad" num_pad { active, hovered => hovered, pressed => pressed }
+    "number_input" number_input { active, pressed => pressed, disabled => disabled, focused => focused }
+    "password_input" password_input { active, focused => focused }
+    "pin_input" pin_input { active, focused => focused }
+    "popover" popover { active }
+    "property_grid" property_grid { active }
+    "range_slider" range_slider { active, hovered => hovered, dragging => dragging }
+    "search_bar" search_bar { active, focused => focused }
+    "segmented_button" segmented_button { active, hovered => hovered, selected => selected }
+    "selection_list" selection_list { style, focused => focused, disabled => disabled }
+    "split" split { active, hovered => hovered, dragged => dragging, focused => focused, disabled => disabled }
+    "table" table { style }
+    "tag_input" tag_input { active, focused => focused }
+    "tick_slider" tick_slider { active, hovered => hovered, dragging => dragging }
+    "time_picker" time_picker { active, selected => selected, hovered => hovered, focused => focused }
+    "toast" toast { active }
+    "tour" tour { active }
+    "virtual_list" virtual_list { active, hovered => hovered }
+    "wheel_picker" wheel_picker { active, hovered => hovered }
+}
+
+#[cfg(feature = "chip")]
+impl style::chip::StyleSheet for Selectable<style::chip::Appearance> {
+    type Style = iced::Theme;
+
+    fn active(&self, _style: &Self::Style, is_selected: bool) -> style::chip::Appearance {
+        self.get(is_selected).active
+    }
+
+    fn hovered(&self, _style: &Self::Style, is_selected: bool) -> style::chip::Appearance {
+        let appearances = self.get(is_selected);
+        appearances
+            .hovered
+            .as_deref()
+            .copied()
+            .unwrap_or(appearances.active)
+    }
+}
+
+#[cfg(feature = "chip")]
+impl From<&iced::Theme> for Selectable<style::chip::Appearance> {
+    fn from(theme: &iced::Theme) -> Self {
+        use style::chip::StyleSheet;
+
+        let style = <iced::Theme as StyleSheet>::Style::default();
+        let appearances = |is_selected| Appearances {
+            active: theme.active(&style, is_selected),
+            hovered: Some(Box::new(theme.hovered(&style, is_selected))),
+            ..Appearances::default()
+        };
+
+        Self {
+            unselected: appearances(false),
+            selected: appearances(true),
+        }
+    }
+}
+
+#[cfg(feature = "tab_bar")]
+impl style::tab_bar::StyleSheet for Selectable<style::tab_bar::Appearance> {
+    type Style = iced::Theme;
+
+    fn active(&self, _style: &Self::Style, is_active: bool) -> style::tab_bar::Appearance {
+        self.get(is_active).active
+    }
+
+    fn hovered(&self, _style: &Self::Style, is_active: bool) -> style::tab_bar::Appearance {
+        let appearances = self.get(is_active);
+        appearances
+            .hovered
+            .as_deref()
+            .copied()
+            .unwrap_or(appearances.active)
+    }
+
+    fn focused(&self, _style: &Self::Style, is_active: bool) -> style::tab_bar::Appearance {
+        let appearances = self.get(is_active);
+        appearances
+            .focused
+            .as_deref()
+            .copied()
+            .unwrap_or(appearances.active)
+    }
+}
+
+#[cfg(feature = "tab_bar")]
+impl From<&iced::Theme> for Selectable<style::tab_bar::Appearance> {
+    fn from(theme: &iced::Theme) -> Self {
+        use style::tab_bar::StyleSheet;
+
+        let style = <iced::Theme as StyleSheet>::Style::default();
+        let appearances = |is_active| Appearances {
+            active: theme.active(&style, is_active),
+            hovered: Some(Box::new(theme.hovered(&style, is_active))),
+            focused: Some(Box::new(theme.focused(&style, is_active))),
+            ..Appearances::default()
+        };
+
+        Self {
+            unselected: appearances(false),
+            selected: appearances(true),
+        }
+    }
+}
+
+#[cfg(feature = "status_dot")]
+impl style::status_dot::StyleSheet for StatusAppearances {
+    type Style = iced::Theme;
+
+    fn active(
+        &self,
+        _style: &Self::Style,
+        status: style::status_dot::Status,
+    ) -> style::status_dot::Appearance {
+        use style::status_dot::Status;
+
+        match status {
+            Status::Online => self.online,
+            Status::Away => self.away,
+            Status::Busy => self.busy,
+            Status::Error => self.error,
+            Status::Offline => self.offline,
+        }
+    }
+}
+
+#[cfg(feature = "status_dot")]
+impl From<&iced::Theme> for StatusAppearances {
+    fn from(theme: &iced::Theme) -> Self {
+        use style::status_dot::{Status, StyleSheet};
+
+        let style = <iced::Theme as StyleSheet>::Style::default();
+
+        Self {
+            online: theme.active(&style, Status::Online),
+            away: theme.active(&style, Status::Away),
+            busy: theme.active(&style, Status::Busy),
+            error: theme.active(&style, Status::Error),
+            offline: theme.active(&style, Status::Offline),
+        }
+    }
+}
+
+/// The error returned when loading or saving a [`Theme`].
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be read or written.
+    Io(io::Error),
+    /// The extension of the file is neither `toml` nor `json`.
+    UnknownFormat,
+    /// The content is not a valid theme.
+    Parse(String),
+    /// The theme could not be written in the format.
+    Write(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "theme file error: {error}"),
+            Self::UnknownFormat => f.write_str("unknown theme file format"),
+            Self::Parse(error) => write!(f, "invalid theme: {error}"),
+            Self::Write(error) => write!(f, "theme not written: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The format of a theme file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    /// A TOML file.
+    Toml,
+    /// A JSON file.
+    Json,
+}
+
+impl Format {
+    /// Returns the format of the file at the `path`, from its extension.
+    fn of(path: &Path) -> Result<Self, Error> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("toml") => Ok(Self::Toml),
+            Some(extension) if extension.eq_ignore_ascii_case("json") => Ok(Self::Json),
+            _ => Err(Error::UnknownFormat),
+        }
+    }
+}
+
+/// A [`Color`] written as `#rrggbb`, or `#rrggbbaa` when it is translucent.
+#[derive(Clone, Copy)]
+struct Hex(Color);
+
+impl Serialize for Hex {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let [r, g, b, a] = self.0.into_rgba8();
+
+        if a == u8::MAX {
+            serializer.serialize_str(&format!("#{r:02x}{g:02x}{b:02x}"))
+        } else {
+            serializer.serialize_str(&format!("#{r:02x}{g:02x}{b:02x}{a:02x}"))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Hex {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        let digits = text.strip_prefix('#').unwrap_or(&text);
+        let invalid = || de::Error::invalid_value(de::Unexpected::Str(&text), &"a #rrggbb color");
+
+        if !matches!(digits.len(), 6 | 8) || !digits.is_ascii() {
+            return Err(invalid());
+        }
+
+        let channel = |index: usize| {
+            digits
+                .get(index..index + 2)
+                .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+        };
+        let (Some(r), Some(g), Some(b)) = (channel(0), channel(2), channel(4)) else {
+            return Err(invalid());
+        };
+        let a = if digits.len() == 8 {
+            channel(6).ok_or_else(invalid)?
+        } else {
+            u8::MAX
+        };
+
+        Ok(Self(Color::from_rgba8(
+            r,
+            g,
+            b,
+            f32::from(a) / f32::from(u8::MAX),
+        )))
+    }
+}
+
+/// A [`Background`] written as a color or as a linear gradient.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum BackgroundDef {
+    /// A solid color.
+    Color(Hex),
+    /// A linear gradient.
+    Linear {
+        /// The angle of the gradient, in radians.
+        angle: f32,
+        /// The color stops of the gradient.
+        stops: Vec<StopDef>,
+    },
+}
+
+/// A color stop of a gradient.
+#[derive(Serialize, Deserialize)]
+struct StopDef {
+    /// The offset of the stop, from 0 to 1.
+    offset: f32,
+    /// The color of the stop.
+    color: Hex,
+}
+
+impl From<Background> for BackgroundDef {
+    fn from(background: Background) -> Self {
+        match background {
+            Background::Color(color) => Self::Color(Hex(color)),
+            Background::Gradient(Gradient::Linear(linear)) => Self::Linear {
+                angle: linear.angle.0,
+                stops: linear
+                    .stops
+                    .iter()
+                    .flatten()
+                    .map(|stop| StopDef {
+                        offset: stop.offset,
+                        color: Hex(stop.color),
+                    })
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl From<BackgroundDef> for Background {
+    fn from(background: BackgroundDef) -> Self {
+        match background {
+            BackgroundDef::Color(Hex(color)) => Self::Color(color),
+            BackgroundDef::Linear { angle, stops } => Self::Gradient(Gradient::Linear(
+                Linear::new(Radians(angle)).add_stops(stops.into_iter().map(|stop| ColorStop {
+                    offset: stop.offset,
+                    color: stop.color.0,
+                })),
+            )),
+        }
+    }
+}
+
+/// A [`Border`] written with its color, its width and its corner radii.
+#[derive(Serialize, Deserialize)]
+struct BorderDef {
+    /// The color of the border.
+    color: Hex,
+    /// The width of the border.
+    width: f32,
+    /// The radii of the corners, from the top left.
+    radius: [f32; 4],
+}
+
+/// A [`Shadow`] written with its color, its offset and its blur radius.
+#[derive(Serialize, Deserialize)]
+struct ShadowDef {
+    /// The color of the shadow.
+    color: Hex,
+    /// The horizontal and vertical offset of the shadow.
+    offset: [f32; 2],
+    /// The blur radius of the shadow.
+    blur_radius: f32,
+}
+
+/// Writes and reads the [`Color`] fields of the appearances.
+pub(crate) mod color {
+    use super::{Color, Deserialize, Deserializer, Hex, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        Hex(*color).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        Hex::deserialize(deserializer).map(|Hex(color)| color)
+    }
+}
+
+/// Writes and reads the optional [`Color`] fields of the appearances.
+pub(crate) mod option_color {
+    use super::{Color, Deserialize, Deserializer, Hex, Serialize, Serializer};
+
+    #[allow(clippy::ref_option)]
+    pub fn serialize<S: Serializer>(
+        color: &Option<Color>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        color.map(Hex).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Color>, D::Error> {
+        Option::<Hex>::deserialize(deserializer).map(|color| color.map(|Hex(color)| color))
+    }
+}
+
+/// Writes and reads the arrays of [`Color`]s of the appearances.
+pub(crate) mod colors {
+    use super::{de, Color, Deserialize, Deserializer, Hex, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        colors: &[Color; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        colors.map(Hex).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[Color; N], D::Error> {
+        Vec::<Hex>::deserialize(deserializer)?
+            .into_iter()
+            .map(|Hex(color)| color)
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|colors: Vec<Color>| {
+                de::Error::invalid_length(colors.len(), &"an array of colors")
+            })
+    }
+}
+
+/// Writes and reads the [`Background`] fields of the appearances.
+pub(crate) mod background {
+    use super::{Background, BackgroundDef, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        background: &Background,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        BackgroundDef::from(*background).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Background, D::Error> {
+        BackgroundDef::deserialize(deserializer).map(Background::from)
+    }
+}
+
+/// Writes and reads the optional [`Background`] fields of the appearances.
+pub(crate) mod option_background {
+    use super::{Background, BackgroundDef, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[allow(clippy::ref_option)]
+    pub fn serialize<S: Serializer>(
+        background: &Option<Background>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        background.map(BackgroundDef::from).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Background>, D::Error> {
+        Option::<BackgroundDef>::deserialize(deserializer)
+            .map(|background| background.map(Background::from))
+    }
+}
+
+/// Writes and reads the [`Border`] fields of the appearances.
+pub(crate) mod border {
+    use super::{Border, BorderDef, Deserialize, Deserializer, Hex, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(border: &Border, serializer: S) -> Result<S::Ok, S::Error> {
+        BorderDef {
+            color: Hex(border.color),
+            width: border.width,
+            radius: border.radius.into(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Border, D::Error> {
+        BorderDef::deserialize(deserializer).map(|border| Border {
+            color: border.color.0,
+            width: border.width,
+            radius: border.radius.into(),
+        })
+    }
+}
+
+/// Writes and reads the [`Radius`](iced::border::Radius) fields of the
+/// appearances.
+pub(crate) mod radius {
+    use super::{Deserialize, Deserializer, Serialize, Serializer};
+    use iced::border::Radius;
+
+    pub fn serialize<S: Serializer>(radius: &Radius, serializer: S) -> Result<S::Ok, S::Error> {
+        <[f32; 4]>::from(*radius).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Radius, D::Error> {
+        <[f32; 4]>::deserialize(deserializer).map(Radius::from)
+    }
+}
+
+/// Writes and reads the [`Shadow`] fields of the appearances.
+pub(crate) mod shadow {
+    use super::{Deserialize, Deserializer, Hex, Serialize, Serializer, Shadow, ShadowDef, Vector};
+
+    pub fn serialize<S: Serializer>(shadow: &Shadow, serializer: S) -> Result<S::Ok, S::Error> {
+        ShadowDef {
+            color: Hex(shadow.color),
+            offset: [shadow.offset.x, shadow.offset.y],
+            blur_radius: shadow.blur_radius,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Shadow, D::Error> {
+        ShadowDef::deserialize(deserializer).map(|shadow| Shadow {
+            color: shadow.color.0,
+            offset: Vector::new(shadow.offset[0], shadow.offset[1]),
+            blur_radius: shadow.blur_radius,
+        })
+    }
+}
+
+/// Writes and reads the [`Padding`] fields of the appearances.
+pub(crate) mod padding {
+    use super::{Deserialize, Deserializer, Padding, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(padding: &Padding, serializer: S) -> Result<S::Ok, S::Error> {
+        [padding.top, padding.right, padding.bottom, padding.left].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Padding, D::Error> {
+        <[f32; 4]>::deserialize(deserializer).map(|[top, right, bottom, left]| Padding {
+            top,
+            right,
+            bottom,
+            left,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Theme;
+
+    #[test]
+    fn themes_survive_a_round_trip() {
+        let theme = Theme::from(&iced::Theme::Dark);
+
+        let toml = theme
+            .to_toml()
+            .expect("the theme should be written in TOML");
+        let from_toml = Theme::from_toml(&toml).expect("the TOML theme should be read");
+        assert_eq!(from_toml.to_toml().ok(), Some(toml));
+
+        let json = theme
+            .to_json()
+            .expect("the theme should be written in JSON");
+        let from_json = Theme::from_json(&json).expect("the JSON theme should be read");
+        assert_eq!(from_json.to_json().ok(), Some(json));
+    }
+
+    #[test]
+    #[cfg(feature = "badge")]
+    fn missing_values_keep_their_defaults() {
+        let theme = Theme::from_toml(
+            r##"
+            [badge.active]
+            text_color = "#ff000080"
+            "##,
+        )
+        .expect("the partial theme should be read");
+
+        let text_color = theme.badge.active.text_color;
+        assert!((text_color.r - 1.0).abs() < f32::EPSILON);
+        assert!((text_color.a - 128.0 / 255.0).abs() < f32::EPSILON);
+        assert!(theme.badge.hovered.is_none());
+        assert!((theme.badge.active.border_width - 1.0).abs() < f32::EPSILON);
+
+        assert!(Theme::from_toml("badge = { active = { text_color = \"red\" } }").is_err());
+    }
+}
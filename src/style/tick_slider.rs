@@ -0,0 +1,205 @@
+//! Select a value on a slider with tick marks and labels.
+//!
+//! *This API requires the following crate features to be activated: `tick_slider`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`TickSlider`](crate::native::tick_slider::TickSlider).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The color of the rail.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub rail_color: Color,
+
+    /// The color of the rail before the handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub filled_color: Color,
+
+    /// The thickness of the rail.
+    pub rail_width: f32,
+
+    /// The radius of the handle.
+    pub handle_radius: f32,
+
+    /// The color of the handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub handle_color: Color,
+
+    /// The border width of the handle.
+    pub handle_border_width: f32,
+
+    /// The border color of the handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub handle_border_color: Color,
+
+    /// The color of the ring around the handle focused for the keyboard.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub focus_color: Color,
+
+    /// The color of the minor tick marks.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub tick_color: Color,
+
+    /// The color of the major tick marks.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub major_tick_color: Color,
+
+    /// The color of the labels under the major tick marks.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub label_color: Color,
+
+    /// The background of the bubble showing the value while dragging.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub bubble_background: Background,
+
+    /// The color of the text of the bubble.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub bubble_text_color: Color,
+
+    /// The radius of the bubble.
+    pub bubble_border_radius: f32,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            rail_color: [0.85, 0.85, 0.85].into(),
+            filled_color: [0.2, 0.5, 0.8].into(),
+            rail_width: 4.0,
+            handle_radius: 8.0,
+            handle_color: Color::WHITE,
+            handle_border_width: 1.0,
+            handle_border_color: [0.6, 0.6, 0.6].into(),
+            focus_color: Color::from_rgba(0.2, 0.5, 0.8, 0.4),
+            tick_color: [0.75, 0.75, 0.75].into(),
+            major_tick_color: [0.45, 0.45, 0.45].into(),
+            label_color: [0.3, 0.3, 0.3].into(),
+            bubble_background: Color::from_rgb(0.2, 0.2, 0.2).into(),
+            bubble_text_color: Color::WHITE,
+            bubble_border_radius: settings::corner_radius(4.0),
+        }
+    }
+}
+
+/// The appearance of a [`TickSlider`](crate::native::tick_slider::TickSlider).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`TickSlider`](crate::native::tick_slider::TickSlider).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a hovered [`TickSlider`](crate::native::tick_slider::TickSlider).
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance of a [`TickSlider`](crate::native::tick_slider::TickSlider)
+    /// whose handle is dragged.
+    fn dragging(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+}
+
+/// The default appearance of a [`TickSlider`](crate::native::tick_slider::TickSlider).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TickSliderStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl TickSliderStyles {
+    /// Creates a custom [`TickSliderStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for TickSliderStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+
+    fn dragging(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Dragging)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = TickSliderStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let TickSliderStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            rail_color: palette.background.strong.color,
+            filled_color: palette.primary.base.color,
+            handle_color: palette.background.base.color,
+            handle_border_color: palette.background.strong.color,
+            focus_color: Color {
+                a: 0.4,
+                ..palette.primary.base.color
+            },
+            tick_color: palette.background.strong.color,
+            major_tick_color: palette.background.base.text,
+            label_color: palette.background.base.text,
+            bubble_background: palette.primary.strong.color.into(),
+            bubble_text_color: palette.primary.strong.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let TickSliderStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            handle_border_color: palette.primary.base.color,
+            ..self.active(style)
+        }
+    }
+
+    fn dragging(&self, style: &Self::Style) -> Appearance {
+        if let TickSliderStyles::Custom(custom) = style {
+            return custom.dragging(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            handle_color: palette.primary.weak.color,
+            ..self.hovered(style)
+        }
+    }
+}
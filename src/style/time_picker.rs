@@ -1,14 +1,26 @@
 //! Use a time picker as an input element for picking times.
 //!
 //! *This API requires the following crate features to be activated: `time_picker`*
+use crate::core::settings;
 use std::rc::Rc;
 
+use super::Status;
+use crate::core::theme::is_high_contrast;
 use iced::{Background, Color, Theme};
 
 /// The appearance of a [`TimePicker`](crate::native::TimePicker).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Appearance {
     /// The background of the [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
     pub background: Background,
 
     /// The border radius of the [`TimePicker`](crate::native::TimePicker).
@@ -18,25 +30,31 @@ pub struct Appearance {
     pub border_width: f32,
 
     /// The border color of the [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub border_color: Color,
 
     /// The text color of the [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub text_color: Color,
 
     /// The color of the clock numbers of the
     /// [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub clock_number_color: Color,
 
     /// The background of the clock numbers of the
     /// [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub clock_number_background: Color,
 
     /// The color of the dots on the clock of the
     /// [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub clock_dots_color: Color,
 
     /// The color of the hands of the clock of the
     /// [`TimePicker`](crate::native::TimePicker).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
     pub clock_hand_color: Color,
 
     /// The with of the hands of the clock of the
@@ -44,6 +62,12 @@ pub struct Appearance {
     pub clock_hand_width: f32,
 }
 
+impl Default for Appearance {
+    fn default() -> Self {
+        Theme::default().active(&TimePickerStyle::Default)
+    }
+}
+
 /// The appearance of a [`TimePicker`](crate::native::TimePicker).
 pub trait StyleSheet {
     /// The style type of this stylesheet
@@ -80,6 +104,32 @@ impl TimePickerStyle {
     }
 }
 
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for TimePickerStyle {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn selected(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Selected)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Focused)
+    }
+}
+
 impl StyleSheet for Theme {
     type Style = TimePickerStyle;
 
@@ -90,17 +140,22 @@ impl StyleSheet for Theme {
 
         let palette = self.extended_palette();
         let foreground = self.palette();
+        let clock_color = if is_high_contrast(self) {
+            palette.background.strong.color
+        } else {
+            palette.background.weak.color
+        };
 
         Appearance {
             background: palette.background.base.color.into(),
-            border_radius: 15.0,
+            border_radius: settings::corner_radius(15.0),
             border_width: 1.0,
             border_color: foreground.text,
             text_color: foreground.text,
             clock_number_color: foreground.text,
             clock_number_background: palette.background.base.color,
-            clock_dots_color: [0.87, 0.87, 0.87].into(),
-            clock_hand_color: [0.87, 0.87, 0.87].into(),
+            clock_dots_color: clock_color,
+            clock_hand_color: clock_color,
             clock_hand_width: 3.0,
         }
     }
@@ -139,7 +194,11 @@ impl StyleSheet for Theme {
         }
 
         Appearance {
-            border_color: Color::from_rgb(0.5, 0.5, 0.5),
+            border_color: if is_high_contrast(self) {
+                self.extended_palette().primary.strong.color
+            } else {
+                Color::from_rgb(0.5, 0.5, 0.5)
+            },
             ..self.active(style)
         }
     }
@@ -0,0 +1,137 @@
+//! Use toasts to show transient notifications.
+//!
+//! *This API requires the following crate features to be activated: toast*
+use super::colors;
+use crate::core::settings;
+
+use super::Status;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`Toast`](crate::native::toast::Toast).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the [`Toast`](crate::native::toast::Toast).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The border radius of the [`Toast`](crate::native::toast::Toast).
+    pub border_radius: f32,
+
+    /// The border width of the [`Toast`](crate::native::toast::Toast).
+    pub border_width: f32,
+
+    /// The border color of the [`Toast`](crate::native::toast::Toast).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The color of the title of the [`Toast`](crate::native::toast::Toast).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub title_color: Color,
+
+    /// The color of the body of the [`Toast`](crate::native::toast::Toast).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub body_color: Color,
+
+    /// The accent color of an informational [`Toast`](crate::native::toast::Toast).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub info_color: Color,
+
+    /// The accent color of a successful [`Toast`](crate::native::toast::Toast).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub success_color: Color,
+
+    /// The accent color of a warning [`Toast`](crate::native::toast::Toast).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub warning_color: Color,
+
+    /// The accent color of an error [`Toast`](crate::native::toast::Toast).
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub error_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color(Color::WHITE),
+            border_radius: settings::corner_radius(6.0),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            title_color: Color::BLACK,
+            body_color: [0.3, 0.3, 0.3].into(),
+            info_color: colors::PRIMARY,
+            success_color: colors::SUCCESS,
+            warning_color: colors::WARNING,
+            error_color: colors::DANGER,
+        }
+    }
+}
+
+/// The appearance of a [`Toast`](crate::native::toast::Toast).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+    /// The normal appearance of a [`Toast`](crate::native::toast::Toast).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Toast`](crate::native::toast::Toast).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum ToastStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl ToastStyles {
+    /// Creates a custom [`ToastStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for ToastStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = ToastStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let ToastStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            title_color: palette.background.base.text,
+            body_color: palette.background.weak.text,
+            info_color: palette.primary.base.color,
+            success_color: palette.success.base.color,
+            error_color: palette.danger.base.color,
+            ..Appearance::default()
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Guide the user through a sequence of highlighted widgets.
+//!
+//! *This API requires the following crate features to be activated: `tour`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`Tour`](crate::native::tour::Tour).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The color dimming the application around the highlighted widget.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub backdrop_color: Color,
+
+    /// The color of the border around the highlighted widget.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub highlight_border_color: Color,
+
+    /// The width of the border around the highlighted widget.
+    pub highlight_border_width: f32,
+
+    /// The radius of the border around the highlighted widget.
+    pub highlight_border_radius: f32,
+
+    /// The background of the popover explaining the step.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The radius of the popover and of its buttons.
+    pub border_radius: f32,
+
+    /// The color of the title of the step.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub title_color: Color,
+
+    /// The color of the explanation of the step.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub text_color: Color,
+
+    /// The color of the step counter and of the secondary buttons.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub secondary_text_color: Color,
+
+    /// The background of the button going to the next step.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub button_background: Background,
+
+    /// The color of the text of the button going to the next step.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub button_text_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            backdrop_color: Color::from_rgba(0.0, 0.0, 0.0, 0.5),
+            highlight_border_color: [0.3, 0.6, 1.0].into(),
+            highlight_border_width: 2.0,
+            highlight_border_radius: settings::corner_radius(4.0),
+            background: Color::WHITE.into(),
+            border_radius: settings::corner_radius(6.0),
+            title_color: Color::BLACK,
+            text_color: [0.2, 0.2, 0.2].into(),
+            secondary_text_color: [0.45, 0.45, 0.45].into(),
+            button_background: Color::from_rgb(0.2, 0.5, 0.9).into(),
+            button_text_color: Color::WHITE,
+        }
+    }
+}
+
+/// The appearance of a [`Tour`](crate::native::tour::Tour).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`Tour`](crate::native::tour::Tour).
+    fn active(&self, style: &Self::Style) -> Appearance;
+}
+
+/// The default appearance of a [`Tour`](crate::native::tour::Tour).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum TourStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl TourStyles {
+    /// Creates a custom [`TourStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for TourStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = TourStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let TourStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            highlight_border_color: palette.primary.strong.color,
+            background: palette.background.base.color.into(),
+            title_color: palette.background.base.text,
+            text_color: palette.background.base.text,
+            secondary_text_color: palette.background.strong.color,
+            button_background: palette.primary.base.color.into(),
+            button_text_color: palette.primary.base.text,
+            ..Appearance::default()
+        }
+    }
+}
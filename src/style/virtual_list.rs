@@ -0,0 +1,123 @@
+//! Show a long list by only creating its visible items.
+//!
+//! *This API requires the following crate features to be activated: `virtual_list`*
+use super::Status;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`VirtualList`](crate::native::virtual_list::VirtualList).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the list, if any.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::option_background")
+    )]
+    pub background: Option<Background>,
+
+    /// The background of the scrollbar.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub scrollbar_background: Background,
+
+    /// The color of the scroller moving along the scrollbar.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub scroller_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: None,
+            scrollbar_background: Color::TRANSPARENT.into(),
+            scroller_color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+        }
+    }
+}
+
+/// The appearance of a [`VirtualList`](crate::native::virtual_list::VirtualList).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`VirtualList`](crate::native::virtual_list::VirtualList).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a [`VirtualList`](crate::native::virtual_list::VirtualList)
+    /// while its scrollbar is hovered or dragged.
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`VirtualList`](crate::native::virtual_list::VirtualList).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum VirtualListStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl VirtualListStyles {
+    /// Creates a custom [`VirtualListStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for VirtualListStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = VirtualListStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let VirtualListStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            scroller_color: palette.background.strong.color,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let VirtualListStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            scrollbar_background: palette.background.weak.color.into(),
+            scroller_color: palette.primary.strong.color,
+            ..self.active(style)
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Pick an item on a scrolling wheel.
+//!
+//! *This API requires the following crate features to be activated: `wheel_picker`*
+use super::Status;
+use crate::core::settings;
+use iced::{Background, Color, Theme};
+use std::rc::Rc;
+
+/// The appearance of a [`WheelPicker`](crate::native::wheel_picker::WheelPicker).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Appearance {
+    /// The background of the wheel.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub background: Background,
+
+    /// The radius of the wheel and of its selection band.
+    pub border_radius: f32,
+
+    /// The border width of the wheel.
+    pub border_width: f32,
+
+    /// The border color of the wheel.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub border_color: Color,
+
+    /// The color of the items.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub text_color: Color,
+
+    /// The color of the item in the selection band.
+    #[cfg_attr(feature = "serde", serde(with = "crate::style::theme_file::color"))]
+    pub selected_text_color: Color,
+
+    /// The background of the selection band.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::style::theme_file::background")
+    )]
+    pub selection_background: Background,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Background::Color(Color::WHITE),
+            border_radius: settings::corner_radius(8.0),
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+            text_color: [0.3, 0.3, 0.3].into(),
+            selected_text_color: Color::BLACK,
+            selection_background: Background::Color([0.9, 0.9, 0.9].into()),
+        }
+    }
+}
+
+/// The appearance of a [`WheelPicker`](crate::native::wheel_picker::WheelPicker).
+pub trait StyleSheet {
+    /// Style for the trait to use.
+    type Style: Default + Clone;
+
+    /// The normal appearance of a [`WheelPicker`](crate::native::wheel_picker::WheelPicker).
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// The appearance of a hovered [`WheelPicker`](crate::native::wheel_picker::WheelPicker).
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.active(style)
+    }
+}
+
+/// The default appearance of a [`WheelPicker`](crate::native::wheel_picker::WheelPicker).
+#[derive(Clone, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub enum WheelPickerStyles {
+    #[default]
+    Default,
+    Custom(Rc<dyn StyleSheet<Style = Theme>>),
+}
+
+impl WheelPickerStyles {
+    /// Creates a custom [`WheelPickerStyles`] style variant.
+    pub fn custom(style_sheet: impl StyleSheet<Style = Theme> + 'static) -> Self {
+        Self::Custom(Rc::new(style_sheet))
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance + 'static> From<F> for WheelPickerStyles {
+    fn from(f: F) -> Self {
+        Self::custom(f)
+    }
+}
+
+impl<F: Fn(&Theme, Status) -> Appearance> StyleSheet for F {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Active)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self(style, Status::Hovered)
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = WheelPickerStyles;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        if let WheelPickerStyles::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            selected_text_color: palette.primary.strong.text,
+            selection_background: palette.primary.strong.color.into(),
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        if let WheelPickerStyles::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        Appearance {
+            border_color: palette.primary.base.color,
+            ..self.active(style)
+        }
+    }
+}
@@ -0,0 +1,1306 @@
+//! Test the behavior of widgets without a window.
+//!
+//! A [`Harness`] mounts a widget, lays it out with a headless [`Renderer`]
+//! and feeds it synthetic mouse, touch and keyboard events. The messages
+//! published by the widget and the geometry of its layout can then be checked
+//! in unit tests.
+//!
+//! The [`Renderer`] draws nothing and measures the text as if every
+//! character was half as wide as the text size, so the layout of the widgets
+//! showing text is stable across platforms and fonts.
+//!
+//! *This API requires the following crate features to be activated: `testing`*
+//!
+//! # Example
+//! ```ignore
+//! # use iced::{Point, Size};
+//! # use iced_aw::{testing::Harness, TabBar, TabLabel};
+//! #
+//! #[derive(Clone, Debug, PartialEq)]
+//! enum Message {
+//!     Select(usize),
+//! }
+//!
+//! let tab_bar = TabBar::new(Message::Select)
+//!     .push(0, TabLabel::Text(String::from("One")))
+//!     .push(1, TabLabel::Text(String::from("Two")));
+//! let mut harness = Harness::<Message>::new(tab_bar, Size::new(400.0, 50.0));
+//!
+//! let second = harness.layout().children().nth(1).unwrap().bounds();
+//! let _ = harness.click(second.center());
+//!
+//! assert_eq!(harness.messages(), vec![Message::Select(1)]);
+//! ```
+
+#[cfg(feature = "svg")]
+use iced::advanced::svg;
+use iced::{
+    advanced::{
+        clipboard,
+        layout::{Limits, Node},
+        renderer,
+        text::{self, Text},
+        widget::{Operation, Tree},
+        Layout, Shell,
+    },
+    alignment::{Horizontal, Vertical},
+    event, keyboard, mouse, touch, Background, Color, Element, Event, Font, Pixels, Point,
+    Rectangle, Size, Transformation, Vector,
+};
+use std::borrow::Cow;
+
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
+
+/// The width of a character measured by the [`Renderer`], relative to the
+/// text size.
+const CHARACTER_WIDTH: f32 = 0.5;
+
+/// A headless renderer, drawing nothing and measuring the text with a fixed
+/// width per character.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Renderer;
+
+impl renderer::Renderer for Renderer {
+    fn with_layer(&mut self, _bounds: Rectangle, f: impl FnOnce(&mut Self)) {
+        f(self);
+    }
+
+    fn with_transformation(&mut self, _transformation: Transformation, f: impl FnOnce(&mut Self)) {
+        f(self);
+    }
+
+    fn fill_quad(&mut self, _quad: renderer::Quad, _background: impl Into<Background>) {}
+
+    fn clear(&mut self) {}
+}
+
+impl text::Renderer for Renderer {
+    type Font = Font;
+    type Paragraph = Paragraph;
+    type Editor = ();
+
+    const ICON_FONT: Font = Font::DEFAULT;
+    const CHECKMARK_ICON: char = '✓';
+    const ARROW_DOWN_ICON: char = '▼';
+
+    fn default_font(&self) -> Font {
+        Font::default()
+    }
+
+    fn default_size(&self) -> Pixels {
+        Pixels(16.0)
+    }
+
+    fn load_font(&mut self, _font: Cow<'static, [u8]>) {}
+
+    fn fill_paragraph(
+        &mut self,
+        _paragraph: &Paragraph,
+        _position: Point,
+        _color: Color,
+        _clip_bounds: Rectangle,
+    ) {
+    }
+
+    fn fill_editor(
+        &mut self,
+        _editor: &Self::Editor,
+        _position: Point,
+        _color: Color,
+        _clip_bounds: Rectangle,
+    ) {
+    }
+
+    fn fill_text(
+        &mut self,
+        _text: Text<'_, Font>,
+        _position: Point,
+        _color: Color,
+        _clip_bounds: Rectangle,
+    ) {
+    }
+}
+
+#[cfg(feature = "svg")]
+impl svg::Renderer for Renderer {
+    fn dimensions(&self, _handle: &svg::Handle) -> Size<u32> {
+        Size::new(16, 16)
+    }
+
+    fn draw(&mut self, _handle: svg::Handle, _color: Option<Color>, _bounds: Rectangle) {}
+}
+
+/// A text measured by the [`Renderer`], with a fixed width per character.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Paragraph {
+    content: String,
+    font: Font,
+    size: f32,
+    line_height: text::LineHeight,
+    bounds: Size,
+    horizontal_alignment: Horizontal,
+    vertical_alignment: Vertical,
+}
+
+impl Paragraph {
+    /// Returns the width of a character and the height of a line.
+    fn cell(&self) -> Size {
+        Size::new(
+            self.size * CHARACTER_WIDTH,
+            self.line_height.to_absolute(Pixels(self.size)).0,
+        )
+    }
+}
+
+impl Default for Paragraph {
+    fn default() -> Self {
+        Self {
+            content: String::new(),
+            font: Font::default(),
+            size: 16.0,
+            line_height: text::LineHeight::default(),
+            bounds: Size::ZERO,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Top,
+        }
+    }
+}
+
+impl text::Paragraph for Paragraph {
+    type Font = Font;
+
+    fn with_text(text: Text<'_, Font>) -> Self {
+        Self {
+            content: text.content.to_owned(),
+            font: text.font,
+            size: text.size.0,
+            line_height: text.line_height,
+            bounds: text.bounds,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+        }
+    }
+
+    fn resize(&mut self, new_bounds: Size) {
+        self.bounds = new_bounds;
+    }
+
+    fn compare(&self, text: Text<'_, Font>) -> text::Difference {
+        if self.content != text.content
+            || self.font != text.font
+            || (self.size - text.size.0).abs() > f32::EPSILON
+            || self.line_height != text.line_height
+            || self.horizontal_alignment != text.horizontal_alignment
+            || self.vertical_alignment != text.vertical_alignment
+        {
+            text::Difference::Shape
+        } else if self.bounds != text.bounds {
+            text::Difference::Bounds
+        } else {
+            text::Difference::None
+        }
+    }
+
+    fn horizontal_alignment(&self) -> Horizontal {
+        self.horizontal_alignment
+    }
+
+    fn vertical_alignment(&self) -> Vertical {
+        self.vertical_alignment
+    }
+
+    fn min_bounds(&self) -> Size {
+        let cell = self.cell();
+        let lines = self.content.lines().count().max(1);
+        let columns = self
+            .content
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or_default();
+
+        Size::new(cell.width * columns as f32, cell.height * lines as f32)
+    }
+
+    fn hit_test(&self, point: Point) -> Option<text::Hit> {
+        let cell = self.cell();
+        let line = (point.y / cell.height).max(0.0) as usize;
+        let column = (point.x / cell.width).round().max(0.0) as usize;
+        let offset = self
+            .content
+            .lines()
+            .take(line)
+            .map(|line| line.chars().count() + 1)
+            .sum::<usize>();
+        let length = self.content.lines().nth(line)?.chars().count();
+
+        Some(text::Hit::CharOffset(offset + column.min(length)))
+    }
+
+    fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
+        let cell = self.cell();
+        let length = self.content.lines().nth(line)?.chars().count();
+
+        Some(Point::new(
+            cell.width * index.min(length) as f32,
+            cell.height * line as f32,
+        ))
+    }
+}
+
+/// A widget mounted without a window, fed with synthetic events.
+///
+/// The events first go to the overlay of the widget, if it shows one, like
+/// in an application. The layout is computed again when the widget
+/// invalidates it, and the messages published by the widget are kept until
+/// they are taken with [`Harness::messages`].
+#[allow(missing_debug_implementations)]
+pub struct Harness<'a, Message, Theme = iced::Theme, Renderer = self::Renderer> {
+    /// The mounted widget.
+    root: Element<'a, Message, Theme, Renderer>,
+    /// The state of the widget.
+    tree: Tree,
+    /// The layout of the widget.
+    layout: Node,
+    /// The size of the window the widget is laid out in.
+    size: Size,
+    renderer: Renderer,
+    theme: Theme,
+    /// The position of the cursor, moved by the events.
+    cursor: mouse::Cursor,
+    /// The messages published by the widget.
+    messages: Vec<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> Harness<'a, Message, Theme, Renderer>
+where
+    Theme: Default,
+    Renderer: renderer::Renderer + Default,
+{
+    /// Mounts the `root` widget in a window of the given `size`.
+    pub fn new(root: impl Into<Element<'a, Message, Theme, Renderer>>, size: Size) -> Self {
+        let root = root.into();
+        let mut tree = Tree::new(root.as_widget());
+        let renderer = Renderer::default();
+        let layout = root
+            .as_widget()
+            .layout(&mut tree, &renderer, &Limits::new(Size::ZERO, size));
+
+        Self {
+            root,
+            tree,
+            layout,
+            size,
+            renderer,
+            theme: Theme::default(),
+            cursor: mouse::Cursor::Unavailable,
+            messages: Vec::new(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Harness<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Sets the theme the widget is drawn with.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Replaces the mounted widget with the `root` of a new view, keeping the
+    /// state of the previous one like an application does.
+    pub fn update(&mut self, root: impl Into<Element<'a, Message, Theme, Renderer>>) {
+        self.root = root.into();
+        self.tree.diff(self.root.as_widget());
+        self.relayout();
+    }
+
+    /// Returns the layout of the widget.
+    #[must_use]
+    pub fn layout(&self) -> Layout<'_> {
+        Layout::new(&self.layout)
+    }
+
+    /// Returns the bounds of the widget.
+    #[must_use]
+    pub fn bounds(&self) -> Rectangle {
+        self.layout.bounds()
+    }
+
+    /// Returns the state of the widget.
+    #[must_use]
+    pub fn tree(&self) -> &Tree {
+        &self.tree
+    }
+
+    /// Returns the renderer the widget is laid out and drawn with.
+    #[must_use]
+    pub fn renderer(&self) -> &Renderer {
+        &self.renderer
+    }
+
+    /// Takes the messages published by the widget since they were last
+    /// taken.
+    pub fn messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Feeds the widget with an event and returns whether it was captured.
+    pub fn event(&mut self, event: Event) -> event::Status {
+        match &event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(
+                touch::Event::FingerPressed { position, .. }
+                | touch::Event::FingerMoved { position, .. },
+            ) => self.cursor = mouse::Cursor::Available(*position),
+            Event::Mouse(mouse::Event::CursorLeft) => self.cursor = mouse::Cursor::Unavailable,
+            _ => {}
+        }
+
+        let mut clipboard = clipboard::Null;
+        let mut shell = Shell::new(&mut self.messages);
+        let viewport = Rectangle::with_size(self.size);
+        let mut status = event::Status::Ignored;
+        let mut cursor = self.cursor;
+
+        if let Some(mut overlay) = self.root.as_widget_mut().overlay(
+            &mut self.tree,
+            Layout::new(&self.layout),
+            &self.renderer,
+            Vector::ZERO,
+        ) {
+            let node = overlay.layout(&self.renderer, self.size);
+            let layout = Layout::new(&node);
+
+            status = overlay.on_event(
+                event.clone(),
+                layout,
+                self.cursor,
+                &self.renderer,
+                &mut clipboard,
+                &mut shell,
+            );
+
+            if self
+                .cursor
+                .position()
+                .is_some_and(|position| overlay.is_over(layout, &self.renderer, position))
+            {
+                cursor = mouse::Cursor::Unavailable;
+            }
+        }
+
+        if status == event::Status::Ignored {
+            status = self.root.as_widget_mut().on_event(
+                &mut self.tree,
+                event,
+                Layout::new(&self.layout),
+                cursor,
+                &self.renderer,
+                &mut clipboard,
+                &mut shell,
+                &viewport,
+            );
+        }
+
+        if shell.is_layout_invalid() {
+            self.relayout();
+        }
+
+        status
+    }
+
+    /// Moves the cursor to `position`.
+    pub fn move_cursor(&mut self, position: Point) -> event::Status {
+        self.event(Event::Mouse(mouse::Event::CursorMoved { position }))
+    }
+
+    /// Moves the cursor to `position`, then presses and releases the given
+    /// mouse button. Returns whether the press was captured.
+    pub fn click_with(&mut self, position: Point, button: mouse::Button) -> event::Status {
+        let _ = self.move_cursor(position);
+        let status = self.event(Event::Mouse(mouse::Event::ButtonPressed(button)));
+        let _ = self.event(Event::Mouse(mouse::Event::ButtonReleased(button)));
+
+        status
+    }
+
+    /// Clicks at `position` with the left mouse button. Returns whether the
+    /// press was captured.
+    pub fn click(&mut self, position: Point) -> event::Status {
+        self.click_with(position, mouse::Button::Left)
+    }
+
+    /// Touches and lifts a finger at `position`. Returns whether the touch
+    /// was captured.
+    pub fn tap(&mut self, position: Point) -> event::Status {
+        let id = touch::Finger(0);
+        let status = self.event(Event::Touch(touch::Event::FingerPressed { id, position }));
+        let _ = self.event(Event::Touch(touch::Event::FingerLifted { id, position }));
+
+        status
+    }
+
+    /// Presses and releases the given key with the given modifiers. Returns
+    /// whether the press was captured.
+    pub fn press_key_with(
+        &mut self,
+        key: keyboard::Key,
+        modifiers: keyboard::Modifiers,
+    ) -> event::Status {
+        let location = keyboard::Location::Standard;
+        let status = self.event(Event::Keyboard(keyboard::Event::KeyPressed {
+            key: key.clone(),
+            location,
+            modifiers,
+            text: None,
+        }));
+        let _ = self.event(Event::Keyboard(keyboard::Event::KeyReleased {
+            key,
+            location,
+            modifiers,
+        }));
+
+        status
+    }
+
+    /// Presses and releases the given key. Returns whether the press was
+    /// captured.
+    pub fn press_key(&mut self, key: keyboard::Key) -> event::Status {
+        self.press_key_with(key, keyboard::Modifiers::default())
+    }
+
+    /// Returns the mouse interaction of the widget, or of its overlay when
+    /// the cursor is over it.
+    pub fn mouse_interaction(&mut self) -> mouse::Interaction {
+        let viewport = Rectangle::with_size(self.size);
+
+        if let Some(overlay) = self.root.as_widget_mut().overlay(
+            &mut self.tree,
+            Layout::new(&self.layout),
+            &self.renderer,
+            Vector::ZERO,
+        ) {
+            let mut overlay = overlay;
+            let node = overlay.layout(&self.renderer, self.size);
+            let layout = Layout::new(&node);
+
+            if self
+                .cursor
+                .position()
+                .is_some_and(|position| overlay.is_over(layout, &self.renderer, position))
+            {
+                return overlay.mouse_interaction(layout, self.cursor, &viewport, &self.renderer);
+            }
+        }
+
+        self.root.as_widget().mouse_interaction(
+            &self.tree,
+            Layout::new(&self.layout),
+            self.cursor,
+            &viewport,
+            &self.renderer,
+        )
+    }
+
+    /// Returns whether the widget shows an overlay, like an open menu.
+    pub fn has_overlay(&mut self) -> bool {
+        self.root
+            .as_widget_mut()
+            .overlay(
+                &mut self.tree,
+                Layout::new(&self.layout),
+                &self.renderer,
+                Vector::ZERO,
+            )
+            .is_some()
+    }
+
+    /// Runs an `operation` on the widget and its overlay, like the widget
+    /// commands of an application, then lays the widget out again.
+    pub fn operate(&mut self, operation: &mut dyn Operation<Message>) {
+        self.root.as_widget().operate(
+            &mut self.tree,
+            Layout::new(&self.layout),
+            &self.renderer,
+            operation,
+        );
+
+        if let Some(mut overlay) = self.root.as_widget_mut().overlay(
+            &mut self.tree,
+            Layout::new(&self.layout),
+            &self.renderer,
+            Vector::ZERO,
+        ) {
+            let node = overlay.layout(&self.renderer, self.size);
+
+            overlay.operate(Layout::new(&node), &self.renderer, operation);
+        }
+
+        self.relayout();
+    }
+
+    /// Draws the widget and its overlay with the renderer.
+    pub fn draw(&mut self) {
+        let style = renderer::Style {
+            text_color: Color::BLACK,
+        };
+        let viewport = Rectangle::with_size(self.size);
+
+        self.root.as_widget().draw(
+            &self.tree,
+            &mut self.renderer,
+            &self.theme,
+            &style,
+            Layout::new(&self.layout),
+            self.cursor,
+            &viewport,
+        );
+
+        if let Some(mut overlay) = self.root.as_widget_mut().overlay(
+            &mut self.tree,
+            Layout::new(&self.layout),
+            &self.renderer,
+            Vector::ZERO,
+        ) {
+            let node = overlay.layout(&self.renderer, self.size);
+
+            overlay.draw(
+                &mut self.renderer,
+                &self.theme,
+                &style,
+                Layout::new(&node),
+                self.cursor,
+            );
+        }
+    }
+
+    /// Lays the widget out again.
+    fn relayout(&mut self) {
+        self.layout = self.root.as_widget().layout(
+            &mut self.tree,
+            &self.renderer,
+            &Limits::new(Size::ZERO, self.size),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Harness, Paragraph};
+    use iced::{
+        advanced::text::{self, Paragraph as _, Text},
+        alignment::{Horizontal, Vertical},
+        event, Font, Pixels, Size,
+    };
+
+    #[test]
+    fn text_is_measured_per_character() {
+        let paragraph = Paragraph::with_text(Text {
+            content: "four\nlines",
+            bounds: Size::INFINITY,
+            size: Pixels(10.0),
+            line_height: text::LineHeight::Absolute(Pixels(12.0)),
+            font: Font::default(),
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Top,
+            shaping: text::Shaping::Basic,
+        });
+
+        assert_eq!(paragraph.min_bounds(), Size::new(25.0, 24.0));
+        assert_eq!(
+            paragraph.hit_test(iced::Point::new(11.0, 13.0)),
+            Some(text::Hit::CharOffset(7))
+        );
+    }
+
+    #[cfg(feature = "tab_bar")]
+    #[test]
+    fn tab_bar_selects_and_closes_tabs() {
+        use crate::{TabBar, TabLabel};
+
+        #[derive(Clone, Debug, PartialEq)]
+        enum Message {
+            Select(usize),
+            Close(usize),
+        }
+
+        let tab_bar = || {
+            TabBar::new(Message::Select)
+                .push(0, TabLabel::Text(String::from("One")))
+                .push(1, TabLabel::Text(String::from("Two")))
+                .push(2, TabLabel::Text(String::from("Three")))
+                .on_close(Message::Close)
+        };
+        let mut harness = Harness::<Message>::new(tab_bar(), Size::new(600.0, 40.0));
+
+        let tabs: Vec<_> = harness
+            .layout()
+            .children()
+            .map(|tab| tab.bounds())
+            .collect();
+        assert_eq!(tabs.len(), 3);
+        assert!(tabs.windows(2).all(|pair| pair[0].x < pair[1].x));
+
+        let status = harness.click(tabs[1].center());
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(harness.messages(), vec![Message::Select(1)]);
+
+        let crosses: Vec<_> = harness
+            .layout()
+            .children()
+            .filter_map(|tab| tab.children().nth(1))
+            .map(|cross| cross.bounds())
+            .collect();
+        let _ = harness.click(crosses[2].center());
+        assert_eq!(harness.messages(), vec![Message::Close(2)]);
+
+        harness.update(tab_bar());
+        let _ = harness.tap(tabs[0].center());
+        assert_eq!(harness.messages(), vec![Message::Select(0)]);
+
+        let _ = harness.click(iced::Point::new(tabs[2].x + tabs[2].width + 10.0, 20.0));
+        assert!(harness.messages().is_empty());
+    }
+
+    #[cfg(feature = "selection_list")]
+    #[test]
+    fn selection_list_takes_the_keyboard_when_focused() {
+        use crate::SelectionList;
+        use iced::{
+            keyboard::{key::Named, Key},
+            Point,
+        };
+
+        let options = [1, 2, 3];
+        let list = SelectionList::new(&options, |index, _| index).height(iced::Length::Fill);
+        let mut harness = Harness::<usize>::new(list, Size::new(200.0, 200.0));
+        let center = harness.bounds().center();
+
+        let _ = harness.move_cursor(center);
+        let _ = harness.press_key(Key::Named(Named::ArrowDown));
+        let _ = harness.press_key(Key::Named(Named::Enter));
+        assert!(harness.messages().is_empty());
+
+        let _ = harness.click(Point::new(center.x, 1.0));
+        assert_eq!(harness.messages(), vec![0]);
+
+        let _ = harness.move_cursor(Point::new(-10.0, -10.0));
+        let _ = harness.press_key(Key::Named(Named::ArrowDown));
+        let _ = harness.press_key(Key::Named(Named::Enter));
+        assert_eq!(harness.messages(), vec![1]);
+    }
+
+    #[cfg(feature = "selection_list")]
+    #[test]
+    fn selection_list_selects_on_request() {
+        use crate::{widgets::selection_list::request, SelectionList};
+        use iced::{advanced::widget::Id, window, Event, Point};
+        use std::time::Instant;
+
+        let id = Id::new("list");
+        let options = [1, 2, 3];
+        let list = |disabled| {
+            SelectionList::new(&options, |index, _| index)
+                .height(iced::Length::Fill)
+                .id(id.clone())
+                .disabled(disabled)
+        };
+        let redraw = Event::Window(
+            window::Id::MAIN,
+            window::Event::RedrawRequested(Instant::now()),
+        );
+        let mut harness = Harness::<usize>::new(list(false), Size::new(200.0, 200.0));
+
+        harness.operate(&mut request(id.clone(), 2));
+        assert!(harness.messages().is_empty());
+        let _ = harness.event(redraw.clone());
+        assert_eq!(harness.messages(), vec![2]);
+
+        harness.operate(&mut request(id.clone(), 1));
+        harness.update(list(true));
+        let _ = harness.event(redraw);
+        harness.update(list(false));
+        let _ = harness.move_cursor(Point::new(-10.0, -10.0));
+        assert!(harness.messages().is_empty());
+    }
+
+    #[cfg(feature = "tab_bar")]
+    #[test]
+    fn tab_bar_scrolls_its_tabs() {
+        use crate::{widgets::tab_bar, TabBar, TabLabel};
+        use iced::{advanced::widget::Id, Length, Point};
+
+        let id = Id::new("tabs");
+        let tab_bar = (0..5)
+            .fold(TabBar::new(|index| index), |tab_bar, index| {
+                tab_bar.push(index, TabLabel::Text(index.to_string()))
+            })
+            .tab_width(Length::Fixed(100.0))
+            .id(id.clone());
+        let mut harness = Harness::<usize>::new(tab_bar, Size::new(250.0, 40.0));
+        let first = |harness: &Harness<'_, usize>| {
+            harness.layout().children().next().map(|tab| tab.bounds().x)
+        };
+        let last = |harness: &Harness<'_, usize>| {
+            harness
+                .layout()
+                .children()
+                .last()
+                .map(|tab| tab.bounds().x + tab.bounds().width)
+        };
+
+        assert_eq!(first(&harness), Some(0.0));
+
+        harness.operate(&mut tab_bar::request(id.clone(), 4));
+        assert_eq!(last(&harness), Some(250.0));
+
+        let _ = harness.click(Point::new(240.0, 20.0));
+        assert_eq!(harness.messages(), vec![4]);
+
+        harness.operate(&mut tab_bar::request(Id::new("other"), 0));
+        assert_eq!(last(&harness), Some(250.0));
+
+        harness.operate(&mut tab_bar::request(id, 0));
+        assert_eq!(first(&harness), Some(0.0));
+    }
+
+    #[test]
+    fn split_moves_its_divider_on_request() {
+        use crate::widgets::split::{self, Axis, Split};
+        use iced::{advanced::widget::Id, widget::text, Point};
+
+        let id = Id::new("split");
+        let split = Split::new(
+            text("First"),
+            text("Second"),
+            Some(100),
+            Axis::Vertical,
+            |at| at,
+        )
+        .id(id.clone());
+        let mut harness = Harness::<u16>::new(split, Size::new(300.0, 200.0));
+
+        harness.operate(&mut split::request(Id::new("other"), 50));
+        let _ = harness.move_cursor(Point::new(10.0, 10.0));
+        assert!(harness.messages().is_empty());
+
+        harness.operate(&mut split::request(id, 150));
+        let _ = harness.move_cursor(Point::new(20.0, 10.0));
+        assert_eq!(harness.messages(), vec![150]);
+
+        let _ = harness.move_cursor(Point::new(30.0, 10.0));
+        assert!(harness.messages().is_empty());
+    }
+
+    #[test]
+    fn modal_focuses_the_first_widget_of_its_overlay() {
+        use crate::widgets::{modal, Modal};
+        use iced::{
+            advanced::widget::{operation::focusable, operation::Outcome, Id, Operation},
+            widget::{column, container, text, text_input},
+        };
+
+        let first = text_input::Id::new("first");
+        let overlay = container(column![
+            text_input("", "").id(first.clone()).on_input(Id::new),
+            text_input("", "")
+                .id(text_input::Id::new("second"))
+                .on_input(Id::new),
+        ]);
+        let modal = Modal::new(text("Underlay"), Some(overlay)).id(Id::new("modal"));
+        let mut harness = Harness::<Id>::new(modal, Size::new(300.0, 200.0));
+
+        harness.operate(&mut modal::request(Id::new("modal")));
+
+        let mut focused = focusable::find_focused();
+        harness.operate(&mut focused);
+        assert!(matches!(focused.finish(), Outcome::Some(id) if id == Id::from(first)));
+    }
+
+    /// A message of the [`SelectionList`](crate::SelectionList) tests.
+    #[cfg(feature = "selection_list")]
+    #[derive(Clone, Debug, PartialEq)]
+    enum ListMessage {
+        Selected(usize),
+        MultiSelected(Vec<usize>),
+        Reordered(usize, usize),
+    }
+
+    /// The options of the [`SelectionList`](crate::SelectionList) tests, the
+    /// third one being disabled.
+    #[cfg(feature = "selection_list")]
+    const OPTIONS: [usize; 5] = [0, 1, 2, 3, 4];
+
+    /// Whether the [`OPTIONS`] are disabled.
+    #[cfg(feature = "selection_list")]
+    const DISABLED: [bool; 5] = [false, false, true, false, false];
+
+    /// Returns a [`Harness`] of a [`SelectionList`](crate::SelectionList) of
+    /// the [`OPTIONS`], with rows 20 pixels high.
+    #[cfg(feature = "selection_list")]
+    fn list_harness(
+        build: impl FnOnce(
+            crate::widgets::SelectionList<'static, usize, ListMessage, iced::Theme, super::Renderer>,
+        ) -> crate::widgets::SelectionList<
+            'static,
+            usize,
+            ListMessage,
+            iced::Theme,
+            super::Renderer,
+        >,
+    ) -> Harness<'static, ListMessage> {
+        use crate::SelectionList;
+
+        let list = SelectionList::new(&OPTIONS, |index, _| ListMessage::Selected(index))
+            .item_height(20.0)
+            .height(iced::Length::Fill)
+            .disabled_options(&DISABLED);
+
+        Harness::new(build(list), Size::new(200.0, 200.0))
+    }
+
+    /// Returns the center of the row of the list at `row`.
+    #[cfg(feature = "selection_list")]
+    fn row(row: usize) -> iced::Point {
+        iced::Point::new(100.0, row as f32 * 20.0 + 10.0)
+    }
+
+    #[cfg(feature = "selection_list")]
+    #[test]
+    fn selection_list_reuses_its_layout_until_its_options_change() {
+        use crate::{widgets::selection_list::State, SelectionList};
+        use iced::advanced::{layout::Limits, widget::Tree, Widget};
+
+        let list = |options| {
+            SelectionList::<_, _, iced::Theme, super::Renderer>::new(options, |index, _| {
+                ListMessage::Selected(index)
+            })
+        };
+        let cached = |tree: &Tree| tree.state.downcast_ref::<State>().layout.node().is_some();
+        let renderer = super::Renderer;
+        let limits = Limits::new(Size::ZERO, Size::new(200.0, 200.0));
+
+        let first = list(&OPTIONS);
+        let mut tree = Tree::new(&first as &dyn Widget<_, _, _>);
+        let _ = first.layout(&mut tree, &renderer, &limits);
+        assert!(cached(&tree));
+
+        list(&OPTIONS).diff(&mut tree);
+        assert!(cached(&tree));
+
+        list(&OPTIONS).item_height(24.0).diff(&mut tree);
+        assert!(!cached(&tree));
+
+        let _ = first.layout(&mut tree, &renderer, &limits);
+        list(&OPTIONS[..3]).diff(&mut tree);
+        assert!(!cached(&tree));
+    }
+
+    #[cfg(feature = "selection_list")]
+    #[test]
+    fn selection_list_selects_several_options() {
+        use iced::{
+            keyboard::{self, Key, Modifiers},
+            Event,
+        };
+
+        let mut harness = list_harness(|list| list.on_multi_select(ListMessage::MultiSelected));
+        let mut click_with = |row_index, modifiers| {
+            let _ = harness.event(Event::Keyboard(keyboard::Event::ModifiersChanged(
+                modifiers,
+            )));
+            let _ = harness.click(row(row_index));
+            harness.messages()
+        };
+
+        assert_eq!(
+            click_with(1, Modifiers::empty()),
+            vec![
+                ListMessage::MultiSelected(vec![1]),
+                ListMessage::Selected(1)
+            ]
+        );
+        assert_eq!(
+            click_with(3, Modifiers::COMMAND)[0],
+            ListMessage::MultiSelected(vec![1, 3])
+        );
+        assert_eq!(
+            click_with(1, Modifiers::COMMAND)[0],
+            ListMessage::MultiSelected(vec![3])
+        );
+        assert_eq!(
+            click_with(4, Modifiers::SHIFT)[0],
+            ListMessage::MultiSelected(vec![1, 3, 4])
+        );
+
+        let _ = harness.event(Event::Keyboard(keyboard::Event::ModifiersChanged(
+            Modifiers::empty(),
+        )));
+        let _ = harness.press_key_with(Key::Character("a".into()), Modifiers::COMMAND);
+        assert_eq!(
+            harness.messages(),
+            vec![ListMessage::MultiSelected(vec![0, 1, 3, 4])]
+        );
+    }
+
+    #[cfg(feature = "selection_list")]
+    #[test]
+    fn selection_list_navigates_with_the_keyboard() {
+        use iced::keyboard::{key::Named, Key};
+
+        fn select_after(
+            harness: &mut Harness<'_, ListMessage>,
+            keys: &[Named],
+        ) -> Vec<ListMessage> {
+            for key in keys {
+                let _ = harness.press_key(Key::Named(*key));
+            }
+            let _ = harness.press_key(Key::Named(Named::Enter));
+            harness.messages()
+        }
+
+        let mut harness = list_harness(|list| list);
+        assert_eq!(select_after(&mut harness, &[Named::ArrowDown]), vec![]);
+
+        let _ = harness.click(row(0));
+        assert_eq!(harness.messages(), vec![ListMessage::Selected(0)]);
+
+        let keys = [
+            (vec![Named::ArrowDown, Named::ArrowDown], 3),
+            (vec![Named::ArrowUp], 1),
+            (vec![Named::End], 4),
+            (vec![Named::Home], 0),
+        ];
+        for (keys, selected) in keys {
+            assert_eq!(
+                select_after(&mut harness, &keys),
+                vec![ListMessage::Selected(selected)]
+            );
+        }
+    }
+
+    #[cfg(feature = "selection_list")]
+    #[test]
+    fn selection_list_reorders_its_options() {
+        use iced::{mouse, Event};
+
+        let mut harness = list_harness(|list| list.on_reorder(ListMessage::Reordered));
+        let mut drag = |from: usize, to: f32| {
+            let _ = harness.move_cursor(row(from));
+            let _ = harness.event(Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Left,
+            )));
+            let _ = harness.move_cursor(iced::Point::new(100.0, (row(from).y + to) / 2.0));
+            let _ = harness.move_cursor(iced::Point::new(100.0, to));
+            let _ = harness.event(Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Left,
+            )));
+            harness.messages()
+        };
+
+        assert_eq!(
+            drag(0, 60.0),
+            vec![ListMessage::Selected(0), ListMessage::Reordered(0, 2)]
+        );
+        assert_eq!(
+            drag(4, 20.0),
+            vec![ListMessage::Selected(4), ListMessage::Reordered(4, 1)]
+        );
+        assert_eq!(drag(1, 22.0), vec![ListMessage::Selected(1)]);
+    }
+
+    #[cfg(feature = "selection_list")]
+    #[test]
+    fn selection_list_ignores_its_disabled_options() {
+        let mut harness = list_harness(|list| list);
+
+        let _ = harness.click(row(1));
+        assert_eq!(harness.messages(), vec![ListMessage::Selected(1)]);
+
+        let status = harness.click(row(2));
+        assert_eq!(status, event::Status::Ignored);
+        assert!(harness.messages().is_empty());
+    }
+
+    /// Returns a horizontal [`Wrap`] of spaces of the given sizes.
+    #[cfg(feature = "wrap")]
+    fn wrap_of(
+        sizes: &[(f32, f32)],
+    ) -> crate::widgets::wrap::Wrap<
+        'static,
+        (),
+        crate::widgets::wrap::direction::Horizontal,
+        iced::Theme,
+        super::Renderer,
+    > {
+        use crate::widgets::wrap::Wrap;
+        use iced::{widget::Space, Length};
+
+        sizes.iter().fold(Wrap::new(), |wrap, &(width, height)| {
+            wrap.push(Space::new(Length::Fixed(width), Length::Fixed(height)))
+        })
+    }
+
+    /// Returns the positions of the children of the widget.
+    #[cfg(feature = "wrap")]
+    fn positions(harness: &Harness<'_, ()>) -> Vec<(f32, f32)> {
+        harness
+            .layout()
+            .children()
+            .map(|child| (child.bounds().x, child.bounds().y))
+            .collect()
+    }
+
+    #[cfg(feature = "wrap")]
+    #[test]
+    fn wrap_breaks_lines_counting_the_spacing() {
+        let items = [(40.0, 20.0); 3];
+
+        let harness = Harness::<()>::new(wrap_of(&items).spacing(10.0), Size::new(100.0, 100.0));
+        assert_eq!(
+            positions(&harness),
+            vec![(0.0, 0.0), (50.0, 0.0), (0.0, 20.0)]
+        );
+        assert_eq!(harness.bounds().size(), Size::new(90.0, 40.0));
+
+        let harness = Harness::<()>::new(wrap_of(&items).spacing(10.0), Size::new(95.0, 100.0));
+        assert_eq!(
+            positions(&harness),
+            vec![(0.0, 0.0), (0.0, 20.0), (0.0, 40.0)]
+        );
+
+        let harness = Harness::<()>::new(
+            wrap_of(&items).spacing(10.0).line_spacing(5.0).padding(3.0),
+            Size::new(106.0, 100.0),
+        );
+        assert_eq!(
+            positions(&harness),
+            vec![(3.0, 3.0), (53.0, 3.0), (3.0, 28.0)]
+        );
+    }
+
+    #[cfg(feature = "wrap")]
+    #[test]
+    fn wrap_justifies_and_aligns_its_lines() {
+        use crate::widgets::wrap::Justify;
+        use iced::{Alignment, Length};
+
+        let items = [(40.0, 20.0); 3];
+        let wrap = |justify| {
+            wrap_of(&items)
+                .spacing(10.0)
+                .width_items(Length::Fill)
+                .justify_content(justify)
+        };
+        let size = Size::new(100.0, 100.0);
+
+        let harness = Harness::<()>::new(wrap(Justify::End), size);
+        assert_eq!(
+            positions(&harness),
+            vec![(10.0, 0.0), (60.0, 0.0), (60.0, 20.0)]
+        );
+
+        let harness = Harness::<()>::new(wrap(Justify::Center), size);
+        assert_eq!(
+            positions(&harness),
+            vec![(5.0, 0.0), (55.0, 0.0), (30.0, 20.0)]
+        );
+
+        let harness = Harness::<()>::new(wrap(Justify::SpaceBetween), size);
+        assert_eq!(
+            positions(&harness),
+            vec![(0.0, 0.0), (60.0, 0.0), (0.0, 20.0)]
+        );
+
+        let harness = Harness::<()>::new(
+            wrap(Justify::Start)
+                .height_items(Length::Fill)
+                .align_content(Alignment::Center),
+            size,
+        );
+        assert_eq!(
+            positions(&harness),
+            vec![(0.0, 30.0), (50.0, 30.0), (0.0, 50.0)]
+        );
+
+        let harness = Harness::<()>::new(
+            wrap_of(&[(40.0, 20.0), (40.0, 10.0)]).align_items(Alignment::Center),
+            size,
+        );
+        assert_eq!(positions(&harness), vec![(0.0, 0.0), (40.0, 5.0)]);
+    }
+
+    #[cfg(feature = "wrap")]
+    #[test]
+    fn wrap_reverses_its_elements() {
+        use iced::Length;
+
+        let items = [(40.0, 20.0); 3];
+        let size = Size::new(100.0, 100.0);
+
+        let harness = Harness::<()>::new(wrap_of(&items).spacing(10.0).reverse(true), size);
+        assert_eq!(
+            positions(&harness),
+            vec![(0.0, 20.0), (50.0, 0.0), (0.0, 0.0)]
+        );
+
+        let harness = Harness::<()>::new(
+            wrap_of(&items)
+                .spacing(10.0)
+                .width_items(Length::Fill)
+                .rtl(true),
+            size,
+        );
+        assert_eq!(
+            positions(&harness),
+            vec![(60.0, 0.0), (10.0, 0.0), (60.0, 20.0)]
+        );
+    }
+
+    #[cfg(feature = "wrap")]
+    #[test]
+    fn wrap_sizes_its_elements_uniformly() {
+        use crate::widgets::wrap::ItemSizing;
+
+        let items = [(20.0, 10.0), (40.0, 10.0), (30.0, 10.0)];
+        let widths = |sizing| {
+            let harness = Harness::<()>::new(
+                wrap_of(&items).spacing(10.0).item_sizing(sizing),
+                Size::new(100.0, 100.0),
+            );
+            harness
+                .layout()
+                .children()
+                .map(|child| child.bounds().width)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(widths(ItemSizing::Natural), vec![20.0, 40.0, 30.0]);
+        assert_eq!(widths(ItemSizing::UniformPerLine), vec![40.0, 40.0, 30.0]);
+        assert_eq!(widths(ItemSizing::Uniform), vec![40.0, 40.0, 40.0]);
+    }
+
+    #[cfg(feature = "menu")]
+    #[test]
+    fn menu_bar_opens_only_on_request_when_focused() {
+        use crate::widgets::menu::{Item, Menu, MenuBar};
+        use iced::{
+            advanced::widget::{operation::focusable, Id},
+            keyboard::{key::Named, Key},
+            widget::text,
+        };
+
+        let root = |label| Item::with_menu(text(label), Menu::new(vec![Item::new(text("Open"))]));
+        let id = Id::new("menu");
+        let menu_bar = MenuBar::new(vec![root("File"), root("Edit")]).id(id.clone());
+        let mut harness = Harness::<()>::new(menu_bar, Size::new(400.0, 300.0));
+
+        harness.operate(&mut focusable::focus(id));
+        assert!(!harness.has_overlay());
+
+        let _ = harness.press_key(Key::Named(Named::ArrowRight));
+        assert!(!harness.has_overlay());
+
+        let _ = harness.press_key(Key::Named(Named::Enter));
+        assert!(harness.has_overlay());
+
+        let _ = harness.press_key(Key::Named(Named::Escape));
+        assert!(!harness.has_overlay());
+
+        let _ = harness.press_key(Key::Named(Named::ArrowDown));
+        assert!(harness.has_overlay());
+
+        harness.operate(&mut focusable::focus(Id::new("other")));
+        assert!(!harness.has_overlay());
+    }
+
+    #[test]
+    fn widgets_describe_themselves() {
+        use crate::{
+            accessibility::{self, Action, Node, Role},
+            widgets::menu::{Entry, Item, Menu, MenuBar},
+            TabBar, TabLabel,
+        };
+        use iced::{
+            advanced::widget::{operation::Outcome, Id, Operation},
+            widget::column,
+        };
+
+        #[derive(Clone, Debug)]
+        enum Message {
+            Select,
+            Open,
+            Described(Vec<(Option<Id>, Node)>),
+        }
+
+        let tab_bar = TabBar::new(|_| Message::Select)
+            .push(0, TabLabel::Text(String::from("One")))
+            .push(1, TabLabel::Text(String::from("Two")))
+            .set_active_tab(&1)
+            .id(Id::new("tabs"));
+        let menu_bar = MenuBar::new(vec![Item::with_menu(
+            Entry::root("File"),
+            Menu::new(vec![Item::new(Entry::new("Open").on_press(Message::Open))]),
+        )]);
+        let mut harness =
+            Harness::<Message>::new(column![tab_bar, menu_bar], Size::new(400.0, 300.0));
+
+        let mut operation = accessibility::request(Message::Described);
+        harness.operate(&mut operation);
+        let nodes = match operation.finish() {
+            Outcome::Some(Message::Described(nodes)) => nodes,
+            _ => Vec::new(),
+        };
+
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0].0, Some(Id::new("tabs")));
+
+        let tabs = &nodes[0].1;
+        assert_eq!(tabs.role, Role::TabList);
+        assert_eq!(
+            tabs.children
+                .iter()
+                .map(|tab| (tab.name.as_deref(), tab.selected))
+                .collect::<Vec<_>>(),
+            [(Some("One"), Some(false)), (Some("Two"), Some(true))]
+        );
+        assert!(tabs.children[0].actions.contains(&Action::Select));
+        assert!(!tabs.children[0].actions.contains(&Action::Close));
+
+        let menu = &nodes[1].1;
+        assert_eq!(menu.role, Role::MenuBar);
+        assert_eq!(menu.expanded, Some(false));
+        assert_eq!(
+            menu.children,
+            [Node::new(Role::MenuItem)
+                .name("File")
+                .action(Action::Expand)]
+        );
+    }
+
+    #[test]
+    fn badge_shows_its_icon_before_its_content() {
+        use crate::{Badge, Bootstrap};
+        use iced::widget::text;
+
+        let badge: Badge<'_, (), iced::Theme, super::Renderer> =
+            Badge::new(text("New")).icon(Bootstrap::Star);
+        let harness = Harness::<()>::new(badge, Size::new(200.0, 100.0));
+
+        let content = harness.layout().children().next();
+        let parts: Vec<_> = content
+            .into_iter()
+            .flat_map(iced::advanced::Layout::children)
+            .map(|part| part.bounds())
+            .collect();
+
+        assert_eq!(parts.len(), 2);
+        assert!(parts[0].width > 0.0);
+        assert!(parts[0].x + parts[0].width < parts[1].x);
+    }
+}
@@ -0,0 +1,757 @@
+//! Rasterize widgets to images, to compare their appearance with reference
+//! snapshots.
+//!
+//! A [`Harness`] mounting a widget with the snapshot [`Renderer`] draws it on
+//! a [`Snapshot`] of the size of its window. The quads are rasterized with
+//! their background, border and radii, while the text is drawn as a box per
+//! character, measured like with the headless renderer of the
+//! [`testing`](super) module, so that the snapshots do not depend on the
+//! fonts of the platform. Shadows are drawn without blur.
+//!
+//! [`Snapshot::check`] compares a snapshot with the PNG image saved at a
+//! path. The image is written when it does not exist yet, or when the
+//! `ICED_AW_UPDATE_SNAPSHOTS` environment variable is set, which updates the
+//! references after an intended change of appearance.
+//!
+//! *This API requires the following crate features to be activated: `snapshot`*
+//!
+//! # Example
+//! ```ignore
+//! # use iced::{widget::Text, Size, Theme};
+//! # use iced_aw::{testing::{snapshot::Renderer, Harness}, Badge};
+//! #
+//! for (name, theme) in [("light", Theme::Light), ("dark", Theme::Dark)] {
+//!     let badge = Badge::new(Text::new("3"));
+//!     let mut harness =
+//!         Harness::<(), Theme, Renderer>::new(badge, Size::new(40.0, 30.0)).theme(theme);
+//!
+//!     let result = harness
+//!         .snapshot()
+//!         .check(format!("tests/snapshots/badge_{name}.png"));
+//!     assert!(result.is_ok(), "{result:?}");
+//! }
+//! ```
+
+use super::{Harness, Paragraph};
+
+#[cfg(feature = "svg")]
+use iced::advanced::svg;
+use iced::{
+    advanced::{
+        renderer,
+        text::{self, Paragraph as _, Text},
+    },
+    alignment::{Horizontal, Vertical},
+    gradient::Linear,
+    Background, Color, Font, Gradient, Pixels, Point, Rectangle, Size, Transformation,
+};
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+};
+use tiny_skia::{FillRule, Mask, Paint, PathBuilder, Pixmap, Shader, Transform};
+
+/// The environment variable updating the saved snapshots when set.
+const UPDATE_VARIABLE: &str = "ICED_AW_UPDATE_SNAPSHOTS";
+
+/// The largest difference of a color channel between two pixels still
+/// considered equal, absorbing the rounding of the anti-aliasing.
+const TOLERANCE: u8 = 2;
+
+/// A renderer rasterizing the widgets on a [`Snapshot`].
+///
+/// It measures the text like the headless [`Renderer`](super::Renderer), and
+/// draws nothing outside of [`Harness::snapshot`].
+#[derive(Debug)]
+pub struct Renderer {
+    /// The image drawn on, while a snapshot is taken.
+    pixmap: Option<Pixmap>,
+    /// The bounds of the current layer on the image.
+    clip: Rectangle,
+    /// The transformation of the current layer.
+    transformation: Transformation,
+    /// The characters of the current layer, drawn over its quads when the
+    /// layer ends.
+    glyphs: Vec<Glyph>,
+}
+
+/// The box drawn for a character.
+#[derive(Clone, Copy, Debug)]
+struct Glyph {
+    /// The bounds of the box on the image.
+    bounds: Rectangle,
+    /// The color of the text.
+    color: Color,
+    /// The bounds the text is clipped to on the image.
+    clip: Rectangle,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self {
+            pixmap: None,
+            clip: Rectangle::with_size(Size::INFINITY),
+            transformation: Transformation::IDENTITY,
+            glyphs: Vec::new(),
+        }
+    }
+}
+
+impl Renderer {
+    /// Fills the `path` with the `paint`, clipped to the `clip` bounds.
+    fn fill(&mut self, path: &tiny_skia::Path, paint: &Paint<'_>, rule: FillRule, clip: Rectangle) {
+        let Some(pixmap) = &mut self.pixmap else {
+            return;
+        };
+        let image = Rectangle::with_size(Size::new(pixmap.width() as f32, pixmap.height() as f32));
+        let Some(clip) = clip.intersection(&image) else {
+            return;
+        };
+
+        let mask = (clip != image)
+            .then(|| {
+                let mut mask = Mask::new(pixmap.width(), pixmap.height())?;
+                let rect = tiny_skia::Rect::from_xywh(clip.x, clip.y, clip.width, clip.height)?;
+                mask.fill_path(
+                    &PathBuilder::from_rect(rect),
+                    FillRule::Winding,
+                    false,
+                    Transform::identity(),
+                );
+                Some(mask)
+            })
+            .flatten();
+
+        pixmap.fill_path(path, paint, rule, Transform::identity(), mask.as_ref());
+    }
+
+    /// Draws the boxes of the characters of the current layer.
+    fn flush_glyphs(&mut self) {
+        for glyph in std::mem::take(&mut self.glyphs) {
+            let Some(path) = rounded_rectangle(glyph.bounds, [0.0; 4]) else {
+                continue;
+            };
+
+            self.fill(&path, &solid(glyph.color), FillRule::Winding, glyph.clip);
+        }
+    }
+
+    /// Queues the boxes of the characters of the `paragraph` drawn at
+    /// `position`.
+    fn queue_paragraph(
+        &mut self,
+        paragraph: &Paragraph,
+        position: Point,
+        color: Color,
+        clip_bounds: Rectangle,
+    ) {
+        if self.pixmap.is_none() || color.a <= 0.0 {
+            return;
+        }
+
+        let size = paragraph.min_bounds();
+        let x = match paragraph.horizontal_alignment {
+            Horizontal::Left => position.x,
+            Horizontal::Center => position.x - size.width / 2.0,
+            Horizontal::Right => position.x - size.width,
+        };
+        let y = match paragraph.vertical_alignment {
+            Vertical::Top => position.y,
+            Vertical::Center => position.y - size.height / 2.0,
+            Vertical::Bottom => position.y - size.height,
+        };
+        let cell = paragraph.cell();
+        let Some(clip) = (clip_bounds * self.transformation).intersection(&self.clip) else {
+            return;
+        };
+
+        for (row, line) in paragraph.content.lines().enumerate() {
+            for (column, character) in line.chars().enumerate() {
+                if character.is_whitespace() {
+                    continue;
+                }
+
+                let bounds = Rectangle {
+                    x: x + cell.width * (column as f32 + 0.125),
+                    y: y + cell.height * row as f32 + (cell.height - paragraph.size) / 2.0,
+                    width: cell.width * 0.75,
+                    height: paragraph.size,
+                };
+
+                self.glyphs.push(Glyph {
+                    bounds: bounds * self.transformation,
+                    color,
+                    clip,
+                });
+            }
+        }
+    }
+}
+
+impl renderer::Renderer for Renderer {
+    fn with_layer(&mut self, bounds: Rectangle, f: impl FnOnce(&mut Self)) {
+        let clip = self.clip;
+        let glyphs = std::mem::take(&mut self.glyphs);
+
+        self.clip = (bounds * self.transformation)
+            .intersection(&clip)
+            .unwrap_or_default();
+        f(self);
+        self.flush_glyphs();
+
+        self.clip = clip;
+        self.glyphs = glyphs;
+    }
+
+    fn with_transformation(&mut self, transformation: Transformation, f: impl FnOnce(&mut Self)) {
+        let previous = self.transformation;
+
+        self.transformation = previous * transformation;
+        f(self);
+        self.transformation = previous;
+    }
+
+    fn fill_quad(&mut self, quad: renderer::Quad, background: impl Into<Background>) {
+        if self.pixmap.is_none() {
+            return;
+        }
+
+        let scale = self.transformation.scale_factor();
+        let bounds = quad.bounds * self.transformation;
+        let radius: [f32; 4] = quad.border.radius.into();
+        let radius = radius.map(|radius| radius * scale);
+        let clip = self.clip;
+
+        if quad.shadow.color.a > 0.0 {
+            let shadow = bounds + quad.shadow.offset * scale;
+
+            if let Some(path) = rounded_rectangle(shadow, radius) {
+                self.fill(&path, &solid(quad.shadow.color), FillRule::Winding, clip);
+            }
+        }
+
+        if let Some(path) = rounded_rectangle(bounds, radius) {
+            let paint = Paint {
+                shader: shader(background.into(), bounds),
+                anti_alias: true,
+                ..Paint::default()
+            };
+
+            self.fill(&path, &paint, FillRule::Winding, clip);
+        }
+
+        let width = quad.border.width * scale;
+
+        if width > 0.0 && quad.border.color.a > 0.0 {
+            let inner = Rectangle {
+                x: bounds.x + width,
+                y: bounds.y + width,
+                width: (bounds.width - 2.0 * width).max(0.0),
+                height: (bounds.height - 2.0 * width).max(0.0),
+            };
+            let mut builder = PathBuilder::new();
+
+            for (bounds, radius) in [
+                (bounds, radius),
+                (inner, radius.map(|radius| (radius - width).max(0.0))),
+            ] {
+                if let Some(path) = rounded_rectangle(bounds, radius) {
+                    builder.push_path(&path);
+                }
+            }
+
+            if let Some(path) = builder.finish() {
+                self.fill(&path, &solid(quad.border.color), FillRule::EvenOdd, clip);
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        if let Some(pixmap) = &mut self.pixmap {
+            pixmap.fill(tiny_skia::Color::TRANSPARENT);
+        }
+        self.glyphs.clear();
+    }
+}
+
+impl text::Renderer for Renderer {
+    type Font = Font;
+    type Paragraph = Paragraph;
+    type Editor = ();
+
+    const ICON_FONT: Font = Font::DEFAULT;
+    const CHECKMARK_ICON: char = '✓';
+    const ARROW_DOWN_ICON: char = '▼';
+
+    fn default_font(&self) -> Font {
+        Font::default()
+    }
+
+    fn default_size(&self) -> Pixels {
+        Pixels(16.0)
+    }
+
+    fn load_font(&mut self, _font: Cow<'static, [u8]>) {}
+
+    fn fill_paragraph(
+        &mut self,
+        paragraph: &Paragraph,
+        position: Point,
+        color: Color,
+        clip_bounds: Rectangle,
+    ) {
+        self.queue_paragraph(paragraph, position, color, clip_bounds);
+    }
+
+    fn fill_editor(
+        &mut self,
+        _editor: &Self::Editor,
+        _position: Point,
+        _color: Color,
+        _clip_bounds: Rectangle,
+    ) {
+    }
+
+    fn fill_text(
+        &mut self,
+        text: Text<'_, Font>,
+        position: Point,
+        color: Color,
+        clip_bounds: Rectangle,
+    ) {
+        self.queue_paragraph(&Paragraph::with_text(text), position, color, clip_bounds);
+    }
+}
+
+#[cfg(feature = "svg")]
+impl svg::Renderer for Renderer {
+    fn dimensions(&self, _handle: &svg::Handle) -> Size<u32> {
+        Size::new(16, 16)
+    }
+
+    /// Draws the bounds of the image, filled with its color or in gray.
+    fn draw(&mut self, _handle: svg::Handle, color: Option<Color>, bounds: Rectangle) {
+        let color = color.unwrap_or(Color::from_rgb(0.5, 0.5, 0.5));
+
+        if let Some(path) = rounded_rectangle(bounds * self.transformation, [0.0; 4]) {
+            self.fill(&path, &solid(color), FillRule::Winding, self.clip);
+        }
+    }
+}
+
+impl<Message, Theme> Harness<'_, Message, Theme, Renderer> {
+    /// Draws the widget and its overlay on a transparent [`Snapshot`] of the
+    /// size of the window.
+    ///
+    /// Returns an empty snapshot if the window has no area.
+    pub fn snapshot(&mut self) -> Snapshot {
+        let pixmap = Pixmap::new(
+            self.size.width.ceil() as u32,
+            self.size.height.ceil() as u32,
+        );
+
+        self.renderer = Renderer {
+            pixmap,
+            clip: Rectangle::with_size(self.size),
+            ..Renderer::default()
+        };
+        self.draw();
+        self.renderer.flush_glyphs();
+
+        Snapshot {
+            pixmap: self.renderer.pixmap.take(),
+        }
+    }
+}
+
+/// An image of a widget drawn by the snapshot [`Renderer`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot {
+    /// The pixels of the image, if it has an area.
+    pixmap: Option<Pixmap>,
+}
+
+impl Snapshot {
+    /// Returns the size of the image in pixels.
+    #[must_use]
+    pub fn size(&self) -> Size<u32> {
+        self.pixmap.as_ref().map_or(Size::new(0, 0), |pixmap| {
+            Size::new(pixmap.width(), pixmap.height())
+        })
+    }
+
+    /// Returns the color of the pixel at the given coordinates, if they are
+    /// in the image.
+    #[must_use]
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Color> {
+        let color = self.pixmap.as_ref()?.pixel(x, y)?.demultiply();
+
+        Some(Color::from_rgba8(
+            color.red(),
+            color.green(),
+            color.blue(),
+            f32::from(color.alpha()) / 255.0,
+        ))
+    }
+
+    /// Returns the number of pixels differing between the two snapshots, or
+    /// `None` if they do not have the same size.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Option<usize> {
+        if self.size() != other.size() {
+            return None;
+        }
+
+        let pixels = |snapshot: &Self| {
+            snapshot
+                .pixmap
+                .as_ref()
+                .map_or(&[][..], |pixmap| pixmap.data())
+                .chunks_exact(4)
+                .map(|pixel| [pixel[0], pixel[1], pixel[2], pixel[3]])
+                .collect::<Vec<_>>()
+        };
+
+        Some(
+            pixels(self)
+                .iter()
+                .zip(pixels(other).iter())
+                .filter(|(a, b)| {
+                    a.iter()
+                        .zip(b.iter())
+                        .any(|(a, b)| a.abs_diff(*b) > TOLERANCE)
+                })
+                .count(),
+        )
+    }
+
+    /// Compares the snapshot with the PNG image saved at the `path`.
+    ///
+    /// The snapshot is saved at the `path` if no image exists yet, or if the
+    /// `ICED_AW_UPDATE_SNAPSHOTS` environment variable is set. When they
+    /// differ, the snapshot is saved next to the image with the `.new.png`
+    /// extension, to be reviewed.
+    ///
+    /// # Errors
+    /// Returns an error if the snapshot differs from the image, or if the
+    /// image cannot be read or written.
+    pub fn check(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        if std::env::var_os(UPDATE_VARIABLE).is_some() || !path.exists() {
+            return self.save(path);
+        }
+
+        let expected = Self::load(path)?;
+        let result = match self.difference(&expected) {
+            None => Err(Error::Size {
+                expected: expected.size(),
+                actual: self.size(),
+            }),
+            Some(0) => Ok(()),
+            Some(pixels) => Err(Error::Pixels(pixels)),
+        };
+
+        if result.is_err() {
+            self.save(path.with_extension("new.png"))?;
+        }
+
+        result
+    }
+
+    /// Saves the snapshot as a PNG image at the `path`, creating its
+    /// directory if needed.
+    ///
+    /// # Errors
+    /// Returns an error if the image cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let pixmap = self.pixmap.as_ref().ok_or(Error::Empty)?;
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory).map_err(Error::Io)?;
+        }
+
+        let data = pixmap
+            .encode_png()
+            .map_err(|error| Error::Png(error.to_string()))?;
+
+        fs::write(path, data).map_err(Error::Io)
+    }
+
+    /// Loads a snapshot from the PNG image at the `path`.
+    ///
+    /// # Errors
+    /// Returns an error if the image cannot be read or decoded.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let data = fs::read(path).map_err(Error::Io)?;
+        let pixmap = Pixmap::decode_png(&data).map_err(|error| Error::Png(error.to_string()))?;
+
+        Ok(Self {
+            pixmap: Some(pixmap),
+        })
+    }
+}
+
+/// The error returned when checking, saving or loading a [`Snapshot`].
+#[derive(Debug)]
+pub enum Error {
+    /// The image could not be read or written.
+    Io(io::Error),
+    /// The image could not be encoded or decoded.
+    Png(String),
+    /// The snapshot has no area, and cannot be saved.
+    Empty,
+    /// The snapshot and the image do not have the same size.
+    Size {
+        /// The size of the saved image.
+        expected: Size<u32>,
+        /// The size of the snapshot.
+        actual: Size<u32>,
+    },
+    /// The given number of pixels differ between the snapshot and the image.
+    Pixels(usize),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "snapshot file error: {error}"),
+            Self::Png(error) => write!(f, "invalid snapshot image: {error}"),
+            Self::Empty => f.write_str("empty snapshot"),
+            Self::Size { expected, actual } => write!(
+                f,
+                "snapshot of {}x{} pixels instead of {}x{}",
+                actual.width, actual.height, expected.width, expected.height
+            ),
+            Self::Pixels(pixels) => write!(f, "{pixels} pixels differ from the snapshot"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Returns a paint filling with the `color`.
+fn solid(color: Color) -> Paint<'static> {
+    Paint {
+        shader: Shader::SolidColor(into_color(color)),
+        anti_alias: true,
+        ..Paint::default()
+    }
+}
+
+/// Returns the shader filling the `bounds` with the `background`.
+fn shader(background: Background, bounds: Rectangle) -> Shader<'static> {
+    match background {
+        Background::Color(color) => Shader::SolidColor(into_color(color)),
+        Background::Gradient(Gradient::Linear(Linear { angle, stops })) => {
+            let (start, end) = angle.to_distance(&bounds);
+            let first = stops
+                .iter()
+                .flatten()
+                .next()
+                .map_or(Color::BLACK, |stop| stop.color);
+            let stops: Vec<_> = stops
+                .into_iter()
+                .flatten()
+                .map(|stop| tiny_skia::GradientStop::new(stop.offset, into_color(stop.color)))
+                .collect();
+
+            tiny_skia::LinearGradient::new(
+                tiny_skia::Point::from_xy(start.x, start.y),
+                tiny_skia::Point::from_xy(end.x, end.y),
+                stops,
+                tiny_skia::SpreadMode::Pad,
+                Transform::identity(),
+            )
+            // A gradient of a single color, or along a null distance.
+            .unwrap_or_else(|| Shader::SolidColor(into_color(first)))
+        }
+    }
+}
+
+/// Converts a color to the color of the image.
+fn into_color(color: Color) -> tiny_skia::Color {
+    tiny_skia::Color::from_rgba(color.r, color.g, color.b, color.a)
+        .unwrap_or(tiny_skia::Color::TRANSPARENT)
+}
+
+/// Returns the outline of the `bounds` with the radii of their top left, top
+/// right, bottom right and bottom left corners, or `None` if the bounds have
+/// no area.
+fn rounded_rectangle(bounds: Rectangle, radius: [f32; 4]) -> Option<tiny_skia::Path> {
+    /// The distance of the control points of a cubic curve approximating a
+    /// quarter of a circle, relative to its radius.
+    const KAPPA: f32 = 0.552_284_8;
+
+    if bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return None;
+    }
+
+    let max = bounds.width.min(bounds.height) / 2.0;
+    let [top_left, top_right, bottom_right, bottom_left] =
+        radius.map(|radius| radius.clamp(0.0, max));
+    let Rectangle {
+        x,
+        y,
+        width,
+        height,
+    } = bounds;
+    let (right, bottom) = (x + width, y + height);
+    let mut builder = PathBuilder::new();
+
+    builder.move_to(x + top_left, y);
+    builder.line_to(right - top_right, y);
+    builder.cubic_to(
+        right - top_right * (1.0 - KAPPA),
+        y,
+        right,
+        y + top_right * (1.0 - KAPPA),
+        right,
+        y + top_right,
+    );
+    builder.line_to(right, bottom - bottom_right);
+    builder.cubic_to(
+        right,
+        bottom - bottom_right * (1.0 - KAPPA),
+        right - bottom_right * (1.0 - KAPPA),
+        bottom,
+        right - bottom_right,
+        bottom,
+    );
+    builder.line_to(x + bottom_left, bottom);
+    builder.cubic_to(
+        x + bottom_left * (1.0 - KAPPA),
+        bottom,
+        x,
+        bottom - bottom_left * (1.0 - KAPPA),
+        x,
+        bottom - bottom_left,
+    );
+    builder.line_to(x, y + top_left);
+    builder.cubic_to(
+        x,
+        y + top_left * (1.0 - KAPPA),
+        x + top_left * (1.0 - KAPPA),
+        y,
+        x + top_left,
+        y,
+    );
+    builder.close();
+
+    builder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Renderer, Snapshot};
+    use crate::testing::Harness;
+    use iced::{
+        advanced::renderer::{self, Renderer as _},
+        Border, Color, Rectangle, Size, Theme,
+    };
+
+    #[test]
+    fn quads_are_rasterized() {
+        let mut renderer = Renderer {
+            pixmap: tiny_skia::Pixmap::new(40, 40),
+            ..Renderer::default()
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle::new([5.0, 5.0].into(), Size::new(30.0, 30.0)),
+                border: Border {
+                    color: Color::BLACK,
+                    width: 2.0,
+                    radius: 10.0.into(),
+                },
+                ..renderer::Quad::default()
+            },
+            Color::WHITE,
+        );
+        renderer.with_layer(
+            Rectangle::new([0.0, 0.0].into(), Size::new(20.0, 40.0)),
+            |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle::new([15.0, 15.0].into(), Size::new(10.0, 10.0)),
+                        ..renderer::Quad::default()
+                    },
+                    Color::from_rgb(1.0, 0.0, 0.0),
+                );
+            },
+        );
+
+        let snapshot = Snapshot {
+            pixmap: renderer.pixmap.take(),
+        };
+
+        assert_eq!(snapshot.size(), Size::new(40, 40));
+        // The rounded corner is left transparent.
+        assert_eq!(snapshot.pixel(6, 6), Some(Color::TRANSPARENT));
+        assert_eq!(snapshot.pixel(20, 5), Some(Color::BLACK));
+        assert_eq!(snapshot.pixel(30, 20), Some(Color::WHITE));
+        // The red quad is clipped by its layer.
+        assert_eq!(snapshot.pixel(17, 20), Some(Color::from_rgb(1.0, 0.0, 0.0)));
+        assert_eq!(snapshot.pixel(22, 20), Some(Color::WHITE));
+    }
+
+    #[cfg(feature = "badge")]
+    #[test]
+    fn themes_are_compared() {
+        use crate::Badge;
+        use iced::widget::Text;
+
+        let snapshot = |theme: Theme| {
+            let badge = Badge::new(Text::new("12"));
+            let mut harness =
+                Harness::<(), Theme, Renderer>::new(badge, Size::new(60.0, 40.0)).theme(theme);
+            let bounds = harness.bounds();
+
+            (harness.snapshot(), bounds)
+        };
+        let (light, bounds) = snapshot(Theme::Light);
+        let (dark, _) = snapshot(Theme::Dark);
+
+        let x = (bounds.x + 4.0) as u32;
+        let y = bounds.center_y() as u32;
+        let background =
+            |theme: &Theme| Some(theme.extended_palette().background.weak.color.into_rgba8());
+
+        assert_eq!(
+            light.pixel(x, y).map(Color::into_rgba8),
+            background(&Theme::Light)
+        );
+        assert_eq!(
+            dark.pixel(x, y).map(Color::into_rgba8),
+            background(&Theme::Dark)
+        );
+        assert!(light.difference(&dark).is_some_and(|pixels| pixels > 0));
+        assert_eq!(light.difference(&snapshot(Theme::Light).0), Some(0));
+
+        let path = std::env::temp_dir()
+            .join(format!("iced_aw_snapshot_{}", std::process::id()))
+            .join("badge.png");
+        assert!(light.check(&path).is_ok());
+        assert!(light.check(&path).is_ok());
+        assert!(matches!(dark.check(&path), Err(super::Error::Pixels(_))));
+        assert_eq!(
+            Snapshot::load(path.with_extension("new.png")).ok(),
+            Some(dark)
+        );
+
+        if let Some(directory) = path.parent() {
+            assert!(std::fs::remove_dir_all(directory).is_ok());
+        }
+    }
+}
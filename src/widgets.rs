@@ -10,6 +10,20 @@ pub mod common;
 
 pub use common::InnerBounds;
 
+#[cfg(feature = "anchored_overlay")]
+pub mod anchored_overlay;
+#[cfg(feature = "anchored_overlay")]
+/// A widget showing any content in an overlay anchored to a target widget.
+pub type AnchoredOverlay<'a, Message, Theme, Renderer> =
+    anchored_overlay::AnchoredOverlay<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "accordion")]
+pub mod accordion;
+#[cfg(feature = "accordion")]
+/// A container of titled sections that expand and collapse.
+pub type Accordion<'a, Message, Theme, Renderer> =
+    accordion::Accordion<'a, Message, Theme, Renderer>;
+
 #[cfg(feature = "badge")]
 pub mod badge;
 #[cfg(feature = "badge")]
@@ -29,6 +43,12 @@ pub mod card;
 /// A card consisting of a head, body and optional foot.
 pub type Card<'a, Message, Theme, Renderer> = card::Card<'a, Message, Theme, Renderer>;
 
+#[cfg(feature = "carousel")]
+pub mod carousel;
+#[cfg(feature = "carousel")]
+/// A container showing one of its pages at a time.
+pub type Carousel<'a, Message, Theme, Renderer> = carousel::Carousel<'a, Message, Theme, Renderer>;
+
 #[cfg(feature = "color_picker")]
 pub mod color_picker;
 #[cfg(feature = "color_picker")]
@@ -45,6 +65,35 @@ pub mod date_picker;
 #[cfg(feature = "date_picker")]
 pub use date_picker::DatePicker;
 
+#[cfg(feature = "dock")]
+pub mod dock;
+#[cfg(feature = "dock")]
+/// An area docking panels on its edges, in tabs or floating.
+pub type DockArea<'a, Message, Theme, Renderer> = dock::DockArea<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "drag_and_drop")]
+pub mod drag_and_drop;
+#[cfg(feature = "drag_and_drop")]
+/// An element whose payload is dragged to drop targets.
+pub type Draggable<'a, P, Message, Theme, Renderer> =
+    drag_and_drop::Draggable<'a, P, Message, Theme, Renderer>;
+#[cfg(feature = "drag_and_drop")]
+/// An element receiving the payloads dropped on it.
+pub type DropTarget<'a, P, Message, Theme, Renderer> =
+    drag_and_drop::DropTarget<'a, P, Message, Theme, Renderer>;
+
+#[cfg(feature = "drawer")]
+pub mod drawer;
+#[cfg(feature = "drawer")]
+/// A container sliding a panel in from an edge of its content.
+pub type Drawer<'a, Message, Theme, Renderer> = drawer::Drawer<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "file_browser")]
+pub mod file_browser;
+#[cfg(feature = "file_browser")]
+/// A browser of the file system selecting files.
+pub type FileBrowser<'a, Message, Theme> = file_browser::FileBrowser<'a, Message, Theme>;
+
 #[cfg(feature = "selection_list")]
 pub mod selection_list;
 #[cfg(feature = "selection_list")]
@@ -75,12 +124,76 @@ pub mod modal;
 /// A modal content as an overlay.
 pub type Modal<'a, Message, Theme, Renderer> = modal::Modal<'a, Message, Theme, Renderer>;
 
+#[cfg(feature = "month_calendar")]
+pub mod month_calendar;
+#[cfg(feature = "month_calendar")]
+/// A calendar showing the days of a month with the events lasting over them.
+pub type MonthCalendar<'a, Message, Theme> = month_calendar::MonthCalendar<'a, Message, Theme>;
+
+#[cfg(feature = "navigation_rail")]
+pub mod navigation_rail;
+#[cfg(feature = "navigation_rail")]
+/// A vertical rail of destinations to navigate between the views of an application.
+pub type NavigationRail<'a, Message, Id, Theme, Renderer> =
+    navigation_rail::NavigationRail<'a, Message, Id, Theme, Renderer>;
+
+#[cfg(feature = "popover")]
+pub mod popover;
+#[cfg(feature = "popover")]
+/// A widget showing content next to a target widget.
+pub type Popover<'a, Message, Theme, Renderer> = popover::Popover<'a, Message, Theme, Renderer>;
+
 #[cfg(feature = "split")]
 pub mod split;
 #[cfg(feature = "split")]
 /// A split can divide the available space by half to display two different elements.
 pub type Split<'a, Message, Theme, Renderer> = split::Split<'a, Message, Theme, Renderer>;
 
+#[cfg(feature = "table")]
+pub mod table;
+#[cfg(feature = "table")]
+/// A table showing records in rows, with sortable columns.
+pub type Table<'a, T, Message, Theme, Renderer> = table::Table<'a, T, Message, Theme, Renderer>;
+
+#[cfg(feature = "tag_input")]
+pub mod tag_input;
+#[cfg(feature = "tag_input")]
+/// A field where typed text becomes removable chips.
+pub type TagInput<'a, Message, Theme, Renderer> = tag_input::TagInput<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "search_bar")]
+pub mod search_bar;
+#[cfg(feature = "search_bar")]
+/// A text input for search queries with a list of suggestions.
+pub type SearchBar<'a, Message, Theme, Renderer> =
+    search_bar::SearchBar<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "chip")]
+pub mod chip;
+#[cfg(feature = "chip")]
+/// A compact label that can be pressed, toggled or deleted.
+pub type Chip<'a, Message, Theme, Renderer> = chip::Chip<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "pin_input")]
+pub mod pin_input;
+#[cfg(feature = "pin_input")]
+/// A row of boxes each holding one character of a code.
+pub type PinInput<'a, Message, Theme, Renderer> = pin_input::PinInput<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "masked_input")]
+pub mod masked_input;
+#[cfg(feature = "masked_input")]
+/// A text input following a format mask.
+pub type MaskedInput<'a, Message, Theme, Renderer> =
+    masked_input::MaskedInput<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "password_input")]
+pub mod password_input;
+#[cfg(feature = "password_input")]
+/// A secure text input with a button revealing the password and a strength meter.
+pub type PasswordInput<'a, Message, Theme, Renderer> =
+    password_input::PasswordInput<'a, Message, Theme, Renderer>;
+
 #[cfg(feature = "tab_bar")]
 pub mod tab_bar;
 #[cfg(feature = "tab_bar")]
@@ -105,6 +218,22 @@ pub mod time_picker;
 #[cfg(feature = "time_picker")]
 pub use time_picker::TimePicker;
 
+#[cfg(feature = "toast")]
+pub mod toast;
+#[cfg(feature = "toast")]
+/// A container showing transient notifications over its content.
+pub type ToastManager<'a, Message, Theme, Renderer> =
+    toast::ToastManager<'a, Message, Theme, Renderer>;
+#[cfg(feature = "toast")]
+pub use toast::Toast;
+
+#[cfg(feature = "virtual_list")]
+pub mod virtual_list;
+#[cfg(feature = "virtual_list")]
+/// A list only creating the widgets of its visible items.
+pub type VirtualList<'a, Message, Theme, Renderer> =
+    virtual_list::VirtualList<'a, Message, Theme, Renderer>;
+
 #[cfg(feature = "wrap")]
 pub mod wrap;
 #[cfg(feature = "wrap")]
@@ -138,12 +267,139 @@ pub mod segmented_button;
 /// A badge for color highlighting small information.
 pub type SegmentedButton<'a, Message, Theme, Renderer> =
     segmented_button::SegmentedButton<'a, Message, Theme, Renderer>;
+#[cfg(feature = "segmented_button")]
+/// A connected group of segments selecting values.
+pub type SegmentedButtons<'a, V, Message, Theme> =
+    segmented_button::SegmentedButtons<'a, V, Message, Theme>;
 
 #[cfg(feature = "slide_bar")]
 pub mod slide_bar;
 #[cfg(feature = "slide_bar")]
 pub use slide_bar::SlideBar;
 
+#[cfg(feature = "range_slider")]
+pub mod range_slider;
+#[cfg(feature = "range_slider")]
+/// A slider selecting an interval of values with two handles.
+pub type RangeSlider<'a, T, Message, Theme> = range_slider::RangeSlider<'a, T, Message, Theme>;
+
+#[cfg(feature = "knob")]
+pub mod knob;
+#[cfg(feature = "knob")]
+/// A rotary knob selecting a value in a range.
+pub type Knob<'a, T, Message, Theme> = knob::Knob<'a, T, Message, Theme>;
+
+#[cfg(feature = "icon")]
+pub mod icon;
+#[cfg(feature = "icon")]
+/// A glyph of an icon font, rotated, flipped or spinning.
+pub type Icon = icon::Icon;
+
+#[cfg(feature = "image_viewer")]
+pub mod image_viewer;
+#[cfg(feature = "image_viewer")]
+/// A viewer zooming and panning an image.
+pub type ImageViewer<'a, Message, Theme, Renderer> =
+    image_viewer::ImageViewer<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "heatmap_calendar")]
+pub mod heatmap_calendar;
+#[cfg(feature = "heatmap_calendar")]
+/// A calendar showing a value per day of a year as colored cells.
+pub type HeatmapCalendar<'a, Message, Theme> =
+    heatmap_calendar::HeatmapCalendar<'a, Message, Theme>;
+
+#[cfg(feature = "hotkey_input")]
+pub mod hotkey_input;
+#[cfg(feature = "hotkey_input")]
+/// A field recording a key combination.
+pub type HotkeyInput<'a, Message, Theme, Renderer> =
+    hotkey_input::HotkeyInput<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "json_tree")]
+pub mod json_tree;
+#[cfg(feature = "json_tree")]
+/// A tree showing nested data, like a JSON value.
+pub type JsonTree<'a, T, Message, Theme, Renderer> =
+    json_tree::JsonTree<'a, T, Message, Theme, Renderer>;
+
+#[cfg(feature = "log_view")]
+pub mod log_view;
+#[cfg(feature = "log_view")]
+/// A view of the lines of a log, following the appended ones.
+pub type LogView<'a, Message, Theme, Renderer> = log_view::LogView<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "transfer_list")]
+pub mod transfer_list;
+#[cfg(feature = "transfer_list")]
+/// Two lists of options with buttons moving options between them.
+pub type TransferList<'a, T, Message, Theme, Renderer> =
+    transfer_list::TransferList<'a, T, Message, Theme, Renderer>;
+
+#[cfg(feature = "property_grid")]
+pub mod property_grid;
+#[cfg(feature = "property_grid")]
+/// A list of labeled properties edited by the editors of their values.
+pub type PropertyGrid<'a, Message, Theme, Renderer> =
+    property_grid::PropertyGrid<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "tick_slider")]
+pub mod tick_slider;
+#[cfg(feature = "tick_slider")]
+/// A slider with tick marks and labels, showing its value while dragging.
+pub type TickSlider<'a, T, Message, Theme> = tick_slider::TickSlider<'a, T, Message, Theme>;
+
+#[cfg(feature = "multi_pick_list")]
+pub mod multi_pick_list;
+#[cfg(feature = "multi_pick_list")]
+/// A drop down checklist choosing several options.
+pub type MultiPickList<'a, T, Message, Theme, Renderer> =
+    multi_pick_list::MultiPickList<'a, T, Message, Theme, Renderer>;
+
+#[cfg(feature = "tour")]
+pub mod tour;
+#[cfg(feature = "tour")]
+/// A tour highlighting a sequence of widgets of its content.
+pub type Tour<'a, Message, Theme, Renderer> = tour::Tour<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "tree_table")]
+pub mod tree_table;
+#[cfg(feature = "tree_table")]
+/// A table showing hierarchical records, with a tree in its first column.
+pub type TreeTable<'a, T, Message, Theme, Renderer> =
+    tree_table::TreeTable<'a, T, Message, Theme, Renderer>;
+
+#[cfg(feature = "num_pad")]
+pub mod num_pad;
+#[cfg(feature = "num_pad")]
+/// An on-screen number pad for touch screens.
+pub type NumPad<'a, Message, Theme> = num_pad::NumPad<'a, Message, Theme>;
+
+#[cfg(feature = "wheel_picker")]
+pub mod wheel_picker;
+#[cfg(feature = "wheel_picker")]
+/// A wheel of items scrolled like a drum.
+pub type WheelPicker<'a, T, Message, Theme> = wheel_picker::WheelPicker<'a, T, Message, Theme>;
+
+#[cfg(feature = "status_dot")]
+pub mod status_dot;
+#[cfg(feature = "status_dot")]
+/// A small colored dot showing a status.
+pub type StatusDot<Theme> = status_dot::StatusDot<Theme>;
+
+#[cfg(feature = "style_scope")]
+pub mod style_scope;
+#[cfg(feature = "style_scope")]
+/// A container overriding the default styling of the widgets inside it.
+pub type StyleScope<'a, Message, Theme, Renderer> =
+    style_scope::StyleScope<'a, Message, Theme, Renderer>;
+
+#[cfg(feature = "minimap")]
+pub mod minimap;
+#[cfg(feature = "minimap")]
+/// A scaled-down overview of scrollable content.
+pub type Minimap<'a, Message, Theme, Renderer> = minimap::Minimap<'a, Message, Theme, Renderer>;
+
 #[cfg(feature = "drop_down")]
 pub mod drop_down;
 #[cfg(feature = "drop_down")]
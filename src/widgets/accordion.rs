@@ -0,0 +1,757 @@
+//! Stack titled sections that expand and collapse.
+//!
+//! *This API requires the following crate features to be activated: accordion*
+
+use crate::core::{
+    animation,
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    settings,
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay::{self, Group},
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    window, Border, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shadow, Size,
+    Vector,
+};
+use std::time::{Duration, Instant};
+
+pub use crate::style::accordion::{Appearance, StyleSheet};
+
+/// The duration of the expand and collapse animations of a [`Section`].
+const ANIMATION_DURATION: Duration = Duration::from_millis(200);
+/// The space between the header of a [`Section`] and its expansion icon.
+const ICON_SPACING: f32 = 8.0;
+
+/// A titled section of an [`Accordion`].
+#[allow(missing_debug_implementations)]
+pub struct Section<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The header [`Element`] of the [`Section`], toggling it when pressed.
+    header: Element<'a, Message, Theme, Renderer>,
+    /// The content [`Element`] of the [`Section`], shown when expanded.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// Whether the [`Section`] is initially expanded.
+    expanded: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Section<'a, Message, Theme, Renderer> {
+    /// Creates a new collapsed [`Section`] with the given header and content.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            content: content.into(),
+            expanded: false,
+        }
+    }
+
+    /// Sets whether the [`Section`] is initially expanded.
+    #[must_use]
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+}
+
+/// How many sections of an [`Accordion`] can be expanded at once.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Expanding a section collapses the others.
+    Single,
+    /// Any number of sections can be expanded.
+    #[default]
+    Multiple,
+}
+
+/// A container of titled sections, each expanding and collapsing when its
+/// header is pressed.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::accordion::{Accordion, Mode, Section};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(usize, bool),
+/// }
+///
+/// let accordion = Accordion::new(vec![
+///     Section::new(Text::new("General"), Text::new("...")).expanded(true),
+///     Section::new(Text::new("Advanced"), Text::new("...")),
+/// ])
+/// .mode(Mode::Single)
+/// .on_toggle(Message::Toggled);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Accordion<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The sections of the [`Accordion`].
+    sections: Vec<Section<'a, Message, Theme, Renderer>>,
+    /// How many sections can be expanded at once.
+    mode: Mode,
+    /// The function producing the message when a section is expanded or collapsed.
+    on_toggle: Option<Box<dyn Fn(usize, bool) -> Message + 'a>>,
+    /// The width of the [`Accordion`].
+    width: Length,
+    /// The padding of the headers of the sections.
+    header_padding: Padding,
+    /// The padding of the content of the sections.
+    content_padding: Padding,
+    /// The size of the expansion icon of the headers.
+    icon_size: f32,
+    /// The style of the [`Accordion`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of an [`Accordion`].
+#[derive(Debug, Default)]
+struct State {
+    /// Whether each section is expanded.
+    expanded: Vec<bool>,
+    /// The progress of the animation of each section, from 0 (collapsed) to 1 (expanded).
+    progress: Vec<f32>,
+    /// The moment the animations were last advanced.
+    last_update: Option<Instant>,
+}
+
+impl State {
+    /// Expands or collapses the section at `index`, collapsing the others in
+    /// [`Mode::Single`].
+    fn toggle(&mut self, index: usize, mode: Mode) -> bool {
+        let expanded = !self.expanded[index];
+
+        if expanded && mode == Mode::Single {
+            self.expanded.iter_mut().for_each(|other| *other = false);
+        }
+        self.expanded[index] = expanded;
+
+        expanded
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Accordion<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Accordion`] with the given sections.
+    #[must_use]
+    pub fn new(sections: Vec<Section<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            sections,
+            mode: Mode::default(),
+            on_toggle: None,
+            width: Length::Fill,
+            header_padding: Padding::new(10.0),
+            content_padding: Padding::new(10.0),
+            icon_size: settings::scaled(14.0),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds a [`Section`] to the [`Accordion`].
+    #[must_use]
+    pub fn push(mut self, section: Section<'a, Message, Theme, Renderer>) -> Self {
+        self.sections.push(section);
+        self
+    }
+
+    /// Sets how many sections of the [`Accordion`] can be expanded at once.
+    #[must_use]
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the function producing the message when a section is expanded
+    /// or collapsed, with the index of the section and whether it is expanded.
+    #[must_use]
+    pub fn on_toggle(mut self, on_toggle: impl Fn(usize, bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the width of the [`Accordion`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the headers of the sections.
+    #[must_use]
+    pub fn header_padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.header_padding = padding.into();
+        self
+    }
+
+    /// Sets the padding of the content of the sections.
+    #[must_use]
+    pub fn content_padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.content_padding = padding.into();
+        self
+    }
+
+    /// Sets the size of the expansion icon of the headers.
+    #[must_use]
+    pub fn icon_size(mut self, icon_size: f32) -> Self {
+        self.icon_size = icon_size;
+        self
+    }
+
+    /// Sets the style of the [`Accordion`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns whether the sections are initially expanded, keeping the
+    /// first one only in [`Mode::Single`].
+    fn initially_expanded(&self) -> Vec<bool> {
+        let mut found = false;
+
+        self.sections
+            .iter()
+            .map(|section| {
+                let expanded = section.expanded && !(self.mode == Mode::Single && found);
+                found |= expanded;
+                expanded
+            })
+            .collect()
+    }
+}
+
+/// Eases the progress of an animation in and out.
+fn eased(progress: f32) -> f32 {
+    progress * progress * (3.0 - 2.0 * progress)
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Accordion<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + text::Renderer<Font = iced::Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let expanded = self.initially_expanded();
+
+        tree::State::new(State {
+            progress: expanded
+                .iter()
+                .map(|&e| if e { 1.0 } else { 0.0 })
+                .collect(),
+            expanded,
+            last_update: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.sections
+            .iter()
+            .flat_map(|section| [Tree::new(&section.header), Tree::new(&section.content)])
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let expanded = self.initially_expanded();
+
+        state.expanded.truncate(self.sections.len());
+        state.progress.truncate(self.sections.len());
+        for &e in &expanded[state.expanded.len()..] {
+            state.expanded.push(e);
+            state.progress.push(if e { 1.0 } else { 0.0 });
+        }
+
+        let children: Vec<_> = self
+            .sections
+            .iter()
+            .flat_map(|section| [&section.header, &section.content])
+            .collect();
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_ref::<State>();
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let width = limits.max().width;
+        let progress = state.progress.clone();
+
+        let mut y = 0.0;
+        let sections = self
+            .sections
+            .iter()
+            .zip(tree.children.chunks_mut(2))
+            .zip(progress)
+            .map(|((section, trees), progress)| {
+                let header_limits = Limits::new(
+                    Size::ZERO,
+                    Size::new(
+                        (width - self.header_padding.horizontal() - self.icon_size - ICON_SPACING)
+                            .max(0.0),
+                        f32::INFINITY,
+                    ),
+                );
+                let header = section
+                    .header
+                    .as_widget()
+                    .layout(&mut trees[0], renderer, &header_limits)
+                    .move_to(Point::new(
+                        self.header_padding.left,
+                        self.header_padding.top,
+                    ));
+                let header_height = header.size().height + self.header_padding.vertical();
+                let header = Node::with_children(Size::new(width, header_height), vec![header]);
+
+                let content_limits = Limits::new(
+                    Size::ZERO,
+                    Size::new(
+                        (width - self.content_padding.horizontal()).max(0.0),
+                        f32::INFINITY,
+                    ),
+                );
+                let content = section
+                    .content
+                    .as_widget()
+                    .layout(&mut trees[1], renderer, &content_limits)
+                    .move_to(Point::new(
+                        self.content_padding.left,
+                        self.content_padding.top,
+                    ));
+                let content_height = content.size().height + self.content_padding.vertical();
+                let content = Node::with_children(Size::new(width, content_height), vec![content])
+                    .move_to(Point::new(0.0, header_height));
+
+                let height = header_height + content_height * eased(progress);
+                let node = Node::with_children(Size::new(width, height), vec![header, content])
+                    .move_to(Point::new(0.0, y));
+                y += height;
+
+                node
+            })
+            .collect();
+
+        Node::with_children(
+            limits.resolve(self.width, Length::Shrink, Size::new(width, y)),
+            sections,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let accordion_state = state.state.downcast_mut::<State>();
+
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            if let Some(last) = accordion_state.last_update {
+                let step = if settings::reduced_motion() {
+                    1.0
+                } else {
+                    (now - last).as_secs_f32() / ANIMATION_DURATION.as_secs_f32()
+                };
+                let mut animating = false;
+
+                for (progress, &expanded) in accordion_state
+                    .progress
+                    .iter_mut()
+                    .zip(&accordion_state.expanded)
+                {
+                    let target = if expanded { 1.0 } else { 0.0 };
+                    *progress = if expanded {
+                        (*progress + step).min(target)
+                    } else {
+                        (*progress - step).max(target)
+                    };
+                    animating |= (*progress - target).abs() > f32::EPSILON;
+                }
+
+                shell.invalidate_layout();
+                if animating {
+                    accordion_state.last_update = Some(now);
+                    animation::request_frame(shell, now);
+                } else {
+                    accordion_state.last_update = None;
+                }
+            }
+        }
+
+        let mut status = event::Status::Ignored;
+
+        for (i, ((section, trees), section_layout)) in self
+            .sections
+            .iter_mut()
+            .zip(state.children.chunks_mut(2))
+            .zip(layout.children())
+            .enumerate()
+        {
+            let mut children = section_layout.children();
+            let (Some(header_layout), Some(content_layout)) = (children.next(), children.next())
+            else {
+                continue;
+            };
+
+            if let Some(header) = header_layout.children().next() {
+                status = status.merge(section.header.as_widget_mut().on_event(
+                    &mut trees[0],
+                    event.clone(),
+                    header,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                ));
+            }
+
+            // Only the shown part of the content reacts to the cursor.
+            let shown = section_layout
+                .bounds()
+                .intersection(&content_layout.bounds());
+            if let Some(shown) = shown {
+                let content_cursor = if cursor.is_over(shown) {
+                    cursor
+                } else {
+                    Cursor::Unavailable
+                };
+
+                if let Some(content) = content_layout.children().next() {
+                    status = status.merge(section.content.as_widget_mut().on_event(
+                        &mut trees[1],
+                        event.clone(),
+                        content,
+                        content_cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        viewport,
+                    ));
+                }
+            }
+
+            if status == event::Status::Ignored
+                && cursor.is_over(header_layout.bounds())
+                && matches!(
+                    event,
+                    Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                        | Event::Touch(touch::Event::FingerPressed { .. })
+                )
+            {
+                let accordion_state = state.state.downcast_mut::<State>();
+                let expanded = accordion_state.toggle(i, self.mode);
+
+                if let Some(on_toggle) = &self.on_toggle {
+                    shell.publish(on_toggle(i, expanded));
+                }
+
+                accordion_state.last_update = Some(Instant::now());
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                status = event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.sections
+            .iter()
+            .zip(state.children.chunks(2))
+            .zip(layout.children())
+            .map(|((section, trees), section_layout)| {
+                let mut children = section_layout.children();
+                let (Some(header_layout), Some(content_layout)) =
+                    (children.next(), children.next())
+                else {
+                    return mouse::Interaction::default();
+                };
+
+                let header = header_layout.children().next().map_or_else(
+                    mouse::Interaction::default,
+                    |header| {
+                        section
+                            .header
+                            .as_widget()
+                            .mouse_interaction(&trees[0], header, cursor, viewport, renderer)
+                    },
+                );
+
+                if header != mouse::Interaction::default() {
+                    return header;
+                }
+
+                if cursor.is_over(header_layout.bounds()) {
+                    return mouse::Interaction::Pointer;
+                }
+
+                match (
+                    section_layout
+                        .bounds()
+                        .intersection(&content_layout.bounds()),
+                    content_layout.children().next(),
+                ) {
+                    (Some(shown), Some(content)) if cursor.is_over(shown) => section
+                        .content
+                        .as_widget()
+                        .mouse_interaction(&trees[1], content, cursor, viewport, renderer),
+                    _ => mouse::Interaction::default(),
+                }
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let accordion_state = state.state.downcast_ref::<State>();
+        let appearance = theme.active(&self.style);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: appearance.border_radius.into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        for (i, ((section, trees), section_layout)) in self
+            .sections
+            .iter()
+            .zip(state.children.chunks(2))
+            .zip(layout.children())
+            .enumerate()
+        {
+            let mut children = section_layout.children();
+            let (Some(header_layout), Some(content_layout)) = (children.next(), children.next())
+            else {
+                continue;
+            };
+            let header_bounds = header_layout.bounds();
+
+            let radius = appearance.border_radius;
+            let first = i == 0;
+            let last = i + 1 == self.sections.len() && accordion_state.progress[i] <= 0.0;
+            renderer.fill_quad(
+                Quad {
+                    bounds: header_bounds,
+                    border: Border {
+                        radius: [
+                            if first { radius } else { 0.0 },
+                            if first { radius } else { 0.0 },
+                            if last { radius } else { 0.0 },
+                            if last { radius } else { 0.0 },
+                        ]
+                        .into(),
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                if cursor.is_over(header_bounds) {
+                    appearance.header_hovered_background
+                } else {
+                    appearance.header_background
+                },
+            );
+
+            if !first {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: Rectangle {
+                            height: 1.0,
+                            ..header_bounds
+                        },
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.divider_color,
+                );
+            }
+
+            if let Some(header) = header_layout.children().next() {
+                section.header.as_widget().draw(
+                    &trees[0],
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: appearance.header_text_color,
+                    },
+                    header,
+                    cursor,
+                    viewport,
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: &icon_to_string(if accordion_state.expanded[i] {
+                        Bootstrap::CaretDownFill
+                    } else {
+                        Bootstrap::CaretRightFill
+                    }),
+                    bounds: Size::new(self.icon_size, header_bounds.height),
+                    size: Pixels(self.icon_size),
+                    line_height: LineHeight::default(),
+                    font: BOOTSTRAP_FONT,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: Shaping::Basic,
+                },
+                Point::new(
+                    header_bounds.x + header_bounds.width
+                        - self.header_padding.right
+                        - self.icon_size / 2.0,
+                    header_bounds.center_y(),
+                ),
+                appearance.icon_color,
+                header_bounds,
+            );
+
+            let shown = section_layout
+                .bounds()
+                .intersection(&content_layout.bounds());
+            if let (Some(shown), Some(content)) = (shown, content_layout.children().next()) {
+                renderer.with_layer(shown, |renderer| {
+                    section.content.as_widget().draw(
+                        &trees[1],
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: appearance.text_color,
+                        },
+                        content,
+                        cursor,
+                        viewport,
+                    );
+                });
+            }
+        }
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((section, trees), section_layout) in self
+                .sections
+                .iter()
+                .zip(state.children.chunks_mut(2))
+                .zip(layout.children())
+            {
+                for ((element, tree), child_layout) in [&section.header, &section.content]
+                    .into_iter()
+                    .zip(trees.iter_mut())
+                    .zip(section_layout.children())
+                {
+                    if let Some(child_layout) = child_layout.children().next() {
+                        element
+                            .as_widget()
+                            .operate(tree, child_layout, renderer, operation);
+                    }
+                }
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let children = self
+            .sections
+            .iter_mut()
+            .zip(state.children.chunks_mut(2))
+            .zip(layout.children())
+            .flat_map(|((section, trees), section_layout)| {
+                [&mut section.header, &mut section.content]
+                    .into_iter()
+                    .zip(trees.iter_mut())
+                    .zip(section_layout.children())
+            })
+            .filter_map(|((element, tree), child_layout)| {
+                child_layout.children().next().and_then(|child_layout| {
+                    element
+                        .as_widget_mut()
+                        .overlay(tree, child_layout, renderer, translation)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Accordion<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + text::Renderer<Font = iced::Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(accordion: Accordion<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(accordion)
+    }
+}
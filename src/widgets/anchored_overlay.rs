@@ -0,0 +1,299 @@
+//! Attach a custom popup to a target widget.
+//!
+//! *This API requires the following crate features to be activated: `anchored_overlay`*
+
+use super::overlay::anchored_overlay::AnchoredOverlayOverlay;
+use crate::core::lazy_content::LazyContent;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay::{self, Group},
+        renderer,
+        widget::{Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    Element, Event, Length, Rectangle, Size, Vector,
+};
+
+pub use crate::core::overlay::{anchor, Placement};
+
+/// A widget showing any content in an overlay anchored to a target widget.
+///
+/// The content is placed with the same flip and clamp behavior as the
+/// [`Popover`](crate::Popover) and the pickers: it is shown on the side given
+/// by its [`Placement`], or on the opposite side when it does not fit in the
+/// window, and is kept inside the window. Unlike a popover, it draws nothing
+/// around the content, which is shown while the application asks for it.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::{button, container, text};
+/// # use iced_aw::anchored_overlay::{AnchoredOverlay, Placement};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggle,
+///     Close,
+/// }
+///
+/// let popup = AnchoredOverlay::new(
+///     button(text("Account")).on_press(Message::Toggle),
+///     container(text("Signed in")),
+///     Placement::Bottom,
+/// )
+/// .show(true)
+/// .on_dismiss(Message::Close);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct AnchoredOverlay<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    /// The widget the content is anchored to.
+    target: Element<'a, Message, Theme, Renderer>,
+    /// The content shown in the overlay.
+    content: LazyContent<'a, Message, Theme, Renderer>,
+    placement: Placement,
+    /// The space between the content and the target.
+    gap: f32,
+    /// Whether the content is shown.
+    show: bool,
+    /// The optional message produced when the user presses Escape or clicks
+    /// outside of the content and the target.
+    on_dismiss: Option<Message>,
+}
+
+impl<'a, Message, Theme, Renderer> AnchoredOverlay<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`AnchoredOverlay`] showing the `content` on the
+    /// `placement` side of the `target`.
+    pub fn new<T, C>(target: T, content: C, placement: Placement) -> Self
+    where
+        T: Into<Element<'a, Message, Theme, Renderer>>,
+        C: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self::with_content(target, LazyContent::new(content), placement)
+    }
+
+    /// Creates a new [`AnchoredOverlay`] showing the content built by
+    /// `content` on the `placement` side of the `target`.
+    ///
+    /// The content is only built while it is shown, which avoids building an
+    /// expensive content in every view while it is hidden.
+    pub fn lazy<T>(
+        target: T,
+        content: impl FnOnce() -> Element<'a, Message, Theme, Renderer> + 'a,
+        placement: Placement,
+    ) -> Self
+    where
+        T: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self::with_content(target, LazyContent::lazy(content), placement)
+    }
+
+    /// Creates a new [`AnchoredOverlay`] with the given content.
+    fn with_content<T>(
+        target: T,
+        content: LazyContent<'a, Message, Theme, Renderer>,
+        placement: Placement,
+    ) -> Self
+    where
+        T: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self {
+            target: target.into(),
+            content,
+            placement,
+            gap: 4.0,
+            show: true,
+            on_dismiss: None,
+        }
+    }
+
+    /// Sets the side of the target the content is shown on.
+    #[must_use]
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the space between the content and the target.
+    #[must_use]
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets whether the content of the [`AnchoredOverlay`] is shown.
+    #[must_use]
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = show;
+        self
+    }
+
+    /// Sets the message produced when the user presses Escape or clicks
+    /// outside of the content and the target while the content is shown.
+    #[must_use]
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for AnchoredOverlay<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.target), self.content.tree()]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children.resize_with(2, Tree::empty);
+        tree.children[0].diff(&self.target);
+        self.content.diff(&mut tree.children[1]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.target.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.target
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.target.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.target.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.target.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.target
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = state.children.iter_mut();
+        let (target_tree, content_tree) = (children.next()?, children.next()?);
+        let mut group = Group::new();
+
+        if let Some(target) =
+            self.target
+                .as_widget_mut()
+                .overlay(target_tree, layout, renderer, translation)
+        {
+            group = group.push(target);
+        }
+
+        if let Some(content) = self
+            .show
+            .then(|| self.content.get_or_build(content_tree))
+            .flatten()
+        {
+            group = group.push(overlay::Element::new(Box::new(
+                AnchoredOverlayOverlay::new(
+                    content_tree,
+                    content,
+                    layout.bounds() + translation,
+                    self.placement,
+                    self.gap,
+                    self.on_dismiss.clone(),
+                ),
+            )));
+        }
+
+        Some(group.into())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<AnchoredOverlay<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn from(anchored_overlay: AnchoredOverlay<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(anchored_overlay)
+    }
+}
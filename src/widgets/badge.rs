@@ -2,6 +2,11 @@
 //!
 //! *This API requires the following crate features to be activated: badge*
 
+use crate::core::{
+    icons::{IconHandle, IconRenderer, BOOTSTRAP_FONT},
+    settings,
+};
+
 use iced::{
     advanced::{
         layout::{Limits, Node},
@@ -11,6 +16,7 @@ use iced::{
     },
     event,
     mouse::{self, Cursor},
+    widget::Row,
     Alignment, Border, Color, Element, Event, Length, Padding, Point, Rectangle, Shadow, Size,
 };
 
@@ -19,6 +25,12 @@ pub use crate::style::badge::{Appearance, StyleSheet};
 /// The ratio of the border radius.
 const BORDER_RADIUS_RATIO: f32 = 34.0 / 15.0;
 
+/// The size of the icon of a [`Badge`].
+const ICON_SIZE: f32 = 16.0;
+
+/// The space between the icon and the content of a [`Badge`].
+const ICON_SPACING: f32 = 4.0;
+
 /// A badge for color highlighting small information.
 ///
 /// # Example
@@ -52,6 +64,8 @@ where
     style: <Theme as StyleSheet>::Style,
     /// The content [`Element`] of the [`Badge`].
     content: Element<'a, Message, Theme, Renderer>,
+    /// Whether the [`Badge`] is disabled.
+    disabled: bool,
 }
 
 impl<'a, Message, Theme, Renderer> Badge<'a, Message, Theme, Renderer>
@@ -68,13 +82,41 @@ where
         T: Into<Element<'a, Message, Theme, Renderer>>,
     {
         Badge {
-            padding: 7,
+            padding: settings::spaced(7.0) as u16,
             width: Length::Shrink,
             height: Length::Shrink,
             horizontal_alignment: Alignment::Center,
             vertical_alignment: Alignment::Center,
             style: <Theme as StyleSheet>::Style::default(),
             content: content.into(),
+            disabled: false,
+        }
+    }
+
+    /// Sets whether the [`Badge`] is disabled. A disabled [`Badge`] is
+    /// dimmed, and its content does not receive the events.
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Shows the icon before the content of the [`Badge`], drawn with its
+    /// text color. A glyph without its own font is written with the
+    /// Bootstrap icon font.
+    #[must_use]
+    pub fn icon(self, icon: impl Into<IconHandle>) -> Self
+    where
+        Message: 'a,
+        Theme: 'a,
+        Renderer: 'a + IconRenderer,
+    {
+        Self {
+            content: Row::with_children([Element::new(BadgeIcon(icon.into())), self.content])
+                .spacing(settings::spaced(ICON_SPACING))
+                .align_items(Alignment::Center)
+                .into(),
+            ..self
         }
     }
 
@@ -108,8 +150,8 @@ where
 
     /// Sets the style of the [`Badge`].
     #[must_use]
-    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
-        self.style = style;
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
         self
     }
 
@@ -175,6 +217,10 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        if self.disabled {
+            return event::Status::Ignored;
+        }
+
         self.content.as_widget_mut().on_event(
             &mut state.children[0],
             event,
@@ -198,6 +244,10 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if self.disabled {
+            return mouse::Interaction::default();
+        }
+
         self.content.as_widget().mouse_interaction(
             &state.children[0],
             layout,
@@ -220,7 +270,9 @@ where
         let bounds = layout.bounds();
         let mut children = layout.children();
         let is_mouse_over = bounds.contains(cursor.position().unwrap_or_default());
-        let style_sheet = if is_mouse_over {
+        let style_sheet = if self.disabled {
+            theme.disabled(&self.style)
+        } else if is_mouse_over {
             theme.hovered(&self.style)
         } else {
             theme.active(&self.style)
@@ -262,6 +314,45 @@ where
     }
 }
 
+/// The icon shown before the content of a [`Badge`].
+struct BadgeIcon(IconHandle);
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for BadgeIcon
+where
+    Renderer: IconRenderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let size = self
+            .0
+            .measure::<Renderer>(settings::scaled(ICON_SIZE), BOOTSTRAP_FONT);
+
+        Node::new(limits.resolve(Length::Shrink, Length::Shrink, size))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        self.0.draw(
+            renderer,
+            layout.bounds(),
+            settings::scaled(ICON_SIZE),
+            BOOTSTRAP_FONT,
+            style.text_color,
+        );
+    }
+}
+
 impl<'a, Message, Theme, Renderer> From<Badge<'a, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
@@ -2,18 +2,26 @@
 //!
 //! *This API requires the following crate features to be activated: card*
 
-use crate::core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT};
+use crate::core::{
+    focus::TabStop,
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    settings,
+};
 
 use iced::{
     advanced::{
         layout::{Limits, Node},
         renderer,
         text::LineHeight,
-        widget::{Operation, Tree},
+        widget::{
+            operation::Focusable,
+            tree::{self, Tag},
+            Operation, Tree,
+        },
         Clipboard, Layout, Shell, Widget,
     },
     alignment::{Horizontal, Vertical},
-    event,
+    event, keyboard,
     mouse::{self, Cursor},
     touch, Alignment, Border, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle,
     Shadow, Size, Vector,
@@ -68,6 +76,8 @@ where
     close_size: Option<f32>,
     /// The optional message that is send if the close icon of the [`Card`] is pressed.
     on_close: Option<Message>,
+    /// The place of the close icon of the [`Card`] in its tab order.
+    close_tab_stop: TabStop,
     /// The head [`Element`] of the [`Card`].
     head: Element<'a, Message, Theme, Renderer>,
     /// The body [`Element`] of the [`Card`].
@@ -76,6 +86,8 @@ where
     foot: Option<Element<'a, Message, Theme, Renderer>>,
     /// The style of the [`Card`].
     style: <Theme as StyleSheet>::Style,
+    /// Whether the [`Card`] is disabled.
+    disabled: bool,
 }
 
 impl<'a, Message, Theme, Renderer> Card<'a, Message, Theme, Renderer>
@@ -98,15 +110,17 @@ where
             height: Length::Shrink,
             max_width: u32::MAX as f32,
             max_height: u32::MAX as f32,
-            padding_head: DEFAULT_PADDING.into(),
-            padding_body: DEFAULT_PADDING.into(),
-            padding_foot: DEFAULT_PADDING.into(),
+            padding_head: settings::spaced(DEFAULT_PADDING).into(),
+            padding_body: settings::spaced(DEFAULT_PADDING).into(),
+            padding_foot: settings::spaced(DEFAULT_PADDING).into(),
             close_size: None,
             on_close: None,
+            close_tab_stop: TabStop::default(),
             head: head.into(),
             body: body.into(),
             foot: None,
             style: <Theme as StyleSheet>::Style::default(),
+            disabled: false,
         }
     }
 
@@ -158,6 +172,24 @@ where
         self
     }
 
+    /// Sets whether the [`Card`] is disabled. A disabled [`Card`] is
+    /// dimmed, its close icon cannot be pressed, and its head, body and foot
+    /// do not receive the events.
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets whether the close icon of the [`Card`] is reached with Tab,
+    /// before or after the content of the [`Card`]. It is skipped by
+    /// default. The focused close icon is pressed with Enter or Space.
+    #[must_use]
+    pub fn close_tab_stop(mut self, tab_stop: TabStop) -> Self {
+        self.close_tab_stop = tab_stop;
+        self
+    }
+
     /// Sets the padding of the [`Card`].
     ///
     /// This will set the padding of the head, body and foot to the
@@ -193,8 +225,8 @@ where
 
     /// Sets the style of the [`Card`].
     #[must_use]
-    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
-        self.style = style;
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
         self
     }
 
@@ -213,6 +245,14 @@ where
     Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
     Theme: StyleSheet,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn children(&self) -> Vec<Tree> {
         self.foot.as_ref().map_or_else(
             || vec![Tree::new(&self.head), Tree::new(&self.body)],
@@ -301,6 +341,10 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        if self.disabled {
+            return event::Status::Ignored;
+        }
+
         let mut children = layout.children();
 
         let head_layout = children
@@ -320,10 +364,29 @@ where
             viewport,
         );
 
+        let card_state = state.state.downcast_mut::<State>();
+        if let Event::Mouse(mouse::Event::ButtonPressed(_)) = event {
+            card_state.close_focused = false;
+        }
+
         let close_status = head_children
             .next()
             .map_or(event::Status::Ignored, |close_layout| {
                 match event {
+                    Event::Keyboard(keyboard::Event::KeyPressed {
+                        key:
+                            keyboard::Key::Named(
+                                keyboard::key::Named::Enter | keyboard::key::Named::Space,
+                            ),
+                        ..
+                    }) if card_state.close_focused => {
+                        self.on_close
+                            .clone()
+                            .map_or(event::Status::Ignored, |on_close| {
+                                shell.publish(on_close);
+                                event::Status::Captured
+                            })
+                    }
                     Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
                     | Event::Touch(touch::Event::FingerPressed { .. }) => self
                         .on_close
@@ -331,8 +394,7 @@ where
                         // TODO: `let` expressions in this position are experimental
                         // see issue #53667 <https://github.com/rust-lang/rust/issues/53667> for more information
                         .filter(|_| {
-                            close_layout
-                                .bounds()
+                            settings::hit_area(close_layout.bounds())
                                 .contains(cursor.position().unwrap_or_default())
                         })
                         .map_or(event::Status::Ignored, |on_close| {
@@ -393,6 +455,10 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if self.disabled {
+            return mouse::Interaction::default();
+        }
+
         let mut children = layout.children();
 
         let head_layout = children
@@ -406,7 +472,7 @@ where
         let close_layout = head_children.next();
 
         let is_mouse_over_close = close_layout.map_or(false, |layout| {
-            let bounds = layout.bounds();
+            let bounds = settings::hit_area(layout.bounds());
             bounds.contains(cursor.position().unwrap_or_default())
         });
 
@@ -473,6 +539,15 @@ where
         let head_layout = children.next().expect("Missing Head Layout");
         let body_layout = children.next().expect("Missing Body Layout");
         let foot_layout = children.next().expect("Missing Footer Layout");
+        let close_tab_stop = if self.on_close.is_some() && !self.disabled {
+            self.close_tab_stop
+        } else {
+            TabStop::Skipped
+        };
+
+        if close_tab_stop == TabStop::First {
+            operation.focusable(state.state.downcast_mut::<State>(), None);
+        }
 
         self.head
             .as_widget()
@@ -486,6 +561,10 @@ where
                 .as_widget()
                 .operate(&mut state.children[2], foot_layout, renderer, operation);
         };
+
+        if close_tab_stop == TabStop::Last {
+            operation.focusable(state.state.downcast_mut::<State>(), None);
+        }
     }
 
     fn draw(
@@ -500,7 +579,11 @@ where
     ) {
         let bounds = layout.bounds();
         let mut children = layout.children();
-        let style_sheet = theme.active(&self.style);
+        let style_sheet = if self.disabled {
+            theme.disabled(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
 
         // Background
         renderer.fill_quad(
@@ -543,10 +626,29 @@ where
             cursor,
             viewport,
             theme,
-            &self.style,
+            &style_sheet,
             self.close_size,
         );
 
+        if let Some(close_layout) = head_layout
+            .children()
+            .nth(1)
+            .filter(|_| state.state.downcast_ref::<State>().close_focused)
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: close_layout.bounds(),
+                    border: Border {
+                        radius: style_sheet.border_radius.into(),
+                        width: 2.0,
+                        color: style_sheet.close_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
         // ----------- Body ----------------------
         let body_layout = children
             .next()
@@ -559,7 +661,7 @@ where
             cursor,
             viewport,
             theme,
-            &self.style,
+            &style_sheet,
         );
 
         // ----------- Foot ----------------------
@@ -574,7 +676,7 @@ where
             cursor,
             viewport,
             theme,
-            &self.style,
+            &style_sheet,
         );
     }
 
@@ -607,6 +709,27 @@ where
     }
 }
 
+/// The state of a [`Card`].
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the close icon has the keyboard focus.
+    close_focused: bool,
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.close_focused
+    }
+
+    fn focus(&mut self) {
+        self.close_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.close_focused = false;
+    }
+}
+
 /// Calculates the layout of the head.
 #[allow(clippy::too_many_arguments)]
 fn head_node<Message, Theme, Renderer>(
@@ -630,7 +753,7 @@ where
         .height(header_size.height)
         .shrink(padding);
 
-    let close_size = close_size.unwrap_or_else(|| renderer.default_size().0);
+    let close_size = close_size.unwrap_or_else(|| settings::scaled(renderer.default_size().0));
 
     if on_close {
         limits = limits.shrink(Size::new(close_size, 0.0));
@@ -746,14 +869,13 @@ fn draw_head<Message, Theme, Renderer>(
     cursor: Cursor,
     viewport: &Rectangle,
     theme: &Theme,
-    style: &<Theme as StyleSheet>::Style,
+    style_sheet: &Appearance,
     close_size: Option<f32>,
 ) where
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
     Theme: StyleSheet,
 {
     let mut head_children = layout.children();
-    let style_sheet = theme.active(style);
     let bounds = layout.bounds();
     let border_radius = style_sheet.border_radius;
 
@@ -806,14 +928,15 @@ fn draw_head<Message, Theme, Renderer>(
 
     if let Some(close_layout) = head_children.next() {
         let close_bounds = close_layout.bounds();
-        let is_mouse_over_close = close_bounds.contains(cursor.position().unwrap_or_default());
+        let is_mouse_over_close =
+            settings::hit_area(close_bounds).contains(cursor.position().unwrap_or_default());
 
         renderer.fill_text(
             iced::advanced::text::Text {
                 content: &icon_to_string(Bootstrap::X),
                 bounds: Size::new(close_bounds.width, close_bounds.height),
                 size: Pixels(
-                    close_size.unwrap_or_else(|| renderer.default_size().0)
+                    close_size.unwrap_or_else(|| settings::scaled(renderer.default_size().0))
                         + if is_mouse_over_close { 1.0 } else { 0.0 },
                 ),
                 font: BOOTSTRAP_FONT,
@@ -839,13 +962,12 @@ fn draw_body<Message, Theme, Renderer>(
     cursor: Cursor,
     viewport: &Rectangle,
     theme: &Theme,
-    style: &<Theme as StyleSheet>::Style,
+    style_sheet: &Appearance,
 ) where
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
     Theme: StyleSheet,
 {
     let mut body_children = layout.children();
-    let style_sheet = theme.active(style);
 
     // Body background
     renderer.fill_quad(
@@ -886,13 +1008,12 @@ fn draw_foot<Message, Theme, Renderer>(
     cursor: Cursor,
     viewport: &Rectangle,
     theme: &Theme,
-    style: &<Theme as StyleSheet>::Style,
+    style_sheet: &Appearance,
 ) where
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
     Theme: StyleSheet,
 {
     let mut foot_children = layout.children();
-    let style_sheet = theme.active(style);
 
     // Foot background
     renderer.fill_quad(
@@ -0,0 +1,719 @@
+//! Page through elements one at a time.
+//!
+//! *This API requires the following crate features to be activated: carousel*
+
+use crate::core::{
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    settings,
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    window, Border, Element, Event, Length, Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::time::{Duration, Instant};
+
+pub use crate::style::carousel::{Appearance, StyleSheet};
+
+/// The size of the arrow buttons of a [`Carousel`].
+const ARROW_SIZE: f32 = 32.0;
+/// The size of the dots of the pages of a [`Carousel`].
+const DOT_SIZE: f32 = 8.0;
+/// The space between the controls of a [`Carousel`] and its edges.
+const CONTROL_MARGIN: f32 = 12.0;
+/// The distance the cursor moves before the pressed pages are dragged.
+const DRAG_THRESHOLD: f32 = 8.0;
+/// The share of the width a page is dragged by to move to the next one.
+const SWIPE_DISTANCE: f32 = 0.2;
+/// How quickly the pages slide to the current page, per second.
+const SLIDE_SPEED: f32 = 12.0;
+
+/// A container showing one of its pages at a time, paged through with
+/// swipes, arrow buttons and dot indicators, or automatically.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::carousel::Carousel;
+/// # use std::time::Duration;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PageChanged(usize),
+/// }
+///
+/// let carousel = Carousel::new(vec![
+///     Text::new("First").into(),
+///     Text::new("Second").into(),
+/// ])
+/// .autoplay(Duration::from_secs(5))
+/// .on_page_change(Message::PageChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Carousel<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The pages of the [`Carousel`].
+    pages: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// The page requested by the application.
+    page: Option<usize>,
+    /// The function producing the message when the page changes.
+    on_page_change: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The interval between the pages shown automatically.
+    autoplay: Option<Duration>,
+    /// Whether paging past the last page goes back to the first.
+    wrap: bool,
+    /// Whether the arrow buttons are shown.
+    arrows: bool,
+    /// Whether the dots of the pages are shown.
+    dots: bool,
+    width: Length,
+    height: Length,
+    /// The style of the [`Carousel`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`Carousel`].
+#[derive(Debug, Default)]
+struct State {
+    /// The current page.
+    page: usize,
+    /// The page last requested by the application.
+    requested: Option<usize>,
+    /// The position of the pages, in pages, sliding to the current page.
+    position: f32,
+    /// The position of the cursor and the position of the pages when the
+    /// pages were pressed, and whether they are dragged.
+    drag: Option<(Point, f32, bool)>,
+    /// Whether the cursor is over the carousel, pausing the autoplay.
+    hovered: bool,
+    /// The moment the next page is shown automatically.
+    next_autoplay: Option<Instant>,
+    /// The moment the animation was last advanced.
+    last_update: Option<Instant>,
+}
+
+impl<'a, Message, Theme, Renderer> Carousel<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Carousel`] with the given pages.
+    #[must_use]
+    pub fn new(pages: Vec<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            pages,
+            page: None,
+            on_page_change: None,
+            autoplay: None,
+            wrap: false,
+            arrows: true,
+            dots: true,
+            width: Length::Fill,
+            height: Length::Shrink,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds a page to the [`Carousel`].
+    #[must_use]
+    pub fn push(mut self, page: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.pages.push(page.into());
+        self
+    }
+
+    /// Shows the page at the given index, sliding to it when it changes.
+    #[must_use]
+    pub fn page(mut self, page: usize) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the function producing the message when the user, or the
+    /// autoplay, changes the page, with the index of the new page.
+    #[must_use]
+    pub fn on_page_change(mut self, on_page_change: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_page_change = Some(Box::new(on_page_change));
+        self
+    }
+
+    /// Shows the next page after each `interval`, pausing while the cursor
+    /// is over the [`Carousel`]. The pages wrap around.
+    #[must_use]
+    pub fn autoplay(mut self, interval: Duration) -> Self {
+        self.autoplay = Some(interval);
+        self.wrap = true;
+        self
+    }
+
+    /// Sets whether paging past the last page goes back to the first one.
+    #[must_use]
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets whether the arrow buttons are shown.
+    #[must_use]
+    pub fn arrows(mut self, arrows: bool) -> Self {
+        self.arrows = arrows;
+        self
+    }
+
+    /// Sets whether the dots of the pages are shown.
+    #[must_use]
+    pub fn dots(mut self, dots: bool) -> Self {
+        self.dots = dots;
+        self
+    }
+
+    /// Sets the width of the [`Carousel`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Carousel`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Carousel`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the page `delta` pages away from the current one, if any.
+    fn neighbour(&self, page: usize, delta: isize) -> Option<usize> {
+        let count = self.pages.len();
+        if count == 0 {
+            return None;
+        }
+
+        let target = page.checked_add_signed(delta).filter(|&page| page < count);
+
+        if self.wrap {
+            target.or(Some(if delta < 0 { count - 1 } else { 0 }))
+        } else {
+            target
+        }
+    }
+
+    /// Shows the page at `index`, publishing the change.
+    fn go_to(&self, state: &mut State, index: usize, shell: &mut Shell<'_, Message>) {
+        if index != state.page {
+            state.page = index;
+
+            if let Some(on_page_change) = &self.on_page_change {
+                shell.publish(on_page_change(index));
+            }
+        }
+
+        state.next_autoplay = None;
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+
+    /// Returns the bounds of the previous and next arrow buttons.
+    fn arrow_bounds(&self, bounds: Rectangle) -> Option<(Rectangle, Rectangle)> {
+        (self.arrows && self.pages.len() > 1).then(|| {
+            let y = bounds.center_y() - ARROW_SIZE / 2.0;
+            let size = Size::new(ARROW_SIZE, ARROW_SIZE);
+
+            (
+                Rectangle::new(Point::new(bounds.x + CONTROL_MARGIN, y), size),
+                Rectangle::new(
+                    Point::new(bounds.x + bounds.width - CONTROL_MARGIN - ARROW_SIZE, y),
+                    size,
+                ),
+            )
+        })
+    }
+
+    /// Returns the bounds of the dots of the pages.
+    fn dot_bounds(&self, bounds: Rectangle) -> Vec<Rectangle> {
+        if !self.dots || self.pages.len() < 2 {
+            return Vec::new();
+        }
+
+        // Each dot is pressed within a square twice its size.
+        let hit = DOT_SIZE * 2.0;
+        let width = hit * self.pages.len() as f32;
+        let x = bounds.center_x() - width / 2.0;
+        let y = bounds.y + bounds.height - CONTROL_MARGIN - hit;
+
+        (0..self.pages.len())
+            .map(|i| Rectangle::new(Point::new(x + hit * i as f32, y), Size::new(hit, hit)))
+            .collect()
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Carousel<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + text::Renderer<Font = iced::Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let page = self
+            .page
+            .unwrap_or_default()
+            .min(self.pages.len().saturating_sub(1));
+
+        tree::State::new(State {
+            page,
+            requested: self.page,
+            position: page as f32,
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.pages.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let last = self.pages.len().saturating_sub(1);
+
+        if self.page.is_some() && self.page != state.requested {
+            state.page = self.page.unwrap_or_default();
+        }
+        state.requested = self.page;
+        state.page = state.page.min(last);
+        state.position = state.position.min(last as f32);
+
+        tree.diff_children(&self.pages);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let position = tree.state.downcast_ref::<State>().position;
+        let limits = limits.width(self.width).height(self.height);
+
+        let pages: Vec<Node> = self
+            .pages
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(page, tree)| page.as_widget().layout(tree, renderer, &limits))
+            .collect();
+
+        let intrinsic = pages.iter().fold(Size::ZERO, |size, page| {
+            Size::new(
+                size.width.max(page.size().width),
+                size.height.max(page.size().height),
+            )
+        });
+        let size = limits.resolve(self.width, self.height, intrinsic);
+
+        // The pages are laid side by side, centered in the carousel.
+        let pages = pages
+            .into_iter()
+            .enumerate()
+            .map(|(i, page)| {
+                let page_size = page.size();
+                page.move_to(Point::new(
+                    (i as f32 - position) * size.width + (size.width - page_size.width) / 2.0,
+                    (size.height - page_size.height) / 2.0,
+                ))
+            })
+            .collect();
+
+        Node::with_children(size, pages)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let carousel_state = state.state.downcast_mut::<State>();
+
+        match event {
+            Event::Window(_id, window::Event::RedrawRequested(now)) => {
+                let target = carousel_state.page as f32;
+                let dragging = carousel_state.drag.is_some_and(|(.., dragging)| dragging);
+
+                if !dragging && (carousel_state.position - target).abs() > f32::EPSILON {
+                    let elapsed = carousel_state
+                        .last_update
+                        .map_or(0.0, |last| (now - last).as_secs_f32());
+                    let eased = if settings::reduced_motion() {
+                        1.0
+                    } else {
+                        1.0 - (-elapsed * SLIDE_SPEED).exp()
+                    };
+                    carousel_state.position += (target - carousel_state.position) * eased;
+
+                    if (carousel_state.position - target).abs() < 0.001 {
+                        carousel_state.position = target;
+                        carousel_state.last_update = None;
+                    } else {
+                        carousel_state.last_update = Some(now);
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                    shell.invalidate_layout();
+                }
+
+                if let (Some(interval), false) = (self.autoplay, carousel_state.hovered || dragging)
+                {
+                    match carousel_state.next_autoplay {
+                        Some(next) if now >= next => {
+                            if let Some(page) = self.neighbour(carousel_state.page, 1) {
+                                self.go_to(carousel_state, page, shell);
+                            }
+                            carousel_state.next_autoplay = Some(now + interval);
+                        }
+                        Some(_) => {}
+                        None => carousel_state.next_autoplay = Some(now + interval),
+                    }
+
+                    if let Some(next) = carousel_state.next_autoplay {
+                        shell.request_redraw(window::RedrawRequest::At(next));
+                    }
+                }
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let hovered = bounds.contains(position);
+                if hovered != carousel_state.hovered {
+                    carousel_state.hovered = hovered;
+                    // The autoplay starts over once the cursor leaves.
+                    carousel_state.next_autoplay = None;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                if let Some((origin, start, dragging)) = carousel_state.drag {
+                    let distance = position.x - origin.x;
+
+                    if dragging || distance.abs() > DRAG_THRESHOLD {
+                        let last = self.pages.len().saturating_sub(1) as f32;
+                        carousel_state.drag = Some((origin, start, true));
+                        carousel_state.position =
+                            (start - distance / bounds.width.max(1.0)).clamp(0.0, last);
+                        shell.invalidate_layout();
+                        return event::Status::Captured;
+                    }
+                }
+            }
+
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                carousel_state.hovered = false;
+                carousel_state.next_autoplay = None;
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if let Some((_, start, true)) = carousel_state.drag.take() {
+                    let moved = carousel_state.position - start;
+                    let page = if moved > SWIPE_DISTANCE {
+                        self.neighbour(carousel_state.page, 1)
+                    } else if moved < -SWIPE_DISTANCE {
+                        self.neighbour(carousel_state.page, -1)
+                    } else {
+                        None
+                    }
+                    .unwrap_or(carousel_state.page);
+
+                    self.go_to(carousel_state, page, shell);
+                    return event::Status::Captured;
+                }
+            }
+
+            _ => {}
+        }
+
+        // The arrows and the dots are above the pages.
+        if matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) {
+            if let Some((previous, next)) = self.arrow_bounds(bounds) {
+                let delta = if cursor.is_over(previous) {
+                    Some(-1)
+                } else if cursor.is_over(next) {
+                    Some(1)
+                } else {
+                    None
+                };
+
+                if let Some(delta) = delta {
+                    if let Some(page) = self.neighbour(carousel_state.page, delta) {
+                        self.go_to(carousel_state, page, shell);
+                    }
+                    return event::Status::Captured;
+                }
+            }
+
+            if let Some(page) = self
+                .dot_bounds(bounds)
+                .iter()
+                .position(|dot| cursor.is_over(*dot))
+            {
+                self.go_to(carousel_state, page, shell);
+                return event::Status::Captured;
+            }
+        }
+
+        let page = carousel_state.page;
+        let status = match (
+            self.pages.get_mut(page),
+            state.children.get_mut(page),
+            layout.children().nth(page),
+        ) {
+            (Some(element), Some(tree), Some(page_layout)) => element.as_widget_mut().on_event(
+                tree,
+                event.clone(),
+                page_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ),
+            _ => event::Status::Ignored,
+        };
+
+        if status == event::Status::Ignored {
+            if let (
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }),
+                Some(position),
+            ) = (&event, cursor.position_over(bounds))
+            {
+                let carousel_state = state.state.downcast_mut::<State>();
+                carousel_state.drag = Some((position, carousel_state.position, false));
+                return event::Status::Captured;
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let carousel_state = state.state.downcast_ref::<State>();
+
+        if carousel_state.drag.is_some_and(|(.., dragging)| dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let over_control = self
+            .arrow_bounds(bounds)
+            .is_some_and(|(previous, next)| cursor.is_over(previous) || cursor.is_over(next))
+            || self
+                .dot_bounds(bounds)
+                .iter()
+                .any(|dot| cursor.is_over(*dot));
+        if over_control {
+            return mouse::Interaction::Pointer;
+        }
+
+        let page = carousel_state.page;
+        match (
+            self.pages.get(page),
+            state.children.get(page),
+            layout.children().nth(page),
+        ) {
+            (Some(element), Some(tree), Some(page_layout)) => element
+                .as_widget()
+                .mouse_interaction(tree, page_layout, cursor, viewport, renderer),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let carousel_state = state.state.downcast_ref::<State>();
+        let appearance = theme.active(&self.style);
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                Quad {
+                    bounds,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                background,
+            );
+        }
+
+        renderer.with_layer(bounds, |renderer| {
+            for (i, ((page, tree), page_layout)) in self
+                .pages
+                .iter()
+                .zip(&state.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                // Only the pages sliding through the carousel are drawn.
+                if (i as f32 - carousel_state.position).abs() < 1.0 {
+                    page.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        style,
+                        page_layout,
+                        cursor,
+                        viewport,
+                    );
+                }
+            }
+        });
+
+        if let Some((previous, next)) = self.arrow_bounds(bounds) {
+            for (arrow, icon) in [
+                (previous, Bootstrap::CaretLeftFill),
+                (next, Bootstrap::CaretRightFill),
+            ] {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: arrow,
+                        border: Border::with_radius(ARROW_SIZE / 2.0),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.arrow_background,
+                );
+
+                renderer.fill_text(
+                    Text {
+                        content: &icon_to_string(icon),
+                        bounds: arrow.size(),
+                        size: Pixels(ARROW_SIZE / 2.0),
+                        line_height: LineHeight::default(),
+                        font: BOOTSTRAP_FONT,
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                        shaping: Shaping::Basic,
+                    },
+                    arrow.center(),
+                    appearance.arrow_color,
+                    arrow,
+                );
+            }
+        }
+
+        for (i, dot) in self.dot_bounds(bounds).into_iter().enumerate() {
+            let center = dot.center();
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle::new(
+                        Point::new(center.x - DOT_SIZE / 2.0, center.y - DOT_SIZE / 2.0),
+                        Size::new(DOT_SIZE, DOT_SIZE),
+                    ),
+                    border: Border::with_radius(DOT_SIZE / 2.0),
+                    shadow: Shadow::default(),
+                },
+                if i == carousel_state.page {
+                    appearance.active_dot_color
+                } else {
+                    appearance.dot_color
+                },
+            );
+        }
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.pages
+                .iter()
+                .zip(&mut state.children)
+                .zip(layout.children())
+                .for_each(|((page, tree), page_layout)| {
+                    page.as_widget()
+                        .operate(tree, page_layout, renderer, operation);
+                });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let page = state.state.downcast_ref::<State>().page;
+
+        self.pages.get_mut(page)?.as_widget_mut().overlay(
+            state.children.get_mut(page)?,
+            layout.children().nth(page)?,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Carousel<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + text::Renderer<Font = iced::Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(carousel: Carousel<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(carousel)
+    }
+}
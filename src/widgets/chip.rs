@@ -0,0 +1,552 @@
+//! Show compact labels that can be pressed, toggled or deleted.
+//!
+//! *This API requires the following crate features to be activated: `chip`*
+
+use crate::core::{
+    icons::{bootstrap::icon_to_string, Bootstrap, IconHandle, IconRenderer, BOOTSTRAP_FONT},
+    settings,
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{Paragraph, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Element, Event, Font, Length, Padding, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+pub use crate::style::chip::{Appearance, StyleSheet};
+
+/// The default padding of a [`Chip`].
+const DEFAULT_PADDING: [f32; 2] = [4.0, 10.0];
+/// The default space between the parts of a [`Chip`].
+const DEFAULT_SPACING: f32 = 6.0;
+
+/// The function producing the message when a [`Chip`] is toggled.
+type OnToggle<'a, Message> = Box<dyn Fn(bool) -> Message + 'a>;
+
+/// A part of a [`Chip`] that can be pressed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Part {
+    /// The chip itself.
+    Body,
+    /// The cross deleting the chip.
+    Delete,
+}
+
+/// The state of a [`Chip`].
+#[derive(Debug, Default)]
+struct State {
+    /// The part pressed and not released yet.
+    pressed: Option<Part>,
+}
+
+/// A compact label with an optional leading icon or avatar and an optional
+/// cross deleting it.
+///
+/// A chip is pressed like a button with [`on_press`](Chip::on_press), or
+/// toggled with [`on_toggle`](Chip::on_toggle), showing whether it is
+/// [`selected`](Chip::selected). Rows of filter chips are laid out with a
+/// [`Wrap`](crate::widgets::Wrap).
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{chip::Chip, Bootstrap};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Toggled(bool),
+///     Deleted,
+/// }
+///
+/// let chip = Chip::new("Rust")
+///     .icon(Bootstrap::Tag)
+///     .selected(true)
+///     .on_toggle(Message::Toggled)
+///     .on_delete(Message::Deleted);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Chip<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Message: Clone,
+    Renderer: IconRenderer,
+    Theme: StyleSheet,
+{
+    /// The label of the [`Chip`].
+    label: String,
+    /// The leading icon of the [`Chip`].
+    icon: Option<IconHandle>,
+    /// The leading avatar of the [`Chip`], shown instead of the icon.
+    avatar: Option<Element<'a, Message, Theme, Renderer>>,
+    /// The message produced when the [`Chip`] is pressed.
+    on_press: Option<Message>,
+    /// The function producing the message when the [`Chip`] is toggled.
+    on_toggle: Option<OnToggle<'a, Message>>,
+    /// The message produced when the delete cross is pressed.
+    on_delete: Option<Message>,
+    /// Whether the [`Chip`] is selected.
+    selected: bool,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: f32,
+    font: Font,
+    icon_font: Font,
+    /// The style of the [`Chip`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Chip<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: IconRenderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Chip`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            avatar: None,
+            on_press: None,
+            on_toggle: None,
+            on_delete: None,
+            selected: false,
+            width: Length::Shrink,
+            padding: DEFAULT_PADDING.map(settings::spaced).into(),
+            spacing: settings::spaced(DEFAULT_SPACING),
+            text_size: settings::scaled(14.0),
+            font: settings::font(),
+            icon_font: BOOTSTRAP_FONT,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the leading icon of the [`Chip`], a glyph written with the icon
+    /// font unless it has its own, or an SVG.
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<IconHandle>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the leading avatar of the [`Chip`], shown instead of the icon in a
+    /// square as high as the label.
+    #[must_use]
+    pub fn avatar(mut self, avatar: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.avatar = Some(avatar.into());
+        self
+    }
+
+    /// Sets the message produced when the [`Chip`] is pressed.
+    #[must_use]
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the function producing the message when the [`Chip`] is toggled.
+    /// It receives whether the [`Chip`] becomes selected.
+    #[must_use]
+    pub fn on_toggle<F>(mut self, on_toggle: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the message produced when the delete cross is pressed, showing it.
+    #[must_use]
+    pub fn on_delete(mut self, message: Message) -> Self {
+        self.on_delete = Some(message);
+        self
+    }
+
+    /// Sets whether the [`Chip`] is selected.
+    #[must_use]
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the width of the [`Chip`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the [`Chip`].
+    #[must_use]
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the space between the parts of the [`Chip`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the [`Chip`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the label of the [`Chip`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the font of the leading icon of the [`Chip`].
+    #[must_use]
+    pub fn icon_font(mut self, font: impl Into<Font>) -> Self {
+        self.icon_font = font.into();
+        self
+    }
+
+    /// Sets the style of the [`Chip`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Whether the [`Chip`] can be pressed.
+    fn is_interactive(&self) -> bool {
+        self.on_press.is_some() || self.on_toggle.is_some()
+    }
+
+    /// Whether the [`Chip`] has a leading icon or avatar.
+    fn has_leading(&self) -> bool {
+        self.avatar.is_some() || self.icon.is_some()
+    }
+
+    /// Returns the height of the label.
+    fn line_height(&self) -> f32 {
+        LineHeight::default()
+            .to_absolute(Pixels(self.text_size))
+            .0
+            .round()
+    }
+
+    /// Returns the text of `content` centered vertically in `bounds`.
+    fn text(content: &str, bounds: Size, font: Font, size: f32) -> Text<'_, Font> {
+        Text {
+            content,
+            bounds,
+            size: Pixels(size),
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Chip<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + IconRenderer,
+    Theme: 'a + StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.avatar.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(self.avatar.as_slice());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let line = self.line_height();
+        let leading = if self.has_leading() {
+            line + self.spacing
+        } else {
+            0.0
+        };
+        let delete = if self.on_delete.is_some() {
+            line + self.spacing
+        } else {
+            0.0
+        };
+        let label = Renderer::Paragraph::with_text(Text {
+            vertical_alignment: Vertical::Top,
+            ..Self::text(&self.label, Size::INFINITY, self.font, self.text_size)
+        })
+        .min_width();
+
+        let intrinsic = Size::new(
+            self.padding.horizontal() + leading + label + delete,
+            self.padding.vertical() + line,
+        );
+        let size = limits.width(self.width).height(Length::Shrink).resolve(
+            self.width,
+            Length::Shrink,
+            intrinsic,
+        );
+
+        let children = match (&self.avatar, tree.children.first_mut()) {
+            (Some(avatar), Some(avatar_tree)) => vec![avatar
+                .as_widget()
+                .layout(
+                    avatar_tree,
+                    renderer,
+                    &Limits::new(Size::ZERO, Size::new(line, line)),
+                )
+                .move_to(Point::new(self.padding.left, (size.height - line) / 2.0))],
+            _ => Vec::new(),
+        };
+
+        Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let part = if self.on_delete.is_some()
+            && cursor.is_over(settings::hit_area(delete_bounds(self, bounds)))
+        {
+            Some(Part::Delete)
+        } else if self.is_interactive() && cursor.is_over(bounds) {
+            Some(Part::Body)
+        } else {
+            None
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.pressed = part;
+
+                if part.is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let Some(pressed) = state.pressed.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if part == Some(pressed) {
+                    let message = match pressed {
+                        Part::Delete => self.on_delete.clone(),
+                        Part::Body => self.on_toggle.as_ref().map_or_else(
+                            || self.on_press.clone(),
+                            |on_toggle| Some(on_toggle(!self.selected)),
+                        ),
+                    };
+
+                    if let Some(message) = message {
+                        shell.publish(message);
+                    }
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+                event::Status::Ignored
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        if (self.on_delete.is_some()
+            && cursor.is_over(settings::hit_area(delete_bounds(self, bounds))))
+            || (self.is_interactive() && cursor.is_over(bounds))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let line = self.line_height();
+        let appearance = if self.is_interactive() && cursor.is_over(bounds) {
+            theme.hovered(&self.style, self.selected)
+        } else {
+            theme.active(&self.style, self.selected)
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: appearance
+                        .border_radius
+                        .unwrap_or(bounds.height / 2.0)
+                        .into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        let mut x = bounds.x + self.padding.left;
+        let leading = Rectangle::new(
+            Point::new(x, bounds.center_y() - line / 2.0),
+            Size::new(line, line),
+        );
+
+        match (&self.avatar, layout.children().next(), &self.icon) {
+            (Some(avatar), Some(avatar_layout), _) => {
+                avatar.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    avatar_layout,
+                    cursor,
+                    viewport,
+                );
+                x += line + self.spacing;
+            }
+            (None, _, Some(icon)) => {
+                icon.draw(
+                    renderer,
+                    leading,
+                    self.text_size,
+                    self.icon_font,
+                    appearance.icon_color,
+                );
+                x += line + self.spacing;
+            }
+            _ => {}
+        }
+
+        let end = if self.on_delete.is_some() {
+            let delete = delete_bounds(self, bounds);
+
+            renderer.fill_text(
+                Text {
+                    horizontal_alignment: Horizontal::Center,
+                    ..Self::text(
+                        &icon_to_string(Bootstrap::X),
+                        delete.size(),
+                        BOOTSTRAP_FONT,
+                        self.text_size,
+                    )
+                },
+                delete.center(),
+                appearance.icon_color,
+                delete,
+            );
+            delete.x - self.spacing
+        } else {
+            bounds.x + bounds.width - self.padding.right
+        };
+
+        let label = Rectangle::new(
+            Point::new(x, bounds.y),
+            Size::new((end - x).max(0.0), bounds.height),
+        );
+        renderer.fill_text(
+            Self::text(&self.label, label.size(), self.font, self.text_size),
+            Point::new(label.x, label.center_y()),
+            appearance.text_color,
+            label,
+        );
+    }
+}
+
+/// Returns the bounds of the delete cross of a chip with the given bounds.
+fn delete_bounds<Message, Theme, Renderer>(
+    chip: &Chip<'_, Message, Theme, Renderer>,
+    bounds: Rectangle,
+) -> Rectangle
+where
+    Message: Clone,
+    Renderer: IconRenderer,
+    Theme: StyleSheet,
+{
+    let line = chip.line_height();
+
+    Rectangle::new(
+        Point::new(
+            bounds.x + bounds.width - chip.padding.right - line,
+            bounds.center_y() - line / 2.0,
+        ),
+        Size::new(line, line),
+    )
+}
+
+impl<'a, Message, Theme, Renderer> From<Chip<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + IconRenderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(chip: Chip<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(chip)
+    }
+}
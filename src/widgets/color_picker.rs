@@ -5,6 +5,10 @@
 use super::overlay::color_picker::{
     self, ColorBarDragged, ColorPickerOverlay, ColorPickerOverlayButtons,
 };
+use crate::core::{
+    accessibility::{self, Action, Role},
+    color::HexString,
+};
 
 use iced::{
     advanced::{
@@ -29,7 +33,10 @@ use iced::{
     Vector,
 };
 
-pub use crate::style::color_picker::{Appearance, StyleSheet};
+pub use crate::{
+    style::color_picker::{Appearance, StyleSheet},
+    widgets::overlay::color_picker::Focus,
+};
 
 //TODO: Remove ignore when Null is updated. Temp fix for Test runs
 /// An input element for picking colors.
@@ -63,6 +70,8 @@ where
 {
     /// Show the picker.
     show_picker: bool,
+    /// The id of the [`ColorPicker`].
+    id: Option<widget::Id>,
     /// The color to show.
     color: Color,
     /// The underlying element.
@@ -75,6 +84,8 @@ where
     style: <Theme as StyleSheet>::Style,
     /// The buttons of the overlay.
     overlay_state: Element<'a, Message, Theme, Renderer>,
+    /// The parts of the overlay reached with Tab, in order.
+    tab_order: Vec<Focus>,
 }
 
 impl<'a, Message, Theme> ColorPicker<'a, Message, Theme>
@@ -106,19 +117,42 @@ where
     {
         Self {
             show_picker,
+            id: None,
             color,
             underlay: underlay.into(),
             on_cancel,
             on_submit: Box::new(on_submit),
             style: <Theme as StyleSheet>::Style::default(),
             overlay_state: ColorPickerOverlayButtons::default().into(),
+            tab_order: Vec::new(),
         }
     }
 
+    /// Sets the id of the [`ColorPicker`], to focus its overlay with an
+    /// operation while it is shown. The focused overlay takes the keyboard
+    /// input, and Tab moves the focus between its parts.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the parts of the overlay of the [`ColorPicker`] reached with Tab, in
+    /// order. The parts left out are skipped; by default all of them are
+    /// reached.
+    #[must_use]
+    pub fn tab_order(mut self, tab_order: impl IntoIterator<Item = Focus>) -> Self {
+        self.tab_order = tab_order
+            .into_iter()
+            .filter(|part| *part != Focus::None)
+            .collect();
+        self
+    }
+
     /// Sets the style of the [`ColorPicker`].
     #[must_use]
-    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
-        self.style = style;
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
         self
     }
 }
@@ -244,6 +278,29 @@ where
         );
     }
 
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        let mut node = accessibility::Node::new(Role::ColorPicker)
+            .name(self.color.as_hex_string())
+            .expanded(self.show_picker);
+
+        if self.show_picker {
+            let picker_state: &mut State = state.state.downcast_mut();
+            operation.focusable(&mut picker_state.overlay_state, self.id.as_ref());
+            operation.custom(&mut node.action(Action::Focus), self.id.as_ref());
+        } else {
+            operation.custom(&mut node, self.id.as_ref());
+            self.underlay
+                .as_widget()
+                .operate(&mut state.children[0], layout, renderer, operation);
+        }
+    }
+
     fn overlay<'b>(
         &'b mut self,
         state: &'b mut Tree,
@@ -274,6 +331,7 @@ where
                 self.style.clone(),
                 &mut state.children[1],
             )
+            .tab_order(&self.tab_order)
             .overlay(),
         )
     }
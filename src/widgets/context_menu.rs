@@ -4,13 +4,14 @@ use iced::{
     advanced::{
         layout::{Limits, Node},
         overlay, renderer,
-        widget::{tree, Operation, Tree},
+        widget::{tree, Id, Operation, Tree},
         Clipboard, Layout, Shell, Widget,
     },
     event,
     mouse::{self, Button, Cursor},
-    Element, Event, Length, Point, Rectangle, Vector,
+    Command, Element, Event, Length, Point, Rectangle, Vector,
 };
+use std::any::Any;
 
 pub use crate::style::context_menu::StyleSheet;
 use crate::widgets::overlay::ContextMenuOverlay;
@@ -35,6 +36,25 @@ use crate::widgets::overlay::ContextMenuOverlay;
 ///     || Button::new("action1").on_press(Message::Action1).into()
 /// );
 /// ```
+///
+/// A single menu definition can be shared by many underlays by attaching a
+/// payload to each of them with [`ContextMenu::with_data`]:
+/// ```ignore
+/// # use iced::widget::{Text, Button};
+/// # use iced_aw::ContextMenu;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Delete(usize),
+/// }
+///
+/// let rows = (0..3).map(|id| {
+///     ContextMenu::with_data(Text::new(format!("row {id}")), id, |id| {
+///         Button::new("delete").on_press(Message::Delete(*id)).into()
+///     })
+///     .into()
+/// });
+/// ```
 #[allow(missing_debug_implementations)]
 pub struct ContextMenu<'a, Overlay, Message, Theme = iced::Theme, Renderer = iced::Renderer>
 where
@@ -49,6 +69,10 @@ where
     overlay: Overlay,
     /// The style of the [`ContextMenu`].
     style: <Theme as StyleSheet>::Style,
+    /// The id of the [`ContextMenu`], used by [`open`] and [`close`].
+    id: Option<Id>,
+    /// Whether the [`ContextMenu`] is disabled.
+    disabled: bool,
 }
 
 impl<'a, Overlay, Message, Theme, Renderer> ContextMenu<'a, Overlay, Message, Theme, Renderer>
@@ -71,17 +95,64 @@ where
             underlay: underlay.into(),
             overlay,
             style: <Theme as StyleSheet>::Style::default(),
+            id: None,
+            disabled: false,
         }
     }
 
+    /// Sets the [`Id`] of the [`ContextMenu`].
+    ///
+    /// The [`Id`] allows the menu to be opened and closed programmatically
+    /// with [`open`] and [`close`].
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets whether the [`ContextMenu`] is disabled. The menu of a disabled
+    /// [`ContextMenu`] is not shown, neither on right click nor with
+    /// [`open`], while its underlying element stays usable.
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
     /// Sets the style of the [`ContextMenu`].
     #[must_use]
-    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
-        self.style = style;
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
         self
     }
 }
 
+impl<'a, Message, Theme, Renderer>
+    ContextMenu<'a, DataOverlay<'a, Message, Theme, Renderer>, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`ContextMenu`] carrying the given payload.
+    ///
+    /// `underlay`: The underlying element.
+    ///
+    /// `data`: The payload attached to this [`ContextMenu`], e.g. the id of the
+    /// row it wraps.
+    ///
+    /// `overlay`: The content of [`ContextMenuOverlay`] which will be displayed when `underlay` is clicked.
+    /// It receives a reference to `data`.
+    pub fn with_data<U, D, F>(underlay: U, data: D, overlay: F) -> Self
+    where
+        U: Into<Element<'a, Message, Theme, Renderer>>,
+        D: 'a,
+        F: 'a + Fn(&D) -> Element<'a, Message, Theme, Renderer>,
+    {
+        Self::new(underlay, Box::new(move || overlay(&data)))
+    }
+}
+
 impl<'a, Content, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for ContextMenu<'a, Content, Message, Theme, Renderer>
 where
@@ -130,11 +201,13 @@ where
     }
 
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.underlay), Tree::new(&(self.overlay)())]
+        vec![Tree::new(&self.underlay), Tree::empty()]
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.underlay, &(self.overlay)()]);
+        // The content of the menu is only built and diffed while it is shown.
+        tree.children.resize_with(2, Tree::empty);
+        tree.children[0].diff(&self.underlay);
     }
 
     fn operate<'b>(
@@ -146,9 +219,17 @@ where
     ) {
         let s: &mut State = state.state.downcast_mut();
 
-        if s.show {
+        operation.custom(s, self.id.as_ref());
+
+        if s.anchor_to_underlay {
+            let bounds = layout.bounds();
+            s.cursor_position = Point::new(bounds.x, bounds.y + bounds.height);
+            s.anchor_to_underlay = false;
+        }
+
+        if s.show && !self.disabled {
             let content = (self.overlay)();
-            content.as_widget().diff(&mut state.children[1]);
+            state.children[1].diff(&content);
 
             content
                 .as_widget()
@@ -171,7 +252,9 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        if event == Event::Mouse(mouse::Event::ButtonPressed(Button::Right)) {
+        if self.disabled {
+            state.state.downcast_mut::<State>().show = false;
+        } else if event == Event::Mouse(mouse::Event::ButtonPressed(Button::Right)) {
             let bounds = layout.bounds();
 
             if cursor.is_over(bounds) {
@@ -220,7 +303,7 @@ where
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         let s: &mut State = state.state.downcast_mut();
 
-        if !s.show {
+        if !s.show || self.disabled {
             return self.underlay.as_widget_mut().overlay(
                 &mut state.children[0],
                 layout,
@@ -231,7 +314,7 @@ where
 
         let position = s.cursor_position;
         let content = (self.overlay)();
-        content.as_widget().diff(&mut state.children[1]);
+        state.children[1].diff(&content);
         Some(
             ContextMenuOverlay::new(
                 position + translation,
@@ -258,6 +341,10 @@ where
     }
 }
 
+/// The content of a [`ContextMenu`] created by [`ContextMenu::with_data`].
+pub type DataOverlay<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> =
+    Box<dyn Fn() -> Element<'a, Message, Theme, Renderer> + 'a>;
+
 /// The state of the ``context_menu``.
 #[derive(Debug, Default)]
 pub(crate) struct State {
@@ -265,6 +352,8 @@ pub(crate) struct State {
     pub show: bool,
     /// Use for showing the overlay where the click was made.
     pub cursor_position: Point,
+    /// Whether the overlay position should be computed from the underlay bounds.
+    pub anchor_to_underlay: bool,
 }
 
 impl State {
@@ -273,6 +362,69 @@ impl State {
         Self {
             show: false,
             cursor_position: Point::ORIGIN,
+            anchor_to_underlay: false,
         }
     }
 }
+
+/// The request performed on a [`ContextMenu`] by an [`Operation`].
+#[derive(Clone, Copy, Debug)]
+enum Request {
+    /// Open the menu, optionally at the given position.
+    Open(Option<Point>),
+    /// Close the menu.
+    Close,
+}
+
+/// Produces an [`Operation`] applying the given [`Request`] to the
+/// [`ContextMenu`] with the given [`Id`].
+fn request<T>(target: Id, request: Request) -> impl Operation<T> {
+    struct Apply {
+        target: Id,
+        request: Request,
+    }
+
+    impl<T> Operation<T> for Apply {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            if id != Some(&self.target) {
+                return;
+            }
+
+            if let Some(state) = state.downcast_mut::<State>() {
+                match self.request {
+                    Request::Open(position) => {
+                        state.show = true;
+                        match position {
+                            Some(position) => state.cursor_position = position,
+                            None => state.anchor_to_underlay = true,
+                        }
+                    }
+                    Request::Close => state.show = false,
+                }
+            }
+        }
+    }
+
+    Apply { target, request }
+}
+
+/// Produces a [`Command`] that opens the [`ContextMenu`] with the given [`Id`].
+///
+/// The menu is shown at `position` or, if `None`, below the underlying element.
+pub fn open<Message: 'static>(id: Id, position: Option<Point>) -> Command<Message> {
+    Command::widget(request(id, Request::Open(position)))
+}
+
+/// Produces a [`Command`] that closes the [`ContextMenu`] with the given [`Id`].
+pub fn close<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(request(id, Request::Close))
+}
@@ -0,0 +1,339 @@
+use crate::{
+    core::animation::{self, Timeline},
+    widgets::overlay::cupertino_action_sheet::ActionSheetOverlay,
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay::{self, Group},
+        renderer, text,
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    window, Element, Event, Font, Length, Rectangle, Size, Vector,
+};
+use std::time::Duration;
+
+/// The duration of the slide-up and slide-down animations.
+const ANIMATION_DURATION: Duration = Duration::from_millis(250);
+
+/**
+ * `CupertinoActionSheet`
+ *
+ * See
+ *
+ * <https://github.com/flutter/flutter/blob/master/packages/flutter/lib/src/cupertino/dialog.dart>
+ *
+ * for constants, and
+ *
+ * <https://api.flutter.dev/flutter/cupertino/CupertinoActionSheet-class.html>
+ *
+ * for the Flutter example / expected usage.
+ *
+ * The sheet slides up from the bottom of the window over the `underlay` while
+ * `show` is true. Pressing an action publishes its message; the application
+ * is expected to hide the sheet in response.
+ *
+ */
+#[allow(missing_debug_implementations)]
+pub struct CupertinoActionSheet<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    underlay: Element<'a, Message, Theme, Renderer>,
+    show: bool,
+    sheet: Sheet<Message>,
+}
+
+/// The content of a [`CupertinoActionSheet`].
+#[derive(Debug, Clone)]
+pub struct Sheet<Message> {
+    /// The title shown above the actions.
+    pub title: Option<String>,
+    /// The message shown under the title.
+    pub message: Option<String>,
+    /// The actions of the sheet.
+    pub actions: Vec<SheetAction<Message>>,
+    /// The cancel action, shown apart from the others.
+    pub cancel: Option<SheetAction<Message>>,
+    /// The message published when the backdrop is pressed or Escape is pressed.
+    pub on_dismiss: Option<Message>,
+}
+
+/// An action of a [`CupertinoActionSheet`].
+#[derive(Debug, Clone)]
+pub struct SheetAction<Message> {
+    /// The label of the action.
+    pub label: String,
+    /// The message published when the action is pressed.
+    pub message: Message,
+    /// Whether the action destroys data, shown in red.
+    pub destructive: bool,
+}
+
+/// The state of a [`CupertinoActionSheet`].
+#[derive(Debug, Default)]
+pub struct ActionSheetState {
+    /// The progress of the slide-up animation, from 0 (hidden) to 1 (shown).
+    pub(crate) slide: Timeline,
+    /// The index of the pressed action, the cancel action being after the others.
+    pub(crate) pressed: Option<usize>,
+}
+
+impl<'a, Message, Theme, Renderer> CupertinoActionSheet<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`CupertinoActionSheet`] over the `underlay`, shown while
+    /// `show` is true.
+    pub fn new(underlay: impl Into<Element<'a, Message, Theme, Renderer>>, show: bool) -> Self {
+        Self {
+            underlay: underlay.into(),
+            show,
+            sheet: Sheet {
+                title: None,
+                message: None,
+                actions: Vec::new(),
+                cancel: None,
+                on_dismiss: None,
+            },
+        }
+    }
+
+    /// Sets the title of the [`CupertinoActionSheet`].
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.sheet.title = Some(title.into());
+        self
+    }
+
+    /// Sets the message of the [`CupertinoActionSheet`], shown under the title.
+    #[must_use]
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.sheet.message = Some(message.into());
+        self
+    }
+
+    /// Adds an action to the [`CupertinoActionSheet`].
+    #[must_use]
+    pub fn action(mut self, label: impl Into<String>, message: Message) -> Self {
+        self.sheet.actions.push(SheetAction {
+            label: label.into(),
+            message,
+            destructive: false,
+        });
+        self
+    }
+
+    /// Adds a destructive action to the [`CupertinoActionSheet`], shown in red.
+    #[must_use]
+    pub fn destructive_action(mut self, label: impl Into<String>, message: Message) -> Self {
+        self.sheet.actions.push(SheetAction {
+            label: label.into(),
+            message,
+            destructive: true,
+        });
+        self
+    }
+
+    /// Sets the cancel action of the [`CupertinoActionSheet`], shown apart
+    /// under the other actions.
+    #[must_use]
+    pub fn cancel(mut self, label: impl Into<String>, message: Message) -> Self {
+        self.sheet.cancel = Some(SheetAction {
+            label: label.into(),
+            message,
+            destructive: false,
+        });
+        self
+    }
+
+    /// Sets the message published when the backdrop of the
+    /// [`CupertinoActionSheet`] or Escape is pressed.
+    #[must_use]
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.sheet.on_dismiss = Some(message);
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for CupertinoActionSheet<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<ActionSheetState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(ActionSheetState {
+            slide: Timeline::new(if self.show { 1.0 } else { 0.0 }),
+            ..ActionSheetState::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.underlay));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.underlay
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            let sheet_state = state.state.downcast_mut::<ActionSheetState>();
+            let target = if self.show { 1.0 } else { 0.0 };
+
+            if sheet_state
+                .slide
+                .advance_to(target, ANIMATION_DURATION, now)
+            {
+                animation::request_frame(shell, now);
+            }
+
+            if !self.show {
+                sheet_state.pressed = None;
+            }
+        }
+
+        // Like a modal, the sheet blocks the underlay while it is shown.
+        if self.show {
+            return event::Status::Ignored;
+        }
+
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.underlay
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut group = Group::new();
+        let sheet_state = state.state.downcast_mut::<ActionSheetState>();
+
+        if let Some(underlay) = self.underlay.as_widget_mut().overlay(
+            &mut state.children[0],
+            layout,
+            renderer,
+            translation,
+        ) {
+            group = group.push(underlay);
+        }
+
+        // The sheet stays shown while it slides down.
+        if self.show || sheet_state.slide.progress() > 0.0 {
+            group = group.push(overlay::Element::new(Box::new(ActionSheetOverlay::new(
+                sheet_state,
+                &self.sheet,
+                self.show,
+            ))));
+        }
+
+        Some(group.overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CupertinoActionSheet<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font> + 'a,
+    Theme: 'a,
+{
+    fn from(action_sheet: CupertinoActionSheet<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(action_sheet)
+    }
+}
@@ -0,0 +1,379 @@
+use crate::core::settings;
+
+use crate::widgets::cupertino::cupertino_picker::{
+    draw_selection_band, Wheel, ROW_HEIGHT, TEXT_SIZE, VISIBLE_ROWS,
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer, text,
+        widget::tree::{State, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    Color, Element, Event, Font, Length, Rectangle, Size,
+};
+use std::ops::RangeInclusive;
+use time::{util::days_in_year_month, Date, Month, PrimitiveDateTime, Time};
+
+/**
+ * `CupertinoDatePicker`
+ *
+ * See
+ *
+ * <https://github.com/flutter/flutter/blob/master/packages/flutter/lib/src/cupertino/date_picker.dart>
+ *
+ * for constants, and
+ *
+ * <https://api.flutter.dev/flutter/cupertino/CupertinoDatePicker-class.html>
+ *
+ * for the Flutter example / expected usage.
+ *
+ * Each part of the date and time is selected on its own wheel. `on_change`
+ * is called with the new value whenever a wheel comes to rest.
+ *
+ */
+#[allow(missing_debug_implementations)]
+pub struct CupertinoDatePicker<Message> {
+    value: PrimitiveDateTime,
+    mode: DatePickerMode,
+    years: RangeInclusive<i32>,
+    on_change: Option<Box<dyn Fn(PrimitiveDateTime) -> Message>>,
+    width: Length,
+    text_size: f32,
+    text_colour: Color,
+}
+
+/// The parts of the value selected by a [`CupertinoDatePicker`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DatePickerMode {
+    /// The month, day and year.
+    #[default]
+    Date,
+    /// The hour and minute.
+    Time,
+    /// The month, day, year, hour and minute.
+    DateAndTime,
+}
+
+/// A wheel of a [`CupertinoDatePicker`].
+#[derive(Clone, Copy, Debug)]
+enum Column {
+    Month,
+    Day,
+    Year,
+    Hour,
+    Minute,
+}
+
+impl DatePickerMode {
+    fn columns(self) -> &'static [Column] {
+        match self {
+            Self::Date => &[Column::Month, Column::Day, Column::Year],
+            Self::Time => &[Column::Hour, Column::Minute],
+            Self::DateAndTime => &[
+                Column::Month,
+                Column::Day,
+                Column::Year,
+                Column::Hour,
+                Column::Minute,
+            ],
+        }
+    }
+}
+
+impl Column {
+    /// The share of the width taken by the wheel.
+    fn weight(self) -> f32 {
+        match self {
+            Self::Month => 2.0,
+            Self::Year => 1.5,
+            Self::Day | Self::Hour | Self::Minute => 1.0,
+        }
+    }
+
+    /// The number of rows of the wheel.
+    fn count(self, value: PrimitiveDateTime, years: &RangeInclusive<i32>) -> usize {
+        match self {
+            Self::Month => 12,
+            Self::Day => days_in_year_month(value.year(), value.month()).into(),
+            Self::Year => (years.end() - years.start() + 1).max(1) as usize,
+            Self::Hour => 24,
+            Self::Minute => 60,
+        }
+    }
+
+    /// The row of the wheel selected by the value.
+    fn index(self, value: PrimitiveDateTime, years: &RangeInclusive<i32>) -> usize {
+        match self {
+            Self::Month => u8::from(value.month()) as usize - 1,
+            Self::Day => value.day() as usize - 1,
+            Self::Year => (value.year() - years.start()).max(0) as usize,
+            Self::Hour => value.hour().into(),
+            Self::Minute => value.minute().into(),
+        }
+    }
+
+    /// The label of a row of the wheel.
+    fn label(self, row: usize, years: &RangeInclusive<i32>) -> String {
+        match self {
+            Self::Month => Month::try_from(row as u8 + 1)
+                .map(|month| month.to_string())
+                .unwrap_or_default(),
+            Self::Day | Self::Hour => (row + usize::from(matches!(self, Self::Day))).to_string(),
+            Self::Year => (years.start() + row as i32).to_string(),
+            Self::Minute => format!("{row:02}"),
+        }
+    }
+
+    /// Returns the value with the row of the wheel selected, the day being
+    /// clamped to the length of the month.
+    fn apply(
+        self,
+        value: PrimitiveDateTime,
+        row: usize,
+        years: &RangeInclusive<i32>,
+    ) -> PrimitiveDateTime {
+        let (mut year, mut month, mut day) = (value.year(), value.month(), value.day());
+        let (mut hour, mut minute) = (value.hour(), value.minute());
+
+        match self {
+            Self::Month => month = Month::try_from(row as u8 + 1).unwrap_or(month),
+            Self::Day => day = row as u8 + 1,
+            Self::Year => year = years.start() + row as i32,
+            Self::Hour => hour = row as u8,
+            Self::Minute => minute = row as u8,
+        }
+
+        let day = day.min(days_in_year_month(year, month));
+
+        match (
+            Date::from_calendar_date(year, month, day),
+            Time::from_hms(hour, minute, value.second()),
+        ) {
+            (Ok(date), Ok(time)) => PrimitiveDateTime::new(date, time),
+            _ => value,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct DatePickerState {
+    wheels: Vec<Wheel>,
+}
+
+impl<Message> CupertinoDatePicker<Message> {
+    /// Creates a new [`CupertinoDatePicker`] widget with the given `value`.
+    #[must_use]
+    pub fn new(value: PrimitiveDateTime) -> Self {
+        Self {
+            value,
+            mode: DatePickerMode::default(),
+            years: 1900..=2100,
+            on_change: None,
+            width: Length::Fill,
+            text_size: settings::scaled(TEXT_SIZE),
+            text_colour: Color::BLACK,
+        }
+    }
+
+    /// Sets the parts of the value selected by the [`CupertinoDatePicker`].
+    #[must_use]
+    pub fn mode(mut self, mode: DatePickerMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the range of years selectable with the [`CupertinoDatePicker`].
+    #[must_use]
+    pub fn years(mut self, years: RangeInclusive<i32>) -> Self {
+        self.years = years;
+        self
+    }
+
+    /// Sets the `on_change` callback of the [`CupertinoDatePicker`], called
+    /// with the new value whenever a wheel comes to rest.
+    #[must_use]
+    pub fn on_change(mut self, on_change: impl Fn(PrimitiveDateTime) -> Message + 'static) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the width of the [`CupertinoDatePicker`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the text size of the [`CupertinoDatePicker`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the text colour of the [`CupertinoDatePicker`].
+    #[must_use]
+    pub fn text_colour(mut self, colour: Color) -> Self {
+        self.text_colour = colour;
+        self
+    }
+
+    fn wheels(&self) -> Vec<Wheel> {
+        self.mode
+            .columns()
+            .iter()
+            .map(|column| Wheel::new(column.index(self.value, &self.years)))
+            .collect()
+    }
+
+    /// Computes the bounds of the wheels in the given bounds.
+    fn columns(&self, bounds: Rectangle) -> impl Iterator<Item = (Column, Rectangle)> + '_ {
+        let columns = self.mode.columns();
+        let total: f32 = columns.iter().map(|column| column.weight()).sum();
+        let mut x = bounds.x;
+
+        columns.iter().map(move |column| {
+            let width = bounds.width * column.weight() / total;
+            let column_bounds = Rectangle { x, width, ..bounds };
+            x += width;
+
+            (*column, column_bounds)
+        })
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for CupertinoDatePicker<Message>
+where
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn tag(&self) -> Tag {
+        Tag::of::<DatePickerState>()
+    }
+
+    fn state(&self) -> State {
+        State::new(DatePickerState {
+            wheels: self.wheels(),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<DatePickerState>();
+
+        if state.wheels.len() != self.mode.columns().len() {
+            state.wheels = self.wheels();
+        }
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.width(self.width).resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(
+                80.0 * self.mode.columns().len() as f32,
+                ROW_HEIGHT * VISIBLE_ROWS as f32,
+            ),
+        ))
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<DatePickerState>();
+        let bounds = layout.bounds();
+
+        draw_selection_band(renderer, bounds);
+
+        for ((column, bounds), wheel) in self.columns(bounds).zip(&state.wheels) {
+            wheel.draw(
+                renderer,
+                bounds,
+                column.count(self.value, &self.years),
+                |row| column.label(row, &self.years),
+                self.text_size,
+                self.text_colour,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = state.state.downcast_mut::<DatePickerState>();
+        let mut status = event::Status::Ignored;
+        let mut value = self.value;
+
+        for ((column, bounds), wheel) in self.columns(layout.bounds()).zip(&mut state.wheels) {
+            let (wheel_status, settled) = wheel.on_event(
+                &event,
+                bounds,
+                cursor,
+                column.count(self.value, &self.years),
+                column.index(self.value, &self.years),
+                shell,
+            );
+
+            status = status.merge(wheel_status);
+
+            if let Some(row) = settled {
+                value = column.apply(value, row, &self.years);
+            }
+        }
+
+        if let Some(on_change) = &self.on_change {
+            if value != self.value {
+                shell.publish(on_change(value));
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CupertinoDatePicker<Message>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font> + 'a,
+{
+    fn from(picker: CupertinoDatePicker<Message>) -> Self {
+        Self::new(picker)
+    }
+}
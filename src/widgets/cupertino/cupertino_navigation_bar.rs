@@ -0,0 +1,632 @@
+use crate::core::{
+    icons::{Bootstrap, BOOTSTRAP_FONT},
+    SF_UI_ROUNDED,
+};
+use crate::widgets::cupertino::cupertino_colors::system_blue;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Quad},
+        text::{self, Paragraph, Text},
+        widget::{
+            operation::{Operation, Scrollable},
+            tree::{self, Tag},
+            Id, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event, font,
+    mouse::{self, Cursor},
+    touch,
+    widget::{
+        scrollable,
+        text::{LineHeight, Shaping},
+    },
+    Border, Color, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+
+/**
+ * `CupertinoNavigationBar`
+ *
+ * See
+ *
+ * <https://github.com/flutter/flutter/blob/master/packages/flutter/lib/src/cupertino/nav_bar.dart>
+ *
+ * for constants, and
+ *
+ * <https://api.flutter.dev/flutter/cupertino/CupertinoSliverNavigationBar-class.html>
+ *
+ * for the Flutter example / expected usage.
+ *
+ * The bar is shown above the `content`. Its large title collapses into the
+ * inline title as the first scrollable of the `content` (or the one set with
+ * `scrollable`) scrolls down.
+ *
+ */
+#[allow(missing_debug_implementations)]
+pub struct CupertinoNavigationBar<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    title: String,
+    content: Element<'a, Message, Theme, Renderer>,
+    back: Option<(String, Message)>,
+    actions: Vec<(String, Message)>,
+    scrollable: Option<Id>,
+    width: Length,
+    height: Length,
+    background: Color,
+    text_colour: Color,
+}
+
+const INLINE_HEIGHT: f32 = 44.0;
+const LARGE_TITLE_HEIGHT: f32 = 52.0;
+const LARGE_TITLE_SIZE: f32 = 34.0;
+const TITLE_SIZE: f32 = 17.0;
+const BUTTON_SIZE: f32 = 17.0;
+const HORIZONTAL_PADDING: f32 = 16.0;
+const BUTTON_SPACING: f32 = 16.0;
+const CHEVRON_WIDTH: f32 = 22.0;
+const SEPARATOR_HEIGHT: f32 = 0.5;
+
+/// The state of a [`CupertinoNavigationBar`].
+#[derive(Debug, Default)]
+struct NavigationBarState {
+    /// How far the large title is collapsed, from 0 (large) to 1 (inline).
+    collapse: f32,
+    /// The index of the pressed button, the back button being after the actions.
+    pressed: Option<usize>,
+}
+
+/// Finds the translation of the attached scrollable.
+struct ScrollOffset<'b> {
+    id: Option<&'b Id>,
+    offset: Option<f32>,
+}
+
+impl<T> Operation<T> for ScrollOffset<'_> {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if self.offset.is_none() {
+            operate_on_children(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        translation: Vector,
+    ) {
+        if self.offset.is_none() && (self.id.is_none() || self.id == id) {
+            self.offset = Some(translation.y);
+        }
+    }
+}
+
+/// Returns the vertical scroll offset of the first scrollable of the
+/// `content`, or of the one with the given `id`.
+pub(crate) fn scroll_offset<Message, Theme, Renderer>(
+    content: &Element<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+    id: Option<&Id>,
+) -> Option<f32>
+where
+    Renderer: renderer::Renderer,
+{
+    let mut operation = ScrollOffset { id, offset: None };
+    content
+        .as_widget()
+        .operate(tree, layout, renderer, &mut operation);
+
+    operation.offset
+}
+
+impl<'a, Message, Theme, Renderer> CupertinoNavigationBar<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+{
+    /// Creates a new [`CupertinoNavigationBar`] with the given `title` above
+    /// the `content`.
+    pub fn new(
+        title: impl Into<String>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            content: content.into(),
+            back: None,
+            actions: Vec::new(),
+            scrollable: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            background: Color::from_rgba(0.976, 0.976, 0.976, 0.94),
+            text_colour: Color::BLACK,
+        }
+    }
+
+    /// Sets the back button of the [`CupertinoNavigationBar`], shown before
+    /// the title.
+    #[must_use]
+    pub fn back(mut self, label: impl Into<String>, message: Message) -> Self {
+        self.back = Some((label.into(), message));
+        self
+    }
+
+    /// Adds a trailing action button to the [`CupertinoNavigationBar`].
+    #[must_use]
+    pub fn action(mut self, label: impl Into<String>, message: Message) -> Self {
+        self.actions.push((label.into(), message));
+        self
+    }
+
+    /// Sets the [`Id`](scrollable::Id) of the scrollable collapsing the large
+    /// title of the [`CupertinoNavigationBar`]. By default, the first
+    /// scrollable of the content is used.
+    #[must_use]
+    pub fn scrollable(mut self, id: scrollable::Id) -> Self {
+        self.scrollable = Some(id.into());
+        self
+    }
+
+    /// Sets the width of the [`CupertinoNavigationBar`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`CupertinoNavigationBar`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the background colour of the [`CupertinoNavigationBar`].
+    #[must_use]
+    pub fn background(mut self, colour: Color) -> Self {
+        self.background = colour;
+        self
+    }
+
+    /// Sets the title colour of the [`CupertinoNavigationBar`].
+    #[must_use]
+    pub fn text_colour(mut self, colour: Color) -> Self {
+        self.text_colour = colour;
+        self
+    }
+
+    fn bar_height(collapse: f32) -> f32 {
+        INLINE_HEIGHT + LARGE_TITLE_HEIGHT * (1.0 - collapse)
+    }
+
+    fn text(content: &str, size: f32, font: Font, bounds: Size) -> Text<'_, Font> {
+        Text {
+            content,
+            bounds,
+            size: Pixels(size),
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+
+    fn label_width(label: &str) -> f32 {
+        Renderer::Paragraph::with_text(Self::text(
+            label,
+            BUTTON_SIZE,
+            SF_UI_ROUNDED,
+            Size::INFINITY,
+        ))
+        .min_bounds()
+        .width
+    }
+
+    /// Computes the bounds of the buttons in the inline bar, the back button
+    /// being after the actions.
+    fn buttons(&self, bar: Rectangle) -> Vec<Rectangle> {
+        let mut right = bar.x + bar.width - HORIZONTAL_PADDING;
+        let mut buttons: Vec<Rectangle> = self
+            .actions
+            .iter()
+            .map(|(label, _)| {
+                let width = Self::label_width(label);
+                right -= width;
+                let bounds =
+                    Rectangle::new(Point::new(right, bar.y), Size::new(width, INLINE_HEIGHT));
+                right -= BUTTON_SPACING;
+                bounds
+            })
+            .collect();
+
+        if let Some((label, _)) = &self.back {
+            buttons.push(Rectangle::new(
+                Point::new(bar.x + HORIZONTAL_PADDING / 2.0, bar.y),
+                Size::new(CHEVRON_WIDTH + Self::label_width(label), INLINE_HEIGHT),
+            ));
+        }
+
+        buttons
+    }
+
+    fn button_at(&self, bar: Rectangle, cursor: Cursor) -> Option<usize> {
+        self.buttons(bar)
+            .iter()
+            .position(|bounds| cursor.is_over(*bounds))
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for CupertinoNavigationBar<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<NavigationBarState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(NavigationBarState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let bar_height = Self::bar_height(tree.state.downcast_ref::<NavigationBarState>().collapse);
+        let limits = limits.width(self.width).height(self.height);
+
+        let content = self
+            .content
+            .as_widget()
+            .layout(
+                &mut tree.children[0],
+                renderer,
+                &limits.shrink(Size::new(0.0, bar_height)),
+            )
+            .move_to(Point::new(0.0, bar_height));
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(content.size().width, content.size().height + bar_height),
+        );
+
+        Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let bar = Rectangle {
+            height: INLINE_HEIGHT,
+            ..bounds
+        };
+        let content_layout = layout.children().next().expect("Content layout");
+        let mut status = event::Status::Ignored;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let nav_state = state.state.downcast_mut::<NavigationBarState>();
+                nav_state.pressed = self.button_at(bar, cursor);
+
+                if nav_state.pressed.is_some() {
+                    status = event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let nav_state = state.state.downcast_mut::<NavigationBarState>();
+
+                if let Some(pressed) = nav_state.pressed.take() {
+                    if self.button_at(bar, cursor) == Some(pressed) {
+                        let message = self
+                            .actions
+                            .get(pressed)
+                            .or(self.back.as_ref())
+                            .map(|(_, message)| message.clone());
+
+                        if let Some(message) = message {
+                            shell.publish(message);
+                        }
+                    }
+
+                    status = event::Status::Captured;
+                }
+            }
+
+            _ => {}
+        }
+
+        // The content is hidden behind the bar.
+        let content_cursor = if cursor.is_over(Rectangle {
+            height: content_layout.bounds().y - bounds.y,
+            ..bounds
+        }) {
+            Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        if status == event::Status::Ignored {
+            status = self.content.as_widget_mut().on_event(
+                &mut state.children[0],
+                event,
+                content_layout,
+                content_cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        let offset = scroll_offset(
+            &self.content,
+            &mut state.children[0],
+            content_layout,
+            renderer,
+            self.scrollable.as_ref(),
+        );
+
+        let nav_state = state.state.downcast_mut::<NavigationBarState>();
+        let collapse = (offset.unwrap_or(0.0) / LARGE_TITLE_HEIGHT).clamp(0.0, 1.0);
+
+        if (nav_state.collapse - collapse).abs() > f32::EPSILON {
+            nav_state.collapse = collapse;
+            shell.invalidate_layout();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bar = Rectangle {
+            height: INLINE_HEIGHT,
+            ..layout.bounds()
+        };
+
+        if self.button_at(bar, cursor).is_some() {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &state.children[0],
+            layout.children().next().expect("Content layout"),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let nav_state = state.state.downcast_ref::<NavigationBarState>();
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().expect("Content layout");
+        let header = Rectangle {
+            height: content_layout.bounds().y - bounds.y,
+            ..bounds
+        };
+        let bar = Rectangle {
+            height: INLINE_HEIGHT,
+            ..bounds
+        };
+        let collapse = nav_state.collapse;
+        let semibold = Font {
+            weight: font::Weight::Semibold,
+            ..SF_UI_ROUNDED
+        };
+
+        self.content.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor,
+            viewport,
+        );
+
+        renderer.fill_quad(
+            Quad {
+                bounds: header,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            self.background,
+        );
+
+        // The separator appears once the title is inline.
+        renderer.fill_quad(
+            Quad {
+                bounds: Rectangle {
+                    y: header.y + header.height - SEPARATOR_HEIGHT,
+                    height: SEPARATOR_HEIGHT,
+                    ..header
+                },
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            Color::from_rgba(0.0, 0.0, 0.0, 0.3 * collapse),
+        );
+
+        // The large title fades out as it collapses, and the inline title
+        // fades in once it is mostly collapsed.
+        renderer.with_layer(header, |renderer| {
+            let large = Rectangle {
+                x: bounds.x + HORIZONTAL_PADDING,
+                y: bar.y + INLINE_HEIGHT,
+                width: bounds.width - HORIZONTAL_PADDING * 2.0,
+                height: LARGE_TITLE_HEIGHT,
+            };
+
+            renderer.fill_text(
+                Self::text(
+                    &self.title,
+                    LARGE_TITLE_SIZE,
+                    Font {
+                        weight: font::Weight::Bold,
+                        ..SF_UI_ROUNDED
+                    },
+                    large.size(),
+                ),
+                Point::new(large.x, large.center_y()),
+                Color {
+                    a: self.text_colour.a * (1.0 - collapse),
+                    ..self.text_colour
+                },
+                large,
+            );
+        });
+
+        renderer.fill_text(
+            Text {
+                horizontal_alignment: Horizontal::Center,
+                ..Self::text(&self.title, TITLE_SIZE, semibold, bar.size())
+            },
+            bar.center(),
+            Color {
+                a: self.text_colour.a * ((collapse - 0.5) * 2.0).clamp(0.0, 1.0),
+                ..self.text_colour
+            },
+            bar,
+        );
+
+        let buttons = self.buttons(bar);
+        let labels = self
+            .actions
+            .iter()
+            .chain(self.back.iter())
+            .map(|(label, _)| label);
+
+        for (i, (label, button)) in labels.zip(&buttons).enumerate() {
+            let colour = system_blue(if nav_state.pressed == Some(i) {
+                0.4
+            } else {
+                1.0
+            });
+            let mut x = button.x;
+
+            if i == self.actions.len() {
+                let mut buffer = [0; 4];
+
+                renderer.fill_text(
+                    Text {
+                        horizontal_alignment: Horizontal::Center,
+                        ..Self::text(
+                            char::from(Bootstrap::ChevronLeft).encode_utf8(&mut buffer),
+                            BUTTON_SIZE + 3.0,
+                            BOOTSTRAP_FONT,
+                            Size::new(CHEVRON_WIDTH, INLINE_HEIGHT),
+                        )
+                    },
+                    Point::new(x + CHEVRON_WIDTH / 2.0, button.center_y()),
+                    colour,
+                    *button,
+                );
+                x += CHEVRON_WIDTH;
+            }
+
+            renderer.fill_text(
+                Self::text(label, BUTTON_SIZE, SF_UI_ROUNDED, button.size()),
+                Point::new(x, button.center_y()),
+                colour,
+                *button,
+            );
+        }
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut state.children[0],
+            layout.children().next().expect("Content layout"),
+            renderer,
+            operation,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut state.children[0],
+            layout.children().next()?,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CupertinoNavigationBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font> + 'a,
+    Theme: 'a,
+{
+    fn from(navigation_bar: CupertinoNavigationBar<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(navigation_bar)
+    }
+}
@@ -0,0 +1,477 @@
+use crate::core::{animation, settings, SF_UI_ROUNDED};
+use crate::widgets::cupertino::cupertino_colors::secondary_system_fill;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::tree::{State, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor, ScrollDelta},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    window, Border, Color, Element, Event, Font, Length, Pixels, Rectangle, Shadow, Size,
+};
+use std::time::{Duration, Instant};
+
+/**
+ * `CupertinoPicker`
+ *
+ * See
+ *
+ * <https://github.com/flutter/flutter/blob/master/packages/flutter/lib/src/cupertino/picker.dart>
+ *
+ * for constants, and
+ *
+ * <https://api.flutter.dev/flutter/cupertino/CupertinoPicker-class.html>
+ *
+ * for the Flutter example / expected usage.
+ *
+ * The wheel can be dragged, flung and scrolled, and always comes to rest on
+ * an item. `on_select` is called with the index of the item it rests on.
+ *
+ */
+#[allow(missing_debug_implementations)]
+pub struct CupertinoPicker<Message> {
+    items: Vec<String>,
+    selected: usize,
+    on_select: Option<Box<dyn Fn(usize) -> Message>>,
+    width: Length,
+    text_size: f32,
+    text_colour: Color,
+}
+
+/// The height of a row of a wheel.
+pub(crate) const ROW_HEIGHT: f32 = 32.0;
+/// The number of rows shown by a wheel.
+pub(crate) const VISIBLE_ROWS: usize = 5;
+/// The default text size of a wheel.
+pub(crate) const TEXT_SIZE: f32 = 21.0;
+/// The rate at which a flung wheel slows down.
+const FRICTION: f32 = 3.0;
+/// The rate at which a wheel snaps onto the closest row.
+const SNAP_SPEED: f32 = 14.0;
+/// The speed, in rows per second, under which a flung wheel snaps.
+const MIN_VELOCITY: f32 = 1.0;
+/// The delay after which the last drag movement no longer flings the wheel.
+const FLING_TIMEOUT: Duration = Duration::from_millis(80);
+/// The distance under which a press and release select the pressed row.
+const TAP_DISTANCE: f32 = 4.0;
+
+#[derive(Debug)]
+struct Drag {
+    start_y: f32,
+    start_offset: f32,
+    last_y: f32,
+    last_time: Instant,
+}
+
+/// The state of a wheel of rows, scrolled with momentum and snapping onto rows.
+#[derive(Debug, Default)]
+pub(crate) struct Wheel {
+    /// The index of the row at the center of the wheel, fractional while it moves.
+    offset: f32,
+    /// The speed of the wheel, in rows per second.
+    velocity: f32,
+    /// The row the wheel is snapping onto.
+    target: Option<f32>,
+    drag: Option<Drag>,
+    last_update: Option<Instant>,
+    /// The row the wheel rested on, until the application selects it.
+    pending: Option<usize>,
+}
+
+impl Wheel {
+    /// Creates a new [`Wheel`] resting on the row at `selected`.
+    pub(crate) fn new(selected: usize) -> Self {
+        Self {
+            offset: selected as f32,
+            ..Self::default()
+        }
+    }
+
+    /// Processes the `event` for a wheel of `count` rows in the given bounds,
+    /// the row at `selected` being selected by the application. Returns the
+    /// row the wheel came to rest on, if it differs from the selected one.
+    pub(crate) fn on_event<Message>(
+        &mut self,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+        count: usize,
+        selected: usize,
+        shell: &mut Shell<'_, Message>,
+    ) -> (event::Status, Option<usize>) {
+        let max = count.saturating_sub(1) as f32;
+        self.offset = self.offset.clamp(0.0, max);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let rows = match delta {
+                    ScrollDelta::Lines { y, .. } => *y,
+                    ScrollDelta::Pixels { y, .. } => *y / ROW_HEIGHT,
+                };
+
+                self.offset = (self.offset - rows).clamp(0.0, max);
+                self.velocity = 0.0;
+                self.target = Some(self.offset.round());
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return (event::Status::Captured, None);
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    self.drag = Some(Drag {
+                        start_y: position.y,
+                        start_offset: self.offset,
+                        last_y: position.y,
+                        last_time: Instant::now(),
+                    });
+                    self.velocity = 0.0;
+                    self.target = None;
+
+                    return (event::Status::Captured, None);
+                }
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = &mut self.drag {
+                    let now = Instant::now();
+                    let elapsed = now.duration_since(drag.last_time).as_secs_f32();
+
+                    if elapsed > 0.0 {
+                        self.velocity = (drag.last_y - position.y) / ROW_HEIGHT / elapsed;
+                    }
+
+                    drag.last_y = position.y;
+                    drag.last_time = now;
+                    self.offset = (drag.start_offset + (drag.start_y - position.y) / ROW_HEIGHT)
+                        .clamp(0.0, max);
+
+                    return (event::Status::Captured, None);
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if let Some(drag) = self.drag.take() {
+                    if (drag.last_y - drag.start_y).abs() < TAP_DISTANCE {
+                        // A tap selects the tapped row.
+                        let row = self.offset + (drag.last_y - bounds.center_y()) / ROW_HEIGHT;
+                        self.velocity = 0.0;
+                        self.target = Some(row.round().clamp(0.0, max));
+                    } else if drag.last_time.elapsed() > FLING_TIMEOUT
+                        || self.velocity.abs() < MIN_VELOCITY
+                    {
+                        self.velocity = 0.0;
+                        self.target = Some(self.offset.round());
+                    }
+
+                    self.last_update = None;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return (event::Status::Captured, None);
+                }
+            }
+
+            Event::Window(_id, window::Event::RedrawRequested(now)) => {
+                return (
+                    event::Status::Ignored,
+                    self.animate(*now, max, selected, shell),
+                );
+            }
+
+            _ => {}
+        }
+
+        (event::Status::Ignored, None)
+    }
+
+    /// Advances the animation of the wheel to `now`.
+    fn animate<Message>(
+        &mut self,
+        now: Instant,
+        max: f32,
+        selected: usize,
+        shell: &mut Shell<'_, Message>,
+    ) -> Option<usize> {
+        if self.drag.is_some() {
+            return None;
+        }
+
+        // The settled row may no longer exist, e.g. when the day is clamped
+        // to a shorter month.
+        if self.pending == Some(selected) || self.pending.is_some_and(|row| row as f32 > max) {
+            self.pending = None;
+        }
+
+        let elapsed = self
+            .last_update
+            .map_or(0.0, |last| (now - last).as_secs_f32());
+        let mut settled = None;
+
+        if self.velocity != 0.0 {
+            self.offset += self.velocity * elapsed;
+            self.velocity *= (-FRICTION * elapsed).exp();
+
+            if self.offset <= 0.0 || self.offset >= max || self.velocity.abs() < MIN_VELOCITY {
+                self.offset = self.offset.clamp(0.0, max);
+                self.velocity = 0.0;
+                self.target = Some(self.offset.round());
+            }
+        } else if let Some(target) = self.target {
+            if (target - self.offset).abs() > 0.001 {
+                self.offset += (target - self.offset) * (elapsed * SNAP_SPEED).min(1.0);
+            } else {
+                self.offset = target;
+                self.target = None;
+
+                let row = target as usize;
+                if row != selected {
+                    self.pending = Some(row);
+                    settled = Some(row);
+                }
+            }
+        } else if self.pending.is_none() && (self.offset - selected as f32).abs() > f32::EPSILON {
+            // Follow the row selected by the application.
+            self.target = Some((selected as f32).min(max));
+        }
+
+        if self.velocity != 0.0 || self.target.is_some() {
+            self.last_update = Some(now);
+            animation::request_frame(shell, now);
+        } else {
+            self.last_update = None;
+        }
+
+        settled
+    }
+
+    /// Draws the `count` rows of the wheel in the given bounds, with the
+    /// text returned by `label`.
+    pub(crate) fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        count: usize,
+        label: impl Fn(usize) -> String,
+        text_size: f32,
+        colour: Color,
+    ) where
+        Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+    {
+        let max = count.saturating_sub(1) as f32;
+        let offset = self.offset.clamp(0.0, max);
+        let half = VISIBLE_ROWS as f32 / 2.0;
+
+        renderer.with_layer(bounds, |renderer| {
+            let first = (offset - half).floor().max(0.0) as usize;
+            let last = ((offset + half).ceil() as usize).min(count.saturating_sub(1));
+
+            for row in first..=last {
+                let distance = row as f32 - offset;
+                // The rows shrink and fade out towards the edges of the wheel.
+                let fade = (distance.abs() / half).min(1.0);
+                let bounds = Rectangle {
+                    y: bounds.center_y() + distance * ROW_HEIGHT - ROW_HEIGHT / 2.0,
+                    height: ROW_HEIGHT,
+                    ..bounds
+                };
+
+                renderer.fill_text(
+                    Text {
+                        content: &label(row),
+                        bounds: bounds.size(),
+                        size: Pixels(text_size * (1.0 - 0.2 * fade)),
+                        line_height: LineHeight::default(),
+                        font: SF_UI_ROUNDED,
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                        shaping: Shaping::Advanced,
+                    },
+                    bounds.center(),
+                    Color {
+                        a: colour.a * (1.0 - 0.7 * fade),
+                        ..colour
+                    },
+                    bounds,
+                );
+            }
+        });
+    }
+}
+
+/// Draws the band highlighting the selected row of the wheels in the given bounds.
+pub(crate) fn draw_selection_band(renderer: &mut impl renderer::Renderer, bounds: Rectangle) {
+    renderer.fill_quad(
+        Quad {
+            bounds: Rectangle {
+                y: bounds.center_y() - ROW_HEIGHT / 2.0,
+                height: ROW_HEIGHT,
+                ..bounds
+            },
+            border: Border {
+                radius: 8.0.into(),
+                ..Border::default()
+            },
+            shadow: Shadow::default(),
+        },
+        Color {
+            a: 0.5,
+            ..secondary_system_fill()
+        },
+    );
+}
+
+impl<Message> CupertinoPicker<Message> {
+    /// Creates a new [`CupertinoPicker`] widget with the given `items`, the
+    /// item at `selected` being selected.
+    #[must_use]
+    pub fn new(items: impl IntoIterator<Item = impl Into<String>>, selected: usize) -> Self {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            selected,
+            on_select: None,
+            width: Length::Fill,
+            text_size: settings::scaled(TEXT_SIZE),
+            text_colour: Color::BLACK,
+        }
+    }
+
+    /// Sets the `on_select` callback of the [`CupertinoPicker`], called with
+    /// the index of the item the wheel comes to rest on.
+    #[must_use]
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'static) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the width of the [`CupertinoPicker`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the text size of the [`CupertinoPicker`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the text colour of the [`CupertinoPicker`].
+    #[must_use]
+    pub fn text_colour(mut self, colour: Color) -> Self {
+        self.text_colour = colour;
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for CupertinoPicker<Message>
+where
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn tag(&self) -> Tag {
+        Tag::of::<Wheel>()
+    }
+
+    fn state(&self) -> State {
+        State::new(Wheel::new(self.selected))
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.width(self.width).resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(100.0, ROW_HEIGHT * VISIBLE_ROWS as f32),
+        ))
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        draw_selection_band(renderer, bounds);
+        state.state.downcast_ref::<Wheel>().draw(
+            renderer,
+            bounds,
+            self.items.len(),
+            |row| self.items[row].clone(),
+            self.text_size,
+            self.text_colour,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let (status, settled) = state.state.downcast_mut::<Wheel>().on_event(
+            &event,
+            layout.bounds(),
+            cursor,
+            self.items.len(),
+            self.selected,
+            shell,
+        );
+
+        if let (Some(on_select), Some(row)) = (&self.on_select, settled) {
+            shell.publish(on_select(row));
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CupertinoPicker<Message>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font> + 'a,
+{
+    fn from(picker: CupertinoPicker<Message>) -> Self {
+        Self::new(picker)
+    }
+}
@@ -0,0 +1,466 @@
+use crate::{core::animation, widgets::cupertino::cupertino_navigation_bar::scroll_offset};
+
+use iced::{
+    advanced::{
+        graphics::geometry::Renderer as _,
+        layout::{Limits, Node},
+        overlay, renderer,
+        widget::{
+            tree::{self, Tag},
+            Id, Operation, Tree,
+        },
+        Clipboard, Layout, Renderer as _, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor, ScrollDelta},
+    touch,
+    widget::{
+        canvas::{Frame, LineCap, Path, Stroke},
+        scrollable,
+    },
+    window,
+    Color,
+    Element,
+    Event,
+    Length,
+    Point,
+    Rectangle,
+    Renderer, // the actual type
+    Size,
+    Vector,
+};
+use std::{
+    f32::consts::PI,
+    time::{Duration, Instant},
+};
+
+/**
+ * `CupertinoPullToRefresh`
+ *
+ * See
+ *
+ * <https://github.com/flutter/flutter/blob/master/packages/flutter/lib/src/cupertino/refresh.dart>
+ *
+ * for constants, and
+ *
+ * <https://api.flutter.dev/flutter/cupertino/CupertinoSliverRefreshControl-class.html>
+ *
+ * for the Flutter example / expected usage.
+ *
+ * Pulling the `content` down while its first scrollable (or the one set with
+ * `scrollable`) is at the top reveals an activity indicator. Releasing it past
+ * the threshold publishes the `on_refresh` message. The indicator then stays
+ * shown until the application sets `refreshing` back to false.
+ *
+ */
+#[allow(missing_debug_implementations)]
+pub struct CupertinoPullToRefresh<'a, Message, Theme = iced::Theme>
+where
+    Message: Clone,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    refreshing: bool,
+    on_refresh: Option<Message>,
+    scrollable: Option<Id>,
+}
+
+/// The distance past which releasing the content refreshes it.
+const TRIGGER_DISTANCE: f32 = 100.0;
+/// The height of the area showing the indicator while refreshing.
+const INDICATOR_EXTENT: f32 = 60.0;
+/// The share of the pulled distance by which the content moves.
+const RESISTANCE: f32 = 0.6;
+/// The pixels scrolled by a line of the mouse wheel.
+const LINE_HEIGHT: f32 = 20.0;
+/// The delay after which scrolling the mouse wheel counts as released.
+const WHEEL_RELEASE: Duration = Duration::from_millis(150);
+/// The rate at which the content springs back into place.
+const SPRING_SPEED: f32 = 10.0;
+/// The turns per second of the indicator while refreshing.
+const ROTATION_SPEED: f32 = 1.0;
+
+const RADIUS: f32 = 10.0;
+const HAND_COUNT: usize = 8;
+
+/// The state of a [`CupertinoPullToRefresh`].
+#[derive(Debug, Default)]
+struct PullToRefreshState {
+    /// The distance the content is pulled down by.
+    pull: f32,
+    /// The cursor position at which pulling started.
+    drag: Option<f32>,
+    /// The moment the mouse wheel last pulled the content.
+    last_wheel: Option<Instant>,
+    /// Whether the refresh was triggered, until the application starts refreshing.
+    triggered: bool,
+    /// The scroll offset of the content.
+    offset: f32,
+    /// The rotation of the indicator, in turns.
+    rotation: f32,
+    last_update: Option<Instant>,
+}
+
+impl<'a, Message, Theme> CupertinoPullToRefresh<'a, Message, Theme>
+where
+    Message: Clone,
+{
+    /// Creates a new [`CupertinoPullToRefresh`] around the `content`, showing
+    /// the indicator while `refreshing` is true.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        refreshing: bool,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            refreshing,
+            on_refresh: None,
+            scrollable: None,
+        }
+    }
+
+    /// Sets the message published when the [`CupertinoPullToRefresh`] is
+    /// pulled past the threshold and released.
+    #[must_use]
+    pub fn on_refresh(mut self, message: Message) -> Self {
+        self.on_refresh = Some(message);
+        self
+    }
+
+    /// Sets the [`Id`](scrollable::Id) of the scrollable that must be at the
+    /// top to pull the [`CupertinoPullToRefresh`]. By default, the first
+    /// scrollable of the content is used.
+    #[must_use]
+    pub fn scrollable(mut self, id: scrollable::Id) -> Self {
+        self.scrollable = Some(id.into());
+        self
+    }
+
+    /// Ends a pull, triggering the refresh if the content was pulled far enough.
+    fn release(&self, state: &mut PullToRefreshState, shell: &mut Shell<'_, Message>) {
+        if state.pull >= TRIGGER_DISTANCE && !self.refreshing {
+            if let Some(on_refresh) = &self.on_refresh {
+                shell.publish(on_refresh.clone());
+                state.triggered = true;
+            }
+        }
+
+        state.last_update = None;
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+}
+
+impl<Message, Theme> Widget<Message, Theme, Renderer> for CupertinoPullToRefresh<'_, Message, Theme>
+where
+    Message: Clone,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<PullToRefreshState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(PullToRefreshState {
+            pull: if self.refreshing {
+                INDICATOR_EXTENT
+            } else {
+                0.0
+            },
+            ..PullToRefreshState::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let pull = tree.state.downcast_ref::<PullToRefreshState>().pull;
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(content.size(), vec![content.move_to(Point::new(0.0, pull))])
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().expect("Content layout");
+        let refresh_state = state.state.downcast_mut::<PullToRefreshState>();
+        let pull = refresh_state.pull;
+        let can_pull = !self.refreshing && !refresh_state.triggered && refresh_state.offset <= 0.0;
+        let mut status = event::Status::Ignored;
+
+        match &event {
+            Event::Window(_id, window::Event::RedrawRequested(now)) => {
+                if self.refreshing {
+                    refresh_state.triggered = false;
+                }
+
+                if refresh_state
+                    .last_wheel
+                    .is_some_and(|last| *now - last > WHEEL_RELEASE)
+                {
+                    refresh_state.last_wheel = None;
+                    self.release(refresh_state, shell);
+                }
+
+                let elapsed = refresh_state
+                    .last_update
+                    .map_or(0.0, |last| (*now - last).as_secs_f32());
+                let pulling = refresh_state.drag.is_some() || refresh_state.last_wheel.is_some();
+                let target = if self.refreshing || refresh_state.triggered {
+                    INDICATOR_EXTENT
+                } else {
+                    0.0
+                };
+
+                if self.refreshing {
+                    refresh_state.rotation =
+                        (refresh_state.rotation + elapsed * ROTATION_SPEED).fract();
+                }
+
+                if !pulling {
+                    refresh_state.pull = if (refresh_state.pull - target).abs() < 0.5 {
+                        target
+                    } else {
+                        refresh_state.pull
+                            + (target - refresh_state.pull) * (elapsed * SPRING_SPEED).min(1.0)
+                    };
+                }
+
+                if pulling || self.refreshing || (refresh_state.pull - target).abs() > f32::EPSILON
+                {
+                    refresh_state.last_update = Some(*now);
+                    animation::request_frame(shell, *now);
+                } else {
+                    refresh_state.last_update = None;
+                }
+            }
+
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let y = match delta {
+                    ScrollDelta::Lines { y, .. } => *y * LINE_HEIGHT,
+                    ScrollDelta::Pixels { y, .. } => *y,
+                };
+
+                if can_pull && (y > 0.0 || refresh_state.pull > 0.0) {
+                    refresh_state.pull = (refresh_state.pull + y * RESISTANCE).max(0.0);
+                    refresh_state.last_wheel = Some(Instant::now());
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                    status = event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                refresh_state.drag = cursor
+                    .position_over(bounds)
+                    .filter(|_| can_pull)
+                    .map(|position| position.y);
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(start) = refresh_state.drag {
+                    if refresh_state.offset <= 0.0 {
+                        refresh_state.pull = ((position.y - start) * RESISTANCE).max(0.0);
+                    }
+
+                    if refresh_state.pull > 0.0 {
+                        status = event::Status::Captured;
+                    }
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if refresh_state.drag.is_some() =>
+            {
+                refresh_state.drag = None;
+                self.release(refresh_state, shell);
+            }
+
+            _ => {}
+        }
+
+        if (refresh_state.pull - pull).abs() > f32::EPSILON {
+            shell.invalidate_layout();
+        }
+
+        if status == event::Status::Ignored {
+            status = self.content.as_widget_mut().on_event(
+                &mut state.children[0],
+                event,
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        let offset = scroll_offset(
+            &self.content,
+            &mut state.children[0],
+            content_layout,
+            renderer,
+            self.scrollable.as_ref(),
+        );
+        state.state.downcast_mut::<PullToRefreshState>().offset = offset.unwrap_or(0.0);
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &state.children[0],
+            layout.children().next().expect("Content layout"),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let refresh_state = state.state.downcast_ref::<PullToRefreshState>();
+        let bounds = layout.bounds();
+
+        renderer.with_layer(bounds, |renderer| {
+            self.content.as_widget().draw(
+                &state.children[0],
+                renderer,
+                theme,
+                style,
+                layout.children().next().expect("Content layout"),
+                cursor,
+                viewport,
+            );
+        });
+
+        if refresh_state.pull <= 0.0 {
+            return;
+        }
+
+        // The hands appear one by one while pulling, and all spin while refreshing.
+        let spinning = self.refreshing || refresh_state.triggered;
+        let hands = if spinning {
+            HAND_COUNT
+        } else {
+            ((refresh_state.pull / TRIGGER_DISTANCE * HAND_COUNT as f32).ceil() as usize)
+                .min(HAND_COUNT)
+        };
+        let step = (refresh_state.rotation * HAND_COUNT as f32) as usize;
+        let size = Size::new(RADIUS * 2.0, RADIUS * 2.0);
+        let mut frame = Frame::new(renderer, size);
+
+        frame.translate(Vector::new(RADIUS, RADIUS));
+
+        for i in 0..hands {
+            // While spinning, the brightest hand moves clockwise.
+            let age = if spinning {
+                (HAND_COUNT + step - i) % HAND_COUNT
+            } else {
+                0
+            };
+            let alpha = 1.0 - 0.7 * age as f32 / (HAND_COUNT - 1) as f32;
+
+            frame.with_save(|frame| {
+                frame.rotate(2.0 * PI * i as f32 / HAND_COUNT as f32);
+                frame.stroke(
+                    &Path::line(Point::new(0.0, -RADIUS / 2.0), Point::new(0.0, -RADIUS)),
+                    Stroke::default()
+                        .with_width(RADIUS / 5.0)
+                        .with_line_cap(LineCap::Round)
+                        .with_color(Color::from_rgba(0.0, 0.0, 0.0, 0.6 * alpha)),
+                );
+            });
+        }
+
+        let translation = Vector::new(
+            bounds.center_x() - RADIUS,
+            bounds.y + (refresh_state.pull.min(INDICATOR_EXTENT) - size.height) / 2.0,
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(translation, |renderer| {
+                renderer.draw(vec![frame.into_geometry()]);
+            });
+        });
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut state.children[0],
+            layout.children().next().expect("Content layout"),
+            renderer,
+            operation,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut state.children[0],
+            layout.children().next()?,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme> From<CupertinoPullToRefresh<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+{
+    fn from(pull_to_refresh: CupertinoPullToRefresh<'a, Message, Theme>) -> Self {
+        Self::new(pull_to_refresh)
+    }
+}
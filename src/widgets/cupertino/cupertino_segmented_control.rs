@@ -0,0 +1,367 @@
+use crate::core::{animation, settings, SF_UI_ROUNDED};
+use crate::widgets::cupertino::cupertino_colors::secondary_system_fill;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{self, Paragraph, Text},
+        widget::tree::{State, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event, font,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    window, Border, Color, Element, Event, Font, Length, Pixels, Rectangle, Shadow, Size, Vector,
+};
+use std::time::Instant;
+
+/**
+ * `CupertinoSegmentedControl`
+ *
+ * See
+ *
+ * <https://github.com/flutter/flutter/blob/master/packages/flutter/lib/src/cupertino/sliding_segmented_control.dart>
+ *
+ * for constants, and
+ *
+ * <https://api.flutter.dev/flutter/cupertino/CupertinoSlidingSegmentedControl-class.html>
+ *
+ * for the Flutter example / expected usage.
+ *
+ */
+#[allow(missing_debug_implementations)]
+pub struct CupertinoSegmentedControl<Message> {
+    labels: Vec<String>,
+    selected: usize,
+    on_select: Option<Box<dyn Fn(usize) -> Message>>,
+    width: Length,
+    text_size: f32,
+    thumb_colour: Color,
+    track_colour: Color,
+    text_colour: Color,
+}
+
+const HORIZONTAL_PADDING: f32 = 12.0;
+const VERTICAL_PADDING: f32 = 6.0;
+const TRACK_RADIUS: f32 = 9.0;
+const THUMB_INSET: f32 = 2.0;
+const THUMB_RADIUS: f32 = 7.0;
+const SEPARATOR_WIDTH: f32 = 1.0;
+
+/// The rate at which the thumb slides towards the selected segment.
+const ANIMATION_SPEED: f32 = 12.0;
+
+#[derive(Debug)]
+struct SegmentedControlState {
+    /// The position of the thumb, in segments.
+    thumb: f32,
+    last_update: Option<Instant>,
+}
+
+impl<Message> CupertinoSegmentedControl<Message> {
+    /// Creates a new [`CupertinoSegmentedControl`] widget with the given
+    /// `labels`, the segment at `selected` being selected.
+    #[must_use]
+    pub fn new(labels: impl IntoIterator<Item = impl Into<String>>, selected: usize) -> Self {
+        Self {
+            labels: labels.into_iter().map(Into::into).collect(),
+            selected,
+            on_select: None,
+            width: Length::Shrink,
+            text_size: settings::scaled(13.0),
+            thumb_colour: Color::WHITE,
+            track_colour: secondary_system_fill(),
+            text_colour: Color::BLACK,
+        }
+    }
+
+    /// Sets the `on_select` callback of the [`CupertinoSegmentedControl`],
+    /// called with the index of the pressed segment.
+    #[must_use]
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'static) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the width of the [`CupertinoSegmentedControl`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the text size of the [`CupertinoSegmentedControl`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the thumb colour of the [`CupertinoSegmentedControl`].
+    #[must_use]
+    pub fn thumb_colour(mut self, colour: Color) -> Self {
+        self.thumb_colour = colour;
+        self
+    }
+
+    /// Sets the track colour of the [`CupertinoSegmentedControl`].
+    #[must_use]
+    pub fn track_colour(mut self, colour: Color) -> Self {
+        self.track_colour = colour;
+        self
+    }
+
+    /// Sets the text colour of the [`CupertinoSegmentedControl`].
+    #[must_use]
+    pub fn text_colour(mut self, colour: Color) -> Self {
+        self.text_colour = colour;
+        self
+    }
+
+    fn text<'b>(&self, content: &'b str, selected: bool) -> Text<'b, Font> {
+        Text {
+            content,
+            bounds: Size::INFINITY,
+            size: Pixels(self.text_size),
+            line_height: LineHeight::default(),
+            font: if selected {
+                Font {
+                    weight: font::Weight::Semibold,
+                    ..SF_UI_ROUNDED
+                }
+            } else {
+                SF_UI_ROUNDED
+            },
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+
+    fn segment_width(&self, bounds: Rectangle) -> f32 {
+        bounds.width / self.labels.len().max(1) as f32
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for CupertinoSegmentedControl<Message>
+where
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn tag(&self) -> Tag {
+        Tag::of::<SegmentedControlState>()
+    }
+
+    fn state(&self) -> State {
+        State::new(SegmentedControlState {
+            thumb: self.selected as f32,
+            last_update: None,
+        })
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        // The selected label is bold, so measure every label in bold.
+        let label_size = self
+            .labels
+            .iter()
+            .map(|label| Renderer::Paragraph::with_text(self.text(label, true)).min_bounds())
+            .fold(Size::ZERO, |max, size| {
+                Size::new(max.width.max(size.width), max.height.max(size.height))
+            });
+
+        let intrinsic = Size::new(
+            (label_size.width + HORIZONTAL_PADDING * 2.0) * self.labels.len() as f32,
+            label_size.height + VERTICAL_PADDING * 2.0,
+        );
+
+        Node::new(
+            limits
+                .width(self.width)
+                .resolve(self.width, Length::Shrink, intrinsic),
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = state.state.downcast_ref::<SegmentedControlState>();
+        let bounds = layout.bounds();
+        let segment_width = self.segment_width(bounds);
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: TRACK_RADIUS.into(),
+                    ..Border::default()
+                },
+                shadow: Shadow::default(),
+            },
+            self.track_colour,
+        );
+
+        // Separators are hidden next to the thumb.
+        for i in 1..self.labels.len() {
+            if (i as f32 - state.thumb - 0.5).abs() < 1.5 {
+                continue;
+            }
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + segment_width * i as f32 - SEPARATOR_WIDTH / 2.0,
+                        y: bounds.y + VERTICAL_PADDING,
+                        width: SEPARATOR_WIDTH,
+                        height: bounds.height - VERTICAL_PADDING * 2.0,
+                    },
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                Color {
+                    a: 0.3,
+                    ..self.text_colour
+                },
+            );
+        }
+
+        if !self.labels.is_empty() {
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + segment_width * state.thumb + THUMB_INSET,
+                        y: bounds.y + THUMB_INSET,
+                        width: segment_width - THUMB_INSET * 2.0,
+                        height: bounds.height - THUMB_INSET * 2.0,
+                    },
+                    border: Border {
+                        radius: THUMB_RADIUS.into(),
+                        width: 0.5,
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.04),
+                    },
+                    shadow: Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.12),
+                        offset: Vector::new(0.0, 3.0),
+                        blur_radius: 8.0,
+                    },
+                },
+                self.thumb_colour,
+            );
+        }
+
+        for (i, label) in self.labels.iter().enumerate() {
+            let segment = Rectangle {
+                x: bounds.x + segment_width * i as f32,
+                width: segment_width,
+                ..bounds
+            };
+
+            renderer.fill_text(
+                Text {
+                    bounds: segment.size(),
+                    ..self.text(label, i == self.selected)
+                },
+                segment.center(),
+                self.text_colour,
+                segment,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = state.state.downcast_mut::<SegmentedControlState>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Window(_id, window::Event::RedrawRequested(now)) => {
+                let target = self.selected as f32;
+                let elapsed = state
+                    .last_update
+                    .map_or(0.0, |last| (now - last).as_secs_f32());
+
+                if (state.thumb - target).abs() < 0.001 {
+                    state.thumb = target;
+                    state.last_update = None;
+                } else {
+                    let eased = if settings::reduced_motion() {
+                        1.0
+                    } else {
+                        (elapsed * ANIMATION_SPEED).min(1.0)
+                    };
+                    state.thumb += (target - state.thumb) * eased;
+                    state.last_update = Some(now);
+                    animation::request_frame(shell, now);
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let (Some(on_select), Some(position)) =
+                    (&self.on_select, cursor.position_over(bounds))
+                {
+                    let index = ((position.x - bounds.x) / self.segment_width(bounds)) as usize;
+                    let index = index.min(self.labels.len().saturating_sub(1));
+
+                    if index != self.selected {
+                        shell.publish(on_select(index));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_select.is_some() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CupertinoSegmentedControl<Message>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font> + 'a,
+{
+    fn from(control: CupertinoSegmentedControl<Message>) -> Self {
+        Self::new(control)
+    }
+}
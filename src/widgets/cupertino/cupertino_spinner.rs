@@ -1,3 +1,5 @@
+use crate::core::animation;
+
 use iced::{
     advanced::{
         graphics::geometry::Renderer as _,
@@ -187,22 +189,26 @@ impl<Message, Theme> Widget<Message, Theme, Renderer> for CupertinoSpinner {
         &mut self,
         state: &mut Tree,
         event: Event,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _cursor: Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> event::Status {
         let state: &mut SpinnerState = state.state.downcast_mut::<SpinnerState>();
 
-        if let Event::Window(_id, window::Event::RedrawRequested(_now)) = &event {
-            // if is_visible(&bounds) {
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            // A spinner scrolled out of view stops asking for frames.
+            if !layout.bounds().intersects(viewport) {
+                return event::Status::Ignored;
+            }
+
             state.now = time::OffsetDateTime::now_local()
                 .unwrap_or_else(|_| time::OffsetDateTime::now_utc());
 
             state.spinner.clear();
-            shell.request_redraw(window::RedrawRequest::NextFrame);
+            animation::request_frame(shell, now);
             return event::Status::Captured;
         }
 
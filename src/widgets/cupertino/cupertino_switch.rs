@@ -1,3 +1,4 @@
+use crate::core::{animation, settings};
 use crate::widgets::cupertino::cupertino_colors::{secondary_system_fill, system_green};
 
 use iced::{
@@ -318,9 +319,13 @@ where
         let state: &mut SwitchState = state.state.downcast_mut::<SwitchState>();
         let bounds = layout.bounds();
         match event {
-            Event::Window(_id, window::Event::RedrawRequested(_now)) => {
+            Event::Window(_id, window::Event::RedrawRequested(now)) => {
                 if state.toggle_staged {
-                    state.animation_frame += 1;
+                    state.animation_frame = if settings::reduced_motion() {
+                        ANIMATION_FRAME_COUNT
+                    } else {
+                        state.animation_frame + 1
+                    };
 
                     // This machinery is built to accommodate for the most bizarre
                     // behaviour that only happens when `shell.publish` is called...
@@ -336,7 +341,7 @@ where
                     }
 
                     state.switch.clear();
-                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                    animation::request_frame(shell, now);
                 }
 
                 return event::Status::Captured;
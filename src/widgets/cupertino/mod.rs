@@ -4,6 +4,11 @@
 /// Depreciated due to Element loop.
 //pub mod cupertino_alert;
 
+/// Use a Cupertino-style action sheet.
+///
+/// *This API requires the following crate features to be activated: `cupertino`*
+pub mod cupertino_action_sheet;
+
 /// Use a Cupertino-style button.
 ///
 /// *This API requires the following crate features to be activated: `cupertino`*
@@ -14,6 +19,31 @@ pub mod cupertino_button;
 /// *This API requires the following crate features to be activated: `cupertino`*
 pub mod cupertino_colors;
 
+/// Use a Cupertino-style date and time picker.
+///
+/// *This API requires the following crate features to be activated: `cupertino`*
+pub mod cupertino_date_picker;
+
+/// Use a Cupertino-style navigation bar.
+///
+/// *This API requires the following crate features to be activated: `cupertino`*
+pub mod cupertino_navigation_bar;
+
+/// Use a Cupertino-style wheel picker.
+///
+/// *This API requires the following crate features to be activated: `cupertino`*
+pub mod cupertino_picker;
+
+/// Use a Cupertino-style pull-to-refresh container.
+///
+/// *This API requires the following crate features to be activated: `cupertino`*
+pub mod cupertino_pull_to_refresh;
+
+/// Use a Cupertino-style segmented control.
+///
+/// *This API requires the following crate features to be activated: `cupertino`*
+pub mod cupertino_segmented_control;
+
 /// Use a Cupertino-style spinner element.
 ///
 /// *This API requires the following crate features to be activated: `cupertino`*
@@ -2,6 +2,11 @@
 //!
 //! *This API requires the following crate features to be activated: `date_picker`*
 
+use crate::core::{
+    accessibility::{self, Action, Role},
+    settings,
+};
+
 use super::overlay::date_picker::{self, DatePickerOverlay, DatePickerOverlayButtons};
 
 use chrono::Local;
@@ -33,6 +38,7 @@ use iced::{
 pub use crate::{
     core::date::Date,
     style::date_picker::{Appearance, StyleSheet},
+    widgets::overlay::date_picker::Focus,
 };
 
 //TODO: Remove ignore when Null is updated. Temp fix for Test runs
@@ -67,6 +73,8 @@ where
 {
     /// Show the picker.
     show_picker: bool,
+    /// The id of the [`DatePicker`].
+    id: Option<widget::Id>,
     /// The date to show.
     date: Date,
     /// The underlying element.
@@ -82,6 +90,8 @@ where
     //button_style: <Renderer as button::Renderer>::Style, // clone not satisfied
     /// The font and icon size of the [`DatePickerOverlay`] or `None` for the default
     font_size: Option<Pixels>,
+    /// The parts of the overlay reached with Tab, in order.
+    tab_order: Vec<Focus>,
 }
 
 impl<'a, Message, Theme> DatePicker<'a, Message, Theme>
@@ -117,6 +127,7 @@ where
     {
         Self {
             show_picker,
+            id: None,
             date: date.into(),
             underlay: underlay.into(),
             on_cancel,
@@ -125,13 +136,35 @@ where
             overlay_state: DatePickerOverlayButtons::default().into(),
             //button_style: <Renderer as button::Renderer>::Style::default(),
             font_size: None,
+            tab_order: Vec::new(),
         }
     }
 
+    /// Sets the id of the [`DatePicker`], to focus its overlay with an
+    /// operation while it is shown. The focused overlay takes the keyboard
+    /// input, and Tab moves the focus between its parts.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the parts of the overlay of the [`DatePicker`] reached with Tab, in
+    /// order. The parts left out are skipped; by default all of them are
+    /// reached.
+    #[must_use]
+    pub fn tab_order(mut self, tab_order: impl IntoIterator<Item = Focus>) -> Self {
+        self.tab_order = tab_order
+            .into_iter()
+            .filter(|part| *part != Focus::None)
+            .collect();
+        self
+    }
+
     /// Sets the style of the [`DatePicker`].
     #[must_use]
-    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
-        self.style = style;
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
         //self.button_style = style.into();
         self
     }
@@ -269,6 +302,29 @@ where
         );
     }
 
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        let mut node = accessibility::Node::new(Role::DatePicker)
+            .name(self.date.to_string())
+            .expanded(self.show_picker);
+
+        if self.show_picker {
+            let picker_state: &mut State = state.state.downcast_mut();
+            operation.focusable(&mut picker_state.overlay_state, self.id.as_ref());
+            operation.custom(&mut node.action(Action::Focus), self.id.as_ref());
+        } else {
+            operation.custom(&mut node, self.id.as_ref());
+            self.underlay
+                .as_widget()
+                .operate(&mut state.children[0], layout, renderer, operation);
+        }
+    }
+
     fn overlay<'b>(
         &'b mut self,
         state: &'b mut Tree,
@@ -298,8 +354,10 @@ where
                 position,
                 self.style.clone(),
                 &mut state.children[1],
-                self.font_size.unwrap_or_else(|| renderer.default_size()),
+                self.font_size
+                    .unwrap_or_else(|| Pixels(settings::scaled(renderer.default_size().0))),
             )
+            .tab_order(&self.tab_order)
             .overlay(),
         )
     }
@@ -0,0 +1,1508 @@
+//! Dock panels on the edges of an area, stack them in tabs or float them.
+//!
+//! *This API requires the following crate features to be activated: `dock`*
+
+use crate::{
+    core::{
+        icons::{bootstrap::icon_to_string, Bootstrap, IconRenderer, BOOTSTRAP_FONT},
+        settings,
+    },
+    style::tab_bar,
+    widgets::tab_bar::TabBar,
+    TabLabel,
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Quad},
+        text::Text,
+        widget::{
+            tree::{self, Tag, Tree},
+            Operation,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    keyboard::{self, key::Named},
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping, StyleSheet as TextStyleSheet},
+    Background, Border, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size,
+    Vector,
+};
+use std::collections::HashMap;
+
+pub use crate::{
+    core::dock::{Axis, DockLayout, DockNode, DockTarget, Edge, FloatingPanel, ParseLayoutError},
+    style::dock::{Appearance, StyleSheet},
+};
+
+/// The height of the title bars of the floating panels.
+const TITLE_BAR_HEIGHT: f32 = 28.0;
+/// The horizontal padding of the titles of the floating panels.
+const PADDING: f32 = 8.0;
+/// The size of the indicators docking a dragged panel.
+const INDICATOR_SIZE: f32 = 32.0;
+/// The space between the indicators docking a dragged panel.
+const INDICATOR_SPACING: f32 = 4.0;
+/// The distance the cursor moves before a pressed panel is dragged.
+const THRESHOLD: f32 = 4.0;
+/// The size of a panel floated from a group of tabs.
+const FLOATING_SIZE: Size = Size::new(320.0, 240.0);
+
+/// The function producing the message changing the layout of a [`DockArea`].
+type OnChange<'a, Message> = Box<dyn Fn(DockLayout) -> Message + 'a>;
+
+/// The messages of the tab bars of a [`DockArea`].
+#[derive(Clone, Copy, Debug)]
+enum TabMessage {
+    /// The tab at the index was selected.
+    Select(usize),
+    /// The tab at the index was closed.
+    Close(usize),
+}
+
+/// A panel of a [`DockArea`], with its title and its content.
+#[allow(missing_debug_implementations)]
+pub struct DockPanel<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The title of the panel, shown on its tab or its title bar.
+    title: String,
+    /// The content of the panel.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// Whether the panel can be closed.
+    closable: bool,
+}
+
+impl<'a, Message, Theme, Renderer> DockPanel<'a, Message, Theme, Renderer> {
+    /// Creates a new [`DockPanel`] with the given title and content.
+    pub fn new(
+        title: impl Into<String>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            content: content.into(),
+            closable: false,
+        }
+    }
+
+    /// Sets whether the [`DockPanel`] can be closed, removing it from the layout.
+    ///
+    /// The tabs of a group show their close icons only if all of its panels
+    /// can be closed.
+    #[must_use]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+/// A group of tabs of a [`DockArea`].
+struct Group<'a, Message, Theme, Renderer>
+where
+    Renderer: IconRenderer,
+    Theme: tab_bar::StyleSheet,
+{
+    /// The names of the panels of the group.
+    panels: Vec<String>,
+    /// The titles of the panels of the group.
+    titles: Vec<String>,
+    /// The index of the shown panel.
+    active: usize,
+    /// The tab bar of the group.
+    tab_bar: TabBar<TabMessage, usize, Theme, Renderer>,
+    /// The content of the shown panel.
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+/// A floating panel of a [`DockArea`].
+struct Floating<'a, Message, Theme, Renderer> {
+    /// The name of the panel.
+    panel: String,
+    /// The title of the panel.
+    title: String,
+    /// Whether the panel can be closed.
+    closable: bool,
+    /// The content of the panel.
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+/// An area where panels are docked on the edges of each other, stacked in
+/// tabs or floated over the docked ones.
+///
+/// The panels are dragged by their tabs or by their title bars. While a panel
+/// is dragged, indicators show where it can be docked: on an edge of the area,
+/// on an edge of the hovered group of tabs or in it. A panel dropped elsewhere
+/// floats. The [`DockLayout`] is owned by the application, which is given the
+/// changed layout on each change.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::dock::{DockArea, DockLayout, DockPanel};
+/// # use iced::widget::text;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     LayoutChanged(DockLayout),
+/// }
+///
+/// let layout = DockLayout::new("editor");
+///
+/// let dock = DockArea::new(
+///     &layout,
+///     |panel| DockPanel::new(panel, text(panel)).closable(true),
+///     Message::LayoutChanged,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DockArea<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: IconRenderer,
+    Theme: StyleSheet + tab_bar::StyleSheet,
+{
+    /// The layout of the panels.
+    layout: DockLayout,
+    /// The groups of tabs, in the order of the layout.
+    groups: Vec<Group<'a, Message, Theme, Renderer>>,
+    /// The floating panels, from the bottom one to the top one.
+    floating: Vec<Floating<'a, Message, Theme, Renderer>>,
+    /// The function producing the message changing the layout.
+    on_change: OnChange<'a, Message>,
+    /// The width of the [`DockArea`].
+    width: Length,
+    /// The height of the [`DockArea`].
+    height: Length,
+    /// The width of the splitters between the docked panels.
+    splitter_width: f32,
+    /// The text size of the titles.
+    text_size: f32,
+    /// The font of the titles.
+    font: Font,
+    /// The style of the [`DockArea`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> DockArea<'a, Message, Theme, Renderer>
+where
+    Renderer: IconRenderer,
+    Theme: StyleSheet + tab_bar::StyleSheet,
+{
+    /// Creates a new [`DockArea`] showing the panels of `layout`.
+    ///
+    /// It expects:
+    ///     * the layout of the panels.
+    ///     * the function producing the [`DockPanel`] of a panel from its name.
+    ///     * the function producing the message changing the layout.
+    pub fn new<V, F>(layout: &DockLayout, view: V, on_change: F) -> Self
+    where
+        V: Fn(&str) -> DockPanel<'a, Message, Theme, Renderer>,
+        F: 'a + Fn(DockLayout) -> Message,
+    {
+        let mut tabs = Vec::new();
+        if let Some(root) = layout.root() {
+            collect_tabs(root, &mut tabs);
+        }
+
+        let groups = tabs
+            .into_iter()
+            .map(|(panels, active)| {
+                let mut views: Vec<_> = panels.iter().map(|panel| view(panel)).collect();
+                let titles: Vec<String> = views.iter().map(|view| view.title.clone()).collect();
+                let closable = views.iter().all(|view| view.closable);
+
+                let mut tab_bar = TabBar::with_tab_labels(
+                    titles
+                        .iter()
+                        .map(|title| TabLabel::Text(title.clone()))
+                        .enumerate()
+                        .collect(),
+                    TabMessage::Select,
+                )
+                .tab_width(Length::Shrink)
+                .set_active_tab(&active);
+                if closable {
+                    tab_bar = tab_bar.on_close(TabMessage::Close);
+                }
+
+                Group {
+                    panels: panels.to_vec(),
+                    titles,
+                    active,
+                    tab_bar,
+                    content: views.swap_remove(active).content,
+                }
+            })
+            .collect();
+
+        let floating = layout
+            .floating()
+            .iter()
+            .map(|floating| {
+                let view = view(&floating.panel);
+
+                Floating {
+                    panel: floating.panel.clone(),
+                    title: view.title,
+                    closable: view.closable,
+                    content: view.content,
+                }
+            })
+            .collect();
+
+        Self {
+            layout: layout.clone(),
+            groups,
+            floating,
+            on_change: Box::new(on_change),
+            width: Length::Fill,
+            height: Length::Fill,
+            splitter_width: 4.0,
+            text_size: settings::scaled(14.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`DockArea`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`DockArea`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the width of the splitters between the docked panels.
+    #[must_use]
+    pub fn splitter_width(mut self, width: f32) -> Self {
+        self.splitter_width = width;
+        self
+    }
+
+    /// Sets the text size of the titles of the panels.
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = size;
+        self.map_tab_bars(|tab_bar| tab_bar.text_size(size))
+    }
+
+    /// Sets the font of the titles of the panels.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self.map_tab_bars(|tab_bar| tab_bar.text_font(font))
+    }
+
+    /// Sets the style of the [`DockArea`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the tab bars of the [`DockArea`].
+    #[must_use]
+    pub fn tab_bar_style(self, style: <Theme as tab_bar::StyleSheet>::Style) -> Self
+    where
+        <Theme as tab_bar::StyleSheet>::Style: Clone,
+    {
+        self.map_tab_bars(|tab_bar| tab_bar.style(style.clone()))
+    }
+
+    /// Applies `f` to the tab bars of the groups.
+    fn map_tab_bars(
+        mut self,
+        f: impl Fn(
+            TabBar<TabMessage, usize, Theme, Renderer>,
+        ) -> TabBar<TabMessage, usize, Theme, Renderer>,
+    ) -> Self {
+        self.groups = self
+            .groups
+            .into_iter()
+            .map(|group| Group {
+                tab_bar: f(group.tab_bar),
+                ..group
+            })
+            .collect();
+        self
+    }
+
+    /// Returns the names of the shown panels with their contents, docked ones
+    /// first.
+    fn contents(&self) -> impl Iterator<Item = (&str, &Element<'a, Message, Theme, Renderer>)> {
+        self.groups
+            .iter()
+            .map(|group| (group.panels[group.active].as_str(), &group.content))
+            .chain(
+                self.floating
+                    .iter()
+                    .map(|floating| (floating.panel.as_str(), &floating.content)),
+            )
+    }
+
+    /// Returns the title of the named panel.
+    fn title<'b>(&'b self, panel: &'b str) -> &'b str {
+        self.groups
+            .iter()
+            .flat_map(|group| group.panels.iter().zip(&group.titles))
+            .chain(self.floating.iter().map(|f| (&f.panel, &f.title)))
+            .find_map(|(name, title)| (name == panel).then_some(title.as_str()))
+            .unwrap_or(panel)
+    }
+
+    /// Computes the regions of the groups and the splitters between them.
+    fn split(
+        &self,
+        node: &DockNode,
+        region: Rectangle,
+        path: &mut Vec<bool>,
+        regions: &mut Vec<Rectangle>,
+        splitters: &mut Vec<Splitter>,
+    ) {
+        match node {
+            DockNode::Tabs { .. } => regions.push(region),
+            DockNode::Split {
+                axis,
+                ratio,
+                first,
+                second,
+            } => {
+                let (first_region, splitter, second_region) = match axis {
+                    Axis::Horizontal => {
+                        let available = (region.width - self.splitter_width).max(0.0);
+                        let width = (available * ratio).round();
+                        (
+                            Rectangle { width, ..region },
+                            Rectangle {
+                                x: region.x + width,
+                                width: self.splitter_width,
+                                ..region
+                            },
+                            Rectangle {
+                                x: region.x + width + self.splitter_width,
+                                width: available - width,
+                                ..region
+                            },
+                        )
+                    }
+                    Axis::Vertical => {
+                        let available = (region.height - self.splitter_width).max(0.0);
+                        let height = (available * ratio).round();
+                        (
+                            Rectangle { height, ..region },
+                            Rectangle {
+                                y: region.y + height,
+                                height: self.splitter_width,
+                                ..region
+                            },
+                            Rectangle {
+                                y: region.y + height + self.splitter_width,
+                                height: available - height,
+                                ..region
+                            },
+                        )
+                    }
+                };
+
+                splitters.push(Splitter {
+                    path: path.clone(),
+                    axis: *axis,
+                    bounds: splitter,
+                    region,
+                });
+
+                path.push(false);
+                self.split(first, first_region, path, regions, splitters);
+                let _ = path.pop();
+
+                path.push(true);
+                self.split(second, second_region, path, regions, splitters);
+                let _ = path.pop();
+            }
+        }
+    }
+
+    /// Returns the indicators docking a panel dragged to `position`: on the
+    /// edges of the area and around the center of the hovered group.
+    fn indicators(&self, layout: Layout<'_>, position: Point) -> Vec<Indicator> {
+        let area = layout.bounds();
+        let edges = [Edge::Left, Edge::Right, Edge::Top, Edge::Bottom];
+        let square = |center: Point| {
+            Rectangle::new(
+                Point::new(
+                    center.x - INDICATOR_SIZE / 2.0,
+                    center.y - INDICATOR_SIZE / 2.0,
+                ),
+                Size::new(INDICATOR_SIZE, INDICATOR_SIZE),
+            )
+        };
+
+        let inset = INDICATOR_SPACING * 2.0 + INDICATOR_SIZE / 2.0;
+        let mut indicators: Vec<Indicator> = edges
+            .into_iter()
+            .map(|edge| {
+                let center = match edge {
+                    Edge::Left => Point::new(area.x + inset, area.center_y()),
+                    Edge::Right => Point::new(area.x + area.width - inset, area.center_y()),
+                    Edge::Top => Point::new(area.center_x(), area.y + inset),
+                    Edge::Bottom => Point::new(area.center_x(), area.y + area.height - inset),
+                };
+
+                Indicator {
+                    bounds: square(center),
+                    edge: Some(edge),
+                    target: DockTarget::Area(edge),
+                    preview: half(area, edge),
+                }
+            })
+            .collect();
+
+        let hovered = self
+            .groups
+            .iter()
+            .zip(layout.children())
+            .find(|(_, layout)| layout.bounds().contains(position));
+
+        if let Some((group, layout)) = hovered {
+            let region = layout.bounds();
+            let center = region.center();
+            let panel = &group.panels[group.active];
+            let step = INDICATOR_SIZE + INDICATOR_SPACING;
+
+            indicators.push(Indicator {
+                bounds: square(center),
+                edge: None,
+                target: DockTarget::With(panel.clone()),
+                preview: region,
+            });
+            indicators.extend(edges.into_iter().map(|edge| {
+                let offset = match edge {
+                    Edge::Left => Vector::new(-step, 0.0),
+                    Edge::Right => Vector::new(step, 0.0),
+                    Edge::Top => Vector::new(0.0, -step),
+                    Edge::Bottom => Vector::new(0.0, step),
+                };
+
+                Indicator {
+                    bounds: square(center + offset),
+                    edge: Some(edge),
+                    target: DockTarget::Beside(panel.clone(), edge),
+                    preview: half(region, edge),
+                }
+            }));
+        }
+
+        indicators
+    }
+
+    /// Returns the area of the close icon of a title bar.
+    fn close_icon(title_bar: Rectangle) -> Rectangle {
+        Rectangle {
+            x: title_bar.x + title_bar.width - TITLE_BAR_HEIGHT,
+            width: TITLE_BAR_HEIGHT,
+            ..title_bar
+        }
+    }
+
+    /// Returns the text drawn with the given content, bounds, font and size.
+    fn text(content: &str, bounds: Rectangle, font: Font, size: f32) -> Text<'_, Font> {
+        Text {
+            content,
+            bounds: bounds.size(),
+            size: Pixels(size),
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+
+    /// Publishes the message changing the layout with `f`, if it changes.
+    fn change(&self, shell: &mut Shell<'_, Message>, f: impl FnOnce(&mut DockLayout)) {
+        let mut layout = self.layout.clone();
+        f(&mut layout);
+
+        if layout != self.layout {
+            shell.publish((self.on_change)(layout));
+        }
+    }
+}
+
+/// Collects the panels of the groups of tabs of `node` with their shown panel.
+fn collect_tabs<'b>(node: &'b DockNode, tabs: &mut Vec<(&'b [String], usize)>) {
+    match node {
+        DockNode::Tabs { panels, active } => tabs.push((panels, *active)),
+        DockNode::Split { first, second, .. } => {
+            collect_tabs(first, tabs);
+            collect_tabs(second, tabs);
+        }
+    }
+}
+
+/// Returns the half of `region` on its `edge`.
+fn half(region: Rectangle, edge: Edge) -> Rectangle {
+    match edge {
+        Edge::Left => Rectangle {
+            width: region.width / 2.0,
+            ..region
+        },
+        Edge::Right => Rectangle {
+            x: region.x + region.width / 2.0,
+            width: region.width / 2.0,
+            ..region
+        },
+        Edge::Top => Rectangle {
+            height: region.height / 2.0,
+            ..region
+        },
+        Edge::Bottom => Rectangle {
+            y: region.y + region.height / 2.0,
+            height: region.height / 2.0,
+            ..region
+        },
+    }
+}
+
+/// Returns the index of the topmost floating panel under the cursor.
+fn floating_at(floating: &[Layout<'_>], cursor: Cursor) -> Option<usize> {
+    floating
+        .iter()
+        .rposition(|layout| cursor.is_over(layout.bounds()))
+}
+
+/// Returns a quad with the given bounds and border.
+fn quad(bounds: Rectangle, width: f32, color: iced::Color, shadow: Shadow) -> Quad {
+    Quad {
+        bounds,
+        border: Border {
+            radius: 0.0.into(),
+            width,
+            color,
+        },
+        shadow,
+    }
+}
+
+/// A splitter between two docked nodes.
+#[derive(Clone, Debug)]
+struct Splitter {
+    /// The path of the split from the root of the layout.
+    path: Vec<bool>,
+    /// The axis of the split.
+    axis: Axis,
+    /// The bounds of the splitter, relative to the area.
+    bounds: Rectangle,
+    /// The region shared by the split, relative to the area.
+    region: Rectangle,
+}
+
+/// An indicator docking a dragged panel.
+#[derive(Clone, Debug)]
+struct Indicator {
+    /// The bounds of the indicator.
+    bounds: Rectangle,
+    /// The edge it docks the panel on, or `None` for the tabs of a group.
+    edge: Option<Edge>,
+    /// Where it docks the panel.
+    target: DockTarget,
+    /// The space the panel takes once docked.
+    preview: Rectangle,
+}
+
+/// What the user is doing with a [`DockArea`].
+#[derive(Debug, Default)]
+enum Action {
+    /// Nothing.
+    #[default]
+    Idle,
+    /// Dragging the splitter at the index.
+    Resizing(usize),
+    /// Pressing or dragging a panel.
+    Dragging {
+        /// The name of the panel.
+        panel: String,
+        /// The position where the panel was pressed.
+        origin: Point,
+        /// The position of the cursor in the dragged panel.
+        grab: Vector,
+        /// The size of the dragged panel.
+        size: Size,
+        /// Whether the cursor moved past the threshold.
+        moved: bool,
+    },
+}
+
+/// The state of a [`DockArea`].
+#[derive(Debug, Default)]
+struct State {
+    /// The names of the panels whose contents follow the tab bars in the
+    /// children of the tree.
+    contents: Vec<String>,
+    /// The trees of the contents of the hidden panels.
+    hidden: HashMap<String, Tree>,
+    /// The splitters of the last layout.
+    splitters: Vec<Splitter>,
+    /// What the user is doing.
+    action: Action,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DockArea<'_, Message, Theme, Renderer>
+where
+    Renderer: IconRenderer,
+    Theme: StyleSheet + tab_bar::StyleSheet + TextStyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            contents: self.contents().map(|(panel, _)| panel.to_owned()).collect(),
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.groups
+            .iter()
+            .map(|group| Tree::new(&group.tab_bar as &dyn Widget<TabMessage, Theme, Renderer>))
+            .chain(self.contents().map(|(_, content)| Tree::new(content)))
+            .collect()
+    }
+
+    // The trees of the contents follow their panels between groups, and
+    // those of the hidden panels are kept until they are shown again.
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let bars = tree.children.len().saturating_sub(state.contents.len());
+
+        let mut trees = std::mem::take(&mut state.hidden);
+        trees.extend(state.contents.drain(..).zip(tree.children.drain(bars..)));
+
+        tree.children.clear();
+        tree.children.extend(
+            self.groups
+                .iter()
+                .map(|group| Tree::new(&group.tab_bar as &dyn Widget<TabMessage, Theme, Renderer>)),
+        );
+
+        for (panel, content) in self.contents() {
+            let child = trees.remove(panel).map_or_else(
+                || Tree::new(content),
+                |mut child| {
+                    child.diff(content);
+                    child
+                },
+            );
+            tree.children.push(child);
+            state.contents.push(panel.to_owned());
+        }
+
+        trees.retain(|panel, _| self.layout.contains(panel));
+        state.hidden = trees;
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(self.width, self.height, Size::ZERO);
+
+        let mut regions = Vec::new();
+        let mut splitters = Vec::new();
+        if let Some(root) = self.layout.root() {
+            self.split(
+                root,
+                Rectangle::with_size(size),
+                &mut Vec::new(),
+                &mut regions,
+                &mut splitters,
+            );
+        }
+
+        let (bars, contents) = tree.children.split_at_mut(self.groups.len());
+        let mut nodes = Vec::new();
+
+        for ((group, region), (bar_tree, content_tree)) in self
+            .groups
+            .iter()
+            .zip(&regions)
+            .zip(bars.iter_mut().zip(contents.iter_mut()))
+        {
+            let bar =
+                group
+                    .tab_bar
+                    .layout(bar_tree, renderer, &Limits::new(Size::ZERO, region.size()));
+            let bar_height = bar.size().height;
+            let content = group
+                .content
+                .as_widget()
+                .layout(
+                    content_tree,
+                    renderer,
+                    &Limits::new(
+                        Size::ZERO,
+                        Size::new(region.width, (region.height - bar_height).max(0.0)),
+                    ),
+                )
+                .move_to(Point::new(0.0, bar_height));
+
+            nodes.push(
+                Node::with_children(region.size(), vec![bar, content]).move_to(region.position()),
+            );
+        }
+
+        for ((floating, placed), content_tree) in self
+            .floating
+            .iter()
+            .zip(self.layout.floating())
+            .zip(contents.iter_mut().skip(self.groups.len()))
+        {
+            // Keeps the title bar of the panel in the area.
+            let bounds = placed.bounds;
+            let position = Point::new(
+                bounds.x.min(size.width - TITLE_BAR_HEIGHT).max(0.0),
+                bounds.y.min(size.height - TITLE_BAR_HEIGHT).max(0.0),
+            );
+            let content = floating
+                .content
+                .as_widget()
+                .layout(
+                    content_tree,
+                    renderer,
+                    &Limits::new(
+                        Size::ZERO,
+                        Size::new(bounds.width, (bounds.height - TITLE_BAR_HEIGHT).max(0.0)),
+                    ),
+                )
+                .move_to(Point::new(0.0, TITLE_BAR_HEIGHT));
+
+            nodes.push(Node::with_children(bounds.size(), vec![content]).move_to(position));
+        }
+
+        tree.state.downcast_mut::<State>().splitters = splitters;
+
+        Node::with_children(size, nodes)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let offset = Vector::new(bounds.x, bounds.y);
+        let children: Vec<Layout<'_>> = layout.children().collect();
+        let (group_layouts, floating_layouts) = children.split_at(self.groups.len());
+
+        let is_press = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+        let is_move = matches!(
+            event,
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. })
+        );
+        let is_release = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+        );
+
+        match &mut state.action {
+            Action::Idle => {}
+            Action::Resizing(index) => {
+                if is_release {
+                    state.action = Action::Idle;
+                    return event::Status::Captured;
+                }
+
+                if let (true, Some(splitter), Some(position)) =
+                    (is_move, state.splitters.get(*index), cursor.position())
+                {
+                    let region = splitter.region + offset;
+                    let ratio = match splitter.axis {
+                        Axis::Horizontal => {
+                            (position.x - region.x - self.splitter_width / 2.0)
+                                / (region.width - self.splitter_width).max(1.0)
+                        }
+                        Axis::Vertical => {
+                            (position.y - region.y - self.splitter_width / 2.0)
+                                / (region.height - self.splitter_width).max(1.0)
+                        }
+                    };
+                    let path = splitter.path.clone();
+                    self.change(shell, |layout| layout.resize(&path, ratio));
+                    return event::Status::Captured;
+                }
+            }
+            Action::Dragging {
+                panel,
+                origin,
+                grab,
+                size,
+                moved,
+            } => {
+                if is_move {
+                    if let Some(position) = cursor.position() {
+                        *moved |= position.distance(*origin) > THRESHOLD;
+                    }
+                    if *moved {
+                        return event::Status::Captured;
+                    }
+                } else if is_release {
+                    let (panel, grab, size, moved) = (panel.clone(), *grab, *size, *moved);
+                    state.action = Action::Idle;
+
+                    if let (true, Some(position)) = (moved, cursor.position()) {
+                        let target = self
+                            .indicators(layout, position)
+                            .into_iter()
+                            .find(|indicator| indicator.bounds.contains(position))
+                            .map_or_else(
+                                || {
+                                    DockTarget::Float(Rectangle::new(
+                                        position - grab - offset,
+                                        size,
+                                    ))
+                                },
+                                |indicator| indicator.target,
+                            );
+                        self.change(shell, |layout| layout.dock(panel, target));
+                        return event::Status::Captured;
+                    }
+                } else if let (
+                    true,
+                    Event::Keyboard(keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(Named::Escape),
+                        ..
+                    }),
+                ) = (*moved, &event)
+                {
+                    state.action = Action::Idle;
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        let covering = floating_at(floating_layouts, cursor);
+
+        if let (true, Some(position)) = (is_press, cursor.position()) {
+            if let Some(index) = covering {
+                let floating = &self.floating[index];
+                let panel_bounds = floating_layouts[index].bounds();
+                let title_bar = Rectangle {
+                    height: TITLE_BAR_HEIGHT,
+                    ..panel_bounds
+                };
+
+                if floating.closable && Self::close_icon(title_bar).contains(position) {
+                    let panel = floating.panel.clone();
+                    self.change(shell, |layout| {
+                        let _ = layout.remove(&panel);
+                    });
+                    return event::Status::Captured;
+                }
+
+                // Pressing a floating panel raises it above the others.
+                let panel = floating.panel.clone();
+                self.change(shell, |layout| layout.select(&panel));
+
+                if title_bar.contains(position) {
+                    state.action = Action::Dragging {
+                        panel,
+                        origin: position,
+                        grab: position - panel_bounds.position(),
+                        size: panel_bounds.size(),
+                        moved: false,
+                    };
+                    return event::Status::Captured;
+                }
+            } else if let Some(index) = state
+                .splitters
+                .iter()
+                .position(|splitter| (splitter.bounds + offset).contains(position))
+            {
+                state.action = Action::Resizing(index);
+                return event::Status::Captured;
+            } else if let Some((index, bar_layout)) = group_layouts
+                .iter()
+                .filter_map(|layout| layout.children().next())
+                .enumerate()
+                .find(|(_, bar_layout)| bar_layout.bounds().contains(position))
+            {
+                let group = &mut self.groups[index];
+                let mut messages = Vec::new();
+                let mut bar_shell = Shell::new(&mut messages);
+                let status = group.tab_bar.on_event(
+                    &mut tree.children[index],
+                    event,
+                    bar_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    &mut bar_shell,
+                    viewport,
+                );
+
+                for message in messages {
+                    let group = &self.groups[index];
+                    match message {
+                        TabMessage::Select(tab) => {
+                            let panel = group.panels[tab].clone();
+                            let tab_bounds = bar_layout
+                                .children()
+                                .nth(tab)
+                                .map_or_else(|| bar_layout.bounds(), |tab| tab.bounds());
+
+                            self.change(shell, |layout| layout.select(&panel));
+                            state.action = Action::Dragging {
+                                panel,
+                                origin: position,
+                                grab: position - tab_bounds.position(),
+                                size: FLOATING_SIZE,
+                                moved: false,
+                            };
+                        }
+                        TabMessage::Close(tab) => {
+                            let panel = group.panels[tab].clone();
+                            self.change(shell, |layout| {
+                                let _ = layout.remove(&panel);
+                            });
+                        }
+                    }
+                }
+
+                return status;
+            }
+        }
+
+        let groups = self.groups.len();
+        let mut status = event::Status::Ignored;
+
+        // The floating panels, topmost first, hide the cursor from the panels under them.
+        for (index, (floating, floating_layout)) in self
+            .floating
+            .iter_mut()
+            .zip(floating_layouts)
+            .enumerate()
+            .rev()
+        {
+            let Some(content_layout) = floating_layout.children().next() else {
+                continue;
+            };
+            let cursor = if covering == Some(index) {
+                cursor
+            } else {
+                Cursor::Unavailable
+            };
+
+            status = status.merge(floating.content.as_widget_mut().on_event(
+                &mut tree.children[groups + index],
+                event.clone(),
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ));
+        }
+
+        let cursor = if covering.is_some() {
+            Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        for (index, (group, group_layout)) in self.groups.iter_mut().zip(group_layouts).enumerate()
+        {
+            let Some(content_layout) = group_layout.children().nth(1) else {
+                continue;
+            };
+
+            status = status.merge(group.content.as_widget_mut().on_event(
+                &mut tree.children[groups + index],
+                event.clone(),
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ));
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let offset = Vector::new(bounds.x, bounds.y);
+        let children: Vec<Layout<'_>> = layout.children().collect();
+        let (group_layouts, floating_layouts) = children.split_at(self.groups.len());
+        let groups = self.groups.len();
+        let resizing = |axis| match axis {
+            Axis::Horizontal => mouse::Interaction::ResizingHorizontally,
+            Axis::Vertical => mouse::Interaction::ResizingVertically,
+        };
+
+        match &state.action {
+            Action::Resizing(index) => {
+                if let Some(splitter) = state.splitters.get(*index) {
+                    return resizing(splitter.axis);
+                }
+            }
+            Action::Dragging { moved: true, .. } => return mouse::Interaction::Grabbing,
+            _ => {}
+        }
+
+        let Some(position) = cursor.position() else {
+            return mouse::Interaction::default();
+        };
+
+        if let Some(index) = floating_at(floating_layouts, cursor) {
+            let panel_bounds = floating_layouts[index].bounds();
+            let title_bar = Rectangle {
+                height: TITLE_BAR_HEIGHT,
+                ..panel_bounds
+            };
+
+            return if self.floating[index].closable
+                && Self::close_icon(title_bar).contains(position)
+            {
+                mouse::Interaction::Pointer
+            } else if title_bar.contains(position) {
+                mouse::Interaction::Grab
+            } else {
+                floating_layouts[index].children().next().map_or_else(
+                    mouse::Interaction::default,
+                    |content_layout| {
+                        self.floating[index].content.as_widget().mouse_interaction(
+                            &tree.children[groups + index],
+                            content_layout,
+                            cursor,
+                            viewport,
+                            renderer,
+                        )
+                    },
+                )
+            };
+        }
+
+        if let Some(splitter) = state
+            .splitters
+            .iter()
+            .find(|splitter| (splitter.bounds + offset).contains(position))
+        {
+            return resizing(splitter.axis);
+        }
+
+        self.groups
+            .iter()
+            .zip(group_layouts)
+            .enumerate()
+            .find(|(_, (_, group_layout))| group_layout.bounds().contains(position))
+            .map_or_else(
+                mouse::Interaction::default,
+                |(index, (group, group_layout))| {
+                    let mut children = group_layout.children();
+                    match (children.next(), children.next()) {
+                        (Some(bar_layout), _) if bar_layout.bounds().contains(position) => {
+                            group.tab_bar.mouse_interaction(
+                                &tree.children[index],
+                                bar_layout,
+                                cursor,
+                                viewport,
+                                renderer,
+                            )
+                        }
+                        (_, Some(content_layout)) => group.content.as_widget().mouse_interaction(
+                            &tree.children[groups + index],
+                            content_layout,
+                            cursor,
+                            viewport,
+                            renderer,
+                        ),
+                        _ => mouse::Interaction::default(),
+                    }
+                },
+            )
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let offset = Vector::new(bounds.x, bounds.y);
+        let children: Vec<Layout<'_>> = layout.children().collect();
+        let (group_layouts, floating_layouts) = children.split_at(self.groups.len());
+        let groups = self.groups.len();
+        let covering = floating_at(floating_layouts, cursor);
+
+        let active = <Theme as StyleSheet>::active(theme, &self.style);
+        let hovered_splitter = match &state.action {
+            Action::Resizing(index) => Some(*index),
+            Action::Idle if covering.is_none() => cursor.position().and_then(|position| {
+                state
+                    .splitters
+                    .iter()
+                    .position(|splitter| (splitter.bounds + offset).contains(position))
+            }),
+            _ => None,
+        };
+        let appearance = if hovered_splitter.is_some() {
+            <Theme as StyleSheet>::hovered(theme, &self.style)
+        } else {
+            active
+        };
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                quad(bounds, 0.0, iced::Color::TRANSPARENT, Shadow::default()),
+                background,
+            );
+        }
+
+        if let Some(index) = hovered_splitter {
+            if let Some(splitter) = state.splitters.get(index) {
+                renderer.fill_quad(
+                    quad(
+                        splitter.bounds + offset,
+                        0.0,
+                        iced::Color::TRANSPARENT,
+                        Shadow::default(),
+                    ),
+                    appearance.splitter_background,
+                );
+            }
+        }
+        for (index, splitter) in state.splitters.iter().enumerate() {
+            if Some(index) != hovered_splitter {
+                renderer.fill_quad(
+                    quad(
+                        splitter.bounds + offset,
+                        0.0,
+                        iced::Color::TRANSPARENT,
+                        Shadow::default(),
+                    ),
+                    active.splitter_background,
+                );
+            }
+        }
+
+        // The docked panels.
+        let docked_cursor = if covering.is_some() {
+            Cursor::Unavailable
+        } else {
+            cursor
+        };
+        for (index, (group, group_layout)) in self.groups.iter().zip(group_layouts).enumerate() {
+            let group_bounds = group_layout.bounds();
+            renderer.fill_quad(
+                quad(
+                    group_bounds,
+                    appearance.border_width,
+                    appearance.border_color,
+                    Shadow::default(),
+                ),
+                appearance.panel_background,
+            );
+
+            let mut layouts = group_layout.children();
+            if let Some(bar_layout) = layouts.next() {
+                group.tab_bar.draw(
+                    &tree.children[index],
+                    renderer,
+                    theme,
+                    style,
+                    bar_layout,
+                    docked_cursor,
+                    viewport,
+                );
+            }
+            if let Some(content_layout) = layouts.next() {
+                let content_bounds = content_layout.bounds();
+                renderer.with_layer(content_bounds, |renderer| {
+                    group.content.as_widget().draw(
+                        &tree.children[groups + index],
+                        renderer,
+                        theme,
+                        style,
+                        content_layout,
+                        docked_cursor,
+                        &content_bounds,
+                    );
+                });
+            }
+        }
+
+        // The floating panels, each in its own layer over the ones under it.
+        for (index, (floating, floating_layout)) in
+            self.floating.iter().zip(floating_layouts).enumerate()
+        {
+            let panel_bounds = floating_layout.bounds();
+            let title_bar = Rectangle {
+                height: TITLE_BAR_HEIGHT,
+                ..panel_bounds
+            };
+            let cursor = if covering == Some(index) {
+                cursor
+            } else {
+                Cursor::Unavailable
+            };
+
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    quad(
+                        panel_bounds,
+                        appearance.border_width,
+                        appearance.border_color,
+                        appearance.shadow,
+                    ),
+                    appearance.panel_background,
+                );
+                renderer.fill_quad(
+                    quad(
+                        title_bar,
+                        appearance.border_width,
+                        appearance.border_color,
+                        Shadow::default(),
+                    ),
+                    appearance.title_bar_background,
+                );
+
+                let mut title = Rectangle {
+                    x: title_bar.x + PADDING,
+                    width: (title_bar.width - PADDING * 2.0).max(0.0),
+                    ..title_bar
+                };
+                if floating.closable {
+                    let close_icon = Self::close_icon(title_bar);
+                    title.width = (title.width - close_icon.width).max(0.0);
+
+                    renderer.fill_text(
+                        Text {
+                            horizontal_alignment: Horizontal::Center,
+                            ..Self::text(
+                                &icon_to_string(Bootstrap::X),
+                                close_icon,
+                                BOOTSTRAP_FONT,
+                                self.text_size + if cursor.is_over(close_icon) { 2.0 } else { 0.0 },
+                            )
+                        },
+                        close_icon.center(),
+                        appearance.title_color,
+                        close_icon,
+                    );
+                }
+                renderer.fill_text(
+                    Self::text(&floating.title, title, self.font, self.text_size),
+                    Point::new(title.x, title.center_y()),
+                    appearance.title_color,
+                    title,
+                );
+
+                if let Some(content_layout) = floating_layout.children().next() {
+                    let content_bounds = content_layout.bounds();
+                    renderer.with_layer(content_bounds, |renderer| {
+                        floating.content.as_widget().draw(
+                            &tree.children[groups + index],
+                            renderer,
+                            theme,
+                            style,
+                            content_layout,
+                            cursor,
+                            &content_bounds,
+                        );
+                    });
+                }
+            });
+        }
+
+        // The indicators and the dragged panel.
+        let (
+            Action::Dragging {
+                panel,
+                grab,
+                size,
+                moved: true,
+                ..
+            },
+            Some(position),
+        ) = (&state.action, cursor.position())
+        else {
+            return;
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            let indicators = self.indicators(layout, position);
+            let target = indicators
+                .iter()
+                .find(|indicator| indicator.bounds.contains(position));
+
+            let preview = target.map_or_else(
+                || Rectangle::new(position - *grab, *size),
+                |indicator| indicator.preview,
+            );
+            renderer.fill_quad(
+                quad(
+                    preview,
+                    appearance.border_width,
+                    active.indicator_border_color,
+                    Shadow::default(),
+                ),
+                active.preview_background,
+            );
+            if target.is_none() {
+                let title = Rectangle {
+                    x: preview.x + PADDING,
+                    width: (preview.width - PADDING * 2.0).max(0.0),
+                    height: TITLE_BAR_HEIGHT,
+                    ..preview
+                };
+                renderer.fill_text(
+                    Self::text(self.title(panel), title, self.font, self.text_size),
+                    Point::new(title.x, title.center_y()),
+                    active.title_color,
+                    title,
+                );
+            }
+
+            for indicator in &indicators {
+                let is_hovered = target.is_some_and(|target| target.bounds == indicator.bounds);
+                renderer.fill_quad(
+                    Quad {
+                        bounds: indicator.bounds,
+                        border: Border {
+                            radius: 4.0.into(),
+                            width: if is_hovered { 2.0 } else { 1.0 },
+                            color: active.indicator_border_color,
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    active.indicator_background,
+                );
+
+                let inner = Rectangle {
+                    x: indicator.bounds.x + 6.0,
+                    y: indicator.bounds.y + 6.0,
+                    width: indicator.bounds.width - 12.0,
+                    height: indicator.bounds.height - 12.0,
+                };
+                let side = indicator.edge.map_or(inner, |edge| half(inner, edge));
+                renderer.fill_quad(
+                    quad(side, 0.0, iced::Color::TRANSPARENT, Shadow::default()),
+                    Background::Color(active.indicator_border_color),
+                );
+            }
+        });
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let groups = self.groups.len();
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for (((_, content), tree), layout) in self
+                .contents()
+                .zip(tree.children.iter_mut().skip(groups))
+                .zip(layout.children())
+            {
+                if let Some(content_layout) = layout.children().last() {
+                    content
+                        .as_widget()
+                        .operate(tree, content_layout, renderer, operation);
+                }
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let groups = self.groups.len();
+        let overlays: Vec<_> = self
+            .groups
+            .iter_mut()
+            .map(|group| &mut group.content)
+            .chain(
+                self.floating
+                    .iter_mut()
+                    .map(|floating| &mut floating.content),
+            )
+            .zip(tree.children.iter_mut().skip(groups))
+            .zip(layout.children())
+            .filter_map(|((content, tree), layout)| {
+                content.as_widget_mut().overlay(
+                    tree,
+                    layout.children().last()?,
+                    renderer,
+                    translation,
+                )
+            })
+            .collect();
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DockArea<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + IconRenderer,
+    Theme: 'a + StyleSheet + tab_bar::StyleSheet + TextStyleSheet,
+{
+    fn from(dock: DockArea<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(dock)
+    }
+}
@@ -0,0 +1,684 @@
+//! Drag payloads from draggable elements and drop them on targets.
+//!
+//! A [`Draggable`] carries a typed payload, which is dragged with a preview
+//! following the cursor. The [`DropTarget`]s accepting the type of the payload,
+//! and passing their [`accept`](DropTarget::accept) check, are highlighted
+//! while it is dragged and produce a message when it is dropped on them.
+//!
+//! *This API requires the following crate features to be activated: `drag_and_drop`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Quad},
+        widget::{
+            tree::{self, Tag, Tree},
+            Operation,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch, Border, Color, Element, Event, Length, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+pub use crate::style::drag_and_drop::{Appearance, StyleSheet};
+use crate::widgets::overlay::DragPreviewOverlay;
+
+/// The payload being dragged, shared by the [`Draggable`] dragging it and the
+/// [`DropTarget`]s it is dragged over.
+struct Session {
+    /// The dragged payload.
+    payload: Rc<dyn Any>,
+    /// Whether the payload was released, the targets seeing the same release.
+    released: bool,
+}
+
+thread_local! {
+    /// The current drag of the application.
+    static SESSION: RefCell<Option<Session>> = const { RefCell::new(None) };
+}
+
+/// Starts dragging `payload`.
+fn start(payload: Rc<dyn Any>) {
+    SESSION.with(|session| {
+        *session.borrow_mut() = Some(Session {
+            payload,
+            released: false,
+        });
+    });
+}
+
+/// Releases the dragged payload.
+fn release() {
+    SESSION.with(|session| {
+        if let Some(session) = session.borrow_mut().as_mut() {
+            session.released = true;
+        }
+    });
+}
+
+/// Forgets a released payload once the release has been seen by every widget.
+fn settle(event: &Event) {
+    if !is_release(event) {
+        SESSION.with(|session| {
+            let mut session = session.borrow_mut();
+            if session.as_ref().is_some_and(|session| session.released) {
+                *session = None;
+            }
+        });
+    }
+}
+
+/// Returns the dragged payload if it is a `P`, including a payload released
+/// by the current event when `released` is set.
+fn payload<P: Clone + 'static>(released: bool) -> Option<P> {
+    SESSION.with(|session| {
+        session
+            .borrow()
+            .as_ref()
+            .filter(|session| released || !session.released)
+            .and_then(|session| session.payload.downcast_ref::<P>().cloned())
+    })
+}
+
+/// Whether the event releases a drag.
+fn is_release(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+    )
+}
+
+/// An element whose payload is dragged to [`DropTarget`]s.
+///
+/// The drag starts once the cursor pressed on the content moves further than
+/// the [`threshold`](Draggable::threshold), unless the content captured the
+/// press. The content, or a custom [`preview`](Draggable::preview), follows the
+/// cursor while dragging.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::drag_and_drop::{Draggable, DropTarget};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Moved(usize),
+/// }
+///
+/// let card = Draggable::new(3, Text::new("Card 3"));
+/// let column = DropTarget::new(Text::new("Done"), Message::Moved);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Draggable<'a, P, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The payload dragged from the [`Draggable`].
+    payload: P,
+    /// The content of the [`Draggable`].
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The preview following the cursor, if not the content.
+    preview: Option<Element<'a, Message, Theme, Renderer>>,
+    /// The message produced when the drag starts.
+    on_drag_start: Option<Message>,
+    /// The message produced when the payload is released.
+    on_drag_end: Option<Message>,
+    /// The distance the cursor moves before the drag starts.
+    threshold: f32,
+    /// The style of the preview.
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`Draggable`].
+#[derive(Debug)]
+struct DragState {
+    /// Where the content was pressed.
+    pressed_at: Option<Point>,
+    /// Whether the payload is being dragged.
+    dragging: bool,
+    /// The position of the cursor.
+    position: Point,
+    /// Where the content is held from its top left corner.
+    grab: Vector,
+    /// The size of the content when the drag started.
+    size: Size,
+}
+
+impl<'a, P, Message, Theme, Renderer> Draggable<'a, P, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Draggable`] dragging `payload` from the given content.
+    pub fn new<C>(payload: P, content: C) -> Self
+    where
+        C: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self {
+            payload,
+            content: content.into(),
+            preview: None,
+            on_drag_start: None,
+            on_drag_end: None,
+            threshold: 4.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the element following the cursor while dragging, instead of the content.
+    #[must_use]
+    pub fn preview<E>(mut self, preview: E) -> Self
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        self.preview = Some(preview.into());
+        self
+    }
+
+    /// Sets the message produced when the drag starts.
+    #[must_use]
+    pub fn on_drag_start(mut self, message: Message) -> Self {
+        self.on_drag_start = Some(message);
+        self
+    }
+
+    /// Sets the message produced when the payload is released, on a
+    /// [`DropTarget`] or not.
+    #[must_use]
+    pub fn on_drag_end(mut self, message: Message) -> Self {
+        self.on_drag_end = Some(message);
+        self
+    }
+
+    /// Sets the distance the cursor moves before the drag starts.
+    #[must_use]
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets the style of the preview of the [`Draggable`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<P, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Draggable<'_, P, Message, Theme, Renderer>
+where
+    P: Clone + 'static,
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<DragState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(DragState {
+            pressed_at: None,
+            dragging: false,
+            position: Point::ORIGIN,
+            grab: Vector::ZERO,
+            size: Size::ZERO,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.content)
+            .chain(&self.preview)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(
+            &std::iter::once(&self.content)
+                .chain(&self.preview)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        settle(&event);
+        let state = tree.state.downcast_mut::<DragState>();
+
+        if state.dragging {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                    state.position = position;
+                }
+                _ if is_release(&event) => {
+                    state.dragging = false;
+                    state.pressed_at = None;
+                    release();
+
+                    if let Some(on_drag_end) = self.on_drag_end.clone() {
+                        shell.publish(on_drag_end);
+                    }
+                }
+                _ => return event::Status::Ignored,
+            }
+            return event::Status::Captured;
+        }
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+        let state = tree.state.downcast_mut::<DragState>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if status == event::Status::Ignored =>
+            {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.pressed_at = Some(position);
+                    state.grab = position - bounds.position();
+                    state.size = bounds.size();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state
+                    .pressed_at
+                    .is_some_and(|pressed_at| pressed_at.distance(position) > self.threshold) =>
+            {
+                state.dragging = true;
+                state.position = position;
+                start(Rc::new(self.payload.clone()));
+
+                if let Some(on_drag_start) = self.on_drag_start.clone() {
+                    shell.publish(on_drag_start);
+                }
+                return event::Status::Captured;
+            }
+            _ if is_release(&event) => state.pressed_at = None,
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<DragState>().dragging {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::default() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<DragState>();
+
+        if !state.dragging {
+            return self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                translation,
+            );
+        }
+
+        // The cursor position is already in the coordinates of the window.
+        let position = state.position - state.grab;
+        let size = state.size;
+        let (tree, preview, size) = match &mut self.preview {
+            Some(preview) => (tree.children.get_mut(1)?, preview, None),
+            None => (&mut tree.children[0], &mut self.content, Some(size)),
+        };
+
+        Some(overlay::Element::new(Box::new(DragPreviewOverlay::new(
+            tree,
+            preview,
+            position,
+            size,
+            &self.style,
+        ))))
+    }
+}
+
+impl<'a, P, Message, Theme, Renderer> From<Draggable<'a, P, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    P: 'a + Clone + 'static,
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(draggable: Draggable<'a, P, Message, Theme, Renderer>) -> Self {
+        Element::new(draggable)
+    }
+}
+
+/// The function telling whether a [`DropTarget`] accepts a payload.
+type Accept<'a, P> = Box<dyn Fn(&P) -> bool + 'a>;
+
+/// An element receiving the payloads of type `P` dropped on it.
+///
+/// It is highlighted while a payload it accepts is dragged, more so when the
+/// payload is over it.
+#[allow(missing_debug_implementations)]
+pub struct DropTarget<'a, P, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The content of the [`DropTarget`].
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The function producing the message when a payload is dropped.
+    on_drop: Box<dyn Fn(P) -> Message + 'a>,
+    /// The function telling whether a payload is accepted.
+    accept: Option<Accept<'a, P>>,
+    /// The style of the [`DropTarget`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, P, Message, Theme, Renderer> DropTarget<'a, P, Message, Theme, Renderer>
+where
+    P: Clone + 'static,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`DropTarget`] around the given content, producing a
+    /// message with the payload dropped on it.
+    pub fn new<C, F>(content: C, on_drop: F) -> Self
+    where
+        C: Into<Element<'a, Message, Theme, Renderer>>,
+        F: 'a + Fn(P) -> Message,
+    {
+        Self {
+            content: content.into(),
+            on_drop: Box::new(on_drop),
+            accept: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function telling whether the [`DropTarget`] accepts a payload.
+    /// By default, every payload of type `P` is accepted.
+    #[must_use]
+    pub fn accept<F>(mut self, accept: F) -> Self
+    where
+        F: 'a + Fn(&P) -> bool,
+    {
+        self.accept = Some(Box::new(accept));
+        self
+    }
+
+    /// Sets the style of the [`DropTarget`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the dragged payload if the [`DropTarget`] accepts it.
+    fn accepted(&self, released: bool) -> Option<P> {
+        payload::<P>(released)
+            .filter(|payload| self.accept.as_ref().map_or(true, |accept| accept(payload)))
+    }
+}
+
+impl<P, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropTarget<'_, P, Message, Theme, Renderer>
+where
+    P: Clone + 'static,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        settle(&event);
+
+        if is_release(&event) && cursor.is_over(layout.bounds()) {
+            if let Some(payload) = self.accepted(true) {
+                shell.publish((self.on_drop)(payload));
+                return event::Status::Captured;
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = match self.accepted(false) {
+            Some(_) if cursor.is_over(bounds) => theme.hovered(&self.style),
+            Some(_) => theme.valid(&self.style),
+            None => theme.active(&self.style),
+        };
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                Quad {
+                    bounds,
+                    border: Border::with_radius(appearance.border_radius),
+                    shadow: appearance.shadow,
+                },
+                background,
+            );
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        // The border is drawn over the content to stay visible on its background.
+        if appearance.border_width > 0.0 {
+            renderer.fill_quad(
+                Quad {
+                    bounds,
+                    border: Border {
+                        radius: appearance.border_radius.into(),
+                        width: appearance.border_width,
+                        color: appearance.border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, P, Message, Theme, Renderer> From<DropTarget<'a, P, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    P: 'a + Clone + 'static,
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(target: DropTarget<'a, P, Message, Theme, Renderer>) -> Self {
+        Element::new(target)
+    }
+}
@@ -0,0 +1,659 @@
+//! Slide a panel in from an edge of the content.
+//!
+//! *This API requires the following crate features to be activated: drawer*
+
+use super::overlay::drawer::DrawerOverlay;
+
+use crate::core::animation::{self, Easing, Timeline};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay::{self, Group},
+        renderer::{self, Quad},
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    window, Border, Element, Event, Length, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::time::Duration;
+
+pub use crate::style::drawer::{Appearance, StyleSheet};
+
+/// The duration of the slide animation of a [`Drawer`].
+const ANIMATION_DURATION: Duration = Duration::from_millis(250);
+
+/// The edge of the content from which the panel of a [`Drawer`] slides in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Edge {
+    /// The left edge.
+    #[default]
+    Left,
+    /// The right edge.
+    Right,
+    /// The top edge.
+    Top,
+    /// The bottom edge.
+    Bottom,
+}
+
+impl Edge {
+    /// Returns the bounds of a panel of the given `size` sliding in from
+    /// the edge of `bounds`, shown by the given eased `progress`.
+    pub(crate) fn panel(self, bounds: Rectangle, size: f32, progress: f32) -> Rectangle {
+        let hidden = size * (1.0 - progress);
+
+        match self {
+            Self::Left => Rectangle::new(
+                Point::new(bounds.x - hidden, bounds.y),
+                Size::new(size, bounds.height),
+            ),
+            Self::Right => Rectangle::new(
+                Point::new(bounds.x + bounds.width - size + hidden, bounds.y),
+                Size::new(size, bounds.height),
+            ),
+            Self::Top => Rectangle::new(
+                Point::new(bounds.x, bounds.y - hidden),
+                Size::new(bounds.width, size),
+            ),
+            Self::Bottom => Rectangle::new(
+                Point::new(bounds.x, bounds.y + bounds.height - size + hidden),
+                Size::new(bounds.width, size),
+            ),
+        }
+    }
+
+    /// Returns the distance moved from `origin` to `position` towards the
+    /// edge, closing the panel.
+    pub(crate) fn closing_distance(self, origin: Point, position: Point) -> f32 {
+        match self {
+            Self::Left => origin.x - position.x,
+            Self::Right => position.x - origin.x,
+            Self::Top => origin.y - position.y,
+            Self::Bottom => position.y - origin.y,
+        }
+    }
+
+    /// Returns whether the edge is vertical.
+    fn is_vertical(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+}
+
+/// Where the panel of a [`Drawer`] is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Placement {
+    /// The panel slides over the content, above a backdrop.
+    #[default]
+    Over,
+    /// The panel slides in beside the content, which shrinks to make room for it.
+    Beside,
+}
+
+/// A container sliding a panel in from an edge of its content, for
+/// navigation menus and detail panels.
+///
+/// The application decides whether the panel is open. It is asked to close
+/// it when the backdrop is pressed, when the Escape key is pressed, or when
+/// the panel is dragged towards its edge.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::drawer::{Drawer, Edge};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CloseMenu,
+/// }
+///
+/// let drawer = Drawer::new(Text::new("Content"), Text::new("Menu"), true)
+///     .edge(Edge::Left)
+///     .on_close(Message::CloseMenu);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Drawer<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The underlying element.
+    underlay: Element<'a, Message, Theme, Renderer>,
+    /// The content of the panel.
+    panel: Element<'a, Message, Theme, Renderer>,
+    /// Whether the panel is open.
+    open: bool,
+    /// The optional message produced when the user closes the panel.
+    on_close: Option<Message>,
+    /// The edge from which the panel slides in.
+    edge: Edge,
+    /// Where the panel is shown.
+    placement: Placement,
+    /// The width, or the height for the top and bottom edges, of the panel.
+    size: f32,
+    /// The style of the [`Drawer`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`Drawer`].
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// The progress of the slide animation, from 0 (closed) to 1 (open).
+    pub(crate) slide: Timeline,
+    /// The panel being dragged by the user.
+    pub(crate) drag: Option<Drag>,
+}
+
+/// A drag of the panel of a [`Drawer`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Drag {
+    /// The position of the cursor when the panel was pressed.
+    pub(crate) origin: Point,
+    /// The progress when the panel was pressed.
+    pub(crate) start: f32,
+    /// Whether the cursor moved far enough to drag the panel.
+    pub(crate) dragging: bool,
+}
+
+impl State {
+    /// Returns the progress of the slide animation.
+    pub(crate) const fn progress(&self) -> f32 {
+        self.slide.progress()
+    }
+
+    /// Returns the progress of the animation with an ease-out curve.
+    pub(crate) fn eased(&self) -> f32 {
+        Easing::EaseOut.apply(self.progress())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Drawer<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Drawer`] sliding the `panel` in over the `underlay`
+    /// while `open` is true.
+    pub fn new(
+        underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+        panel: impl Into<Element<'a, Message, Theme, Renderer>>,
+        open: bool,
+    ) -> Self {
+        Self {
+            underlay: underlay.into(),
+            panel: panel.into(),
+            open,
+            on_close: None,
+            edge: Edge::default(),
+            placement: Placement::default(),
+            size: 300.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the message produced when the user closes the panel, by pressing
+    /// the backdrop or the Escape key, or by dragging the panel to its edge.
+    #[must_use]
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    /// Sets the edge from which the panel slides in.
+    #[must_use]
+    pub fn edge(mut self, edge: Edge) -> Self {
+        self.edge = edge;
+        self
+    }
+
+    /// Sets whether the panel is shown over or beside the content.
+    #[must_use]
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the width of the panel, or its height for the top and bottom edges.
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the style of the [`Drawer`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the layouts of the underlay and of the panel shown beside it.
+    fn beside(layout: Layout<'_>) -> (Option<Layout<'_>>, Option<Layout<'_>>) {
+        let mut children = layout.children();
+        (children.next(), children.next())
+    }
+}
+
+/// Lays out the `panel` content in a panel of the given bounds.
+pub(crate) fn layout_panel<Message, Theme, Renderer>(
+    panel: &Element<'_, Message, Theme, Renderer>,
+    tree: &mut Tree,
+    renderer: &Renderer,
+    bounds: Rectangle,
+) -> Node
+where
+    Renderer: renderer::Renderer,
+{
+    let content = panel
+        .as_widget()
+        .layout(tree, renderer, &Limits::new(Size::ZERO, bounds.size()));
+
+    Node::with_children(bounds.size(), vec![content]).move_to(bounds.position())
+}
+
+/// Draws the background and the border of a panel of the given bounds.
+pub(crate) fn draw_panel_background<Renderer>(
+    renderer: &mut Renderer,
+    appearance: &Appearance,
+    edge: Edge,
+    bounds: Rectangle,
+    shadow: bool,
+) where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        Quad {
+            bounds,
+            border: Border::default(),
+            shadow: if shadow {
+                Shadow {
+                    color: appearance.shadow_color,
+                    offset: Vector::ZERO,
+                    blur_radius: 16.0,
+                }
+            } else {
+                Shadow::default()
+            },
+        },
+        appearance.background,
+    );
+
+    let border = appearance.border_width;
+    let line = match edge {
+        Edge::Left => Rectangle {
+            x: bounds.x + bounds.width - border,
+            width: border,
+            ..bounds
+        },
+        Edge::Right => Rectangle {
+            width: border,
+            ..bounds
+        },
+        Edge::Top => Rectangle {
+            y: bounds.y + bounds.height - border,
+            height: border,
+            ..bounds
+        },
+        Edge::Bottom => Rectangle {
+            height: border,
+            ..bounds
+        },
+    };
+
+    renderer.fill_quad(
+        Quad {
+            bounds: line,
+            border: Border::default(),
+            shadow: Shadow::default(),
+        },
+        appearance.border_color,
+    );
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Drawer<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            slide: Timeline::new(if self.open { 1.0 } else { 0.0 }),
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.underlay), Tree::new(&self.panel)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.underlay, &self.panel]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.underlay.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let eased = tree.state.downcast_ref::<State>().eased();
+
+        if self.placement == Placement::Over {
+            let underlay =
+                self.underlay
+                    .as_widget()
+                    .layout(&mut tree.children[0], renderer, limits);
+            return Node::with_children(underlay.size(), vec![underlay]);
+        }
+
+        // The underlay makes room for the shown part of the panel.
+        let shown = self.size * eased;
+        let max = limits.max();
+        let underlay_limits = if self.edge.is_vertical() {
+            Limits::new(
+                limits.min(),
+                Size::new((max.width - shown).max(0.0), max.height),
+            )
+        } else {
+            Limits::new(
+                limits.min(),
+                Size::new(max.width, (max.height - shown).max(0.0)),
+            )
+        };
+        let underlay =
+            self.underlay
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, &underlay_limits);
+        let underlay_size = underlay.size();
+
+        let (size, offset) = match self.edge {
+            Edge::Left => (
+                Size::new(underlay_size.width + shown, underlay_size.height),
+                Vector::new(shown, 0.0),
+            ),
+            Edge::Right => (
+                Size::new(underlay_size.width + shown, underlay_size.height),
+                Vector::ZERO,
+            ),
+            Edge::Top => (
+                Size::new(underlay_size.width, underlay_size.height + shown),
+                Vector::new(0.0, shown),
+            ),
+            Edge::Bottom => (
+                Size::new(underlay_size.width, underlay_size.height + shown),
+                Vector::ZERO,
+            ),
+        };
+
+        let panel = layout_panel(
+            &self.panel,
+            &mut tree.children[1],
+            renderer,
+            self.edge
+                .panel(Rectangle::new(Point::ORIGIN, size), self.size, eased),
+        );
+
+        Node::with_children(size, vec![underlay.translate(offset), panel])
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            let drawer_state = state.state.downcast_mut::<State>();
+            let target = if self.open { 1.0 } else { 0.0 };
+
+            if drawer_state.drag.is_some_and(|drag| drag.dragging) {
+                drawer_state.slide.stop();
+            } else if drawer_state
+                .slide
+                .advance_to(target, ANIMATION_DURATION, now)
+            {
+                animation::request_frame(shell, now);
+                if self.placement == Placement::Beside {
+                    shell.invalidate_layout();
+                }
+            }
+        }
+
+        let (Some(underlay_layout), panel_layout) = Self::beside(layout) else {
+            return event::Status::Ignored;
+        };
+
+        if let Some(panel_layout) = panel_layout.and_then(|panel| panel.children().next()) {
+            let status = self.panel.as_widget_mut().on_event(
+                &mut state.children[1],
+                event.clone(),
+                panel_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        // Like a modal, the panel shown over the content keeps it from
+        // reacting to the user.
+        let covered = self.placement == Placement::Over
+            && (self.open || state.state.downcast_ref::<State>().progress() > 0.0);
+        if covered && !matches!(event, Event::Window(..)) {
+            return event::Status::Ignored;
+        }
+
+        self.underlay.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            underlay_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let (Some(underlay_layout), panel_layout) = Self::beside(layout) else {
+            return mouse::Interaction::default();
+        };
+
+        let panel = panel_layout
+            .and_then(|panel| panel.children().next())
+            .map_or_else(mouse::Interaction::default, |panel_layout| {
+                self.panel.as_widget().mouse_interaction(
+                    &state.children[1],
+                    panel_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            });
+
+        panel.max(self.underlay.as_widget().mouse_interaction(
+            &state.children[0],
+            underlay_layout,
+            cursor,
+            viewport,
+            renderer,
+        ))
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let (Some(underlay_layout), panel_layout) = Self::beside(layout) else {
+            return;
+        };
+
+        self.underlay.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            underlay_layout,
+            cursor,
+            viewport,
+        );
+
+        if let Some(panel_layout) = panel_layout {
+            let bounds = layout.bounds();
+
+            renderer.with_layer(bounds, |renderer| {
+                draw_panel_background(
+                    renderer,
+                    &theme.active(&self.style),
+                    self.edge,
+                    panel_layout.bounds(),
+                    false,
+                );
+
+                if let Some(content_layout) = panel_layout.children().next() {
+                    self.panel.as_widget().draw(
+                        &state.children[1],
+                        renderer,
+                        theme,
+                        style,
+                        content_layout,
+                        cursor,
+                        &bounds,
+                    );
+                }
+            });
+        }
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let (Some(underlay_layout), panel_layout) = Self::beside(layout) else {
+            return;
+        };
+
+        self.underlay.as_widget().operate(
+            &mut state.children[0],
+            underlay_layout,
+            renderer,
+            operation,
+        );
+
+        if let Some(content_layout) = panel_layout.and_then(|panel| panel.children().next()) {
+            self.panel.as_widget().operate(
+                &mut state.children[1],
+                content_layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (underlay_layout, panel_layout) = Self::beside(layout);
+        let drawer_state = state.state.downcast_mut::<State>();
+        let mut children = state.children.iter_mut();
+        let (underlay_tree, panel_tree) = (children.next()?, children.next()?);
+        let mut group = Group::new();
+
+        if let Some(underlay) = self.underlay.as_widget_mut().overlay(
+            underlay_tree,
+            underlay_layout?,
+            renderer,
+            translation,
+        ) {
+            group = group.push(underlay);
+        }
+
+        match self.placement {
+            Placement::Over if self.open || drawer_state.progress() > 0.0 => {
+                group = group.push(overlay::Element::new(Box::new(DrawerOverlay::new(
+                    drawer_state,
+                    panel_tree,
+                    &mut self.panel,
+                    self.open,
+                    self.on_close.clone(),
+                    self.edge,
+                    self.size,
+                    &self.style,
+                ))));
+            }
+            Placement::Beside => {
+                if let Some(panel) = panel_layout
+                    .and_then(|panel| panel.children().next())
+                    .and_then(|content_layout| {
+                        self.panel.as_widget_mut().overlay(
+                            panel_tree,
+                            content_layout,
+                            renderer,
+                            translation,
+                        )
+                    })
+                {
+                    group = group.push(panel);
+                }
+            }
+            Placement::Over => {}
+        }
+
+        Some(group.overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Drawer<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(drawer: Drawer<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(drawer)
+    }
+}
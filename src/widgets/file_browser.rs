@@ -0,0 +1,995 @@
+//! Browse the file system and select files.
+//!
+//! *This API requires the following crate features to be activated: `file_browser`*
+
+use crate::core::{
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    settings,
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{self, Paragraph, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    keyboard::{self, key::Named},
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+pub use crate::style::file_browser::{Appearance, StyleSheet};
+
+/// The height of the path and of the filter of a [`FileBrowser`].
+const BAR_HEIGHT: f32 = 30.0;
+/// The horizontal padding of the parts of a [`FileBrowser`].
+const PADDING: f32 = 8.0;
+/// The width of the separators between the directories of the path.
+const SEPARATOR_WIDTH: f32 = 16.0;
+/// The width of the button showing the hidden files.
+const TOGGLE_WIDTH: f32 = 64.0;
+/// The width of the scrollbar.
+const SCROLLBAR_WIDTH: f32 = 4.0;
+/// The longest time between the clicks of a double click.
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// The function telling which files a [`FileBrowser`] shows.
+type Filter<'a> = Box<dyn Fn(&Path) -> bool + 'a>;
+
+/// An entry of a directory.
+#[derive(Clone, Debug)]
+struct Entry {
+    /// The path of the entry.
+    path: PathBuf,
+    /// The name of the entry.
+    name: String,
+    /// Whether the entry is a directory.
+    is_dir: bool,
+}
+
+impl Entry {
+    /// Whether the entry is hidden, its name starting with a dot.
+    fn is_hidden(&self) -> bool {
+        self.name.starts_with('.')
+    }
+}
+
+/// A directory of the path shown above the entries.
+#[derive(Clone, Debug)]
+struct Crumb {
+    /// The path of the directory.
+    path: PathBuf,
+    /// The name of the directory.
+    name: String,
+    /// The bounds of the name.
+    bounds: Rectangle,
+}
+
+/// A browser of the file system showing the entries of a directory.
+///
+/// The path of the directory is shown above the entries, each of its
+/// directories opening on click. Typing filters the entries by name, and a
+/// button shows the hidden files. Double clicking a directory, or pressing
+/// Enter, opens it, while Backspace opens its parent.
+///
+/// Several entries are selected by holding Ctrl or Shift, unless
+/// [`multiple`](FileBrowser::multiple) is disabled.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::file_browser::FileBrowser;
+/// # use std::path::PathBuf;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Selected(Vec<PathBuf>),
+/// }
+///
+/// let browser = FileBrowser::new(PathBuf::from("/home"))
+///     .on_select(Message::Selected)
+///     .filter(|path| path.extension().is_some_and(|extension| extension == "png"));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct FileBrowser<'a, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The directory shown first.
+    directory: PathBuf,
+    /// The function producing the message when the selection changes.
+    on_select: Option<Box<dyn Fn(Vec<PathBuf>) -> Message + 'a>>,
+    /// The function producing the message when a file is opened.
+    on_open: Option<Box<dyn Fn(PathBuf) -> Message + 'a>>,
+    /// The function producing the message when another directory is shown.
+    on_navigate: Option<Box<dyn Fn(PathBuf) -> Message + 'a>>,
+    /// The function telling which files are shown.
+    filter: Option<Filter<'a>>,
+    /// Whether several entries can be selected.
+    multiple: bool,
+    /// Whether the hidden files are shown first.
+    show_hidden: bool,
+    width: Length,
+    height: Length,
+    row_height: f32,
+    text_size: f32,
+    font: Font,
+    /// The style of the [`FileBrowser`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`FileBrowser`].
+#[derive(Debug)]
+struct State {
+    /// The shown directory.
+    directory: PathBuf,
+    /// The directory last given by the application.
+    given: PathBuf,
+    /// The entries of the shown directory, directories first.
+    entries: Vec<Entry>,
+    /// The error met reading the shown directory.
+    error: Option<String>,
+    /// Whether the hidden files are shown.
+    show_hidden: bool,
+    /// The text the names of the entries are filtered by.
+    filter: String,
+    /// The selected entries.
+    selected: Vec<PathBuf>,
+    /// The entry a selection made with Shift extends from.
+    anchor: Option<PathBuf>,
+    /// The entry last clicked or moved to with the keyboard.
+    current: Option<PathBuf>,
+    /// The scroll offset of the entries.
+    offset: f32,
+    /// Whether the browser receives the typed text.
+    focused: bool,
+    /// The keyboard modifiers currently pressed.
+    modifiers: keyboard::Modifiers,
+    /// When and on which entry the last click happened.
+    last_click: Option<(Instant, PathBuf)>,
+}
+
+impl State {
+    /// Creates the state of a browser showing `directory`.
+    fn new(directory: PathBuf, show_hidden: bool) -> Self {
+        let mut state = Self {
+            given: directory.clone(),
+            directory,
+            entries: Vec::new(),
+            error: None,
+            show_hidden,
+            filter: String::new(),
+            selected: Vec::new(),
+            anchor: None,
+            current: None,
+            offset: 0.0,
+            focused: false,
+            modifiers: keyboard::Modifiers::default(),
+            last_click: None,
+        };
+        state.load();
+        state
+    }
+
+    /// Reads the entries of the shown directory.
+    fn load(&mut self) {
+        self.entries.clear();
+        self.error = None;
+
+        match fs::read_dir(&self.directory) {
+            Ok(read) => {
+                self.entries = read
+                    .filter_map(Result::ok)
+                    .map(|entry| {
+                        let path = entry.path();
+                        Entry {
+                            name: entry.file_name().to_string_lossy().into_owned(),
+                            is_dir: path.is_dir(),
+                            path,
+                        }
+                    })
+                    .collect();
+                self.entries.sort_by(|a, b| {
+                    b.is_dir
+                        .cmp(&a.is_dir)
+                        .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+                });
+            }
+            Err(error) => self.error = Some(error.to_string()),
+        }
+    }
+}
+
+impl<'a, Message, Theme> FileBrowser<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`FileBrowser`] showing the entries of the given directory.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+            on_select: None,
+            on_open: None,
+            on_navigate: None,
+            filter: None,
+            multiple: true,
+            show_hidden: false,
+            width: Length::Fill,
+            height: Length::Fixed(360.0),
+            row_height: settings::spaced(28.0),
+            text_size: settings::scaled(14.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message when the selection changes.
+    /// It receives the paths of the selected entries.
+    #[must_use]
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: 'a + Fn(Vec<PathBuf>) -> Message,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the function producing the message when a file is opened by
+    /// double clicking it or pressing Enter.
+    #[must_use]
+    pub fn on_open<F>(mut self, on_open: F) -> Self
+    where
+        F: 'a + Fn(PathBuf) -> Message,
+    {
+        self.on_open = Some(Box::new(on_open));
+        self
+    }
+
+    /// Sets the function producing the message when another directory is shown.
+    #[must_use]
+    pub fn on_navigate<F>(mut self, on_navigate: F) -> Self
+    where
+        F: 'a + Fn(PathBuf) -> Message,
+    {
+        self.on_navigate = Some(Box::new(on_navigate));
+        self
+    }
+
+    /// Sets the function telling which files are shown. Directories are
+    /// always shown.
+    #[must_use]
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: 'a + Fn(&Path) -> bool,
+    {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets whether several entries can be selected.
+    #[must_use]
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Sets whether the hidden files are shown first.
+    #[must_use]
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+
+    /// Sets the width of the [`FileBrowser`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`FileBrowser`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of the entries of the [`FileBrowser`].
+    #[must_use]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the text size of the [`FileBrowser`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`FileBrowser`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`FileBrowser`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the bounds of the path, of the filter and of the entries.
+    fn parts(bounds: Rectangle) -> (Rectangle, Rectangle, Rectangle) {
+        let path = Rectangle {
+            height: BAR_HEIGHT,
+            ..bounds
+        };
+        let filter = Rectangle {
+            y: path.y + path.height,
+            height: BAR_HEIGHT,
+            ..bounds
+        };
+        let list = Rectangle {
+            y: filter.y + filter.height,
+            height: (bounds.height - BAR_HEIGHT * 2.0).max(0.0),
+            ..bounds
+        };
+
+        (path, filter, list)
+    }
+
+    /// Returns the bounds of the button showing the hidden files.
+    fn toggle(path: Rectangle) -> Rectangle {
+        Rectangle {
+            x: path.x + path.width - TOGGLE_WIDTH - PADDING / 2.0,
+            y: path.y + 4.0,
+            width: TOGGLE_WIDTH,
+            height: path.height - 8.0,
+        }
+    }
+
+    /// Returns the text of `content` centered vertically in `bounds`.
+    fn text(content: &str, bounds: Rectangle, font: Font, size: f32) -> Text<'_, Font> {
+        Text {
+            content,
+            bounds: bounds.size(),
+            size: Pixels(size),
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+
+    /// Returns the directories of the path of `directory` fitting in `bar`,
+    /// dropping the outermost ones first.
+    fn crumbs<Renderer>(&self, directory: &Path, bar: Rectangle) -> Vec<Crumb>
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        let mut crumbs: Vec<Crumb> = directory
+            .ancestors()
+            .map(|path| Crumb {
+                name: path.file_name().map_or_else(
+                    || path.display().to_string(),
+                    |name| name.to_string_lossy().into_owned(),
+                ),
+                path: path.to_path_buf(),
+                bounds: Rectangle::default(),
+            })
+            .collect();
+        crumbs.reverse();
+
+        let widths: Vec<f32> = crumbs
+            .iter()
+            .map(|crumb| {
+                Renderer::Paragraph::with_text(Text {
+                    size: Pixels(self.text_size),
+                    bounds: Size::INFINITY,
+                    ..Self::text(&crumb.name, bar, self.font, self.text_size)
+                })
+                .min_width()
+                .ceil()
+            })
+            .collect();
+
+        let available = bar.width - PADDING * 2.0;
+        let mut skipped = 0;
+        while skipped + 1 < crumbs.len()
+            && widths[skipped..].iter().sum::<f32>()
+                + SEPARATOR_WIDTH * (crumbs.len() - skipped - 1) as f32
+                > available
+        {
+            skipped += 1;
+        }
+
+        let mut x = bar.x + PADDING;
+        crumbs
+            .into_iter()
+            .zip(widths)
+            .skip(skipped)
+            .map(|(crumb, width)| {
+                let bounds = Rectangle::new(Point::new(x, bar.y), Size::new(width, bar.height));
+                x += width + SEPARATOR_WIDTH;
+                Crumb { bounds, ..crumb }
+            })
+            .collect()
+    }
+
+    /// Returns the indices of the entries shown with the filters.
+    fn visible(&self, state: &State) -> Vec<usize> {
+        let filter = state.filter.to_lowercase();
+
+        state
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| {
+                (state.show_hidden || !entry.is_hidden())
+                    && (filter.is_empty() || entry.name.to_lowercase().contains(&filter))
+                    && (entry.is_dir || self.filter.as_ref().map_or(true, |f| f(&entry.path)))
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Returns the largest scroll offset of `count` entries in `list`.
+    fn max_offset(&self, count: usize, list: Rectangle) -> f32 {
+        (self.row_height * count as f32 - list.height).max(0.0)
+    }
+
+    /// Shows the entries of `directory`.
+    fn navigate(&self, state: &mut State, directory: PathBuf, shell: &mut Shell<'_, Message>) {
+        state.directory = directory;
+        state.filter.clear();
+        state.offset = 0.0;
+        state.anchor = None;
+        state.current = None;
+        state.last_click = None;
+        state.load();
+
+        if !state.selected.is_empty() {
+            state.selected.clear();
+            self.publish_selection(state, shell);
+        }
+
+        if let Some(on_navigate) = &self.on_navigate {
+            shell.publish(on_navigate(state.directory.clone()));
+        }
+    }
+
+    /// Publishes the selected entries.
+    fn publish_selection(&self, state: &State, shell: &mut Shell<'_, Message>) {
+        if let Some(on_select) = &self.on_select {
+            shell.publish(on_select(state.selected.clone()));
+        }
+    }
+
+    /// Selects the entry at `position` of the `visible` entries, extending or
+    /// toggling the selection with the pressed modifiers.
+    fn select(
+        &self,
+        state: &mut State,
+        visible: &[usize],
+        position: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let path = state.entries[visible[position]].path.clone();
+        state.current = Some(path.clone());
+        let anchor = state
+            .anchor
+            .as_ref()
+            .and_then(|anchor| {
+                visible
+                    .iter()
+                    .position(|&index| &state.entries[index].path == anchor)
+            })
+            .filter(|_| self.multiple && state.modifiers.shift());
+
+        if let Some(anchor) = anchor {
+            let (from, to) = (anchor.min(position), anchor.max(position));
+            state.selected = visible[from..=to]
+                .iter()
+                .map(|&index| state.entries[index].path.clone())
+                .collect();
+        } else if self.multiple && state.modifiers.command() {
+            if let Some(selected) = state.selected.iter().position(|selected| selected == &path) {
+                let _ = state.selected.remove(selected);
+            } else {
+                state.selected.push(path.clone());
+            }
+            state.anchor = Some(path);
+        } else {
+            state.selected = vec![path.clone()];
+            state.anchor = Some(path);
+        }
+
+        self.publish_selection(state, shell);
+    }
+
+    /// Opens the directory or the file at `index` of the entries.
+    fn open(&self, state: &mut State, index: usize, shell: &mut Shell<'_, Message>) {
+        let entry = &state.entries[index];
+
+        if entry.is_dir {
+            let path = entry.path.clone();
+            self.navigate(state, path, shell);
+        } else if let Some(on_open) = &self.on_open {
+            shell.publish(on_open(entry.path.clone()));
+        }
+    }
+
+    /// Scrolls the entries so that the entry at `position` is visible.
+    fn scroll_to(&self, state: &mut State, position: usize, list: Rectangle) {
+        let top = self.row_height * position as f32;
+
+        if top < state.offset {
+            state.offset = top;
+        } else if top + self.row_height > state.offset + list.height {
+            state.offset = top + self.row_height - list.height;
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for FileBrowser<'_, Message, Theme>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.directory.clone(), self.show_hidden))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // A directory given by the application replaces the one opened by the user.
+        if self.directory != state.given {
+            *state = State::new(self.directory.clone(), self.show_hidden);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::new(0.0, BAR_HEIGHT * 2.0 + self.row_height * 8.0),
+        ))
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let (path, _filter, list) = Self::parts(bounds);
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+                event::Status::Ignored
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.focused = false;
+                    return event::Status::Ignored;
+                };
+                state.focused = true;
+
+                if Self::toggle(path).contains(position) {
+                    state.show_hidden = !state.show_hidden;
+                    state.offset = 0.0;
+                    return event::Status::Captured;
+                }
+
+                if let Some(crumb) = self
+                    .crumbs::<Renderer>(&state.directory, path)
+                    .into_iter()
+                    .find(|crumb| crumb.bounds.contains(position))
+                {
+                    if crumb.path != state.directory {
+                        self.navigate(state, crumb.path, shell);
+                    }
+                    return event::Status::Captured;
+                }
+
+                if list.contains(position) {
+                    let visible = self.visible(state);
+                    let row = ((position.y - list.y + state.offset) / self.row_height) as usize;
+
+                    if let Some(&index) = visible.get(row) {
+                        let now = Instant::now();
+                        let entry = state.entries[index].path.clone();
+                        let double = state.last_click.as_ref().is_some_and(|(at, path)| {
+                            now.duration_since(*at) < DOUBLE_CLICK && path == &entry
+                        });
+
+                        if double {
+                            state.last_click = None;
+                            self.open(state, index, shell);
+                        } else {
+                            state.last_click = Some((now, entry));
+                            self.select(state, &visible, row, shell);
+                        }
+                    }
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(list) => {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * self.row_height,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                let count = self.visible(state).len();
+
+                state.offset = (state.offset - delta).clamp(0.0, self.max_offset(count, list));
+                event::Status::Captured
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) if state.focused => {
+                let visible = self.visible(state);
+                let current = state.current.as_ref().and_then(|current| {
+                    visible
+                        .iter()
+                        .position(|&index| &state.entries[index].path == current)
+                });
+                let end = visible.len().saturating_sub(1);
+                let mut move_to = |state: &mut State, position: usize| {
+                    if !visible.is_empty() {
+                        self.select(state, &visible, position, shell);
+                        self.scroll_to(state, position, list);
+                    }
+                };
+
+                match key.as_ref() {
+                    keyboard::Key::Named(Named::ArrowDown) => {
+                        move_to(state, current.map_or(0, |current| (current + 1).min(end)));
+                    }
+                    keyboard::Key::Named(Named::ArrowUp) => {
+                        move_to(
+                            state,
+                            current.map_or(0, |current| current.saturating_sub(1)),
+                        );
+                    }
+                    keyboard::Key::Named(Named::Home) => move_to(state, 0),
+                    keyboard::Key::Named(Named::End) => move_to(state, end),
+                    keyboard::Key::Named(Named::Enter) => {
+                        if let Some(&index) = current.and_then(|current| visible.get(current)) {
+                            self.open(state, index, shell);
+                        }
+                    }
+                    keyboard::Key::Named(Named::Backspace) => {
+                        if state.filter.pop().is_none() {
+                            if let Some(parent) = state.directory.parent().map(Path::to_path_buf) {
+                                self.navigate(state, parent, shell);
+                            }
+                        }
+                        state.offset = 0.0;
+                    }
+                    keyboard::Key::Named(Named::Escape) => {
+                        state.filter.clear();
+                        state.offset = 0.0;
+                    }
+                    keyboard::Key::Character("a") if modifiers.command() && self.multiple => {
+                        state.selected = visible
+                            .iter()
+                            .map(|&index| state.entries[index].path.clone())
+                            .collect();
+                        self.publish_selection(state, shell);
+                    }
+                    _ => match text {
+                        Some(text) if !modifiers.command() => {
+                            state
+                                .filter
+                                .extend(text.chars().filter(|c| !c.is_control()));
+                            state.offset = 0.0;
+                        }
+                        _ => return event::Status::Ignored,
+                    },
+                }
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return mouse::Interaction::default();
+        };
+
+        let state = tree.state.downcast_ref::<State>();
+        let (path, _filter, list) = Self::parts(layout.bounds());
+        let row = ((position.y - list.y + state.offset) / self.row_height) as usize;
+
+        if Self::toggle(path).contains(position)
+            || self
+                .crumbs::<Renderer>(&state.directory, path)
+                .iter()
+                .any(|crumb| crumb.bounds.contains(position))
+            || (list.contains(position) && row < self.visible(state).len())
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = if state.focused {
+            theme.focused(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+        let (path, filter, list) = Self::parts(bounds);
+        let quad = |bounds: Rectangle, radius: f32| Quad {
+            bounds,
+            border: Border::with_radius(radius),
+            shadow: Shadow::default(),
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: appearance.border_radius.into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            // The path of the directory, with the button showing the hidden files.
+            let toolbar = Rectangle {
+                height: BAR_HEIGHT * 2.0,
+                ..bounds
+            };
+            renderer.fill_quad(quad(toolbar, 0.0), appearance.toolbar_background);
+
+            let toggle = Self::toggle(path);
+            if state.show_hidden {
+                renderer.fill_quad(quad(toggle, 4.0), appearance.toggle_background);
+            }
+            renderer.fill_text(
+                Text {
+                    horizontal_alignment: Horizontal::Center,
+                    ..Self::text("Hidden", toggle, self.font, self.text_size - 2.0)
+                },
+                toggle.center(),
+                appearance.text_color,
+                toggle,
+            );
+
+            let crumbs_bar = Rectangle {
+                width: (toggle.x - path.x).max(0.0),
+                ..path
+            };
+            let crumbs = self.crumbs::<Renderer>(&state.directory, crumbs_bar);
+            for (i, crumb) in crumbs.iter().enumerate() {
+                renderer.fill_text(
+                    Self::text(&crumb.name, crumb.bounds, self.font, self.text_size),
+                    Point::new(crumb.bounds.x, crumb.bounds.center_y()),
+                    appearance.text_color,
+                    crumbs_bar,
+                );
+
+                if i + 1 < crumbs.len() {
+                    let separator = Rectangle::new(
+                        Point::new(crumb.bounds.x + crumb.bounds.width, path.y),
+                        Size::new(SEPARATOR_WIDTH, path.height),
+                    );
+                    renderer.fill_text(
+                        Text {
+                            horizontal_alignment: Horizontal::Center,
+                            ..Self::text(
+                                &icon_to_string(Bootstrap::CaretRightFill),
+                                separator,
+                                BOOTSTRAP_FONT,
+                                self.text_size - 4.0,
+                            )
+                        },
+                        separator.center(),
+                        appearance.muted_text_color,
+                        crumbs_bar,
+                    );
+                }
+            }
+
+            // The filter, or a hint to type one.
+            let filter_text = Rectangle {
+                x: filter.x + PADDING,
+                width: (filter.width - PADDING * 2.0).max(0.0),
+                ..filter
+            };
+            let (content, color) = if state.filter.is_empty() {
+                ("Type to filter", appearance.muted_text_color)
+            } else {
+                (state.filter.as_str(), appearance.text_color)
+            };
+            renderer.fill_text(
+                Self::text(content, filter_text, self.font, self.text_size),
+                Point::new(filter_text.x, filter_text.center_y()),
+                color,
+                filter_text,
+            );
+
+            // The entries.
+            let visible = self.visible(state);
+
+            if let Some(error) = &state.error {
+                let message = Rectangle {
+                    x: list.x + PADDING,
+                    height: self.row_height,
+                    width: (list.width - PADDING * 2.0).max(0.0),
+                    ..list
+                };
+                renderer.fill_text(
+                    Self::text(error, message, self.font, self.text_size),
+                    Point::new(message.x, message.center_y()),
+                    appearance.muted_text_color,
+                    message,
+                );
+            }
+
+            renderer.with_layer(list, |renderer| {
+                let first = (state.offset / self.row_height) as usize;
+                let count = (list.height / self.row_height).ceil() as usize + 1;
+
+                for (row, &index) in visible.iter().enumerate().skip(first).take(count) {
+                    let entry = &state.entries[index];
+                    let bounds = Rectangle::new(
+                        Point::new(list.x, list.y + self.row_height * row as f32 - state.offset),
+                        Size::new(list.width, self.row_height),
+                    );
+                    let selected = state.selected.contains(&entry.path);
+
+                    if selected {
+                        renderer.fill_quad(quad(bounds, 0.0), appearance.selected_background);
+                    } else if cursor.is_over(bounds) && cursor.is_over(list) {
+                        renderer.fill_quad(quad(bounds, 0.0), appearance.hovered_background);
+                    }
+
+                    let marker = Rectangle {
+                        x: bounds.x + PADDING,
+                        width: self.text_size,
+                        ..bounds
+                    };
+                    if entry.is_dir {
+                        renderer.fill_text(
+                            Text {
+                                horizontal_alignment: Horizontal::Center,
+                                ..Self::text(
+                                    &icon_to_string(Bootstrap::CaretRightFill),
+                                    marker,
+                                    BOOTSTRAP_FONT,
+                                    self.text_size - 2.0,
+                                )
+                            },
+                            marker.center(),
+                            if selected {
+                                appearance.selected_text_color
+                            } else {
+                                appearance.directory_color
+                            },
+                            marker,
+                        );
+                    }
+
+                    let name = Rectangle {
+                        x: marker.x + marker.width + PADDING / 2.0,
+                        width: (bounds.width - marker.width - PADDING * 2.5).max(0.0),
+                        ..bounds
+                    };
+                    renderer.fill_text(
+                        Self::text(&entry.name, name, self.font, self.text_size),
+                        Point::new(name.x, name.center_y()),
+                        if selected {
+                            appearance.selected_text_color
+                        } else if entry.is_hidden() {
+                            appearance.muted_text_color
+                        } else {
+                            appearance.text_color
+                        },
+                        name,
+                    );
+                }
+
+                let content_height = self.row_height * visible.len() as f32;
+                if content_height > list.height {
+                    let height = (list.height * list.height / content_height).max(16.0);
+                    let y = list.y
+                        + (list.height - height) * state.offset
+                            / self.max_offset(visible.len(), list).max(1.0);
+
+                    renderer.fill_quad(
+                        quad(
+                            Rectangle::new(
+                                Point::new(list.x + list.width - SCROLLBAR_WIDTH - 2.0, y),
+                                Size::new(SCROLLBAR_WIDTH, height),
+                            ),
+                            SCROLLBAR_WIDTH / 2.0,
+                        ),
+                        appearance.scrollbar_color,
+                    );
+                }
+            });
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FileBrowser<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(browser: FileBrowser<'a, Message, Theme>) -> Self {
+        Element::new(browser)
+    }
+}
@@ -15,7 +15,7 @@ use super::types::GridRow;
 
 #[allow(clippy::too_many_arguments)]
 pub(super) fn layout<Message, Theme, Renderer>(
-    tree: &mut Tree,
+    trees: &mut [Tree],
     renderer: &Renderer,
     limits: &Limits,
     column_count: usize,
@@ -40,11 +40,12 @@ where
     let grid_limit = limits.shrink(padding);
     // Measure the minimum row and column size to fit the contents
     minimum_row_column_sizes(
-        tree,
+        trees,
         renderer,
         &mut column_widths,
         &mut row_heights,
         rows,
+        column_spacing,
         grid_limit.max(),
     );
 
@@ -76,7 +77,7 @@ where
 
     // Lay out the widgets
     create_grid_layout(
-        tree,
+        trees,
         element_count,
         rows,
         &row_heights,
@@ -92,21 +93,26 @@ where
 }
 
 fn minimum_row_column_sizes<Message, Theme, Renderer>(
-    tree: &mut Tree,
+    trees: &mut [Tree],
     renderer: &Renderer,
     column_widths: &mut Vec<f32>,
     row_heights: &mut Vec<f32>,
     rows: &[GridRow<'_, Message, Theme, Renderer>],
+    column_spacing: Pixels,
     max_allowed_size: Size<f32>,
 ) where
     Renderer: renderer::Renderer,
 {
-    let mut children = tree.children.iter_mut();
+    let mut children = trees.iter_mut();
+    // The first column, span and width of the elements spanning several columns
+    let mut spanning = Vec::new();
+
     for row in rows {
         let mut row_height = 0.0f32;
         let mut min_width = 0.0f32;
+        let mut col_idx = 0;
 
-        for (col_idx, element) in row.elements.iter().enumerate() {
+        for (element, &span) in row.elements.iter().zip(&row.spans) {
             let child_limits = Limits::NONE.width(Length::Shrink).height(Length::Shrink);
             let Size { width, height } = element
                 .as_widget()
@@ -117,24 +123,47 @@ fn minimum_row_column_sizes<Message, Theme, Renderer>(
                 )
                 .size();
 
+            row_height = row_height.max(height);
+
+            if column_widths.len() < col_idx + span {
+                column_widths.resize(col_idx + span, 0.0);
+            }
+
+            if span > 1 {
+                spanning.push((col_idx, span, width));
+                col_idx += span;
+                continue;
+            }
+
             min_width = if width == f32::INFINITY {
                 min_width
             } else {
                 min_width.max(width)
             };
 
-            #[allow(clippy::option_if_let_else)]
-            match column_widths.get_mut(col_idx) {
-                Some(col_width) => {
-                    *col_width = max_allowed_size.width.min(col_width.max(min_width));
-                }
-                None => column_widths.insert(col_idx, max_allowed_size.width.min(min_width)),
-            }
-
-            row_height = row_height.max(height);
+            column_widths[col_idx] = max_allowed_size
+                .width
+                .min(column_widths[col_idx].max(min_width));
+            col_idx += 1;
         }
         row_heights.push(row_height);
     }
+
+    // Widen the last spanned column when the spanned columns are too narrow
+    for (col_idx, span, width) in spanning {
+        if width == f32::INFINITY {
+            continue;
+        }
+
+        let columns = &mut column_widths[col_idx..col_idx + span];
+        let spanned_width = total_length(columns, column_spacing);
+
+        if let Some(last) = columns.last_mut() {
+            *last = max_allowed_size
+                .width
+                .min(*last + (width - spanned_width).max(0.0));
+        }
+    }
 }
 
 fn adjust_size_for_fixed_length(sizes: &mut [f32], length_settings: &[Length]) {
@@ -194,7 +223,7 @@ fn allocate_space(current_sizes: &mut [f32], length_settings: &[Length], availab
 
 #[allow(clippy::too_many_arguments)]
 fn create_grid_layout<Message, Theme, Renderer>(
-    tree: &mut Tree,
+    trees: &mut [Tree],
     element_count: usize,
     rows: &[GridRow<'_, Message, Theme, Renderer>],
     row_heights: &[f32],
@@ -212,13 +241,16 @@ where
 {
     let mut y = padding.top;
     let mut nodes = Vec::with_capacity(element_count);
-    let mut children = tree.children.iter_mut();
+    let mut children = trees.iter_mut();
 
     for (row_position, (row, &row_height)) in rows.iter().zip(row_heights).with_position() {
         let mut x = padding.left;
-        for (col_position, (element, &column_width)) in
-            row.elements.iter().zip(column_widths).with_position()
-        {
+        let mut col_idx = 0;
+
+        for (element, &span) in row.elements.iter().zip(&row.spans) {
+            let columns = &column_widths
+                [col_idx.min(column_widths.len())..(col_idx + span).min(column_widths.len())];
+            let column_width = total_length(columns, column_spacing);
             let widget = element.as_widget();
             let widget_size = widget.size();
             let widget_limits = Limits::NONE
@@ -241,10 +273,8 @@ where
                 );
             nodes.push(node);
 
-            x += column_width;
-            if not_last(col_position) {
-                x += column_spacing.0;
-            }
+            x += column_width + column_spacing.0;
+            col_idx += span;
         }
         y += row_height;
         if not_last(row_position) {
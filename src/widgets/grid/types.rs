@@ -187,7 +187,7 @@ where
     pub(super) fn column_count(&self) -> usize {
         self.rows
             .iter()
-            .map(|row| row.elements.len())
+            .map(|row| row.spans.iter().sum())
             .max()
             .unwrap_or(0)
     }
@@ -205,6 +205,8 @@ where
 #[allow(missing_debug_implementations)]
 pub struct GridRow<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
     pub(crate) elements: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// The number of columns spanned by each element.
+    pub(crate) spans: Vec<usize>,
 }
 
 impl<'a, Message, Theme, Renderer> Default for GridRow<'a, Message, Theme, Renderer>
@@ -214,6 +216,7 @@ where
     fn default() -> Self {
         Self {
             elements: Vec::new(),
+            spans: Vec::new(),
         }
     }
 }
@@ -232,17 +235,31 @@ where
     #[must_use]
     pub fn with_elements(children: Vec<impl Into<Element<'a, Message, Theme, Renderer>>>) -> Self {
         Self {
+            spans: vec![1; children.len()],
             elements: children.into_iter().map(std::convert::Into::into).collect(),
         }
     }
 
     /// Adds a widget to the [`GridRow`].
     #[must_use]
-    pub fn push<E>(mut self, element: E) -> Self
+    pub fn push<E>(self, element: E) -> Self
+    where
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        self.push_span(element, 1)
+    }
+
+    /// Adds a widget spanning the given number of columns to the
+    /// [`GridRow`].
+    ///
+    /// A span of zero is treated as a span of one.
+    #[must_use]
+    pub fn push_span<E>(mut self, element: E, span: usize) -> Self
     where
         E: Into<Element<'a, Message, Theme, Renderer>>,
     {
         self.elements.push(element.into());
+        self.spans.push(span.max(1));
         self
     }
 }
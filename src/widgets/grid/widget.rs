@@ -4,19 +4,31 @@ use iced::{
         overlay::Group,
         renderer,
         renderer::Style,
-        widget::{Operation, Tree},
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
         Clipboard, Layout, Shell, Widget,
     },
     event, mouse, overlay, Element, Event, Length, Rectangle, Size, Vector,
 };
 
 use super::{layout::layout, types::Grid};
+use crate::core::layout_cache::LayoutCache;
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Grid<'a, Message, Theme, Renderer>
 where
     Renderer: renderer::Renderer,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<LayoutCache>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(LayoutCache::default())
+    }
+
     fn size(&self) -> Size<Length> {
         Size {
             width: self.width,
@@ -38,24 +50,33 @@ where
             "At least one row height is required"
         );
 
-        layout(
-            tree,
-            renderer,
-            limits,
-            self.column_count(),
-            self.row_count(),
-            self.element_count(),
-            &self.rows,
-            self.column_spacing,
-            self.row_spacing,
-            self.padding,
-            self.horizontal_alignment,
-            self.vertical_alignment,
-            self.width,
-            self.height,
-            &self.column_widths,
-            &self.row_heights,
-        )
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        state
+            .downcast_mut::<LayoutCache>()
+            .get_or_layout(limits, || {
+                layout(
+                    children,
+                    renderer,
+                    limits,
+                    self.column_count(),
+                    self.row_count(),
+                    self.element_count(),
+                    &self.rows,
+                    self.column_spacing,
+                    self.row_spacing,
+                    self.padding,
+                    self.horizontal_alignment,
+                    self.vertical_alignment,
+                    self.width,
+                    self.height,
+                    &self.column_widths,
+                    &self.row_heights,
+                )
+            })
+            .clone()
     }
 
     fn draw(
@@ -72,6 +93,9 @@ where
             .elements_iter()
             .zip(&state.children)
             .zip(layout.children())
+            // The children outside of the viewport, like the ones scrolled
+            // out of a scrollable, are not drawn.
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
         {
             element
                 .as_widget()
@@ -84,6 +108,7 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<LayoutCache>().invalidate();
         tree.diff_children(&self.elements_iter().collect::<Vec<_>>());
     }
 
@@ -133,7 +158,13 @@ where
                 )
             });
 
-        children_status.fold(event::Status::Ignored, event::Status::merge)
+        let status = children_status.fold(event::Status::Ignored, event::Status::merge);
+
+        if shell.is_layout_invalid() {
+            state.state.downcast_mut::<LayoutCache>().invalidate();
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -147,6 +178,7 @@ where
         self.elements_iter()
             .zip(&state.children)
             .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
             .map(|((e, state), layout)| {
                 e.as_widget()
                     .mouse_interaction(state, layout, cursor, viewport, renderer)
@@ -0,0 +1,529 @@
+//! Show a value per day of a year as colored cells, like an activity graph.
+//!
+//! *This API requires the following crate features to be activated: `heatmap_calendar`*
+
+use crate::core::{date::WEEKDAY_LABELS, settings};
+use crate::widgets::overlay::HeatmapCalendarOverlay;
+
+use chrono::{Datelike, Duration, NaiveDate};
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::collections::BTreeMap;
+
+pub use crate::{
+    core::date::Date,
+    style::heatmap_calendar::{Appearance, StyleSheet},
+};
+
+/// The number of levels of the values, the first one being for the days
+/// without a value.
+const LEVELS: usize = 5;
+/// The weekdays having a label, counted from Monday.
+const LABELED_WEEKDAYS: [usize; 3] = [0, 2, 4];
+
+/// A calendar showing the days of a year as cells colored by the level of
+/// their value, with a tooltip over the hovered day.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::heatmap_calendar::{Date, HeatmapCalendar};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     DayPressed(Date),
+/// }
+///
+/// let day = Date {
+///     year: 2024,
+///     month: 3,
+///     day: 14,
+/// };
+/// let values = vec![(day, 4.0)];
+///
+/// let calendar = HeatmapCalendar::new(2024, values).on_day_press(Message::DayPressed);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct HeatmapCalendar<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The shown year.
+    year: i32,
+    /// The values of the days.
+    values: BTreeMap<NaiveDate, f32>,
+    /// The value of the highest level, or `None` for the highest value.
+    max_value: Option<f32>,
+    /// The function producing the message when a day is pressed.
+    on_day_press: Option<Box<dyn Fn(Date) -> Message + 'a>>,
+    /// The function producing the text of the tooltip of a day.
+    tooltip: Option<Box<dyn Fn(Date, f32) -> String + 'a>>,
+    cell_size: f32,
+    spacing: f32,
+    text_size: f32,
+    font: Font,
+    /// The style of the [`HeatmapCalendar`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`HeatmapCalendar`].
+#[derive(Debug, Default)]
+struct State {
+    /// The day under the cursor.
+    hovered: Option<NaiveDate>,
+    /// The day on which the cursor was pressed.
+    pressed: Option<NaiveDate>,
+}
+
+impl<'a, Message, Theme> HeatmapCalendar<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`HeatmapCalendar`] showing the given year, with the
+    /// given values of its days. The values of the same day are summed.
+    #[must_use]
+    pub fn new(year: i32, values: impl IntoIterator<Item = (Date, f32)>) -> Self {
+        let mut days = BTreeMap::new();
+        for (date, value) in values {
+            *days.entry(NaiveDate::from(date)).or_insert(0.0) += value;
+        }
+
+        Self {
+            year,
+            values: days,
+            max_value: None,
+            on_day_press: None,
+            tooltip: None,
+            cell_size: 12.0,
+            spacing: settings::spaced(3.0),
+            text_size: settings::scaled(10.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the value of the highest level. By default, it is the highest
+    /// value of the shown year.
+    #[must_use]
+    pub fn max_value(mut self, max_value: f32) -> Self {
+        self.max_value = Some(max_value);
+        self
+    }
+
+    /// Sets the function producing the message when a day is pressed.
+    #[must_use]
+    pub fn on_day_press<F>(mut self, on_day_press: F) -> Self
+    where
+        F: 'a + Fn(Date) -> Message,
+    {
+        self.on_day_press = Some(Box::new(on_day_press));
+        self
+    }
+
+    /// Sets the function producing the text of the tooltip of a day from its
+    /// date and value.
+    #[must_use]
+    pub fn tooltip<F>(mut self, tooltip: F) -> Self
+    where
+        F: 'a + Fn(Date, f32) -> String,
+    {
+        self.tooltip = Some(Box::new(tooltip));
+        self
+    }
+
+    /// Sets the size of the side of a day.
+    #[must_use]
+    pub fn cell_size(mut self, cell_size: f32) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Sets the space between the days.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the labels and of the tooltip.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the labels and of the tooltip.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`HeatmapCalendar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the first and the last day of the shown year.
+    fn year_bounds(&self) -> (NaiveDate, NaiveDate) {
+        let first = NaiveDate::from_ymd_opt(self.year, 1, 1).unwrap_or_default();
+        let last = NaiveDate::from_ymd_opt(self.year, 12, 31).unwrap_or(first);
+
+        (first, last)
+    }
+
+    /// Returns the first day of the grid, the Monday of the first week of the year.
+    fn first_shown(&self) -> NaiveDate {
+        let (first, _last) = self.year_bounds();
+        first - Duration::days(i64::from(first.weekday().num_days_from_monday()))
+    }
+
+    /// Returns the number of weeks of the grid.
+    fn weeks(&self) -> usize {
+        let (_first, last) = self.year_bounds();
+        (last - self.first_shown()).num_days() as usize / 7 + 1
+    }
+
+    /// Returns the height of a line of the labels.
+    fn label_height(&self) -> f32 {
+        LineHeight::default()
+            .to_absolute(Pixels(self.text_size))
+            .0
+            .round()
+    }
+
+    /// Returns the width of the labels of the weekdays.
+    fn label_width(&self) -> f32 {
+        (self.text_size * 2.0).round()
+    }
+
+    /// Returns the origin of the grid of the days.
+    fn grid_origin(&self, bounds: Rectangle) -> Point {
+        Point::new(
+            bounds.x + self.label_width() + self.spacing,
+            bounds.y + self.label_height() + self.spacing,
+        )
+    }
+
+    /// Returns the bounds of the given day.
+    fn cell(&self, bounds: Rectangle, date: NaiveDate) -> Rectangle {
+        let origin = self.grid_origin(bounds);
+        let offset = (date - self.first_shown()).num_days() as f32;
+        let step = self.cell_size + self.spacing;
+
+        Rectangle::new(
+            Point::new(
+                origin.x + (offset / 7.0).floor() * step,
+                origin.y + (offset % 7.0) * step,
+            ),
+            Size::new(self.cell_size, self.cell_size),
+        )
+    }
+
+    /// Returns the day of the shown year under `position`.
+    fn day_at(&self, bounds: Rectangle, position: Point) -> Option<NaiveDate> {
+        let origin = self.grid_origin(bounds);
+        let step = self.cell_size + self.spacing;
+        let x = position.x - origin.x;
+        let y = position.y - origin.y;
+
+        if x < 0.0 || y < 0.0 || x % step > self.cell_size || y % step > self.cell_size {
+            return None;
+        }
+
+        let week = (x / step) as i64;
+        let weekday = (y / step) as i64;
+        if weekday >= 7 {
+            return None;
+        }
+
+        let date = self.first_shown() + Duration::days(week * 7 + weekday);
+        let (first, last) = self.year_bounds();
+
+        (first..=last).contains(&date).then_some(date)
+    }
+
+    /// Returns the level of the value of the given day.
+    fn level(&self, date: NaiveDate, max: f32) -> usize {
+        match self.values.get(&date) {
+            Some(&value) if value > 0.0 && max > 0.0 => {
+                ((value / max * (LEVELS - 1) as f32).ceil() as usize).clamp(1, LEVELS - 1)
+            }
+            _ => 0,
+        }
+    }
+
+    /// Returns the value of the highest level.
+    fn max(&self) -> f32 {
+        self.max_value.unwrap_or_else(|| {
+            let (first, last) = self.year_bounds();
+            self.values
+                .range(first..=last)
+                .map(|(_date, &value)| value)
+                .fold(0.0, f32::max)
+        })
+    }
+
+    /// Returns the text of the tooltip of the given day.
+    fn tooltip_text(&self, date: NaiveDate) -> String {
+        let value = self.values.get(&date).copied().unwrap_or(0.0);
+
+        self.tooltip.as_ref().map_or_else(
+            || format!("{value} on {}", date.format("%b %-d, %Y")),
+            |tooltip| tooltip(date.into(), value),
+        )
+    }
+
+    /// Returns a label of the calendar.
+    fn text<'c>(&self, content: &'c str, bounds: Rectangle) -> Text<'c, Font> {
+        Text {
+            content,
+            bounds: bounds.size(),
+            size: Pixels(self.text_size),
+            line_height: LineHeight::default(),
+            font: self.font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for HeatmapCalendar<'_, Message, Theme>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let step = self.cell_size + self.spacing;
+
+        Node::new(limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(
+                self.label_width() + step * self.weeks() as f32,
+                self.label_height() + step * 7.0,
+            ),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let day = cursor
+            .position_over(bounds)
+            .and_then(|position| self.day_at(bounds, position));
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                state.hovered = day;
+                event::Status::Ignored
+            }
+
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                state.hovered = None;
+                event::Status::Ignored
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.pressed = day;
+                if day.is_some() && self.on_day_press.is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let pressed = state.pressed.take();
+
+                match (&self.on_day_press, day) {
+                    (Some(on_day_press), Some(day)) if pressed == Some(day) => {
+                        shell.publish(on_day_press(day.into()));
+                        event::Status::Captured
+                    }
+                    _ => event::Status::Ignored,
+                }
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let over_day = cursor
+            .position_over(bounds)
+            .and_then(|position| self.day_at(bounds, position))
+            .is_some();
+
+        if over_day && self.on_day_press.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let origin = self.grid_origin(bounds);
+        let step = self.cell_size + self.spacing;
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        // The labels of the months above their first week.
+        let (first, last) = self.year_bounds();
+        for month in 1..=12 {
+            let Some(date) = NaiveDate::from_ymd_opt(self.year, month, 1) else {
+                continue;
+            };
+            let x = self.cell(bounds, date).x;
+            let label = Rectangle::new(
+                Point::new(x, bounds.y),
+                Size::new(step * 4.0, self.label_height()),
+            );
+
+            renderer.fill_text(
+                self.text(&date.format("%b").to_string(), label),
+                Point::new(label.x, label.center_y()),
+                appearance.label_color,
+                label,
+            );
+        }
+
+        // The labels of some weekdays in front of their row.
+        for weekday in LABELED_WEEKDAYS {
+            let label = Rectangle::new(
+                Point::new(bounds.x, origin.y + step * weekday as f32),
+                Size::new(self.label_width(), self.cell_size),
+            );
+
+            renderer.fill_text(
+                self.text(&WEEKDAY_LABELS[weekday], label),
+                Point::new(label.x, label.center_y()),
+                appearance.label_color,
+                label,
+            );
+        }
+
+        // The days, colored by the level of their value.
+        let max = self.max();
+        let mut date = first;
+        while date <= last {
+            let hovered = state.hovered == Some(date);
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: self.cell(bounds, date),
+                    border: Border {
+                        radius: appearance.cell_border_radius.into(),
+                        width: if hovered { 1.0 } else { 0.0 },
+                        color: appearance.hovered_border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.level_colors[self.level(date, max)],
+            );
+
+            date += Duration::days(1);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let hovered = tree.state.downcast_ref::<State>().hovered?;
+
+        Some(overlay::Element::new(Box::new(
+            HeatmapCalendarOverlay::new(
+                self.tooltip_text(hovered),
+                self.cell(layout.bounds(), hovered) + translation,
+                self.text_size,
+                self.font,
+                &self.style,
+            ),
+        )))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<HeatmapCalendar<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(heatmap_calendar: HeatmapCalendar<'a, Message, Theme>) -> Self {
+        Element::new(heatmap_calendar)
+    }
+}
@@ -2,12 +2,40 @@
 //!
 //!
 
+#[cfg(feature = "toast")]
+pub mod toast;
+
 #[allow(unused_imports)]
 use iced::{self, advanced::renderer, Color, Element};
 #[allow(unused_imports)]
 use std::{borrow::Cow, fmt::Display, hash::Hash};
 
-/// Creates a [`Grid`] with the given [`GridRow`]s.
+/// Creates a [`Grid`] with the given [`GridRow`]s, or with the given rows
+/// of widgets.
+///
+/// Syntax:
+/// ```ignore
+/// grid!(row, row, ...)
+///
+/// grid![
+///     widget, widget, ...;
+///     widget => span, widget;
+///     ...
+/// ]
+/// ```
+///
+/// The widgets of a row are separated by `,` and the rows by `;`. A widget
+/// followed by `=> span` spans that many columns. A grid of a single row of
+/// widgets without spans needs a trailing `;`, without it the widgets are
+/// taken as [`GridRow`]s.
+///
+/// ```ignore
+/// let form = grid![
+///     text("Name"), text_input("", &name);
+///     text("Email"), text_input("", &email);
+///     button("Submit") => 2;
+/// ];
+/// ```
 ///
 /// [`Grid`]: crate::Grid
 /// [`GridRow`]: crate::GridRow
@@ -20,10 +48,18 @@ macro_rules! grid {
     ($($x:expr),+ $(,)?) => (
         $crate::Grid::with_rows(vec![$($x),+])
     );
+    ($($($x:expr $(=> $span:expr)?),+ $(,)?);+ $(;)?) => (
+        $crate::Grid::with_rows(vec![$( $crate::grid_row!($($x $(=> $span)?),+) ),+])
+    );
 }
 
 /// Creates a [`GridRow`] with the given widgets.
 ///
+/// A widget followed by `=> span` spans that many columns:
+/// ```ignore
+/// grid_row!(text("Title") => 2, button("Close"))
+/// ```
+///
 /// [`GridRow`]: crate::GridRow
 #[cfg(feature = "grid")]
 #[macro_export]
@@ -34,6 +70,9 @@ macro_rules! grid_row {
     ($($x:expr),+ $(,)?) => (
         $crate::GridRow::with_elements(vec![$(iced::Element::from($x)),+])
     );
+    ($($x:expr $(=> $span:expr)?),+ $(,)?) => (
+        $crate::GridRow::new() $( .push_span($x, { let span = 1; $(let span = $span;)? span }) )+
+    );
 }
 
 /// Creates a horizontal [`Wrap`] with the given children.
@@ -72,33 +111,59 @@ macro_rules! wrap_vertical {
 /// ```
 /// menu_items!(
 ///     (widget)
-///     (widget)
-///     (widget, menu)
-///     (widget)
 ///     (widget, menu)
-///     (widget)
+///     ("label" => message)
+///     ("label" => message, shortcut: "Ctrl+S")
+///     ("label" => message, checked: bool)
+///     (---)
+///     ("label" [
+///         ...
+///     ])
 ///     ...
 /// )
 /// ```
+///
+/// A `"label" => message` item is an [`Entry`] publishing the message, with
+/// the given builder methods applied to it. `(---)` is a [`Separator`], and
+/// `"label" [...]` is an [`Entry`] opening a submenu of the nested items.
+///
+/// [`Entry`]: crate::menu::Entry
+/// [`Separator`]: crate::menu::Separator
 #[cfg(feature = "menu")]
 #[macro_export]
 macro_rules! menu_items {
     ($($x:tt)+) => {
-        {
-            macro_rules! wrap_item {
-                (($i:expr , $m:expr)) => (
-                    $crate::menu::Item::with_menu($i, $m)
-                );
-                (($i:expr)) => (
-                    $crate::menu::Item::new($i)
-                );
-            }
-
-            vec![ $( wrap_item!($x) ),+ ]
-        }
+        vec![ $( $crate::__menu_item!($x) ),+ ]
     }
 }
 
+/// Creates a menu item of the [`menu_items!`](crate::menu_items) syntax.
+#[cfg(feature = "menu")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __menu_item {
+    ((- - -)) => (
+        $crate::menu::Item::new($crate::menu::Separator::new())
+    );
+    (($label:literal => $message:expr $(, $method:ident : $value:expr)* $(,)?)) => (
+        $crate::menu::Item::new(
+            $crate::menu::Entry::new($label).on_press($message) $( .$method($value) )*
+        )
+    );
+    (($label:literal [ $($items:tt)+ ])) => (
+        $crate::menu::Item::with_menu(
+            $crate::menu::Entry::new($label).submenu(true),
+            $crate::menu::entries($crate::menu_items!( $($items)+ )),
+        )
+    );
+    (($i:expr , $m:expr)) => (
+        $crate::menu::Item::with_menu($i, $m)
+    );
+    (($i:expr)) => (
+        $crate::menu::Item::new($i)
+    );
+}
+
 /// Creates a [`Menu`] with the given items.
 ///
 /// [`Menu`]: crate::menu::Menu
@@ -131,16 +196,55 @@ macro_rules! menu {
 /// ```
 /// menu_bar!(
 ///     (widget, menu)
-///     (widget, menu)
-///     (widget, menu)
+///     ("label" [
+///         ...
+///     ])
 ///     ...
 /// )
 /// ```
+///
+/// A `"label" [...]` root opens a menu of the nested items, written with the
+/// [`menu_items!`](crate::menu_items) syntax:
+///
+/// ```ignore
+/// let bar = menu_bar!(
+///     ("File" [
+///         ("New" => Message::New, shortcut: "Ctrl+N")
+///         ("Open" => Message::Open, shortcut: "Ctrl+O")
+///         ("Open Recent" [
+///             ("notes.txt" => Message::OpenRecent(0))
+///             ("todo.txt" => Message::OpenRecent(1))
+///         ])
+///         (---)
+///         ("Quit" => Message::Quit, shortcut: "Ctrl+Q")
+///     ])
+///     ("View" [
+///         ("Word Wrap" => Message::ToggleWrap, checked: state.wrap)
+///         ("Line Numbers" => Message::ToggleLineNumbers, checked: state.line_numbers)
+///     ])
+/// );
+/// ```
 #[cfg(feature = "menu")]
 #[macro_export]
 macro_rules! menu_bar {
-    ($(($x:expr, $m:expr))+) => (
-        $crate::menu::MenuBar::new(vec![ $( Item::with_menu($x, $m) ),+ ])
+    ($($x:tt)+) => (
+        $crate::menu::MenuBar::new(vec![ $( $crate::__menu_root!($x) ),+ ])
+    );
+}
+
+/// Creates a root of the [`menu_bar!`](crate::menu_bar) syntax.
+#[cfg(feature = "menu")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __menu_root {
+    (($label:literal [ $($items:tt)+ ])) => (
+        $crate::menu::Item::with_menu(
+            $crate::menu::Entry::root($label),
+            $crate::menu::entries($crate::menu_items!( $($items)+ )),
+        )
+    );
+    (($x:expr, $m:expr)) => (
+        $crate::menu::Item::with_menu($x, $m)
     );
 }
 
@@ -379,7 +483,8 @@ where
     Theme: 'a
         + crate::style::selection_list::StyleSheet
         + iced::widget::container::StyleSheet
-        + iced::widget::scrollable::StyleSheet,
+        + iced::widget::scrollable::StyleSheet
+        + iced::widget::text_input::StyleSheet,
     T: Clone + Display + Eq + Hash,
     [T]: ToOwned<Owned = Vec<T>>,
 {
@@ -409,9 +514,837 @@ where
     Theme: 'a
         + crate::style::selection_list::StyleSheet
         + iced::widget::container::StyleSheet
-        + iced::widget::scrollable::StyleSheet,
+        + iced::widget::scrollable::StyleSheet
+        + iced::widget::text_input::StyleSheet,
     T: Clone + Display + Eq + Hash,
     [T]: ToOwned<Owned = Vec<T>>,
 {
     crate::SelectionList::new(options, on_selected)
 }
+
+#[cfg(feature = "anchored_overlay")]
+/// Shortcut helper to create an [`AnchoredOverlay`] Widget, showing the
+/// `content` on the `placement` side of the `target`.
+///
+/// [`AnchoredOverlay`]: crate::AnchoredOverlay
+#[must_use]
+pub fn anchored_overlay<'a, Message, Theme, Renderer>(
+    target: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    placement: crate::anchored_overlay::Placement,
+) -> crate::AnchoredOverlay<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    crate::AnchoredOverlay::new(target, content, placement)
+}
+
+#[cfg(feature = "accordion")]
+/// Shortcut helper to create an [`Accordion`] Widget.
+///
+/// [`Accordion`]: crate::Accordion
+#[must_use]
+pub fn accordion<Message, Theme, Renderer>(
+    sections: Vec<crate::widgets::accordion::Section<'_, Message, Theme, Renderer>>,
+) -> crate::Accordion<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: crate::style::accordion::StyleSheet,
+{
+    crate::Accordion::new(sections)
+}
+
+#[cfg(feature = "carousel")]
+/// Shortcut helper to create a [`Carousel`] Widget.
+///
+/// [`Carousel`]: crate::Carousel
+#[must_use]
+pub fn carousel<Message, Theme, Renderer>(
+    pages: Vec<Element<'_, Message, Theme, Renderer>>,
+) -> crate::Carousel<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: crate::style::carousel::StyleSheet,
+{
+    crate::Carousel::new(pages)
+}
+
+#[cfg(feature = "chip")]
+/// Shortcut helper to create a [`Chip`] Widget.
+///
+/// [`Chip`]: crate::Chip
+#[must_use]
+pub fn chip<'a, Message, Theme, Renderer>(
+    label: impl Into<String>,
+) -> crate::Chip<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::core::icons::IconRenderer,
+    Theme: crate::style::chip::StyleSheet,
+{
+    crate::Chip::new(label)
+}
+
+#[cfg(feature = "context_menu")]
+/// Shortcut helper to create a [`ContextMenu`] Widget.
+///
+/// [`ContextMenu`]: crate::ContextMenu
+#[must_use]
+pub fn context_menu<'a, Overlay, Message, Theme, Renderer>(
+    underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+    overlay: Overlay,
+) -> crate::ContextMenu<'a, Overlay, Message, Theme, Renderer>
+where
+    Overlay: Fn() -> Element<'a, Message, Theme, Renderer>,
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: crate::style::context_menu::StyleSheet,
+{
+    crate::ContextMenu::new(underlay, overlay)
+}
+
+#[cfg(feature = "dock")]
+/// Shortcut helper to create a [`DockArea`] Widget.
+///
+/// [`DockArea`]: crate::DockArea
+#[must_use]
+pub fn dock_area<'a, Message, Theme, Renderer>(
+    layout: &crate::widgets::dock::DockLayout,
+    view: impl Fn(&str) -> crate::widgets::dock::DockPanel<'a, Message, Theme, Renderer>,
+    on_change: impl Fn(crate::widgets::dock::DockLayout) -> Message + 'a,
+) -> crate::DockArea<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::icons::IconRenderer,
+    Theme: crate::style::dock::StyleSheet + crate::style::tab_bar::StyleSheet,
+{
+    crate::DockArea::new(layout, view, on_change)
+}
+
+#[cfg(feature = "drag_and_drop")]
+/// Shortcut helper to create a [`Draggable`] Widget.
+///
+/// [`Draggable`]: crate::Draggable
+#[must_use]
+pub fn draggable<'a, P, Message, Theme, Renderer>(
+    payload: P,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> crate::Draggable<'a, P, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: crate::style::drag_and_drop::StyleSheet,
+{
+    crate::Draggable::new(payload, content)
+}
+
+#[cfg(feature = "drag_and_drop")]
+/// Shortcut helper to create a [`DropTarget`] Widget.
+///
+/// [`DropTarget`]: crate::DropTarget
+#[must_use]
+pub fn drop_target<'a, P, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    on_drop: impl Fn(P) -> Message + 'a,
+) -> crate::DropTarget<'a, P, Message, Theme, Renderer>
+where
+    P: Clone + 'static,
+    Renderer: renderer::Renderer,
+    Theme: crate::style::drag_and_drop::StyleSheet,
+{
+    crate::DropTarget::new(content, on_drop)
+}
+
+#[cfg(feature = "drawer")]
+/// Shortcut helper to create a [`Drawer`] Widget.
+///
+/// [`Drawer`]: crate::Drawer
+#[must_use]
+pub fn drawer<'a, Message, Theme, Renderer>(
+    underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+    panel: impl Into<Element<'a, Message, Theme, Renderer>>,
+    open: bool,
+) -> crate::Drawer<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: crate::style::drawer::StyleSheet,
+{
+    crate::Drawer::new(underlay, panel, open)
+}
+
+#[cfg(feature = "drop_down")]
+/// Shortcut helper to create a [`DropDown`] Widget.
+///
+/// [`DropDown`]: crate::DropDown
+#[must_use]
+pub fn drop_down<'a, Message, Theme, Renderer>(
+    underlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+    overlay: impl Into<Element<'a, Message, Theme, Renderer>>,
+    expanded: bool,
+) -> crate::DropDown<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    crate::DropDown::new(underlay, overlay, expanded)
+}
+
+#[cfg(feature = "file_browser")]
+/// Shortcut helper to create a [`FileBrowser`] Widget.
+///
+/// [`FileBrowser`]: crate::FileBrowser
+#[must_use]
+pub fn file_browser<'a, Message, Theme>(
+    directory: impl Into<std::path::PathBuf>,
+) -> crate::FileBrowser<'a, Message, Theme>
+where
+    Theme: crate::style::file_browser::StyleSheet,
+{
+    crate::FileBrowser::new(directory)
+}
+
+#[cfg(feature = "heatmap_calendar")]
+/// Shortcut helper to create a [`HeatmapCalendar`] Widget.
+///
+/// [`HeatmapCalendar`]: crate::HeatmapCalendar
+#[must_use]
+pub fn heatmap_calendar<'a, Message, Theme>(
+    year: i32,
+    values: impl IntoIterator<Item = (crate::core::date::Date, f32)>,
+) -> crate::HeatmapCalendar<'a, Message, Theme>
+where
+    Theme: crate::style::heatmap_calendar::StyleSheet,
+{
+    crate::HeatmapCalendar::new(year, values)
+}
+
+#[cfg(feature = "hotkey_input")]
+/// Shortcut helper to create a [`HotkeyInput`] Widget.
+///
+/// [`HotkeyInput`]: crate::HotkeyInput
+#[must_use]
+pub fn hotkey_input<'a, Message, Theme, Renderer>(
+    value: Option<&crate::core::hotkey::Hotkey>,
+    on_change: impl Fn(Option<crate::core::hotkey::Hotkey>) -> Message + 'a,
+) -> crate::HotkeyInput<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: crate::style::hotkey_input::StyleSheet,
+{
+    crate::HotkeyInput::new(value, on_change)
+}
+
+#[cfg(feature = "icon")]
+/// Shortcut helper to create an [`Icon`] Widget.
+///
+/// [`Icon`]: crate::Icon
+#[must_use]
+pub fn icon(glyph: impl Into<char>) -> crate::Icon {
+    crate::Icon::new(glyph)
+}
+
+#[cfg(feature = "image_viewer")]
+/// Shortcut helper to create an [`ImageViewer`] Widget.
+///
+/// [`ImageViewer`]: crate::ImageViewer
+#[must_use]
+pub fn image_viewer<'a, Message, Theme, Renderer>(
+    handle: impl Into<iced::advanced::image::Handle>,
+) -> crate::ImageViewer<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::image::Renderer<Handle = iced::advanced::image::Handle>,
+    Theme: crate::style::image_viewer::StyleSheet,
+{
+    crate::ImageViewer::new(handle)
+}
+
+#[cfg(feature = "json_tree")]
+/// Shortcut helper to create a [`JsonTree`] Widget.
+///
+/// [`JsonTree`]: crate::JsonTree
+#[must_use]
+pub fn json_tree<T, Message, Theme, Renderer>(
+    root: &T,
+) -> crate::JsonTree<'_, T, Message, Theme, Renderer>
+where
+    T: crate::core::json_tree::TreeData,
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: crate::style::json_tree::StyleSheet,
+{
+    crate::JsonTree::new(root)
+}
+
+#[cfg(feature = "knob")]
+/// Shortcut helper to create a [`Knob`] Widget.
+///
+/// [`Knob`]: crate::Knob
+#[must_use]
+pub fn knob<'a, T, Message, Theme>(
+    range: std::ops::RangeInclusive<T>,
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> crate::Knob<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Theme: crate::style::knob::StyleSheet,
+{
+    crate::Knob::new(range, value, on_change)
+}
+
+#[cfg(feature = "log_view")]
+/// Shortcut helper to create a [`LogView`] Widget.
+///
+/// [`LogView`]: crate::LogView
+#[must_use]
+pub fn log_view<Message, Theme, Renderer>(
+    buffer: &crate::core::log::LogBuffer,
+) -> crate::LogView<'_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: crate::style::log_view::StyleSheet,
+{
+    crate::LogView::new(buffer)
+}
+
+#[cfg(feature = "masked_input")]
+/// Shortcut helper to create a [`MaskedInput`] Widget.
+///
+/// [`MaskedInput`]: crate::MaskedInput
+#[must_use]
+pub fn masked_input<'a, Message, Theme, Renderer>(
+    mask: impl Into<crate::core::mask::Mask>,
+    value: &str,
+    on_input: impl Fn(String, String) -> Message + 'a,
+) -> crate::MaskedInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: iced::widget::text_input::StyleSheet,
+{
+    crate::MaskedInput::new(mask, value, on_input)
+}
+
+#[cfg(feature = "menu")]
+/// Shortcut helper to create a [`MenuBar`] Widget.
+///
+/// [`MenuBar`]: crate::menu::MenuBar
+pub fn menu_bar<Message, Theme, Renderer>(
+    roots: Vec<crate::menu::Item<'_, Message, Theme, Renderer>>,
+) -> crate::menu::MenuBar<'_, Message, Theme, Renderer>
+where
+    Theme: crate::style::menu_bar::StyleSheet,
+    Renderer: renderer::Renderer,
+{
+    crate::menu::MenuBar::new(roots)
+}
+
+#[cfg(feature = "menu")]
+/// Shortcut helper to create a [`Menu`] of [`Item`]s.
+///
+/// [`Menu`]: crate::menu::Menu
+/// [`Item`]: crate::menu::Item
+pub fn menu<Message, Theme, Renderer>(
+    items: Vec<crate::menu::Item<'_, Message, Theme, Renderer>>,
+) -> crate::menu::Menu<'_, Message, Theme, Renderer>
+where
+    Theme: crate::style::menu_bar::StyleSheet,
+    Renderer: renderer::Renderer,
+{
+    crate::menu::Menu::new(items)
+}
+
+#[cfg(feature = "minimap")]
+/// Shortcut helper to create a [`Minimap`] Widget.
+///
+/// [`Minimap`]: crate::Minimap
+#[must_use]
+pub fn minimap<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    viewport: iced::Rectangle,
+    on_scroll: impl Fn(iced::widget::scrollable::AbsoluteOffset) -> Message + 'a,
+) -> crate::Minimap<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: crate::style::minimap::StyleSheet,
+{
+    crate::Minimap::new(content, viewport, on_scroll)
+}
+
+#[cfg(feature = "month_calendar")]
+/// Shortcut helper to create a [`MonthCalendar`] Widget.
+///
+/// [`MonthCalendar`]: crate::MonthCalendar
+#[must_use]
+pub fn month_calendar<Message, Theme>(
+    month: crate::core::date::Date,
+    events: &[crate::widgets::month_calendar::CalendarEvent],
+) -> crate::MonthCalendar<'_, Message, Theme>
+where
+    Theme: crate::style::month_calendar::StyleSheet,
+{
+    crate::MonthCalendar::new(month, events)
+}
+
+#[cfg(feature = "multi_pick_list")]
+/// Shortcut helper to create a [`MultiPickList`] Widget.
+///
+/// [`MultiPickList`]: crate::MultiPickList
+#[must_use]
+pub fn multi_pick_list<'a, T, Message, Theme, Renderer>(
+    options: &'a [T],
+    selected: &'a [T],
+    on_change: impl Fn(Vec<T>) -> Message + 'a,
+) -> crate::MultiPickList<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + PartialEq + ToString,
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: crate::style::multi_pick_list::StyleSheet,
+{
+    crate::MultiPickList::new(options, selected, on_change)
+}
+
+#[cfg(feature = "navigation_rail")]
+/// Shortcut helper to create a [`NavigationRail`] Widget.
+///
+/// [`NavigationRail`]: crate::NavigationRail
+#[must_use]
+pub fn navigation_rail<'a, Message, Id, Theme, Renderer>(
+    on_select: impl Fn(Id) -> Message + 'a,
+) -> crate::NavigationRail<'a, Message, Id, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: crate::style::navigation_rail::StyleSheet,
+    Id: Eq + Clone,
+{
+    crate::NavigationRail::new(on_select)
+}
+
+#[cfg(feature = "num_pad")]
+/// Shortcut helper to create a [`NumPad`] Widget.
+///
+/// [`NumPad`]: crate::NumPad
+#[must_use]
+pub fn num_pad<'a, Message, Theme>(
+    on_key: impl Fn(crate::core::num_pad::NumPadKey) -> Message + 'a,
+) -> crate::NumPad<'a, Message, Theme>
+where
+    Theme: crate::style::num_pad::StyleSheet,
+{
+    crate::NumPad::new(on_key)
+}
+
+#[cfg(feature = "password_input")]
+/// Shortcut helper to create a [`PasswordInput`] Widget.
+///
+/// [`PasswordInput`]: crate::PasswordInput
+#[must_use]
+pub fn password_input<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    value: &str,
+    on_input: impl Fn(String) -> Message + 'a,
+) -> crate::PasswordInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: crate::style::password_input::StyleSheet + iced::widget::text_input::StyleSheet,
+{
+    crate::PasswordInput::new(placeholder, value, on_input)
+}
+
+#[cfg(feature = "pin_input")]
+/// Shortcut helper to create a [`PinInput`] Widget.
+///
+/// [`PinInput`]: crate::PinInput
+#[must_use]
+pub fn pin_input<'a, Message, Theme, Renderer>(
+    length: usize,
+    value: &str,
+    on_input: impl Fn(String) -> Message + 'a,
+) -> crate::PinInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: crate::style::pin_input::StyleSheet,
+{
+    crate::PinInput::new(length, value, on_input)
+}
+
+#[cfg(feature = "popover")]
+/// Shortcut helper to create a [`Popover`] Widget.
+///
+/// [`Popover`]: crate::Popover
+#[must_use]
+pub fn popover<'a, Message, Theme, Renderer>(
+    target: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> crate::Popover<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: crate::style::popover::StyleSheet,
+{
+    crate::Popover::new(target, content)
+}
+
+#[cfg(feature = "property_grid")]
+/// Shortcut helper to create a [`PropertyGrid`] Widget.
+///
+/// [`PropertyGrid`]: crate::PropertyGrid
+#[must_use]
+pub fn property_grid<'a, Message, Theme, Renderer>(
+    properties: &'a [crate::core::property::Property],
+    on_change: impl Fn(usize, crate::core::property::PropertyValue) -> Message + 'a,
+) -> crate::PropertyGrid<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a
+        + crate::style::property_grid::StyleSheet
+        + iced::widget::text_input::StyleSheet
+        + iced::widget::checkbox::StyleSheet
+        + iced::widget::text::StyleSheet,
+{
+    crate::PropertyGrid::new(properties, on_change)
+}
+
+#[cfg(feature = "range_slider")]
+/// Shortcut helper to create a [`RangeSlider`] Widget.
+///
+/// [`RangeSlider`]: crate::RangeSlider
+#[must_use]
+pub fn range_slider<'a, T, Message, Theme>(
+    range: std::ops::RangeInclusive<T>,
+    values: (T, T),
+    on_change: impl Fn((T, T)) -> Message + 'a,
+) -> crate::RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Theme: crate::style::range_slider::StyleSheet,
+{
+    crate::RangeSlider::new(range, values, on_change)
+}
+
+#[cfg(feature = "search_bar")]
+/// Shortcut helper to create a [`SearchBar`] Widget.
+///
+/// [`SearchBar`]: crate::SearchBar
+#[must_use]
+pub fn search_bar<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    value: &str,
+    on_input: impl Fn(String) -> Message + 'a,
+) -> crate::SearchBar<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: crate::style::search_bar::StyleSheet + iced::widget::text_input::StyleSheet,
+{
+    crate::SearchBar::new(placeholder, value, on_input)
+}
+
+#[cfg(feature = "segmented_button")]
+/// Shortcut helper to create a [`SegmentedButton`] Widget.
+///
+/// [`SegmentedButton`]: crate::segmented_button::SegmentedButton
+#[must_use]
+pub fn segmented_button<'a, Message, Theme, Renderer, V>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    value: V,
+    selected: Option<V>,
+    on_select: impl FnOnce(V) -> Message,
+) -> crate::segmented_button::SegmentedButton<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: crate::style::segmented_button::StyleSheet,
+    V: Eq + Copy,
+{
+    crate::segmented_button::SegmentedButton::new(content, value, selected, on_select)
+}
+
+#[cfg(feature = "segmented_button")]
+/// Shortcut helper to create a [`SegmentedButtons`] Widget.
+///
+/// [`SegmentedButtons`]: crate::SegmentedButtons
+#[must_use]
+pub fn segmented_buttons<'a, V, Message, Theme>(
+    segments: Vec<crate::widgets::segmented_button::Segment<V>>,
+    selected: Option<V>,
+    on_select: impl Fn(V) -> Message + 'a,
+) -> crate::SegmentedButtons<'a, V, Message, Theme>
+where
+    V: Clone + PartialEq,
+    Theme: crate::style::segmented_button::StyleSheet,
+{
+    crate::SegmentedButtons::new(segments, selected, on_select)
+}
+
+#[cfg(feature = "slide_bar")]
+/// Shortcut helper to create a [`SlideBar`] Widget.
+///
+/// [`SlideBar`]: crate::SlideBar
+#[must_use]
+pub fn slide_bar<'a, T, Message>(
+    range: std::ops::RangeInclusive<T>,
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> crate::SlideBar<'a, T, Message>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+{
+    crate::SlideBar::new(range, value, on_change)
+}
+
+#[cfg(feature = "spinner")]
+/// Shortcut helper to create a [`Spinner`] Widget.
+///
+/// [`Spinner`]: crate::Spinner
+#[must_use]
+pub fn spinner() -> crate::Spinner {
+    crate::Spinner::new()
+}
+
+#[cfg(feature = "split")]
+/// Shortcut helper to create a [`Split`] Widget.
+///
+/// [`Split`]: crate::Split
+#[must_use]
+pub fn split<'a, Message, Theme, Renderer>(
+    first: impl Into<Element<'a, Message, Theme, Renderer>>,
+    second: impl Into<Element<'a, Message, Theme, Renderer>>,
+    divider_position: Option<u16>,
+    axis: crate::split::Axis,
+    on_resize: impl Fn(u16) -> Message + 'static,
+) -> crate::Split<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + crate::style::split::StyleSheet + iced::widget::container::StyleSheet,
+{
+    crate::Split::new(first, second, divider_position, axis, on_resize)
+}
+
+#[cfg(feature = "status_dot")]
+/// Shortcut helper to create a [`StatusDot`] Widget.
+///
+/// [`StatusDot`]: crate::StatusDot
+#[must_use]
+pub fn status_dot<Theme>(status: crate::style::status_dot::Status) -> crate::StatusDot<Theme>
+where
+    Theme: crate::style::status_dot::StyleSheet,
+{
+    crate::StatusDot::new(status)
+}
+
+#[cfg(feature = "style_scope")]
+/// Shortcut helper to create a [`StyleScope`] Widget.
+///
+/// [`StyleScope`]: crate::style_scope::StyleScope
+#[must_use]
+pub fn style_scope<'a, Message, Theme, Renderer, E>(
+    overrides: crate::style_scope::Overrides,
+    content: impl FnOnce() -> E,
+) -> crate::style_scope::StyleScope<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    E: Into<Element<'a, Message, Theme, Renderer>>,
+{
+    crate::style_scope::StyleScope::new(overrides, content)
+}
+
+#[cfg(feature = "tab_bar")]
+/// Shortcut helper to create a [`TabBar`] Widget.
+///
+/// [`TabBar`]: crate::TabBar
+#[must_use]
+pub fn tab_bar<Message, TabId, Theme, Renderer>(
+    tab_labels: Vec<(TabId, crate::TabLabel)>,
+    on_select: impl Fn(TabId) -> Message + 'static,
+) -> crate::TabBar<Message, TabId, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + crate::core::icons::IconRenderer,
+    Theme: crate::style::tab_bar::StyleSheet,
+    TabId: Eq + Clone,
+{
+    crate::TabBar::with_tab_labels(tab_labels, on_select)
+}
+
+#[cfg(feature = "table")]
+/// Shortcut helper to create a [`Table`] Widget.
+///
+/// [`Table`]: crate::Table
+#[must_use]
+pub fn table<'a, T, Message, Theme, Renderer>(
+    columns: Vec<crate::widgets::table::Column>,
+    rows: &'a [T],
+    view: impl Fn(usize, usize, &'a T) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> crate::Table<'a, T, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: crate::style::table::StyleSheet,
+{
+    crate::Table::new(columns, rows, view)
+}
+
+#[cfg(feature = "tabs")]
+/// Shortcut helper to create a [`Tabs`] Widget.
+///
+/// [`Tabs`]: crate::Tabs
+#[must_use]
+pub fn tabs<'a, Message, TabId, Theme, Renderer>(
+    on_select: impl Fn(TabId) -> Message + 'static,
+) -> crate::Tabs<'a, Message, TabId, Theme, Renderer>
+where
+    Renderer: 'a + renderer::Renderer + crate::core::icons::IconRenderer,
+    Theme: crate::style::tab_bar::StyleSheet + iced::widget::text::StyleSheet,
+    TabId: Eq + Clone,
+{
+    crate::Tabs::new(on_select)
+}
+
+#[cfg(feature = "tag_input")]
+/// Shortcut helper to create a [`TagInput`] Widget.
+///
+/// [`TagInput`]: crate::TagInput
+#[must_use]
+pub fn tag_input<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    tags: &[String],
+    on_change: impl Fn(Vec<String>) -> Message + 'a,
+) -> crate::TagInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: crate::style::tag_input::StyleSheet,
+{
+    crate::TagInput::new(placeholder, tags, on_change)
+}
+
+#[cfg(feature = "tick_slider")]
+/// Shortcut helper to create a [`TickSlider`] Widget.
+///
+/// [`TickSlider`]: crate::TickSlider
+#[must_use]
+pub fn tick_slider<'a, T, Message, Theme>(
+    range: std::ops::RangeInclusive<T>,
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> crate::TickSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Theme: crate::style::tick_slider::StyleSheet,
+{
+    crate::TickSlider::new(range, value, on_change)
+}
+
+#[cfg(feature = "toast")]
+/// Shortcut helper to create a [`ToastManager`] Widget.
+///
+/// [`ToastManager`]: crate::ToastManager
+#[must_use]
+pub fn toast_manager<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    toasts: &'a [crate::Toast<Message>],
+    on_dismiss: impl Fn(usize) -> Message + 'a,
+) -> crate::ToastManager<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: crate::style::toast::StyleSheet,
+{
+    crate::ToastManager::new(content, toasts, on_dismiss)
+}
+
+#[cfg(feature = "tour")]
+/// Shortcut helper to create a [`Tour`] Widget.
+///
+/// [`Tour`]: crate::Tour
+#[must_use]
+pub fn tour<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    steps: &'a [crate::widgets::tour::TourStep],
+    current: Option<usize>,
+    on_step: impl Fn(Option<usize>) -> Message + 'a,
+) -> crate::Tour<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: crate::style::tour::StyleSheet,
+{
+    crate::Tour::new(content, steps, current, on_step)
+}
+
+#[cfg(feature = "transfer_list")]
+/// Shortcut helper to create a [`TransferList`] Widget.
+///
+/// [`TransferList`]: crate::TransferList
+#[must_use]
+pub fn transfer_list<'a, T, Message, Theme, Renderer>(
+    available: &'a [T],
+    chosen: &'a [T],
+    on_change: impl Fn(Vec<T>, Vec<T>) -> Message + 'a,
+) -> crate::TransferList<'a, T, Message, Theme, Renderer>
+where
+    T: 'static + Clone + Display + Eq + Hash,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a
+        + crate::style::selection_list::StyleSheet
+        + iced::widget::container::StyleSheet
+        + iced::widget::scrollable::StyleSheet
+        + iced::widget::text_input::StyleSheet
+        + iced::widget::button::StyleSheet
+        + iced::widget::text::StyleSheet,
+{
+    crate::TransferList::new(available, chosen, on_change)
+}
+
+#[cfg(feature = "tree_table")]
+/// Shortcut helper to create a [`TreeTable`] Widget.
+///
+/// [`TreeTable`]: crate::TreeTable
+#[must_use]
+pub fn tree_table<'a, T, Message, Theme, Renderer>(
+    columns: Vec<crate::widgets::table::Column>,
+    roots: &'a [crate::core::tree_table::TreeNode<T>],
+    view: impl Fn(&'a T, usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> crate::TreeTable<'a, T, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: 'a + crate::style::table::StyleSheet + iced::widget::text::StyleSheet,
+{
+    crate::TreeTable::new(columns, roots, view)
+}
+
+#[cfg(feature = "virtual_list")]
+/// Shortcut helper to create a [`VirtualList`] Widget.
+///
+/// [`VirtualList`]: crate::VirtualList
+#[must_use]
+pub fn virtual_list<'a, Message, Theme, Renderer>(
+    count: usize,
+    producer: impl Fn(std::ops::Range<usize>) -> Vec<Element<'a, Message, Theme, Renderer>> + 'a,
+) -> crate::VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: crate::style::virtual_list::StyleSheet,
+{
+    crate::VirtualList::new(count, producer)
+}
+
+#[cfg(feature = "wheel_picker")]
+/// Shortcut helper to create a [`WheelPicker`] Widget.
+///
+/// [`WheelPicker`]: crate::WheelPicker
+#[must_use]
+pub fn wheel_picker<'a, T, Message, Theme>(
+    items: &'a [T],
+    selected: Option<T>,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> crate::WheelPicker<'a, T, Message, Theme>
+where
+    T: Clone + PartialEq + ToString,
+    Theme: crate::style::wheel_picker::StyleSheet,
+{
+    crate::WheelPicker::new(items, selected, on_select)
+}
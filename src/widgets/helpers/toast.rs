@@ -0,0 +1,135 @@
+//! Shortcut helpers to create and queue [`Toast`]s.
+//!
+//! The queue of toasts of the application lives in a [`Toasts`], whose
+//! [`view`](Toasts::view) shows them over the content:
+//!
+//! ```ignore
+//! # use iced::{widget::text, Element};
+//! # use iced_aw::helpers::toast::{self, Toasts};
+//! #
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Save,
+//!     Dismiss(usize),
+//! }
+//!
+//! struct App {
+//!     toasts: Toasts<Message>,
+//! }
+//!
+//! impl App {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::Save => self.toasts.push(toast::success("Saved")),
+//!             Message::Dismiss(index) => self.toasts.dismiss(index),
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<'_, Message> {
+//!         self.toasts.view(text("Content"), Message::Dismiss).into()
+//!     }
+//! }
+//! ```
+//!
+//! *This API requires the following crate features to be activated: `toast`*
+
+use crate::widgets::toast::{Severity, StyleSheet, Toast, ToastManager};
+use iced::{advanced::renderer, Element};
+
+/// Creates a [`Toast`] informing the user.
+#[must_use]
+pub fn info<Message>(title: impl Into<String>) -> Toast<Message> {
+    Toast::new(title).severity(Severity::Info)
+}
+
+/// Creates a [`Toast`] reporting a success.
+#[must_use]
+pub fn success<Message>(title: impl Into<String>) -> Toast<Message> {
+    Toast::new(title).severity(Severity::Success)
+}
+
+/// Creates a [`Toast`] warning the user.
+#[must_use]
+pub fn warning<Message>(title: impl Into<String>) -> Toast<Message> {
+    Toast::new(title).severity(Severity::Warning)
+}
+
+/// Creates a [`Toast`] reporting an error.
+#[must_use]
+pub fn error<Message>(title: impl Into<String>) -> Toast<Message> {
+    Toast::new(title).severity(Severity::Error)
+}
+
+/// The queue of the [`Toast`]s of an application.
+#[derive(Clone, Debug)]
+pub struct Toasts<Message> {
+    /// The queued toasts, the oldest first.
+    queue: Vec<Toast<Message>>,
+}
+
+impl<Message> Default for Toasts<Message> {
+    fn default() -> Self {
+        Self { queue: Vec::new() }
+    }
+}
+
+impl<Message> Toasts<Message> {
+    /// Creates a new empty [`Toasts`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the [`Toast`] to the end of the queue.
+    pub fn push(&mut self, toast: Toast<Message>) {
+        self.queue.push(toast);
+    }
+
+    /// Removes the [`Toast`] at the given index of the queue, as asked by the
+    /// `on_dismiss` message of the [`ToastManager`]. Does nothing if there is
+    /// no such toast.
+    pub fn dismiss(&mut self, index: usize) {
+        if index < self.queue.len() {
+            let _ = self.queue.remove(index);
+        }
+    }
+
+    /// Removes all the [`Toast`]s of the queue.
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+
+    /// Returns the queued [`Toast`]s, the oldest first.
+    #[must_use]
+    pub fn as_slice(&self) -> &[Toast<Message>] {
+        &self.queue
+    }
+
+    /// Returns the number of queued [`Toast`]s.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns whether the queue is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Creates a [`ToastManager`] showing the queued [`Toast`]s over the
+    /// `content`, producing the message of `on_dismiss` with the index of a
+    /// dismissed toast.
+    pub fn view<'a, Theme, Renderer>(
+        &'a self,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_dismiss: impl Fn(usize) -> Message + 'a,
+    ) -> ToastManager<'a, Message, Theme, Renderer>
+    where
+        Message: Clone,
+        Renderer: renderer::Renderer,
+        Theme: StyleSheet,
+    {
+        ToastManager::new(content, &self.queue, on_dismiss)
+    }
+}
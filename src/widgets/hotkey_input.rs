@@ -0,0 +1,453 @@
+//! Record a key combination by pressing it.
+//!
+//! *This API requires the following crate features to be activated: `hotkey_input`*
+
+use crate::core::{
+    hotkey::modifiers_label,
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    settings,
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::{
+            self,
+            operation::{self, Operation},
+            tree::{self, Tag, Tree},
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    keyboard::{self, key::Named, Modifiers},
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+use std::marker::PhantomData;
+
+pub use crate::{
+    core::hotkey::Hotkey,
+    style::hotkey_input::{Appearance, StyleSheet},
+};
+
+/// The default placeholder of a [`HotkeyInput`] without a key combination.
+const DEFAULT_PLACEHOLDER: &str = "None";
+/// The default hint of a [`HotkeyInput`] waiting for a key combination.
+const DEFAULT_HINT: &str = "Press a key combination";
+
+/// A field recording a key combination, like a configurable shortcut.
+///
+/// Once focused with a click, the field shows the held modifiers and records
+/// the next key pressed with them. Escape stops the recording, while
+/// Backspace and Delete clear the key combination.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::hotkey_input::{Hotkey, HotkeyInput};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ShortcutChanged(Option<Hotkey>),
+/// }
+///
+/// let shortcut: Option<Hotkey> = None;
+///
+/// let hotkey_input = HotkeyInput::new(shortcut.as_ref(), Message::ShortcutChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct HotkeyInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// The recorded key combination.
+    value: Option<Hotkey>,
+    /// The function producing the message when the key combination changes.
+    on_change: Box<dyn Fn(Option<Hotkey>) -> Message + 'a>,
+    /// The id of the [`HotkeyInput`].
+    id: Option<widget::Id>,
+    /// The text shown without a key combination.
+    placeholder: String,
+    /// The text shown while waiting for a key combination.
+    hint: String,
+    /// Whether the key combination can be cleared.
+    clearable: bool,
+    width: Length,
+    padding: f32,
+    text_size: f32,
+    font: Font,
+    /// The style of the [`HotkeyInput`].
+    style: <Theme as StyleSheet>::Style,
+    #[allow(clippy::missing_docs_in_private_items)]
+    renderer: PhantomData<Renderer>,
+}
+
+/// The state of a [`HotkeyInput`].
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the next key combination is recorded.
+    is_focused: bool,
+    /// The modifiers held while recording.
+    modifiers: Modifiers,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.modifiers = Modifiers::default();
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Message, Theme, Renderer> HotkeyInput<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`HotkeyInput`] showing the given key combination.
+    pub fn new<F>(value: Option<&Hotkey>, on_change: F) -> Self
+    where
+        F: 'a + Fn(Option<Hotkey>) -> Message,
+    {
+        Self {
+            value: value.cloned(),
+            on_change: Box::new(on_change),
+            id: None,
+            placeholder: DEFAULT_PLACEHOLDER.to_owned(),
+            hint: DEFAULT_HINT.to_owned(),
+            clearable: true,
+            width: Length::Fill,
+            padding: settings::spaced(8.0),
+            text_size: settings::scaled(16.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+            renderer: PhantomData,
+        }
+    }
+
+    /// Sets the id of the [`HotkeyInput`], to focus it with an operation.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the text shown without a key combination.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the text shown while waiting for a key combination.
+    #[must_use]
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = hint.into();
+        self
+    }
+
+    /// Sets whether the key combination can be cleared, with the button at
+    /// the end of the field or with Backspace and Delete.
+    #[must_use]
+    pub fn clearable(mut self, clearable: bool) -> Self {
+        self.clearable = clearable;
+        self
+    }
+
+    /// Sets the width of the [`HotkeyInput`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the [`HotkeyInput`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`HotkeyInput`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`HotkeyInput`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`HotkeyInput`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the bounds of the button clearing the key combination, if shown.
+    fn clear_button(&self, bounds: Rectangle) -> Option<Rectangle> {
+        let size = LineHeight::default().to_absolute(Pixels(self.text_size)).0;
+
+        (self.clearable && self.value.is_some()).then(|| {
+            Rectangle::new(
+                Point::new(
+                    bounds.x + bounds.width - self.padding - size,
+                    bounds.center_y() - size / 2.0,
+                ),
+                Size::new(size, size),
+            )
+        })
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for HotkeyInput<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let height =
+            LineHeight::default().to_absolute(Pixels(self.text_size)).0 + self.padding * 2.0;
+
+        Node::new(limits.width(self.width).resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(0.0, height),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.is_focused = false;
+                    return event::Status::Ignored;
+                };
+
+                if self
+                    .clear_button(bounds)
+                    .is_some_and(|button| button.contains(position))
+                {
+                    state.is_focused = false;
+                    shell.publish((self.on_change)(None));
+                } else {
+                    operation::Focusable::focus(state);
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) if state.is_focused => {
+                state.modifiers = modifiers;
+                event::Status::Ignored
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                if state.is_focused =>
+            {
+                if modifiers.is_empty() {
+                    match key.as_ref() {
+                        keyboard::Key::Named(Named::Escape) => {
+                            state.is_focused = false;
+                            return event::Status::Captured;
+                        }
+                        keyboard::Key::Named(Named::Tab) => {
+                            state.is_focused = false;
+                            return event::Status::Ignored;
+                        }
+                        keyboard::Key::Named(Named::Backspace | Named::Delete)
+                            if self.clearable =>
+                        {
+                            state.is_focused = false;
+                            shell.publish((self.on_change)(None));
+                            return event::Status::Captured;
+                        }
+                        _ => {}
+                    }
+                }
+
+                match Hotkey::from_key_press(&key, modifiers) {
+                    Some(hotkey) => {
+                        state.is_focused = false;
+                        shell.publish((self.on_change)(Some(hotkey)));
+                    }
+                    None => state.modifiers = modifiers,
+                }
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = if state.is_focused {
+            theme.focused(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: appearance.border_radius.into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        let clear_button = self.clear_button(bounds);
+        let (content, color) = match (&self.value, state.is_focused) {
+            (_, true) if !state.modifiers.is_empty() => (
+                format!("{}…", modifiers_label(state.modifiers)),
+                appearance.text_color,
+            ),
+            (_, true) => (self.hint.clone(), appearance.placeholder_color),
+            (Some(hotkey), false) => (hotkey.to_string(), appearance.text_color),
+            (None, false) => (self.placeholder.clone(), appearance.placeholder_color),
+        };
+
+        let text_bounds = Rectangle {
+            x: bounds.x + self.padding,
+            width: clear_button.map_or(bounds.x + bounds.width - self.padding, |button| button.x)
+                - bounds.x
+                - self.padding,
+            ..bounds
+        };
+        renderer.fill_text(
+            Text {
+                content: &content,
+                bounds: text_bounds.size(),
+                size: Pixels(self.text_size),
+                line_height: LineHeight::default(),
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: Shaping::Advanced,
+            },
+            Point::new(text_bounds.x, text_bounds.center_y()),
+            color,
+            text_bounds,
+        );
+
+        if let Some(button) = clear_button {
+            renderer.fill_text(
+                Text {
+                    content: &icon_to_string(Bootstrap::X),
+                    bounds: button.size(),
+                    size: Pixels(self.text_size),
+                    line_height: LineHeight::default(),
+                    font: BOOTSTRAP_FONT,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: Shaping::Basic,
+                },
+                button.center(),
+                if cursor.is_over(button) {
+                    appearance.hovered_icon_color
+                } else {
+                    appearance.icon_color
+                },
+                button,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        operation.focusable(state, self.id.as_ref());
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<HotkeyInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(hotkey_input: HotkeyInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(hotkey_input)
+    }
+}
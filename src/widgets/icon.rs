@@ -0,0 +1,248 @@
+//! Draw a glyph of an icon font, rotated, flipped or spinning.
+//!
+//! *This API requires the following crate features to be activated: `icon`*
+
+use crate::core::{
+    animation::{self, Timeline},
+    icons::BOOTSTRAP_FONT,
+    settings,
+};
+
+use iced::{
+    advanced::{
+        graphics::geometry::Renderer as _,
+        layout::{Limits, Node},
+        renderer::{self, Renderer as _},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::Cursor,
+    widget::{
+        canvas::{self, Frame},
+        text::{LineHeight, Shaping},
+    },
+    window, Color, Element, Event, Font, Length, Pixels, Point, Radians, Rectangle, Renderer, Size,
+    Vector,
+};
+use std::{f32::consts::TAU, time::Duration};
+
+/// The default size of an [`Icon`].
+const DEFAULT_SIZE: f32 = 16.0;
+
+/// How many times slower an [`Icon`] spins when the
+/// [`settings`](crate::settings) ask for reduced motion.
+const REDUCED_MOTION_SLOWDOWN: f32 = 3.0;
+
+/// The state of an [`Icon`].
+#[derive(Debug, Default)]
+struct State {
+    /// The progress of the current turn of the spin.
+    spin: Timeline,
+}
+
+/// A glyph of an icon font, drawn with a size and a color, and optionally
+/// rotated, flipped or spinning, like a refresh icon while loading.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{Bootstrap, Icon};
+/// # use std::time::Duration;
+/// #
+/// let refresh = Icon::new(Bootstrap::ArrowClockwise)
+///     .size(24.0)
+///     .spin(Duration::from_secs(1));
+/// let back = Icon::new(Bootstrap::ArrowRight).flip_horizontal(true);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Icon {
+    /// The glyph of the [`Icon`].
+    glyph: char,
+    /// The font of the glyph.
+    font: Font,
+    /// The size of the [`Icon`].
+    size: f32,
+    /// The color of the [`Icon`], the text color by default.
+    color: Option<Color>,
+    /// The rotation of the [`Icon`], clockwise.
+    rotation: Radians,
+    /// Whether the [`Icon`] is mirrored left to right.
+    flip_horizontal: bool,
+    /// Whether the [`Icon`] is mirrored top to bottom.
+    flip_vertical: bool,
+    /// The duration of a full turn of the [`Icon`], if it spins.
+    spin: Option<Duration>,
+}
+
+impl Icon {
+    /// Creates a new [`Icon`] drawing the given glyph of the Bootstrap icon
+    /// font.
+    pub fn new(glyph: impl Into<char>) -> Self {
+        Self {
+            glyph: glyph.into(),
+            font: BOOTSTRAP_FONT,
+            size: settings::scaled(DEFAULT_SIZE),
+            color: None,
+            rotation: Radians(0.0),
+            flip_horizontal: false,
+            flip_vertical: false,
+            spin: None,
+        }
+    }
+
+    /// Sets the font of the glyph of the [`Icon`].
+    #[must_use]
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the size of the [`Icon`].
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the color of the [`Icon`].
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the clockwise rotation of the [`Icon`] around its center.
+    #[must_use]
+    pub fn rotation(mut self, rotation: impl Into<Radians>) -> Self {
+        self.rotation = rotation.into();
+        self
+    }
+
+    /// Sets whether the [`Icon`] is mirrored left to right.
+    #[must_use]
+    pub fn flip_horizontal(mut self, flip: bool) -> Self {
+        self.flip_horizontal = flip;
+        self
+    }
+
+    /// Sets whether the [`Icon`] is mirrored top to bottom.
+    #[must_use]
+    pub fn flip_vertical(mut self, flip: bool) -> Self {
+        self.flip_vertical = flip;
+        self
+    }
+
+    /// Makes the [`Icon`] spin clockwise, one full turn per `period`.
+    ///
+    /// A zero period stops the [`Icon`] where it is.
+    #[must_use]
+    pub fn spin(mut self, period: Duration) -> Self {
+        self.spin = Some(period);
+        self
+    }
+}
+
+impl<Message, Theme> Widget<Message, Theme, Renderer> for Icon {
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(self.size, self.size),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            match self.spin {
+                Some(period) if layout.bounds().intersects(viewport) => {
+                    let period = if settings::reduced_motion() {
+                        period.mul_f32(REDUCED_MOTION_SLOWDOWN)
+                    } else {
+                        period
+                    };
+
+                    state.spin.cycle(period, now);
+                    animation::request_frame(shell, now);
+                }
+                // Resume from where the spin was stopped.
+                _ => state.spin.stop(),
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let spin = if self.spin.is_some() {
+            state.spin.progress() * TAU
+        } else {
+            0.0
+        };
+
+        let mut frame = Frame::new(renderer, bounds.size());
+        frame.translate(Vector::new(bounds.width / 2.0, bounds.height / 2.0));
+        frame.rotate(self.rotation.0 + spin);
+        frame.scale_nonuniform(Vector::new(
+            if self.flip_horizontal { -1.0 } else { 1.0 },
+            if self.flip_vertical { -1.0 } else { 1.0 },
+        ));
+        frame.fill_text(canvas::Text {
+            content: self.glyph.to_string(),
+            position: Point::ORIGIN,
+            color: self.color.unwrap_or(style.text_color),
+            size: Pixels(self.size),
+            line_height: LineHeight::default(),
+            font: self.font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        });
+
+        let geometry = frame.into_geometry();
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw(vec![geometry]);
+        });
+    }
+}
+
+impl<Message, Theme> From<Icon> for Element<'_, Message, Theme, Renderer> {
+    fn from(icon: Icon) -> Self {
+        Element::new(icon)
+    }
+}
@@ -0,0 +1,532 @@
+//! Zoom and pan an image.
+//!
+//! *This API requires the following crate features to be activated: `image_viewer`*
+
+use iced::{
+    advanced::{
+        image::{self, FilterMethod, Handle},
+        layout::{Limits, Node},
+        mouse::{click, Click},
+        renderer::{self, Quad},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch, Border, Color, Element, Event, Length, Point, Rectangle, Shadow, Size, Vector,
+};
+
+pub use crate::style::image_viewer::{Appearance, StyleSheet};
+
+/// The default smallest scale of an [`ImageViewer`].
+const DEFAULT_MIN_SCALE: f32 = 0.1;
+/// The default largest scale of an [`ImageViewer`].
+const DEFAULT_MAX_SCALE: f32 = 16.0;
+/// The default factor the scale is multiplied by for each wheel step.
+const DEFAULT_SCALE_STEP: f32 = 1.2;
+/// The number of pixels scrolled by a touchpad counted as one wheel step.
+const PIXELS_PER_STEP: f32 = 60.0;
+/// The size of the squares of the checkerboard.
+const CHECKER_SIZE: f32 = 10.0;
+
+/// The part of the image shown by an [`ImageViewer`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Viewport {
+    /// The size of a pixel of the image on the screen.
+    pub scale: f32,
+    /// The visible part of the image, in pixels of the image.
+    pub visible: Rectangle,
+}
+
+/// The function producing the message when the viewport changes.
+type OnViewportChange<'a, Message> = Box<dyn Fn(Viewport) -> Message + 'a>;
+
+/// The state of an [`ImageViewer`].
+#[derive(Debug, Default)]
+struct State {
+    /// The scale and the position of the image relative to the viewer, or
+    /// `None` while the image fits the viewer.
+    view: Option<(f32, Vector)>,
+    /// The last position of the cursor while the image is dragged.
+    dragging: Option<Point>,
+    /// The last click on the viewer, to detect double clicks.
+    last_click: Option<Click>,
+}
+
+/// A viewer showing an image that can be zoomed with the mouse wheel around
+/// the cursor and panned by dragging it.
+///
+/// The image fits the viewer until it is zoomed. A double click switches
+/// between the fitted image and its actual size around the cursor. A
+/// checkerboard can be shown behind transparent images. Every change of the
+/// visible part of the image is sent as a [`Viewport`], to keep a minimap in
+/// sync.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::image_viewer::{ImageViewer, Viewport};
+/// # use iced::advanced::image::Handle;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ViewportChanged(Viewport),
+/// }
+///
+/// let viewer = ImageViewer::new(Handle::from_path("photo.png"))
+///     .checkerboard(true)
+///     .on_viewport_change(Message::ViewportChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ImageViewer<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Theme: StyleSheet,
+{
+    /// The image shown.
+    handle: Handle,
+    /// The function producing the message when the viewport changes.
+    on_viewport_change: Option<OnViewportChange<'a, Message>>,
+    /// The smallest scale.
+    min_scale: f32,
+    /// The largest scale.
+    max_scale: f32,
+    /// The factor the scale is multiplied by for each wheel step.
+    scale_step: f32,
+    /// Whether a checkerboard is shown behind the image.
+    checkerboard: bool,
+    /// How the image is filtered when it is scaled.
+    filter_method: FilterMethod,
+    width: Length,
+    height: Length,
+    /// The style of the [`ImageViewer`].
+    style: <Theme as StyleSheet>::Style,
+    #[allow(clippy::missing_docs_in_private_items)]
+    renderer: std::marker::PhantomData<Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> ImageViewer<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`ImageViewer`] showing the image of the given handle.
+    pub fn new(handle: impl Into<Handle>) -> Self {
+        Self {
+            handle: handle.into(),
+            on_viewport_change: None,
+            min_scale: DEFAULT_MIN_SCALE,
+            max_scale: DEFAULT_MAX_SCALE,
+            scale_step: DEFAULT_SCALE_STEP,
+            checkerboard: false,
+            filter_method: FilterMethod::default(),
+            width: Length::Fill,
+            height: Length::Fill,
+            style: <Theme as StyleSheet>::Style::default(),
+            renderer: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the function producing the message when the visible part of the
+    /// image changes.
+    #[must_use]
+    pub fn on_viewport_change<F>(mut self, on_viewport_change: F) -> Self
+    where
+        F: 'a + Fn(Viewport) -> Message,
+    {
+        self.on_viewport_change = Some(Box::new(on_viewport_change));
+        self
+    }
+
+    /// Sets the smallest scale of the image.
+    #[must_use]
+    pub fn min_scale(mut self, min_scale: f32) -> Self {
+        self.min_scale = min_scale;
+        self
+    }
+
+    /// Sets the largest scale of the image.
+    #[must_use]
+    pub fn max_scale(mut self, max_scale: f32) -> Self {
+        self.max_scale = max_scale;
+        self
+    }
+
+    /// Sets the factor the scale is multiplied by for each wheel step.
+    #[must_use]
+    pub fn scale_step(mut self, scale_step: f32) -> Self {
+        self.scale_step = scale_step;
+        self
+    }
+
+    /// Sets whether a checkerboard is shown behind the image.
+    #[must_use]
+    pub fn checkerboard(mut self, checkerboard: bool) -> Self {
+        self.checkerboard = checkerboard;
+        self
+    }
+
+    /// Sets how the image is filtered when it is scaled.
+    #[must_use]
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the width of the [`ImageViewer`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`ImageViewer`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`ImageViewer`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the size of the image.
+    fn image_size(&self, renderer: &Renderer) -> Size {
+        let size = renderer.dimensions(&self.handle);
+
+        Size::new(size.width as f32, size.height as f32)
+    }
+
+    /// Returns the scale and the position of the image fitting a viewer of
+    /// the given size.
+    fn fit(viewer: Size, image: Size) -> (f32, Vector) {
+        if image.width <= 0.0 || image.height <= 0.0 {
+            return (1.0, Vector::new(0.0, 0.0));
+        }
+
+        let scale = (viewer.width / image.width).min(viewer.height / image.height);
+
+        (
+            scale,
+            Vector::new(
+                (viewer.width - image.width * scale) / 2.0,
+                (viewer.height - image.height * scale) / 2.0,
+            ),
+        )
+    }
+
+    /// Returns the current scale and position of the image.
+    fn view(state: &State, viewer: Size, image: Size) -> (f32, Vector) {
+        state.view.unwrap_or_else(|| Self::fit(viewer, image))
+    }
+
+    /// Keeps an image at the given scale and position within the viewer: it
+    /// is centered along the axes where it is smaller than the viewer, and
+    /// covers the viewer along the others.
+    fn clamp(viewer: Size, image: Size, scale: f32, offset: Vector) -> Vector {
+        let clamp_axis = |viewer: f32, image: f32, offset: f32| {
+            let image = image * scale;
+
+            if image <= viewer {
+                (viewer - image) / 2.0
+            } else {
+                offset.clamp(viewer - image, 0.0)
+            }
+        };
+
+        Vector::new(
+            clamp_axis(viewer.width, image.width, offset.x),
+            clamp_axis(viewer.height, image.height, offset.y),
+        )
+    }
+
+    /// Sets the scale and the position of the image and sends the new
+    /// viewport.
+    fn set_view(
+        &self,
+        state: &mut State,
+        view: Option<(f32, Vector)>,
+        viewer: Size,
+        image: Size,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        state.view = view;
+
+        let Some(on_viewport_change) = &self.on_viewport_change else {
+            return;
+        };
+
+        let (scale, offset) = Self::view(state, viewer, image);
+        let visible = Rectangle::new(
+            Point::new(-offset.x / scale, -offset.y / scale),
+            Size::new(viewer.width / scale, viewer.height / scale),
+        )
+        .intersection(&Rectangle::with_size(image))
+        .unwrap_or_default();
+
+        shell.publish(on_viewport_change(Viewport { scale, visible }));
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ImageViewer<'_, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            self.image_size(renderer),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let viewer = bounds.size();
+        let image = self.image_size(renderer);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let steps = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / PIXELS_PER_STEP,
+                };
+                let (scale, offset) = Self::view(state, viewer, image);
+                let new_scale =
+                    (scale * self.scale_step.powf(steps)).clamp(self.min_scale, self.max_scale);
+
+                if (new_scale - scale).abs() > f32::EPSILON {
+                    // The point of the image under the cursor stays there.
+                    let cursor = Vector::new(position.x, position.y);
+                    let offset = cursor - (cursor - offset) * (new_scale / scale);
+                    let offset = Self::clamp(viewer, image, new_scale, offset);
+
+                    self.set_view(state, Some((new_scale, offset)), viewer, image, shell);
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                let click = Click::new(position, state.last_click);
+                state.last_click = Some(click);
+
+                if matches!(click.kind(), click::Kind::Double) {
+                    state.dragging = None;
+
+                    let view = if state.view.is_some() {
+                        None
+                    } else {
+                        // The point of the image under the cursor stays there
+                        // at the actual size.
+                        let (scale, offset) = Self::fit(viewer, image);
+                        let cursor = position - bounds.position();
+                        let offset = cursor - (cursor - offset) * (1.0 / scale);
+
+                        Some((1.0, Self::clamp(viewer, image, 1.0, offset)))
+                    };
+
+                    self.set_view(state, view, viewer, image, shell);
+                } else {
+                    state.dragging = Some(position);
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(last) = state.dragging else {
+                    return event::Status::Ignored;
+                };
+
+                state.dragging = Some(position);
+
+                let (scale, offset) = Self::view(state, viewer, image);
+                let moved = Self::clamp(viewer, image, scale, offset + (position - last));
+
+                if moved != offset {
+                    self.set_view(state, Some((scale, moved)), viewer, image, shell);
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.dragging.is_some() =>
+            {
+                state.dragging = None;
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State>().dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let image = self.image_size(renderer);
+        let (scale, offset) = Self::view(state, bounds.size(), image);
+        let image_bounds = Rectangle::new(
+            bounds.position() + offset,
+            Size::new(image.width * scale, image.height * scale),
+        );
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: 0.0.into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            if self.checkerboard {
+                if let Some(visible) = image_bounds.intersection(&clip) {
+                    draw_checkerboard(renderer, image_bounds, visible, &appearance);
+                }
+            }
+
+            image::Renderer::draw(
+                renderer,
+                self.handle.clone(),
+                self.filter_method,
+                image_bounds,
+            );
+        });
+    }
+}
+
+/// Draws the squares of the checkerboard behind an image with the given
+/// bounds within its `visible` part.
+fn draw_checkerboard<Renderer>(
+    renderer: &mut Renderer,
+    image: Rectangle,
+    visible: Rectangle,
+    appearance: &Appearance,
+) where
+    Renderer: renderer::Renderer,
+{
+    let square = |color: Color, bounds: Rectangle| {
+        (
+            Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            color,
+        )
+    };
+
+    let (quad, color) = square(appearance.checker_light, visible);
+    renderer.fill_quad(quad, color);
+
+    let first_column = ((visible.x - image.x) / CHECKER_SIZE).floor() as i64;
+    let first_row = ((visible.y - image.y) / CHECKER_SIZE).floor() as i64;
+    let last_column = ((visible.x + visible.width - image.x) / CHECKER_SIZE).ceil() as i64;
+    let last_row = ((visible.y + visible.height - image.y) / CHECKER_SIZE).ceil() as i64;
+
+    for row in first_row..last_row {
+        for column in (first_column..last_column).filter(|column| (row + column) % 2 != 0) {
+            let cell = Rectangle::new(
+                Point::new(
+                    image.x + column as f32 * CHECKER_SIZE,
+                    image.y + row as f32 * CHECKER_SIZE,
+                ),
+                Size::new(CHECKER_SIZE, CHECKER_SIZE),
+            );
+
+            if let Some(cell) = cell.intersection(&visible) {
+                let (quad, color) = square(appearance.checker_dark, cell);
+                renderer.fill_quad(quad, color);
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ImageViewer<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + image::Renderer<Handle = Handle>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(image_viewer: ImageViewer<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(image_viewer)
+    }
+}
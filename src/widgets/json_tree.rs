@@ -0,0 +1,466 @@
+//! Show nested data, like JSON values, as an expandable tree.
+//!
+//! *This API requires the following crate features to be activated: `json_tree`*
+
+use crate::core::{
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    json_tree::{paths_to_depth, rows, Row},
+    settings,
+};
+
+use iced::{
+    advanced::{
+        clipboard,
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{self, Paragraph, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Color, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+use std::{collections::HashSet, marker::PhantomData};
+
+pub use crate::{
+    core::json_tree::{Path, PathSegment, TreeData, ValueKind},
+    style::json_tree::{Appearance, StyleSheet},
+};
+
+/// A tree showing nested data, like a JSON value, with rows expanding and
+/// collapsing the arrays and the objects.
+///
+/// Values are colored by their kind. Right clicking a row copies its path,
+/// like `$.users[0].name`, to the clipboard. Rows matching the searched text
+/// are highlighted and revealed.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::json_tree::JsonTree;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PathCopied(String),
+/// }
+///
+/// // `Json` implements `TreeData`.
+/// let data = Json(value);
+///
+/// let json_tree = JsonTree::new(&data)
+///     .search("name")
+///     .on_copy_path(Message::PathCopied);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct JsonTree<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    T: TreeData,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// The shown data.
+    root: &'a T,
+    /// The searched text.
+    query: String,
+    /// The function producing the message when the path of a row is copied.
+    on_copy_path: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    /// The depth down to which the values are expanded at first.
+    expand_depth: usize,
+    width: Length,
+    indent: f32,
+    padding: f32,
+    text_size: f32,
+    font: Font,
+    /// The style of the [`JsonTree`].
+    style: <Theme as StyleSheet>::Style,
+    #[allow(clippy::missing_docs_in_private_items)]
+    renderer: PhantomData<Renderer>,
+}
+
+/// The state of a [`JsonTree`].
+#[derive(Debug)]
+struct State {
+    /// The paths of the values expanded by the user.
+    expanded: HashSet<Path>,
+}
+
+impl<'a, T, Message, Theme, Renderer> JsonTree<'a, T, Message, Theme, Renderer>
+where
+    T: TreeData,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`JsonTree`] showing the given data.
+    pub fn new(root: &'a T) -> Self {
+        Self {
+            root,
+            query: String::new(),
+            on_copy_path: None,
+            expand_depth: 1,
+            width: Length::Fill,
+            indent: 16.0,
+            padding: settings::spaced(2.0),
+            text_size: settings::scaled(14.0),
+            font: Font::MONOSPACE,
+            style: <Theme as StyleSheet>::Style::default(),
+            renderer: PhantomData,
+        }
+    }
+
+    /// Sets the searched text. The rows whose key or value contains it are
+    /// highlighted and revealed.
+    #[must_use]
+    pub fn search(mut self, query: impl Into<String>) -> Self {
+        self.query = query.into();
+        self
+    }
+
+    /// Sets the function producing the message when the path of a row is
+    /// copied with a right click.
+    #[must_use]
+    pub fn on_copy_path<F>(mut self, on_copy_path: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_copy_path = Some(Box::new(on_copy_path));
+        self
+    }
+
+    /// Sets the depth down to which the values are expanded at first, the
+    /// root being at depth 0.
+    #[must_use]
+    pub fn expand_depth(mut self, expand_depth: usize) -> Self {
+        self.expand_depth = expand_depth;
+        self
+    }
+
+    /// Sets the width of the [`JsonTree`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the indentation of each level of the [`JsonTree`].
+    #[must_use]
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the vertical padding of the rows of the [`JsonTree`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`JsonTree`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`JsonTree`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`JsonTree`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the visible rows.
+    fn rows(&self, state: &State) -> Vec<Row<'a, T>> {
+        rows(self.root, &state.expanded, &self.query)
+    }
+
+    /// Returns the height of a row.
+    fn row_height(&self) -> f32 {
+        LineHeight::default().to_absolute(Pixels(self.text_size)).0 + self.padding * 2.0
+    }
+
+    /// Returns the index of the row under `position`.
+    fn row_at(&self, bounds: Rectangle, position: Point, count: usize) -> Option<usize> {
+        let index = ((position.y - bounds.y) / self.row_height()).floor();
+        (index >= 0.0 && (index as usize) < count).then_some(index as usize)
+    }
+
+    /// Returns a text of the tree.
+    fn text<'b>(&self, content: &'b str, bounds: Size, font: Font) -> Text<'b, Font> {
+        Text {
+            content,
+            bounds,
+            size: Pixels(self.text_size),
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+}
+
+/// Returns the text and the color of the value of a row.
+fn value_text<T: TreeData>(row: &Row<'_, T>, appearance: &Appearance) -> (String, Color) {
+    let value = row.value;
+
+    match value.kind() {
+        ValueKind::Null => (value.label(), appearance.null_color),
+        ValueKind::Bool => (value.label(), appearance.bool_color),
+        ValueKind::Number => (value.label(), appearance.number_color),
+        ValueKind::String => (format!("\"{}\"", value.label()), appearance.string_color),
+        ValueKind::Array => (
+            format!("[{}]", value.children().len()),
+            appearance.summary_color,
+        ),
+        ValueKind::Object => (
+            format!("{{{}}}", value.children().len()),
+            appearance.summary_color,
+        ),
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for JsonTree<'_, T, Message, Theme, Renderer>
+where
+    T: TreeData,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            expanded: paths_to_depth(self.root, self.expand_depth),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let count = self.rows(tree.state.downcast_ref::<State>()).len();
+
+        Node::new(limits.width(self.width).resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(0.0, self.row_height() * count as f32),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let copy = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => false,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => true,
+            _ => return event::Status::Ignored,
+        };
+        let bounds = layout.bounds();
+        let Some(position) = cursor.position_over(bounds) else {
+            return event::Status::Ignored;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let mut rows = self.rows(state);
+        let Some(index) = self.row_at(bounds, position, rows.len()) else {
+            return event::Status::Ignored;
+        };
+        let row = rows.swap_remove(index);
+
+        if copy {
+            let path = row.path.to_string();
+            clipboard.write(clipboard::Kind::Standard, path.clone());
+
+            if let Some(on_copy_path) = &self.on_copy_path {
+                shell.publish(on_copy_path(path));
+            }
+            return event::Status::Captured;
+        }
+
+        if !row.value.kind().is_container() {
+            return event::Status::Ignored;
+        }
+
+        if row.expanded {
+            let _ = state.expanded.remove(&row.path);
+        } else {
+            let _ = state.expanded.insert(row.path);
+        }
+        shell.invalidate_layout();
+
+        event::Status::Captured
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let Some(position) = cursor.position_over(bounds) else {
+            return mouse::Interaction::default();
+        };
+
+        let rows = self.rows(tree.state.downcast_ref::<State>());
+        match self.row_at(bounds, position, rows.len()) {
+            Some(index) if rows[index].value.kind().is_container() => mouse::Interaction::Pointer,
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let rows = self.rows(tree.state.downcast_ref::<State>());
+        let row_height = self.row_height();
+        let hovered = cursor
+            .position_over(bounds)
+            .and_then(|position| self.row_at(bounds, position, rows.len()));
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        // Only the rows in the viewport are drawn.
+        let first = ((viewport.y - bounds.y) / row_height).floor().max(0.0) as usize;
+        let last = ((viewport.y + viewport.height - bounds.y) / row_height).ceil() as usize;
+
+        for (index, row) in rows
+            .iter()
+            .enumerate()
+            .take(last.min(rows.len()))
+            .skip(first)
+        {
+            let row_bounds = Rectangle::new(
+                Point::new(bounds.x, bounds.y + row_height * index as f32),
+                Size::new(bounds.width, row_height),
+            );
+
+            let background = if row.matches {
+                Some(appearance.match_background)
+            } else if hovered == Some(index) {
+                Some(appearance.hovered_background)
+            } else {
+                None
+            };
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: row_bounds,
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                    },
+                    background,
+                );
+            }
+
+            let mut x = bounds.x + self.indent * row.path.depth() as f32;
+
+            if row.value.kind().is_container() {
+                let toggle = Rectangle::new(
+                    Point::new(x, row_bounds.y),
+                    Size::new(self.text_size, row_height),
+                );
+                renderer.fill_text(
+                    Text {
+                        shaping: Shaping::Basic,
+                        horizontal_alignment: Horizontal::Center,
+                        ..self.text(
+                            &icon_to_string(if row.expanded {
+                                Bootstrap::CaretDownFill
+                            } else {
+                                Bootstrap::CaretRightFill
+                            }),
+                            toggle.size(),
+                            BOOTSTRAP_FONT,
+                        )
+                    },
+                    toggle.center(),
+                    appearance.toggle_color,
+                    toggle,
+                );
+            }
+            x += self.text_size + 4.0;
+
+            let available = |x: f32| Size::new((bounds.x + bounds.width - x).max(0.0), row_height);
+
+            if let Some(segment) = row.path.0.last() {
+                let key = match segment {
+                    PathSegment::Key(key) => format!("\"{key}\": "),
+                    PathSegment::Index(index) => format!("{index}: "),
+                };
+                let text = self.text(&key, available(x), self.font);
+                let width = Renderer::Paragraph::with_text(text).min_bounds().width;
+
+                renderer.fill_text(
+                    text,
+                    Point::new(x, row_bounds.center_y()),
+                    appearance.key_color,
+                    row_bounds,
+                );
+                x += width;
+            }
+
+            let (value, color) = value_text(row, &appearance);
+            renderer.fill_text(
+                self.text(&value, available(x), self.font),
+                Point::new(x, row_bounds.center_y()),
+                color,
+                row_bounds,
+            );
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<JsonTree<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a + TreeData,
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(json_tree: JsonTree<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(json_tree)
+    }
+}
@@ -0,0 +1,579 @@
+//! Turn a rotary knob to select a value.
+//!
+//! *This API requires the following crate features to be activated: `knob`*
+
+use crate::core::settings;
+
+use iced::{
+    advanced::{
+        graphics::geometry::Renderer as _,
+        layout::{Limits, Node},
+        mouse::{click, Click},
+        renderer::{self, Renderer as _},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    keyboard::{self, key::Named},
+    mouse::{self, Cursor},
+    touch,
+    widget::canvas::{path::Arc, Frame, LineCap, Path, Stroke},
+    Element, Event, Length, Point, Radians, Rectangle, Renderer, Size, Vector,
+};
+use std::{f32::consts::PI, ops::RangeInclusive};
+
+pub use crate::style::knob::{Appearance, StyleSheet};
+
+/// The default diameter of a [`Knob`].
+const DEFAULT_SIZE: f32 = 48.0;
+/// The angle of the minimum value, clockwise from the right.
+const START_ANGLE: f32 = 0.75 * PI;
+/// The angle between the minimum and the maximum values.
+const SWEEP_ANGLE: f32 = 1.5 * PI;
+/// The vertical distance dragged to go through the whole range.
+const DRAG_DISTANCE: f32 = 200.0;
+/// The length of the tick marks.
+const TICK_LENGTH: f32 = 4.0;
+/// The number of steps moved by the Page Up and Page Down keys.
+const PAGE_STEPS: f64 = 10.0;
+
+/// How a [`Knob`] follows the cursor while it is dragged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DragMode {
+    /// Dragging up increases the value, and dragging down decreases it.
+    #[default]
+    Vertical,
+    /// The knob points to the cursor while it moves around the center.
+    Circular,
+}
+
+/// A drag in progress on a [`Knob`].
+#[derive(Clone, Copy, Debug)]
+struct Drag {
+    /// The vertical position of the cursor when the drag started.
+    origin: f32,
+    /// The value when the drag started.
+    value: f64,
+}
+
+/// The state of a [`Knob`].
+#[derive(Debug, Default)]
+struct State {
+    /// The drag in progress.
+    dragging: Option<Drag>,
+    /// Whether the arrow keys turn the knob.
+    focused: bool,
+    /// The last click on the knob, to detect double clicks.
+    last_click: Option<Click>,
+}
+
+/// A rotary knob selecting a value in a range, like the controls of audio
+/// tools.
+///
+/// The knob is turned by dragging it, vertically or around its center
+/// depending on its [`DragMode`], with the mouse wheel, or with the arrow
+/// keys once pressed. A double click resets it to its default value. An arc
+/// shows the value within the range, with optional tick marks.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::knob::{DragMode, Knob};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     GainChanged(f32),
+/// }
+///
+/// let knob = Knob::new(-24.0..=24.0, 6.0, Message::GainChanged)
+///     .step(0.5)
+///     .default_value(0.0)
+///     .ticks(9)
+///     .drag_mode(DragMode::Circular);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Knob<'a, T, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The range of the values.
+    range: RangeInclusive<T>,
+    /// The current value.
+    value: T,
+    /// The value restored by a double click.
+    default_value: T,
+    /// The step the value snaps to.
+    step: T,
+    /// The function producing the message when the value changes.
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    /// The optional message produced when the knob is released.
+    on_release: Option<Message>,
+    /// How the knob follows the cursor.
+    drag_mode: DragMode,
+    /// The number of tick marks.
+    ticks: usize,
+    size: f32,
+    /// The style of the [`Knob`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Theme> Knob<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Knob`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current value
+    ///   * a function that will be called when the knob is turned. It
+    ///     receives the new value and must produce a `Message`.
+    pub fn new<F>(range: RangeInclusive<T>, value: T, on_change: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        let value = if value < *range.start() {
+            *range.start()
+        } else if value > *range.end() {
+            *range.end()
+        } else {
+            value
+        };
+
+        Self {
+            default_value: *range.start(),
+            range,
+            value,
+            step: T::from(1),
+            on_change: Box::new(on_change),
+            on_release: None,
+            drag_mode: DragMode::default(),
+            ticks: 0,
+            size: settings::scaled(DEFAULT_SIZE),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the step the value snaps to.
+    #[must_use]
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the value restored by a double click. Defaults to the start of
+    /// the range.
+    #[must_use]
+    pub fn default_value(mut self, default_value: impl Into<T>) -> Self {
+        self.default_value = default_value.into();
+        self
+    }
+
+    /// Sets the message produced when the knob is released.
+    #[must_use]
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets how the [`Knob`] follows the cursor while it is dragged.
+    #[must_use]
+    pub fn drag_mode(mut self, drag_mode: DragMode) -> Self {
+        self.drag_mode = drag_mode;
+        self
+    }
+
+    /// Sets the number of tick marks spread evenly from the minimum to the
+    /// maximum values.
+    #[must_use]
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Sets the diameter of the [`Knob`].
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the style of the [`Knob`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<T, Message, Theme> Knob<'_, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Theme: StyleSheet,
+{
+    /// Returns the start and the end of the range.
+    fn bounds_of_range(&self) -> (f64, f64) {
+        ((*self.range.start()).into(), (*self.range.end()).into())
+    }
+
+    /// Returns the part of the range below the current value.
+    fn ratio(&self) -> f32 {
+        let (start, end) = self.bounds_of_range();
+
+        if end > start {
+            ((self.value.into() - start) / (end - start)) as f32
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the value pointed to by the cursor at `position` around the
+    /// center of the given bounds.
+    fn value_at_angle(&self, bounds: Rectangle, position: Point) -> f64 {
+        let (start, end) = self.bounds_of_range();
+        let offset = position - bounds.center();
+        let angle = (offset.y.atan2(offset.x) - START_ANGLE).rem_euclid(2.0 * PI);
+
+        // The gap below the knob is split between the minimum and the maximum.
+        let angle = if angle <= SWEEP_ANGLE {
+            angle
+        } else if angle < SWEEP_ANGLE + (2.0 * PI - SWEEP_ANGLE) / 2.0 {
+            SWEEP_ANGLE
+        } else {
+            0.0
+        };
+
+        self.snap(start + f64::from(angle / SWEEP_ANGLE) * (end - start))
+    }
+
+    /// Returns the step moved by the keyboard and the mouse wheel.
+    fn key_step(&self) -> f64 {
+        let step = self.step.into();
+
+        if step > 0.0 {
+            step
+        } else {
+            let (start, end) = self.bounds_of_range();
+            (end - start) / 100.0
+        }
+    }
+
+    /// Snaps `value` to the step and to the range.
+    fn snap(&self, value: f64) -> f64 {
+        let (start, end) = self.bounds_of_range();
+        let step = self.step.into();
+
+        let snapped = if step > 0.0 {
+            ((value - start) / step).round() * step + start
+        } else {
+            value
+        };
+
+        snapped.clamp(start, end)
+    }
+
+    /// Sets the value and publishes it if it changed.
+    fn set(&mut self, value: f64, shell: &mut Shell<'_, Message>) {
+        if (value - self.value.into()).abs() <= f64::EPSILON {
+            return;
+        }
+
+        let Some(value) = T::from_f64(value) else {
+            return;
+        };
+
+        self.value = value;
+        shell.publish((self.on_change)(value));
+    }
+
+    /// Returns the radius of the arcs of a knob with the given appearance.
+    fn arc_radius(&self, appearance: &Appearance) -> f32 {
+        (self.size - appearance.arc_width) / 2.0
+    }
+
+    /// Returns the radius of the body of a knob with the given appearance.
+    fn body_radius(&self, appearance: &Appearance) -> f32 {
+        let ticks = if self.ticks > 0 {
+            TICK_LENGTH + 2.0
+        } else {
+            0.0
+        };
+
+        (self.arc_radius(appearance) - appearance.arc_width / 2.0 - ticks - 2.0).max(0.0)
+    }
+}
+
+/// Returns the point at `radius` from `center` in the direction of `angle`.
+fn polar(center: Point, radius: f32, angle: f32) -> Point {
+    center + Vector::new(angle.cos() * radius, angle.sin() * radius)
+}
+
+impl<T, Message, Theme> Widget<Message, Theme, Renderer> for Knob<'_, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.size), Length::Fixed(self.size))
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let size = Length::Fixed(self.size);
+
+        Node::new(
+            limits
+                .width(size)
+                .height(size)
+                .resolve(size, size, Size::ZERO),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.focused = false;
+                    return event::Status::Ignored;
+                };
+
+                let click = Click::new(position, state.last_click);
+                state.last_click = Some(click);
+                state.focused = true;
+
+                if matches!(click.kind(), click::Kind::Double) {
+                    let value = self.snap(self.default_value.into());
+                    self.set(value, shell);
+                    return event::Status::Captured;
+                }
+
+                state.dragging = Some(Drag {
+                    origin: position.y,
+                    value: self.value.into(),
+                });
+
+                if self.drag_mode == DragMode::Circular {
+                    let value = self.value_at_angle(bounds, position);
+                    self.set(value, shell);
+                }
+
+                return event::Status::Captured;
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = state.dragging {
+                    let value = match self.drag_mode {
+                        DragMode::Vertical => {
+                            let (start, end) = self.bounds_of_range();
+                            let moved = f64::from((drag.origin - position.y) / DRAG_DISTANCE);
+                            self.snap(drag.value + moved * (end - start))
+                        }
+                        DragMode::Circular => self.value_at_angle(bounds, position),
+                    };
+
+                    self.set(value, shell);
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.dragging.is_some() =>
+            {
+                state.dragging = None;
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+                return event::Status::Captured;
+            }
+
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let (mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. }) =
+                    delta;
+
+                if y != 0.0 {
+                    let value = self.value.into() + self.key_step() * f64::from(y.signum());
+                    let value = self.snap(value);
+                    self.set(value, shell);
+                }
+                return event::Status::Captured;
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if state.focused => {
+                let (start, end) = self.bounds_of_range();
+                let step = self.key_step();
+                let value = self.value.into();
+
+                let target = match key {
+                    Named::ArrowLeft | Named::ArrowDown => value - step,
+                    Named::ArrowRight | Named::ArrowUp => value + step,
+                    Named::PageDown => value - step * PAGE_STEPS,
+                    Named::PageUp => value + step * PAGE_STEPS,
+                    Named::Home => start,
+                    Named::End => end,
+                    _ => return event::Status::Ignored,
+                };
+
+                let target = self.snap(target);
+                self.set(target, shell);
+                return event::Status::Captured;
+            }
+
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State>().dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = if state.dragging.is_some() {
+            theme.dragging(&self.style)
+        } else if cursor.is_over(bounds) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        let mut frame = Frame::new(renderer, bounds.size());
+        let center = frame.center();
+        let arc_radius = self.arc_radius(&appearance);
+        let body_radius = self.body_radius(&appearance);
+        let angle = START_ANGLE + SWEEP_ANGLE * self.ratio().clamp(0.0, 1.0);
+        let arc = |end_angle: f32| {
+            Path::new(|builder| {
+                builder.arc(Arc {
+                    center,
+                    radius: arc_radius,
+                    start_angle: Radians(START_ANGLE),
+                    end_angle: Radians(end_angle),
+                });
+            })
+        };
+        let line = |color, width| {
+            Stroke::default()
+                .with_color(color)
+                .with_width(width)
+                .with_line_cap(LineCap::Round)
+        };
+
+        frame.stroke(
+            &arc(START_ANGLE + SWEEP_ANGLE),
+            line(appearance.track_color, appearance.arc_width),
+        );
+        if angle > START_ANGLE {
+            frame.stroke(
+                &arc(angle),
+                line(appearance.value_color, appearance.arc_width),
+            );
+        }
+
+        for index in 0..self.ticks {
+            let ratio = if self.ticks > 1 {
+                index as f32 / (self.ticks - 1) as f32
+            } else {
+                0.5
+            };
+            let tick_angle = START_ANGLE + SWEEP_ANGLE * ratio;
+            let inner = body_radius + 2.0;
+
+            frame.stroke(
+                &Path::line(
+                    polar(center, inner, tick_angle),
+                    polar(center, inner + TICK_LENGTH, tick_angle),
+                ),
+                line(appearance.tick_color, 1.0),
+            );
+        }
+
+        let body = Path::circle(center, body_radius);
+        frame.fill(&body, appearance.background);
+        if appearance.border_width > 0.0 {
+            frame.stroke(
+                &body,
+                Stroke::default()
+                    .with_color(appearance.border_color)
+                    .with_width(appearance.border_width),
+            );
+        }
+
+        frame.stroke(
+            &Path::line(
+                polar(center, body_radius * 0.35, angle),
+                polar(center, body_radius * 0.85, angle),
+            ),
+            line(appearance.indicator_color, 2.0),
+        );
+
+        let geometry = frame.into_geometry();
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw(vec![geometry]);
+        });
+    }
+}
+
+impl<'a, T, Message, Theme> From<Knob<'a, T, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet,
+{
+    fn from(knob: Knob<'a, T, Message, Theme>) -> Self {
+        Element::new(knob)
+    }
+}
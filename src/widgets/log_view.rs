@@ -0,0 +1,514 @@
+//! Show the lines of a log, following the appended ones.
+//!
+//! *This API requires the following crate features to be activated: `log_view`*
+
+use crate::core::settings;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+use std::marker::PhantomData;
+
+pub use crate::{
+    core::log::{LogBuffer, LogLine, Severity},
+    style::log_view::{Appearance, StyleSheet},
+};
+
+/// The smallest height of the scroller of a [`LogView`].
+const MIN_SCROLLER_HEIGHT: f32 = 16.0;
+/// The horizontal padding of the lines of a [`LogView`].
+const PADDING: f32 = 4.0;
+
+/// A view of the lines of a [`LogBuffer`], only drawing the visible ones.
+///
+/// While following, the view stays scrolled to the last line as lines are
+/// appended. Scrolling up pauses the following, and scrolling back to the
+/// bottom resumes it. Lines are colored by their [`Severity`], and the lines
+/// containing the searched text are highlighted.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::log_view::{LogBuffer, LogView};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     FollowChanged(bool),
+/// }
+///
+/// let mut log = LogBuffer::new(10_000);
+/// log.push("INFO server started");
+///
+/// let log_view = LogView::new(&log).on_follow_change(Message::FollowChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct LogView<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// The shown lines.
+    buffer: &'a LogBuffer,
+    /// Whether the view follows the appended lines, as last given.
+    follow: bool,
+    /// The searched text, lowercase.
+    query: String,
+    /// The function producing the message when the following is paused or resumed.
+    on_follow_change: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    text_size: f32,
+    font: Font,
+    scrollbar_width: f32,
+    /// The style of the [`LogView`].
+    style: <Theme as StyleSheet>::Style,
+    #[allow(clippy::missing_docs_in_private_items)]
+    renderer: PhantomData<Renderer>,
+}
+
+/// The state of a [`LogView`].
+#[derive(Debug)]
+struct State {
+    /// Whether the view follows the appended lines.
+    following: bool,
+    /// Whether the view follows the appended lines, as last given by the application.
+    given: bool,
+    /// The scroll offset, when not following.
+    offset: f32,
+    /// The number of lines dropped by the buffer when the offset was set.
+    dropped: u64,
+    /// Where the scroller is held from its top while being dragged.
+    grab: Option<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> LogView<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`LogView`] of the lines of the given buffer, following
+    /// the appended lines.
+    #[must_use]
+    pub fn new(buffer: &'a LogBuffer) -> Self {
+        Self {
+            buffer,
+            follow: true,
+            query: String::new(),
+            on_follow_change: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            text_size: settings::scaled(13.0),
+            font: Font::MONOSPACE,
+            scrollbar_width: 8.0,
+            style: <Theme as StyleSheet>::Style::default(),
+            renderer: PhantomData,
+        }
+    }
+
+    /// Sets whether the [`LogView`] follows the appended lines. A change of
+    /// the given value pauses or resumes the following.
+    #[must_use]
+    pub fn follow(mut self, follow: bool) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Sets the searched text. The lines containing it, case-insensitively,
+    /// are highlighted.
+    #[must_use]
+    pub fn search(mut self, query: &str) -> Self {
+        self.query = query.to_lowercase();
+        self
+    }
+
+    /// Sets the function producing the message when the following is paused
+    /// or resumed by scrolling.
+    #[must_use]
+    pub fn on_follow_change<F>(mut self, on_follow_change: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        self.on_follow_change = Some(Box::new(on_follow_change));
+        self
+    }
+
+    /// Sets the width of the [`LogView`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`LogView`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the text size of the [`LogView`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`LogView`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the scrollbar of the [`LogView`].
+    #[must_use]
+    pub fn scrollbar_width(mut self, scrollbar_width: f32) -> Self {
+        self.scrollbar_width = scrollbar_width;
+        self
+    }
+
+    /// Sets the style of the [`LogView`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the height of a line.
+    fn line_height(&self) -> f32 {
+        LineHeight::default()
+            .to_absolute(Pixels(self.text_size))
+            .0
+            .max(1.0)
+    }
+
+    /// Returns the largest scroll offset in a view of `height`.
+    fn max_offset(&self, height: f32) -> f32 {
+        (self.line_height() * self.buffer.len() as f32 - height).max(0.0)
+    }
+
+    /// Returns the scroll offset in a view of `height`. A paused view keeps
+    /// showing the same lines while the oldest ones are dropped.
+    fn offset(&self, state: &State, height: f32) -> f32 {
+        let max = self.max_offset(height);
+
+        if state.following {
+            return max;
+        }
+
+        let dropped = self.buffer.dropped().saturating_sub(state.dropped);
+        (state.offset - self.line_height() * dropped as f32).clamp(0.0, max)
+    }
+
+    /// Scrolls to `offset` in a view of `height`, pausing the following
+    /// above the bottom and resuming it at the bottom.
+    fn scroll_to(
+        &self,
+        state: &mut State,
+        offset: f32,
+        height: f32,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let max = self.max_offset(height);
+        let offset = offset.clamp(0.0, max);
+        let following = offset >= max - 0.5;
+
+        state.offset = offset;
+        state.dropped = self.buffer.dropped();
+
+        if following != state.following {
+            state.following = following;
+
+            if let Some(on_follow_change) = &self.on_follow_change {
+                shell.publish(on_follow_change(following));
+            }
+        }
+    }
+
+    /// Returns the bounds of the scrollbar and of its scroller for a view in
+    /// `bounds` scrolled by `offset`, if the lines do not fit.
+    fn scrollbar(&self, bounds: Rectangle, offset: f32) -> Option<(Rectangle, Rectangle)> {
+        let content = self.line_height() * self.buffer.len() as f32;
+        if content <= bounds.height {
+            return None;
+        }
+
+        let track = Rectangle {
+            x: bounds.x + bounds.width - self.scrollbar_width,
+            width: self.scrollbar_width,
+            ..bounds
+        };
+        let height = (bounds.height * bounds.height / content)
+            .max(MIN_SCROLLER_HEIGHT)
+            .min(track.height);
+        let scroller = Rectangle {
+            y: track.y + (track.height - height) * offset / (content - bounds.height),
+            height,
+            ..track
+        };
+
+        Some((track, scroller))
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for LogView<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            following: self.follow,
+            given: self.follow,
+            offset: 0.0,
+            dropped: self.buffer.dropped(),
+            grab: None,
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // A value given by the application replaces the one set by scrolling.
+        if self.follow != state.given {
+            state.given = self.follow;
+            state.following = self.follow;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::new(0.0, self.line_height() * self.buffer.len() as f32),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let max = self.max_offset(bounds.height);
+        let state = tree.state.downcast_mut::<State>();
+        let offset = self.offset(state, bounds.height);
+        let scrollbar = self.scrollbar(bounds, offset);
+
+        if let (Some(grab), Some((track, scroller))) = (state.grab, scrollbar) {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                    let fraction = (position.y - grab - track.y) / (track.height - scroller.height);
+                    self.scroll_to(state, fraction * max, bounds.height, shell);
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => state.grab = None,
+                _ => return event::Status::Ignored,
+            }
+            return event::Status::Captured;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some((track, scroller)) = scrollbar else {
+                    return event::Status::Ignored;
+                };
+                let Some(position) = cursor.position_over(track) else {
+                    return event::Status::Ignored;
+                };
+
+                // A press outside of the scroller moves its center under the cursor.
+                let grab = if scroller.contains(position) {
+                    position.y - scroller.y
+                } else {
+                    let fraction = (position.y - scroller.height / 2.0 - track.y)
+                        / (track.height - scroller.height);
+                    self.scroll_to(state, fraction * max, bounds.height, shell);
+                    scroller.height / 2.0
+                };
+                state.grab = Some(grab);
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * self.line_height() * 3.0,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                self.scroll_to(state, offset - delta, bounds.height, shell);
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let offset = self.offset(state, bounds.height);
+        let scrollbar = self.scrollbar(bounds, offset);
+        let appearance =
+            if state.grab.is_some() || scrollbar.is_some_and(|(track, _)| cursor.is_over(track)) {
+                theme.hovered(&self.style)
+            } else {
+                theme.active(&self.style)
+            };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        let Some(visible) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let line_height = self.line_height();
+        let first = (offset / line_height).floor() as usize;
+        let last = ((offset + bounds.height) / line_height).ceil() as usize;
+        let text_width = bounds.width
+            - PADDING * 2.0
+            - if scrollbar.is_some() {
+                self.scrollbar_width
+            } else {
+                0.0
+            };
+
+        renderer.with_layer(visible, |renderer| {
+            for index in first..last.min(self.buffer.len()) {
+                let Some(line) = self.buffer.get(index) else {
+                    break;
+                };
+                let line_bounds = Rectangle::new(
+                    Point::new(bounds.x, bounds.y + line_height * index as f32 - offset),
+                    Size::new(bounds.width, line_height),
+                );
+
+                if !self.query.is_empty() && line.text.to_lowercase().contains(&self.query) {
+                    renderer.fill_quad(
+                        Quad {
+                            bounds: line_bounds,
+                            border: Border::default(),
+                            shadow: Shadow::default(),
+                        },
+                        appearance.match_background,
+                    );
+                }
+
+                renderer.fill_text(
+                    Text {
+                        content: &line.text,
+                        bounds: Size::new(text_width.max(0.0), line_height),
+                        size: Pixels(self.text_size),
+                        line_height: LineHeight::default(),
+                        font: self.font,
+                        horizontal_alignment: Horizontal::Left,
+                        vertical_alignment: Vertical::Center,
+                        shaping: Shaping::Basic,
+                    },
+                    Point::new(line_bounds.x + PADDING, line_bounds.center_y()),
+                    match line.severity {
+                        Severity::Trace => appearance.trace_color,
+                        Severity::Debug => appearance.debug_color,
+                        Severity::Info => appearance.info_color,
+                        Severity::Warn => appearance.warn_color,
+                        Severity::Error => appearance.error_color,
+                    },
+                    visible,
+                );
+            }
+
+            if let Some((track, scroller)) = scrollbar {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: track,
+                        border: Border::with_radius(self.scrollbar_width / 2.0),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.scrollbar_background,
+                );
+                renderer.fill_quad(
+                    Quad {
+                        bounds: scroller,
+                        border: Border::with_radius(self.scrollbar_width / 2.0),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.scroller_color,
+                );
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<LogView<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(log_view: LogView<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(log_view)
+    }
+}
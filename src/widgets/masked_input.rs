@@ -0,0 +1,358 @@
+//! Type text following a format mask.
+//!
+//! *This API requires the following crate features to be activated: `masked_input`*
+
+use crate::core::settings;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer, text,
+        widget::{self, tree::Tree, Operation},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    keyboard::{self, key::Named},
+    mouse::{self, Cursor},
+    widget::text_input::{self, cursor, TextInput, Value},
+    Element, Event, Font, Length, Padding, Rectangle, Size,
+};
+
+pub use crate::core::mask::Mask;
+
+/// The default padding of a [`MaskedInput`].
+const DEFAULT_PADDING: f32 = 5.0;
+/// The character standing for a slot in the default placeholder.
+const PLACEHOLDER: char = '_';
+
+/// The function producing a message from the raw and the formatted values.
+type OnValue<'a, Message> = Box<dyn Fn(String, String) -> Message + 'a>;
+
+/// A text input following a [`Mask`], like `(###) ###-####` for a phone
+/// number or `##/##/####` for a date.
+///
+/// The literals of the mask are inserted automatically and the keystrokes not
+/// fitting the next slot are rejected. Each change sends both the raw value,
+/// made of the typed characters only, and the formatted value.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::masked_input::MaskedInput;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PhoneChanged(String, String),
+/// }
+///
+/// let phone = String::from("555");
+///
+/// let masked_input = MaskedInput::new("(###) ###-####", &phone, Message::PhoneChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MaskedInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: text_input::StyleSheet,
+{
+    /// The mask of the [`MaskedInput`].
+    mask: Mask,
+    /// The raw value given by the application.
+    value: String,
+    /// The text shown while nothing is typed.
+    placeholder: String,
+    /// The function producing the message when the value changes.
+    on_input: OnValue<'a, Message>,
+    /// The message produced when Enter is pressed.
+    on_submit: Option<Message>,
+    /// The id of the text input of the [`MaskedInput`].
+    id: Option<text_input::Id>,
+    width: Length,
+    padding: Padding,
+    text_size: f32,
+    font: Font,
+    #[allow(clippy::missing_docs_in_private_items)]
+    renderer: std::marker::PhantomData<(Theme, Renderer)>,
+}
+
+impl<'a, Message, Theme, Renderer> MaskedInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: text_input::StyleSheet,
+{
+    /// Creates a new [`MaskedInput`].
+    ///
+    /// It expects:
+    ///   * the mask, or its pattern
+    ///   * the current raw value
+    ///   * a function that will be called when the value changes. It receives
+    ///     the raw and the formatted values and must produce a `Message`.
+    pub fn new<F>(mask: impl Into<Mask>, value: &str, on_input: F) -> Self
+    where
+        F: 'a + Fn(String, String) -> Message,
+    {
+        let mask = mask.into();
+
+        Self {
+            placeholder: mask.template(PLACEHOLDER),
+            value: mask.extract(value),
+            mask,
+            on_input: Box::new(on_input),
+            on_submit: None,
+            id: None,
+            width: Length::Fill,
+            padding: settings::spaced(DEFAULT_PADDING).into(),
+            text_size: settings::scaled(16.0),
+            font: settings::font(),
+            renderer: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the text shown while nothing is typed. Defaults to the mask with
+    /// its slots written as underscores.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the message produced when Enter is pressed.
+    #[must_use]
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Sets the id of the text input of the [`MaskedInput`], to focus it with
+    /// the operations of text inputs.
+    #[must_use]
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`MaskedInput`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the [`MaskedInput`].
+    #[must_use]
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MaskedInput`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`MaskedInput`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Returns the text input editing the formatted value.
+    fn input(&self) -> TextInput<'_, String, Theme, Renderer> {
+        let input = TextInput::new(&self.placeholder, &self.mask.format(&self.value))
+            .on_input(|text| text)
+            .width(self.width)
+            .padding(self.padding)
+            .size(self.text_size)
+            .font(self.font);
+
+        match &self.id {
+            Some(id) => input.id(id.clone()),
+            None => input,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MaskedInput<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + text_input::StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.input() as &dyn Widget<String, Theme, Renderer>
+        )]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children[0].diff(&self.input() as &dyn Widget<String, Theme, Renderer>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        Widget::<String, Theme, Renderer>::layout(
+            &self.input(),
+            &mut tree.children[0],
+            renderer,
+            limits,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let input_state = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>();
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(Named::Enter),
+            ..
+        }) = event
+        {
+            if let Some(message) = self.on_submit.clone().filter(|_| input_state.is_focused()) {
+                shell.publish(message);
+                return event::Status::Captured;
+            }
+        }
+
+        let mut messages = Vec::new();
+        let mut input_shell = Shell::new(&mut messages);
+        let status = Widget::<String, Theme, Renderer>::on_event(
+            &mut self.input(),
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut input_shell,
+            viewport,
+        );
+
+        if input_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if input_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+        if let Some(request) = input_shell.redraw_request() {
+            shell.request_redraw(request);
+        }
+
+        let Some(text) = messages.pop() else {
+            return status;
+        };
+
+        // The caret stays after the same typed characters once the literals
+        // are inserted and the rejected keystrokes removed.
+        let input_state = tree.children[0]
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+        let caret = match input_state.cursor().state(&Value::new(&text)) {
+            cursor::State::Index(index) => index,
+            cursor::State::Selection { end, .. } => end,
+        };
+        let typed: String = text.chars().take(caret).collect();
+        let count = self.mask.extract(&typed).chars().count();
+        input_state.move_cursor_to(self.mask.position(count));
+
+        let value = self.mask.extract(&text);
+
+        if value != self.value {
+            self.value = value;
+            shell.publish((self.on_input)(
+                self.value.clone(),
+                self.mask.format(&self.value),
+            ));
+        }
+
+        shell.invalidate_layout();
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::<String, Theme, Renderer>::mouse_interaction(
+            &self.input(),
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        Widget::<String, Theme, Renderer>::draw(
+            &self.input(),
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let id = self.id.clone().map(widget::Id::from);
+        let input = tree.children[0]
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        operation.focusable(input, id.as_ref());
+        operation.text_input(input, id.as_ref());
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MaskedInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + text_input::StyleSheet,
+{
+    fn from(masked_input: MaskedInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(masked_input)
+    }
+}
@@ -144,6 +144,7 @@
 //!
 
 mod common;
+pub mod entry;
 mod flex;
 mod menu_bar;
 mod menu_bar_overlay;
@@ -151,5 +152,6 @@ mod menu_tree;
 
 pub use crate::style::menu_bar::{Appearance, StyleSheet};
 pub use common::{DrawPath, ScrollSpeed};
+pub use entry::{entries, Entry, Separator};
 pub use menu_bar::MenuBar;
 pub use menu_tree::{Item, Menu};
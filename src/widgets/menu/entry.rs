@@ -0,0 +1,624 @@
+//! Ready-made items of a [`Menu`]: labeled entries with an optional icon,
+//! keyboard shortcut and check mark, and separators.
+//!
+//! They are what the [`menu_bar!`](crate::menu_bar) and
+//! [`menu!`](crate::menu) macros build from their declarative syntax.
+
+use super::{Item, Menu};
+use crate::{
+    core::{
+        accessibility::{self, Action, Role},
+        icons::{Bootstrap, IconHandle, IconRenderer, BOOTSTRAP_FONT},
+    },
+    style::menu_bar::StyleSheet,
+};
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        text::{self, Paragraph, Text},
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch, Border, Color, Element, Event, Font, Length, Padding, Pixels, Point, Rectangle, Shadow,
+    Size,
+};
+
+/// The width of the [`Menu`]s created by [`entries`].
+const ENTRIES_WIDTH: f32 = 220.0;
+
+/// The distance between the [`Menu`]s created by [`entries`] and their
+/// parent item.
+const ENTRIES_OFFSET: f32 = 6.0;
+
+/// The space between the columns of an [`Entry`].
+const GAP: f32 = 8.0;
+
+/// The height of a [`Separator`].
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+/// The opacity of the label of a disabled [`Entry`].
+const DISABLED_ALPHA: f32 = 0.5;
+
+/// The opacity of the shortcut of an [`Entry`].
+const SHORTCUT_ALPHA: f32 = 0.6;
+
+/// The opacity of the line of a [`Separator`].
+const SEPARATOR_ALPHA: f32 = 0.2;
+
+/// Creates a [`Menu`] sized for [`Entry`]s, opening next to its parent item.
+pub fn entries<Message, Theme, Renderer>(
+    items: Vec<Item<'_, Message, Theme, Renderer>>,
+) -> Menu<'_, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: renderer::Renderer,
+{
+    Menu::new(items)
+        .max_width(ENTRIES_WIDTH)
+        .offset(ENTRIES_OFFSET)
+}
+
+/// The state of an [`Entry`].
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the [`Entry`] is being pressed.
+    is_pressed: bool,
+}
+
+/// A labeled entry of a [`Menu`], publishing a message when it is clicked.
+///
+/// An entry shows an optional check mark or icon before its label and its
+/// keyboard shortcut after it. An entry opening a submenu shows an arrow instead of a
+/// shortcut. An entry without a message is disabled.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{menu::Entry, Bootstrap};
+/// #
+/// #[derive(Clone, Debug)]
+/// enum Message {
+///     Save,
+///     ToggleWrap,
+/// }
+///
+/// let save = Entry::new("Save").on_press(Message::Save).shortcut("Ctrl+S");
+/// let wrap = Entry::new("Word wrap").on_press(Message::ToggleWrap).checked(true);
+/// let open = Entry::new("Open").icon(Bootstrap::Folder);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Entry<Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The label of the [`Entry`].
+    label: String,
+    /// The message published when the [`Entry`] is clicked.
+    on_press: Option<Message>,
+    /// The keyboard shortcut shown after the label.
+    shortcut: Option<String>,
+    /// Whether the check mark is shown before the label, if the [`Entry`]
+    /// can be checked.
+    checked: Option<bool>,
+    /// The icon shown before the label when the check mark is not.
+    icon: Option<IconHandle>,
+    /// Whether the [`Entry`] opens a submenu.
+    submenu: bool,
+    /// Whether the [`Entry`] is a root of a menu bar.
+    root: bool,
+    /// The size of the text of the [`Entry`].
+    text_size: Option<f32>,
+    /// The font of the label and the shortcut.
+    font: Option<Font>,
+    /// The padding around the content of the [`Entry`].
+    padding: Padding,
+    /// The style of the [`Entry`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Message, Theme> Entry<Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new disabled [`Entry`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            on_press: None,
+            shortcut: None,
+            checked: None,
+            icon: None,
+            submenu: false,
+            root: false,
+            text_size: None,
+            font: None,
+            padding: Padding::from([4, 8]),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Creates a new [`Entry`] for a root of a [`MenuBar`](super::MenuBar),
+    /// as wide as its label.
+    pub fn root(label: impl Into<String>) -> Self {
+        Self {
+            root: true,
+            submenu: true,
+            ..Self::new(label)
+        }
+    }
+
+    /// Sets the message published when the [`Entry`] is clicked, enabling
+    /// it.
+    #[must_use]
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the keyboard shortcut shown after the label of the [`Entry`].
+    ///
+    /// The shortcut is only shown, handling it is up to the application.
+    #[must_use]
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Sets whether the check mark before the label of the [`Entry`] is
+    /// shown.
+    #[must_use]
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Sets the icon shown before the label of the [`Entry`], in place of the
+    /// check mark when it is not shown.
+    ///
+    /// A glyph without its own font is drawn with the font of the label.
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<IconHandle>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets whether the [`Entry`] opens a submenu, showing an arrow after its
+    /// label. An [`Entry`] opening a submenu is never disabled.
+    #[must_use]
+    pub fn submenu(mut self, submenu: bool) -> Self {
+        self.submenu = submenu;
+        self
+    }
+
+    /// Sets the size of the text of the [`Entry`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the label and the shortcut of the [`Entry`].
+    #[must_use]
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the padding around the content of the [`Entry`].
+    #[must_use]
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`Entry`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns whether the [`Entry`] reacts to the cursor.
+    fn is_enabled(&self) -> bool {
+        self.on_press.is_some() || self.submenu
+    }
+
+    /// Returns the text of the [`Entry`] with the given content and font.
+    fn text<'b, Renderer>(
+        &self,
+        renderer: &Renderer,
+        content: &'b str,
+        font: Option<Font>,
+        bounds: Size,
+        horizontal_alignment: Horizontal,
+    ) -> Text<'b, Font>
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        Text {
+            content,
+            bounds,
+            size: Pixels(self.text_size.unwrap_or_else(|| renderer.default_size().0)),
+            line_height: text::LineHeight::default(),
+            font: font
+                .or(self.font)
+                .unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        }
+    }
+
+    /// Returns the width of the given content drawn as a text of the
+    /// [`Entry`].
+    fn measure<Renderer>(&self, renderer: &Renderer, content: &str, font: Option<Font>) -> Size
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        Renderer::Paragraph::with_text(self.text(
+            renderer,
+            content,
+            font,
+            Size::INFINITY,
+            Horizontal::Left,
+        ))
+        .min_bounds()
+    }
+
+    /// Returns the width of the column of the check mark, of the icon and of
+    /// the arrow, the size of the text.
+    fn icon_width<Renderer>(&self, renderer: &Renderer) -> f32
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        self.text_size.unwrap_or_else(|| renderer.default_size().0)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Entry<Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+    Renderer: IconRenderer,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(
+            if self.root {
+                Length::Shrink
+            } else {
+                Length::Fill
+            },
+            Length::Shrink,
+        )
+    }
+
+    fn layout(&self, _tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let label = self.measure(renderer, &self.label, None);
+        let mut width = label.width;
+
+        if !self.root {
+            let icon = self.icon_width(renderer);
+            width += icon + GAP;
+
+            if self.submenu {
+                width += GAP + icon;
+            } else if let Some(shortcut) = &self.shortcut {
+                width += GAP * 2.0 + self.measure(renderer, shortcut, None).width;
+            }
+        }
+
+        let size = limits.resolve(
+            Widget::<Message, Theme, Renderer>::size(self).width,
+            Length::Shrink,
+            Size::new(
+                width + self.padding.horizontal(),
+                label.height + self.padding.vertical(),
+            ),
+        );
+
+        Node::new(size)
+    }
+
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let mut node = accessibility::Node::new(Role::MenuItem)
+            .name(&self.label)
+            .disabled(self.on_press.is_none() && !self.submenu);
+
+        if let Some(checked) = self.checked {
+            node = node.checked(checked);
+        }
+        if self.on_press.is_some() {
+            node = node.action(Action::Click);
+        }
+        if self.submenu {
+            node = node.action(Action::Expand);
+        }
+
+        operation.custom(&mut node, None);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(on_press) = &self.on_press else {
+            return event::Status::Ignored;
+        };
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                state.is_pressed = true;
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if state.is_pressed =>
+            {
+                state.is_pressed = false;
+
+                if cursor.is_over(layout.bounds()) {
+                    shell.publish(on_press.clone());
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => state.is_pressed = false,
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_press.is_some() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if self.is_enabled() && cursor.is_over(bounds) {
+            let appearance = theme.appearance(&self.style);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: appearance.path_border,
+                    shadow: Shadow::default(),
+                },
+                appearance.path,
+            );
+        }
+
+        let color = if self.is_enabled() {
+            style.text_color
+        } else {
+            Color {
+                a: style.text_color.a * DISABLED_ALPHA,
+                ..style.text_color
+            }
+        };
+        let content = Rectangle {
+            x: bounds.x + self.padding.left,
+            y: bounds.y + self.padding.top,
+            width: bounds.width - self.padding.horizontal(),
+            height: bounds.height - self.padding.vertical(),
+        };
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let icon = if self.root {
+            0.0
+        } else {
+            self.icon_width(renderer)
+        };
+
+        if self.checked == Some(true) {
+            let check = char::from(Bootstrap::Check).to_string();
+            renderer.fill_text(
+                self.text(
+                    renderer,
+                    &check,
+                    Some(BOOTSTRAP_FONT),
+                    Size::new(icon, content.height),
+                    Horizontal::Center,
+                ),
+                Point::new(content.x + icon / 2.0, content.center_y()),
+                color,
+                clip,
+            );
+        } else if let Some(handle) = self.icon.as_ref().filter(|_| !self.root) {
+            handle.draw(
+                renderer,
+                Rectangle {
+                    width: icon,
+                    ..content
+                },
+                icon,
+                self.font.unwrap_or_else(|| renderer.default_font()),
+                color,
+            );
+        }
+
+        let label_x = if self.root {
+            content.x
+        } else {
+            content.x + icon + GAP
+        };
+        renderer.fill_text(
+            self.text(
+                renderer,
+                &self.label,
+                None,
+                Size::new(content.x + content.width - label_x, content.height),
+                Horizontal::Left,
+            ),
+            Point::new(label_x, content.center_y()),
+            color,
+            clip,
+        );
+
+        if self.root {
+            return;
+        }
+
+        if self.submenu {
+            let arrow = char::from(Bootstrap::CaretRightFill).to_string();
+            renderer.fill_text(
+                self.text(
+                    renderer,
+                    &arrow,
+                    Some(BOOTSTRAP_FONT),
+                    Size::new(icon, content.height),
+                    Horizontal::Center,
+                ),
+                Point::new(content.x + content.width - icon / 2.0, content.center_y()),
+                color,
+                clip,
+            );
+        } else if let Some(shortcut) = &self.shortcut {
+            renderer.fill_text(
+                self.text(
+                    renderer,
+                    shortcut,
+                    None,
+                    Size::new(content.width, content.height),
+                    Horizontal::Right,
+                ),
+                Point::new(content.x + content.width, content.center_y()),
+                Color {
+                    a: color.a * SHORTCUT_ALPHA,
+                    ..color
+                },
+                clip,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Entry<Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: 'a + StyleSheet,
+    Renderer: IconRenderer,
+{
+    fn from(entry: Entry<Message, Theme>) -> Self {
+        Element::new(entry)
+    }
+}
+
+/// A horizontal line separating groups of items of a [`Menu`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Separator;
+
+impl Separator {
+    /// Creates a new [`Separator`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Separator
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fixed(SEPARATOR_HEIGHT))
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.resolve(Length::Fill, Length::Fixed(SEPARATOR_HEIGHT), Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + GAP,
+                    y: bounds.center_y().floor(),
+                    width: (bounds.width - GAP * 2.0).max(0.0),
+                    height: 1.0,
+                },
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            Color {
+                a: style.text_color.a * SEPARATOR_ALPHA,
+                ..style.text_color
+            },
+        );
+    }
+}
+
+impl<Message, Theme, Renderer> From<Separator> for Element<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn from(separator: Separator) -> Self {
+        Element::new(separator)
+    }
+}
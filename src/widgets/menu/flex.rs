@@ -65,8 +65,11 @@ impl Axis {
 /// Computes the flex layout with the given axis and limits, applying spacing,
 /// padding and alignment to the items as needed.
 ///
+/// The element of an item and its tree are given by `element_of` and
+/// `tree_of`, so that the items are laid out without collecting them.
+///
 /// It returns a new layout [`Node`].
-pub fn resolve<'a, E, T, Message, Theme, Renderer>(
+pub fn resolve<'a, I, Message, Theme, Renderer>(
     axis: Axis,
     renderer: &Renderer,
     limits: &Limits,
@@ -75,13 +78,12 @@ pub fn resolve<'a, E, T, Message, Theme, Renderer>(
     padding: Padding,
     spacing: f32,
     align_items: Alignment,
-    items: &[E],
-    trees: &mut [T],
+    items: &[I],
+    trees: &mut [widget::Tree],
+    element_of: impl Fn(&I) -> &Element<'a, Message, Theme, Renderer>,
+    tree_of: impl Fn(&mut widget::Tree) -> &mut widget::Tree,
 ) -> Node
 where
-    E: std::borrow::Borrow<Element<'a, Message, Theme, Renderer>>,
-    T: std::borrow::BorrowMut<widget::Tree>,
-
     Renderer: renderer::Renderer,
 {
     let limits = limits.width(width).height(height).shrink(padding);
@@ -107,7 +109,7 @@ where
 
     for (i, (child, tree)) in items.iter().zip(trees.iter_mut()).enumerate() {
         let (fill_main_factor, fill_cross_factor) = {
-            let size = child.borrow().as_widget().size();
+            let size = element_of(child).as_widget().size();
 
             axis.pack(size.width.fill_factor(), size.height.fill_factor())
         };
@@ -119,10 +121,9 @@ where
                 let child_limits = Limits::new(Size::ZERO, Size::new(max_width, max_height));
 
                 let layout =
-                    child
-                        .borrow()
+                    element_of(child)
                         .as_widget()
-                        .layout(tree.borrow_mut(), renderer, &child_limits);
+                        .layout(tree_of(tree), renderer, &child_limits);
                 let size = layout.size();
 
                 available -= axis.main(size);
@@ -137,7 +138,7 @@ where
 
     for (i, (child, tree)) in items.iter().zip(trees.iter_mut()).enumerate() {
         let (fill_main_factor, fill_cross_factor) = {
-            let size = child.borrow().as_widget().size();
+            let size = element_of(child).as_widget().size();
 
             axis.pack(size.width.fill_factor(), size.height.fill_factor())
         };
@@ -148,10 +149,9 @@ where
             let child_limits = Limits::new(Size::ZERO, Size::new(max_width, max_height));
 
             let layout =
-                child
-                    .borrow()
+                element_of(child)
                     .as_widget()
-                    .layout(tree.borrow_mut(), renderer, &child_limits);
+                    .layout(tree_of(tree), renderer, &child_limits);
             let size = layout.size();
 
             available -= axis.main(size);
@@ -174,7 +174,7 @@ where
 
     for (i, (child, tree)) in items.iter().zip(trees).enumerate() {
         let (fill_main_factor, fill_cross_factor) = {
-            let size = child.borrow().as_widget().size();
+            let size = element_of(child).as_widget().size();
 
             axis.pack(size.width.fill_factor(), size.height.fill_factor())
         };
@@ -203,10 +203,9 @@ where
             );
 
             let layout =
-                child
-                    .borrow()
+                element_of(child)
                     .as_widget()
-                    .layout(tree.borrow_mut(), renderer, &child_limits);
+                    .layout(tree_of(tree), renderer, &child_limits);
             cross = cross.max(axis.cross(layout.size()));
 
             nodes[i] = layout;
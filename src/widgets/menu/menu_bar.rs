@@ -9,20 +9,70 @@ use iced::{
     advanced::{
         layout::{Limits, Node},
         mouse, overlay, renderer,
-        widget::{tree, Tree},
+        widget::{self, operation, tree, Operation, Tree},
         Clipboard, Layout, Shell, Widget,
     },
-    alignment, event, Element, Event, Length, Padding, Rectangle, Size,
+    alignment, event,
+    keyboard::{self, key::Named},
+    Color, Element, Event, Length, Padding, Point, Rectangle, Size,
 };
 
 use super::{common::*, flex, menu_bar_overlay::MenuBarOverlay, menu_tree::*};
+use crate::core::{
+    accessibility::{self, Action, Role},
+    hit_test,
+};
 use crate::style::menu_bar::*;
 
 #[derive(Default)]
+#[allow(clippy::struct_excessive_bools)]
 pub(super) struct MenuBarState {
     pub(super) active_root: Index,
     pub(super) open: bool,
     pub(super) is_pressed: bool,
+    /// Whether the menu bar has the keyboard focus, whether a menu is open or
+    /// not.
+    pub(super) focused: bool,
+    /// Whether the focus ring is shown, when the menu bar was focused or
+    /// opened with the keyboard.
+    pub(super) focus_visible: bool,
+    /// The bounds of the roots, relative to the menu bar and ordered from
+    /// left to right, to find the root under the cursor.
+    pub(super) root_bounds: Vec<Rectangle>,
+    /// The bounds of the bar and of the menus above the one handling an
+    /// event, kept to reuse their allocation.
+    pub(super) prev_bounds: Vec<Rectangle>,
+}
+impl MenuBarState {
+    /// Returns the index of the root under the cursor, in the menu bar of the
+    /// given bounds.
+    fn root_at(&self, bar_bounds: Rectangle, cursor: mouse::Cursor) -> Option<usize> {
+        let position = cursor.position()? - (bar_bounds.position() - Point::ORIGIN);
+
+        hit_test::find_x(&self.root_bounds, |bounds| *bounds, position)
+    }
+}
+impl operation::Focusable for MenuBarState {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Focuses the first root, showing the focus ring without opening its
+    /// menu, which is left to Enter, Space or the down arrow.
+    fn focus(&mut self) {
+        self.focused = true;
+        self.focus_visible = true;
+        if self.active_root.is_none() {
+            self.active_root = Some(0);
+        }
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = false;
+        self.open = false;
+        self.active_root = None;
+        self.focus_visible = false;
+    }
 }
 
 /// menu bar
@@ -40,6 +90,7 @@ where
     check_bounds_width: f32,
     draw_path: DrawPath,
     scroll_speed: ScrollSpeed,
+    id: Option<widget::Id>,
     style: Theme::Style,
 }
 impl<'a, Message, Theme, Renderer> MenuBar<'a, Message, Theme, Renderer>
@@ -67,6 +118,7 @@ where
                 line: 60.0,
                 pixel: 1.0,
             },
+            id: None,
             style: Theme::Style::default(),
         }
     }
@@ -113,6 +165,16 @@ where
         self
     }
 
+    /// Sets the id of the [`MenuBar`], to focus it with an operation.
+    ///
+    /// Focusing the [`MenuBar`] shows a focus ring on its first root without
+    /// opening its menu. The arrow keys then move between the roots, Enter,
+    /// Space or the down arrow open the menu and Escape closes it.
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the style variant of this [`MenuBar`].
     pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
         self.style = style.into();
@@ -149,7 +211,7 @@ where
 
     /// tree: Tree{bar_state, \[item_tree...]}
     fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
-        flex::resolve(
+        let node = flex::resolve(
             flex::Axis::Horizontal,
             renderer,
             limits,
@@ -158,13 +220,18 @@ where
             self.padding,
             self.spacing,
             alignment::Alignment::Center,
-            &self.roots.iter().map(|item| &item.item).collect::<Vec<_>>(),
-            &mut tree
-                .children
-                .iter_mut()
-                .map(|tree| &mut tree.children[0])
-                .collect::<Vec<_>>(),
-        )
+            &self.roots,
+            &mut tree.children,
+            |item| &item.item,
+            |tree| &mut tree.children[0],
+        );
+
+        let bar = tree.state.downcast_mut::<MenuBarState>();
+        bar.root_bounds.clear();
+        bar.root_bounds
+            .extend(node.children().iter().map(Node::bounds));
+
+        node
     }
 
     fn on_event(
@@ -208,18 +275,18 @@ where
                     bar.is_pressed = true;
                     Captured
                 } else {
+                    bar.focused = false;
                     Ignored
                 }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
                 if cursor.is_over(bar_bounds) && bar.is_pressed {
                     bar.open = true;
+                    bar.focused = true;
                     bar.is_pressed = false;
-                    for (i, l) in layout.children().enumerate() {
-                        if cursor.is_over(l.bounds()) {
-                            bar.active_root = Some(i);
-                            break;
-                        }
+                    bar.focus_visible = false;
+                    if let Some(root) = bar.root_at(bar_bounds, cursor) {
+                        bar.active_root = Some(root);
                     }
                     Captured
                 } else {
@@ -229,11 +296,8 @@ where
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 if bar.open {
                     if cursor.is_over(bar_bounds) {
-                        for (i, l) in layout.children().enumerate() {
-                            if cursor.is_over(l.bounds()) {
-                                bar.active_root = Some(i);
-                                break;
-                            }
+                        if let Some(root) = bar.root_at(bar_bounds, cursor) {
+                            bar.active_root = Some(root);
                         }
                     } else {
                         bar.open = false;
@@ -243,6 +307,39 @@ where
                     Ignored
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if bar.open || bar.focused => {
+                let count = self.roots.len();
+
+                match (key, bar.active_root) {
+                    (Named::Enter | Named::Space | Named::ArrowDown, Some(_)) if !bar.open => {
+                        bar.open = true;
+                        bar.focus_visible = true;
+                        Captured
+                    }
+                    (Named::ArrowRight, Some(active)) => {
+                        bar.active_root = Some((active + 1) % count);
+                        bar.focus_visible = true;
+                        Captured
+                    }
+                    (Named::ArrowLeft, Some(active)) => {
+                        bar.active_root = Some((active + count - 1) % count);
+                        bar.focus_visible = true;
+                        Captured
+                    }
+                    (Named::Escape, _) if bar.open => {
+                        // The focus stays on the root of the closed menu.
+                        bar.open = false;
+                        if !bar.focused {
+                            bar.active_root = None;
+                        }
+                        Captured
+                    }
+                    _ => Ignored,
+                }
+            }
             _ => Ignored,
         }
         .merge(status)
@@ -321,6 +418,59 @@ where
             .for_each(|((item, tree), layout)| {
                 item.draw(tree, renderer, theme, style, layout, cursor, viewport);
             });
+
+        if let Some(active_layout) = state
+            .active_root
+            .filter(|_| (state.open || state.focused) && state.focus_visible)
+            .and_then(|active| layout.children().nth(active))
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: active_layout.bounds(),
+                    border: theme.focused(&self.style).path_border,
+                    ..Default::default()
+                },
+                Color::TRANSPARENT,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if self.roots.is_empty() {
+            return;
+        }
+
+        let roots = accessibility::gather(|operation| {
+            for ((root, tree), layout) in self
+                .roots
+                .iter()
+                .zip(tree.children.iter_mut())
+                .zip(layout.children())
+            {
+                root.item
+                    .as_widget()
+                    .operate(&mut tree.children[0], layout, renderer, operation);
+            }
+        });
+        let state = tree.state.downcast_mut::<MenuBarState>();
+        let mut node = accessibility::Node::new(Role::MenuBar)
+            .expanded(state.open)
+            .action(Action::Focus)
+            .action(if state.open {
+                Action::Collapse
+            } else {
+                Action::Expand
+            })
+            .children(roots);
+
+        operation.focusable(state, self.id.as_ref());
+        operation.custom(&mut node, self.id.as_ref());
     }
 
     fn overlay<'b>(
@@ -332,17 +482,13 @@ where
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
         let state = tree.state.downcast_mut::<MenuBarState>();
 
-        let init_bar_bounds = layout.bounds();
-        let init_root_bounds = layout.children().map(|l| l.bounds()).collect();
-
         if state.open {
             Some(
                 MenuBarOverlay {
                     translation,
                     tree,
                     roots: &mut self.roots,
-                    init_bar_bounds,
-                    init_root_bounds,
+                    init_bar_bounds: layout.bounds(),
                     check_bounds_width: self.check_bounds_width,
                     draw_path: &self.draw_path,
                     scroll_speed: self.scroll_speed,
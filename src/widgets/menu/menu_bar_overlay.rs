@@ -30,7 +30,6 @@ where
 
     pub(super) roots: &'b mut [Item<'a, Message, Theme, Renderer>],
     pub(super) init_bar_bounds: Rectangle,
-    pub(super) init_root_bounds: Vec<Rectangle>,
     pub(super) check_bounds_width: f32,
     pub(super) draw_path: &'b DrawPath,
     pub(super) scroll_speed: ScrollSpeed,
@@ -56,15 +55,16 @@ where
 
         let bar = self.tree.state.downcast_ref::<MenuBarState>();
         let bar_bounds = self.init_bar_bounds;
+        let bar_offset = bar_bounds.position() - Point::ORIGIN;
 
         let bar_node = Node::with_children(bar_bounds.size(), [].into())
             .move_to(bar_bounds.position() + translation);
 
         let roots_node = Node::with_children(
             Size::ZERO,
-            self.init_root_bounds
+            bar.root_bounds
                 .iter()
-                .map(|r| Node::new(r.size()).move_to(r.position()))
+                .map(|r| Node::new(r.size()).move_to(r.position() + bar_offset))
                 .collect(),
         )
         .translate(translation);
@@ -75,7 +75,7 @@ where
 
         let active_root = &mut self.roots[active];
         let active_tree = &mut self.tree.children[active]; // item_tree: Tree{ stateless, [ widget_tree, menu_tree ] }
-        let parent_bounds = self.init_root_bounds[active] + translation;
+        let parent_bounds = bar.root_bounds[active] + bar_offset + translation;
 
         fn rec<Message, Theme: StyleSheet, Renderer: renderer::Renderer>(
             renderer: &Renderer,
@@ -188,6 +188,11 @@ where
     ) -> event::Status {
         use event::Status::*;
 
+        // The menus have no keyboard handling, the keys are left to the bar.
+        if let Event::Keyboard(_) = event {
+            return Ignored;
+        }
+
         let viewport = layout.bounds();
         let mut lc = layout.children();
         let bar_bounds = lc.next().unwrap().bounds();
@@ -207,7 +212,8 @@ where
 
         let active_root = &mut self.roots[active];
         let active_tree = &mut self.tree.children[active];
-        let mut prev_bounds_list = vec![bar_bounds];
+        bar.prev_bounds.clear();
+        bar.prev_bounds.push(bar_bounds);
 
         #[rustfmt::skip]
         fn rec<'a, 'b, Message, Theme: StyleSheet, Renderer: renderer::Renderer>(
@@ -319,7 +325,7 @@ where
             shell,
             parent_bounds,
             &viewport,
-            &mut prev_bounds_list,
+            &mut bar.prev_bounds,
             &mut bar.active_root,
             self.scroll_speed,
         );
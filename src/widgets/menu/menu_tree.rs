@@ -12,6 +12,7 @@
 
 use super::common::*;
 use super::flex;
+use crate::core::{hit_test, layout_cache::LayoutCache};
 use iced::{
     advanced::{
         layout::{Layout, Limits, Node},
@@ -62,6 +63,8 @@ pub(super) struct MenuState {
     pub(super) active: Index,
     pub(super) slice: MenuSlice,
     pub(super) pressed: bool,
+    /// The layout of the items, reused until the items change.
+    items_layout: LayoutCache,
 }
 impl Default for MenuState {
     fn default() -> Self {
@@ -75,6 +78,7 @@ impl Default for MenuState {
                 upper_bound_rel: f32::MAX,
             },
             pressed: false,
+            items_layout: LayoutCache::default(),
         }
     }
 }
@@ -169,6 +173,10 @@ where
 
     /// tree: Tree{menu_state, \[item_tree...]}
     pub(super) fn diff(&self, tree: &mut Tree) {
+        tree.state
+            .downcast_mut::<MenuState>()
+            .items_layout
+            .invalidate();
         tree.diff_children_custom(&self.items, |tree, item| item.diff(tree), Item::tree);
     }
 
@@ -187,22 +195,26 @@ where
     ) -> (Node, (Direction, Direction)) {
         let limits = limits.max_width(self.max_width);
 
-        let items_node = flex::resolve(
-            flex::Axis::Vertical,
-            renderer,
-            &limits,
-            self.width,
-            self.height,
-            Padding::ZERO,
-            self.spacing,
-            alignment::Alignment::Center,
-            &self.items.iter().map(|i| &i.item).collect::<Vec<_>>(),
-            &mut tree
-                .children
-                .iter_mut()
-                .map(|t| &mut t.children[0])
-                .collect::<Vec<_>>(),
-        );
+        let Tree {
+            state, children, ..
+        } = tree;
+        let menu_state = state.downcast_mut::<MenuState>();
+        let items_node = menu_state.items_layout.get_or_layout(&limits, || {
+            flex::resolve(
+                flex::Axis::Vertical,
+                renderer,
+                &limits,
+                self.width,
+                self.height,
+                Padding::ZERO,
+                self.spacing,
+                alignment::Alignment::Center,
+                &self.items,
+                children,
+                |item| &item.item,
+                |tree| &mut tree.children[0],
+            )
+        });
 
         let aod = Aod::new(
             self.axis,
@@ -228,11 +240,9 @@ where
         let children_bounds = Rectangle::new(children_position, children_size);
         let check_bounds = pad_rectangle(children_bounds, [check_bounds_width; 4].into());
 
-        let menu_state = tree.state.downcast_mut::<MenuState>();
-
         // calc slice
         let slice = MenuSlice::new(
-            &items_node,
+            items_node,
             children_position - Point::ORIGIN,
             viewport.size(),
             menu_state.scroll_offset,
@@ -342,6 +352,10 @@ where
             })
             .fold(Ignored, event::Status::merge);
 
+        if shell.is_layout_invalid() {
+            menu_state.items_layout.invalidate();
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if cursor.is_over(prescroll) {
@@ -545,18 +559,32 @@ where
         // let check_bounds = lc.next().unwrap().bounds();
 
         let menu_state = tree.state.downcast_mut::<MenuState>();
-        let slice = &menu_state.slice;
         menu_state.active = None;
 
-        for (i, (item, layout)) in self.items[slice.start_index..=slice.end_index]
-            .iter()
-            .zip(slice_layout.children())
-            .enumerate()
-        {
-            if item.menu.is_some() && cursor.is_over(layout.bounds()) {
-                menu_state.active = Some(i + slice.start_index);
-                return event::Status::Captured;
-            }
+        // The items are shown in the slice at their position in the menu.
+        let index = cursor.position().and_then(|position| {
+            let position = position - (slice_layout.bounds().position() - Point::ORIGIN);
+            let items = menu_state.items_layout.node()?.children();
+
+            hit_test::find_y(items, Node::bounds, position)
+        });
+        let slice = &menu_state.slice;
+
+        let Some(index) =
+            index.filter(|index| (slice.start_index..=slice.end_index).contains(index))
+        else {
+            return event::Status::Ignored;
+        };
+
+        // The first and last items of the slice may be clipped.
+        let is_over = slice_layout
+            .children()
+            .nth(index - slice.start_index)
+            .is_some_and(|layout| cursor.is_over(layout.bounds()));
+
+        if self.items[index].menu.is_some() && is_over {
+            menu_state.active = Some(index);
+            return event::Status::Captured;
         }
         event::Status::Ignored
     }
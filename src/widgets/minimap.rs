@@ -0,0 +1,404 @@
+//! Show a scaled-down overview of scrollable content, with a rectangle
+//! dragged to scroll it.
+//!
+//! *This API requires the following crate features to be activated: `minimap`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor, ScrollDelta},
+    touch,
+    widget::scrollable::AbsoluteOffset,
+    Border, Element, Event, Length, Point, Rectangle, Shadow, Size, Transformation, Vector,
+};
+
+pub use crate::style::minimap::{Appearance, StyleSheet};
+
+/// The pixels scrolled by a line of the mouse wheel.
+const LINE_HEIGHT: f32 = 60.0;
+
+/// A scaled-down overview of the content of a scrollable, showing the
+/// visible part of the content with a rectangle that can be dragged to
+/// scroll it.
+///
+/// The minimap is given its own copy of the content, laid out with the
+/// width of the visible part and drawn scaled down to fit the minimap. Like
+/// the content of a scrollable, it should not fill the height given to it.
+/// The content of the minimap does not react to the cursor.
+///
+/// The application keeps the visible part of the content from the
+/// [`on_scroll`](iced::widget::Scrollable::on_scroll) messages of the
+/// scrollable, and scrolls it with
+/// [`scroll_to`](iced::widget::scrollable::scroll_to) when the minimap
+/// produces a new offset.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::scrollable::{self, AbsoluteOffset, Viewport};
+/// # use iced::Rectangle;
+/// # use iced_aw::minimap::Minimap;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Scrolled(Viewport),
+///     ScrollTo(AbsoluteOffset),
+/// }
+///
+/// // In `update`, `Message::Scrolled` keeps the visible part:
+/// // let offset = viewport.absolute_offset();
+/// // self.visible = Rectangle::new(Point::new(offset.x, offset.y), viewport.bounds().size());
+/// // and `Message::ScrollTo` returns `scrollable::scroll_to(ID.clone(), offset)`.
+///
+/// let minimap = Minimap::new(document_view(), visible, Message::ScrollTo);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Minimap<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The copy of the content shown scaled down.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The visible part of the content, in the coordinates of the content.
+    viewport: Rectangle,
+    /// The function producing the message with the new scroll offset.
+    on_scroll: Box<dyn Fn(AbsoluteOffset) -> Message + 'a>,
+    width: Length,
+    height: Length,
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`Minimap`].
+#[derive(Debug, Default)]
+struct State {
+    /// The layout of the content, at its full size.
+    content: Node,
+    /// The distance between the top left corner of the viewport rectangle and
+    /// the cursor dragging it.
+    grab: Option<Vector>,
+}
+
+impl<'a, Message, Theme, Renderer> Minimap<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Minimap`] of the `content` whose `viewport` part is
+    /// visible, producing the message of `on_scroll` with the new scroll
+    /// offset when the viewport rectangle is moved.
+    pub fn new<C, F>(content: C, viewport: Rectangle, on_scroll: F) -> Self
+    where
+        C: Into<Element<'a, Message, Theme, Renderer>>,
+        F: 'a + Fn(AbsoluteOffset) -> Message,
+    {
+        Self {
+            content: content.into(),
+            viewport,
+            on_scroll: Box::new(on_scroll),
+            width: Length::Fixed(120.0),
+            height: Length::Fill,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`Minimap`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Minimap`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`Minimap`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the scale of the content and its top left corner in the
+    /// given bounds, where it is fitted and centered horizontally.
+    fn placement(content: Size, bounds: Rectangle) -> (f32, Point) {
+        let scale = if content.width > 0.0 && content.height > 0.0 {
+            (bounds.width / content.width).min(bounds.height / content.height)
+        } else {
+            1.0
+        };
+
+        (
+            scale,
+            Point::new(
+                bounds.x + (bounds.width - content.width * scale) / 2.0,
+                bounds.y,
+            ),
+        )
+    }
+
+    /// Returns the viewport rectangle in the given bounds.
+    fn viewport_bounds(&self, content: Size, bounds: Rectangle) -> Rectangle {
+        let (scale, origin) = Self::placement(content, bounds);
+
+        Rectangle::new(
+            Point::new(
+                origin.x + self.viewport.x * scale,
+                origin.y + self.viewport.y * scale,
+            ),
+            Size::new(
+                self.viewport.width.min(content.width) * scale,
+                self.viewport.height.min(content.height) * scale,
+            ),
+        )
+    }
+
+    /// Returns the scroll offset of the content, clamped to its size.
+    fn clamp(&self, content: Size, x: f32, y: f32) -> AbsoluteOffset {
+        AbsoluteOffset {
+            x: x.clamp(0.0, (content.width - self.viewport.width).max(0.0)),
+            y: y.clamp(0.0, (content.height - self.viewport.height).max(0.0)),
+        }
+    }
+
+    /// Returns the scroll offset placing the top left corner of the viewport
+    /// rectangle at `position`.
+    fn offset_at(&self, content: Size, bounds: Rectangle, position: Point) -> AbsoluteOffset {
+        let (scale, origin) = Self::placement(content, bounds);
+
+        self.clamp(
+            content,
+            (position.x - origin.x) / scale,
+            (position.y - origin.y) / scale,
+        )
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Minimap<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &Limits::new(Size::ZERO, Size::new(self.viewport.width, f32::INFINITY)),
+        );
+        tree.state.downcast_mut::<State>().content = content;
+
+        Node::new(limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::ZERO,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+        let content = state.content.size();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+                let rectangle = self.viewport_bounds(content, bounds);
+
+                // Pressing outside of the rectangle centers it on the cursor.
+                let grab = if rectangle.contains(position) {
+                    position - rectangle.position()
+                } else {
+                    Vector::new(rectangle.width / 2.0, rectangle.height / 2.0)
+                };
+                state.grab = Some(grab);
+                shell.publish((self.on_scroll)(self.offset_at(
+                    content,
+                    bounds,
+                    position - grab,
+                )));
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let Some(grab) = state.grab else {
+                    return event::Status::Ignored;
+                };
+                shell.publish((self.on_scroll)(self.offset_at(
+                    content,
+                    bounds,
+                    position - grab,
+                )));
+
+                event::Status::Captured
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if state.grab.take().is_some() {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let (x, y) = match delta {
+                    ScrollDelta::Lines { x, y } => (x * LINE_HEIGHT, y * LINE_HEIGHT),
+                    ScrollDelta::Pixels { x, y } => (x, y),
+                };
+                shell.publish((self.on_scroll)(self.clamp(
+                    content,
+                    self.viewport.x - x,
+                    self.viewport.y - y,
+                )));
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.grab.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(self.viewport_bounds(state.content.size(), bounds)) {
+            mouse::Interaction::Grab
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let content = state.content.size();
+        let rectangle = self.viewport_bounds(content, bounds);
+        let appearance = if state.grab.is_some() || cursor.is_over(rectangle) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: appearance.border_radius.into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        let (scale, origin) = Self::placement(content, bounds);
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_transformation(
+                Transformation::translate(origin.x, origin.y) * Transformation::scale(scale),
+                |renderer| {
+                    self.content.as_widget().draw(
+                        &tree.children[0],
+                        renderer,
+                        theme,
+                        style,
+                        Layout::new(&state.content),
+                        Cursor::Unavailable,
+                        &Rectangle::new(Point::ORIGIN, content),
+                    );
+                },
+            );
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: rectangle,
+                    border: Border {
+                        radius: appearance.border_radius.into(),
+                        width: appearance.viewport_border_width,
+                        color: appearance.viewport_border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.viewport_background,
+            );
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Minimap<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(minimap: Minimap<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(minimap)
+    }
+}
@@ -4,26 +4,45 @@
 
 use super::overlay::modal::ModalOverlay;
 
+use crate::core::{
+    accessibility::{self, Action, Role},
+    animation::{self, Easing, Timeline},
+};
+
 use iced::{
     advanced::{
         layout::{Limits, Node},
         overlay::{self, Group},
         renderer,
-        widget::{Operation, Tree},
+        widget::{
+            operation::Focusable,
+            tree::{self, Tag},
+            Id, Operation, Tree,
+        },
         Clipboard, Layout, Shell, Widget,
     },
     alignment, event,
     mouse::{self, Cursor},
-    Element, Event, Length, Rectangle, Size, Vector,
+    window, Alignment, Command, Element, Event, Length, Rectangle, Size, Vector,
 };
+use std::time::Duration;
 
 pub use crate::style::modal::StyleSheet;
 
+/// The duration of the transition showing the overlay of a [`Modal`].
+const ANIMATION_DURATION: Duration = Duration::from_millis(200);
+
 /// A modal content as an overlay.
 ///
 /// Can be used in combination with the [`Card`](crate::card::Card)
 /// widget to form dialog elements.
 ///
+/// While the overlay is shown, the operations only reach its content: the
+/// [`focus`](crate::focus) moves between the widgets of the overlay and
+/// cannot leave it.
+///
+/// The backdrop fades in and the content slides up when the overlay is shown.
+///
 /// # Example
 /// ```ignore
 /// # use iced::widget::Text;
@@ -59,6 +78,8 @@ where
     style: <Theme as StyleSheet>::Style,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    /// The id of the [`Modal`], used by [`focus_first`].
+    id: Option<Id>,
 }
 
 impl<'a, Message, Theme, Renderer> Modal<'a, Message, Theme, Renderer>
@@ -87,6 +108,7 @@ where
             style: <Theme as StyleSheet>::Style::default(),
             horizontal_alignment: alignment::Horizontal::Center,
             vertical_alignment: alignment::Vertical::Center,
+            id: None,
         }
     }
 
@@ -122,10 +144,18 @@ where
         self
     }
 
+    /// Sets the [`Id`] of the [`Modal`], to focus the first widget of its
+    /// overlay with [`focus_first`].
+    #[must_use]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the style of the [`Modal`].
     #[must_use]
-    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
-        self.style = style;
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
         self
     }
 }
@@ -137,6 +167,14 @@ where
     Renderer: renderer::Renderer,
     Theme: StyleSheet,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<Transition>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Transition::default())
+    }
+
     fn children(&self) -> Vec<Tree> {
         self.overlay.as_ref().map_or_else(
             || vec![Tree::new(&self.underlay)],
@@ -173,6 +211,16 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            let transition = state.state.downcast_mut::<Transition>();
+
+            if self.overlay.is_none() {
+                transition.show.set(0.0);
+            } else if transition.show.advance_to(1.0, ANIMATION_DURATION, now) {
+                animation::request_frame(shell, now);
+            }
+        }
+
         if self.overlay.is_none() {
             return self.underlay.as_widget_mut().on_event(
                 &mut state.children[0],
@@ -238,6 +286,8 @@ where
         renderer: &Renderer,
         translation: Vector,
     ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let progress =
+            Easing::EaseOut.apply(state.state.downcast_ref::<Transition>().show.progress());
         let mut group = Group::new();
         let mut children = state.children.iter_mut();
 
@@ -253,15 +303,19 @@ where
             if let Some(el) = children.next() {
                 overlay.as_widget().diff(el);
 
-                group = group.push(overlay::Element::new(Box::new(ModalOverlay::new(
-                    el,
-                    overlay,
-                    self.backdrop.clone(),
-                    self.esc.clone(),
-                    self.style.clone(),
-                    self.horizontal_alignment,
-                    self.vertical_alignment,
-                ))));
+                group = group.push(overlay::Element::new(Box::new(
+                    ModalOverlay::new(
+                        el,
+                        overlay,
+                        self.backdrop.clone(),
+                        self.esc.clone(),
+                        self.style.clone(),
+                        self.horizontal_alignment,
+                        self.vertical_alignment,
+                        progress,
+                    )
+                    .id(self.id.clone()),
+                )));
             }
         }
 
@@ -278,9 +332,37 @@ where
         if let Some(overlay) = &self.overlay {
             overlay.as_widget().diff(&mut state.children[1]);
 
-            overlay
+            let mut node = accessibility::Node::new(Role::Dialog).action(Action::Focus);
+            if self.esc.is_some() || self.backdrop.is_some() {
+                node = node.action(Action::Close);
+            }
+            operation.custom(&mut node, self.id.as_ref());
+
+            // The layout of the modal is the one of the underlay: the overlay
+            // is laid out over it like the `ModalOverlay` lays it out.
+            let bounds = layout.bounds();
+            let node = overlay
                 .as_widget()
-                .operate(&mut state.children[1], layout, renderer, operation);
+                .layout(
+                    &mut state.children[1],
+                    renderer,
+                    &Limits::new(Size::ZERO, bounds.size()),
+                )
+                .align(
+                    Alignment::from(self.horizontal_alignment),
+                    Alignment::from(self.vertical_alignment),
+                    bounds.size(),
+                )
+                .translate(Vector::new(bounds.x, bounds.y));
+
+            operation.container(self.id.as_ref(), node.bounds(), &mut |operation| {
+                overlay.as_widget().operate(
+                    &mut state.children[1],
+                    Layout::new(&node),
+                    renderer,
+                    operation,
+                );
+            });
         } else {
             self.underlay
                 .as_widget()
@@ -301,6 +383,14 @@ where
     }
 }
 
+/// The state of the transition of a [`Modal`].
+#[derive(Debug, Default)]
+struct Transition {
+    /// The progress of the transition showing the overlay, from 0 (hidden)
+    /// to 1 (shown).
+    show: Timeline,
+}
+
 /// The state of the modal.
 #[derive(Debug, Default)]
 pub struct State<S> {
@@ -340,3 +430,64 @@ impl<S> State<S> {
         &self.state
     }
 }
+
+/// The [`Operation`] focusing the first focusable widget in the overlay of
+/// the [`Modal`] with the given [`Id`], unfocusing the others.
+struct FocusFirst {
+    /// The id of the [`Modal`].
+    target: Id,
+    /// Whether the widgets visited are in the overlay of the [`Modal`].
+    inside: bool,
+    /// Whether a widget was focused.
+    focused: bool,
+}
+
+impl<T> Operation<T> for FocusFirst {
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        if self.inside && !self.focused {
+            state.focus();
+            self.focused = true;
+        } else {
+            state.unfocus();
+        }
+    }
+
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let entered = !self.inside && id == Some(&self.target);
+
+        // The overlay is operated with the modal and on its own, the first
+        // widget in it is focused both times.
+        if entered {
+            self.inside = true;
+            self.focused = false;
+        }
+        operate_on_children(self);
+        if entered {
+            self.inside = false;
+        }
+    }
+}
+
+/// Produces a [`Command`] that focuses the first focusable widget in the
+/// overlay of the [`Modal`] with the given [`Id`], like a dialog focusing
+/// its first field when it opens.
+///
+/// No widget is focused while the [`Modal`] does not show its overlay.
+pub fn focus_first<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(request(id))
+}
+
+/// Produces the [`Operation`] focusing the first focusable widget in the
+/// overlay of the [`Modal`] with the given [`Id`].
+pub(crate) fn request<T>(target: Id) -> impl Operation<T> {
+    FocusFirst {
+        target,
+        inside: false,
+        focused: false,
+    }
+}
@@ -0,0 +1,806 @@
+//! Show the events of a month on a calendar grid.
+//!
+//! *This API requires the following crate features to be activated: `month_calendar`*
+
+use crate::core::{
+    date::{pred_month, succ_month, WEEKDAY_LABELS},
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    settings,
+};
+
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    border::Radius,
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Color, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+use std::cmp::Reverse;
+
+pub use crate::{
+    core::date::Date,
+    style::month_calendar::{Appearance, StyleSheet},
+};
+
+/// The number of weeks shown by a [`MonthCalendar`].
+const WEEKS: usize = 6;
+/// The height of the header of a [`MonthCalendar`].
+const HEADER_HEIGHT: f32 = 36.0;
+/// The height of the labels of the weekdays.
+const LABELS_HEIGHT: f32 = 24.0;
+/// The padding inside a day.
+const DAY_PADDING: f32 = 4.0;
+/// The space between the bars of the events.
+const BAR_SPACING: f32 = 2.0;
+
+/// An event shown by a [`MonthCalendar`] as a bar over the days it lasts.
+#[derive(Clone, Debug)]
+pub struct CalendarEvent {
+    /// The title of the event.
+    pub title: String,
+    /// The first day of the event.
+    pub start: Date,
+    /// The last day of the event, included.
+    pub end: Date,
+    /// The color of the bar of the event, or `None` for the color of the style.
+    pub color: Option<Color>,
+}
+
+impl CalendarEvent {
+    /// Creates a new [`CalendarEvent`] lasting from `start` to `end`, both included.
+    pub fn new(title: impl Into<String>, start: Date, end: Date) -> Self {
+        Self {
+            title: title.into(),
+            start,
+            end,
+            color: None,
+        }
+    }
+
+    /// Sets the color of the bar of the [`CalendarEvent`].
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Returns the first and the last day of the event, in order.
+    fn days(&self) -> (NaiveDate, NaiveDate) {
+        let (start, end) = (NaiveDate::from(self.start), NaiveDate::from(self.end));
+        (start.min(end), start.max(end))
+    }
+}
+
+/// A part of the bar of an event within a week.
+#[derive(Clone, Copy, Debug)]
+struct Bar {
+    /// The index of the event.
+    event: usize,
+    /// The bounds of the bar.
+    bounds: Rectangle,
+    /// Whether the event starts in this part.
+    starts: bool,
+    /// Whether the event ends in this part.
+    ends: bool,
+}
+
+/// A calendar showing the days of a month with the events lasting over them.
+///
+/// Events lasting several days are shown as bars over these days, split at
+/// the end of the weeks. The events not fitting in a day are counted in its
+/// corner.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::month_calendar::{CalendarEvent, Date, MonthCalendar};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DayPressed(Date),
+///     EventPressed(usize),
+/// }
+///
+/// let events = vec![CalendarEvent::new(
+///     "Conference",
+///     Date::from_ymd(2024, 3, 12),
+///     Date::from_ymd(2024, 3, 14),
+/// )];
+///
+/// let calendar = MonthCalendar::new(Date::from_ymd(2024, 3, 1), &events)
+///     .on_day_press(Message::DayPressed)
+///     .on_event_press(Message::EventPressed);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MonthCalendar<'a, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The month to show, given by any of its days.
+    month: NaiveDate,
+    /// The events to show.
+    events: &'a [CalendarEvent],
+    /// The function producing the message when a day is pressed.
+    on_day_press: Option<Box<dyn Fn(Date) -> Message + 'a>>,
+    /// The function producing the message when an event is pressed.
+    on_event_press: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The function producing the message when another month is shown.
+    on_month_change: Option<Box<dyn Fn(Date) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    /// The smallest height of a week.
+    week_height: f32,
+    text_size: f32,
+    font: Font,
+    /// The style of the [`MonthCalendar`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`MonthCalendar`].
+#[derive(Debug)]
+struct State {
+    /// The first day of the shown month.
+    shown: NaiveDate,
+    /// The first day of the month last given by the application.
+    given: NaiveDate,
+}
+
+impl<'a, Message, Theme> MonthCalendar<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`MonthCalendar`] showing the month of the given date,
+    /// with the given events.
+    #[must_use]
+    pub fn new(month: Date, events: &'a [CalendarEvent]) -> Self {
+        Self {
+            month: month.into(),
+            events,
+            on_day_press: None,
+            on_event_press: None,
+            on_month_change: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            week_height: 96.0,
+            text_size: settings::scaled(14.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function producing the message when a day is pressed.
+    #[must_use]
+    pub fn on_day_press<F>(mut self, on_day_press: F) -> Self
+    where
+        F: 'a + Fn(Date) -> Message,
+    {
+        self.on_day_press = Some(Box::new(on_day_press));
+        self
+    }
+
+    /// Sets the function producing the message when an event is pressed. It
+    /// receives the index of the event.
+    #[must_use]
+    pub fn on_event_press<F>(mut self, on_event_press: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_event_press = Some(Box::new(on_event_press));
+        self
+    }
+
+    /// Sets the function producing the message when the previous or next
+    /// month is shown. It receives the first day of the shown month.
+    #[must_use]
+    pub fn on_month_change<F>(mut self, on_month_change: F) -> Self
+    where
+        F: 'a + Fn(Date) -> Message,
+    {
+        self.on_month_change = Some(Box::new(on_month_change));
+        self
+    }
+
+    /// Sets the width of the [`MonthCalendar`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`MonthCalendar`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the smallest height of a week of the [`MonthCalendar`].
+    #[must_use]
+    pub fn week_height(mut self, week_height: f32) -> Self {
+        self.week_height = week_height;
+        self
+    }
+
+    /// Sets the text size of the [`MonthCalendar`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`MonthCalendar`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MonthCalendar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the bounds of the header, of the labels of the weekdays and of
+    /// the grid of the days.
+    fn parts(bounds: Rectangle) -> (Rectangle, Rectangle, Rectangle) {
+        let header = Rectangle {
+            height: HEADER_HEIGHT,
+            ..bounds
+        };
+        let labels = Rectangle {
+            y: header.y + header.height,
+            height: LABELS_HEIGHT,
+            ..bounds
+        };
+        let grid = Rectangle {
+            y: labels.y + labels.height,
+            height: (bounds.height - HEADER_HEIGHT - LABELS_HEIGHT).max(0.0),
+            ..bounds
+        };
+
+        (header, labels, grid)
+    }
+
+    /// Returns the bounds of the buttons showing the previous and the next month.
+    fn arrows(header: Rectangle) -> (Rectangle, Rectangle) {
+        let size = Size::new(header.height, header.height);
+
+        (
+            Rectangle::new(header.position(), size),
+            Rectangle::new(
+                Point::new(header.x + header.width - header.height, header.y),
+                size,
+            ),
+        )
+    }
+
+    /// Returns the bounds of the day in the given `week` and `weekday` of the `grid`.
+    fn day(grid: Rectangle, week: usize, weekday: usize) -> Rectangle {
+        let size = Size::new(grid.width / 7.0, grid.height / WEEKS as f32);
+
+        Rectangle::new(
+            Point::new(
+                grid.x + size.width * weekday as f32,
+                grid.y + size.height * week as f32,
+            ),
+            size,
+        )
+    }
+
+    /// Returns the text of `content` centered vertically in `bounds`.
+    fn text(
+        content: &str,
+        bounds: Rectangle,
+        size: f32,
+        font: Font,
+        horizontal_alignment: Horizontal,
+    ) -> Text<'_, Font> {
+        Text {
+            content,
+            bounds: bounds.size(),
+            size: Pixels(size),
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+
+    /// Returns the height of the number of a day.
+    fn number_height(&self) -> f32 {
+        LineHeight::default()
+            .to_absolute(Pixels(self.text_size))
+            .0
+            .round()
+    }
+
+    /// Returns the height of the bar of an event.
+    fn bar_height(&self) -> f32 {
+        self.text_size + 4.0
+    }
+
+    /// Returns the bars of the events visible in the `grid` starting on the
+    /// day `first`, and the number of events not fitting in each day.
+    fn bars(&self, grid: Rectangle, first: NaiveDate) -> (Vec<Bar>, [[usize; 7]; WEEKS]) {
+        let mut bars = Vec::new();
+        let mut hidden = [[0; 7]; WEEKS];
+        let day_height = grid.height / WEEKS as f32;
+        let lanes_top = DAY_PADDING * 2.0 + self.number_height();
+        let capacity = ((day_height - lanes_top - DAY_PADDING + BAR_SPACING)
+            / (self.bar_height() + BAR_SPACING))
+            .floor()
+            .max(0.0) as usize;
+
+        // Longer events come first among the events starting the same day,
+        // so that they keep the top lanes.
+        let mut order: Vec<usize> = (0..self.events.len()).collect();
+        order.sort_by_key(|&index| {
+            let (start, end) = self.events[index].days();
+            (start, Reverse(end - start))
+        });
+
+        for (week, hidden) in hidden.iter_mut().enumerate() {
+            let week_start = first + Duration::days(7 * week as i64);
+            let week_end = week_start + Duration::days(6);
+            let mut lanes: Vec<[bool; 7]> = Vec::new();
+
+            for &index in &order {
+                let (start, end) = self.events[index].days();
+
+                if end < week_start || start > week_end {
+                    continue;
+                }
+
+                let from = (start.max(week_start) - week_start).num_days() as usize;
+                let to = (end.min(week_end) - week_start).num_days() as usize;
+
+                let lane = lanes
+                    .iter()
+                    .position(|lane| lane[from..=to].iter().all(|taken| !taken))
+                    .unwrap_or_else(|| {
+                        lanes.push([false; 7]);
+                        lanes.len() - 1
+                    });
+                lanes[lane][from..=to]
+                    .iter_mut()
+                    .for_each(|taken| *taken = true);
+
+                if lane >= capacity {
+                    hidden[from..=to].iter_mut().for_each(|count| *count += 1);
+                    continue;
+                }
+
+                let left = Self::day(grid, week, from);
+                let right = Self::day(grid, week, to);
+                let x = left.x
+                    + if start >= week_start {
+                        DAY_PADDING
+                    } else {
+                        0.0
+                    };
+                let x_end = right.x + right.width - if end <= week_end { DAY_PADDING } else { 0.0 };
+
+                bars.push(Bar {
+                    event: index,
+                    bounds: Rectangle::new(
+                        Point::new(
+                            x,
+                            left.y + lanes_top + (self.bar_height() + BAR_SPACING) * lane as f32,
+                        ),
+                        Size::new((x_end - x).max(0.0), self.bar_height()),
+                    ),
+                    starts: start >= week_start,
+                    ends: end <= week_end,
+                });
+            }
+        }
+
+        (bars, hidden)
+    }
+}
+
+/// Returns the first day of the month of `date`.
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// Returns the first day shown for the month starting on `month`, the
+/// Monday of its first week.
+fn first_shown(month: NaiveDate) -> NaiveDate {
+    month - Duration::days(i64::from(month.weekday().num_days_from_monday()))
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MonthCalendar<'_, Message, Theme>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let month = first_of_month(self.month);
+
+        tree::State::new(State {
+            shown: month,
+            given: month,
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let month = first_of_month(self.month);
+
+        // A month given by the application replaces the one shown with the arrows.
+        if month != state.given {
+            state.given = month;
+            state.shown = month;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let intrinsic = Size::new(
+            0.0,
+            HEADER_HEIGHT + LABELS_HEIGHT + self.week_height * WEEKS as f32,
+        );
+
+        Node::new(limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            intrinsic,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let (Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. })) = event
+        else {
+            return event::Status::Ignored;
+        };
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return event::Status::Ignored;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let (header, _labels, grid) = Self::parts(layout.bounds());
+        let (previous, next) = Self::arrows(header);
+
+        if previous.contains(position) || next.contains(position) {
+            state.shown = if previous.contains(position) {
+                pred_month(state.shown)
+            } else {
+                succ_month(state.shown)
+            };
+
+            if let Some(on_month_change) = &self.on_month_change {
+                shell.publish(on_month_change(state.shown.into()));
+            }
+            return event::Status::Captured;
+        }
+
+        let first = first_shown(state.shown);
+
+        if let Some(on_event_press) = &self.on_event_press {
+            let (bars, _hidden) = self.bars(grid, first);
+
+            if let Some(bar) = bars.iter().find(|bar| bar.bounds.contains(position)) {
+                shell.publish(on_event_press(bar.event));
+                return event::Status::Captured;
+            }
+        }
+
+        if let Some(on_day_press) = &self.on_day_press {
+            if grid.contains(position) {
+                let week = ((position.y - grid.y) / (grid.height / WEEKS as f32)) as usize;
+                let weekday = ((position.x - grid.x) / (grid.width / 7.0)) as usize;
+                let day = first + Duration::days((week.min(WEEKS - 1) * 7 + weekday.min(6)) as i64);
+
+                shell.publish(on_day_press(day.into()));
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return mouse::Interaction::default();
+        };
+
+        let state = tree.state.downcast_ref::<State>();
+        let (header, _labels, grid) = Self::parts(layout.bounds());
+        let (previous, next) = Self::arrows(header);
+        let on_bar = || {
+            self.bars(grid, first_shown(state.shown))
+                .0
+                .iter()
+                .any(|bar| bar.bounds.contains(position))
+        };
+
+        if previous.contains(position)
+            || next.contains(position)
+            || (self.on_day_press.is_some() && grid.contains(position))
+            || (self.on_event_press.is_some() && on_bar())
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let (header, labels, grid) = Self::parts(bounds);
+        let first = first_shown(state.shown);
+        let today = Local::now().naive_local().date();
+        let line = |renderer: &mut Renderer, bounds: Rectangle| {
+            renderer.fill_quad(
+                Quad {
+                    bounds,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                appearance.grid_color,
+            );
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: appearance.border_radius.into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        // The header with the title of the month between the arrows.
+        let (previous, next) = Self::arrows(header);
+        for (arrow, icon) in [
+            (previous, Bootstrap::CaretLeftFill),
+            (next, Bootstrap::CaretRightFill),
+        ] {
+            renderer.fill_text(
+                Self::text(
+                    &icon_to_string(icon),
+                    arrow,
+                    self.text_size,
+                    BOOTSTRAP_FONT,
+                    Horizontal::Center,
+                ),
+                arrow.center(),
+                appearance.header_text_color,
+                arrow,
+            );
+        }
+
+        renderer.fill_text(
+            Self::text(
+                &state.shown.format("%B %Y").to_string(),
+                header,
+                self.text_size + 2.0,
+                self.font,
+                Horizontal::Center,
+            ),
+            header.center(),
+            appearance.header_text_color,
+            header,
+        );
+
+        // The labels of the weekdays.
+        for (weekday, label) in WEEKDAY_LABELS.iter().enumerate() {
+            let label_bounds = Rectangle {
+                y: labels.y,
+                height: labels.height,
+                ..Self::day(grid, 0, weekday)
+            };
+
+            renderer.fill_text(
+                Self::text(
+                    label,
+                    label_bounds,
+                    self.text_size - 2.0,
+                    self.font,
+                    Horizontal::Center,
+                ),
+                label_bounds.center(),
+                appearance.weekday_text_color,
+                label_bounds,
+            );
+        }
+
+        // The days, their numbers and the lines between them.
+        let (bars, hidden) = self.bars(grid, first);
+
+        for (week, hidden) in hidden.iter().enumerate() {
+            line(
+                renderer,
+                Rectangle::new(
+                    Self::day(grid, week, 0).position(),
+                    Size::new(grid.width, 1.0),
+                ),
+            );
+
+            for (weekday, &hidden) in hidden.iter().enumerate() {
+                let day_bounds = Self::day(grid, week, weekday);
+                let date = first + Duration::days((week * 7 + weekday) as i64);
+
+                if self.on_day_press.is_some() && cursor.is_over(day_bounds) {
+                    renderer.fill_quad(
+                        Quad {
+                            bounds: day_bounds,
+                            border: Border::default(),
+                            shadow: Shadow::default(),
+                        },
+                        appearance.hovered_day_background,
+                    );
+                }
+
+                if weekday > 0 {
+                    line(
+                        renderer,
+                        Rectangle::new(day_bounds.position(), Size::new(1.0, day_bounds.height)),
+                    );
+                }
+
+                let number_height = self.number_height();
+                let number = Rectangle::new(
+                    Point::new(day_bounds.x + DAY_PADDING, day_bounds.y + DAY_PADDING),
+                    Size::new(number_height + DAY_PADDING, number_height),
+                );
+                let color = if date == today {
+                    renderer.fill_quad(
+                        Quad {
+                            bounds: number,
+                            border: Border::with_radius(number_height / 2.0),
+                            shadow: Shadow::default(),
+                        },
+                        appearance.today_background,
+                    );
+                    appearance.today_text_color
+                } else if date.month() == state.shown.month() {
+                    appearance.day_text_color
+                } else {
+                    appearance.outside_day_text_color
+                };
+
+                renderer.fill_text(
+                    Self::text(
+                        &date.day().to_string(),
+                        number,
+                        self.text_size,
+                        self.font,
+                        Horizontal::Center,
+                    ),
+                    number.center(),
+                    color,
+                    number,
+                );
+
+                if hidden > 0 {
+                    let more = Rectangle {
+                        x: number.x + number.width,
+                        width: (day_bounds.width - number.width - DAY_PADDING * 3.0).max(0.0),
+                        ..number
+                    };
+
+                    renderer.fill_text(
+                        Self::text(
+                            &format!("+{hidden}"),
+                            more,
+                            self.text_size - 2.0,
+                            self.font,
+                            Horizontal::Right,
+                        ),
+                        Point::new(more.x + more.width, more.center_y()),
+                        appearance.more_text_color,
+                        more,
+                    );
+                }
+            }
+        }
+
+        // The bars of the events over the days.
+        for bar in bars {
+            let event = &self.events[bar.event];
+            let radius = self.bar_height() / 4.0;
+            let (start_radius, end_radius) = (
+                if bar.starts { radius } else { 0.0 },
+                if bar.ends { radius } else { 0.0 },
+            );
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: bar.bounds,
+                    border: Border {
+                        radius: Radius::from([start_radius, end_radius, end_radius, start_radius]),
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                event.color.unwrap_or(appearance.event_color),
+            );
+
+            let title = Rectangle {
+                x: bar.bounds.x + DAY_PADDING,
+                width: (bar.bounds.width - DAY_PADDING * 2.0).max(0.0),
+                ..bar.bounds
+            };
+            renderer.fill_text(
+                Self::text(
+                    &event.title,
+                    title,
+                    self.text_size - 2.0,
+                    self.font,
+                    Horizontal::Left,
+                ),
+                Point::new(title.x, title.center_y()),
+                appearance.event_text_color,
+                title,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MonthCalendar<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(calendar: MonthCalendar<'a, Message, Theme>) -> Self {
+        Element::new(calendar)
+    }
+}
@@ -0,0 +1,475 @@
+//! Choose several options from a drop down checklist.
+//!
+//! *This API requires the following crate features to be activated: `multi_pick_list`*
+
+use crate::core::{
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    settings,
+};
+use crate::widgets::overlay::MultiPickListOverlay;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Quad},
+        text::{self, Paragraph, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::marker::PhantomData;
+
+pub use crate::style::multi_pick_list::{Appearance, StyleSheet};
+
+/// The horizontal padding of the text of a chip.
+const CHIP_PADDING: f32 = 6.0;
+/// The space between two chips.
+const CHIP_SPACING: f32 = 4.0;
+/// The number of chosen options named by the default summary.
+const SUMMARY_NAMES: usize = 2;
+
+/// How a [`MultiPickList`] shows the chosen options while closed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionDisplay {
+    /// A text naming the chosen options, or counting them when there are many.
+    #[default]
+    Summary,
+    /// A chip per chosen option, followed by the number of the ones that do
+    /// not fit.
+    Chips,
+}
+
+/// The function summarizing the chosen options.
+type Summary<'a, T> = Box<dyn Fn(&[T]) -> String + 'a>;
+
+/// A field looking like a pick list, opening a checklist of options of which
+/// several can be chosen.
+///
+/// The list stays open while options are toggled, and closes on a press
+/// outside of it or on Escape. The arrow keys move through the options, and
+/// Enter or Space toggles the highlighted one. The chosen options are
+/// produced in the order of the options.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::multi_pick_list::{MultiPickList, SelectionDisplay};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LanguagesChanged(Vec<String>),
+/// }
+///
+/// let languages = vec![String::from("Rust"), String::from("Go"), String::from("C")];
+/// let chosen = vec![String::from("Rust")];
+///
+/// let multi_pick_list = MultiPickList::new(&languages, &chosen, Message::LanguagesChanged)
+///     .placeholder("Languages")
+///     .display(SelectionDisplay::Chips);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MultiPickList<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    T: Clone + PartialEq + ToString,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// The options to choose from.
+    options: &'a [T],
+    /// The chosen options.
+    selected: &'a [T],
+    /// The function producing the message when the chosen options change.
+    on_change: Box<dyn Fn(Vec<T>) -> Message + 'a>,
+    /// The text shown when no option is chosen.
+    placeholder: String,
+    /// How the chosen options are shown while closed.
+    display: SelectionDisplay,
+    /// The function summarizing the chosen options, if not the default one.
+    summary: Option<Summary<'a, T>>,
+    width: Length,
+    padding: f32,
+    text_size: f32,
+    font: Font,
+    /// The style of the [`MultiPickList`].
+    style: <Theme as StyleSheet>::Style,
+    #[allow(clippy::missing_docs_in_private_items)]
+    renderer: PhantomData<Renderer>,
+}
+
+/// The state of a [`MultiPickList`].
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// Whether the list of options is open.
+    pub(crate) open: bool,
+    /// The index of the highlighted option.
+    pub(crate) highlighted: Option<usize>,
+}
+
+impl<'a, T, Message, Theme, Renderer> MultiPickList<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + PartialEq + ToString,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`MultiPickList`] of the `options`, of which the
+    /// `selected` ones are chosen, producing a message with the chosen
+    /// options when they change.
+    pub fn new<F>(options: &'a [T], selected: &'a [T], on_change: F) -> Self
+    where
+        F: 'a + Fn(Vec<T>) -> Message,
+    {
+        Self {
+            options,
+            selected,
+            on_change: Box::new(on_change),
+            placeholder: String::new(),
+            display: SelectionDisplay::default(),
+            summary: None,
+            width: Length::Fill,
+            padding: settings::spaced(5.0),
+            text_size: settings::scaled(14.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+            renderer: PhantomData,
+        }
+    }
+
+    /// Sets the text shown when no option is chosen.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets how the chosen options are shown while closed.
+    #[must_use]
+    pub fn display(mut self, display: SelectionDisplay) -> Self {
+        self.display = display;
+        self
+    }
+
+    /// Sets the function summarizing the chosen options with
+    /// [`SelectionDisplay::Summary`], like `"3 selected"`.
+    #[must_use]
+    pub fn summary(mut self, summary: impl Fn(&[T]) -> String + 'a) -> Self {
+        self.summary = Some(Box::new(summary));
+        self
+    }
+
+    /// Sets the width of the [`MultiPickList`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the [`MultiPickList`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`MultiPickList`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`MultiPickList`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MultiPickList`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the summary of the chosen options.
+    fn summary_text(&self) -> String {
+        if let Some(summary) = &self.summary {
+            return summary(self.selected);
+        }
+
+        if self.selected.len() > SUMMARY_NAMES {
+            format!("{} selected", self.selected.len())
+        } else {
+            self.selected
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    }
+
+    /// Returns a text of the [`MultiPickList`] in `bounds`.
+    fn text<'c>(&self, content: &'c str, bounds: Size, font: Font) -> Text<'c, Font> {
+        Text {
+            content,
+            bounds,
+            size: Pixels(self.text_size),
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+
+    /// Returns the width of `content`.
+    fn measure(&self, content: &str) -> f32 {
+        Renderer::Paragraph::with_text(self.text(content, Size::INFINITY, self.font))
+            .min_bounds()
+            .width
+    }
+
+    /// Draws a chip per chosen option in `inner`, followed by the number of
+    /// the ones that do not fit.
+    fn draw_chips(&self, renderer: &mut Renderer, appearance: &Appearance, inner: Rectangle) {
+        let end = inner.x + inner.width;
+        let mut x = inner.x;
+
+        for (i, option) in self.selected.iter().enumerate() {
+            let label = option.to_string();
+            let width = self.measure(&label) + CHIP_PADDING * 2.0;
+            let rest = self.selected.len() - i - 1;
+            let reserved = if rest > 0 {
+                self.measure(&format!("+{rest}")) + CHIP_SPACING
+            } else {
+                0.0
+            };
+
+            if x + width + reserved > end {
+                let more = format!("+{}", self.selected.len() - i);
+                renderer.fill_text(
+                    self.text(&more, inner.size(), self.font),
+                    Point::new(x, inner.center_y()),
+                    appearance.text_color,
+                    inner,
+                );
+                return;
+            }
+
+            let chip = Rectangle::new(Point::new(x, inner.y), Size::new(width, inner.height));
+            renderer.fill_quad(
+                Quad {
+                    bounds: chip,
+                    border: Border::with_radius(appearance.border_radius),
+                    shadow: Shadow::default(),
+                },
+                appearance.chip_background,
+            );
+            renderer.fill_text(
+                self.text(&label, chip.size(), self.font),
+                Point::new(chip.x + CHIP_PADDING, chip.center_y()),
+                appearance.chip_text_color,
+                chip,
+            );
+
+            x += width + CHIP_SPACING;
+        }
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MultiPickList<'_, T, Message, Theme, Renderer>
+where
+    T: Clone + PartialEq + ToString,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let height =
+            LineHeight::default().to_absolute(Pixels(self.text_size)).0 + self.padding * 2.0;
+
+        Node::new(limits.width(self.width).height(height).resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(0.0, height),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                let state = tree.state.downcast_mut::<State>();
+                state.open = !state.open;
+                state.highlighted = None;
+                shell.invalidate_layout();
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = if state.open || cursor.is_over(bounds) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: appearance.border_radius.into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        let handle_width = self.text_size;
+        let inner = Rectangle {
+            x: bounds.x + self.padding,
+            y: bounds.y + self.padding / 2.0,
+            width: (bounds.width - self.padding * 3.0 - handle_width).max(0.0),
+            height: (bounds.height - self.padding).max(0.0),
+        };
+
+        if self.selected.is_empty() {
+            renderer.fill_text(
+                self.text(&self.placeholder, inner.size(), self.font),
+                Point::new(inner.x, inner.center_y()),
+                appearance.placeholder_color,
+                inner,
+            );
+        } else {
+            match self.display {
+                SelectionDisplay::Summary => {
+                    renderer.fill_text(
+                        self.text(&self.summary_text(), inner.size(), self.font),
+                        Point::new(inner.x, inner.center_y()),
+                        appearance.text_color,
+                        inner,
+                    );
+                }
+                SelectionDisplay::Chips => self.draw_chips(renderer, &appearance, inner),
+            }
+        }
+
+        let handle = Rectangle::new(
+            Point::new(
+                bounds.x + bounds.width - self.padding - handle_width,
+                bounds.y,
+            ),
+            Size::new(handle_width, bounds.height),
+        );
+        renderer.fill_text(
+            Text {
+                horizontal_alignment: Horizontal::Center,
+                ..self.text(
+                    &icon_to_string(Bootstrap::ChevronDown),
+                    handle.size(),
+                    BOOTSTRAP_FONT,
+                )
+            },
+            handle.center(),
+            appearance.handle_color,
+            handle,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        if !state.open || self.options.is_empty() {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(MultiPickListOverlay::new(
+            state,
+            self.options,
+            self.selected,
+            &*self.on_change,
+            layout.bounds() + translation,
+            self.text_size,
+            self.font,
+            &self.style,
+        ))))
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<MultiPickList<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a + Clone + PartialEq + ToString,
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(multi_pick_list: MultiPickList<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(multi_pick_list)
+    }
+}
@@ -0,0 +1,634 @@
+//! Navigate between the destinations of an application.
+//!
+//! *This API requires the following crate features to be activated: `navigation_rail`*
+
+use crate::core::{
+    icons::{Bootstrap, IconHandle, IconRenderer, BOOTSTRAP_FONT},
+    settings,
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Quad},
+        text::Text,
+        widget::{Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+
+pub use crate::style::navigation_rail::{Appearance, StyleSheet};
+
+/// A vertical rail of destinations to navigate between the views of an
+/// application, the vertical counterpart of a [`TabBar`](crate::TabBar).
+///
+/// The rail shows the icons of its destinations, and their labels when
+/// expanded. A footer, such as a settings button, can be placed at its bottom.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::{navigation_rail::NavigationRail, Bootstrap};
+/// #
+/// #[derive(Debug, Clone, PartialEq, Eq)]
+/// enum Page {
+///     Home,
+///     Inbox,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Navigate(Page),
+///     ToggleRail(bool),
+/// }
+///
+/// let rail = NavigationRail::new(Message::Navigate)
+///     .push(Page::Home, Bootstrap::House.into(), "Home")
+///     .push(Page::Inbox, Bootstrap::Inbox.into(), "Inbox")
+///     .selected(&Page::Home)
+///     .on_toggle(Message::ToggleRail);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NavigationRail<'a, Message, Id, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+    Id: Eq + Clone,
+{
+    /// The destinations of the rail, with their icon and label.
+    destinations: Vec<(Id, IconHandle, String)>,
+    /// The index of the selected destination.
+    selected: Option<usize>,
+    /// The function producing the message when a destination is selected.
+    on_select: Box<dyn Fn(Id) -> Message + 'a>,
+    /// Whether the labels of the destinations are shown.
+    expanded: bool,
+    /// The function producing the message when the toggle button is pressed,
+    /// with whether the rail should be expanded.
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    /// The optional footer [`Element`] at the bottom of the rail.
+    footer: Option<Element<'a, Message, Theme, Renderer>>,
+    /// The width of the rail showing the icons only.
+    collapsed_width: f32,
+    /// The width of the rail showing the labels.
+    expanded_width: f32,
+    /// The height of the destinations.
+    item_height: f32,
+    /// The space between the destinations.
+    spacing: f32,
+    /// The space between the destinations and the edges of the rail.
+    padding: f32,
+    icon_size: f32,
+    text_size: f32,
+    /// The font of the icons, the Bootstrap icons by default.
+    icon_font: Option<Font>,
+    text_font: Option<Font>,
+    /// The style of the rail.
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Id, Theme, Renderer> NavigationRail<'a, Message, Id, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+    Id: Eq + Clone,
+{
+    /// Creates a new empty [`NavigationRail`] producing a message with the
+    /// id of the destination selected by the user.
+    pub fn new(on_select: impl Fn(Id) -> Message + 'a) -> Self {
+        Self {
+            destinations: Vec::new(),
+            selected: None,
+            on_select: Box::new(on_select),
+            expanded: false,
+            on_toggle: None,
+            footer: None,
+            collapsed_width: 72.0,
+            expanded_width: 220.0,
+            item_height: settings::spaced(48.0),
+            spacing: settings::spaced(4.0),
+            padding: settings::spaced(12.0),
+            icon_size: settings::scaled(20.0),
+            text_size: settings::scaled(15.0),
+            icon_font: None,
+            text_font: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds a destination with the given id, icon and label to the [`NavigationRail`].
+    ///
+    /// The icon is a glyph drawn with the icon font of the rail unless it has
+    /// its own, or an SVG.
+    #[must_use]
+    pub fn push(mut self, id: Id, icon: impl Into<IconHandle>, label: impl Into<String>) -> Self {
+        self.destinations.push((id, icon.into(), label.into()));
+        self
+    }
+
+    /// Sets the selected destination of the [`NavigationRail`], among the
+    /// destinations pushed so far.
+    #[must_use]
+    pub fn selected(mut self, id: &Id) -> Self {
+        self.selected = self
+            .destinations
+            .iter()
+            .position(|(destination, ..)| destination == id);
+        self
+    }
+
+    /// Sets whether the [`NavigationRail`] shows the labels of its destinations.
+    #[must_use]
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Shows a button at the top of the [`NavigationRail`] to expand or
+    /// collapse it, producing a message with whether it should be expanded.
+    #[must_use]
+    pub fn on_toggle(mut self, on_toggle: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the footer [`Element`] at the bottom of the [`NavigationRail`].
+    #[must_use]
+    pub fn footer(mut self, footer: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Sets the width of the [`NavigationRail`] when showing its icons only.
+    #[must_use]
+    pub fn collapsed_width(mut self, width: f32) -> Self {
+        self.collapsed_width = width;
+        self
+    }
+
+    /// Sets the width of the [`NavigationRail`] when showing its labels.
+    #[must_use]
+    pub fn expanded_width(mut self, width: f32) -> Self {
+        self.expanded_width = width;
+        self
+    }
+
+    /// Sets the height of the destinations of the [`NavigationRail`].
+    #[must_use]
+    pub fn item_height(mut self, item_height: f32) -> Self {
+        self.item_height = item_height;
+        self
+    }
+
+    /// Sets the space between the destinations of the [`NavigationRail`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the space between the destinations and the edges of the [`NavigationRail`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the size of the icons of the destinations.
+    #[must_use]
+    pub fn icon_size(mut self, icon_size: f32) -> Self {
+        self.icon_size = icon_size;
+        self
+    }
+
+    /// Sets the text size of the labels of the destinations.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the icons of the destinations.
+    #[must_use]
+    pub fn icon_font(mut self, font: impl Into<Font>) -> Self {
+        self.icon_font = Some(font.into());
+        self
+    }
+
+    /// Sets the font of the labels of the destinations.
+    #[must_use]
+    pub fn text_font(mut self, font: Font) -> Self {
+        self.text_font = Some(font);
+        self
+    }
+
+    /// Sets the style of the [`NavigationRail`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the width of the [`NavigationRail`].
+    fn rail_width(&self) -> f32 {
+        if self.expanded {
+            self.expanded_width
+        } else {
+            self.collapsed_width
+        }
+    }
+
+    /// Returns the bounds of the toggle button in the given bounds of the rail.
+    fn toggle_bounds(&self, bounds: Rectangle) -> Option<Rectangle> {
+        self.on_toggle.as_ref().map(|_| Rectangle {
+            x: bounds.x + self.padding,
+            y: bounds.y + self.padding,
+            width: bounds.width - self.padding * 2.0,
+            height: self.item_height,
+        })
+    }
+
+    /// Returns the bounds of the destination at `index` in the given bounds of the rail.
+    fn destination_bounds(&self, bounds: Rectangle, index: usize) -> Rectangle {
+        let top = self
+            .toggle_bounds(bounds)
+            .map_or(bounds.y + self.padding, |toggle| {
+                toggle.y + toggle.height + self.spacing * 2.0
+            });
+
+        Rectangle {
+            x: bounds.x + self.padding,
+            y: top + (self.item_height + self.spacing) * index as f32,
+            width: bounds.width - self.padding * 2.0,
+            height: self.item_height,
+        }
+    }
+
+    /// Returns the index of the destination under the cursor.
+    fn destination_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<usize> {
+        (0..self.destinations.len()).find(|&i| cursor.is_over(self.destination_bounds(bounds, i)))
+    }
+}
+
+impl<Message, Id, Theme, Renderer> NavigationRail<'_, Message, Id, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + IconRenderer,
+    Theme: StyleSheet,
+    Id: Eq + Clone,
+{
+    /// Draws an icon, followed by its label when the rail is expanded.
+    fn draw_item(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        icon: &IconHandle,
+        icon_font: Font,
+        label: &str,
+        colors: (iced::Color, iced::Color),
+    ) {
+        let icon_x = if self.expanded {
+            bounds.x
+        } else {
+            bounds.center_x() - self.item_height / 2.0
+        };
+
+        icon.draw(
+            renderer,
+            Rectangle {
+                x: icon_x,
+                width: self.item_height,
+                ..bounds
+            },
+            self.icon_size,
+            icon_font,
+            colors.0,
+        );
+
+        if self.expanded {
+            let x = bounds.x + self.item_height;
+
+            renderer.fill_text(
+                Text {
+                    content: label,
+                    bounds: Size::new((bounds.x + bounds.width - x).max(0.0), bounds.height),
+                    size: Pixels(self.text_size),
+                    line_height: LineHeight::default(),
+                    font: self.text_font.unwrap_or_default(),
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                    shaping: Shaping::Advanced,
+                },
+                Point::new(x, bounds.center_y()),
+                colors.1,
+                bounds,
+            );
+        }
+    }
+}
+
+impl<Message, Id, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for NavigationRail<'_, Message, Id, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + IconRenderer,
+    Theme: StyleSheet,
+    Id: Eq + Clone,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.footer.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(self.footer.as_slice());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.rail_width()), Length::Fill)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits
+            .width(self.rail_width())
+            .height(Length::Fill)
+            .resolve(self.rail_width(), Length::Fill, Size::ZERO);
+
+        let children = self
+            .footer
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(footer, tree)| {
+                let limits = Limits::new(
+                    Size::ZERO,
+                    Size::new(
+                        (size.width - self.padding * 2.0).max(0.0),
+                        (size.height - self.padding * 2.0).max(0.0),
+                    ),
+                );
+                let node = footer.as_widget().layout(tree, renderer, &limits);
+                let footer_size = node.size();
+
+                node.move_to(Point::new(
+                    (size.width - footer_size.width) / 2.0,
+                    size.height - self.padding - footer_size.height,
+                ))
+            })
+            .collect();
+
+        Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let (Some(footer), Some(footer_layout)) = (&mut self.footer, layout.children().next()) {
+            if footer.as_widget_mut().on_event(
+                &mut state.children[0],
+                event.clone(),
+                footer_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            ) == event::Status::Captured
+            {
+                return event::Status::Captured;
+            }
+        }
+
+        if !matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) {
+            return event::Status::Ignored;
+        }
+
+        let bounds = layout.bounds();
+
+        if let (Some(on_toggle), Some(toggle)) = (&self.on_toggle, self.toggle_bounds(bounds)) {
+            if cursor.is_over(toggle) {
+                shell.publish(on_toggle(!self.expanded));
+                return event::Status::Captured;
+            }
+        }
+
+        if let Some(index) = self.destination_at(bounds, cursor) {
+            shell.publish((self.on_select)(self.destinations[index].0.clone()));
+            return event::Status::Captured;
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        if self.destination_at(bounds, cursor).is_some()
+            || self
+                .toggle_bounds(bounds)
+                .is_some_and(|toggle| cursor.is_over(toggle))
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        match (&self.footer, layout.children().next()) {
+            (Some(footer), Some(footer_layout)) => footer.as_widget().mouse_interaction(
+                &state.children[0],
+                footer_layout,
+                cursor,
+                viewport,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(&self.style);
+        let icon_font = self.icon_font.unwrap_or(BOOTSTRAP_FONT);
+        let radius = self.item_height / 2.0;
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        renderer.fill_quad(
+            Quad {
+                bounds: Rectangle {
+                    x: bounds.x + bounds.width - appearance.border_width,
+                    width: appearance.border_width,
+                    ..bounds
+                },
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            appearance.border_color,
+        );
+
+        if let Some(toggle) = self.toggle_bounds(bounds) {
+            if cursor.is_over(toggle) {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: toggle,
+                        border: Border::with_radius(radius),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.hovered_background,
+                );
+            }
+
+            self.draw_item(
+                renderer,
+                toggle,
+                &IconHandle::from(if self.expanded {
+                    Bootstrap::CaretLeftFill
+                } else {
+                    Bootstrap::CaretRightFill
+                }),
+                BOOTSTRAP_FONT,
+                "",
+                (appearance.icon_color, appearance.text_color),
+            );
+        }
+
+        for (i, (_, icon, label)) in self.destinations.iter().enumerate() {
+            let item = self.destination_bounds(bounds, i);
+            let selected = self.selected == Some(i);
+
+            // The indicator spans the whole destination when the labels are
+            // shown, and sits behind the icon otherwise.
+            let indicator = if self.expanded {
+                item
+            } else {
+                Rectangle {
+                    x: item.center_x() - self.item_height * 0.75,
+                    width: self.item_height * 1.5,
+                    ..item
+                }
+            };
+
+            if selected || cursor.is_over(item) {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: indicator,
+                        border: Border::with_radius(radius),
+                        shadow: Shadow::default(),
+                    },
+                    if selected {
+                        appearance.indicator_background
+                    } else {
+                        appearance.hovered_background
+                    },
+                );
+            }
+
+            self.draw_item(
+                renderer,
+                item,
+                icon,
+                icon_font,
+                label,
+                if selected {
+                    (
+                        appearance.selected_icon_color,
+                        appearance.selected_text_color,
+                    )
+                } else {
+                    (appearance.icon_color, appearance.text_color)
+                },
+            );
+        }
+
+        if let (Some(footer), Some(footer_layout)) = (&self.footer, layout.children().next()) {
+            footer.as_widget().draw(
+                &state.children[0],
+                renderer,
+                theme,
+                style,
+                footer_layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let (Some(footer), Some(footer_layout)) = (&self.footer, layout.children().next()) {
+            footer
+                .as_widget()
+                .operate(&mut state.children[0], footer_layout, renderer, operation);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let footer_layout = layout.children().next()?;
+
+        self.footer.as_mut()?.as_widget_mut().overlay(
+            &mut state.children[0],
+            footer_layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Id, Theme, Renderer> From<NavigationRail<'a, Message, Id, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Id: 'a + Eq + Clone,
+    Renderer: 'a + renderer::Renderer + IconRenderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(rail: NavigationRail<'a, Message, Id, Theme, Renderer>) -> Self {
+        Element::new(rail)
+    }
+}
@@ -0,0 +1,454 @@
+//! Enter numbers with an on-screen number pad, for touch screens.
+//!
+//! *This API requires the following crate features to be activated: `num_pad`*
+
+use crate::core::{
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    num_pad::apply,
+    settings,
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+pub use crate::{
+    core::num_pad::NumPadKey,
+    style::num_pad::{Appearance, StyleSheet},
+};
+
+/// What the keys of a [`NumPad`] act on.
+enum Target<'a, Message> {
+    /// The keys produce messages.
+    Keys(Box<dyn Fn(NumPadKey) -> Message + 'a>),
+    /// The keys edit a value.
+    Value {
+        /// The edited value.
+        value: &'a str,
+        /// The function producing the message with the edited value.
+        on_change: Box<dyn Fn(String) -> Message + 'a>,
+    },
+}
+
+/// An on-screen number pad with large keys for the digits, a decimal
+/// separator, a backspace and a confirm key, for touch screens.
+///
+/// The keys either produce a message each, or edit a value bound to the
+/// number pad.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::num_pad::NumPad;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     AmountChanged(String),
+///     Pay,
+/// }
+///
+/// let amount = String::from("12.5");
+///
+/// let num_pad = NumPad::with_value(&amount, Message::AmountChanged)
+///     .max_length(8)
+///     .on_confirm(Message::Pay);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumPad<'a, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// What the keys act on.
+    target: Target<'a, Message>,
+    /// The message produced by the confirm key, when editing a value.
+    on_confirm: Option<Message>,
+    /// Whether the decimal separator key is shown.
+    decimal: bool,
+    /// The maximum number of characters of the edited value.
+    max_length: Option<usize>,
+    /// The width and height of the keys.
+    key_size: f32,
+    /// The space between the keys.
+    spacing: f32,
+    /// The space between the keys and the edges of the number pad.
+    padding: f32,
+    text_size: f32,
+    font: Font,
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`NumPad`].
+#[derive(Debug, Default)]
+struct State {
+    /// The key being pressed.
+    pressed: Option<NumPadKey>,
+}
+
+impl<'a, Message, Theme> NumPad<'a, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`NumPad`] producing the message of `on_key` for each
+    /// pressed key.
+    pub fn new<F>(on_key: F) -> Self
+    where
+        F: 'a + Fn(NumPadKey) -> Message,
+    {
+        Self::with_target(Target::Keys(Box::new(on_key)))
+    }
+
+    /// Creates a new [`NumPad`] editing the `value`, producing the message
+    /// of `on_change` with the edited value.
+    pub fn with_value<F>(value: &'a str, on_change: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        Self::with_target(Target::Value {
+            value,
+            on_change: Box::new(on_change),
+        })
+    }
+
+    fn with_target(target: Target<'a, Message>) -> Self {
+        Self {
+            target,
+            on_confirm: None,
+            decimal: true,
+            max_length: None,
+            key_size: 64.0,
+            spacing: settings::spaced(8.0),
+            padding: 0.0,
+            text_size: settings::scaled(24.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the message produced by the confirm key when the [`NumPad`]
+    /// edits a value.
+    #[must_use]
+    pub fn on_confirm(mut self, message: Message) -> Self {
+        self.on_confirm = Some(message);
+        self
+    }
+
+    /// Sets whether the decimal separator key is shown.
+    #[must_use]
+    pub fn decimal(mut self, decimal: bool) -> Self {
+        self.decimal = decimal;
+        self
+    }
+
+    /// Sets the maximum number of characters of the value edited by the
+    /// [`NumPad`].
+    #[must_use]
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the width and height of the keys of the [`NumPad`].
+    #[must_use]
+    pub fn key_size(mut self, key_size: f32) -> Self {
+        self.key_size = key_size;
+        self
+    }
+
+    /// Sets the space between the keys of the [`NumPad`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the space between the keys and the edges of the [`NumPad`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the labels of the keys of the [`NumPad`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the labels of the keys of the [`NumPad`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`NumPad`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the keys shown with their bounds in the given bounds.
+    fn keys(&self, bounds: Rectangle) -> Vec<(NumPadKey, Rectangle)> {
+        let step = self.key_size + self.spacing;
+        let origin = Point::new(bounds.x + self.padding, bounds.y + self.padding);
+        let key = Size::new(self.key_size, self.key_size);
+
+        let mut keys: Vec<(NumPadKey, Rectangle)> = NumPadKey::GRID
+            .iter()
+            .enumerate()
+            .flat_map(|(row, keys)| {
+                keys.iter().enumerate().map(move |(column, &num_pad_key)| {
+                    let position = Point::new(
+                        origin.x + step * column as f32,
+                        origin.y + step * row as f32,
+                    );
+                    (num_pad_key, Rectangle::new(position, key))
+                })
+            })
+            .filter(|(num_pad_key, _)| self.decimal || *num_pad_key != NumPadKey::Decimal)
+            .collect();
+
+        keys.push((
+            NumPadKey::Confirm,
+            Rectangle::new(
+                Point::new(origin.x, origin.y + step * NumPadKey::GRID.len() as f32),
+                Size::new(step * 3.0 - self.spacing, self.key_size),
+            ),
+        ));
+
+        keys
+    }
+
+    /// Returns the key under the cursor.
+    fn key_at(&self, bounds: Rectangle, cursor: Cursor) -> Option<NumPadKey> {
+        let position = cursor.position_over(bounds)?;
+
+        self.keys(bounds)
+            .into_iter()
+            .find(|(_, key)| key.contains(position))
+            .map(|(key, _)| key)
+    }
+
+    /// Returns the label of a key, and whether it is an icon.
+    fn label(key: NumPadKey) -> (String, bool) {
+        match key {
+            NumPadKey::Digit(digit) => (digit.to_string(), false),
+            NumPadKey::Decimal => (String::from("."), false),
+            NumPadKey::Backspace => (icon_to_string(Bootstrap::Backspace), true),
+            NumPadKey::Confirm => (icon_to_string(Bootstrap::CheckLg), true),
+        }
+    }
+}
+
+impl<Message, Theme> NumPad<'_, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// Publishes the message of a pressed key.
+    fn press(&self, key: NumPadKey, shell: &mut Shell<'_, Message>) {
+        match &self.target {
+            Target::Keys(on_key) => shell.publish(on_key(key)),
+            Target::Value { .. } if key == NumPadKey::Confirm => {
+                if let Some(on_confirm) = &self.on_confirm {
+                    shell.publish(on_confirm.clone());
+                }
+            }
+            Target::Value { value, on_change } => {
+                if let Some(value) = apply(value, key, self.decimal, self.max_length) {
+                    shell.publish(on_change(value));
+                }
+            }
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for NumPad<'_, Message, Theme>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let rows = NumPadKey::GRID.len() as f32 + 1.0;
+        let size = Size::new(
+            self.key_size * 3.0 + self.spacing * 2.0 + self.padding * 2.0,
+            self.key_size * rows + self.spacing * (rows - 1.0) + self.padding * 2.0,
+        );
+
+        Node::new(limits.resolve(Length::Shrink, Length::Shrink, size))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(key) = self.key_at(bounds, cursor) {
+                    state.pressed = Some(key);
+                    return event::Status::Captured;
+                }
+            }
+
+            // A key is pressed when released over it, so that a finger can
+            // slide off a key to cancel it.
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(pressed) = state.pressed.take() {
+                    if self.key_at(bounds, cursor) == Some(pressed) {
+                        self.press(pressed, shell);
+                    }
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed = None;
+            }
+
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.key_at(layout.bounds(), cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let hovered = self.key_at(bounds, cursor);
+        let active = theme.active(&self.style);
+
+        if let Some(background) = active.background {
+            renderer.fill_quad(
+                Quad {
+                    bounds,
+                    border: Border::with_radius(active.key_border_radius),
+                    shadow: Shadow::default(),
+                },
+                background,
+            );
+        }
+
+        for (key, key_bounds) in self.keys(bounds) {
+            let appearance = if state.pressed == Some(key) {
+                theme.pressed(&self.style)
+            } else if hovered == Some(key) {
+                theme.hovered(&self.style)
+            } else {
+                active
+            };
+            let (background, text_color) = if key == NumPadKey::Confirm {
+                (appearance.confirm_background, appearance.confirm_text_color)
+            } else {
+                (appearance.key_background, appearance.key_text_color)
+            };
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: key_bounds,
+                    border: Border {
+                        radius: appearance.key_border_radius.into(),
+                        width: appearance.key_border_width,
+                        color: appearance.key_border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                background,
+            );
+
+            let (label, icon) = Self::label(key);
+            renderer.fill_text(
+                Text {
+                    content: &label,
+                    bounds: key_bounds.size(),
+                    size: Pixels(self.text_size),
+                    line_height: LineHeight::default(),
+                    font: if icon { BOOTSTRAP_FONT } else { self.font },
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: if icon {
+                        Shaping::Basic
+                    } else {
+                        Shaping::Advanced
+                    },
+                },
+                key_bounds.center(),
+                text_color,
+                key_bounds,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<NumPad<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(num_pad: NumPad<'a, Message, Theme>) -> Self {
+        Element::new(num_pad)
+    }
+}
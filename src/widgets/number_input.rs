@@ -6,6 +6,7 @@ use iced::{
         layout::{Limits, Node},
         renderer,
         widget::{
+            self,
             tree::{State, Tag},
             Operation, Tree,
         },
@@ -26,11 +27,11 @@ use iced::{
 use num_traits::{Num, NumAssignOps};
 use std::{fmt::Display, str::FromStr};
 
-use crate::style;
 pub use crate::{
     core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
     style::number_input::{self, Appearance, StyleSheet},
 };
+use crate::{core::settings, style};
 
 /// The default padding
 const DEFAULT_PADDING: f32 = 5.0;
@@ -85,6 +86,10 @@ where
     font: Renderer::Font,
     /// The Width to use for the NumberBox Default is Length::Fill
     width: Length,
+    /// The id of the [`NumberInput`].
+    id: Option<widget::Id>,
+    /// The parts of the [`NumberInput`] reached with Tab, in order.
+    tab_order: Vec<Part>,
 }
 
 impl<'a, T, Message, Theme, Renderer> NumberInput<'a, T, Message, Theme, Renderer>
@@ -109,7 +114,7 @@ where
         F: 'static + Fn(T) -> Message + Copy,
         T: 'static,
     {
-        let padding = DEFAULT_PADDING;
+        let padding = settings::spaced(DEFAULT_PADDING);
         let convert_to_num = move |s: String| {
             on_changed(T::from_str(&s).unwrap_or(if s.is_empty() { T::zero() } else { value }))
         };
@@ -128,6 +133,8 @@ where
             style: <Theme as number_input::StyleSheet>::Style::default(),
             font: Renderer::Font::default(),
             width: Length::Shrink,
+            id: None,
+            tab_order: vec![Part::Input],
         }
     }
 
@@ -185,6 +192,22 @@ where
         self
     }
 
+    /// Sets the id of the [`NumberInput`], to focus it with an operation.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the parts of the [`NumberInput`] reached with Tab, in order. The
+    /// parts left out are skipped; by default only the text input is
+    /// reached. A focused button is pressed with Enter or Space.
+    #[must_use]
+    pub fn tab_order(mut self, tab_order: impl IntoIterator<Item = Part>) -> Self {
+        self.tab_order = tab_order.into_iter().collect();
+        self
+    }
+
     /// Sets the padding of the [`NumberInput`].
     #[must_use]
     pub fn padding(mut self, units: f32) -> Self {
@@ -302,7 +325,9 @@ where
             .content
             .layout(&mut tree.children[0], renderer, &limits, None);
         let limits2 = Limits::new(Size::new(0.0, 0.0), content.size());
-        let txt_size = self.size.unwrap_or_else(|| renderer.default_size().0);
+        let txt_size = self
+            .size
+            .unwrap_or_else(|| settings::scaled(renderer.default_size().0));
 
         let icon_size = txt_size * 2.5 / 4.0;
         let btn_mod = |c| {
@@ -311,7 +336,7 @@ where
                 .center_x()
         };
 
-        let element = if self.padding < DEFAULT_PADDING {
+        let element = if self.padding < settings::spaced(DEFAULT_PADDING) {
             Element::new(
                 Row::<Message, Theme, Renderer>::new()
                     .spacing(1)
@@ -355,19 +380,31 @@ where
         &self,
         tree: &mut Tree,
         layout: Layout<'_>,
-        renderer: &Renderer,
+        _renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
         operation.container(None, layout.bounds(), &mut |operation| {
-            self.content.operate(
-                &mut tree.children[0],
-                layout
-                    .children()
-                    .next()
-                    .expect("NumberInput inner child Textbox was not created."),
-                renderer,
-                operation,
-            );
+            // The text input is operated on directly, so that it is found
+            // with the id of the number input.
+            let state = tree.children[0]
+                .state
+                .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+            let modifiers = tree.state.downcast_mut::<ModifierState>();
+
+            for part in &self.tab_order {
+                match part {
+                    Part::Input => operation.focusable(state, self.id.as_ref()),
+                    Part::Decrease | Part::Increase => {
+                        let mut button = ButtonFocus {
+                            focused: &mut modifiers.focused_button,
+                            button: *part,
+                        };
+                        operation.focusable(&mut button, None);
+                    }
+                }
+            }
+
+            operation.text_input(state, self.id.as_ref());
         });
     }
 
@@ -397,8 +434,12 @@ where
             .next()
             .expect("fail to get decreate mod layout")
             .bounds();
-        let mouse_over_inc = inc_bounds.contains(cursor.position().unwrap_or_default());
-        let mouse_over_dec = dec_bounds.contains(cursor.position().unwrap_or_default());
+        let hit = settings::hit_part(
+            &[inc_bounds, dec_bounds],
+            cursor.position().unwrap_or_default(),
+        );
+        let mouse_over_inc = hit == Some(0);
+        let mouse_over_dec = hit == Some(1);
         let modifiers = state.state.downcast_mut::<ModifierState>();
         let child = &mut state.children[0];
 
@@ -406,9 +447,29 @@ where
             return event::Status::Ignored;
         }
 
-        if layout
-            .bounds()
-            .contains(cursor.position().unwrap_or_default())
+        match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Enter | keyboard::key::Named::Space),
+                ..
+            }) if modifiers.focused_button.is_some() => {
+                if modifiers.focused_button == Some(Part::Decrease) {
+                    self.decrease_val(shell);
+                } else {
+                    self.increase_val(shell);
+                }
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                modifiers.focused_button = None;
+            }
+            _ => {}
+        }
+
+        if mouse_over_inc
+            || mouse_over_dec
+            || layout
+                .bounds()
+                .contains(cursor.position().unwrap_or_default())
         {
             if mouse_over_inc || mouse_over_dec {
                 let mut event_status = event::Status::Captured;
@@ -620,8 +681,12 @@ where
         let is_mouse_over = bounds.contains(cursor.position().unwrap_or_default());
         let is_decrease_disabled = self.value <= self.bounds.0 || self.bounds.0 == self.bounds.1;
         let is_increase_disabled = self.value >= self.bounds.1 || self.bounds.0 == self.bounds.1;
-        let mouse_over_decrease = dec_bounds.contains(cursor.position().unwrap_or_default());
-        let mouse_over_increase = inc_bounds.contains(cursor.position().unwrap_or_default());
+        let hit = settings::hit_part(
+            &[inc_bounds, dec_bounds],
+            cursor.position().unwrap_or_default(),
+        );
+        let mouse_over_increase = hit == Some(0);
+        let mouse_over_decrease = hit == Some(1);
 
         if (mouse_over_decrease && !is_decrease_disabled)
             || (mouse_over_increase && !is_increase_disabled)
@@ -669,12 +734,20 @@ where
         );
         let is_decrease_disabled = self.value <= self.bounds.0 || self.bounds.0 == self.bounds.1;
         let is_increase_disabled = self.value >= self.bounds.1 || self.bounds.0 == self.bounds.1;
+        let is_focused = state.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
 
         let decrease_btn_style = if is_decrease_disabled {
             style::number_input::StyleSheet::disabled(theme, &self.style)
             //theme.disabled(&self.style)
         } else if state.state.downcast_ref::<ModifierState>().decrease_pressed {
             style::number_input::StyleSheet::pressed(theme, &self.style)
+        } else if is_focused
+            || state.state.downcast_ref::<ModifierState>().focused_button == Some(Part::Decrease)
+        {
+            style::number_input::StyleSheet::focused(theme, &self.style)
         } else {
             style::number_input::StyleSheet::active(theme, &self.style)
         };
@@ -683,11 +756,17 @@ where
             style::number_input::StyleSheet::disabled(theme, &self.style)
         } else if state.state.downcast_ref::<ModifierState>().increase_pressed {
             style::number_input::StyleSheet::pressed(theme, &self.style)
+        } else if is_focused
+            || state.state.downcast_ref::<ModifierState>().focused_button == Some(Part::Increase)
+        {
+            style::number_input::StyleSheet::focused(theme, &self.style)
         } else {
             style::number_input::StyleSheet::active(theme, &self.style)
         };
 
-        let txt_size = self.size.unwrap_or_else(|| renderer.default_size().0);
+        let txt_size = self
+            .size
+            .unwrap_or_else(|| settings::scaled(renderer.default_size().0));
 
         let icon_size = Pixels(txt_size * 2.5 / 4.0);
 
@@ -697,8 +776,8 @@ where
                 bounds: dec_bounds,
                 border: Border {
                     radius: (3.0).into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
+                    width: decrease_btn_style.button_border_width,
+                    color: decrease_btn_style.button_border_color,
                 },
                 shadow: Shadow::default(),
             },
@@ -729,8 +808,8 @@ where
                 bounds: inc_bounds,
                 border: Border {
                     radius: (3.0).into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
+                    width: increase_btn_style.button_border_width,
+                    color: increase_btn_style.button_border_color,
                 },
                 shadow: Shadow::default(),
             },
@@ -764,6 +843,43 @@ pub struct ModifierState {
     pub decrease_pressed: bool,
     /// The state of increase button on a [`NumberInput`].
     pub increase_pressed: bool,
+    /// The button of a [`NumberInput`] having the keyboard focus.
+    pub focused_button: Option<Part>,
+}
+
+/// A part of a [`NumberInput`] reached with Tab.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    /// The text input.
+    Input,
+    /// The button decreasing the value.
+    Decrease,
+    /// The button increasing the value.
+    Increase,
+}
+
+/// The focus of a button of a [`NumberInput`], given to the operations.
+struct ButtonFocus<'a> {
+    /// The button having the keyboard focus.
+    focused: &'a mut Option<Part>,
+    /// The button given to the operations.
+    button: Part,
+}
+
+impl widget::operation::Focusable for ButtonFocus<'_> {
+    fn is_focused(&self) -> bool {
+        *self.focused == Some(self.button)
+    }
+
+    fn focus(&mut self) {
+        *self.focused = Some(self.button);
+    }
+
+    fn unfocus(&mut self) {
+        if self.is_focused() {
+            *self.focused = None;
+        }
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> From<NumberInput<'a, T, Message, Theme, Renderer>>
@@ -0,0 +1,214 @@
+//! The overlay of an [`AnchoredOverlay`](crate::widgets::anchored_overlay::AnchoredOverlay)
+//! showing its content.
+//!
+//! *This API requires the following crate features to be activated: `anchored_overlay`*
+use crate::core::overlay::{anchor, Placement};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay, renderer,
+        widget::{Operation, Tree},
+        Clipboard, Layout, Overlay, Shell,
+    },
+    event, keyboard,
+    mouse::{self, Cursor},
+    touch, Element, Event, Rectangle, Size, Vector,
+};
+
+/// The overlay of an [`AnchoredOverlay`](crate::widgets::anchored_overlay::AnchoredOverlay).
+#[allow(missing_debug_implementations)]
+pub struct AnchoredOverlayOverlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    /// The state of the content.
+    tree: &'b mut Tree,
+    /// The content of the overlay.
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    /// The bounds of the target the content is anchored to.
+    target: Rectangle,
+    placement: Placement,
+    gap: f32,
+    /// The optional message produced when the user closes the overlay.
+    on_dismiss: Option<Message>,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> AnchoredOverlayOverlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`AnchoredOverlayOverlay`].
+    pub(crate) fn new(
+        tree: &'b mut Tree,
+        content: &'b mut Element<'a, Message, Theme, Renderer>,
+        target: Rectangle,
+        placement: Placement,
+        gap: f32,
+        on_dismiss: Option<Message>,
+    ) -> Self {
+        Self {
+            tree,
+            content,
+            target,
+            placement,
+            gap,
+            on_dismiss,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for AnchoredOverlayOverlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let limits = Limits::new(Size::ZERO, bounds);
+        let content = self
+            .content
+            .as_widget()
+            .layout(self.tree, renderer, &limits);
+        let size = content.size();
+
+        Node::with_children(size, vec![content]).move_to(anchor(
+            self.target,
+            size,
+            self.placement,
+            self.gap,
+            bounds,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        if let Some(on_dismiss) = &self.on_dismiss {
+            if let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) = event
+            {
+                shell.publish(on_dismiss.clone());
+                return event::Status::Captured;
+            }
+        }
+
+        let status = layout
+            .children()
+            .next()
+            .map_or(event::Status::Ignored, |content_layout| {
+                self.content.as_widget_mut().on_event(
+                    self.tree,
+                    event.clone(),
+                    content_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &bounds,
+                )
+            });
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => match cursor.position() {
+                Some(position) if bounds.contains(position) => event::Status::Captured,
+                // Presses on the target are left to the target, which usually
+                // toggles the overlay itself.
+                Some(position) if !self.target.contains(position) => {
+                    if let Some(on_dismiss) = &self.on_dismiss {
+                        shell.publish(on_dismiss.clone());
+                    }
+                    status
+                }
+                _ => status,
+            },
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        layout
+            .children()
+            .next()
+            .map_or_else(mouse::Interaction::default, |content_layout| {
+                self.content.as_widget().mouse_interaction(
+                    self.tree,
+                    content_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+
+        if let Some(content_layout) = layout.children().next() {
+            self.content.as_widget().draw(
+                self.tree,
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                &bounds,
+            );
+        }
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        let content_layout = layout.children().next()?;
+
+        self.content
+            .as_widget_mut()
+            .overlay(self.tree, content_layout, renderer, Vector::ZERO)
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let Some(content_layout) = layout.children().next() {
+            self.content
+                .as_widget()
+                .operate(self.tree, content_layout, renderer, operation);
+        }
+    }
+}
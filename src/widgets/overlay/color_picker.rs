@@ -4,10 +4,12 @@
 
 use crate::{
     color_picker,
+    core::focus::{self, Direction},
     core::icons::bootstrap::{icon_to_string, Bootstrap},
     core::{
         color::{HexString, Hsv},
         overlay::Position,
+        settings,
     },
     style::{
         color_picker::{Appearance, StyleSheet},
@@ -21,7 +23,7 @@ use iced::{
         layout::{Limits, Node},
         overlay, renderer,
         text::Renderer as _,
-        widget::{self, tree::Tree},
+        widget::{self, operation, tree::Tree},
         Clipboard, Layout, Overlay, Renderer as _, Shell, Text, Widget,
     },
     alignment::{self, Horizontal, Vertical},
@@ -41,6 +43,7 @@ use iced::{
     Event,
     Length,
     Padding,
+    Pixels,
     Point,
     Rectangle,
     Renderer, // the actual type
@@ -48,7 +51,7 @@ use iced::{
     Size,
     Vector,
 };
-use std::collections::HashMap;
+use std::{cell::Cell, collections::HashMap};
 
 /// The padding around the elements.
 const PADDING: f32 = 10.0;
@@ -85,6 +88,9 @@ where
     style: <Theme as StyleSheet>::Style,
     /// The reference to the tree holding the state of this overlay.
     tree: &'a mut Tree,
+    /// The parts of the [`ColorPickerOverlay`] reached with Tab, in order, or the
+    /// default order when empty.
+    tab_order: &'a [Focus],
 }
 
 impl<'a, Message, Theme> ColorPickerOverlay<'a, Message, Theme>
@@ -126,9 +132,18 @@ where
             position,
             style,
             tree,
+            tab_order: &[],
         }
     }
 
+    /// Sets the parts of the [`ColorPickerOverlay`] reached with Tab, in order. The
+    /// default order is used when empty.
+    #[must_use]
+    pub fn tab_order(mut self, tab_order: &'a [Focus]) -> Self {
+        self.tab_order = tab_order;
+        self
+    }
+
     /// Turn this [`ColorPickerOverlay`] into an overlay [`Element`](overlay::Element).
     #[must_use]
     pub fn overlay(self) -> overlay::Element<'a, Message, Theme, Renderer> {
@@ -440,14 +455,17 @@ where
             let mut status = event::Status::Ignored;
 
             if matches!(key, keyboard::Key::Named(keyboard::key::Named::Tab)) {
-                if self.state.keyboard_modifiers.shift() {
-                    self.state.focus = self.state.focus.previous();
+                let direction = if self.state.keyboard_modifiers.shift() {
+                    Direction::Previous
                 } else {
-                    self.state.focus = self.state.focus.next();
-                }
-                // TODO: maybe place this better
-                self.state.sat_value_canvas_cache.clear();
-                self.state.hue_canvas_cache.clear();
+                    Direction::Next
+                };
+
+                self.state.focus = focus::cycle(self.tab_order, self.state.focus, direction)
+                    .unwrap_or_else(|| match direction {
+                        Direction::Next => self.state.focus.next(),
+                        Direction::Previous => self.state.focus.previous(),
+                    });
             } else {
                 let sat_value_handle = |key_code: &keyboard::Key, color: &mut Color| {
                     let mut hsv_color: Hsv = (*color).into();
@@ -642,8 +660,6 @@ where
         if event::Status::Captured
             == self.on_event_keyboard(&event, layout, cursor, shell, renderer, clipboard)
         {
-            self.state.sat_value_canvas_cache.clear();
-            self.state.hue_canvas_cache.clear();
             return event::Status::Captured;
         }
 
@@ -719,13 +735,6 @@ where
         }
         // ----------- Block 2 end ------------------
 
-        if hsv_color_status == event::Status::Captured
-            || rgba_color_status == event::Status::Captured
-        {
-            self.state.sat_value_canvas_cache.clear();
-            self.state.hue_canvas_cache.clear();
-        }
-
         status
             .merge(hsv_color_status)
             .merge(rgba_color_status)
@@ -962,7 +971,9 @@ where
 
     let mut hex_text_layout = Row::<Message, Theme, Renderer>::new()
         .width(Length::Fill)
-        .height(Length::Fixed(renderer.default_size().0 + 2.0 * PADDING))
+        .height(Length::Fixed(
+            settings::scaled(renderer.default_size().0) + 2.0 * PADDING,
+        ))
         .layout(color_picker.tree, renderer, &hex_text_limits);
 
     let block2_limits = block2_limits.shrink(Size::new(
@@ -1231,81 +1242,80 @@ fn hsv_color<Message, Theme>(
         sat_value_style_state = sat_value_style_state.max(StyleState::Hovered);
     }
 
-    let geometry = color_picker.state.sat_value_canvas_cache.draw(
-        renderer,
-        sat_value_layout.bounds().size(),
-        |frame| {
-            let column_count = frame.width() as u16;
-            let row_count = frame.height() as u16;
-
-            for column in 0..column_count {
-                for row in 0..row_count {
-                    let saturation = f32::from(column) / frame.width();
-                    let value = f32::from(row) / frame.height();
-
-                    frame.fill_rectangle(
-                        Point::new(f32::from(column), f32::from(row)),
-                        Size::new(1.0, 1.0),
-                        Color::from(Hsv::from_hsv(hsv_color.hue, saturation, value)),
-                    );
-                }
-            }
+    // The gradient only depends on the size of the area, which the cache
+    // checks, and on the hue.
+    let state = &color_picker.state;
+    if state.sat_value_hue.replace(Some(hsv_color.hue)) != Some(hsv_color.hue) {
+        state.sat_value_canvas_cache.clear();
+    }
 
-            let stroke = Stroke {
-                style: Style::Solid(
-                    Hsv {
-                        hue: 0,
-                        saturation: 0.0,
-                        value: 1.0 - hsv_color.value,
-                    }
-                    .into(),
-                ),
-                width: 3.0,
-                line_cap: LineCap::Round,
-                ..Stroke::default()
-            };
+    let size = sat_value_layout.bounds().size();
+    let gradient = state.sat_value_canvas_cache.draw(renderer, size, |frame| {
+        let column_count = frame.width() as u16;
+        let row_count = frame.height() as u16;
 
-            let saturation = hsv_color.saturation * frame.width();
-            let value = hsv_color.value * frame.height();
+        for column in 0..column_count {
+            for row in 0..row_count {
+                let saturation = f32::from(column) / frame.width();
+                let value = f32::from(row) / frame.height();
 
-            frame.stroke(
-                &Path::line(
-                    Point::new(saturation, 0.0),
-                    Point::new(saturation, frame.height()),
-                ),
-                stroke.clone(),
-            );
+                frame.fill_rectangle(
+                    Point::new(f32::from(column), f32::from(row)),
+                    Size::new(1.0, 1.0),
+                    Color::from(Hsv::from_hsv(hsv_color.hue, saturation, value)),
+                );
+            }
+        }
+    });
 
-            frame.stroke(
-                &Path::line(Point::new(0.0, value), Point::new(frame.width(), value)),
-                stroke,
-            );
+    let mut frame = canvas::Frame::new(renderer, size);
+    let stroke = Stroke {
+        style: Style::Solid(
+            Hsv {
+                hue: 0,
+                saturation: 0.0,
+                value: 1.0 - hsv_color.value,
+            }
+            .into(),
+        ),
+        width: 3.0,
+        line_cap: LineCap::Round,
+        ..Stroke::default()
+    };
 
-            let stroke = Stroke {
-                style: Style::Solid(
-                    style_sheet
-                        .get(&sat_value_style_state)
-                        .expect("Style Sheet not found.")
-                        .bar_border_color,
-                ),
-                width: 2.0,
-                line_cap: LineCap::Round,
-                ..Stroke::default()
-            };
+    let saturation = hsv_color.saturation * frame.width();
+    let value = hsv_color.value * frame.height();
 
-            frame.stroke(
-                &Path::rectangle(
-                    Point::new(0.0, 0.0),
-                    Size::new(frame.size().width - 0.0, frame.size().height - 0.0),
-                ),
-                stroke,
-            );
-        },
+    frame.stroke(
+        &Path::line(
+            Point::new(saturation, 0.0),
+            Point::new(saturation, frame.height()),
+        ),
+        stroke.clone(),
     );
 
+    frame.stroke(
+        &Path::line(Point::new(0.0, value), Point::new(frame.width(), value)),
+        stroke,
+    );
+
+    let stroke = Stroke {
+        style: Style::Solid(
+            style_sheet
+                .get(&sat_value_style_state)
+                .expect("Style Sheet not found.")
+                .bar_border_color,
+        ),
+        width: 2.0,
+        line_cap: LineCap::Round,
+        ..Stroke::default()
+    };
+
+    frame.stroke(&Path::rectangle(Point::ORIGIN, frame.size()), stroke);
+
     let translation = Vector::new(sat_value_layout.bounds().x, sat_value_layout.bounds().y);
     renderer.with_translation(translation, |renderer| {
-        renderer.draw(vec![geometry]);
+        renderer.draw(vec![gradient, frame.into_geometry()]);
     });
 
     let hue_layout = hsv_color_children
@@ -1319,71 +1329,65 @@ fn hsv_color<Message, Theme>(
         hue_style_state = hue_style_state.max(StyleState::Hovered);
     }
 
-    let geometry =
-        color_picker
-            .state
-            .hue_canvas_cache
-            .draw(renderer, hue_layout.bounds().size(), |frame| {
-                let column_count = frame.width() as u16;
-
-                for column in 0..column_count {
-                    let hue = (f32::from(column) * 360.0 / frame.width()) as u16;
-
-                    let hsv_color = Hsv::from_hsv(hue, 1.0, 1.0);
-                    let stroke = Stroke {
-                        style: Style::Solid(hsv_color.into()),
-                        width: 1.0,
-                        line_cap: LineCap::Round,
-                        ..Stroke::default()
-                    };
+    // The gradient only depends on the size of the strip, which the cache
+    // checks.
+    let size = hue_layout.bounds().size();
+    let gradient = state.hue_canvas_cache.draw(renderer, size, |frame| {
+        let column_count = frame.width() as u16;
 
-                    frame.stroke(
-                        &Path::line(
-                            Point::new(f32::from(column), 0.0),
-                            Point::new(f32::from(column), frame.height()),
-                        ),
-                        stroke,
-                    );
-                }
+        for column in 0..column_count {
+            let hue = (f32::from(column) * 360.0 / frame.width()) as u16;
 
-                let stroke = Stroke {
-                    style: Style::Solid(Color::BLACK),
-                    width: 3.0,
-                    line_cap: LineCap::Round,
-                    ..Stroke::default()
-                };
+            let hsv_color = Hsv::from_hsv(hue, 1.0, 1.0);
+            let stroke = Stroke {
+                style: Style::Solid(hsv_color.into()),
+                width: 1.0,
+                line_cap: LineCap::Round,
+                ..Stroke::default()
+            };
 
-                let column = f32::from(hsv_color.hue) * frame.width() / 360.0;
+            frame.stroke(
+                &Path::line(
+                    Point::new(f32::from(column), 0.0),
+                    Point::new(f32::from(column), frame.height()),
+                ),
+                stroke,
+            );
+        }
+    });
 
-                frame.stroke(
-                    &Path::line(Point::new(column, 0.0), Point::new(column, frame.height())),
-                    stroke,
-                );
+    let mut frame = canvas::Frame::new(renderer, size);
+    let stroke = Stroke {
+        style: Style::Solid(Color::BLACK),
+        width: 3.0,
+        line_cap: LineCap::Round,
+        ..Stroke::default()
+    };
 
-                let stroke = Stroke {
-                    style: Style::Solid(
-                        style_sheet
-                            .get(&hue_style_state)
-                            .expect("Style Sheet not found.")
-                            .bar_border_color,
-                    ),
-                    width: 2.0,
-                    line_cap: LineCap::Round,
-                    ..Stroke::default()
-                };
+    let column = f32::from(hsv_color.hue) * frame.width() / 360.0;
 
-                frame.stroke(
-                    &Path::rectangle(
-                        Point::new(0.0, 0.0),
-                        Size::new(frame.size().width, frame.size().height),
-                    ),
-                    stroke,
-                );
-            });
+    frame.stroke(
+        &Path::line(Point::new(column, 0.0), Point::new(column, frame.height())),
+        stroke,
+    );
+
+    let stroke = Stroke {
+        style: Style::Solid(
+            style_sheet
+                .get(&hue_style_state)
+                .expect("Style Sheet not found.")
+                .bar_border_color,
+        ),
+        width: 2.0,
+        line_cap: LineCap::Round,
+        ..Stroke::default()
+    };
+
+    frame.stroke(&Path::rectangle(Point::ORIGIN, frame.size()), stroke);
 
     let translation = Vector::new(hue_layout.bounds().x, hue_layout.bounds().y);
     renderer.with_translation(translation, |renderer| {
-        renderer.draw(vec![geometry]);
+        renderer.draw(vec![gradient, frame.into_geometry()]);
     });
 }
 
@@ -1424,7 +1428,7 @@ fn rgba_color(
             Text {
                 content: label,
                 bounds: Size::new(label_layout.bounds().width, label_layout.bounds().height),
-                size: renderer.default_size(),
+                size: Pixels(settings::scaled(renderer.default_size().0)),
                 font: crate::BOOTSTRAP_FONT,
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
@@ -1502,7 +1506,7 @@ fn rgba_color(
             Text {
                 content: &format!("{}", (255.0 * value) as u8),
                 bounds: Size::new(value_layout.bounds().width, value_layout.bounds().height),
-                size: renderer.default_size(),
+                size: Pixels(settings::scaled(renderer.default_size().0)),
                 font: renderer.default_font(),
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
@@ -1639,7 +1643,7 @@ fn hex_text(
         Text {
             content: &color.as_hex_string(),
             bounds: Size::new(layout.bounds().width, layout.bounds().height),
-            size: renderer.default_size(),
+            size: Pixels(settings::scaled(renderer.default_size().0)),
             font: renderer.default_font(),
             horizontal_alignment: Horizontal::Center,
             vertical_alignment: Vertical::Center,
@@ -1665,9 +1669,11 @@ fn hex_text(
 pub struct State {
     /// The selected color of the [`ColorPickerOverlay`].
     pub(crate) color: Color,
-    /// The cache of the sat/value canvas of the [`ColorPickerOverlay`].
+    /// The cache of the sat/value gradient of the [`ColorPickerOverlay`].
     pub(crate) sat_value_canvas_cache: canvas::Cache,
-    /// The cache of the hue canvas of the [`ColorPickerOverlay`].
+    /// The hue the cached sat/value gradient is drawn with.
+    pub(crate) sat_value_hue: Cell<Option<u16>>,
+    /// The cache of the hue gradient of the [`ColorPickerOverlay`].
     pub(crate) hue_canvas_cache: canvas::Cache,
     /// The dragged color bar of the [`ColorPickerOverlay`].
     pub(crate) color_bar_dragged: ColorBarDragged,
@@ -1693,6 +1699,7 @@ impl Default for State {
         Self {
             color: Color::from_rgb(0.5, 0.25, 0.25),
             sat_value_canvas_cache: canvas::Cache::default(),
+            sat_value_hue: Cell::new(None),
             hue_canvas_cache: canvas::Cache::default(),
             color_bar_dragged: ColorBarDragged::None,
             focus: Focus::default(),
@@ -1815,6 +1822,22 @@ impl Default for ColorBarDragged {
     }
 }
 
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focus != Focus::None
+    }
+
+    fn focus(&mut self) {
+        if self.focus == Focus::None {
+            self.focus = Focus::Overlay;
+        }
+    }
+
+    fn unfocus(&mut self) {
+        self.focus = Focus::None;
+    }
+}
+
 /// An enumeration of all focusable element of the [`ColorPickerOverlay`].
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Focus {
@@ -0,0 +1,376 @@
+//! The overlay of a [`CupertinoActionSheet`](crate::widgets::cupertino::cupertino_action_sheet::CupertinoActionSheet).
+//!
+//! *This API requires the following crate features to be activated: cupertino*
+use crate::core::{animation::Easing, SF_UI_ROUNDED};
+use crate::widgets::cupertino::{
+    cupertino_action_sheet::{ActionSheetState, Sheet},
+    cupertino_colors::{system_blue, system_red},
+};
+
+use iced::{
+    advanced::{
+        layout::Node,
+        renderer::{self, Quad},
+        text::{self, Text},
+        Clipboard, Layout, Overlay, Shell,
+    },
+    alignment::{Horizontal, Vertical},
+    border::Radius,
+    event, font, keyboard,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Color, Event, Font, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+const MARGIN: f32 = 8.0;
+const MAX_WIDTH: f32 = 400.0;
+const RADIUS: f32 = 13.0;
+const ACTION_HEIGHT: f32 = 57.0;
+const ACTION_TEXT_SIZE: f32 = 20.0;
+const HEADER_PADDING: f32 = 14.0;
+const HEADER_LINE_HEIGHT: f32 = 18.0;
+const HEADER_TEXT_SIZE: f32 = 13.0;
+const SEPARATOR_HEIGHT: f32 = 0.5;
+
+/// The overlay of a [`CupertinoActionSheet`](crate::widgets::cupertino::cupertino_action_sheet::CupertinoActionSheet).
+#[allow(missing_debug_implementations)]
+pub struct ActionSheetOverlay<'b, Message> {
+    /// The state of the action sheet.
+    state: &'b mut ActionSheetState,
+    /// The content of the action sheet.
+    sheet: &'b Sheet<Message>,
+    /// Whether the actions can be pressed, false while the sheet slides down.
+    interactive: bool,
+}
+
+/// The bounds of the parts of the sheet.
+struct Parts {
+    group: Option<Rectangle>,
+    header: Option<Rectangle>,
+    actions: Vec<Rectangle>,
+    cancel: Option<Rectangle>,
+}
+
+impl<'b, Message> ActionSheetOverlay<'b, Message>
+where
+    Message: Clone,
+{
+    /// Creates a new [`ActionSheetOverlay`].
+    pub(crate) fn new(
+        state: &'b mut ActionSheetState,
+        sheet: &'b Sheet<Message>,
+        interactive: bool,
+    ) -> Self {
+        Self {
+            state,
+            sheet,
+            interactive,
+        }
+    }
+
+    /// Returns the progress of the animation with an ease-out curve.
+    fn eased(&self) -> f32 {
+        Easing::EaseOut.apply(self.state.slide.progress())
+    }
+
+    /// Computes the bounds of the parts of the sheet in the window `bounds`.
+    fn parts(&self, bounds: Rectangle) -> Parts {
+        let width = (bounds.width - MARGIN * 2.0).clamp(0.0, MAX_WIDTH);
+        let x = bounds.x + (bounds.width - width) / 2.0;
+
+        let lines =
+            usize::from(self.sheet.title.is_some()) + usize::from(self.sheet.message.is_some());
+        let header_height = if lines == 0 {
+            0.0
+        } else {
+            HEADER_PADDING * 2.0 + HEADER_LINE_HEIGHT * lines as f32
+        };
+        let group_height = header_height + ACTION_HEIGHT * self.sheet.actions.len() as f32;
+        let cancel_height = if self.sheet.cancel.is_some() {
+            ACTION_HEIGHT + MARGIN
+        } else {
+            0.0
+        };
+        let total = group_height + cancel_height + MARGIN;
+        let top = bounds.y + bounds.height - total * self.eased();
+
+        let row = |y: f32, height: f32| Rectangle {
+            x,
+            y,
+            width,
+            height,
+        };
+
+        Parts {
+            group: (group_height > 0.0).then(|| row(top, group_height)),
+            header: (header_height > 0.0).then(|| row(top, header_height)),
+            actions: (0..self.sheet.actions.len())
+                .map(|i| {
+                    row(
+                        top + header_height + ACTION_HEIGHT * i as f32,
+                        ACTION_HEIGHT,
+                    )
+                })
+                .collect(),
+            cancel: self
+                .sheet
+                .cancel
+                .as_ref()
+                .map(|_| row(top + group_height + MARGIN, ACTION_HEIGHT)),
+        }
+    }
+
+    /// Returns the index of the action under the cursor, the cancel action
+    /// being after the others.
+    fn action_at(parts: &Parts, cursor: Cursor) -> Option<usize> {
+        parts
+            .actions
+            .iter()
+            .chain(parts.cancel.iter())
+            .position(|bounds| cursor.is_over(*bounds))
+    }
+}
+
+fn text(content: &str, size: f32, font: Font, bounds: Rectangle) -> Text<'_, Font> {
+    Text {
+        content,
+        bounds: bounds.size(),
+        size: Pixels(size),
+        line_height: LineHeight::default(),
+        font,
+        horizontal_alignment: Horizontal::Center,
+        vertical_alignment: Vertical::Center,
+        shaping: Shaping::Advanced,
+    }
+}
+
+fn fill(renderer: &mut impl renderer::Renderer, bounds: Rectangle, radius: Radius, color: Color) {
+    renderer.fill_quad(
+        Quad {
+            bounds,
+            border: Border {
+                radius,
+                ..Border::default()
+            },
+            shadow: Shadow::default(),
+        },
+        color,
+    );
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer> for ActionSheetOverlay<'_, Message>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        Node::new(bounds)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let parts = self.parts(bounds);
+        let semibold = Font {
+            weight: font::Weight::Semibold,
+            ..SF_UI_ROUNDED
+        };
+        let grey = Color::from_rgb(0.56, 0.56, 0.58);
+        let separator = Color::from_rgba(0.0, 0.0, 0.0, 0.2);
+        let pressed = Color::from_rgba(0.0, 0.0, 0.0, 0.1);
+
+        fill(
+            renderer,
+            bounds,
+            0.0.into(),
+            Color::from_rgba(0.0, 0.0, 0.0, 0.4 * self.eased()),
+        );
+
+        if let Some(group) = parts.group {
+            fill(
+                renderer,
+                group,
+                RADIUS.into(),
+                Color::from_rgba(0.97, 0.97, 0.97, 0.95),
+            );
+        }
+
+        if let Some(header) = parts.header {
+            let lines = [
+                self.sheet.title.as_deref().map(|title| (title, semibold)),
+                self.sheet
+                    .message
+                    .as_deref()
+                    .map(|message| (message, SF_UI_ROUNDED)),
+            ];
+
+            for (i, (content, font)) in lines.into_iter().flatten().enumerate() {
+                let line = Rectangle {
+                    y: header.y + HEADER_PADDING + HEADER_LINE_HEIGHT * i as f32,
+                    height: HEADER_LINE_HEIGHT,
+                    ..header
+                };
+
+                renderer.fill_text(
+                    text(content, HEADER_TEXT_SIZE, font, line),
+                    line.center(),
+                    grey,
+                    line,
+                );
+            }
+        }
+
+        let count = parts.actions.len();
+        for (i, (action, bounds)) in self.sheet.actions.iter().zip(&parts.actions).enumerate() {
+            if i > 0 || parts.header.is_some() {
+                fill(
+                    renderer,
+                    Rectangle {
+                        height: SEPARATOR_HEIGHT,
+                        ..*bounds
+                    },
+                    0.0.into(),
+                    separator,
+                );
+            }
+
+            if self.state.pressed == Some(i) {
+                let top = if i == 0 && parts.header.is_none() {
+                    RADIUS
+                } else {
+                    0.0
+                };
+                let bottom = if i + 1 == count { RADIUS } else { 0.0 };
+
+                fill(
+                    renderer,
+                    *bounds,
+                    [top, top, bottom, bottom].into(),
+                    pressed,
+                );
+            }
+
+            renderer.fill_text(
+                text(&action.label, ACTION_TEXT_SIZE, SF_UI_ROUNDED, *bounds),
+                bounds.center(),
+                if action.destructive {
+                    system_red(1.0)
+                } else {
+                    system_blue(1.0)
+                },
+                *bounds,
+            );
+        }
+
+        if let (Some(cancel), Some(bounds)) = (&self.sheet.cancel, parts.cancel) {
+            fill(renderer, bounds, RADIUS.into(), Color::WHITE);
+
+            if self.state.pressed == Some(count) {
+                fill(renderer, bounds, RADIUS.into(), pressed);
+            }
+
+            renderer.fill_text(
+                text(&cancel.label, ACTION_TEXT_SIZE, semibold, bounds),
+                bounds.center(),
+                system_blue(1.0),
+                bounds,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        if !self.interactive {
+            return event::Status::Ignored;
+        }
+
+        let parts = self.parts(layout.bounds());
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                if let Some(on_dismiss) = &self.sheet.on_dismiss {
+                    shell.publish(on_dismiss.clone());
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                self.state.pressed = Self::action_at(&parts, cursor);
+
+                let over_sheet = parts
+                    .group
+                    .iter()
+                    .chain(parts.cancel.iter())
+                    .any(|bounds| cursor.is_over(*bounds));
+
+                if !over_sheet {
+                    if let Some(on_dismiss) = &self.sheet.on_dismiss {
+                        shell.publish(on_dismiss.clone());
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(pressed) = self.state.pressed.take() {
+                    if Self::action_at(&parts, cursor) == Some(pressed) {
+                        let action = self
+                            .sheet
+                            .actions
+                            .get(pressed)
+                            .or(self.sheet.cancel.as_ref());
+
+                        if let Some(action) = action {
+                            shell.publish(action.message.clone());
+                        }
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.interactive && Self::action_at(&self.parts(layout.bounds()), cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        // The backdrop covers the whole window.
+        true
+    }
+}
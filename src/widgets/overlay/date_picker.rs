@@ -3,6 +3,7 @@
 //! *This API requires the following crate features to be activated: `date_picker`*
 
 use crate::{
+    core::focus::{self, Direction},
     core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
     core::{
         date::{Date, IsInMonth},
@@ -21,7 +22,7 @@ use iced::{
         layout::{Limits, Node},
         overlay, renderer,
         text::Renderer as _,
-        widget::tree::Tree,
+        widget::{operation, tree::Tree},
         Clipboard, Layout, Overlay, Renderer as _, Shell, Widget,
     },
     alignment::{Horizontal, Vertical},
@@ -78,6 +79,9 @@ where
     tree: &'a mut Tree,
     /// The font size of text and icons in the [`DatePickerOverlay`]
     font_size: Pixels,
+    /// The parts of the [`DatePickerOverlay`] reached with Tab, in order, or the
+    /// default order when empty.
+    tab_order: &'a [Focus],
 }
 
 impl<'a, Message, Theme> DatePickerOverlay<'a, Message, Theme>
@@ -123,9 +127,18 @@ where
             style,
             tree,
             font_size,
+            tab_order: &[],
         }
     }
 
+    /// Sets the parts of the [`DatePickerOverlay`] reached with Tab, in order. The
+    /// default order is used when empty.
+    #[must_use]
+    pub fn tab_order(mut self, tab_order: &'a [Focus]) -> Self {
+        self.tab_order = tab_order;
+        self
+    }
+
     /// Turn this [`DatePickerOverlay`] into an overlay [`Element`](overlay::Element).
     #[must_use]
     pub fn overlay(self) -> overlay::Element<'a, Message, Theme, Renderer> {
@@ -311,11 +324,17 @@ where
 
             match key.as_ref() {
                 keyboard::Key::Named(keyboard::key::Named::Tab) => {
-                    if self.state.keyboard_modifiers.shift() {
-                        self.state.focus = self.state.focus.previous();
+                    let direction = if self.state.keyboard_modifiers.shift() {
+                        Direction::Previous
                     } else {
-                        self.state.focus = self.state.focus.next();
-                    }
+                        Direction::Next
+                    };
+
+                    self.state.focus = focus::cycle(self.tab_order, self.state.focus, direction)
+                        .unwrap_or_else(|| match direction {
+                            Direction::Next => self.state.focus.next(),
+                            Direction::Previous => self.state.focus.previous(),
+                        });
                 }
                 keyboard::Key::Named(k) => match self.state.focus {
                     Focus::Month => match k {
@@ -1006,6 +1025,22 @@ where
     }
 }
 
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focus != Focus::None
+    }
+
+    fn focus(&mut self) {
+        if self.focus == Focus::None {
+            self.focus = Focus::Overlay;
+        }
+    }
+
+    fn unfocus(&mut self) {
+        self.focus = Focus::None;
+    }
+}
+
 /// An enumeration of all focusable elements of the [`DatePickerOverlay`].
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Focus {
@@ -0,0 +1,120 @@
+//! The overlay of a [`Draggable`](crate::widgets::drag_and_drop::Draggable)
+//! showing the preview following the cursor.
+//!
+//! *This API requires the following crate features to be activated: `drag_and_drop`*
+use crate::style::drag_and_drop::StyleSheet;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        widget::Tree,
+        Layout, Overlay,
+    },
+    mouse::Cursor,
+    Border, Element, Point, Size,
+};
+
+/// The overlay of a [`Draggable`](crate::widgets::drag_and_drop::Draggable).
+#[allow(missing_debug_implementations)]
+pub struct DragPreviewOverlay<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The state of the preview.
+    tree: &'b mut Tree,
+    /// The preview following the cursor.
+    preview: &'b mut Element<'a, Message, Theme, Renderer>,
+    /// The position of the top left corner of the preview.
+    position: Point,
+    /// The size of the preview, or `None` for the size it asks for.
+    size: Option<Size>,
+    /// The style of the preview.
+    style: &'b <Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> DragPreviewOverlay<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`DragPreviewOverlay`].
+    pub(crate) fn new(
+        tree: &'b mut Tree,
+        preview: &'b mut Element<'a, Message, Theme, Renderer>,
+        position: Point,
+        size: Option<Size>,
+        style: &'b <Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            tree,
+            preview,
+            position,
+            size,
+            style,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for DragPreviewOverlay<'_, '_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let limits = Limits::new(Size::ZERO, self.size.unwrap_or(bounds));
+        let preview = self
+            .preview
+            .as_widget()
+            .layout(self.tree, renderer, &limits);
+
+        Node::with_children(self.size.unwrap_or_else(|| preview.size()), vec![preview])
+            .move_to(self.position)
+    }
+
+    // The preview lets the cursor through to the drop targets under it.
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        false
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.preview(self.style);
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                Quad {
+                    bounds,
+                    border: Border {
+                        radius: appearance.border_radius.into(),
+                        width: appearance.border_width,
+                        color: appearance.border_color,
+                    },
+                    shadow: appearance.shadow,
+                },
+                background,
+            );
+        }
+
+        if let Some(preview_layout) = layout.children().next() {
+            self.preview.as_widget().draw(
+                self.tree,
+                renderer,
+                theme,
+                style,
+                preview_layout,
+                cursor,
+                &bounds,
+            );
+        }
+    }
+}
@@ -0,0 +1,309 @@
+//! The overlay of a [`Drawer`](crate::widgets::drawer::Drawer) showing its panel over the content.
+//!
+//! *This API requires the following crate features to be activated: drawer*
+use crate::style::drawer::StyleSheet;
+use crate::widgets::drawer::{draw_panel_background, layout_panel, Drag, Edge, State};
+
+use iced::{
+    advanced::{
+        layout::Node,
+        overlay,
+        renderer::{self, Quad},
+        widget::{Operation, Tree},
+        Clipboard, Layout, Overlay, Shell,
+    },
+    event, keyboard,
+    mouse::{self, Cursor},
+    touch, window, Border, Color, Element, Event, Rectangle, Shadow, Size, Vector,
+};
+
+/// The distance the cursor moves before the pressed panel is dragged.
+const DRAG_THRESHOLD: f32 = 8.0;
+
+/// The overlay of a [`Drawer`](crate::widgets::drawer::Drawer).
+#[allow(missing_debug_implementations)]
+pub struct DrawerOverlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The state of the drawer.
+    state: &'b mut State,
+    /// The state of the content of the panel.
+    tree: &'b mut Tree,
+    /// The content of the panel.
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    /// Whether the panel is open.
+    open: bool,
+    /// The optional message produced when the user closes the panel.
+    on_close: Option<Message>,
+    edge: Edge,
+    size: f32,
+    /// The style of the drawer.
+    style: &'b <Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> DrawerOverlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`DrawerOverlay`].
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        state: &'b mut State,
+        tree: &'b mut Tree,
+        content: &'b mut Element<'a, Message, Theme, Renderer>,
+        open: bool,
+        on_close: Option<Message>,
+        edge: Edge,
+        size: f32,
+        style: &'b <Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            state,
+            tree,
+            content,
+            open,
+            on_close,
+            edge,
+            size,
+            style,
+        }
+    }
+
+    /// Asks the application to close the panel.
+    fn close(&self, shell: &mut Shell<'_, Message>) {
+        if let (Some(on_close), true) = (&self.on_close, self.open) {
+            shell.publish(on_close.clone());
+        }
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for DrawerOverlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let window = Rectangle::new(iced::Point::ORIGIN, bounds);
+        let panel = layout_panel(
+            self.content,
+            self.tree,
+            renderer,
+            self.edge.panel(window, self.size, self.state.eased()),
+        );
+
+        Node::with_children(bounds, vec![panel])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let Some(panel_layout) = layout.children().next() else {
+            return event::Status::Ignored;
+        };
+        let panel = panel_layout.bounds();
+
+        // A dragged panel follows the cursor, and closes once dragged past
+        // half of its size.
+        if let Some(drag) = self.state.drag {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                    let distance = self.edge.closing_distance(drag.origin, position);
+
+                    if drag.dragging || distance > DRAG_THRESHOLD {
+                        self.state.drag = Some(Drag {
+                            dragging: true,
+                            ..drag
+                        });
+                        self.state
+                            .slide
+                            .set((drag.start - distance / self.size).clamp(0.0, 1.0));
+                        shell.invalidate_layout();
+                        return event::Status::Captured;
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    self.state.drag = None;
+
+                    if drag.dragging {
+                        if self.state.progress() < 0.5 {
+                            self.close(shell);
+                        } else {
+                            shell.request_redraw(window::RedrawRequest::NextFrame);
+                        }
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(keyboard::key::Named::Escape),
+            ..
+        }) = event
+        {
+            self.close(shell);
+            return event::Status::Captured;
+        }
+
+        let status =
+            panel_layout
+                .children()
+                .next()
+                .map_or(event::Status::Ignored, |content_layout| {
+                    self.content.as_widget_mut().on_event(
+                        self.tree,
+                        event.clone(),
+                        content_layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        &layout.bounds(),
+                    )
+                });
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position() {
+                    if panel.contains(position) {
+                        self.state.drag = Some(Drag {
+                            origin: position,
+                            start: self.state.progress(),
+                            dragging: false,
+                        });
+                    } else {
+                        self.close(shell);
+                    }
+                }
+                event::Status::Captured
+            }
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.state.drag.is_some_and(|drag| drag.dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        layout
+            .children()
+            .next()
+            .and_then(|panel| panel.children().next())
+            .map_or_else(mouse::Interaction::default, |content_layout| {
+                self.content.as_widget().mouse_interaction(
+                    self.tree,
+                    content_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(self.style);
+        let eased = self.state.eased();
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            Color {
+                a: appearance.backdrop.a * eased,
+                ..appearance.backdrop
+            },
+        );
+
+        if let Some(panel_layout) = layout.children().next() {
+            draw_panel_background(
+                renderer,
+                &appearance,
+                self.edge,
+                panel_layout.bounds(),
+                true,
+            );
+
+            if let Some(content_layout) = panel_layout.children().next() {
+                self.content.as_widget().draw(
+                    self.tree,
+                    renderer,
+                    theme,
+                    style,
+                    content_layout,
+                    cursor,
+                    &bounds,
+                );
+            }
+        }
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        let content_layout = layout.children().next()?.children().next()?;
+
+        self.content
+            .as_widget_mut()
+            .overlay(self.tree, content_layout, renderer, Vector::ZERO)
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let Some(content_layout) = layout
+            .children()
+            .next()
+            .and_then(|panel| panel.children().next())
+        {
+            self.content
+                .as_widget()
+                .operate(self.tree, content_layout, renderer, operation);
+        }
+    }
+}
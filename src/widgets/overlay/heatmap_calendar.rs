@@ -0,0 +1,134 @@
+//! The overlay of a [`HeatmapCalendar`](crate::widgets::heatmap_calendar::HeatmapCalendar)
+//! showing the tooltip of the hovered day.
+//!
+//! *This API requires the following crate features to be activated: `heatmap_calendar`*
+use crate::style::heatmap_calendar::StyleSheet;
+
+use iced::{
+    advanced::{
+        layout::Node,
+        renderer::{self, Quad},
+        text::{self, Paragraph, Text},
+        Layout, Overlay,
+    },
+    alignment::{Horizontal, Vertical},
+    mouse::Cursor,
+    widget::text::{LineHeight, Shaping},
+    Border, Font, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+/// The padding around the text of the tooltip.
+const PADDING: f32 = 6.0;
+/// The space between the tooltip and the hovered day.
+const GAP: f32 = 4.0;
+
+/// The overlay of a [`HeatmapCalendar`](crate::widgets::heatmap_calendar::HeatmapCalendar).
+#[allow(missing_debug_implementations)]
+pub struct HeatmapCalendarOverlay<'b, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The text of the tooltip.
+    content: String,
+    /// The bounds of the hovered day.
+    cell: Rectangle,
+    text_size: f32,
+    font: Font,
+    /// The style of the heatmap calendar.
+    style: &'b <Theme as StyleSheet>::Style,
+}
+
+impl<'b, Theme> HeatmapCalendarOverlay<'b, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`HeatmapCalendarOverlay`].
+    pub(crate) fn new(
+        content: String,
+        cell: Rectangle,
+        text_size: f32,
+        font: Font,
+        style: &'b <Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            content,
+            cell,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Returns the text of the tooltip.
+    fn text(&self, bounds: Size) -> Text<'_, Font> {
+        Text {
+            content: &self.content,
+            bounds,
+            size: Pixels(self.text_size),
+            line_height: LineHeight::default(),
+            font: self.font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for HeatmapCalendarOverlay<'_, Theme>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let text = Renderer::Paragraph::with_text(self.text(Size::INFINITY)).min_bounds();
+        let size = Size::new(text.width + PADDING * 2.0, text.height + PADDING * 2.0);
+
+        // The tooltip is centered above the day, or below it when it does
+        // not fit above.
+        let x = (self.cell.center_x() - size.width / 2.0)
+            .min(bounds.width - size.width)
+            .max(0.0);
+        let above = self.cell.y - GAP - size.height;
+        let y = if above >= 0.0 {
+            above
+        } else {
+            self.cell.y + self.cell.height + GAP
+        };
+
+        Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(self.style);
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::with_radius(appearance.tooltip_border_radius),
+                shadow: Shadow::default(),
+            },
+            appearance.tooltip_background,
+        );
+
+        renderer.fill_text(
+            self.text(bounds.size()),
+            bounds.center(),
+            appearance.tooltip_text_color,
+            bounds,
+        );
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        // The tooltip lets the cursor reach the days below it.
+        false
+    }
+}
@@ -1,30 +1,100 @@
 //! Display interactive elements on top of other widgets.
 
+#[cfg(feature = "anchored_overlay")]
+pub mod anchored_overlay;
+#[cfg(feature = "anchored_overlay")]
+pub use anchored_overlay::AnchoredOverlayOverlay;
+
 #[cfg(feature = "color_picker")]
 pub mod color_picker;
 #[cfg(feature = "color_picker")]
 pub use color_picker::ColorPickerOverlay;
 
+#[cfg(feature = "cupertino")]
+pub mod cupertino_action_sheet;
+#[cfg(feature = "cupertino")]
+pub use cupertino_action_sheet::ActionSheetOverlay;
+
 #[cfg(feature = "date_picker")]
 pub mod date_picker;
 #[cfg(feature = "date_picker")]
 pub use date_picker::DatePickerOverlay;
 
+#[cfg(feature = "drag_and_drop")]
+pub mod drag_and_drop;
+#[cfg(feature = "drag_and_drop")]
+pub use drag_and_drop::DragPreviewOverlay;
+
+#[cfg(feature = "drawer")]
+pub mod drawer;
+#[cfg(feature = "drawer")]
+pub use drawer::DrawerOverlay;
+
 #[cfg(feature = "floating_element")]
 pub mod floating_element;
 #[cfg(feature = "floating_element")]
 pub use floating_element::FloatingElementOverlay;
 
+#[cfg(feature = "heatmap_calendar")]
+pub mod heatmap_calendar;
+#[cfg(feature = "heatmap_calendar")]
+pub use heatmap_calendar::HeatmapCalendarOverlay;
+
 #[cfg(feature = "modal")]
 pub mod modal;
 #[cfg(feature = "modal")]
 pub use modal::ModalOverlay;
 
+#[cfg(feature = "property_grid")]
+pub mod property_grid;
+#[cfg(feature = "property_grid")]
+pub use property_grid::PropertyGridOverlay;
+
+#[cfg(feature = "multi_pick_list")]
+pub mod multi_pick_list;
+#[cfg(feature = "multi_pick_list")]
+pub use multi_pick_list::MultiPickListOverlay;
+
+#[cfg(feature = "popover")]
+pub mod popover;
+#[cfg(feature = "popover")]
+pub use popover::PopoverOverlay;
+
+#[cfg(feature = "tag_input")]
+pub mod tag_input;
+#[cfg(feature = "tag_input")]
+pub use tag_input::TagInputOverlay;
+
+#[cfg(feature = "search_bar")]
+pub mod search_bar;
+#[cfg(feature = "search_bar")]
+pub use search_bar::SearchBarOverlay;
+
+#[cfg(feature = "tick_slider")]
+pub mod tick_slider;
+#[cfg(feature = "tick_slider")]
+pub use tick_slider::TickSliderOverlay;
+
 #[cfg(feature = "time_picker")]
 pub mod time_picker;
 #[cfg(feature = "time_picker")]
 pub use time_picker::{State, TimePickerOverlay};
 
+#[cfg(feature = "toast")]
+pub mod toast;
+#[cfg(feature = "toast")]
+pub use toast::ToastOverlay;
+
+#[cfg(feature = "tour")]
+pub mod tour;
+#[cfg(feature = "tour")]
+pub use tour::TourOverlay;
+
+#[cfg(feature = "style_scope")]
+pub mod style_scope;
+#[cfg(feature = "style_scope")]
+pub use style_scope::StyleScopeOverlay;
+
 #[cfg(feature = "context_menu")]
 pub mod context_menu;
 #[cfg(feature = "context_menu")]
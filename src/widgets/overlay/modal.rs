@@ -6,14 +6,18 @@ use iced::{
     advanced::{
         layout::{Limits, Node},
         overlay, renderer,
-        widget::{Operation, Tree},
+        widget::{Id, Operation, Tree},
         Clipboard, Layout, Overlay, Shell,
     },
     alignment, event, keyboard,
     mouse::{self, Cursor},
-    touch, Alignment, Border, Color, Element, Event, Rectangle, Shadow, Size, Vector,
+    touch, Alignment, Background, Border, Color, Element, Event, Rectangle, Shadow, Size, Vector,
 };
 
+/// The distance the content of a [`ModalOverlay`] slides up while it is
+/// shown.
+const SLIDE_DISTANCE: f32 = 16.0;
+
 /// The overlay of the modal.
 #[allow(missing_debug_implementations)]
 pub struct ModalOverlay<'a, 'b, Message, Theme, Renderer>
@@ -34,6 +38,10 @@ where
     style: <Theme as StyleSheet>::Style,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    /// The eased progress of the transition showing the [`ModalOverlay`].
+    progress: f32,
+    /// The id of the modal showing the [`ModalOverlay`].
+    id: Option<Id>,
 }
 
 impl<'a, 'b, Message, Theme, Renderer> ModalOverlay<'a, 'b, Message, Theme, Renderer>
@@ -43,6 +51,7 @@ where
     Theme: StyleSheet,
 {
     /// Creates a new [`ModalOverlay`](ModalOverlay).
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         state: &'b mut Tree,
         content: &'b mut Element<'a, Message, Theme, Renderer>,
@@ -51,6 +60,7 @@ where
         style: <Theme as StyleSheet>::Style,
         horizontal_alignment: alignment::Horizontal,
         vertical_alignment: alignment::Vertical,
+        progress: f32,
     ) -> Self {
         ModalOverlay {
             state,
@@ -60,8 +70,18 @@ where
             style,
             horizontal_alignment,
             vertical_alignment,
+            progress,
+            id: None,
         }
     }
+
+    /// Sets the id of the modal showing the [`ModalOverlay`], which the
+    /// operations on its content are scoped to.
+    #[must_use]
+    pub fn id(mut self, id: Option<Id>) -> Self {
+        self.id = id;
+        self
+    }
 }
 
 impl<'a, 'b, Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
@@ -181,6 +201,15 @@ where
         let bounds = layout.bounds();
 
         let style_sheet = theme.active(&self.style);
+        let background = match style_sheet.background {
+            Background::Color(color) => Background::Color(Color {
+                a: color.a * self.progress,
+                ..color
+            }),
+            Background::Gradient(gradient) => {
+                Background::Gradient(gradient.mul_alpha(self.progress))
+            }
+        };
 
         // Background
         renderer.fill_quad(
@@ -193,7 +222,7 @@ where
                 },
                 shadow: Shadow::default(),
             },
-            style_sheet.background,
+            background,
         );
 
         let content_layout = layout
@@ -202,14 +231,19 @@ where
             .expect("Native: Layout should have a content layout.");
 
         // Modal
-        self.content.as_widget().draw(
-            self.state,
-            renderer,
-            theme,
-            style,
-            content_layout,
-            cursor,
-            &bounds,
+        renderer.with_translation(
+            Vector::new(0.0, SLIDE_DISTANCE * (1.0 - self.progress)),
+            |renderer| {
+                self.content.as_widget().draw(
+                    self.state,
+                    renderer,
+                    theme,
+                    style,
+                    content_layout,
+                    cursor,
+                    &bounds,
+                );
+            },
         );
     }
 
@@ -238,8 +272,10 @@ where
             .next()
             .expect("Native: Layout should have a content layout.");
 
-        self.content
-            .as_widget()
-            .operate(self.state, content_layout, renderer, operation);
+        operation.container(self.id.as_ref(), layout.bounds(), &mut |operation| {
+            self.content
+                .as_widget()
+                .operate(self.state, content_layout, renderer, operation);
+        });
     }
 }
@@ -0,0 +1,346 @@
+//! The overlay of a [`MultiPickList`](crate::widgets::multi_pick_list::MultiPickList)
+//! listing its options with check boxes.
+//!
+//! *This API requires the following crate features to be activated: `multi_pick_list`*
+use crate::core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT};
+use crate::style::multi_pick_list::StyleSheet;
+use crate::widgets::multi_pick_list::State;
+
+use iced::{
+    advanced::{
+        layout::Node,
+        renderer::{self, Quad},
+        text::{self, Text},
+        Clipboard, Layout, Overlay, Shell,
+    },
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Event, Font, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+/// The vertical padding of an option.
+const ITEM_PADDING: f32 = 5.0;
+
+/// The overlay of a [`MultiPickList`](crate::widgets::multi_pick_list::MultiPickList).
+#[allow(missing_debug_implementations)]
+pub struct MultiPickListOverlay<'a, 'b, T, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The state of the multi pick list.
+    state: &'b mut State,
+    /// The options to list.
+    options: &'b [T],
+    /// The chosen options.
+    selected: &'b [T],
+    /// The texts of the options.
+    labels: Vec<String>,
+    /// The function producing the message when the chosen options change.
+    on_change: &'b (dyn Fn(Vec<T>) -> Message + 'a),
+    /// The bounds of the field.
+    field: Rectangle,
+    text_size: f32,
+    font: Font,
+    /// The style of the multi pick list.
+    style: &'b <Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, T, Message, Theme> MultiPickListOverlay<'a, 'b, T, Message, Theme>
+where
+    T: Clone + PartialEq + ToString,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`MultiPickListOverlay`].
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        state: &'b mut State,
+        options: &'b [T],
+        selected: &'b [T],
+        on_change: &'b (dyn Fn(Vec<T>) -> Message + 'a),
+        field: Rectangle,
+        text_size: f32,
+        font: Font,
+        style: &'b <Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            state,
+            options,
+            selected,
+            labels: options.iter().map(ToString::to_string).collect(),
+            on_change,
+            field,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Returns the height of an option.
+    fn item_height(&self) -> f32 {
+        LineHeight::default().to_absolute(Pixels(self.text_size)).0 + ITEM_PADDING * 2.0
+    }
+
+    /// Returns the index of the option under `position`.
+    fn item_at(&self, bounds: Rectangle, position: Point) -> Option<usize> {
+        if !bounds.contains(position) {
+            return None;
+        }
+
+        let index = ((position.y - bounds.y) / self.item_height()) as usize;
+        (index < self.options.len()).then_some(index)
+    }
+
+    /// Publishes the chosen options with the option at `index` toggled, in
+    /// the order of the options.
+    fn toggle(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        let toggled = &self.options[index];
+        let chosen = self.selected.contains(toggled);
+
+        shell.publish((self.on_change)(
+            self.options
+                .iter()
+                .filter(|option| {
+                    if *option == toggled {
+                        !chosen
+                    } else {
+                        self.selected.contains(option)
+                    }
+                })
+                .cloned()
+                .collect(),
+        ));
+    }
+}
+
+impl<T, Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for MultiPickListOverlay<'_, '_, T, Message, Theme>
+where
+    T: Clone + PartialEq + ToString,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let height = self.item_height() * self.options.len() as f32;
+        let below = self.field.y + self.field.height;
+
+        // The list opens above the field when it does not fit below.
+        let y = if below + height > bounds.height && self.field.y - height >= 0.0 {
+            self.field.y - height
+        } else {
+            below
+        };
+
+        Node::new(Size::new(self.field.width, height)).move_to(Point::new(self.field.x, y))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(index) = self.item_at(bounds, position) {
+                    self.state.highlighted = Some(index);
+                }
+                event::Status::Ignored
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // The list stays open while options are toggled.
+                if let Some(index) = cursor
+                    .position()
+                    .and_then(|position| self.item_at(bounds, position))
+                {
+                    self.toggle(index, shell);
+                    return event::Status::Captured;
+                }
+
+                self.state.open = false;
+                shell.invalidate_layout();
+
+                if cursor.is_over(self.field) {
+                    // The press closing the list does not open it again.
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) => {
+                let last = self.options.len().saturating_sub(1);
+
+                match key {
+                    keyboard::key::Named::Escape => {
+                        self.state.open = false;
+                        shell.invalidate_layout();
+                    }
+                    keyboard::key::Named::ArrowDown => {
+                        self.state.highlighted = Some(
+                            self.state
+                                .highlighted
+                                .map_or(0, |index| (index + 1).min(last)),
+                        );
+                    }
+                    keyboard::key::Named::ArrowUp => {
+                        self.state.highlighted = Some(
+                            self.state
+                                .highlighted
+                                .map_or(last, |index| index.saturating_sub(1)),
+                        );
+                    }
+                    keyboard::key::Named::Enter | keyboard::key::Named::Space => {
+                        if let Some(index) = self
+                            .state
+                            .highlighted
+                            .filter(|&index| index < self.options.len())
+                        {
+                            self.toggle(index, shell);
+                        }
+                    }
+                    _ => return event::Status::Ignored,
+                }
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.hovered(self.style);
+        let item_height = self.item_height();
+        let check_size = self.text_size;
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: appearance.border_radius.into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.menu_background,
+        );
+
+        for (i, (option, label)) in self.options.iter().zip(&self.labels).enumerate() {
+            let item = Rectangle::new(
+                Point::new(bounds.x, bounds.y + item_height * i as f32),
+                Size::new(bounds.width, item_height),
+            );
+            let highlighted = self.state.highlighted == Some(i);
+            let chosen = self.selected.contains(option);
+
+            if highlighted {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: item,
+                        border: Border::with_radius(appearance.border_radius),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.highlighted_background,
+                );
+            }
+
+            let check = Rectangle::new(
+                Point::new(
+                    item.x + ITEM_PADDING * 2.0,
+                    item.center_y() - check_size / 2.0,
+                ),
+                Size::new(check_size, check_size),
+            );
+            renderer.fill_quad(
+                Quad {
+                    bounds: check,
+                    border: Border {
+                        radius: appearance.border_radius.into(),
+                        width: 1.0,
+                        color: appearance.check_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                if chosen {
+                    appearance.check_color.into()
+                } else {
+                    appearance.menu_background
+                },
+            );
+            if chosen {
+                renderer.fill_text(
+                    Text {
+                        content: &icon_to_string(Bootstrap::Check),
+                        bounds: check.size(),
+                        size: Pixels(check_size),
+                        line_height: LineHeight::default(),
+                        font: BOOTSTRAP_FONT,
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                        shaping: Shaping::Basic,
+                    },
+                    check.center(),
+                    appearance.check_mark_color,
+                    check,
+                );
+            }
+
+            let text_x = check.x + check.width + ITEM_PADDING * 2.0;
+            renderer.fill_text(
+                Text {
+                    content: label,
+                    bounds: Size::new(item.x + item.width - text_x, item.height),
+                    size: Pixels(self.text_size),
+                    line_height: LineHeight::default(),
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                    shaping: Shaping::Advanced,
+                },
+                Point::new(text_x, item.center_y()),
+                if highlighted {
+                    appearance.highlighted_text_color
+                } else {
+                    appearance.option_text_color
+                },
+                item,
+            );
+        }
+    }
+}
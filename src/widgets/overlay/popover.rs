@@ -0,0 +1,341 @@
+//! The overlay of a [`Popover`](crate::widgets::popover::Popover) showing its content.
+//!
+//! *This API requires the following crate features to be activated: `popover`*
+use crate::core::overlay::anchor;
+use crate::style::popover::StyleSheet;
+use crate::widgets::popover::{Placement, State, Trigger};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Quad},
+        widget::{Operation, Tree},
+        Clipboard, Layout, Overlay, Shell,
+    },
+    event, keyboard,
+    mouse::{self, Cursor},
+    touch, Border, Element, Event, Padding, Point, Rectangle, Shadow, Size, Vector,
+};
+
+/// The overlay of a [`Popover`](crate::widgets::popover::Popover).
+#[allow(missing_debug_implementations)]
+pub struct PopoverOverlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The state of the popover.
+    state: &'b mut State,
+    /// The state of the content.
+    tree: &'b mut Tree,
+    /// The content of the popover.
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    /// The bounds of the target the popover points at.
+    target: Rectangle,
+    placement: Placement,
+    trigger: Trigger,
+    /// The optional message produced when the user closes a manual popover.
+    on_dismiss: Option<Message>,
+    gap: f32,
+    arrow_size: f32,
+    padding: Padding,
+    max_width: f32,
+    /// The style of the popover.
+    style: &'b <Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> PopoverOverlay<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`PopoverOverlay`].
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        state: &'b mut State,
+        tree: &'b mut Tree,
+        content: &'b mut Element<'a, Message, Theme, Renderer>,
+        target: Rectangle,
+        placement: Placement,
+        trigger: Trigger,
+        on_dismiss: Option<Message>,
+        gap: f32,
+        arrow_size: f32,
+        padding: Padding,
+        max_width: f32,
+        style: &'b <Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            state,
+            tree,
+            content,
+            target,
+            placement,
+            trigger,
+            on_dismiss,
+            gap,
+            arrow_size,
+            padding,
+            max_width,
+            style,
+        }
+    }
+
+    /// Closes the popover, or asks the application to close a manual one.
+    fn dismiss(&mut self, shell: &mut Shell<'_, Message>) {
+        match self.trigger {
+            Trigger::Hover(_) | Trigger::Click => {
+                self.state.open = false;
+                self.state.hovered_since = None;
+                self.state.left_at = None;
+                self.state.over_popover = false;
+            }
+            Trigger::Manual(_) => {
+                if let Some(on_dismiss) = self.on_dismiss.clone() {
+                    shell.publish(on_dismiss);
+                }
+            }
+        }
+        shell.invalidate_layout();
+    }
+}
+
+/// Returns the side of the `target` the `popover` is on.
+fn side(popover: Rectangle, target: Rectangle) -> Placement {
+    if popover.y + popover.height <= target.y {
+        Placement::Top
+    } else if popover.y >= target.y + target.height {
+        Placement::Bottom
+    } else if popover.x + popover.width <= target.x {
+        Placement::Left
+    } else {
+        Placement::Right
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for PopoverOverlay<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let padding = Size::new(self.padding.horizontal(), self.padding.vertical());
+        let limits = Limits::new(
+            Size::ZERO,
+            Size::new(self.max_width.min(bounds.width), bounds.height),
+        )
+        .shrink(padding);
+        let content = self
+            .content
+            .as_widget()
+            .layout(self.tree, renderer, &limits)
+            .move_to(Point::new(self.padding.left, self.padding.top));
+        let size = content.size().expand(padding);
+
+        Node::with_children(size, vec![content]).move_to(anchor(
+            self.target,
+            size,
+            self.placement,
+            self.gap + self.arrow_size / 2.0,
+            bounds,
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        if let Event::Mouse(mouse::Event::CursorMoved { position }) = event {
+            self.state.over_popover = bounds.contains(position);
+        }
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(keyboard::key::Named::Escape),
+            ..
+        }) = event
+        {
+            self.dismiss(shell);
+            return event::Status::Captured;
+        }
+
+        let status = layout
+            .children()
+            .next()
+            .map_or(event::Status::Ignored, |content_layout| {
+                self.content.as_widget_mut().on_event(
+                    self.tree,
+                    event.clone(),
+                    content_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &bounds,
+                )
+            });
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => match cursor.position() {
+                Some(position) if bounds.contains(position) => event::Status::Captured,
+                // Presses on the target are left to the target, which toggles
+                // a click popover itself.
+                Some(position) if !self.target.contains(position) => {
+                    self.dismiss(shell);
+                    status
+                }
+                _ => status,
+            },
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        layout
+            .children()
+            .next()
+            .map_or_else(mouse::Interaction::default, |content_layout| {
+                self.content.as_widget().mouse_interaction(
+                    self.tree,
+                    content_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(self.style);
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: appearance.border_radius.into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: appearance.shadow,
+            },
+            appearance.background,
+        );
+
+        // The arrow is drawn as one pixel wide strips narrowing away from the
+        // popover, centered on the target where the popover allows it.
+        let half = self.arrow_size / 2.0;
+        let inset = appearance.border_radius + half;
+        let side = side(bounds, self.target);
+        let anchor = match side {
+            Placement::Top | Placement::Bottom => self.target.center_x().clamp(
+                bounds.x + inset,
+                (bounds.x + bounds.width - inset).max(bounds.x + inset),
+            ),
+            Placement::Left | Placement::Right => self.target.center_y().clamp(
+                bounds.y + inset,
+                (bounds.y + bounds.height - inset).max(bounds.y + inset),
+            ),
+        };
+
+        for strip in 0..half.ceil() as usize {
+            let distance = strip as f32;
+            let length = self.arrow_size * (1.0 - distance / half);
+            let strip = match side {
+                Placement::Top => Rectangle::new(
+                    Point::new(anchor - length / 2.0, bounds.y + bounds.height + distance),
+                    Size::new(length, 1.0),
+                ),
+                Placement::Bottom => Rectangle::new(
+                    Point::new(anchor - length / 2.0, bounds.y - distance - 1.0),
+                    Size::new(length, 1.0),
+                ),
+                Placement::Left => Rectangle::new(
+                    Point::new(bounds.x + bounds.width + distance, anchor - length / 2.0),
+                    Size::new(1.0, length),
+                ),
+                Placement::Right => Rectangle::new(
+                    Point::new(bounds.x - distance - 1.0, anchor - length / 2.0),
+                    Size::new(1.0, length),
+                ),
+            };
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: strip,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                appearance.background,
+            );
+        }
+
+        if let Some(content_layout) = layout.children().next() {
+            self.content.as_widget().draw(
+                self.tree,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: appearance.text_color,
+                },
+                content_layout,
+                cursor,
+                &bounds,
+            );
+        }
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        let content_layout = layout.children().next()?;
+
+        self.content
+            .as_widget_mut()
+            .overlay(self.tree, content_layout, renderer, Vector::ZERO)
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if let Some(content_layout) = layout.children().next() {
+            self.content
+                .as_widget()
+                .operate(self.tree, content_layout, renderer, operation);
+        }
+    }
+}
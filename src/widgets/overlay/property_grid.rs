@@ -0,0 +1,248 @@
+//! The overlay of a [`PropertyGrid`](crate::widgets::property_grid::PropertyGrid) listing the options of a choice.
+//!
+//! *This API requires the following crate features to be activated: `property_grid`*
+use crate::core::property::PropertyValue;
+use crate::style::property_grid::StyleSheet;
+use crate::widgets::property_grid::State;
+
+use iced::{
+    advanced::{
+        layout::Node,
+        renderer::{self, Quad},
+        text::{self, Text},
+        Clipboard, Layout, Overlay, Shell,
+    },
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Event, Font, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+/// The vertical padding of an option.
+const ITEM_PADDING: f32 = 4.0;
+
+/// The overlay of a [`PropertyGrid`](crate::widgets::property_grid::PropertyGrid).
+#[allow(missing_debug_implementations)]
+pub struct PropertyGridOverlay<'a, 'b, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The state of the property grid.
+    state: &'b mut State,
+    /// The index of the edited property.
+    index: usize,
+    /// The options of the edited property.
+    options: &'b [String],
+    /// The function producing the message when an option is chosen.
+    on_change: &'b (dyn Fn(usize, PropertyValue) -> Message + 'a),
+    /// The bounds of the field of the choice.
+    field: Rectangle,
+    text_size: f32,
+    font: Font,
+    /// The style of the property grid.
+    style: &'b <Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Theme> PropertyGridOverlay<'a, 'b, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`PropertyGridOverlay`].
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        state: &'b mut State,
+        index: usize,
+        options: &'b [String],
+        on_change: &'b (dyn Fn(usize, PropertyValue) -> Message + 'a),
+        field: Rectangle,
+        text_size: f32,
+        font: Font,
+        style: &'b <Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            state,
+            index,
+            options,
+            on_change,
+            field,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Returns the height of an option.
+    fn item_height(&self) -> f32 {
+        LineHeight::default().to_absolute(Pixels(self.text_size)).0 + ITEM_PADDING * 2.0
+    }
+
+    /// Returns the index of the option under `position`.
+    fn item_at(&self, bounds: Rectangle, position: Point) -> Option<usize> {
+        if !bounds.contains(position) {
+            return None;
+        }
+
+        let index = ((position.y - bounds.y) / self.item_height()) as usize;
+        (index < self.options.len()).then_some(index)
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for PropertyGridOverlay<'_, '_, Message, Theme>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let height = self.item_height() * self.options.len() as f32;
+        let below = self.field.y + self.field.height;
+
+        // The list opens above the field when it does not fit below.
+        let y = if below + height > bounds.height && self.field.y - height >= 0.0 {
+            self.field.y - height
+        } else {
+            below
+        };
+
+        Node::new(Size::new(self.field.width, height)).move_to(Point::new(self.field.x, y))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(index) = self.item_at(bounds, position) {
+                    self.state.highlighted = Some(index);
+                }
+                event::Status::Ignored
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                self.state.open = None;
+                shell.invalidate_layout();
+
+                if let Some(selected) = cursor
+                    .position()
+                    .and_then(|position| self.item_at(bounds, position))
+                {
+                    shell.publish((self.on_change)(
+                        self.index,
+                        PropertyValue::Choice {
+                            options: self.options.to_vec(),
+                            selected,
+                        },
+                    ));
+                    event::Status::Captured
+                } else if cursor.is_over(self.field) {
+                    // The press closing the list does not open it again.
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                ..
+            }) => {
+                self.state.open = None;
+                shell.invalidate_layout();
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.active(self.style);
+        let item_height = self.item_height();
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: appearance.field_border_radius.into(),
+                    width: 1.0,
+                    color: appearance.field_border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.menu_background,
+        );
+
+        for (i, option) in self.options.iter().enumerate() {
+            let item = Rectangle::new(
+                Point::new(bounds.x, bounds.y + item_height * i as f32),
+                Size::new(bounds.width, item_height),
+            );
+            let highlighted = self.state.highlighted == Some(i);
+
+            if highlighted {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: item,
+                        border: Border::with_radius(appearance.field_border_radius),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.highlighted_background,
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: option,
+                    bounds: Size::new(item.width - ITEM_PADDING * 2.0, item.height),
+                    size: Pixels(self.text_size),
+                    line_height: LineHeight::default(),
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                    shaping: Shaping::Advanced,
+                },
+                Point::new(item.x + ITEM_PADDING * 2.0, item.center_y()),
+                if highlighted {
+                    appearance.highlighted_text_color
+                } else {
+                    appearance.text_color
+                },
+                item,
+            );
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! The overlay of a [`SearchBar`](crate::widgets::search_bar::SearchBar) listing its suggestions.
+//!
+//! *This API requires the following crate features to be activated: `search_bar`*
+use crate::style::search_bar::StyleSheet;
+use crate::widgets::search_bar::State;
+
+use iced::{
+    advanced::{
+        layout::Node,
+        renderer::{self, Quad},
+        text::{self, Text},
+        Clipboard, Layout, Overlay, Shell,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Event, Font, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+/// The vertical padding of a suggestion.
+const ITEM_PADDING: f32 = 5.0;
+
+/// The overlay of a [`SearchBar`](crate::widgets::search_bar::SearchBar).
+#[allow(missing_debug_implementations)]
+pub struct SearchBarOverlay<'a, 'b, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The state of the search bar.
+    state: &'b mut State,
+    /// The suggestions to list.
+    suggestions: &'b [String],
+    /// The function producing the message when a suggestion is picked.
+    on_select: &'b (dyn Fn(String) -> Message + 'a),
+    /// The position of the field.
+    position: Point,
+    /// The size of the field.
+    field: Size,
+    text_size: f32,
+    font: Font,
+    /// The style of the search bar.
+    style: &'b <Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Theme> SearchBarOverlay<'a, 'b, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`SearchBarOverlay`].
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        state: &'b mut State,
+        suggestions: &'b [String],
+        on_select: &'b (dyn Fn(String) -> Message + 'a),
+        position: Point,
+        field: Size,
+        text_size: f32,
+        font: Font,
+        style: &'b <Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            state,
+            suggestions,
+            on_select,
+            position,
+            field,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Returns the height of a suggestion.
+    fn item_height(&self) -> f32 {
+        LineHeight::default().to_absolute(Pixels(self.text_size)).0 + ITEM_PADDING * 2.0
+    }
+
+    /// Returns the index of the suggestion under `position`.
+    fn item_at(&self, bounds: Rectangle, position: Point) -> Option<usize> {
+        if !bounds.contains(position) {
+            return None;
+        }
+
+        let index = ((position.y - bounds.y) / self.item_height()) as usize;
+        (index < self.suggestions.len()).then_some(index)
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for SearchBarOverlay<'_, '_, Message, Theme>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let height = self.item_height() * self.suggestions.len() as f32;
+        let below = self.position.y + self.field.height;
+
+        // The list opens above the field when it does not fit below.
+        let y = if below + height > bounds.height && self.position.y - height >= 0.0 {
+            self.position.y - height
+        } else {
+            below
+        };
+
+        Node::new(Size::new(self.field.width, height)).move_to(Point::new(self.position.x, y))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(index) = self.item_at(bounds, position) {
+                    self.state.highlighted = Some(index);
+                }
+                event::Status::Ignored
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(index) = cursor
+                    .position()
+                    .and_then(|position| self.item_at(bounds, position))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let suggestion = self.suggestions[index].clone();
+                self.state.pick(&suggestion);
+                shell.publish((self.on_select)(suggestion));
+                shell.invalidate_layout();
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.focused(self.style);
+        let item_height = self.item_height();
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: appearance.border_radius.into(),
+                    width: appearance.border_width,
+                    color: appearance.suggestions_border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.suggestions_background,
+        );
+
+        for (i, suggestion) in self.suggestions.iter().enumerate() {
+            let item = Rectangle::new(
+                Point::new(bounds.x, bounds.y + item_height * i as f32),
+                Size::new(bounds.width, item_height),
+            );
+            let highlighted = self.state.highlighted == Some(i);
+
+            if highlighted {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: item,
+                        border: Border::with_radius(appearance.border_radius),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.highlighted_suggestion_background,
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: suggestion,
+                    bounds: Size::new(item.width - ITEM_PADDING * 2.0, item.height),
+                    size: Pixels(self.text_size),
+                    line_height: LineHeight::default(),
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                    shaping: Shaping::Advanced,
+                },
+                Point::new(item.x + ITEM_PADDING * 2.0, item.center_y()),
+                if highlighted {
+                    appearance.highlighted_suggestion_text_color
+                } else {
+                    appearance.suggestion_text_color
+                },
+                item,
+            );
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! The overlays of the content of a [`StyleScope`](crate::widgets::StyleScope).
+//!
+//! *This API requires the following crate features to be activated: `style_scope`*
+
+use crate::widgets::style_scope::{scoped_style, Overrides};
+
+use iced::{
+    advanced::{
+        layout::Node, overlay, renderer, widget::Operation, Clipboard, Layout, Overlay, Shell,
+    },
+    event,
+    mouse::{self, Cursor},
+    Color, Event, Point, Rectangle, Size,
+};
+
+/// An overlay of the content of a [`StyleScope`](crate::widgets::StyleScope),
+/// handled and drawn with the same overrides.
+#[allow(missing_debug_implementations)]
+pub struct StyleScopeOverlay<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The overlay of the content.
+    content: overlay::Element<'a, Message, Theme, Renderer>,
+    /// The settings overridden inside the scope.
+    overrides: Overrides,
+    /// The theme of the content, if it is not the one of the application.
+    theme: Option<&'a Theme>,
+    /// The default color of the text of the content.
+    text_color: Option<Color>,
+}
+
+impl<'a, Message, Theme, Renderer> StyleScopeOverlay<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    /// Creates a new [`StyleScopeOverlay`] wrapping the overlay of the
+    /// content of a scope.
+    pub(crate) fn new(
+        content: overlay::Element<'a, Message, Theme, Renderer>,
+        overrides: Overrides,
+        theme: Option<&'a Theme>,
+        text_color: Option<Color>,
+    ) -> Self {
+        StyleScopeOverlay {
+            content,
+            overrides,
+            theme,
+            text_color,
+        }
+    }
+
+    /// Turn this [`StyleScopeOverlay`] into an overlay [`Element`](overlay::Element).
+    #[must_use]
+    pub fn overlay(self) -> overlay::Element<'a, Message, Theme, Renderer> {
+        overlay::Element::new(Box::new(self))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for StyleScopeOverlay<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> Node {
+        let content = &mut self.content;
+
+        self.overrides.apply(|| content.layout(renderer, bounds))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        self.overrides.apply(|| {
+            self.content.draw(
+                renderer,
+                self.theme.unwrap_or(theme),
+                &scoped_style(style, self.text_color),
+                layout,
+                cursor,
+            );
+        });
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let content = &mut self.content;
+
+        self.overrides
+            .apply(|| content.operate(layout, renderer, operation));
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let content = &mut self.content;
+
+        self.overrides
+            .apply(|| content.on_event(event, layout, cursor, renderer, clipboard, shell))
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.overrides.apply(|| {
+            self.content
+                .mouse_interaction(layout, cursor, viewport, renderer)
+        })
+    }
+
+    fn is_over(&self, layout: Layout<'_>, renderer: &Renderer, cursor_position: Point) -> bool {
+        self.content.is_over(layout, renderer, cursor_position)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let overrides = self.overrides;
+        let theme = self.theme;
+        let text_color = self.text_color;
+        let content = &mut self.content;
+
+        overrides
+            .apply(|| content.overlay(layout, renderer))
+            .map(|content| StyleScopeOverlay::new(content, overrides, theme, text_color).overlay())
+    }
+}
@@ -0,0 +1,133 @@
+//! The overlay of a [`TickSlider`](crate::widgets::tick_slider::TickSlider)
+//! showing the value of the dragged handle.
+//!
+//! *This API requires the following crate features to be activated: `tick_slider`*
+use crate::style::tick_slider::StyleSheet;
+
+use iced::{
+    advanced::{
+        layout::Node,
+        renderer::{self, Quad},
+        text::{self, Paragraph, Text},
+        Layout, Overlay,
+    },
+    alignment::{Horizontal, Vertical},
+    mouse::Cursor,
+    widget::text::{LineHeight, Shaping},
+    Border, Font, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+/// The padding around the text of the bubble.
+const PADDING: f32 = 4.0;
+/// The space between the bubble and the handle.
+const GAP: f32 = 4.0;
+
+/// The overlay of a [`TickSlider`](crate::widgets::tick_slider::TickSlider).
+#[allow(missing_debug_implementations)]
+pub struct TickSliderOverlay<'b, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The text of the bubble.
+    content: String,
+    /// The bounds of the handle.
+    handle: Rectangle,
+    text_size: f32,
+    font: Font,
+    /// The style of the tick slider.
+    style: &'b <Theme as StyleSheet>::Style,
+}
+
+impl<'b, Theme> TickSliderOverlay<'b, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`TickSliderOverlay`].
+    pub(crate) fn new(
+        content: String,
+        handle: Rectangle,
+        text_size: f32,
+        font: Font,
+        style: &'b <Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            content,
+            handle,
+            text_size,
+            font,
+            style,
+        }
+    }
+
+    /// Returns the text of the bubble.
+    fn text(&self, bounds: Size) -> Text<'_, Font> {
+        Text {
+            content: &self.content,
+            bounds,
+            size: Pixels(self.text_size),
+            line_height: LineHeight::default(),
+            font: self.font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer> for TickSliderOverlay<'_, Theme>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let text = Renderer::Paragraph::with_text(self.text(Size::INFINITY)).min_bounds();
+        let size = Size::new(text.width + PADDING * 2.0, text.height + PADDING * 2.0);
+
+        // The bubble is centered above the handle, or below it when it does
+        // not fit above.
+        let x = (self.handle.center_x() - size.width / 2.0)
+            .min(bounds.width - size.width)
+            .max(0.0);
+        let above = self.handle.y - GAP - size.height;
+        let y = if above >= 0.0 {
+            above
+        } else {
+            self.handle.y + self.handle.height + GAP
+        };
+
+        Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = theme.dragging(self.style);
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::with_radius(appearance.bubble_border_radius),
+                shadow: Shadow::default(),
+            },
+            appearance.bubble_background,
+        );
+
+        renderer.fill_text(
+            self.text(bounds.size()),
+            bounds.center(),
+            appearance.bubble_text_color,
+            bounds,
+        );
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        // The bubble lets the cursor keep dragging the handle below it.
+        false
+    }
+}
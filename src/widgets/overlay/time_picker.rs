@@ -8,11 +8,12 @@ use crate::{
         MINUTE_RADIUS_PERCENTAGE, MINUTE_RADIUS_PERCENTAGE_NO_SECONDS, PERIOD_PERCENTAGE,
         SECOND_RADIUS_PERCENTAGE,
     },
+    core::focus::{self, Direction},
     core::icons::{
         bootstrap::icon_to_string,
         {Bootstrap, BOOTSTRAP_FONT},
     },
-    core::{clock, overlay::Position, time::Period},
+    core::{clock, overlay::Position, settings, time::Period},
     style::style_state::StyleState,
     time_picker::{self, Time},
 };
@@ -24,7 +25,7 @@ use iced::{
         layout::{Limits, Node},
         overlay, renderer,
         text::Renderer as _,
-        widget::tree::Tree,
+        widget::{operation, tree::Tree},
         Clipboard, Layout, Overlay, Renderer as _, Shell, Text, Widget,
     },
     alignment::{Horizontal, Vertical},
@@ -88,6 +89,9 @@ where
     style: <Theme as StyleSheet>::Style,
     /// The reference to the tree holding the state of this overlay.
     tree: &'a mut Tree,
+    /// The parts of the [`TimePickerOverlay`] reached with Tab, in order, or the
+    /// default order when empty.
+    tab_order: &'a [Focus],
 }
 
 impl<'a, Message, Theme> TimePickerOverlay<'a, Message, Theme>
@@ -128,9 +132,18 @@ where
             position,
             style,
             tree,
+            tab_order: &[],
         }
     }
 
+    /// Sets the parts of the [`TimePickerOverlay`] reached with Tab, in order. The
+    /// default order is used when empty.
+    #[must_use]
+    pub fn tab_order(mut self, tab_order: &'a [Focus]) -> Self {
+        self.tab_order = tab_order;
+        self
+    }
+
     /// Turn this [`TimePickerOverlay`] into an overlay [`Element`](overlay::Element).
     #[must_use]
     pub fn overlay(self) -> overlay::Element<'a, Message, Theme, Renderer> {
@@ -464,11 +477,24 @@ where
             let mut status = event::Status::Ignored;
 
             if matches!(key, keyboard::Key::Named(keyboard::key::Named::Tab)) {
-                if self.state.keyboard_modifiers.shift() {
-                    self.state.focus = self.state.focus.previous(self.state.show_seconds);
+                let direction = if self.state.keyboard_modifiers.shift() {
+                    Direction::Previous
                 } else {
-                    self.state.focus = self.state.focus.next(self.state.show_seconds);
-                }
+                    Direction::Next
+                };
+                let show_seconds = self.state.show_seconds;
+                let tab_order: Vec<Focus> = self
+                    .tab_order
+                    .iter()
+                    .copied()
+                    .filter(|part| show_seconds || *part != Focus::DigitalSecond)
+                    .collect();
+
+                self.state.focus = focus::cycle(&tab_order, self.state.focus, direction)
+                    .unwrap_or_else(|| match direction {
+                        Direction::Next => self.state.focus.next(show_seconds),
+                        Direction::Previous => self.state.focus.previous(show_seconds),
+                    });
             } else {
                 let mut keyboard_handle =
                     |key_code: &keyboard::Key, time: &mut NaiveTime, duration: Duration| {
@@ -963,8 +989,8 @@ where
     Message: 'static + Clone,
     Theme: StyleSheet + button::StyleSheet + text::StyleSheet + container::StyleSheet,
 {
-    let arrow_size = renderer.default_size().0;
-    let font_size = 1.2 * renderer.default_size().0;
+    let arrow_size = settings::scaled(renderer.default_size().0);
+    let font_size = 1.2 * settings::scaled(renderer.default_size().0);
 
     let mut digital_clock_row = Row::<Message, Theme, Renderer>::new()
         .align_items(Alignment::Center)
@@ -1473,7 +1499,10 @@ fn draw_digital_clock<Message, Theme>(
             Text {
                 content: char::from(Bootstrap::CaretUpFill).encode_utf8(&mut buffer),
                 bounds: Size::new(up_bounds.width, up_bounds.height),
-                size: Pixels(renderer.default_size().0 + if up_arrow_hovered { 1.0 } else { 0.0 }),
+                size: Pixels(
+                    settings::scaled(renderer.default_size().0)
+                        + if up_arrow_hovered { 1.0 } else { 0.0 },
+                ),
                 font: crate::core::icons::BOOTSTRAP_FONT,
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
@@ -1493,7 +1522,7 @@ fn draw_digital_clock<Message, Theme>(
             Text {
                 content: &text,
                 bounds: Size::new(center_bounds.width, center_bounds.height),
-                size: renderer.default_size(),
+                size: Pixels(settings::scaled(renderer.default_size().0)),
                 font: renderer.default_font(),
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
@@ -1514,7 +1543,8 @@ fn draw_digital_clock<Message, Theme>(
                 content: char::from(Bootstrap::CaretDownFill).encode_utf8(&mut buffer),
                 bounds: Size::new(down_bounds.width, down_bounds.height),
                 size: Pixels(
-                    renderer.default_size().0 + if down_arrow_hovered { 1.0 } else { 0.0 },
+                    settings::scaled(renderer.default_size().0)
+                        + if down_arrow_hovered { 1.0 } else { 0.0 },
                 ),
                 font: crate::core::icons::BOOTSTRAP_FONT,
                 horizontal_alignment: Horizontal::Center,
@@ -1566,7 +1596,7 @@ fn draw_digital_clock<Message, Theme>(
                 hour_minute_separator.bounds().width,
                 hour_minute_separator.bounds().height,
             ),
-            size: renderer.default_size(),
+            size: Pixels(settings::scaled(renderer.default_size().0)),
             font: renderer.default_font(),
             horizontal_alignment: Horizontal::Center,
             vertical_alignment: Vertical::Center,
@@ -1604,7 +1634,7 @@ fn draw_digital_clock<Message, Theme>(
                     minute_second_separator.bounds().width,
                     minute_second_separator.bounds().height,
                 ),
-                size: renderer.default_size(),
+                size: Pixels(settings::scaled(renderer.default_size().0)),
                 font: renderer.default_font(),
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
@@ -1644,7 +1674,7 @@ fn draw_digital_clock<Message, Theme>(
                     "AM"
                 },
                 bounds: Size::new(period.bounds().width, period.bounds().height),
-                size: renderer.default_size(),
+                size: Pixels(settings::scaled(renderer.default_size().0)),
                 font: renderer.default_font(),
                 horizontal_alignment: Horizontal::Center,
                 vertical_alignment: Vertical::Center,
@@ -1812,6 +1842,22 @@ pub enum ClockDragged {
     Second,
 }
 
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focus != Focus::None
+    }
+
+    fn focus(&mut self) {
+        if self.focus == Focus::None {
+            self.focus = Focus::Overlay;
+        }
+    }
+
+    fn unfocus(&mut self) {
+        self.focus = Focus::None;
+    }
+}
+
 /// An enumeration of all focusable elements of the [`TimePickerOverlay`].
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Focus {
@@ -0,0 +1,419 @@
+//! The overlay of a [`ToastManager`](crate::widgets::toast::ToastManager).
+//!
+//! *This API requires the following crate features to be activated: toast*
+use crate::core::{animation::Easing, settings};
+use crate::style::toast::{Appearance, StyleSheet};
+use crate::widgets::toast::{Corner, Severity, State, Toast};
+
+use iced::{
+    advanced::{
+        layout::Node,
+        renderer::{self, Quad},
+        text::{self, Paragraph, Text},
+        Clipboard, Layout, Overlay, Shell,
+    },
+    alignment::{Horizontal, Vertical},
+    event, font,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    window, Background, Border, Color, Event, Font, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+/// The space between the content of a toast and its edges.
+const INNER_PADDING: f32 = 12.0;
+/// The width of the accent bar on the leading edge of a toast.
+const ACCENT_WIDTH: f32 = 4.0;
+/// The space between the title, the body and the action of a toast.
+const GAP: f32 = 4.0;
+
+/// The overlay of a [`ToastManager`](crate::widgets::toast::ToastManager).
+#[allow(missing_debug_implementations)]
+pub struct ToastOverlay<'b, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The state of the toast manager.
+    state: &'b mut State,
+    /// The queue of toasts.
+    toasts: &'b [Toast<Message>],
+    /// The indices of the toasts shown.
+    visible: Vec<usize>,
+    corner: Corner,
+    width: f32,
+    spacing: f32,
+    padding: f32,
+    text_size: f32,
+    /// The style of the toasts.
+    style: &'b <Theme as StyleSheet>::Style,
+}
+
+/// The bounds of the parts of a toast.
+struct Parts {
+    title: Rectangle,
+    body: Option<Rectangle>,
+    action: Option<Rectangle>,
+    close: Rectangle,
+}
+
+impl<'b, Message, Theme> ToastOverlay<'b, Message, Theme>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`ToastOverlay`].
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        state: &'b mut State,
+        toasts: &'b [Toast<Message>],
+        visible: Vec<usize>,
+        corner: Corner,
+        width: f32,
+        spacing: f32,
+        padding: f32,
+        text_size: f32,
+        style: &'b <Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            state,
+            toasts,
+            visible,
+            corner,
+            width,
+            spacing,
+            padding,
+            text_size,
+            style,
+        }
+    }
+
+    fn text(content: &'b str, size: f32, bold: bool, bounds: Size) -> Text<'b, Font> {
+        Text {
+            content,
+            bounds,
+            size: Pixels(size),
+            line_height: LineHeight::default(),
+            font: if bold {
+                Font {
+                    weight: font::Weight::Bold,
+                    ..settings::font()
+                }
+            } else {
+                settings::font()
+            },
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Top,
+            shaping: Shaping::Advanced,
+        }
+    }
+
+    /// Computes the bounds of the parts of the `toast` with the given bounds.
+    fn parts<Renderer>(&self, toast: &'b Toast<Message>, bounds: Rectangle) -> Parts
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        let close_size = self.text_size;
+        let x = bounds.x + ACCENT_WIDTH + INNER_PADDING;
+        let text_width = bounds.x + bounds.width - INNER_PADDING - close_size - GAP - x;
+        let measure = |content: &'b str, size: f32, bold: bool| {
+            Renderer::Paragraph::with_text(Self::text(
+                content,
+                size,
+                bold,
+                Size::new(text_width, f32::INFINITY),
+            ))
+            .min_bounds()
+        };
+
+        let title_height = measure(&toast.title, self.text_size, true).height;
+        let title = Rectangle::new(
+            Point::new(x, bounds.y + INNER_PADDING),
+            Size::new(text_width, title_height),
+        );
+        let mut bottom = title.y + title.height;
+
+        let body = toast.body.as_deref().map(|body| {
+            let height = measure(body, self.text_size - 2.0, false).height;
+            let body = Rectangle::new(Point::new(x, bottom + GAP), Size::new(text_width, height));
+            bottom = body.y + body.height;
+            body
+        });
+
+        let action = toast.action.as_ref().map(|(label, _)| {
+            let size = measure(label, self.text_size, true);
+            Rectangle::new(
+                Point::new(
+                    bounds.x + bounds.width - INNER_PADDING - size.width,
+                    bottom + GAP,
+                ),
+                size,
+            )
+        });
+
+        Parts {
+            title,
+            body,
+            action,
+            close: Rectangle::new(
+                Point::new(
+                    bounds.x + bounds.width - INNER_PADDING - close_size,
+                    bounds.y + INNER_PADDING,
+                ),
+                Size::new(close_size, close_size),
+            ),
+        }
+    }
+
+    /// Returns the height of the `toast`.
+    fn height<Renderer>(&self, toast: &'b Toast<Message>) -> f32
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        let parts = self.parts::<Renderer>(
+            toast,
+            Rectangle::new(Point::ORIGIN, Size::new(self.width, 0.0)),
+        );
+
+        let last = parts.action.or(parts.body).unwrap_or(parts.title);
+
+        last.y + last.height + INNER_PADDING
+    }
+
+    /// Returns the progress of the animation of the toast at `index` with an ease-out curve.
+    fn eased(&self, index: usize) -> f32 {
+        Easing::EaseOut.apply(self.state.toasts[index].enter.progress())
+    }
+
+    fn accent(appearance: &Appearance, severity: Severity) -> Color {
+        match severity {
+            Severity::Info => appearance.info_color,
+            Severity::Success => appearance.success_color,
+            Severity::Warning => appearance.warning_color,
+            Severity::Error => appearance.error_color,
+        }
+    }
+}
+
+fn faded(color: Color, alpha: f32) -> Color {
+    Color {
+        a: color.a * alpha,
+        ..color
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for ToastOverlay<'_, Message, Theme>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let mut offset = self.padding;
+        let mut children = Vec::with_capacity(self.visible.len());
+
+        for &i in &self.visible {
+            let height = self.height::<Renderer>(&self.toasts[i]);
+            let eased = self.eased(i);
+            // The toasts slide in from the edge of the window.
+            let slide = (1.0 - eased) * (self.width + self.padding);
+
+            let x = match self.corner {
+                Corner::TopLeft | Corner::BottomLeft => self.padding - slide,
+                Corner::TopRight | Corner::BottomRight => {
+                    bounds.width - self.padding - self.width + slide
+                }
+            };
+            let y = match self.corner {
+                Corner::TopLeft | Corner::TopRight => offset,
+                Corner::BottomLeft | Corner::BottomRight => bounds.height - offset - height,
+            };
+
+            // The others move smoothly as a toast comes and goes.
+            offset += (height + self.spacing) * eased;
+            children.push(Node::new(Size::new(self.width, height)).move_to(Point::new(x, y)));
+        }
+
+        Node::with_children(bounds, children)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let appearance = theme.active(self.style);
+
+        for (&i, toast_layout) in self.visible.iter().zip(layout.children()) {
+            let toast = &self.toasts[i];
+            let bounds = toast_layout.bounds();
+            let parts = self.parts::<Renderer>(toast, bounds);
+            let alpha = self.eased(i);
+            let accent = faded(Self::accent(&appearance, toast.severity), alpha);
+
+            renderer.fill_quad(
+                Quad {
+                    bounds,
+                    border: Border {
+                        radius: appearance.border_radius.into(),
+                        width: appearance.border_width,
+                        color: faded(appearance.border_color, alpha),
+                    },
+                    shadow: Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.15 * alpha),
+                        offset: iced::Vector::new(0.0, 2.0),
+                        blur_radius: 8.0,
+                    },
+                },
+                match appearance.background {
+                    Background::Color(color) => Background::Color(faded(color, alpha)),
+                    background @ Background::Gradient(_) => background,
+                },
+            );
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle {
+                        width: ACCENT_WIDTH,
+                        ..bounds
+                    },
+                    border: Border {
+                        radius: [appearance.border_radius, 0.0, 0.0, appearance.border_radius]
+                            .into(),
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                accent,
+            );
+
+            renderer.fill_text(
+                Self::text(&toast.title, self.text_size, true, parts.title.size()),
+                parts.title.position(),
+                faded(appearance.title_color, alpha),
+                bounds,
+            );
+
+            if let (Some(body), Some(body_bounds)) = (&toast.body, parts.body) {
+                renderer.fill_text(
+                    Self::text(body, self.text_size - 2.0, false, body_bounds.size()),
+                    body_bounds.position(),
+                    faded(appearance.body_color, alpha),
+                    bounds,
+                );
+            }
+
+            if let (Some((label, _)), Some(action)) = (&toast.action, parts.action) {
+                let hovered = cursor.is_over(action);
+
+                renderer.fill_text(
+                    Self::text(label, self.text_size, true, action.size()),
+                    action.position(),
+                    if hovered { faded(accent, 0.7) } else { accent },
+                    bounds,
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    ..Self::text("×", self.text_size, false, parts.close.size())
+                },
+                parts.close.center(),
+                faded(
+                    appearance.body_color,
+                    if cursor.is_over(parts.close) {
+                        alpha
+                    } else {
+                        alpha * 0.6
+                    },
+                ),
+                bounds,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        if !matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        ) {
+            return event::Status::Ignored;
+        }
+
+        for (&i, toast_layout) in self.visible.iter().zip(layout.children()) {
+            let bounds = toast_layout.bounds();
+
+            if !cursor.is_over(bounds) {
+                continue;
+            }
+
+            let toast = &self.toasts[i];
+            let parts = self.parts::<Renderer>(toast, bounds);
+            let state = &mut self.state.toasts[i];
+
+            if state.leaving {
+                return event::Status::Captured;
+            }
+
+            if let (Some((_, message)), Some(action)) = (&toast.action, parts.action) {
+                if cursor.is_over(action) {
+                    shell.publish(message.clone());
+                    state.leaving = true;
+                }
+            }
+
+            if cursor.is_over(parts.close) {
+                state.leaving = true;
+            }
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+            return event::Status::Captured;
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        for (&i, toast_layout) in self.visible.iter().zip(layout.children()) {
+            let parts = self.parts::<Renderer>(&self.toasts[i], toast_layout.bounds());
+
+            if parts
+                .action
+                .iter()
+                .chain(std::iter::once(&parts.close))
+                .any(|bounds| cursor.is_over(*bounds))
+            {
+                return mouse::Interaction::Pointer;
+            }
+        }
+
+        mouse::Interaction::default()
+    }
+
+    fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        // Only the toasts cover the content.
+        layout
+            .children()
+            .any(|toast| toast.bounds().contains(cursor_position))
+    }
+}
@@ -0,0 +1,502 @@
+//! The overlay of a [`Tour`](crate::widgets::tour::Tour) highlighting the
+//! target of its current step.
+//!
+//! *This API requires the following crate features to be activated: `tour`*
+use crate::style::tour::StyleSheet;
+use crate::widgets::tour::{TourLabels, TourStep};
+
+use iced::{
+    advanced::{
+        layout::Node,
+        renderer::{self, Quad},
+        text::{self, Paragraph, Text},
+        Clipboard, Layout, Overlay, Shell,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    font::Weight,
+    keyboard,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Color, Event, Font, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+/// The space kept between the popover and the edges of the window.
+const MARGIN: f32 = 8.0;
+/// The space between the highlighted widget and the border around it.
+const HIGHLIGHT_PADDING: f32 = 4.0;
+/// The space between the popover and the highlighted widget.
+const GAP: f32 = 10.0;
+/// The space between the parts of the popover.
+const SPACING: f32 = 8.0;
+/// The padding of the buttons.
+const BUTTON_PADDING: f32 = 6.0;
+
+/// A button of the popover of a [`Tour`](crate::widgets::tour::Tour).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Button {
+    /// Ends the tour early.
+    Skip,
+    /// Goes to the previous step.
+    Back,
+    /// Goes to the next step, or ends the tour on the last step.
+    Next,
+}
+
+/// The overlay of a [`Tour`](crate::widgets::tour::Tour).
+#[allow(missing_debug_implementations)]
+pub struct TourOverlay<'a, 'b, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The current step.
+    step: &'b TourStep,
+    /// The index of the current step.
+    index: usize,
+    /// The number of steps.
+    count: usize,
+    /// The bounds of the highlighted widget, if found.
+    target: Option<Rectangle>,
+    /// The function producing the message with the next step.
+    on_step: &'b (dyn Fn(Option<usize>) -> Message + 'a),
+    /// The labels of the buttons.
+    labels: &'b TourLabels,
+    /// The width of the popover.
+    popover_width: f32,
+    padding: f32,
+    text_size: f32,
+    font: Font,
+    /// The height of the title, measured by the layout.
+    title_height: f32,
+    /// The height of the explanation, measured by the layout.
+    text_height: f32,
+    /// The style of the tour.
+    style: &'b <Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Theme> TourOverlay<'a, 'b, Message, Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`TourOverlay`].
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        step: &'b TourStep,
+        index: usize,
+        count: usize,
+        target: Option<Rectangle>,
+        on_step: &'b (dyn Fn(Option<usize>) -> Message + 'a),
+        labels: &'b TourLabels,
+        popover_width: f32,
+        padding: f32,
+        text_size: f32,
+        font: Font,
+        style: &'b <Theme as StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            step,
+            index,
+            count,
+            target,
+            on_step,
+            labels,
+            popover_width,
+            padding,
+            text_size,
+            font,
+            title_height: 0.0,
+            text_height: 0.0,
+            style,
+        }
+    }
+
+    /// Returns the bold font of the title.
+    fn title_font(&self) -> Font {
+        Font {
+            weight: Weight::Bold,
+            ..self.font
+        }
+    }
+
+    /// Returns a text of the popover.
+    fn text<'c>(
+        &self,
+        content: &'c str,
+        bounds: Size,
+        font: Font,
+        horizontal_alignment: Horizontal,
+    ) -> Text<'c, Font> {
+        Text {
+            content,
+            bounds,
+            size: Pixels(self.text_size),
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment,
+            vertical_alignment: Vertical::Top,
+            shaping: Shaping::Advanced,
+        }
+    }
+
+    /// Returns the area cut out of the backdrop around the highlighted widget.
+    fn hole(&self) -> Option<Rectangle> {
+        self.target.map(|target| target.expand(HIGHLIGHT_PADDING))
+    }
+
+    /// Returns the height of the buttons.
+    fn button_height(&self) -> f32 {
+        LineHeight::default().to_absolute(Pixels(self.text_size)).0 + BUTTON_PADDING * 2.0
+    }
+
+    /// Returns the label of a button.
+    fn label(&self, button: Button) -> &str {
+        match button {
+            Button::Skip => &self.labels.skip,
+            Button::Back => &self.labels.back,
+            Button::Next if self.index + 1 >= self.count => &self.labels.done,
+            Button::Next => &self.labels.next,
+        }
+    }
+
+    /// Returns the step following a press on a button.
+    fn target_of(&self, button: Button) -> Option<usize> {
+        match button {
+            Button::Skip => None,
+            Button::Back => Some(self.index.saturating_sub(1)),
+            Button::Next => Some(self.index + 1).filter(|&next| next < self.count),
+        }
+    }
+
+    /// Returns the buttons of the popover with their bounds, from the right.
+    fn buttons<Renderer>(&self, popover: Rectangle) -> Vec<(Button, Rectangle)>
+    where
+        Renderer: text::Renderer<Font = Font>,
+    {
+        let mut buttons = vec![Button::Next];
+        if self.index > 0 {
+            buttons.push(Button::Back);
+        }
+        if self.index + 1 < self.count {
+            buttons.push(Button::Skip);
+        }
+
+        let height = self.button_height();
+        let y = popover.y + popover.height - self.padding - height;
+        let mut right = popover.x + popover.width - self.padding;
+
+        buttons
+            .into_iter()
+            .map(|button| {
+                let width = Renderer::Paragraph::with_text(self.text(
+                    self.label(button),
+                    Size::INFINITY,
+                    self.font,
+                    Horizontal::Left,
+                ))
+                .min_bounds()
+                .width
+                    + BUTTON_PADDING * 4.0;
+                right -= width;
+                let bounds = Rectangle::new(Point::new(right, y), Size::new(width, height));
+                right -= SPACING / 2.0;
+
+                (button, bounds)
+            })
+            .collect()
+    }
+}
+
+impl<Message, Theme, Renderer> Overlay<Message, Theme, Renderer>
+    for TourOverlay<'_, '_, Message, Theme>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+        let width = self
+            .popover_width
+            .min(bounds.width - MARGIN * 2.0)
+            .max(self.padding * 2.0);
+        let inner = Size::new(width - self.padding * 2.0, f32::INFINITY);
+
+        self.title_height = Renderer::Paragraph::with_text(self.text(
+            &self.step.title,
+            inner,
+            self.title_font(),
+            Horizontal::Left,
+        ))
+        .min_bounds()
+        .height;
+        self.text_height = Renderer::Paragraph::with_text(self.text(
+            &self.step.text,
+            inner,
+            self.font,
+            Horizontal::Left,
+        ))
+        .min_bounds()
+        .height;
+
+        let height = self.padding * 2.0
+            + self.title_height
+            + SPACING
+            + self.text_height
+            + SPACING * 2.0
+            + self.button_height();
+        let size = Size::new(width, height);
+
+        // The popover is below the highlighted widget, above it when it does
+        // not fit below, or centered in the window without a target.
+        let position = self.hole().map_or_else(
+            || Point::new((bounds.width - width) / 2.0, (bounds.height - height) / 2.0),
+            |hole| {
+                let x = (hole.center_x() - width / 2.0)
+                    .min(bounds.width - MARGIN - width)
+                    .max(MARGIN);
+                let below = hole.y + hole.height + GAP;
+                let above = hole.y - GAP - height;
+                let y = if below + height <= bounds.height - MARGIN || above < MARGIN {
+                    below.min(bounds.height - MARGIN - height).max(MARGIN)
+                } else {
+                    above
+                };
+
+                Point::new(x, y)
+            },
+        );
+
+        Node::with_children(bounds, vec![Node::new(size).move_to(position)])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let Some(popover) = layout.children().next().map(|popover| popover.bounds()) else {
+            return event::Status::Ignored;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some((button, _)) = self
+                    .buttons::<Renderer>(popover)
+                    .into_iter()
+                    .find(|(_, bounds)| cursor.is_over(*bounds))
+                {
+                    shell.publish((self.on_step)(self.target_of(button)));
+                    return event::Status::Captured;
+                }
+
+                // The highlighted widget stays interactive.
+                if self.hole().is_some_and(|hole| cursor.is_over(hole)) {
+                    event::Status::Ignored
+                } else {
+                    event::Status::Captured
+                }
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) => {
+                let button = match key {
+                    keyboard::key::Named::Escape => Button::Skip,
+                    keyboard::key::Named::ArrowRight | keyboard::key::Named::Enter => Button::Next,
+                    keyboard::key::Named::ArrowLeft if self.index > 0 => Button::Back,
+                    _ => return event::Status::Ignored,
+                };
+
+                shell.publish((self.on_step)(self.target_of(button)));
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let over_button = layout.children().next().is_some_and(|popover| {
+            self.buttons::<Renderer>(popover.bounds())
+                .iter()
+                .any(|(_, bounds)| cursor.is_over(*bounds))
+        });
+
+        if over_button {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        let Some(popover) = layout.children().next().map(|popover| popover.bounds()) else {
+            return;
+        };
+        let window = layout.bounds();
+        let appearance = theme.active(self.style);
+        let quad = |bounds, radius: f32| Quad {
+            bounds,
+            border: Border::with_radius(radius),
+            shadow: Shadow::default(),
+        };
+
+        match self.hole() {
+            Some(hole) => {
+                let bottom = hole.y + hole.height;
+                let right = hole.x + hole.width;
+
+                for bounds in [
+                    Rectangle::new(window.position(), Size::new(window.width, hole.y)),
+                    Rectangle::new(
+                        Point::new(window.x, bottom),
+                        Size::new(window.width, (window.height - bottom).max(0.0)),
+                    ),
+                    Rectangle::new(Point::new(window.x, hole.y), Size::new(hole.x, hole.height)),
+                    Rectangle::new(
+                        Point::new(right, hole.y),
+                        Size::new((window.width - right).max(0.0), hole.height),
+                    ),
+                ] {
+                    renderer.fill_quad(quad(bounds, 0.0), appearance.backdrop_color);
+                }
+
+                renderer.fill_quad(
+                    Quad {
+                        bounds: hole,
+                        border: Border {
+                            radius: appearance.highlight_border_radius.into(),
+                            width: appearance.highlight_border_width,
+                            color: appearance.highlight_border_color,
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+            None => renderer.fill_quad(quad(window, 0.0), appearance.backdrop_color),
+        }
+
+        renderer.fill_quad(
+            Quad {
+                bounds: popover,
+                border: Border::with_radius(appearance.border_radius),
+                shadow: Shadow {
+                    color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+                    offset: iced::Vector::new(0.0, 2.0),
+                    blur_radius: 8.0,
+                },
+            },
+            appearance.background,
+        );
+
+        let inner_width = popover.width - self.padding * 2.0;
+        let title = Rectangle::new(
+            Point::new(popover.x + self.padding, popover.y + self.padding),
+            Size::new(inner_width, self.title_height),
+        );
+        renderer.fill_text(
+            self.text(
+                &self.step.title,
+                title.size(),
+                self.title_font(),
+                Horizontal::Left,
+            ),
+            title.position(),
+            appearance.title_color,
+            title,
+        );
+
+        let explanation = Rectangle::new(
+            Point::new(title.x, title.y + title.height + SPACING),
+            Size::new(inner_width, self.text_height),
+        );
+        renderer.fill_text(
+            self.text(
+                &self.step.text,
+                explanation.size(),
+                self.font,
+                Horizontal::Left,
+            ),
+            explanation.position(),
+            appearance.text_color,
+            explanation,
+        );
+
+        let buttons = self.buttons::<Renderer>(popover);
+        let button_height = self.button_height();
+        let counter = format!("{} / {}", self.index + 1, self.count);
+        let counter_bounds = Rectangle::new(
+            Point::new(
+                title.x,
+                popover.y + popover.height - self.padding - button_height + BUTTON_PADDING,
+            ),
+            Size::new(inner_width, button_height),
+        );
+        renderer.fill_text(
+            self.text(&counter, counter_bounds.size(), self.font, Horizontal::Left),
+            counter_bounds.position(),
+            appearance.secondary_text_color,
+            counter_bounds,
+        );
+
+        for (button, bounds) in buttons {
+            let hovered = cursor.is_over(bounds);
+            let color = if button == Button::Next {
+                renderer.fill_quad(
+                    quad(bounds, appearance.border_radius),
+                    appearance.button_background,
+                );
+                appearance.button_text_color
+            } else {
+                if hovered {
+                    renderer.fill_quad(
+                        quad(bounds, appearance.border_radius),
+                        Color {
+                            a: 0.1,
+                            ..appearance.secondary_text_color
+                        },
+                    );
+                }
+                appearance.secondary_text_color
+            };
+
+            renderer.fill_text(
+                self.text(
+                    self.label(button),
+                    bounds.size(),
+                    self.font,
+                    Horizontal::Center,
+                ),
+                Point::new(bounds.center_x(), bounds.y + BUTTON_PADDING),
+                color,
+                bounds,
+            );
+        }
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        // The highlighted widget stays reachable through the backdrop.
+        !self
+            .hole()
+            .is_some_and(|hole| hole.contains(cursor_position))
+    }
+}
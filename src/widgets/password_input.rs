@@ -0,0 +1,525 @@
+//! Type a password and see how strong it is.
+//!
+//! *This API requires the following crate features to be activated: `password_input`*
+
+use crate::core::{
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    settings,
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::{
+            self,
+            tree::{self, Tag, Tree},
+            Operation,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    keyboard::{self, key::Named},
+    mouse::{self, Cursor},
+    touch,
+    widget::{
+        text::{LineHeight, Shaping},
+        text_input::{self, TextInput},
+    },
+    Border, Element, Event, Font, Length, Padding, Pixels, Point, Rectangle, Shadow, Size,
+};
+
+pub use crate::core::password::Strength;
+pub use crate::style::password_input::{Appearance, StyleSheet};
+
+/// The default padding of a [`PasswordInput`].
+const DEFAULT_PADDING: f32 = 5.0;
+/// The default height of the strength meter of a [`PasswordInput`].
+const DEFAULT_METER_HEIGHT: f32 = 4.0;
+
+/// The function producing a message from the typed password.
+type OnInput<'a, Message> = Box<dyn Fn(String) -> Message + 'a>;
+/// The function estimating the strength of a password.
+type Scorer<'a> = Box<dyn Fn(&str) -> Strength + 'a>;
+
+/// A secure text input with a button revealing the password and an optional
+/// meter showing its strength.
+///
+/// The strength is computed by the scorer given to
+/// [`strength_meter`](PasswordInput::strength_meter), like
+/// [`Strength::estimate`].
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::password_input::{PasswordInput, Strength};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PasswordChanged(String),
+///     Login,
+/// }
+///
+/// let password = String::new();
+///
+/// let password_input = PasswordInput::new("Password", &password, Message::PasswordChanged)
+///     .on_submit(Message::Login)
+///     .strength_meter(Strength::estimate);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct PasswordInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// The text shown while nothing is typed.
+    placeholder: String,
+    /// The password given by the application.
+    value: String,
+    /// The function producing the message when the password changes.
+    on_input: OnInput<'a, Message>,
+    /// The message produced when Enter is pressed.
+    on_submit: Option<Message>,
+    /// The function estimating the strength shown by the meter, if any.
+    scorer: Option<Scorer<'a>>,
+    /// The id of the text input of the [`PasswordInput`].
+    id: Option<text_input::Id>,
+    width: Length,
+    padding: f32,
+    text_size: f32,
+    font: Font,
+    meter_height: f32,
+    /// The style of the [`PasswordInput`].
+    style: <Theme as StyleSheet>::Style,
+    #[allow(clippy::missing_docs_in_private_items)]
+    renderer: std::marker::PhantomData<Renderer>,
+}
+
+/// The state of a [`PasswordInput`].
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the password is shown in clear.
+    revealed: bool,
+}
+
+impl<'a, Message, Theme, Renderer> PasswordInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// Creates a new [`PasswordInput`].
+    ///
+    /// It expects:
+    ///   * the placeholder shown while nothing is typed
+    ///   * the current password
+    ///   * a function that will be called when the password changes. It
+    ///     receives the new password and must produce a `Message`.
+    pub fn new<F>(placeholder: &str, value: &str, on_input: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        Self {
+            placeholder: placeholder.to_owned(),
+            value: value.to_owned(),
+            on_input: Box::new(on_input),
+            on_submit: None,
+            scorer: None,
+            id: None,
+            width: Length::Fill,
+            padding: settings::spaced(DEFAULT_PADDING),
+            text_size: settings::scaled(16.0),
+            font: settings::font(),
+            meter_height: DEFAULT_METER_HEIGHT,
+            style: <Theme as StyleSheet>::Style::default(),
+            renderer: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the message produced when Enter is pressed.
+    #[must_use]
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    /// Shows a strength meter below the field, filled according to the
+    /// strength estimated by `scorer`.
+    #[must_use]
+    pub fn strength_meter<F>(mut self, scorer: F) -> Self
+    where
+        F: 'a + Fn(&str) -> Strength,
+    {
+        self.scorer = Some(Box::new(scorer));
+        self
+    }
+
+    /// Sets the id of the text input of the [`PasswordInput`], to focus it
+    /// with the operations of text inputs.
+    #[must_use]
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`PasswordInput`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the [`PasswordInput`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`PasswordInput`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`PasswordInput`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the height of the strength meter of the [`PasswordInput`].
+    #[must_use]
+    pub fn meter_height(mut self, meter_height: f32) -> Self {
+        self.meter_height = meter_height;
+        self
+    }
+
+    /// Sets the style of the [`PasswordInput`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the text input editing the password, leaving room for the
+    /// button revealing it.
+    fn input(&self, revealed: bool) -> TextInput<'_, String, Theme, Renderer> {
+        let input = TextInput::new(&self.placeholder, &self.value)
+            .on_input(|password| password)
+            .secure(!revealed)
+            .width(Length::Fill)
+            .padding(Padding {
+                top: self.padding,
+                right: self.padding * 2.0 + self.text_size,
+                bottom: self.padding,
+                left: self.padding,
+            })
+            .size(self.text_size)
+            .font(self.font);
+
+        match &self.id {
+            Some(id) => input.id(id.clone()),
+            None => input,
+        }
+    }
+
+    /// Returns the bounds of the button revealing the password of a field
+    /// with the given bounds.
+    fn reveal_button(&self, field: Rectangle) -> Rectangle {
+        let width = self.padding * 2.0 + self.text_size;
+
+        Rectangle {
+            x: field.x + field.width - width,
+            width,
+            ..field
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for PasswordInput<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.input(false) as &dyn Widget<String, Theme, Renderer>
+        )]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let revealed = tree.state.downcast_ref::<State>().revealed;
+        tree.children[0].diff(&self.input(revealed) as &dyn Widget<String, Theme, Renderer>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let revealed = tree.state.downcast_ref::<State>().revealed;
+        let input = Widget::<String, Theme, Renderer>::layout(
+            &self.input(revealed),
+            &mut tree.children[0],
+            renderer,
+            &limits.width(self.width),
+        );
+        let meter = if self.scorer.is_some() {
+            self.padding + self.meter_height
+        } else {
+            0.0
+        };
+        let size = Size::new(input.size().width, input.size().height + meter);
+
+        Node::with_children(size, vec![input])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(input_layout) = layout.children().next() else {
+            return event::Status::Ignored;
+        };
+        let state = tree.state.downcast_mut::<State>();
+        let input_state = tree.children[0]
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(self.reveal_button(input_layout.bounds())) =>
+            {
+                state.revealed = !state.revealed;
+                input_state.focus();
+                shell.invalidate_layout();
+                return event::Status::Captured;
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(Named::Enter),
+                ..
+            }) if input_state.is_focused() => {
+                if let Some(message) = self.on_submit.clone() {
+                    shell.publish(message);
+                    return event::Status::Captured;
+                }
+            }
+
+            _ => {}
+        }
+
+        let mut messages = Vec::new();
+        let mut input_shell = Shell::new(&mut messages);
+        let status = Widget::<String, Theme, Renderer>::on_event(
+            &mut self.input(state.revealed),
+            &mut tree.children[0],
+            event,
+            input_layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut input_shell,
+            viewport,
+        );
+
+        if input_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if input_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+        if let Some(request) = input_shell.redraw_request() {
+            shell.request_redraw(request);
+        }
+
+        if let Some(password) = messages.pop() {
+            self.value.clone_from(&password);
+            shell.publish((self.on_input)(password));
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        layout
+            .children()
+            .next()
+            .map_or_else(mouse::Interaction::default, |input_layout| {
+                if cursor.is_over(self.reveal_button(input_layout.bounds())) {
+                    mouse::Interaction::Pointer
+                } else {
+                    Widget::<String, Theme, Renderer>::mouse_interaction(
+                        &self.input(state.revealed),
+                        &tree.children[0],
+                        input_layout,
+                        cursor,
+                        viewport,
+                        renderer,
+                    )
+                }
+            })
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let Some(input_layout) = layout.children().next() else {
+            return;
+        };
+        let field = input_layout.bounds();
+
+        Widget::<String, Theme, Renderer>::draw(
+            &self.input(state.revealed),
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            input_layout,
+            cursor,
+            viewport,
+        );
+
+        let appearance = if tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused()
+        {
+            <Theme as StyleSheet>::focused(theme, &self.style)
+        } else {
+            <Theme as StyleSheet>::active(theme, &self.style)
+        };
+
+        let reveal = self.reveal_button(field);
+        renderer.fill_text(
+            Text {
+                content: &icon_to_string(if state.revealed {
+                    Bootstrap::EyeSlash
+                } else {
+                    Bootstrap::Eye
+                }),
+                bounds: reveal.size(),
+                size: Pixels(self.text_size),
+                line_height: LineHeight::default(),
+                font: BOOTSTRAP_FONT,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: Shaping::Basic,
+            },
+            reveal.center(),
+            if cursor.is_over(reveal) {
+                appearance.hovered_icon_color
+            } else {
+                appearance.icon_color
+            },
+            reveal,
+        );
+
+        let Some(scorer) = &self.scorer else {
+            return;
+        };
+
+        let meter = Rectangle::new(
+            Point::new(field.x, field.y + field.height + self.padding),
+            Size::new(field.width, self.meter_height),
+        );
+        renderer.fill_quad(
+            Quad {
+                bounds: meter,
+                border: Border::with_radius(appearance.meter_border_radius),
+                shadow: Shadow::default(),
+            },
+            appearance.meter_background,
+        );
+
+        if self.value.is_empty() {
+            return;
+        }
+
+        let strength = scorer(&self.value);
+        renderer.fill_quad(
+            Quad {
+                bounds: Rectangle {
+                    width: meter.width * strength.ratio(),
+                    ..meter
+                },
+                border: Border::with_radius(appearance.meter_border_radius),
+                shadow: Shadow::default(),
+            },
+            match strength {
+                Strength::Weak => appearance.weak_color,
+                Strength::Fair => appearance.fair_color,
+                Strength::Good => appearance.good_color,
+                Strength::Strong => appearance.strong_color,
+            },
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let id = self.id.clone().map(widget::Id::from);
+        let input = tree.children[0]
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        operation.focusable(input, id.as_ref());
+        operation.text_input(input, id.as_ref());
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<PasswordInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet + text_input::StyleSheet,
+{
+    fn from(password_input: PasswordInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(password_input)
+    }
+}
@@ -0,0 +1,494 @@
+//! Type a code digit by digit in separate boxes.
+//!
+//! *This API requires the following crate features to be activated: `pin_input`*
+
+use crate::core::settings;
+
+use iced::{
+    advanced::{
+        clipboard,
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    keyboard::{self, key::Named},
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+use std::marker::PhantomData;
+
+pub use crate::style::pin_input::{Appearance, StyleSheet};
+
+/// The default size of a box of a [`PinInput`].
+const DEFAULT_BOX_SIZE: f32 = 40.0;
+/// The default space between the boxes of a [`PinInput`].
+const DEFAULT_SPACING: f32 = 8.0;
+/// The character shown instead of the typed ones in masked mode.
+const MASK: char = '•';
+
+/// The function producing a message from the typed code.
+type OnCode<'a, Message> = Box<dyn Fn(String) -> Message + 'a>;
+
+/// A row of boxes each holding one character of a code, like a PIN or a one
+/// time password.
+///
+/// Typing a character fills the focused box and moves to the next one.
+/// Backspace clears the focused box, or the previous one when it is empty.
+/// Pasted text is split across the boxes starting at the focused one. The
+/// code is filled from the left, so clicking an empty box focuses the first
+/// empty one.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::pin_input::PinInput;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CodeChanged(String),
+///     CodeEntered(String),
+/// }
+///
+/// let code = String::from("12");
+///
+/// let pin_input = PinInput::new(6, &code, Message::CodeChanged)
+///     .on_complete(Message::CodeEntered)
+///     .masked(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct PinInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// The number of boxes.
+    length: usize,
+    /// The characters of the current code.
+    value: Vec<char>,
+    /// The function producing the message when the code changes.
+    on_input: OnCode<'a, Message>,
+    /// The function producing the message when every box is filled.
+    on_complete: Option<OnCode<'a, Message>>,
+    /// Whether the typed characters are hidden.
+    masked: bool,
+    /// Whether only digits can be typed.
+    numeric: bool,
+    box_size: f32,
+    spacing: f32,
+    text_size: f32,
+    font: Font,
+    /// The style of the [`PinInput`].
+    style: <Theme as StyleSheet>::Style,
+    #[allow(clippy::missing_docs_in_private_items)]
+    renderer: PhantomData<Renderer>,
+}
+
+/// The state of a [`PinInput`].
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// The box receiving the typed characters.
+    pub(crate) focused: Option<usize>,
+}
+
+impl<'a, Message, Theme, Renderer> PinInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`PinInput`].
+    ///
+    /// It expects:
+    ///   * the number of boxes, at least one
+    ///   * the current code
+    ///   * a function that will be called when the code changes. It receives
+    ///     the new code and must produce a `Message`.
+    pub fn new<F>(length: usize, value: &str, on_input: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        let length = length.max(1);
+
+        Self {
+            length,
+            value: value.chars().take(length).collect(),
+            on_input: Box::new(on_input),
+            on_complete: None,
+            masked: false,
+            numeric: true,
+            box_size: settings::scaled(DEFAULT_BOX_SIZE),
+            spacing: settings::spaced(DEFAULT_SPACING),
+            text_size: settings::scaled(20.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+            renderer: PhantomData,
+        }
+    }
+
+    /// Sets the function producing the message when every box is filled. It
+    /// receives the complete code.
+    #[must_use]
+    pub fn on_complete<F>(mut self, on_complete: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_complete = Some(Box::new(on_complete));
+        self
+    }
+
+    /// Sets whether the typed characters are hidden behind dots.
+    #[must_use]
+    pub fn masked(mut self, masked: bool) -> Self {
+        self.masked = masked;
+        self
+    }
+
+    /// Sets whether only digits can be typed. Otherwise letters are accepted
+    /// too. Defaults to `true`.
+    #[must_use]
+    pub fn numeric(mut self, numeric: bool) -> Self {
+        self.numeric = numeric;
+        self
+    }
+
+    /// Sets the size of the boxes of the [`PinInput`].
+    #[must_use]
+    pub fn box_size(mut self, box_size: f32) -> Self {
+        self.box_size = box_size;
+        self
+    }
+
+    /// Sets the space between the boxes of the [`PinInput`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the [`PinInput`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`PinInput`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`PinInput`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Whether `c` can be typed in a box.
+    fn accepts(&self, c: char) -> bool {
+        if self.numeric {
+            c.is_ascii_digit()
+        } else {
+            c.is_alphanumeric()
+        }
+    }
+
+    /// Returns the box that can receive the caret closest to `index`, as the
+    /// boxes are filled without gaps.
+    fn caret(&self, index: usize) -> usize {
+        index.min(self.value.len()).min(self.length - 1)
+    }
+
+    /// Publishes the current code, and completes it when every box is filled.
+    fn publish(&self, shell: &mut Shell<'_, Message>) {
+        let code: String = self.value.iter().collect();
+        shell.publish((self.on_input)(code.clone()));
+
+        if self.value.len() == self.length {
+            if let Some(on_complete) = &self.on_complete {
+                shell.publish(on_complete(code));
+            }
+        }
+    }
+
+    /// Writes the accepted characters of `text` in the boxes from `index`,
+    /// moving the caret after the last one written.
+    fn write(
+        &mut self,
+        state: &mut State,
+        index: usize,
+        text: &str,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let chars: Vec<char> = text.chars().filter(|c| self.accepts(*c)).collect();
+
+        if chars.is_empty() {
+            return;
+        }
+
+        let mut index = self.caret(index);
+
+        for c in chars.into_iter().take(self.length - index) {
+            if index < self.value.len() {
+                self.value[index] = c;
+            } else {
+                self.value.push(c);
+            }
+            index += 1;
+        }
+
+        state.focused = Some(self.caret(index));
+        self.publish(shell);
+    }
+
+    /// Returns the text of `content` centered in `bounds`.
+    fn text(content: &str, bounds: Size, font: Font, size: f32) -> Text<'_, Font> {
+        Text {
+            content,
+            bounds,
+            size: Pixels(size),
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for PinInput<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let count = self.length as f32;
+        let size = Size::new(
+            count * self.box_size + (count - 1.0) * self.spacing,
+            self.box_size,
+        );
+        let children = (0..self.length)
+            .map(|index| {
+                Node::new(Size::new(self.box_size, self.box_size)).move_to(Point::new(
+                    index as f32 * (self.box_size + self.spacing),
+                    0.0,
+                ))
+            })
+            .collect();
+
+        Node::with_children(
+            limits.resolve(Length::Shrink, Length::Shrink, size),
+            children,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    state.focused = None;
+                    return event::Status::Ignored;
+                };
+
+                let index = layout
+                    .children()
+                    .position(|cell| cell.bounds().contains(position))
+                    .unwrap_or(self.value.len());
+                state.focused = Some(self.caret(index));
+
+                event::Status::Captured
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) => {
+                let Some(focused) = state.focused.map(|index| self.caret(index)) else {
+                    return event::Status::Ignored;
+                };
+
+                match key.as_ref() {
+                    keyboard::Key::Named(Named::Backspace) => {
+                        if focused < self.value.len() {
+                            let _ = self.value.remove(focused);
+                            self.publish(shell);
+                        } else if let Some(previous) = focused.checked_sub(1) {
+                            let _ = self.value.remove(previous);
+                            state.focused = Some(previous);
+                            self.publish(shell);
+                        }
+                    }
+                    keyboard::Key::Named(Named::Delete) => {
+                        if focused < self.value.len() {
+                            let _ = self.value.remove(focused);
+                            self.publish(shell);
+                        }
+                    }
+                    keyboard::Key::Named(Named::ArrowLeft) => {
+                        state.focused = Some(focused.saturating_sub(1));
+                    }
+                    keyboard::Key::Named(Named::ArrowRight) => {
+                        state.focused = Some(self.caret(focused + 1));
+                    }
+                    keyboard::Key::Named(Named::Home) => {
+                        state.focused = Some(0);
+                    }
+                    keyboard::Key::Named(Named::End) => {
+                        state.focused = Some(self.caret(self.length));
+                    }
+                    keyboard::Key::Named(Named::Escape | Named::Tab) => {
+                        state.focused = None;
+                        return event::Status::Ignored;
+                    }
+                    keyboard::Key::Character("v") if modifiers.command() => {
+                        if let Some(content) = clipboard.read(clipboard::Kind::Standard) {
+                            self.write(state, focused, &content, shell);
+                        }
+                    }
+                    _ => match text {
+                        Some(text) if !modifiers.command() => {
+                            self.write(state, focused, &text, shell);
+                        }
+                        _ => return event::Status::Ignored,
+                    },
+                }
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let focused = state.focused.map(|index| self.caret(index));
+
+        for (index, cell) in layout.children().enumerate() {
+            let bounds = cell.bounds();
+            let appearance = if focused == Some(index) {
+                theme.focused(&self.style)
+            } else {
+                theme.active(&self.style)
+            };
+
+            renderer.fill_quad(
+                Quad {
+                    bounds,
+                    border: Border {
+                        radius: appearance.border_radius.into(),
+                        width: appearance.border_width,
+                        color: appearance.border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                appearance.background,
+            );
+
+            match self.value.get(index) {
+                Some(&c) => {
+                    let c = if self.masked { MASK } else { c };
+
+                    renderer.fill_text(
+                        Self::text(&c.to_string(), bounds.size(), self.font, self.text_size),
+                        bounds.center(),
+                        appearance.text_color,
+                        bounds,
+                    );
+                }
+                None if focused == Some(index) => {
+                    let height = LineHeight::default()
+                        .to_absolute(Pixels(self.text_size))
+                        .0
+                        .min(bounds.height);
+
+                    renderer.fill_quad(
+                        Quad {
+                            bounds: Rectangle::new(
+                                Point::new(bounds.center_x(), bounds.center_y() - height / 2.0),
+                                Size::new(1.0, height),
+                            ),
+                            border: Border::default(),
+                            shadow: Shadow::default(),
+                        },
+                        appearance.text_color,
+                    );
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<PinInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(pin_input: PinInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(pin_input)
+    }
+}
@@ -0,0 +1,452 @@
+//! Show rich content next to a target widget.
+//!
+//! *This API requires the following crate features to be activated: `popover`*
+
+use super::overlay::popover::PopoverOverlay;
+use crate::core::{lazy_content::LazyContent, settings};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay::{self, Group},
+        renderer,
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch, window, Element, Event, Length, Padding, Rectangle, Size, Vector,
+};
+use std::time::{Duration, Instant};
+
+pub use crate::{
+    core::overlay::Placement,
+    style::popover::{Appearance, StyleSheet},
+};
+
+/// How long a hovered [`Popover`] stays open after the cursor leaves it, so
+/// the cursor can move between the target and the popover.
+const CLOSE_DELAY: Duration = Duration::from_millis(150);
+
+/// What opens a [`Popover`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Trigger {
+    /// Hovering the target for the given delay.
+    Hover(Duration),
+    /// Clicking the target, which also closes it.
+    Click,
+    /// The application, which shows the popover while the value is `true`.
+    Manual(bool),
+}
+
+impl Default for Trigger {
+    fn default() -> Self {
+        Self::Hover(Duration::from_millis(500))
+    }
+}
+
+/// A widget showing content next to a target widget, pointed at it by an arrow.
+///
+/// The popover is shown on the side given by its [`Placement`], or on the
+/// opposite side when it does not fit in the window, and is kept inside the
+/// window along the target. Pressing Escape or clicking outside of it closes
+/// it.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::{button, text};
+/// # use iced_aw::popover::{Popover, Trigger};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Save,
+/// }
+///
+/// let popover = Popover::new(
+///     button(text("Save")).on_press(Message::Save),
+///     text("Saves the document to the disk"),
+/// )
+/// .trigger(Trigger::Hover(std::time::Duration::from_millis(300)));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Popover<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The widget the popover points at.
+    target: Element<'a, Message, Theme, Renderer>,
+    /// The content of the popover.
+    content: LazyContent<'a, Message, Theme, Renderer>,
+    placement: Placement,
+    trigger: Trigger,
+    /// The optional message produced when the user closes a manual popover.
+    on_dismiss: Option<Message>,
+    /// The space between the arrow and the target.
+    gap: f32,
+    /// The width of the arrow.
+    arrow_size: f32,
+    padding: Padding,
+    max_width: f32,
+    /// The style of the [`Popover`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`Popover`].
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// Whether a hover or click popover is open.
+    pub(crate) open: bool,
+    /// When the cursor started hovering the closed target.
+    pub(crate) hovered_since: Option<Instant>,
+    /// When the cursor left the open popover and its target.
+    pub(crate) left_at: Option<Instant>,
+    /// Whether the cursor is over the popover.
+    pub(crate) over_popover: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Popover<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Popover`] showing the `content` next to the `target`.
+    pub fn new<T, C>(target: T, content: C) -> Self
+    where
+        T: Into<Element<'a, Message, Theme, Renderer>>,
+        C: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self::with_content(target, LazyContent::new(content))
+    }
+
+    /// Creates a new [`Popover`] showing the content built by `content` next
+    /// to the `target`.
+    ///
+    /// The content is only built while the popover is open, which avoids
+    /// building an expensive content in every view while it is closed.
+    pub fn lazy<T>(
+        target: T,
+        content: impl FnOnce() -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self
+    where
+        T: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self::with_content(target, LazyContent::lazy(content))
+    }
+
+    /// Creates a new [`Popover`] with the given content.
+    fn with_content<T>(target: T, content: LazyContent<'a, Message, Theme, Renderer>) -> Self
+    where
+        T: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self {
+            target: target.into(),
+            content,
+            placement: Placement::default(),
+            trigger: Trigger::default(),
+            on_dismiss: None,
+            gap: 2.0,
+            arrow_size: 12.0,
+            padding: settings::spaced(8.0).into(),
+            max_width: 320.0,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the side of the target the [`Popover`] is shown on.
+    #[must_use]
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets what opens the [`Popover`].
+    #[must_use]
+    pub fn trigger(mut self, trigger: Trigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// Sets the message produced when the user closes a [`Trigger::Manual`]
+    /// [`Popover`] by pressing Escape or clicking outside of it.
+    #[must_use]
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+
+    /// Sets the space between the arrow of the [`Popover`] and the target.
+    #[must_use]
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the width of the arrow of the [`Popover`].
+    #[must_use]
+    pub fn arrow_size(mut self, arrow_size: f32) -> Self {
+        self.arrow_size = arrow_size;
+        self
+    }
+
+    /// Sets the padding around the content of the [`Popover`].
+    #[must_use]
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the maximum width of the [`Popover`].
+    #[must_use]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the style of the [`Popover`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Opens or closes a hover popover as the cursor moves to `inside` or
+    /// out of the target and the popover.
+    fn hover(state: &mut State, inside: bool, delay: Duration, shell: &mut Shell<'_, Message>) {
+        let now = Instant::now();
+
+        if inside {
+            state.left_at = None;
+
+            if !state.open && state.hovered_since.is_none() {
+                state.hovered_since = Some(now);
+                shell.request_redraw(window::RedrawRequest::At(now + delay));
+            }
+        } else {
+            state.hovered_since = None;
+
+            if state.open && state.left_at.is_none() {
+                state.left_at = Some(now);
+                shell.request_redraw(window::RedrawRequest::At(now + CLOSE_DELAY));
+            }
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Popover<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.target), self.content.tree()]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children.resize_with(2, Tree::empty);
+        tree.children[0].diff(&self.target);
+        self.content.diff(&mut tree.children[1]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.target.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.target
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.target.as_widget_mut().on_event(
+            &mut state.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+        let popover_state = state.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match (self.trigger, event) {
+            (Trigger::Hover(delay), Event::Mouse(mouse::Event::CursorMoved { position })) => {
+                let inside = bounds.contains(position) || popover_state.over_popover;
+                Self::hover(popover_state, inside, delay, shell);
+            }
+            (Trigger::Hover(delay), Event::Mouse(mouse::Event::CursorLeft)) => {
+                Self::hover(popover_state, false, delay, shell);
+            }
+            (Trigger::Hover(delay), Event::Window(_, window::Event::RedrawRequested(now))) => {
+                if let Some(since) = popover_state.hovered_since {
+                    if now >= since + delay {
+                        popover_state.open = true;
+                        popover_state.hovered_since = None;
+                        shell.invalidate_layout();
+                    } else {
+                        shell.request_redraw(window::RedrawRequest::At(since + delay));
+                    }
+                }
+
+                if let Some(left_at) = popover_state.left_at {
+                    if now >= left_at + CLOSE_DELAY {
+                        popover_state.open = false;
+                        popover_state.left_at = None;
+                        popover_state.over_popover = false;
+                        shell.invalidate_layout();
+                    } else {
+                        shell.request_redraw(window::RedrawRequest::At(left_at + CLOSE_DELAY));
+                    }
+                }
+            }
+            (
+                Trigger::Click,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }),
+            ) if cursor.is_over(bounds) => {
+                popover_state.open = !popover_state.open;
+                shell.invalidate_layout();
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.target.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.target.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.target
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let popover_state = state.state.downcast_mut::<State>();
+        let mut children = state.children.iter_mut();
+        let (target_tree, content_tree) = (children.next()?, children.next()?);
+        let mut group = Group::new();
+        let open = match self.trigger {
+            Trigger::Manual(open) => open,
+            Trigger::Hover(_) | Trigger::Click => popover_state.open,
+        };
+
+        if let Some(target) =
+            self.target
+                .as_widget_mut()
+                .overlay(target_tree, layout, renderer, translation)
+        {
+            group = group.push(target);
+        }
+
+        if let Some(content) = open
+            .then(|| self.content.get_or_build(content_tree))
+            .flatten()
+        {
+            group = group.push(overlay::Element::new(Box::new(PopoverOverlay::new(
+                popover_state,
+                content_tree,
+                content,
+                layout.bounds() + translation,
+                self.placement,
+                self.trigger,
+                self.on_dismiss.clone(),
+                self.gap,
+                self.arrow_size,
+                self.padding,
+                self.max_width,
+                &self.style,
+            ))));
+        }
+
+        Some(group.overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Popover<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(popover: Popover<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(popover)
+    }
+}
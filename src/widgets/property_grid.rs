@@ -0,0 +1,701 @@
+//! Edit labeled properties, grouped and searched, like the inspector of an
+//! editor.
+//!
+//! *This API requires the following crate features to be activated: `property_grid`*
+
+use crate::core::{
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    property::{items, Item},
+    settings,
+};
+use crate::widgets::overlay::PropertyGridOverlay;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::{self, Cursor},
+    touch,
+    widget::{
+        checkbox,
+        text::{LineHeight, Shaping},
+        text_input, Checkbox, Column, Row, Space, TextInput,
+    },
+    Alignment, Border, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size,
+    Vector,
+};
+use std::collections::{HashMap, HashSet};
+
+pub use crate::{
+    core::property::{Property, PropertyValue},
+    style::property_grid::{Appearance, StyleSheet},
+};
+
+/// The padding of the text inputs of the editors.
+const FIELD_PADDING: f32 = 4.0;
+
+/// An action of the editors of a [`PropertyGrid`].
+#[derive(Clone, Debug)]
+enum Action {
+    /// The property at the index takes the value.
+    Edit(usize, PropertyValue),
+    /// The text of the number or the color at the index is edited.
+    Draft(usize, String),
+}
+
+/// The function producing the message with the index and the new value of a
+/// property.
+type OnChange<'a, Message> = Box<dyn Fn(usize, PropertyValue) -> Message + 'a>;
+
+/// A two-column list of labeled properties, each one edited by the editor of
+/// its value: a text input for texts, numbers and hexadecimal colors, a
+/// checkbox for booleans and a drop down list for choices.
+///
+/// The properties are listed in collapsible groups, and only the ones whose
+/// label contains the searched text are shown. A number or a color is only
+/// changed once its text is valid.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::property_grid::{Property, PropertyGrid, PropertyValue};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PropertyChanged(usize, PropertyValue),
+/// }
+///
+/// let properties = vec![
+///     Property::new("Name", PropertyValue::Text(String::from("button"))),
+///     Property::new("Width", PropertyValue::Number(120.0)).group("Layout"),
+///     Property::new("Visible", PropertyValue::Bool(true)).group("Layout"),
+///     Property::new("Align", PropertyValue::choice(["Left", "Center"], 0)).group("Layout"),
+/// ];
+///
+/// let property_grid = PropertyGrid::new(&properties, Message::PropertyChanged)
+///     .search("wid");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct PropertyGrid<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// The edited properties.
+    properties: &'a [Property],
+    /// The function producing the message when a property is edited.
+    on_change: OnChange<'a, Message>,
+    /// The text searched in the labels of the properties.
+    search: String,
+    width: Length,
+    /// The width of the column of the labels.
+    label_width: Length,
+    padding: f32,
+    text_size: f32,
+    font: Font,
+    /// The style of the [`PropertyGrid`].
+    style: <Theme as StyleSheet>::Style,
+    #[allow(clippy::missing_docs_in_private_items)]
+    renderer: std::marker::PhantomData<Renderer>,
+}
+
+/// The state of a [`PropertyGrid`].
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// The edited texts of the numbers and the colors, by index.
+    drafts: HashMap<usize, String>,
+    /// The collapsed groups.
+    collapsed: HashSet<String>,
+    /// The index of the choice whose options are listed.
+    pub(crate) open: Option<usize>,
+    /// The index of the option under the cursor.
+    pub(crate) highlighted: Option<usize>,
+}
+
+impl<'a, Message, Theme, Renderer> PropertyGrid<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a
+        + StyleSheet
+        + text_input::StyleSheet
+        + checkbox::StyleSheet
+        + iced::widget::text::StyleSheet,
+{
+    /// Creates a new [`PropertyGrid`] editing the `properties`, producing a
+    /// message with the index and the new value of an edited property.
+    pub fn new<F>(properties: &'a [Property], on_change: F) -> Self
+    where
+        F: 'a + Fn(usize, PropertyValue) -> Message,
+    {
+        Self {
+            properties,
+            on_change: Box::new(on_change),
+            search: String::new(),
+            width: Length::Fill,
+            label_width: Length::FillPortion(2),
+            padding: settings::spaced(4.0),
+            text_size: settings::scaled(14.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+            renderer: std::marker::PhantomData,
+        }
+    }
+
+    /// Shows only the properties whose label contains `query`,
+    /// case-insensitively.
+    #[must_use]
+    pub fn search(mut self, query: impl Into<String>) -> Self {
+        self.search = query.into();
+        self
+    }
+
+    /// Sets the width of the [`PropertyGrid`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the width of the column of the labels. The editors fill
+    /// `Length::FillPortion(3)`.
+    #[must_use]
+    pub fn label_width(mut self, label_width: impl Into<Length>) -> Self {
+        self.label_width = label_width.into();
+        self
+    }
+
+    /// Sets the padding of the rows of the [`PropertyGrid`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`PropertyGrid`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`PropertyGrid`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`PropertyGrid`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the height of the fields of the editors.
+    fn field_height(&self) -> f32 {
+        LineHeight::default().to_absolute(Pixels(self.text_size)).0 + FIELD_PADDING * 2.0
+    }
+
+    /// Returns the text drawn by the [`PropertyGrid`] in `bounds`.
+    fn text<'c>(&self, content: &'c str, bounds: Size, font: Font) -> Text<'c, Font> {
+        Text {
+            content,
+            bounds,
+            size: Pixels(self.text_size),
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+
+    /// Builds the rows of the shown properties, leaving space for the
+    /// headers, the labels, the drop down lists and the color swatches drawn
+    /// by the [`PropertyGrid`].
+    fn content(&self, state: &State) -> Element<'a, Action, Theme, Renderer> {
+        let field_height = self.field_height();
+
+        let text_input = |value: &str| {
+            TextInput::new("", value)
+                .size(self.text_size)
+                .font(self.font)
+                .padding(FIELD_PADDING)
+                .width(Length::Fill)
+        };
+
+        let rows = items(self.properties, &self.search, &state.collapsed)
+            .into_iter()
+            .map(|item| -> Element<'a, Action, Theme, Renderer> {
+                let Item::Property(index) = item else {
+                    return Space::new(Length::Fill, field_height + self.padding * 2.0).into();
+                };
+
+                let value = &self.properties[index].value;
+                let draft = || {
+                    state
+                        .drafts
+                        .get(&index)
+                        .cloned()
+                        .or_else(|| value.to_text())
+                        .unwrap_or_default()
+                };
+
+                let editor: Element<'a, Action, Theme, Renderer> = match value {
+                    PropertyValue::Text(text) => text_input(text)
+                        .on_input(move |text| Action::Edit(index, PropertyValue::Text(text)))
+                        .into(),
+                    PropertyValue::Number(_) => text_input(&draft())
+                        .on_input(move |text| Action::Draft(index, text))
+                        .into(),
+                    PropertyValue::Bool(checked) => Checkbox::new("", *checked)
+                        .size(self.text_size)
+                        .on_toggle(move |checked| Action::Edit(index, PropertyValue::Bool(checked)))
+                        .into(),
+                    PropertyValue::Color(_) => Row::new()
+                        .spacing(self.padding)
+                        .align_items(Alignment::Center)
+                        .push(Space::new(field_height, field_height))
+                        .push(text_input(&draft()).on_input(move |text| Action::Draft(index, text)))
+                        .into(),
+                    PropertyValue::Choice { .. } => Space::new(Length::Fill, field_height).into(),
+                };
+
+                Row::new()
+                    .padding(self.padding)
+                    .spacing(self.padding)
+                    .align_items(Alignment::Center)
+                    .push(Space::new(self.label_width, field_height))
+                    .push(
+                        Row::new()
+                            .width(Length::FillPortion(3))
+                            .align_items(Alignment::Center)
+                            .push(editor),
+                    )
+                    .into()
+            });
+
+        Column::with_children(rows).width(self.width).into()
+    }
+
+    /// Returns the bounds of the drop down list of the choice or of the
+    /// swatch of the color at `index`.
+    fn field_bounds(&self, state: &State, layout: Layout<'_>, index: usize) -> Option<Rectangle> {
+        let content = layout.children().next()?;
+
+        items(self.properties, &self.search, &state.collapsed)
+            .into_iter()
+            .zip(content.children())
+            .find(|(item, _)| *item == Item::Property(index))
+            .and_then(|(_, row)| row.children().nth(1))
+            .and_then(|editor| editor.children().next())
+            .and_then(|editor| editor.children().next().or(Some(editor)))
+            .map(|field| field.bounds())
+    }
+
+    /// Applies the `action` of an editor, publishing the new value of its
+    /// property.
+    fn apply(&self, state: &mut State, action: Action, shell: &mut Shell<'_, Message>) {
+        match action {
+            Action::Edit(index, value) => shell.publish((self.on_change)(index, value)),
+            Action::Draft(index, text) => {
+                let current = &self.properties[index].value;
+                if let Some(value) = current.parse(&text) {
+                    if value != *current {
+                        shell.publish((self.on_change)(index, value));
+                    }
+                }
+                let _ = state.drafts.insert(index, text);
+                shell.invalidate_layout();
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for PropertyGrid<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a
+        + StyleSheet
+        + text_input::StyleSheet
+        + checkbox::StyleSheet
+        + iced::widget::text::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(self.content(&State::default()))]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let properties = self.properties;
+
+        // The drafts whose value changed elsewhere are dropped.
+        state.drafts.retain(|&index, draft| {
+            properties.get(index).is_some_and(|property| {
+                property.value.to_text().is_some()
+                    && property
+                        .value
+                        .parse(draft)
+                        .map_or(true, |value| value == property.value)
+            })
+        });
+        if !state.open.is_some_and(|index| {
+            matches!(
+                properties.get(index).map(|property| &property.value),
+                Some(PropertyValue::Choice { .. })
+            )
+        }) {
+            state.open = None;
+        }
+
+        let content = self.content(tree.state.downcast_ref::<State>());
+        tree.diff_children(&[&content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self.content(tree.state.downcast_ref::<State>());
+        let content = content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(content_layout) = layout.children().next() else {
+            return event::Status::Ignored;
+        };
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let state = tree.state.downcast_mut::<State>();
+            let pressed = items(self.properties, &self.search, &state.collapsed)
+                .into_iter()
+                .zip(content_layout.children())
+                .find(|(_, row)| cursor.is_over(row.bounds()))
+                .map(|(item, _)| match item {
+                    Item::Group(group, _) => Err(group.to_owned()),
+                    Item::Property(index) => Ok(index),
+                });
+
+            match pressed {
+                Some(Err(group)) => {
+                    if !state.collapsed.remove(&group) {
+                        let _ = state.collapsed.insert(group);
+                    }
+                    shell.invalidate_widgets();
+                    return event::Status::Captured;
+                }
+                Some(Ok(index)) => {
+                    if let PropertyValue::Choice { selected, .. } = self.properties[index].value {
+                        if self
+                            .field_bounds(state, layout, index)
+                            .is_some_and(|field| cursor.is_over(field))
+                        {
+                            state.open = Some(index);
+                            state.highlighted = Some(selected);
+                            shell.invalidate_layout();
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                None => {}
+            }
+        }
+
+        let mut actions = Vec::new();
+        let mut local_shell = Shell::new(&mut actions);
+        let status = self
+            .content(tree.state.downcast_ref::<State>())
+            .as_widget_mut()
+            .on_event(
+                &mut tree.children[0],
+                event,
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut local_shell,
+                viewport,
+            );
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if local_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        for action in actions {
+            self.apply(state, action, shell);
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let Some(content_layout) = layout.children().next() else {
+            return mouse::Interaction::default();
+        };
+        let state = tree.state.downcast_ref::<State>();
+
+        let over_field = items(self.properties, &self.search, &state.collapsed)
+            .into_iter()
+            .zip(content_layout.children())
+            .any(|(item, row)| match item {
+                Item::Group(..) => cursor.is_over(row.bounds()),
+                Item::Property(index) => {
+                    matches!(self.properties[index].value, PropertyValue::Choice { .. })
+                        && self
+                            .field_bounds(state, layout, index)
+                            .is_some_and(|field| cursor.is_over(field))
+                }
+            });
+        if over_field {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.content(state).as_widget().mouse_interaction(
+            &tree.children[0],
+            content_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let Some(content_layout) = layout.children().next() else {
+            return;
+        };
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = StyleSheet::active(theme, &self.style);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        let field_quad = |bounds| Quad {
+            bounds,
+            border: Border {
+                radius: appearance.field_border_radius.into(),
+                width: 1.0,
+                color: appearance.field_border_color,
+            },
+            shadow: Shadow::default(),
+        };
+
+        for (item, row) in items(self.properties, &self.search, &state.collapsed)
+            .into_iter()
+            .zip(content_layout.children())
+        {
+            let row_bounds = row.bounds();
+
+            let index = match item {
+                Item::Group(group, collapsed) => {
+                    renderer.fill_quad(
+                        Quad {
+                            bounds: row_bounds,
+                            border: Border::default(),
+                            shadow: Shadow::default(),
+                        },
+                        appearance.header_background,
+                    );
+
+                    let icon = icon_to_string(if collapsed {
+                        Bootstrap::ChevronRight
+                    } else {
+                        Bootstrap::ChevronDown
+                    });
+                    let x = row_bounds.x + self.padding;
+                    renderer.fill_text(
+                        self.text(&icon, row_bounds.size(), BOOTSTRAP_FONT),
+                        Point::new(x, row_bounds.center_y()),
+                        appearance.header_text_color,
+                        row_bounds,
+                    );
+                    renderer.fill_text(
+                        self.text(group, row_bounds.size(), self.font),
+                        Point::new(x + self.text_size * 1.5, row_bounds.center_y()),
+                        appearance.header_text_color,
+                        row_bounds,
+                    );
+                    continue;
+                }
+                Item::Property(index) => index,
+            };
+            let property = &self.properties[index];
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle {
+                        y: row_bounds.y + row_bounds.height - 1.0,
+                        height: 1.0,
+                        ..row_bounds
+                    },
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                appearance.separator_color,
+            );
+
+            if let Some(label) = row.children().next() {
+                let label_bounds = label.bounds();
+                renderer.fill_text(
+                    self.text(&property.label, label_bounds.size(), self.font),
+                    Point::new(label_bounds.x, label_bounds.center_y()),
+                    appearance.label_color,
+                    label_bounds,
+                );
+            }
+
+            let Some(field) = self.field_bounds(state, layout, index) else {
+                continue;
+            };
+            match &property.value {
+                PropertyValue::Color(color) => {
+                    renderer.fill_quad(field_quad(field), *color);
+                }
+                PropertyValue::Choice { options, selected } => {
+                    renderer.fill_quad(field_quad(field), appearance.field_background);
+
+                    let inner = Rectangle {
+                        x: field.x + FIELD_PADDING * 2.0,
+                        width: (field.width - FIELD_PADDING * 4.0 - self.text_size).max(0.0),
+                        ..field
+                    };
+                    if let Some(option) = options.get(*selected) {
+                        renderer.fill_text(
+                            self.text(option, inner.size(), self.font),
+                            Point::new(inner.x, inner.center_y()),
+                            appearance.text_color,
+                            inner,
+                        );
+                    }
+
+                    let chevron = icon_to_string(Bootstrap::ChevronDown);
+                    renderer.fill_text(
+                        self.text(&chevron, field.size(), BOOTSTRAP_FONT),
+                        Point::new(
+                            field.x + field.width - FIELD_PADDING * 2.0 - self.text_size,
+                            field.center_y(),
+                        ),
+                        appearance.text_color,
+                        field,
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        self.content(state).as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let index = state.open?;
+
+        let PropertyValue::Choice { options, .. } = &self.properties.get(index)?.value else {
+            return None;
+        };
+        let field = self.field_bounds(state, layout, index)? + translation;
+
+        Some(overlay::Element::new(Box::new(PropertyGridOverlay::new(
+            state,
+            index,
+            options,
+            &*self.on_change,
+            field,
+            self.text_size,
+            self.font,
+            &self.style,
+        ))))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<PropertyGrid<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a
+        + StyleSheet
+        + text_input::StyleSheet
+        + checkbox::StyleSheet
+        + iced::widget::text::StyleSheet,
+{
+    fn from(property_grid: PropertyGrid<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(property_grid)
+    }
+}
@@ -0,0 +1,481 @@
+//! Select an interval of values with two handles.
+//!
+//! *This API requires the following crate features to be activated: `range_slider`*
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event, keyboard,
+    mouse::{self, Cursor},
+    touch, Border, Color, Element, Event, Length, Point, Rectangle, Shadow, Size,
+};
+use std::ops::RangeInclusive;
+
+pub use crate::style::range_slider::{Appearance, StyleSheet};
+
+/// The default height of a [`RangeSlider`].
+const DEFAULT_HEIGHT: f32 = 22.0;
+/// The number of steps moved by the Page Up and Page Down keys.
+const PAGE_STEPS: f64 = 10.0;
+
+/// A handle of a [`RangeSlider`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Handle {
+    /// The handle of the low value.
+    Low,
+    /// The handle of the high value.
+    High,
+}
+
+/// A slider selecting an interval of values with two handles.
+///
+/// The rail between the handles is highlighted. A pressed handle can be
+/// moved with the arrow keys, as well as with Page Up, Page Down, Home and End.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::range_slider::RangeSlider;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PriceChanged((f32, f32)),
+/// }
+///
+/// let slider = RangeSlider::new(0.0..=100.0, (20.0, 80.0), Message::PriceChanged).step(5.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, T, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The range of the values.
+    range: RangeInclusive<T>,
+    /// The low and high values.
+    values: (T, T),
+    /// The step the values snap to.
+    step: T,
+    /// The function producing the message when the values change.
+    on_change: Box<dyn Fn((T, T)) -> Message + 'a>,
+    /// The optional message produced when a handle is released.
+    on_release: Option<Message>,
+    width: Length,
+    height: f32,
+    /// The style of the [`RangeSlider`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`RangeSlider`].
+#[derive(Debug, Default)]
+struct State {
+    /// The handle being dragged.
+    dragging: Option<Handle>,
+    /// The handle moved by the keyboard.
+    focused: Option<Handle>,
+}
+
+impl<'a, T, Message, Theme> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the low and high values of the selected interval
+    ///   * a function that will be called when a handle is moved. It receives
+    ///     the new low and high values and must produce a `Message`.
+    pub fn new<F>(range: RangeInclusive<T>, values: (T, T), on_change: F) -> Self
+    where
+        F: 'a + Fn((T, T)) -> Message,
+    {
+        let clamp = |value: T| {
+            if value < *range.start() {
+                *range.start()
+            } else if value > *range.end() {
+                *range.end()
+            } else {
+                value
+            }
+        };
+        let (low, high) = (clamp(values.0), clamp(values.1));
+
+        Self {
+            values: if low <= high {
+                (low, high)
+            } else {
+                (high, low)
+            },
+            range,
+            step: T::from(1),
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: DEFAULT_HEIGHT,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the step the values snap to.
+    #[must_use]
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the message produced when a handle is released.
+    #[must_use]
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`].
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<T, Message, Theme> RangeSlider<'_, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Theme: StyleSheet,
+{
+    /// Returns the start and the end of the range.
+    fn bounds_of_range(&self) -> (f64, f64) {
+        ((*self.range.start()).into(), (*self.range.end()).into())
+    }
+
+    /// Returns the value of the `handle`.
+    fn value(&self, handle: Handle) -> f64 {
+        match handle {
+            Handle::Low => self.values.0.into(),
+            Handle::High => self.values.1.into(),
+        }
+    }
+
+    /// Returns the horizontal position of `value` on the rail of the given bounds,
+    /// inset by the radius of the handles.
+    fn position(&self, bounds: Rectangle, radius: f32, value: f64) -> f32 {
+        let (start, end) = self.bounds_of_range();
+        let rail = (bounds.width - radius * 2.0).max(0.0);
+        let ratio = if end > start {
+            ((value - start) / (end - start)) as f32
+        } else {
+            0.0
+        };
+
+        bounds.x + radius + rail * ratio
+    }
+
+    /// Returns the value at the horizontal position `x` of the rail, snapped to the step.
+    fn value_at(&self, bounds: Rectangle, radius: f32, x: f32) -> f64 {
+        let (start, end) = self.bounds_of_range();
+        let rail = (bounds.width - radius * 2.0).max(1.0);
+        let ratio = f64::from(((x - bounds.x - radius) / rail).clamp(0.0, 1.0));
+
+        self.snap(start + ratio * (end - start))
+    }
+
+    /// Snaps `value` to the step and to the range.
+    fn snap(&self, value: f64) -> f64 {
+        let (start, end) = self.bounds_of_range();
+        let step = self.step.into();
+
+        let snapped = if step > 0.0 {
+            ((value - start) / step).round() * step + start
+        } else {
+            value
+        };
+
+        snapped.clamp(start, end)
+    }
+
+    /// Moves the `handle` to `value`, keeping the handles in order, and
+    /// publishes the new values if they changed.
+    fn set(&mut self, handle: Handle, value: f64, shell: &mut Shell<'_, Message>) {
+        let value = match handle {
+            Handle::Low => value.min(self.value(Handle::High)),
+            Handle::High => value.max(self.value(Handle::Low)),
+        };
+
+        if (value - self.value(handle)).abs() <= f64::EPSILON {
+            return;
+        }
+
+        let Some(value) = T::from_f64(value) else {
+            return;
+        };
+
+        match handle {
+            Handle::Low => self.values.0 = value,
+            Handle::High => self.values.1 = value,
+        }
+
+        shell.publish((self.on_change)(self.values));
+    }
+
+    /// Returns the handle closest to the horizontal position `x`.
+    fn closest(&self, bounds: Rectangle, radius: f32, x: f32) -> Handle {
+        let low = self.position(bounds, radius, self.value(Handle::Low));
+        let high = self.position(bounds, radius, self.value(Handle::High));
+
+        // When the handles overlap, the one to move is the one the cursor is
+        // moving towards.
+        if (x - low).abs() < (x - high).abs() || ((high - low).abs() < f32::EPSILON && x < low) {
+            Handle::Low
+        } else {
+            Handle::High
+        }
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RangeSlider<'_, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Fixed(self.height))
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let height = Length::Fixed(self.height);
+
+        Node::new(
+            limits
+                .width(self.width)
+                .height(height)
+                .resolve(self.width, height, Size::ZERO),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        // The handles are inset by their default radius, the style being
+        // unknown here.
+        let radius = Appearance::default().handle_radius;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    let handle = self.closest(bounds, radius, position.x);
+                    let value = self.value_at(bounds, radius, position.x);
+
+                    state.dragging = Some(handle);
+                    state.focused = Some(handle);
+
+                    self.set(handle, value, shell);
+                    return event::Status::Captured;
+                }
+
+                state.focused = None;
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(handle) = state.dragging {
+                    let value = self.value_at(bounds, radius, position.x);
+                    self.set(handle, value, shell);
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.dragging.is_some() =>
+            {
+                state.dragging = None;
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+                return event::Status::Captured;
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) => {
+                if let Some(handle) = state.focused {
+                    let (start, end) = self.bounds_of_range();
+                    let step = self.step.into();
+                    let value = self.value(handle);
+
+                    let target = match key {
+                        keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown => {
+                            value - step
+                        }
+                        keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp => {
+                            value + step
+                        }
+                        keyboard::key::Named::PageDown => value - step * PAGE_STEPS,
+                        keyboard::key::Named::PageUp => value + step * PAGE_STEPS,
+                        keyboard::key::Named::Home => start,
+                        keyboard::key::Named::End => end,
+                        _ => return event::Status::Ignored,
+                    };
+
+                    let target = self.snap(target);
+                    self.set(handle, target, shell);
+                    return event::Status::Captured;
+                }
+            }
+
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State>().dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = if state.dragging.is_some() {
+            theme.dragging(&self.style)
+        } else if cursor.is_over(bounds) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+        let radius = Appearance::default().handle_radius;
+        let low = self.position(bounds, radius, self.value(Handle::Low));
+        let high = self.position(bounds, radius, self.value(Handle::High));
+        let rail_y = bounds.center_y() - appearance.rail_width / 2.0;
+        let rail = |x: f32, width: f32| Quad {
+            bounds: Rectangle::new(
+                Point::new(x, rail_y),
+                Size::new(width, appearance.rail_width),
+            ),
+            border: Border::with_radius(appearance.rail_width / 2.0),
+            shadow: Shadow::default(),
+        };
+
+        renderer.fill_quad(
+            rail(bounds.x + radius, (bounds.width - radius * 2.0).max(0.0)),
+            appearance.rail_color,
+        );
+        renderer.fill_quad(rail(low, high - low), appearance.range_color);
+
+        for (handle, x) in [(Handle::Low, low), (Handle::High, high)] {
+            let handle_radius = appearance.handle_radius;
+
+            if state.focused == Some(handle) {
+                let ring = handle_radius + 3.0;
+                renderer.fill_quad(
+                    Quad {
+                        bounds: Rectangle::new(
+                            Point::new(x - ring, bounds.center_y() - ring),
+                            Size::new(ring * 2.0, ring * 2.0),
+                        ),
+                        border: Border::with_radius(ring),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.focus_color,
+                );
+            }
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle::new(
+                        Point::new(x - handle_radius, bounds.center_y() - handle_radius),
+                        Size::new(handle_radius * 2.0, handle_radius * 2.0),
+                    ),
+                    border: Border {
+                        radius: handle_radius.into(),
+                        width: appearance.handle_border_width,
+                        color: appearance.handle_border_color,
+                    },
+                    shadow: Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.15),
+                        offset: iced::Vector::new(0.0, 1.0),
+                        blur_radius: 2.0,
+                    },
+                },
+                appearance.handle_color,
+            );
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<RangeSlider<'a, T, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(slider: RangeSlider<'a, T, Message, Theme>) -> Self {
+        Element::new(slider)
+    }
+}
@@ -0,0 +1,655 @@
+//! Type a search query and pick one of the suggested results.
+//!
+//! *This API requires the following crate features to be activated: `search_bar`*
+
+use crate::core::{
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    settings,
+};
+use crate::widgets::overlay::SearchBarOverlay;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay, renderer,
+        text::{self, Text},
+        widget::{
+            self,
+            tree::{self, Tag, Tree},
+            Operation,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    keyboard::{self, key::Named},
+    mouse::{self, Cursor},
+    touch,
+    widget::{
+        text::{LineHeight, Shaping},
+        text_input::{self, TextInput},
+    },
+    window, Element, Event, Font, Length, Padding, Pixels, Rectangle, Size, Vector,
+};
+use std::time::{Duration, Instant};
+
+pub use crate::style::search_bar::{Appearance, StyleSheet};
+
+/// The default padding of a [`SearchBar`].
+const DEFAULT_PADDING: f32 = 5.0;
+/// The default time a [`SearchBar`] waits after the last keystroke.
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+/// The largest number of suggestions shown at once.
+const MAX_SUGGESTIONS: usize = 8;
+
+/// The function producing a message from a query or a suggestion.
+type OnText<'a, Message> = Box<dyn Fn(String) -> Message + 'a>;
+
+/// A text input for search queries, with a search icon, a clear button and a
+/// list of suggestions.
+///
+/// The typed query is sent once no key is pressed for the debounce time, or
+/// right away with Enter. The suggestions, given by the application for the
+/// last query, are listed below the field while it is focused. They are
+/// picked with the cursor, or with the arrow keys and Enter, and Escape hides
+/// them until the query changes.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::search_bar::SearchBar;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Search(String),
+///     Open(String),
+/// }
+///
+/// let query = String::from("ice");
+///
+/// let search_bar = SearchBar::new("Search crates", &query, Message::Search)
+///     .on_select(Message::Open)
+///     .suggestions(vec![String::from("iced"), String::from("iced_aw")]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SearchBar<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// The text shown while nothing is typed.
+    placeholder: String,
+    /// The query given by the application.
+    value: String,
+    /// The function producing the message when the query changes.
+    on_input: OnText<'a, Message>,
+    /// The function producing the message when a suggestion is picked.
+    on_select: Option<OnText<'a, Message>>,
+    /// The suggestions for the query.
+    suggestions: Vec<String>,
+    /// The time waited after the last keystroke before sending the query.
+    debounce: Duration,
+    /// The id of the text input of the [`SearchBar`].
+    id: Option<text_input::Id>,
+    width: Length,
+    padding: f32,
+    text_size: f32,
+    font: Font,
+    /// The style of the [`SearchBar`].
+    style: <Theme as StyleSheet>::Style,
+    #[allow(clippy::missing_docs_in_private_items)]
+    renderer: std::marker::PhantomData<Renderer>,
+}
+
+/// The state of a [`SearchBar`].
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// The typed query.
+    pub(crate) query: String,
+    /// The query given by the application at the last update.
+    value: String,
+    /// When the typed query is sent, if it was not sent yet.
+    deadline: Option<Instant>,
+    /// The suggestion selected with the arrow keys or the cursor.
+    pub(crate) highlighted: Option<usize>,
+    /// Whether the suggestions are hidden until the query changes.
+    pub(crate) dismissed: bool,
+    /// Whether the text input is focused again after a suggestion is picked.
+    pub(crate) refocus: bool,
+}
+
+impl State {
+    /// Replaces the query with the picked `suggestion` and hides the suggestions.
+    pub(crate) fn pick(&mut self, suggestion: &str) {
+        suggestion.clone_into(&mut self.query);
+        self.deadline = None;
+        self.highlighted = None;
+        self.dismissed = true;
+        self.refocus = true;
+    }
+}
+
+impl<'a, Message, Theme, Renderer> SearchBar<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet + text_input::StyleSheet,
+{
+    /// Creates a new [`SearchBar`].
+    ///
+    /// It expects:
+    ///   * the placeholder shown while nothing is typed
+    ///   * the current query
+    ///   * a function that will be called with the query once typing pauses
+    ///     and must produce a `Message`.
+    pub fn new<F>(placeholder: &str, value: &str, on_input: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        Self {
+            placeholder: placeholder.to_owned(),
+            value: value.to_owned(),
+            on_input: Box::new(on_input),
+            on_select: None,
+            suggestions: Vec::new(),
+            debounce: DEFAULT_DEBOUNCE,
+            id: None,
+            width: Length::Fill,
+            padding: settings::spaced(DEFAULT_PADDING),
+            text_size: settings::scaled(16.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+            renderer: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the function producing the message when a suggestion is picked.
+    ///
+    /// Without it, picking a suggestion sends it as the query.
+    #[must_use]
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: 'a + Fn(String) -> Message,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the suggestions listed below the [`SearchBar`].
+    #[must_use]
+    pub fn suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Sets the time the [`SearchBar`] waits after the last keystroke before
+    /// sending the query. A zero duration sends each keystroke.
+    #[must_use]
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Sets the [`Id`](text_input::Id) of the text input of the [`SearchBar`],
+    /// to focus it with [`text_input::focus`].
+    #[must_use]
+    pub fn id(mut self, id: text_input::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`SearchBar`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the [`SearchBar`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`SearchBar`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`SearchBar`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`SearchBar`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the text input editing `query`, leaving room for the icons.
+    fn input<'b>(&'b self, query: &'b str) -> TextInput<'b, String, Theme, Renderer> {
+        let icon_space = self.padding + self.text_size;
+        let input = TextInput::new(&self.placeholder, query)
+            .on_input(|query| query)
+            .width(Length::Fill)
+            .padding(Padding {
+                top: self.padding,
+                right: self.padding + icon_space,
+                bottom: self.padding,
+                left: self.padding + icon_space,
+            })
+            .size(self.text_size)
+            .font(self.font);
+
+        match &self.id {
+            Some(id) => input.id(id.clone()),
+            None => input,
+        }
+    }
+
+    /// Returns the bounds of the search icon of a field with the given bounds.
+    fn search_icon(&self, field: Rectangle) -> Rectangle {
+        Rectangle {
+            x: field.x + self.padding,
+            width: self.text_size,
+            ..field
+        }
+    }
+
+    /// Returns the bounds of the clear button of a field with the given bounds.
+    fn clear_button(&self, field: Rectangle) -> Rectangle {
+        let width = self.padding * 2.0 + self.text_size;
+
+        Rectangle {
+            x: field.x + field.width - width,
+            width,
+            ..field
+        }
+    }
+
+    /// Returns the text of `content` centered in `bounds`.
+    fn icon(content: &str, bounds: Rectangle, size: f32) -> Text<'_, Font> {
+        Text {
+            content,
+            bounds: bounds.size(),
+            size: Pixels(size),
+            line_height: LineHeight::default(),
+            font: BOOTSTRAP_FONT,
+            horizontal_alignment: Horizontal::Center,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Basic,
+        }
+    }
+
+    /// Returns the shown suggestions.
+    fn shown(&self) -> &[String] {
+        &self.suggestions[..self.suggestions.len().min(MAX_SUGGESTIONS)]
+    }
+
+    /// Sends `query` as the new query.
+    fn send(&self, state: &mut State, shell: &mut Shell<'_, Message>) {
+        state.deadline = None;
+        shell.publish((self.on_input)(state.query.clone()));
+    }
+
+    /// Sends the picked `suggestion`.
+    fn select(&self, suggestion: String, shell: &mut Shell<'_, Message>) {
+        shell.publish(self.on_select.as_ref().unwrap_or(&self.on_input)(
+            suggestion,
+        ));
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for SearchBar<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet + text_input::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            query: self.value.clone(),
+            value: self.value.clone(),
+            ..State::default()
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.input(&self.value) as &dyn Widget<String, Theme, Renderer>
+        )]
+    }
+
+    // A query changed by the application replaces the typed one.
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.value != self.value {
+            state.value.clone_from(&self.value);
+            state.query.clone_from(&self.value);
+            state.deadline = None;
+        }
+
+        tree.children[0].diff(&self.input(&state.query) as &dyn Widget<String, Theme, Renderer>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_ref::<State>();
+        let input = Widget::<String, Theme, Renderer>::layout(
+            &self.input(&state.query),
+            &mut tree.children[0],
+            renderer,
+            &limits.width(self.width),
+        );
+
+        Node::with_children(input.size(), vec![input])
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let Some(input_layout) = layout.children().next() else {
+            return event::Status::Ignored;
+        };
+        let focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            match state.deadline {
+                Some(deadline) if now >= deadline => self.send(state, shell),
+                Some(deadline) => shell.request_redraw(window::RedrawRequest::At(deadline)),
+                None => {}
+            }
+        }
+
+        match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !state.query.is_empty() && cursor.is_over(self.clear_button(bounds)) =>
+            {
+                state.query.clear();
+                state.highlighted = None;
+                state.dismissed = false;
+                self.send(state, shell);
+                shell.invalidate_layout();
+
+                let input = tree.children[0]
+                    .state
+                    .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+                input.focus();
+                input.move_cursor_to_front();
+
+                return event::Status::Captured;
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) if focused => {
+                let count = if state.dismissed {
+                    0
+                } else {
+                    self.shown().len()
+                };
+
+                match named {
+                    Named::ArrowDown | Named::ArrowUp if count > 0 => {
+                        state.highlighted = Some(match (state.highlighted, named) {
+                            (None, Named::ArrowDown) => 0,
+                            (None, _) => count - 1,
+                            (Some(index), Named::ArrowDown) => (index + 1) % count,
+                            (Some(index), _) => (index + count - 1) % count,
+                        });
+                        return event::Status::Captured;
+                    }
+                    Named::Enter => {
+                        if let Some(suggestion) = state
+                            .highlighted
+                            .filter(|_| count > 0)
+                            .and_then(|index| self.shown().get(index).cloned())
+                        {
+                            state.pick(&suggestion);
+                            self.select(suggestion, shell);
+                            shell.invalidate_layout();
+                        } else if state.deadline.is_some() {
+                            self.send(state, shell);
+                        }
+                        return event::Status::Captured;
+                    }
+                    Named::Escape if count > 0 => {
+                        state.dismissed = true;
+                        state.highlighted = None;
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+
+            _ => {}
+        }
+
+        let mut messages = Vec::new();
+        let mut input_shell = Shell::new(&mut messages);
+        let status = Widget::<String, Theme, Renderer>::on_event(
+            &mut self.input(&state.query),
+            &mut tree.children[0],
+            event,
+            input_layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut input_shell,
+            viewport,
+        );
+
+        if input_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if input_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+        if let Some(request) = input_shell.redraw_request() {
+            shell.request_redraw(request);
+        }
+
+        if let Some(query) = messages.pop() {
+            state.query = query;
+            state.highlighted = None;
+            state.dismissed = false;
+            shell.invalidate_layout();
+
+            if self.debounce.is_zero() {
+                self.send(state, shell);
+            } else {
+                let deadline = Instant::now() + self.debounce;
+                state.deadline = Some(deadline);
+                shell.request_redraw(window::RedrawRequest::At(deadline));
+            }
+        }
+
+        // A suggestion picked with the cursor unfocuses the text input.
+        if std::mem::take(&mut state.refocus) {
+            let input = tree.children[0]
+                .state
+                .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+            input.focus();
+            input.move_cursor_to_end();
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if !state.query.is_empty() && cursor.is_over(self.clear_button(layout.bounds())) {
+            return mouse::Interaction::Pointer;
+        }
+
+        layout
+            .children()
+            .next()
+            .map_or_else(mouse::Interaction::default, |input_layout| {
+                Widget::<String, Theme, Renderer>::mouse_interaction(
+                    &self.input(&state.query),
+                    &tree.children[0],
+                    input_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let Some(input_layout) = layout.children().next() else {
+            return;
+        };
+
+        Widget::<String, Theme, Renderer>::draw(
+            &self.input(&state.query),
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            input_layout,
+            cursor,
+            viewport,
+        );
+
+        let appearance = if tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused()
+        {
+            <Theme as StyleSheet>::focused(theme, &self.style)
+        } else {
+            <Theme as StyleSheet>::active(theme, &self.style)
+        };
+
+        let search = self.search_icon(bounds);
+        renderer.fill_text(
+            Self::icon(&icon_to_string(Bootstrap::Search), search, self.text_size),
+            search.center(),
+            appearance.icon_color,
+            search,
+        );
+
+        if !state.query.is_empty() {
+            let clear = self.clear_button(bounds);
+            renderer.fill_text(
+                Self::icon(&icon_to_string(Bootstrap::X), clear, self.text_size),
+                clear.center(),
+                if cursor.is_over(clear) {
+                    appearance.hovered_icon_color
+                } else {
+                    appearance.icon_color
+                },
+                clear,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let id = self.id.clone().map(widget::Id::from);
+        let input = tree.children[0]
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        operation.focusable(input, id.as_ref());
+        operation.text_input(input, id.as_ref());
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+        let state = tree.state.downcast_mut::<State>();
+
+        if !focused || state.dismissed || self.suggestions.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+        Some(overlay::Element::new(Box::new(SearchBarOverlay::new(
+            state,
+            self.shown(),
+            &**self.on_select.as_ref().unwrap_or(&self.on_input),
+            bounds.position() + translation,
+            bounds.size(),
+            self.text_size,
+            self.font,
+            &self.style,
+        ))))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<SearchBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet + text_input::StyleSheet,
+{
+    fn from(search_bar: SearchBar<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(search_bar)
+    }
+}
@@ -1,15 +1,24 @@
 //! Create choices using `segnmented_button` buttons.
+use crate::core::{
+    icons::{IconHandle, IconRenderer, BOOTSTRAP_FONT},
+    settings,
+};
+
 use iced::{
     advanced::{
         layout::{Limits, Node},
         renderer,
+        text::{Paragraph, Text},
         widget::Tree,
         Clipboard, Layout, Shell, Widget,
     },
+    alignment::{Horizontal, Vertical},
     event,
     mouse::{self, Cursor},
-    touch, Alignment, Background, Border, Color, Element, Event, Length, Padding, Point, Rectangle,
-    Shadow, Size,
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Alignment, Background, Border, Color, Element, Event, Font, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Size,
 };
 
 pub use crate::style::segmented_button::StyleSheet;
@@ -116,8 +125,8 @@ where
 
     /// Sets the style of the [`SegmentedButton`](SegmentedButton).
     #[must_use]
-    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
-        self.style = style;
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
         self
     }
 }
@@ -300,3 +309,496 @@ where
         Self::new(segmented_button)
     }
 }
+
+/// A segment of [`SegmentedButtons`], showing an icon and/or a label.
+#[derive(Clone, Debug)]
+pub struct Segment<V> {
+    /// The value selected by the segment.
+    value: V,
+    /// The icon of the segment.
+    icon: Option<IconHandle>,
+    /// The label of the segment.
+    label: Option<String>,
+}
+
+impl<V> Segment<V> {
+    /// Creates a new [`Segment`] selecting the given value.
+    pub fn new(value: V) -> Self {
+        Self {
+            value,
+            icon: None,
+            label: None,
+        }
+    }
+
+    /// Sets the icon of the [`Segment`], a glyph drawn with the
+    /// [`icon_font`](SegmentedButtons::icon_font) of the [`SegmentedButtons`]
+    /// unless it has its own, or an SVG.
+    #[must_use]
+    pub fn icon(mut self, icon: impl Into<IconHandle>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the label of the [`Segment`].
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// How the segments of [`SegmentedButtons`] are selected.
+enum Selection<'a, V, Message> {
+    /// A single segment is selected at a time.
+    Single(Box<dyn Fn(V) -> Message + 'a>),
+    /// Any segments are selected at the same time.
+    Multiple(Box<dyn Fn(Vec<V>) -> Message + 'a>),
+}
+
+/// A connected group of segments, each selecting a value, shown with rounded
+/// outer corners. It is meant for two to six segments.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::segmented_button::{Segment, SegmentedButtons};
+/// # use iced_aw::Bootstrap;
+/// #
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum View {
+///     List,
+///     Grid,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ViewSelected(View),
+/// }
+///
+/// let buttons = SegmentedButtons::new(
+///     vec![
+///         Segment::new(View::List).icon(Bootstrap::ListUl).label("List"),
+///         Segment::new(View::Grid).icon(Bootstrap::Grid).label("Grid"),
+///     ],
+///     Some(View::List),
+///     Message::ViewSelected,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SegmentedButtons<'a, V, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The segments.
+    segments: Vec<Segment<V>>,
+    /// The values of the selected segments.
+    selected: Vec<V>,
+    /// How the segments are selected.
+    selection: Selection<'a, V, Message>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    /// The space between the icon and the label of a segment.
+    spacing: f32,
+    text_size: f32,
+    font: Font,
+    icon_font: Font,
+    /// The style of the [`SegmentedButtons`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, V, Message, Theme> SegmentedButtons<'a, V, Message, Theme>
+where
+    V: Clone + PartialEq,
+    Theme: StyleSheet,
+{
+    /// Creates new [`SegmentedButtons`] selecting a single segment at a time.
+    ///
+    /// It expects:
+    ///     * the segments of the [`SegmentedButtons`].
+    ///     * the value of the selected segment, if any.
+    ///     * the function producing the message when a segment is pressed.
+    pub fn new<F>(segments: Vec<Segment<V>>, selected: Option<V>, on_select: F) -> Self
+    where
+        F: 'a + Fn(V) -> Message,
+    {
+        Self::with_selection(
+            segments,
+            selected.into_iter().collect(),
+            Selection::Single(Box::new(on_select)),
+        )
+    }
+
+    /// Creates new [`SegmentedButtons`] selecting any segments at the same time.
+    ///
+    /// It expects:
+    ///     * the segments of the [`SegmentedButtons`].
+    ///     * the values of the selected segments.
+    ///     * the function producing the message when a segment is toggled. It
+    ///       receives the values of the selected segments, in the order of the segments.
+    pub fn multiple<F>(segments: Vec<Segment<V>>, selected: &[V], on_change: F) -> Self
+    where
+        F: 'a + Fn(Vec<V>) -> Message,
+    {
+        Self::with_selection(
+            segments,
+            selected.to_vec(),
+            Selection::Multiple(Box::new(on_change)),
+        )
+    }
+
+    /// Creates new [`SegmentedButtons`] with the given [`Selection`].
+    fn with_selection(
+        segments: Vec<Segment<V>>,
+        selected: Vec<V>,
+        selection: Selection<'a, V, Message>,
+    ) -> Self {
+        Self {
+            segments,
+            selected,
+            selection,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            padding: Padding::from([8, 16]),
+            spacing: settings::spaced(8.0),
+            text_size: settings::scaled(14.0),
+            font: settings::font(),
+            icon_font: BOOTSTRAP_FONT,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`SegmentedButtons`], shared equally by the segments.
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`SegmentedButtons`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the padding of the segments of the [`SegmentedButtons`].
+    #[must_use]
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the space between the icon and the label of the segments.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the [`SegmentedButtons`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the labels of the [`SegmentedButtons`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the font of the icons of the [`SegmentedButtons`].
+    #[must_use]
+    pub fn icon_font(mut self, icon_font: impl Into<Font>) -> Self {
+        self.icon_font = icon_font.into();
+        self
+    }
+
+    /// Sets the style of the [`SegmentedButtons`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the text of `content` centered vertically in `bounds`.
+    fn text(content: &str, bounds: Size, font: Font, size: f32) -> Text<'_, Font> {
+        Text {
+            content,
+            bounds,
+            size: Pixels(size),
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+
+    /// Returns the widths of the icon and of the label of `segment`.
+    fn measure<Renderer>(&self, segment: &Segment<V>) -> (f32, f32)
+    where
+        Renderer: IconRenderer,
+    {
+        let width = |content: &str, font| {
+            Renderer::Paragraph::with_text(Self::text(
+                content,
+                Size::INFINITY,
+                font,
+                self.text_size,
+            ))
+            .min_width()
+            .ceil()
+        };
+
+        (
+            segment.icon.as_ref().map_or(0.0, |icon| {
+                icon.measure::<Renderer>(self.text_size, self.icon_font)
+                    .width
+                    .ceil()
+            }),
+            segment
+                .label
+                .as_deref()
+                .map_or(0.0, |label| width(label, self.font)),
+        )
+    }
+}
+
+impl<V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for SegmentedButtons<'_, V, Message, Theme>
+where
+    V: Clone + PartialEq,
+    Renderer: IconRenderer,
+    Theme: StyleSheet,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let content = self
+            .segments
+            .iter()
+            .map(|segment| {
+                let (icon, label) = self.measure::<Renderer>(segment);
+                let spacing = if icon > 0.0 && label > 0.0 {
+                    self.spacing
+                } else {
+                    0.0
+                };
+                icon + spacing + label
+            })
+            .fold(0.0, f32::max);
+        let segment = Size::new(
+            content + self.padding.horizontal(),
+            self.text_size * 1.3 + self.padding.vertical(),
+        );
+
+        let size = limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::new(segment.width * self.segments.len() as f32, segment.height),
+        );
+        let width = size.width / self.segments.len().max(1) as f32;
+
+        Node::with_children(
+            size,
+            (0..self.segments.len())
+                .map(|i| {
+                    Node::new(Size::new(width, size.height))
+                        .move_to(Point::new(width * i as f32, 0.0))
+                })
+                .collect(),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(segment) = self
+                    .segments
+                    .iter()
+                    .zip(layout.children())
+                    .find(|(_, layout)| cursor.is_over(layout.bounds()))
+                    .map(|(segment, _)| segment)
+                else {
+                    return event::Status::Ignored;
+                };
+
+                match &self.selection {
+                    Selection::Single(on_select) => shell.publish(on_select(segment.value.clone())),
+                    Selection::Multiple(on_change) => {
+                        let toggled = !self.selected.contains(&segment.value);
+                        let selected = self
+                            .segments
+                            .iter()
+                            .filter(|other| {
+                                if other.value == segment.value {
+                                    toggled
+                                } else {
+                                    self.selected.contains(&other.value)
+                                }
+                            })
+                            .map(|other| other.value.clone())
+                            .collect();
+                        shell.publish(on_change(selected));
+                    }
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let active = theme.active(&self.style);
+        let radius = active
+            .border_radius
+            .unwrap_or(bounds.height / 2.0)
+            .min(bounds.height / 2.0);
+        let border_color = active.border_color.unwrap_or(Color::BLACK);
+        let last = self.segments.len().saturating_sub(1);
+
+        for (i, (segment, layout)) in self.segments.iter().zip(layout.children()).enumerate() {
+            let bounds = layout.bounds();
+            let appearance = if self.selected.contains(&segment.value) {
+                theme.selected(&self.style)
+            } else if cursor.is_over(bounds) {
+                theme.hovered(&self.style)
+            } else {
+                active
+            };
+
+            // Only the outer corners of the first and last segments are rounded.
+            let left = if i == 0 { radius } else { 0.0 };
+            let right = if i == last { radius } else { 0.0 };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border::with_radius([left, right, right, left]),
+                    shadow: Shadow::default(),
+                },
+                appearance.background,
+            );
+
+            if i > 0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x - active.border_width / 2.0,
+                            width: active.border_width,
+                            ..bounds
+                        },
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                    },
+                    border_color,
+                );
+            }
+
+            let (icon_width, label_width) = self.measure::<Renderer>(segment);
+            let spacing = if icon_width > 0.0 && label_width > 0.0 {
+                self.spacing
+            } else {
+                0.0
+            };
+            let mut x = bounds.center_x() - (icon_width + spacing + label_width) / 2.0;
+
+            if let Some(icon) = &segment.icon {
+                icon.draw(
+                    renderer,
+                    Rectangle::new(
+                        Point::new(x, bounds.y),
+                        Size::new(icon_width, bounds.height),
+                    ),
+                    self.text_size,
+                    self.icon_font,
+                    appearance.text_color,
+                );
+                x += icon_width + spacing;
+            }
+
+            if let Some(label) = &segment.label {
+                renderer.fill_text(
+                    Self::text(
+                        label,
+                        Size::new(label_width, bounds.height),
+                        self.font,
+                        self.text_size,
+                    ),
+                    Point::new(x, bounds.center_y()),
+                    appearance.text_color,
+                    bounds,
+                );
+            }
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius: radius.into(),
+                    width: active.border_width,
+                    color: border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            Color::TRANSPARENT,
+        );
+    }
+}
+
+impl<'a, V, Message, Theme, Renderer> From<SegmentedButtons<'a, V, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    V: 'a + Clone + PartialEq,
+    Message: 'a,
+    Renderer: 'a + IconRenderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(segmented_buttons: SegmentedButtons<'a, V, Message, Theme>) -> Self {
+        Self::new(segmented_buttons)
+    }
+}
@@ -1,5 +1,7 @@
 //! Display a dropdown list of selectable values.
 pub mod list;
+use crate::core::{layout_cache::LayoutCache, settings};
+
 use crate::style::selection_list::StyleSheet;
 
 use iced::{
@@ -8,19 +10,33 @@ use iced::{
         layout::{Limits, Node},
         renderer,
         text::{Paragraph, Text},
-        widget::{tree, Tree},
+        widget::{self, tree, Operation, Tree},
         Clipboard, Layout, Shell, Widget,
     },
     alignment::{Horizontal, Vertical},
     event,
     mouse::{self, Cursor},
-    widget::{container, scrollable, text, text::LineHeight, Container, Scrollable},
-    Border, Element, Event, Font, Length, Pixels, Rectangle, Shadow, Size,
+    widget::{
+        container, scrollable, scrollable::AbsoluteOffset, text, text::LineHeight, text_input,
+        Column, Container, Scrollable, TextInput,
+    },
+    Border, Command, Element, Event, Font, Length, Pixels, Rectangle, Shadow, Size,
+};
+use std::{
+    any::Any,
+    collections::hash_map::DefaultHasher,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
 };
-use std::{fmt::Display, hash::Hash, marker::PhantomData};
 
 pub use list::List;
 
+/// The function building the [`Element`] shown for an option of a
+/// [`SelectionList`], given its index and value.
+pub type ItemView<T, Message, Theme = iced::Theme, Renderer = iced::Renderer> =
+    dyn for<'b> Fn(usize, &'b T) -> Element<'b, Message, Theme, Renderer>;
+
 /// A widget for selecting a single value from a dynamic scrollable list of options.
 #[allow(missing_debug_implementations)]
 #[allow(clippy::type_repetition_in_bounds)]
@@ -31,10 +47,38 @@ where
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
     Theme: StyleSheet + container::StyleSheet,
 {
-    /// Container for Rendering List.
-    container: Container<'a, Message, Theme, Renderer>,
     /// List of Elements to Render.
     options: &'a [T],
+    /// Function Pointer On Select to call on Mouse button press.
+    on_selected: Box<dyn Fn(usize, T) -> Message>,
+    /// Function called with the selected indices when the selection changes.
+    on_multi_select: Option<Box<dyn Fn(Vec<usize>) -> Message>>,
+    /// Whether the option at each index is disabled.
+    disabled_options: Option<&'a [bool]>,
+    /// Whether the option at each index is checked, when checkboxes are shown.
+    checked: Option<&'a [bool]>,
+    /// Function called when the checkbox of an option is toggled.
+    on_toggle: Option<Box<dyn Fn(usize, bool) -> Message>>,
+    /// Function called when an option is double-clicked.
+    on_double_click: Option<Box<dyn Fn(usize, T) -> Message>>,
+    /// Function called when an option is right-clicked.
+    on_right_click: Option<Box<dyn Fn(usize, T) -> Message>>,
+    /// Function called when an option is dragged to another place.
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message>>,
+    /// Set the Selected ID manually.
+    selected: Option<usize>,
+    /// The text filtering the options.
+    filter: Option<&'a str>,
+    /// Function called when the text filtering the options is edited.
+    on_filter: Option<Box<dyn Fn(String) -> Message>>,
+    /// The group headers and the index of the option they are shown before.
+    headers: Vec<(usize, String)>,
+    /// The function building the content of each option, if set.
+    item_view: Option<Box<ItemView<T, Message, Theme, Renderer>>>,
+    /// The height of a row, if it is not computed from the text size.
+    item_height: Option<f32>,
+    /// The id of the [`SelectionList`].
+    id: Option<widget::Id>,
     /// Label Font
     font: Renderer::Font,
     /// The Containers Width
@@ -47,6 +91,8 @@ where
     text_size: f32,
     /// Style for Looks
     style: <Theme as StyleSheet>::Style,
+    /// Whether the whole [`SelectionList`] is disabled.
+    disabled: bool,
 }
 
 #[allow(clippy::type_repetition_in_bounds)]
@@ -54,7 +100,8 @@ impl<'a, T, Message, Theme, Renderer> SelectionList<'a, T, Message, Theme, Rende
 where
     Message: 'a + Clone,
     Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
-    Theme: 'a + StyleSheet + container::StyleSheet + scrollable::StyleSheet,
+    Theme:
+        'a + StyleSheet + container::StyleSheet + scrollable::StyleSheet + text_input::StyleSheet,
     T: Clone + Display + Eq + Hash,
     [T]: ToOwned<Owned = Vec<T>>,
 {
@@ -63,27 +110,30 @@ where
     /// selected. This will default the `style`, `text_size` and `padding`. use `new_with`
     /// to set those.
     pub fn new(options: &'a [T], on_selected: impl Fn(usize, T) -> Message + 'static) -> Self {
-        let container = Container::new(Scrollable::new(List {
+        Self {
             options,
-            font: Font::default(),
-            text_size: 12.0,
-            padding: 5.0,
-            style: <Theme as StyleSheet>::Style::default(),
             on_selected: Box::new(on_selected),
+            on_multi_select: None,
+            disabled_options: None,
+            checked: None,
+            on_toggle: None,
+            on_double_click: None,
+            on_right_click: None,
+            on_reorder: None,
             selected: None,
-            phantomdata: PhantomData,
-        }))
-        .padding(1);
-
-        Self {
-            options,
-            font: Font::default(),
+            filter: None,
+            on_filter: None,
+            headers: Vec::new(),
+            item_view: None,
+            item_height: None,
+            id: None,
+            font: settings::font(),
             style: <Theme as StyleSheet>::Style::default(),
-            container,
             width: Length::Fill,
             height: Length::Fill,
-            padding: 5.0,
-            text_size: 12.0,
+            padding: settings::spaced(5.0),
+            text_size: settings::scaled(12.0),
+            disabled: false,
         }
     }
 
@@ -99,27 +149,30 @@ where
         selected: Option<usize>,
         font: Font,
     ) -> Self {
-        let container = Container::new(Scrollable::new(List {
-            options,
-            font,
-            text_size,
-            padding,
-            style: style.clone(),
-            selected,
-            on_selected: Box::new(on_selected),
-            phantomdata: PhantomData,
-        }))
-        .padding(1);
-
         Self {
             options,
+            on_selected: Box::new(on_selected),
+            on_multi_select: None,
+            disabled_options: None,
+            checked: None,
+            on_toggle: None,
+            on_double_click: None,
+            on_right_click: None,
+            on_reorder: None,
+            selected,
+            filter: None,
+            on_filter: None,
+            headers: Vec::new(),
+            item_view: None,
+            item_height: None,
+            id: None,
             font,
             style,
-            container,
             width: Length::Fill,
             height: Length::Fill,
             padding,
             text_size,
+            disabled: false,
         }
     }
 
@@ -139,33 +192,264 @@ where
 
     /// Sets the style of the [`SelectionList`].
     #[must_use]
-    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
-        self.style = style;
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Enables the selection of several options of the [`SelectionList`].
+    ///
+    /// Ctrl-click toggles an option, Shift-click selects a range of options
+    /// and Ctrl+A selects all of them. The given function is called with the
+    /// sorted indices of the selected options each time the selection changes.
+    #[must_use]
+    pub fn on_multi_select(
+        mut self,
+        on_multi_select: impl Fn(Vec<usize>) -> Message + 'static,
+    ) -> Self {
+        self.on_multi_select = Some(Box::new(on_multi_select));
+        self
+    }
+
+    /// Disables the options of the [`SelectionList`] for which `disabled` is true.
+    ///
+    /// Disabled options are dimmed, cannot be selected or checked by clicking
+    /// and are skipped by the keyboard navigation.
+    #[must_use]
+    pub fn disabled_options(mut self, disabled: &'a [bool]) -> Self {
+        self.disabled_options = Some(disabled);
+        self
+    }
+
+    /// Sets whether the whole [`SelectionList`] is disabled. A disabled
+    /// [`SelectionList`] is dimmed, and does not react to the mouse or the
+    /// keyboard.
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Shows a checkbox at the start of each row of the [`SelectionList`].
+    ///
+    /// `checked` tells whether the option at each index is checked, and
+    /// `on_toggle` is called with the index of an option and its new state
+    /// when its checkbox is clicked, or when Space is pressed on the
+    /// highlighted option. Checking an option does not select it.
+    #[must_use]
+    pub fn checkboxes(
+        mut self,
+        checked: &'a [bool],
+        on_toggle: impl Fn(usize, bool) -> Message + 'static,
+    ) -> Self {
+        self.checked = Some(checked);
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the message produced with the index and the value of an option
+    /// when it is double-clicked, e.g. to open it.
+    ///
+    /// The first click of a double click still selects the option.
+    #[must_use]
+    pub fn on_double_click(
+        mut self,
+        on_double_click: impl Fn(usize, T) -> Message + 'static,
+    ) -> Self {
+        self.on_double_click = Some(Box::new(on_double_click));
+        self
+    }
+
+    /// Sets the message produced with the index and the value of an option
+    /// when it is right-clicked, e.g. to show a context menu.
+    ///
+    /// Right-clicking an option does not select it.
+    #[must_use]
+    pub fn on_right_click(
+        mut self,
+        on_right_click: impl Fn(usize, T) -> Message + 'static,
+    ) -> Self {
+        self.on_right_click = Some(Box::new(on_right_click));
+        self
+    }
+
+    /// Enables reordering the options of the [`SelectionList`] by dragging them.
+    ///
+    /// A line shows where the dragged option will be dropped. The given
+    /// function is called with the index of the dragged option and the index
+    /// it should be moved to, as in `options.remove(from)` followed by
+    /// `options.insert(to, option)`.
+    #[must_use]
+    pub fn on_reorder(mut self, on_reorder: impl Fn(usize, usize) -> Message + 'static) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Shows a text input above the options of the [`SelectionList`], only
+    /// keeping the options containing its text.
+    ///
+    /// `query` is the current text of the input, `on_filter` is called when it
+    /// is edited. The type-ahead search is disabled while the filter is shown.
+    #[must_use]
+    pub fn filter(
+        mut self,
+        query: &'a str,
+        on_filter: impl Fn(String) -> Message + 'static,
+    ) -> Self {
+        self.filter = Some(query);
+        self.on_filter = Some(Box::new(on_filter));
+        self
+    }
+
+    /// Shows a group header with the given `label` before the option at `index`.
+    ///
+    /// Headers cannot be selected, are hidden when no option of their group
+    /// is shown and stick to the top of the [`SelectionList`] while their
+    /// group is scrolled.
+    #[must_use]
+    pub fn header(mut self, index: usize, label: impl Into<String>) -> Self {
+        self.headers.push((index, label.into()));
+        self.headers.sort_by_key(|(index, _)| *index);
+        self
+    }
+
+    /// Shows the [`Element`] built by the given function for each option of
+    /// the [`SelectionList`] instead of its text.
+    ///
+    /// The function is given the index and the value of the option. Only the
+    /// visible options are built, and the events captured by their content
+    /// (e.g. a click on a button) do not select them. The text color of the
+    /// option is passed as the default text color of the content.
+    #[must_use]
+    pub fn item_view(
+        mut self,
+        item_view: impl for<'b> Fn(usize, &'b T) -> Element<'b, Message, Theme, Renderer> + 'static,
+    ) -> Self {
+        self.item_view = Some(Box::new(item_view));
+        self
+    }
+
+    /// Sets the id of the [`SelectionList`], to focus it with an operation or
+    /// to select an option with [`select`]. Only the focused list takes the
+    /// keyboard input, whether the cursor is over it or not.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the height of the rows of the [`SelectionList`].
+    ///
+    /// By default, it is the text size plus the padding on both sides.
+    #[must_use]
+    pub fn item_height(mut self, item_height: f32) -> Self {
+        self.item_height = Some(item_height);
         self
     }
 }
 
+#[allow(clippy::type_repetition_in_bounds)]
+impl<T, Message, Theme, Renderer> SelectionList<'_, T, Message, Theme, Renderer>
+where
+    Message: Clone,
+    T: Clone + Display + Eq + Hash,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: StyleSheet + container::StyleSheet + scrollable::StyleSheet + text_input::StyleSheet,
+{
+    /// Builds the [`Container`] rendering the [`List`] of the [`SelectionList`].
+    fn container(&self) -> Container<'_, Message, Theme, Renderer> {
+        let list = Scrollable::new(List {
+            options: self.options,
+            font: self.font,
+            style: self.style.clone(),
+            on_selected: self.on_selected.as_ref(),
+            on_multi_select: self.on_multi_select.as_deref(),
+            disabled_options: self.disabled_options,
+            disabled: self.disabled,
+            checked: self.checked,
+            on_toggle: self.on_toggle.as_deref(),
+            on_double_click: self.on_double_click.as_deref(),
+            on_right_click: self.on_right_click.as_deref(),
+            on_reorder: self.on_reorder.as_deref(),
+            padding: self.padding,
+            text_size: self.text_size,
+            selected: self.selected,
+            filter: self.filter,
+            headers: &self.headers,
+            item_view: self.item_view.as_deref(),
+            item_height: self.item_height,
+            id: self.id.as_ref(),
+            phantomdata: PhantomData,
+        });
+
+        match (self.filter, &self.on_filter) {
+            (Some(query), Some(on_filter)) => Container::new(Column::with_children([
+                TextInput::new("", query)
+                    .on_input(on_filter.as_ref())
+                    .font(self.font)
+                    .size(self.text_size)
+                    .padding(self.padding)
+                    .into(),
+                list.into(),
+            ])),
+            _ => Container::new(list),
+        }
+        .padding(1)
+    }
+
+    /// Returns the hash of what the layout of the [`SelectionList`] depends
+    /// on, besides its limits.
+    fn layout_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.options.hash(&mut hasher);
+        self.headers.hash(&mut hasher);
+        self.filter.hash(&mut hasher);
+        self.on_filter.is_some().hash(&mut hasher);
+        self.on_toggle.is_some().hash(&mut hasher);
+        self.item_height.map(f32::to_bits).hash(&mut hasher);
+        self.text_size.to_bits().hash(&mut hasher);
+        self.padding.to_bits().hash(&mut hasher);
+        self.font.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns the tree of the [`Scrollable`] showing the [`List`].
+    fn scrollable_tree<'b>(&self, tree: &'b mut Tree) -> &'b mut Tree {
+        // The container shares the tree of its content.
+        let content = &mut tree.children[0];
+
+        if self.filter.is_some() {
+            &mut content.children[1]
+        } else {
+            content
+        }
+    }
+}
+
 impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for SelectionList<'a, T, Message, Theme, Renderer>
 where
     T: 'a + Clone + ToString + Eq + Hash + Display,
-    Message: 'static,
+    Message: 'static + Clone,
     Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font> + 'a,
-    Theme: StyleSheet + container::StyleSheet,
+    Theme: StyleSheet + container::StyleSheet + scrollable::StyleSheet + text_input::StyleSheet,
 {
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.container as &dyn Widget<_, _, _>)]
+        vec![Tree::new(&self.container() as &dyn Widget<_, _, _>)]
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.container as &dyn Widget<_, _, _>]);
         let state = tree.state.downcast_mut::<State>();
+        let hash = self.layout_hash();
 
-        state.values = self
-            .options
-            .iter()
-            .map(|_| graphics::text::Paragraph::new())
-            .collect();
+        // The content built by the item view may change with any view.
+        if self.item_view.is_some() || state.laid_out != Some(hash) {
+            state.layout.invalidate();
+            state.laid_out = Some(hash);
+        }
+
+        tree.diff_children(&[&self.container() as &dyn Widget<_, _, _>]);
     }
 
     fn size(&self) -> Size<Length> {
@@ -177,7 +461,10 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new(self.options))
+        tree::State::new(State {
+            laid_out: Some(self.layout_hash()),
+            ..State::default()
+        })
     }
 
     fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
@@ -188,37 +475,63 @@ where
         let limits = limits.width(self.width).height(self.height);
 
         let max_width = match self.width {
-            Length::Shrink => self
-                .options
-                .iter()
-                .enumerate()
-                .map(|(id, val)| {
-                    let text = Text {
-                        content: &val.to_string(),
-                        size: Pixels(self.text_size),
-                        line_height: LineHeight::default(),
-                        bounds: Size::INFINITY,
-                        font: self.font,
-                        horizontal_alignment: Horizontal::Left,
-                        vertical_alignment: Vertical::Top,
-                        shaping: text::Shaping::Advanced,
+            Length::Shrink => {
+                let mut hasher = DefaultHasher::new();
+                self.options.hash(&mut hasher);
+                self.text_size.to_bits().hash(&mut hasher);
+                self.padding.to_bits().hash(&mut hasher);
+                self.font.hash(&mut hasher);
+                self.headers.hash(&mut hasher);
+                self.on_toggle.is_some().hash(&mut hasher);
+                let hash = hasher.finish();
+
+                if state.measured != Some(hash) {
+                    let checkbox = if self.on_toggle.is_some() {
+                        (self.text_size + self.padding * 2.0) as u32
+                    } else {
+                        0
                     };
 
-                    state.values[id].update(text);
-                    state.values[id].min_bounds().width.round() as u32 + self.padding as u32 * 2
-                })
-                .max()
-                .unwrap_or(100),
+                    state.max_width = self
+                        .options
+                        .iter()
+                        .map(ToString::to_string)
+                        .chain(self.headers.iter().map(|(_, label)| label.clone()))
+                        .map(|val| {
+                            let text = Text {
+                                content: &val,
+                                size: Pixels(self.text_size),
+                                line_height: LineHeight::default(),
+                                bounds: Size::INFINITY,
+                                font: self.font,
+                                horizontal_alignment: Horizontal::Left,
+                                vertical_alignment: Vertical::Top,
+                                shaping: text::Shaping::Advanced,
+                            };
+
+                            state.paragraph.update(text);
+                            state.paragraph.min_bounds().width.round() as u32
+                                + self.padding as u32 * 2
+                                + checkbox
+                        })
+                        .max()
+                        .unwrap_or(100);
+                    state.measured = Some(hash);
+                }
+
+                state.max_width
+            }
             _ => limits.max().width as u32,
         };
 
         let limits = limits.max_width(max_width as f32 + self.padding * 2.0);
 
-        let content = self
-            .container
-            .layout(&mut tree.children[0], renderer, &limits);
+        let content = state.layout.get_or_layout(&limits, || {
+            self.container()
+                .layout(&mut tree.children[0], renderer, &limits)
+        });
         let size = limits.resolve(self.width, self.height, content.size());
-        Node::with_children(size, vec![content])
+        Node::with_children(size, vec![content.clone()])
     }
 
     fn on_event(
@@ -232,7 +545,17 @@ where
         shell: &mut Shell<Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        self.container.on_event(
+        if self.disabled {
+            // A selection requested before the list was disabled is dropped.
+            self.scrollable_tree(state).children[0]
+                .state
+                .downcast_mut::<list::ListState>()
+                .requested = None;
+
+            return event::Status::Ignored;
+        }
+
+        let status = self.container().on_event(
             &mut state.children[0],
             event,
             layout
@@ -244,7 +567,47 @@ where
             clipboard,
             shell,
             viewport,
-        )
+        );
+
+        if shell.is_layout_invalid() {
+            state.state.downcast_mut::<State>().layout.invalidate();
+        }
+
+        let scrollable_tree = self.scrollable_tree(state);
+        let list_state = scrollable_tree.children[0]
+            .state
+            .downcast_mut::<list::ListState>();
+
+        if let Some(y) = list_state.scroll_to.take() {
+            scrollable_tree
+                .state
+                .downcast_mut::<scrollable::State>()
+                .scroll_to(AbsoluteOffset { x: 0.0, y });
+        }
+
+        status
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if self.disabled {
+            return;
+        }
+
+        self.container().operate(
+            &mut state.children[0],
+            layout
+                .children()
+                .next()
+                .expect("Scrollable Child Missing in Selection List"),
+            renderer,
+            operation,
+        );
     }
 
     fn mouse_interaction(
@@ -255,7 +618,11 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.container
+        if self.disabled {
+            return mouse::Interaction::default();
+        }
+
+        self.container()
             .mouse_interaction(&state.children[0], layout, cursor, viewport, renderer)
     }
 
@@ -269,20 +636,26 @@ where
         cursor: Cursor,
         _viewport: &Rectangle,
     ) {
+        let appearance = if self.disabled {
+            <Theme as StyleSheet>::disabled(theme, &self.style)
+        } else {
+            theme.style(&self.style)
+        };
+
         renderer.fill_quad(
             renderer::Quad {
                 bounds: layout.bounds(),
                 border: Border {
                     radius: (0.0).into(),
-                    width: theme.style(&self.style).border_width,
-                    color: theme.style(&self.style).border_color,
+                    width: appearance.border_width,
+                    color: appearance.border_color,
                 },
                 shadow: Shadow::default(),
             },
-            theme.style(&self.style).background,
+            appearance.background,
         );
 
-        self.container.draw(
+        self.container().draw(
             &state.children[0],
             renderer,
             theme,
@@ -301,33 +674,89 @@ impl<'a, T, Message, Theme, Renderer> From<SelectionList<'a, T, Message, Theme,
     for Element<'a, Message, Theme, Renderer>
 where
     T: Clone + ToString + Eq + Hash + Display,
-    Message: 'static,
+    Message: 'static + Clone,
     Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
-    Theme: 'a + StyleSheet + container::StyleSheet,
+    Theme:
+        'a + StyleSheet + container::StyleSheet + scrollable::StyleSheet + text_input::StyleSheet,
 {
     fn from(selection_list: SelectionList<'a, T, Message, Theme, Renderer>) -> Self {
         Element::new(selection_list)
     }
 }
 
-/// A Paragraph cache to enhance speed of layouting.
+/// A cache of the width of the options to enhance speed of layouting.
 #[derive(Debug, Default, Clone)]
 pub struct State {
-    values: Vec<graphics::text::Paragraph>,
+    /// The paragraph used to measure the options.
+    paragraph: graphics::text::Paragraph,
+    /// The hash of the options measured last.
+    measured: Option<u64>,
+    /// The width of the widest option measured last.
+    max_width: u32,
+    /// The layout of the list, reused until the options or the limits change.
+    pub(crate) layout: LayoutCache,
+    /// The hash of the options and settings of the cached layout.
+    laid_out: Option<u64>,
 }
 
 impl State {
-    /// Creates a new [`State`], representing an unfocused [`TextInput`].
-    pub fn new<T>(options: &[T]) -> Self
+    /// Creates a new [`State`] with no measured options.
+    ///
+    /// The options are not needed anymore, the [`State`] measures them when
+    /// the list is laid out.
+    #[must_use]
+    #[deprecated(note = "use `State::default` instead")]
+    pub fn new<T>(_options: &[T]) -> Self
     where
         T: Clone + Display + Eq + Hash,
         [T]: ToOwned<Owned = Vec<T>>,
     {
-        Self {
-            values: options
-                .iter()
-                .map(|_| graphics::text::Paragraph::new())
-                .collect(),
+        Self::default()
+    }
+}
+
+/// Produces an [`Operation`] requesting the selection of the option at
+/// `index` in the [`SelectionList`] with the given [`Id`](widget::Id).
+pub(crate) fn request<T>(target: widget::Id, index: usize) -> impl Operation<T> {
+    struct Apply {
+        target: widget::Id,
+        index: usize,
+    }
+
+    impl<T> Operation<T> for Apply {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if id != Some(&self.target) {
+                return;
+            }
+
+            if let Some(state) = state.downcast_mut::<list::ListState>() {
+                state.requested = Some(self.index);
+            }
         }
     }
+
+    Apply { target, index }
+}
+
+/// Produces a [`Command`] that selects the option at `index` in the
+/// [`SelectionList`] with the given [`Id`](widget::Id) and scrolls it into
+/// view.
+///
+/// The option is selected like with the keyboard. An operation cannot publish
+/// messages, so the list publishes its selection messages with the next event
+/// it handles, which is the redraw following the command.
+///
+/// The request is dropped if the option or the whole list is disabled, even
+/// if the list is enabled again later.
+pub fn select<Message: 'static>(id: widget::Id, index: usize) -> Command<Message> {
+    Command::widget(request(id, index))
 }
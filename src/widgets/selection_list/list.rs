@@ -1,31 +1,46 @@
 //! Build and show dropdown `ListMenus`.
 
-use crate::selection_list::StyleSheet;
+use super::ItemView;
+use crate::{
+    core::accessibility::{self, Action, Role},
+    style::selection_list::{Appearance, StyleSheet},
+};
 
 use iced::{
     advanced::{
+        self,
         layout::{Limits, Node},
         renderer,
         widget::{
+            self,
+            operation::Focusable,
             tree::{State, Tag},
-            Tree,
+            Operation, Tree,
         },
         Clipboard, Layout, Shell, Widget,
     },
     alignment::{Horizontal, Vertical},
-    event,
+    event, keyboard,
     mouse::{self, Cursor},
     touch,
     widget::text::LineHeight,
-    Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Shadow, Size,
+    Border, Color, Element, Event, Length, Pixels, Point, Rectangle, Shadow, Size, Vector,
 };
 use std::{
-    collections::hash_map::DefaultHasher,
+    collections::{hash_map::DefaultHasher, BTreeSet},
     fmt::Display,
     hash::{Hash, Hasher},
     marker::PhantomData,
+    ops::Range,
+    time::{Duration, Instant},
 };
 
+/// The delay after which the type-ahead search starts over.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The distance the cursor has to move before a pressed option is dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+
 /// The Private [`List`] Handles the Actual list rendering.
 #[allow(missing_debug_implementations)]
 pub struct List<'a, T: 'a, Message, Theme, Renderer>
@@ -43,26 +58,716 @@ where
     /// Style for Font colors and Box hover colors.
     pub style: <Theme as StyleSheet>::Style,
     /// Function Pointer On Select to call on Mouse button press.
-    pub on_selected: Box<dyn Fn(usize, T) -> Message>,
+    pub on_selected: &'a dyn Fn(usize, T) -> Message,
+    /// Function called with the selected indices when the selection changes.
+    pub on_multi_select: Option<&'a dyn Fn(Vec<usize>) -> Message>,
+    /// Whether the option at each index is disabled, if any is.
+    pub disabled_options: Option<&'a [bool]>,
+    /// Whether the whole list is disabled.
+    pub disabled: bool,
+    /// Whether the option at each index is checked, when a checkbox is shown on each row.
+    pub checked: Option<&'a [bool]>,
+    /// Function called with the index of an option and its new checked state
+    /// when its checkbox is toggled.
+    pub on_toggle: Option<&'a dyn Fn(usize, bool) -> Message>,
+    /// Function called with the index and the value of a double-clicked option.
+    pub on_double_click: Option<&'a dyn Fn(usize, T) -> Message>,
+    /// Function called with the index and the value of a right-clicked option.
+    pub on_right_click: Option<&'a dyn Fn(usize, T) -> Message>,
+    /// Function called with the index of a dragged option and the index it is dropped at.
+    pub on_reorder: Option<&'a dyn Fn(usize, usize) -> Message>,
     /// The padding Width
     pub padding: f32,
     /// The Text Size
     pub text_size: f32,
     /// Set the Selected ID manually.
     pub selected: Option<usize>,
+    /// Only the options containing this text are shown, if set.
+    pub filter: Option<&'a str>,
+    /// The group headers and the index of the option they are shown before, sorted.
+    pub headers: &'a [(usize, String)],
+    /// The function building the content of each option instead of its text, if set.
+    pub item_view: Option<&'a ItemView<T, Message, Theme, Renderer>>,
+    /// The height of a row, if it is not computed from the text size.
+    pub item_height: Option<f32>,
+    /// The id of the list, to focus it with an operation.
+    pub id: Option<&'a widget::Id>,
     /// Shadow Type holder for Renderer.
     pub phantomdata: PhantomData<Renderer>,
 }
 
+/// The content built by the item view for a shown option.
+#[derive(Debug)]
+pub struct Item {
+    /// The index of the option.
+    pub index: usize,
+    /// The hash of the value of the option, which the content follows when
+    /// the option moves.
+    pub key: u64,
+    /// The row the content is shown in.
+    pub row: usize,
+    /// The state of the content.
+    pub tree: Tree,
+    /// The layout of the content, relative to the [`List`].
+    pub node: Node,
+}
+
+/// A row of the [`List`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Row {
+    /// The group header at the given index.
+    Header(usize),
+    /// The option at the given index.
+    Option(usize),
+}
+
 /// The Private [`ListState`] Handles the State of the inner list.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Default)]
 pub struct ListState {
     /// Statehood of hovered_option
     pub hovered_option: Option<usize>,
     /// The index in the list of options of the last chosen Item Clicked for Processing
     pub last_selected_index: Option<(usize, u64)>,
-    /// String Build Cache
-    pub options: Vec<String>,
+    /// The number of options of the list.
+    pub len: usize,
+    /// The rows shown in the list, in order, if they are filtered or grouped.
+    pub visible: Option<Vec<Row>>,
+    /// The filter and the hash of the options and headers `visible` was computed for.
+    pub filtered_for: Option<(String, u64)>,
+    /// The indices of the selected options when several options can be selected.
+    pub selected_options: BTreeSet<usize>,
+    /// The option from which Shift-click selects a range.
+    pub anchor: Option<usize>,
+    /// The current state of the keyboard modifiers.
+    pub modifiers: keyboard::Modifiers,
+    /// The last click on the list, to detect double clicks.
+    pub last_click: Option<advanced::mouse::Click>,
+    /// Whether the list receives the keyboard events when the cursor is not over it.
+    pub focused: bool,
+    /// Whether the focus ring is shown, when the list was focused or
+    /// navigated with the keyboard rather than clicked.
+    pub focus_visible: bool,
+    /// The text typed so far by the type-ahead search.
+    pub search: String,
+    /// The moment of the last key press of the type-ahead search.
+    pub last_search: Option<Instant>,
+    /// The scroll offset requested by the list, applied by the
+    /// [`SelectionList`](crate::SelectionList).
+    pub scroll_to: Option<f32>,
+    /// The rows shown in the viewport when the last event was processed.
+    pub shown: Range<usize>,
+    /// The content built by the item view for the shown options.
+    pub items: Vec<Item>,
+    /// The width the content of the options was laid out for, until the
+    /// content is diffed again because the view or its layout changed.
+    pub items_width: Option<f32>,
+    /// The index of the pressed option and the vertical position of the press,
+    /// while it may be dragged.
+    pub dragged: Option<(usize, f32)>,
+    /// The row before which the dragged option would be dropped.
+    pub drop_row: Option<usize>,
+    /// The option to select at the next event, requested with
+    /// [`select`](super::select).
+    pub requested: Option<usize>,
+}
+
+impl Focusable for ListState {
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn focus(&mut self) {
+        self.focused = true;
+        self.focus_visible = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.focused = false;
+        self.focus_visible = false;
+    }
+}
+
+impl ListState {
+    /// Updates the selected options after a click on the option at `index`,
+    /// according to the current keyboard modifiers. Only the `enabled`
+    /// options of a range are selected.
+    fn select(&mut self, index: usize, enabled: impl Fn(usize) -> bool) {
+        let anchor = self.anchor.filter(|_| self.modifiers.shift());
+
+        if !self.modifiers.command() {
+            self.selected_options.clear();
+        }
+
+        if let Some(anchor) = anchor {
+            let (first, last) = (
+                self.row_of(anchor).unwrap_or_default(),
+                self.row_of(index).unwrap_or_default(),
+            );
+            let range: Vec<usize> = (first.min(last)..=first.max(last))
+                .filter_map(|row| self.option_of(row))
+                .filter(|index| enabled(*index))
+                .collect();
+            self.selected_options.extend(range);
+        } else {
+            if !self.selected_options.insert(index) {
+                let _ = self.selected_options.remove(&index);
+            }
+            self.anchor = Some(index);
+        }
+    }
+
+    /// Returns the number of rows shown in the list.
+    fn rows(&self) -> usize {
+        self.visible.as_ref().map_or(self.len, Vec::len)
+    }
+
+    /// Returns the index of the option shown at `row`, if any.
+    fn option_of(&self, row: usize) -> Option<usize> {
+        self.visible.as_ref().map_or_else(
+            || (row < self.len).then_some(row),
+            |visible| match visible.get(row) {
+                Some(Row::Option(index)) => Some(*index),
+                _ => None,
+            },
+        )
+    }
+
+    /// Returns the `enabled` option shown at `row` or the closest one after
+    /// it, or before it if `forward` is false, falling back to the other
+    /// direction.
+    fn option_near(
+        &self,
+        row: usize,
+        forward: bool,
+        enabled: impl Fn(usize) -> bool,
+    ) -> Option<usize> {
+        let option = |row| self.option_of(row).filter(|index| enabled(*index));
+        let after = || (row..self.rows()).find_map(option);
+        let before = || (0..=row).rev().find_map(option);
+
+        if forward {
+            after().or_else(before)
+        } else {
+            before().or_else(after)
+        }
+    }
+
+    /// Returns the index of the group header shown at `row`, if any.
+    fn header_of(&self, row: usize) -> Option<usize> {
+        match self.visible.as_ref()?.get(row) {
+            Some(Row::Header(header)) => Some(*header),
+            _ => None,
+        }
+    }
+
+    /// Returns the row showing the option at `index`, if it is visible.
+    fn row_of(&self, index: usize) -> Option<usize> {
+        self.visible.as_ref().map_or_else(
+            || (index < self.len).then_some(index),
+            |visible| visible.iter().position(|row| *row == Row::Option(index)),
+        )
+    }
+
+    /// Returns the index the dragged option at `from` ends up at when it is
+    /// dropped before the given row.
+    fn drop_index(&self, from: usize, row: usize) -> usize {
+        let to = (row..self.rows())
+            .find_map(|row| self.option_of(row))
+            .unwrap_or_else(|| {
+                (0..self.rows())
+                    .rev()
+                    .find_map(|row| self.option_of(row))
+                    .map_or(0, |last| last + 1)
+            });
+
+        if to > from {
+            to - 1
+        } else {
+            to
+        }
+    }
+
+    /// Adds the typed `text` to the type-ahead search and returns the index of
+    /// the first `enabled` option matching it, starting after the `current` one.
+    fn type_ahead<T: Display>(
+        &mut self,
+        options: &[T],
+        text: &str,
+        current: Option<usize>,
+        enabled: impl Fn(usize) -> bool,
+    ) -> Option<usize> {
+        let now = Instant::now();
+        if self
+            .last_search
+            .map_or(true, |last| now.duration_since(last) > TYPE_AHEAD_TIMEOUT)
+        {
+            self.search.clear();
+        }
+        self.last_search = Some(now);
+        self.search.push_str(&text.to_lowercase());
+
+        // Typing the same character again cycles through the matching options.
+        let mut chars = self.search.chars();
+        let repeated = chars.next().is_some_and(|first| chars.all(|c| c == first));
+        let search = if repeated {
+            &self.search[..self.search.chars().next().map_or(0, char::len_utf8)]
+        } else {
+            &self.search
+        };
+
+        let start = current
+            .and_then(|current| self.row_of(current))
+            .map_or(0, |row| if repeated { row + 1 } else { row });
+        let rows = self.rows();
+
+        (0..rows)
+            .filter_map(|offset| self.option_of((start + offset) % rows))
+            .find(|index| {
+                enabled(*index)
+                    && options[*index]
+                        .to_string()
+                        .to_lowercase()
+                        .starts_with(search)
+            })
+    }
+}
+
+impl<T, Message, Theme, Renderer> List<'_, T, Message, Theme, Renderer>
+where
+    T: Clone + Display + Eq + Hash,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Theme: StyleSheet,
+{
+    /// The height of a row of the [`List`].
+    fn option_height(&self) -> f32 {
+        self.item_height
+            .unwrap_or(self.text_size + (self.padding * 2.0))
+    }
+
+    /// The width taken by the checkbox at the start of each row, if any.
+    fn leading(&self) -> f32 {
+        if self.on_toggle.is_some() {
+            self.text_size + self.padding * 2.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the bounds of the checkbox of the row in the given bounds.
+    fn checkbox_bounds(&self, row: Rectangle) -> Rectangle {
+        Rectangle {
+            x: row.x + self.padding,
+            y: row.center_y() - self.text_size / 2.0,
+            width: self.text_size,
+            height: self.text_size,
+        }
+    }
+
+    /// Returns the [`Appearance`] of the list, disabled or not.
+    fn appearance(&self, theme: &Theme) -> Appearance {
+        if self.disabled {
+            theme.disabled(&self.style)
+        } else {
+            theme.style(&self.style)
+        }
+    }
+
+    /// Returns whether the option at `index` can be selected.
+    fn is_enabled(&self, index: usize) -> bool {
+        !self
+            .disabled_options
+            .and_then(|disabled| disabled.get(index))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the option at `index` is checked.
+    fn is_checked(&self, index: usize) -> bool {
+        self.checked
+            .and_then(|checked| checked.get(index))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Toggles the checkbox of the option at `index`.
+    fn toggle(&self, index: usize, shell: &mut Shell<Message>) {
+        if let Some(on_toggle) = self.on_toggle {
+            shell.publish(on_toggle(index, !self.is_checked(index)));
+        }
+    }
+
+    /// Draws the checkbox of the option at `index` in the row in the given bounds.
+    fn draw_checkbox(&self, renderer: &mut Renderer, index: usize, row: Rectangle, color: Color) {
+        let bounds = self.checkbox_bounds(row);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius: 2.0.into(),
+                    width: 1.0,
+                    color,
+                },
+                shadow: Shadow::default(),
+            },
+            Color::TRANSPARENT,
+        );
+
+        if self.is_checked(index) {
+            let inset = (self.text_size / 4.0).max(2.0);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + inset,
+                        y: bounds.y + inset,
+                        width: (bounds.width - inset * 2.0).max(0.0),
+                        height: (bounds.height - inset * 2.0).max(0.0),
+                    },
+                    border: Border {
+                        radius: 1.0.into(),
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                color,
+            );
+        }
+    }
+
+    /// Returns the rows intersecting the given `viewport`.
+    fn rows_in(
+        &self,
+        list_state: &ListState,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) -> Range<usize> {
+        let offset = viewport.y - bounds.y;
+        let start = (offset / self.option_height()).max(0.0) as usize;
+        let end = ((offset + viewport.height) / self.option_height()).ceil() as usize;
+        start..end.min(list_state.rows())
+    }
+
+    /// Lays out the `element` built by the item view for the option shown at
+    /// `row`, relative to the [`List`].
+    fn layout_item(
+        &self,
+        element: &Element<'_, Message, Theme, Renderer>,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        width: f32,
+        row: usize,
+    ) -> Node {
+        let option_height = self.option_height();
+        let limits = Limits::new(
+            Size::ZERO,
+            Size::new(
+                (width - self.leading() - self.padding * 2.0).max(0.0),
+                (option_height - self.padding * 2.0).max(0.0),
+            ),
+        );
+        let node = element.as_widget().layout(tree, renderer, &limits);
+        let y = option_height * row as f32 + (option_height - node.size().height) / 2.0;
+
+        node.move_to(Point::new(self.leading() + self.padding, y))
+    }
+
+    /// Builds, diffs and lays out the content of the options shown in the
+    /// `shown` rows, dropping the content of the others.
+    ///
+    /// The content is keyed by the value of its option: it keeps its state
+    /// when the option moves, and only the options whose value changed get a
+    /// new state. The content that is still shown in the same row is only
+    /// diffed and laid out again when the view or the width changed.
+    fn update_items(
+        &self,
+        list_state: &mut ListState,
+        renderer: &Renderer,
+        width: f32,
+        shown: Range<usize>,
+    ) {
+        let Some(item_view) = self.item_view else {
+            list_state.items.clear();
+            return;
+        };
+
+        let up_to_date = list_state.items_width == Some(width);
+        let mut previous = std::mem::take(&mut list_state.items);
+
+        for row in shown {
+            let Some(index) = list_state.option_of(row) else {
+                continue;
+            };
+            let key = hash_of(&self.options[index]);
+            let reused = previous
+                .iter()
+                .position(|item| item.key == key && item.index == index)
+                .or_else(|| previous.iter().position(|item| item.key == key))
+                .map(|position| previous.swap_remove(position));
+
+            let item = match reused {
+                Some(item) if up_to_date && item.index == index && item.row == row => item,
+                Some(Item { mut tree, .. }) => {
+                    let element = item_view(index, &self.options[index]);
+                    tree.diff(&element);
+                    let node = self.layout_item(&element, &mut tree, renderer, width, row);
+
+                    Item {
+                        index,
+                        key,
+                        row,
+                        tree,
+                        node,
+                    }
+                }
+                None => {
+                    let element = item_view(index, &self.options[index]);
+                    let mut tree = Tree::new(&element);
+                    let node = self.layout_item(&element, &mut tree, renderer, width, row);
+
+                    Item {
+                        index,
+                        key,
+                        row,
+                        tree,
+                        node,
+                    }
+                }
+            };
+
+            list_state.items.push(item);
+        }
+
+        list_state.items_width = Some(width);
+    }
+
+    /// Returns the index of the option under the given position.
+    fn option_at(
+        &self,
+        list_state: &ListState,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+        cursor: Point,
+    ) -> Option<usize> {
+        if self
+            .sticky_header(list_state, bounds, viewport)
+            .is_some_and(|(_, header)| header.contains(cursor))
+        {
+            return None;
+        }
+
+        let row = ((cursor.y - bounds.y) / self.option_height()) as usize;
+        list_state
+            .option_of(row)
+            .filter(|index| self.is_enabled(*index))
+    }
+
+    /// Returns the group header sticking to the top of the `viewport` and its
+    /// bounds, if the header of the first visible option is scrolled out of it.
+    fn sticky_header(
+        &self,
+        list_state: &ListState,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) -> Option<(usize, Rectangle)> {
+        let option_height = self.option_height();
+        let offset = viewport.y - bounds.y;
+        let first = (offset.max(0.0) / option_height) as usize;
+        let header_row = (0..=first.min(list_state.rows().checked_sub(1)?))
+            .rev()
+            .find(|row| list_state.header_of(*row).is_some())?;
+
+        if option_height * header_row as f32 >= offset {
+            return None;
+        }
+
+        // The next header pushes this one out of the viewport.
+        let y = (header_row + 1..list_state.rows())
+            .find(|row| list_state.header_of(*row).is_some())
+            .map_or(viewport.y, |next| {
+                viewport
+                    .y
+                    .min(bounds.y + option_height * (next as f32 - 1.0))
+            });
+
+        Some((
+            list_state.header_of(header_row)?,
+            Rectangle {
+                x: bounds.x,
+                y,
+                width: bounds.width,
+                height: option_height,
+            },
+        ))
+    }
+
+    /// Draws the group header at `header` in the given bounds.
+    fn draw_header(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        header: usize,
+        bounds: Rectangle,
+    ) {
+        let appearance = self.appearance(theme);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            appearance.header_background,
+        );
+
+        renderer.fill_text(
+            iced::advanced::text::Text {
+                content: &self.headers[header].1,
+                bounds: Size::new(f32::INFINITY, bounds.height),
+                size: Pixels(self.text_size),
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                line_height: LineHeight::default(),
+                shaping: iced::widget::text::Shaping::Advanced,
+            },
+            Point::new(bounds.x, bounds.center_y()),
+            appearance.header_text_color,
+            bounds,
+        );
+    }
+
+    /// Returns the rows of the [`List`], only keeping the options containing
+    /// the lowercase `filter` and the headers of their groups.
+    fn visible_rows(&self, filter: Option<&str>) -> Vec<Row> {
+        let mut rows = Vec::new();
+        let mut headers = self.headers.iter().enumerate().peekable();
+        let mut pending = None;
+
+        for (index, option) in self.options.iter().enumerate() {
+            while let Some((header, _)) = headers.next_if(|(_, (before, _))| *before <= index) {
+                pending = Some(header);
+            }
+
+            if filter.map_or(true, |filter| {
+                option.to_string().to_lowercase().contains(filter)
+            }) {
+                if let Some(header) = pending.take() {
+                    rows.push(Row::Header(header));
+                }
+                rows.push(Row::Option(index));
+            }
+        }
+
+        rows
+    }
+
+    /// Selects the option at `index` alone and scrolls it into view.
+    fn select_only(
+        &self,
+        list_state: &mut ListState,
+        index: usize,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+        shell: &mut Shell<Message>,
+    ) {
+        let Some(option) = self.options.get(index) else {
+            return;
+        };
+
+        list_state.last_selected_index = Some((index, hash_of(option)));
+        list_state.hovered_option = Some(index);
+
+        if let Some(on_multi_select) = self.on_multi_select {
+            list_state.selected_options.clear();
+            let _ = list_state.selected_options.insert(index);
+            list_state.anchor = Some(index);
+            shell.publish(on_multi_select(vec![index]));
+        }
+
+        shell.publish((self.on_selected)(index, option.clone()));
+        self.scroll_into_view(list_state, index, bounds, viewport);
+    }
+
+    /// Requests the scroll offset showing the option at `index` in the `viewport`.
+    fn scroll_into_view(
+        &self,
+        list_state: &mut ListState,
+        index: usize,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) {
+        if let Some(row) = list_state.row_of(index) {
+            let top = self.option_height() * row as f32;
+            let offset = viewport.y - bounds.y;
+
+            if top < offset {
+                list_state.scroll_to = Some(top);
+            } else if top + self.option_height() > offset + viewport.height {
+                list_state.scroll_to = Some(top + self.option_height() - viewport.height);
+            }
+        }
+    }
+
+    /// Moves the highlighted option or selects it according to the pressed
+    /// `key`, returning whether the key was handled.
+    fn navigate(
+        &self,
+        list_state: &mut ListState,
+        key: keyboard::key::Named,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+        shell: &mut Shell<Message>,
+    ) -> bool {
+        use keyboard::key::Named;
+
+        let current = list_state
+            .hovered_option
+            .or_else(|| list_state.last_selected_index.map(|(index, _)| index))
+            .and_then(|index| list_state.row_of(index));
+        let last = list_state.rows().saturating_sub(1);
+        let page = ((viewport.height / self.option_height()).floor() as usize).max(1);
+
+        let target = match key {
+            Named::ArrowDown => current.map_or(Some((0, true)), |row| Some((row + 1, true))),
+            Named::ArrowUp => current.map_or(Some((last, false)), |row| {
+                Some((row.saturating_sub(1), false))
+            }),
+            Named::Home => Some((0, true)),
+            Named::End => Some((last, false)),
+            Named::PageDown => Some((current.map_or(0, |row| row + page), true)),
+            Named::PageUp => Some((current.map_or(0, |row| row.saturating_sub(page)), false)),
+            Named::Space if self.on_toggle.is_some() => {
+                if let Some(index) = current
+                    .and_then(|row| list_state.option_of(row))
+                    .filter(|index| self.is_enabled(*index))
+                {
+                    self.toggle(index, shell);
+                }
+                None
+            }
+            Named::Enter => {
+                if let Some(index) = current
+                    .and_then(|row| list_state.option_of(row))
+                    .filter(|index| self.is_enabled(*index))
+                {
+                    self.select_only(list_state, index, bounds, viewport, shell);
+                }
+                None
+            }
+            _ => return false,
+        };
+
+        if let Some(index) = target.and_then(|(row, forward)| {
+            list_state.option_near(row.min(last), forward, |index| self.is_enabled(index))
+        }) {
+            list_state.hovered_option = Some(index);
+            self.scroll_into_view(list_state, index, bounds, viewport);
+        }
+
+        true
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -78,7 +783,7 @@ where
 
     fn state(&self) -> State {
         State::new(ListState {
-            options: self.options.iter().map(ToString::to_string).collect(),
+            len: self.options.len(),
             ..ListState::default()
         })
     }
@@ -88,19 +793,13 @@ where
 
         if let Some(id) = self.selected {
             if let Some(option) = self.options.get(id) {
-                let mut hasher = DefaultHasher::new();
-                option.hash(&mut hasher);
-
-                list_state.last_selected_index = Some((id, hasher.finish()));
+                list_state.last_selected_index = Some((id, hash_of(option)));
             } else {
                 list_state.last_selected_index = None;
             }
         } else if let Some((id, hash)) = list_state.last_selected_index {
             if let Some(option) = self.options.get(id) {
-                let mut hasher = DefaultHasher::new();
-                option.hash(&mut hasher);
-
-                if hash != hasher.finish() {
+                if hash != hash_of(option) {
                     list_state.last_selected_index = None;
                 }
             } else {
@@ -108,21 +807,51 @@ where
             }
         }
 
-        list_state.options = self.options.iter().map(ToString::to_string).collect();
+        list_state.len = self.options.len();
+        list_state.items_width = None;
+        let filter = self
+            .filter
+            .map(str::to_lowercase)
+            .filter(|filter| !filter.is_empty());
+
+        if filter.is_some() || !self.headers.is_empty() {
+            let mut hasher = DefaultHasher::new();
+            self.options.hash(&mut hasher);
+            self.headers.hash(&mut hasher);
+            let key = (filter.clone().unwrap_or_default(), hasher.finish());
+
+            if list_state.filtered_for.as_ref() != Some(&key) {
+                list_state.visible = Some(self.visible_rows(filter.as_deref()));
+                list_state.filtered_for = Some(key);
+            }
+        } else {
+            list_state.visible = None;
+            list_state.filtered_for = None;
+        }
+
+        let len = self.options.len();
+        list_state.selected_options.retain(|index| *index < len);
+        if list_state.anchor.is_some_and(|anchor| anchor >= len) {
+            list_state.anchor = None;
+        }
     }
 
     fn size(&self) -> Size<Length> {
         Size::new(Length::Fill, Length::Shrink)
     }
 
-    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
         use std::f32;
         let limits = limits.height(Length::Fill).width(Length::Fill);
+        let list_state = tree.state.downcast_mut::<ListState>();
+
+        let shown = list_state.shown.start..list_state.shown.end.min(list_state.rows());
+        self.update_items(list_state, renderer, limits.max().width, shown);
 
         #[allow(clippy::cast_precision_loss)]
         let intrinsic = Size::new(
             limits.max().width,
-            (self.text_size + self.padding * 2.0) * self.options.len() as f32,
+            self.option_height() * list_state.rows() as f32,
         );
 
         Node::new(intrinsic)
@@ -134,48 +863,239 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor: Cursor,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> event::Status {
         let bounds = layout.bounds();
         let mut status = event::Status::Ignored;
         let list_state = state.state.downcast_mut::<ListState>();
+
+        // An operation cannot publish messages, so the requested option is
+        // selected with the next event, like the redraw following a command.
+        if let Some(index) = list_state.requested.take() {
+            if !self.disabled && self.is_enabled(index) {
+                self.select_only(list_state, index, bounds, viewport, shell);
+            }
+        }
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            list_state.modifiers = modifiers;
+            return status;
+        }
+
+        if let Some(item_view) = self.item_view {
+            list_state.shown = self.rows_in(list_state, bounds, viewport);
+            self.update_items(list_state, renderer, bounds.width, list_state.shown.clone());
+
+            // The content of the options gets the event first, so that its
+            // buttons do not select the option.
+            let offset = Vector::new(bounds.x, bounds.y);
+            for Item {
+                index, tree, node, ..
+            } in &mut list_state.items
+            {
+                if !self.is_enabled(*index) {
+                    continue;
+                }
+
+                let captured = item_view(*index, &self.options[*index])
+                    .as_widget_mut()
+                    .on_event(
+                        tree,
+                        event.clone(),
+                        Layout::with_offset(offset, node),
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        viewport,
+                    );
+
+                if captured == event::Status::Captured {
+                    status = event::Status::Captured;
+                }
+            }
+
+            if shell.is_layout_invalid() {
+                list_state.items_width = None;
+            }
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        if let Some(on_reorder) = self.on_reorder {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. })
+                | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                    if let (Some((_, origin)), Some(position)) =
+                        (list_state.dragged, cursor.position())
+                    {
+                        if list_state.drop_row.is_some()
+                            || (position.y - origin).abs() > DRAG_THRESHOLD
+                        {
+                            let row = ((position.y - bounds.y) / self.option_height()).round();
+                            list_state.drop_row =
+                                Some((row.max(0.0) as usize).min(list_state.rows()));
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => {
+                    let dragged = list_state.dragged.take();
+
+                    if let (Some((from, _)), Some(row)) = (dragged, list_state.drop_row.take()) {
+                        let to = list_state.drop_index(from, row);
+                        if to != from {
+                            shell.publish(on_reorder(from, to));
+                        }
+                        return event::Status::Captured;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            list_state.focused = cursor.is_over(bounds);
+            list_state.focus_visible = false;
+        }
+
+        // The keyboard only reaches a focused list, so that typing in another
+        // widget does not change the selection of the list under the cursor.
+        let focused = list_state.focused;
         let cursor = cursor.position().unwrap_or_default();
 
         if bounds.contains(cursor) {
             match event {
                 Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                    list_state.hovered_option = Some(
-                        ((cursor.y - bounds.y) / (self.text_size + (self.padding * 2.0))) as usize,
-                    );
+                    list_state.hovered_option =
+                        self.option_at(list_state, bounds, viewport, cursor);
                 }
                 Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
                 | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    list_state.hovered_option = Some(
-                        ((cursor.y - bounds.y) / (self.text_size + (self.padding * 2.0))) as usize,
-                    );
+                    list_state.hovered_option =
+                        self.option_at(list_state, bounds, viewport, cursor);
+
+                    if let Some(index) = list_state.hovered_option {
+                        let row = Rectangle {
+                            y: bounds.y
+                                + self.option_height()
+                                    * ((cursor.y - bounds.y) / self.option_height()).floor(),
+                            height: self.option_height(),
+                            ..bounds
+                        };
+
+                        if self.on_toggle.is_some() && self.checkbox_bounds(row).contains(cursor) {
+                            self.toggle(index, shell);
+                            return event::Status::Captured;
+                        }
+                    }
 
+                    if self.on_reorder.is_some() {
+                        list_state.dragged =
+                            list_state.hovered_option.map(|index| (index, cursor.y));
+                    }
+
+                    // Clicking a disabled option or a header selects nothing,
+                    // and does not publish the previous selection again.
                     if let Some(index) = list_state.hovered_option {
                         if let Some(option) = self.options.get(index) {
-                            let mut hasher = DefaultHasher::new();
-                            option.hash(&mut hasher);
-                            list_state.last_selected_index = Some((index, hasher.finish()));
+                            list_state.last_selected_index = Some((index, hash_of(option)));
+
+                            if let Some(on_multi_select) = self.on_multi_select {
+                                list_state.select(index, |index| self.is_enabled(index));
+                                shell.publish(on_multi_select(
+                                    list_state.selected_options.iter().copied().collect(),
+                                ));
+                            }
+
+                            shell.publish((self.on_selected)(index, option.clone()));
+                            status = event::Status::Captured;
                         }
                     }
 
-                    status =
-                        list_state
-                            .last_selected_index
-                            .map_or(event::Status::Ignored, |last| {
-                                if let Some(option) = self.options.get(last.0) {
-                                    shell.publish((self.on_selected)(last.0, option.clone()));
-                                    event::Status::Captured
-                                } else {
-                                    event::Status::Ignored
-                                }
-                            });
+                    let click = advanced::mouse::Click::new(cursor, list_state.last_click);
+                    list_state.last_click = Some(click);
+
+                    if let (
+                        Some(on_double_click),
+                        Some(index),
+                        advanced::mouse::click::Kind::Double,
+                    ) = (
+                        self.on_double_click,
+                        list_state.hovered_option,
+                        click.kind(),
+                    ) {
+                        shell.publish(on_double_click(index, self.options[index].clone()));
+                        status = event::Status::Captured;
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                    if let Some(on_right_click) = self.on_right_click {
+                        list_state.hovered_option =
+                            self.option_at(list_state, bounds, viewport, cursor);
+
+                        if let Some(index) = list_state.hovered_option {
+                            shell.publish(on_right_click(index, self.options[index].clone()));
+                            status = event::Status::Captured;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if focused {
+            match event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(named),
+                    ..
+                }) if self.navigate(list_state, named, bounds, viewport, shell) => {
+                    list_state.focus_visible = true;
+                    status = event::Status::Captured;
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                    if modifiers.command() && key.as_ref() == keyboard::Key::Character("a") =>
+                {
+                    if let Some(on_multi_select) = self.on_multi_select {
+                        list_state.selected_options = (0..list_state.rows())
+                            .filter_map(|row| list_state.option_of(row))
+                            .filter(|index| self.is_enabled(*index))
+                            .collect();
+                        shell.publish(on_multi_select(
+                            list_state.selected_options.iter().copied().collect(),
+                        ));
+                        status = event::Status::Captured;
+                    }
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    modifiers,
+                    text: Some(text),
+                    ..
+                }) if self.filter.is_none()
+                    && !modifiers.command()
+                    && !modifiers.alt()
+                    && text.chars().all(|c| !c.is_control()) =>
+                {
+                    let current = list_state.last_selected_index.map(|(index, _)| index);
+
+                    if let Some(index) =
+                        list_state.type_ahead(self.options, &text, current, |index| {
+                            self.is_enabled(index)
+                        })
+                    {
+                        self.select_only(list_state, index, bounds, viewport, shell);
+                    }
+                    status = event::Status::Captured;
                 }
                 _ => {}
             }
@@ -184,15 +1104,83 @@ where
         status
     }
 
+    fn operate(
+        &self,
+        state: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let list_state = state.state.downcast_mut::<ListState>();
+
+        operation.focusable(list_state, self.id);
+        operation.custom(list_state, self.id);
+
+        let options = (0..list_state.rows())
+            .filter_map(|row| list_state.option_of(row))
+            .filter_map(|index| {
+                let option = self.options.get(index)?;
+                let selected = if self.on_multi_select.is_some() {
+                    list_state.selected_options.contains(&index)
+                } else {
+                    list_state.last_selected_index.is_some_and(|u| u.0 == index)
+                };
+
+                Some(
+                    accessibility::Node::new(Role::ListItem)
+                        .name(option.to_string())
+                        .selected(selected)
+                        .disabled(self.disabled || !self.is_enabled(index))
+                        .action(Action::Select),
+                )
+            })
+            .collect();
+        let mut node = accessibility::Node::new(Role::ListBox)
+            .disabled(self.disabled)
+            .action(Action::Focus)
+            .children(options);
+
+        operation.custom(&mut node, self.id);
+    }
+
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        state: &Tree,
         layout: Layout<'_>,
         cursor: Cursor,
-        _viewport: &Rectangle,
-        _renderer: &Renderer,
+        viewport: &Rectangle,
+        renderer: &Renderer,
     ) -> mouse::Interaction {
         let bounds = layout.bounds();
+        let list_state = state.state.downcast_ref::<ListState>();
+
+        if let Some(item_view) = self.item_view {
+            let offset = Vector::new(bounds.x, bounds.y);
+            let interaction = list_state
+                .items
+                .iter()
+                .map(
+                    |Item {
+                         index, tree, node, ..
+                     }| {
+                        item_view(*index, &self.options[*index])
+                            .as_widget()
+                            .mouse_interaction(
+                                tree,
+                                Layout::with_offset(offset, node),
+                                cursor,
+                                viewport,
+                                renderer,
+                            )
+                    },
+                )
+                .max()
+                .unwrap_or_default();
+
+            if interaction != mouse::Interaction::default() {
+                return interaction;
+            }
+        }
 
         if bounds.contains(cursor.position().unwrap_or_default()) {
             mouse::Interaction::Pointer
@@ -208,27 +1196,43 @@ where
         theme: &Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor: Cursor,
+        cursor: Cursor,
         viewport: &Rectangle,
     ) {
         use std::f32;
 
         let bounds = layout.bounds();
-        let option_height = self.text_size + (self.padding * 2.0);
-        let offset = viewport.y - bounds.y;
-        let start = (offset / option_height) as usize;
-        let end = ((offset + viewport.height) / option_height).ceil() as usize;
+        let option_height = self.option_height();
         let list_state = state.state.downcast_ref::<ListState>();
+        let offset = Vector::new(bounds.x, bounds.y);
+        let list_bounds = bounds;
+
+        for row in self.rows_in(list_state, bounds, viewport) {
+            if let Some(header) = list_state.header_of(row) {
+                let bounds = Rectangle {
+                    y: bounds.y + option_height * row as f32,
+                    height: option_height,
+                    ..bounds
+                };
+                self.draw_header(renderer, theme, header, bounds);
+                continue;
+            }
 
-        for i in start..end.min(self.options.len()) {
-            let is_selected = list_state.last_selected_index.is_some_and(|u| u.0 == i);
-            let is_hovered = list_state.hovered_option == Some(i);
+            let Some(i) = list_state.option_of(row) else {
+                continue;
+            };
+            let is_selected = if self.on_multi_select.is_some() {
+                list_state.selected_options.contains(&i)
+            } else {
+                list_state.last_selected_index.is_some_and(|u| u.0 == i)
+            };
+            let is_hovered = !self.disabled && list_state.hovered_option == Some(i);
 
             let bounds = Rectangle {
                 x: bounds.x,
-                y: bounds.y + option_height * i as f32,
+                y: bounds.y + option_height * row as f32,
                 width: bounds.width,
-                height: self.text_size + (self.padding * 2.0),
+                height: option_height,
             };
 
             if is_selected || is_hovered {
@@ -243,24 +1247,56 @@ where
                         shadow: Shadow::default(),
                     },
                     if is_selected {
-                        theme.style(&self.style).selected_background
+                        self.appearance(theme).selected_background
                     } else {
-                        theme.style(&self.style).hovered_background
+                        self.appearance(theme).hovered_background
                     },
                 );
             }
 
-            let text_color = if is_selected {
-                theme.style(&self.style).selected_text_color
+            let text_color = if !self.is_enabled(i) {
+                self.appearance(theme).disabled_text_color
+            } else if is_selected {
+                self.appearance(theme).selected_text_color
             } else if is_hovered {
-                theme.style(&self.style).hovered_text_color
+                self.appearance(theme).hovered_text_color
             } else {
-                theme.style(&self.style).text_color
+                self.appearance(theme).text_color
             };
 
+            if self.on_toggle.is_some() {
+                self.draw_checkbox(renderer, i, bounds, text_color);
+            }
+
+            if let Some(item_view) = self.item_view {
+                let element = item_view(i, &self.options[i]);
+                let style = renderer::Style { text_color };
+                let draw = |renderer: &mut Renderer, tree: &Tree, node: &Node| {
+                    element.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        &style,
+                        Layout::with_offset(offset, node),
+                        cursor,
+                        viewport,
+                    );
+                };
+
+                if let Some(item) = list_state.items.iter().find(|item| item.index == i) {
+                    draw(renderer, &item.tree, &item.node);
+                } else {
+                    let mut tree = Tree::new(&element);
+                    let node =
+                        self.layout_item(&element, &mut tree, renderer, list_bounds.width, row);
+                    draw(renderer, &tree, &node);
+                }
+                continue;
+            }
+
             renderer.fill_text(
                 iced::advanced::text::Text {
-                    content: &list_state.options[i],
+                    content: &self.options[i].to_string(),
                     bounds: Size::new(f32::INFINITY, bounds.height),
                     size: Pixels(self.text_size),
                     font: self.font,
@@ -269,14 +1305,67 @@ where
                     line_height: LineHeight::default(),
                     shaping: iced::widget::text::Shaping::Advanced,
                 },
-                Point::new(bounds.x, bounds.center_y()),
+                Point::new(bounds.x + self.leading(), bounds.center_y()),
                 text_color,
                 bounds,
             );
         }
+
+        if let Some(row) = list_state
+            .hovered_option
+            .or_else(|| list_state.last_selected_index.map(|(index, _)| index))
+            .and_then(|index| list_state.row_of(index))
+            .filter(|_| !self.disabled && list_state.focused && list_state.focus_visible)
+        {
+            let appearance = theme.focused(&self.style);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: list_bounds.y + option_height * row as f32,
+                        height: option_height,
+                        ..list_bounds
+                    },
+                    border: Border {
+                        radius: (0.0).into(),
+                        width: appearance.border_width,
+                        color: appearance.border_color,
+                    },
+                    shadow: Shadow::default(),
+                },
+                Color::TRANSPARENT,
+            );
+        }
+
+        if let Some((header, bounds)) = self.sticky_header(list_state, list_bounds, viewport) {
+            self.draw_header(renderer, theme, header, bounds);
+        }
+
+        if let Some(row) = list_state.drop_row {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: list_bounds.x,
+                        y: list_bounds.y + option_height * row as f32 - 1.0,
+                        width: list_bounds.width,
+                        height: 2.0,
+                    },
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                self.appearance(theme).drop_indicator_color,
+            );
+        }
     }
 }
 
+/// Returns the hash of the value of an option.
+fn hash_of(option: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    option.hash(&mut hasher);
+    hasher.finish()
+}
+
 impl<'a, T, Message, Theme, Renderer> From<List<'a, T, Message, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
@@ -1,6 +1,12 @@
 //! A spinner to suggest something is loading.
 
-use crate::style::spinner::StyleSheet;
+use crate::{
+    core::{
+        animation::{self, Timeline},
+        settings,
+    },
+    style::spinner::StyleSheet,
+};
 
 use iced::{
     advanced::{
@@ -16,7 +22,27 @@ use iced::{
     mouse::Cursor,
     window, Border, Color, Element, Event, Length, Rectangle, Shadow, Size, Vector,
 };
-use std::time::{Duration, Instant};
+use std::{f32::consts::PI, time::Duration};
+
+pub use crate::core::animation::Easing;
+
+/// How many times slower a [`Spinner`] turns when the
+/// [`settings`](crate::settings) ask for reduced motion.
+const REDUCED_MOTION_SLOWDOWN: f32 = 3.0;
+
+/// The animation of a [`Spinner`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SpinnerKind {
+    /// A circle spinning around the center.
+    #[default]
+    Circle,
+    /// Three dots bouncing one after the other.
+    Dots,
+    /// Segments around the center, fading out behind the one spinning.
+    Bars,
+    /// A ring growing from the center and fading out.
+    Pulse,
+}
 
 /// A spinner widget, a circle spinning around the center of the widget.
 #[allow(missing_debug_implementations)]
@@ -29,6 +55,12 @@ pub struct Spinner {
     rate: Duration,
     /// The radius of the spinning circle.
     circle_radius: f32,
+    /// The animation of the [`Spinner`].
+    kind: SpinnerKind,
+    /// The easing curve of the animation.
+    easing: Easing,
+    /// Whether the animation is frozen.
+    paused: bool,
 }
 
 impl Default for Spinner {
@@ -38,6 +70,9 @@ impl Default for Spinner {
             height: Length::Fixed(20.0),
             rate: Duration::from_secs_f32(1.0),
             circle_radius: 2.0,
+            kind: SpinnerKind::default(),
+            easing: Easing::default(),
+            paused: false,
         }
     }
 }
@@ -69,11 +104,45 @@ impl Spinner {
         self.circle_radius = radius;
         self
     }
+
+    /// Sets the duration of a full cycle of the animation of the [`Spinner`].
+    ///
+    /// A zero duration stops the animation.
+    #[must_use]
+    pub fn rate(mut self, rate: Duration) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// Sets the easing curve applied to each cycle of the animation.
+    #[must_use]
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Freezes the animation of the [`Spinner`] and stops requesting redraws,
+    /// e.g. while it is hidden behind other content.
+    #[must_use]
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Sets the animation of the [`Spinner`].
+    ///
+    /// The circle radius is used as the radius of the dots, the half-width of
+    /// the bars and the width of the ring.
+    #[must_use]
+    pub fn kind(mut self, kind: SpinnerKind) -> Self {
+        self.kind = kind;
+        self
+    }
 }
 
 struct SpinnerState {
-    last_update: Instant,
-    t: f32,
+    /// The progress of the current cycle of the animation.
+    cycle: Timeline,
 }
 
 fn is_visible(bounds: &Rectangle) -> bool {
@@ -144,15 +213,92 @@ where
             bounds.height
         } / 2.0;
         let state = state.state.downcast_ref::<SpinnerState>();
+        let t = self.easing.apply(state.cycle.progress());
         let center = bounds.center();
-        let distance_from_center = size - self.circle_radius;
-        let (y, x) = (state.t * std::f32::consts::PI * 2.0).sin_cos();
-        let position = Vector::new(
-            center.x + x * distance_from_center - self.circle_radius,
-            center.y + y * distance_from_center - self.circle_radius,
-        );
+        let radius = self.circle_radius;
+        let color = style.text_color;
+
+        match self.kind {
+            SpinnerKind::Circle => {
+                let distance_from_center = size - radius;
+                let (y, x) = (t * PI * 2.0).sin_cos();
+                let position = Vector::new(
+                    center.x + x * distance_from_center - radius,
+                    center.y + y * distance_from_center - radius,
+                );
+
+                fill_circle(renderer, position, radius, color);
+            }
+            SpinnerKind::Dots => {
+                let height = (size - radius).max(0.0);
+
+                for i in 0..3 {
+                    let phase = (t - i as f32 / 6.0) * PI * 2.0;
+                    let bounce = phase.sin().max(0.0) * height;
+                    let position = Vector::new(
+                        center.x + (i as f32 - 1.0) * radius * 3.0 - radius,
+                        center.y + height / 2.0 - bounce - radius,
+                    );
+
+                    fill_circle(renderer, position, radius, color);
+                }
+            }
+            SpinnerKind::Bars => {
+                const BARS: usize = 8;
+                let distance_from_center = size - radius;
+                let head = (t * BARS as f32).floor();
+
+                for i in 0..BARS {
+                    let (y, x) = (i as f32 / BARS as f32 * PI * 2.0).sin_cos();
+                    let behind = (head - i as f32).rem_euclid(BARS as f32);
 
-        fill_circle(renderer, position, self.circle_radius, style.text_color);
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: center.x + x * distance_from_center - radius,
+                                y: center.y + y * distance_from_center - radius,
+                                width: radius * 2.0,
+                                height: radius * 2.0,
+                            },
+                            border: Border {
+                                radius: (radius / 2.0).into(),
+                                width: 0.0,
+                                color: Color::TRANSPARENT,
+                            },
+                            shadow: Shadow::default(),
+                        },
+                        Color {
+                            a: color.a * (1.0 - behind / BARS as f32),
+                            ..color
+                        },
+                    );
+                }
+            }
+            SpinnerKind::Pulse => {
+                let ring = size * t;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: center.x - ring,
+                            y: center.y - ring,
+                            width: ring * 2.0,
+                            height: ring * 2.0,
+                        },
+                        border: Border {
+                            radius: ring.into(),
+                            width: radius.min(ring),
+                            color: Color {
+                                a: color.a * (1.0 - t),
+                                ..color
+                            },
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+        }
     }
 
     fn tag(&self) -> Tag {
@@ -161,8 +307,7 @@ where
 
     fn state(&self) -> State {
         State::new(SpinnerState {
-            last_update: Instant::now(),
-            t: 0.0,
+            cycle: Timeline::default(),
         })
     }
 
@@ -177,30 +322,23 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> Status {
-        const FRAMES_PER_SECOND: u64 = 60;
-
         let bounds = layout.bounds();
 
         if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
-            if is_visible(&bounds) {
-                let state = state.state.downcast_mut::<SpinnerState>();
-                let duration = (now - state.last_update).as_secs_f32();
-                let increment = if self.rate == Duration::ZERO {
-                    0.0
+            let state = state.state.downcast_mut::<SpinnerState>();
+
+            if self.paused {
+                // Resume from where the animation was frozen.
+                state.cycle.stop();
+            } else if is_visible(&bounds) {
+                let period = if settings::reduced_motion() {
+                    self.rate.mul_f32(REDUCED_MOTION_SLOWDOWN)
                 } else {
-                    duration * 1.0 / self.rate.as_secs_f32()
+                    self.rate
                 };
 
-                state.t += increment;
-
-                if state.t > 1.0 {
-                    state.t -= 1.0;
-                }
-
-                shell.request_redraw(window::RedrawRequest::At(
-                    now + Duration::from_millis(1000 / FRAMES_PER_SECOND),
-                ));
-                state.last_update = now;
+                state.cycle.cycle(period, now);
+                animation::request_frame(shell, now);
 
                 return Status::Captured;
             }
@@ -7,20 +7,28 @@ use iced::{
         layout::{Limits, Node},
         overlay, renderer,
         widget::{
+            self,
+            operation::Focusable,
             tree::{State, Tag},
             Operation, Tree,
         },
         Clipboard, Layout, Shell, Widget,
     },
     event,
+    keyboard::{self, key::Named, Key},
     mouse::{self, Cursor},
     touch,
     widget::{container, Container, Row},
-    Border, Color, Element, Event, Length, Padding, Point, Rectangle, Shadow, Size, Vector,
+    Border, Color, Command, Element, Event, Length, Padding, Point, Rectangle, Shadow, Size,
+    Vector,
 };
+use std::any::Any;
 
 pub use crate::style::split::{Appearance, StyleSheet};
 
+/// The distance the divider of a focused [`Split`] moves with the arrow keys.
+const KEYBOARD_STEP: f32 = 10.0;
+
 /// A split can divide the available space by half to display two different elements.
 /// It can split horizontally or vertically.
 ///
@@ -70,6 +78,10 @@ where
     on_resize: Box<dyn Fn(u16) -> Message>,
     /// The style of the [`Split`].
     style: <Theme as StyleSheet>::Style,
+    /// The id of the [`Split`], to focus its divider.
+    id: Option<widget::Id>,
+    /// Whether the [`Split`] is disabled.
+    disabled: bool,
 }
 
 impl<'a, Message, Theme, Renderer> Split<'a, Message, Theme, Renderer>
@@ -117,6 +129,8 @@ where
             min_size_second: 5,
             on_resize: Box::new(on_resize),
             style: <Theme as StyleSheet>::Style::default(),
+            id: None,
+            disabled: false,
         }
     }
 
@@ -165,8 +179,26 @@ where
 
     /// Sets the style of the [`Split`].
     #[must_use]
-    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
-        self.style = style;
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets whether the [`Split`] is disabled. A disabled [`Split`] is
+    /// dimmed, its divider cannot be moved, and its elements do not receive
+    /// the events.
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the id of the [`Split`], to focus its divider with
+    /// [`focus`](crate::focus::focus) or to move it with [`set_position`].
+    /// The focused divider is moved with the arrow keys along its [`Axis`].
+    #[must_use]
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
         self
     }
 }
@@ -220,7 +252,18 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        if self.disabled {
+            return event::Status::Ignored;
+        }
+
         let split_state: &mut SplitState = state.state.downcast_mut();
+
+        // An operation cannot publish messages, so the requested position is
+        // published with the next event.
+        if let Some(position) = split_state.requested.take() {
+            shell.publish((self.on_resize)(position));
+        }
+
         let mut children = layout.children();
 
         let first_layout = children
@@ -240,9 +283,12 @@ where
         let divider_layout = children
             .next()
             .expect("Native: Layout should have a divider layout");
+        let mut divider_status = event::Status::Ignored;
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                split_state.is_focused = false;
+
                 if divider_layout
                     .bounds()
                     .contains(cursor.position().unwrap_or_default())
@@ -252,21 +298,40 @@ where
             }
 
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerLifted { .. }) => {
-                if split_state.dragging {
-                    split_state.dragging = false;
-                }
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if split_state.dragging =>
+            {
+                split_state.dragging = false;
             }
 
             Event::Mouse(mouse::Event::CursorMoved { position })
-            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
-                if split_state.dragging {
-                    let position = match self.axis {
-                        Axis::Horizontal => position.y,
-                        Axis::Vertical => position.x,
-                    };
-
-                    shell.publish((self.on_resize)(position as u16));
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if split_state.dragging =>
+            {
+                let position = match self.axis {
+                    Axis::Horizontal => position.y,
+                    Axis::Vertical => position.x,
+                };
+
+                shell.publish((self.on_resize)(position as u16));
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(key),
+                ..
+            }) if split_state.is_focused => {
+                let bounds = divider_layout.bounds();
+                let position = match (self.axis, key) {
+                    (Axis::Horizontal, Named::ArrowUp) => Some(bounds.center_y() - KEYBOARD_STEP),
+                    (Axis::Horizontal, Named::ArrowDown) => Some(bounds.center_y() + KEYBOARD_STEP),
+                    (Axis::Vertical, Named::ArrowLeft) => Some(bounds.center_x() - KEYBOARD_STEP),
+                    (Axis::Vertical, Named::ArrowRight) => Some(bounds.center_x() + KEYBOARD_STEP),
+                    _ => None,
+                };
+
+                if let Some(position) = position {
+                    shell.publish((self.on_resize)(position.max(0.0) as u16));
+                    divider_status = event::Status::Captured;
                 }
             }
 
@@ -287,7 +352,7 @@ where
             viewport,
         );
 
-        first_status.merge(second_status)
+        first_status.merge(divider_status).merge(second_status)
     }
 
     fn mouse_interaction(
@@ -298,6 +363,10 @@ where
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
+        if self.disabled {
+            return mouse::Interaction::default();
+        }
+
         let mut children = layout.children();
         let first_layout = children
             .next()
@@ -351,6 +420,17 @@ where
         let split_state: &SplitState = state.state.downcast_ref();
         // TODO: clipping!
         let mut children = layout.children();
+        let active = if self.disabled {
+            theme.disabled(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+        let is_hovered = |layout: Layout<'_>| {
+            !self.disabled
+                && layout
+                    .bounds()
+                    .contains(cursor.position().unwrap_or_default())
+        };
 
         // Background
         renderer.fill_quad(
@@ -358,13 +438,12 @@ where
                 bounds: layout.bounds(),
                 border: Border {
                     radius: (0.0).into(),
-                    width: theme.active(&self.style).border_width,
-                    color: theme.active(&self.style).border_color,
+                    width: active.border_width,
+                    color: active.border_color,
                 },
                 shadow: Shadow::default(),
             },
-            theme
-                .active(&self.style)
+            active
                 .background
                 .unwrap_or_else(|| Color::TRANSPARENT.into()),
         );
@@ -384,13 +463,10 @@ where
                 },
                 shadow: Shadow::default(),
             },
-            if first_layout
-                .bounds()
-                .contains(cursor.position().unwrap_or_default())
-            {
+            if is_hovered(first_layout) {
                 theme.hovered(&self.style).first_background
             } else {
-                theme.active(&self.style).first_background
+                active.first_background
             }
             .unwrap_or_else(|| Color::TRANSPARENT.into()),
         );
@@ -424,13 +500,10 @@ where
                 },
                 shadow: Shadow::default(),
             },
-            if second_layout
-                .bounds()
-                .contains(cursor.position().unwrap_or_default())
-            {
+            if is_hovered(second_layout) {
                 theme.hovered(&self.style).second_background
             } else {
-                theme.active(&self.style).second_background
+                active.second_background
             }
             .unwrap_or_else(|| Color::TRANSPARENT.into()),
         );
@@ -446,15 +519,16 @@ where
         );
 
         // Divider
-        let divider_style = if split_state.dragging {
+        let divider_style = if self.disabled {
+            active
+        } else if split_state.dragging {
             theme.dragged(&self.style)
-        } else if divider_layout
-            .bounds()
-            .contains(cursor.position().unwrap_or_default())
-        {
+        } else if split_state.is_focused {
+            theme.focused(&self.style)
+        } else if is_hovered(divider_layout) {
             theme.hovered(&self.style)
         } else {
-            theme.active(&self.style)
+            active
         };
 
         renderer.fill_quad(
@@ -483,6 +557,13 @@ where
         let _divider_layout = children.next().expect("Missing Split Divider");
         let second_layout = children.next().expect("Missing Split Second window");
 
+        if !self.disabled {
+            let split_state = state.state.downcast_mut::<SplitState>();
+
+            operation.focusable(split_state, self.id.as_ref());
+            operation.custom(split_state, self.id.as_ref());
+        }
+
         let (first_state, second_state) = state.children.split_at_mut(1);
 
         self.first
@@ -701,6 +782,11 @@ where
 pub struct SplitState {
     /// If the divider is dragged by the user.
     dragging: bool,
+    /// If the divider is focused with the keyboard.
+    is_focused: bool,
+    /// The position of the divider to publish at the next event, requested
+    /// with [`set_position`].
+    requested: Option<u16>,
 }
 
 impl SplitState {
@@ -711,12 +797,31 @@ impl SplitState {
     ///     - The [`Axis`] to split at.
     #[must_use]
     pub const fn new() -> Self {
-        Self { dragging: false }
+        Self {
+            dragging: false,
+            is_focused: false,
+            requested: None,
+        }
+    }
+}
+
+impl Focusable for SplitState {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
     }
 }
 
 /// The axis to split at.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Axis {
     /// Split horizontally.
     Horizontal,
@@ -729,3 +834,45 @@ impl Default for Axis {
         Self::Vertical
     }
 }
+
+/// Produces an [`Operation`] requesting the given position of the divider of
+/// the [`Split`] with the given [`Id`](widget::Id).
+pub(crate) fn request<T>(target: widget::Id, position: u16) -> impl Operation<T> {
+    struct Apply {
+        target: widget::Id,
+        position: u16,
+    }
+
+    impl<T> Operation<T> for Apply {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if id != Some(&self.target) {
+                return;
+            }
+
+            if let Some(state) = state.downcast_mut::<SplitState>() {
+                state.requested = Some(self.position);
+            }
+        }
+    }
+
+    Apply { target, position }
+}
+
+/// Produces a [`Command`] that moves the divider of the [`Split`] with the
+/// given [`Id`](widget::Id) to `position`.
+///
+/// The [`Split`] publishes its resize message with the `position` at the next
+/// event it handles, like when the divider is dragged, so that the
+/// application keeps the position of the divider.
+pub fn set_position<Message: 'static>(id: widget::Id, position: u16) -> Command<Message> {
+    Command::widget(request(id, position))
+}
@@ -0,0 +1,310 @@
+//! Show the status of something, like a user or a service, with a colored dot.
+//!
+//! *This API requires the following crate features to be activated: `status_dot`*
+
+use crate::core::{
+    animation::{self, Timeline},
+    settings,
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{self, Paragraph, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    mouse::Cursor,
+    widget::text::{LineHeight, Shaping},
+    window, Border, Color, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size,
+};
+use std::time::Duration;
+
+pub use crate::style::status_dot::{Appearance, Status, StyleSheet};
+
+/// The duration of a pulse of a [`StatusDot`].
+const PULSE_PERIOD: Duration = Duration::from_millis(1600);
+/// The size reached by the pulsing ring, relative to the dot.
+const PULSE_SCALE: f32 = 2.5;
+
+/// A small colored dot showing a [`Status`], with an optional label next to
+/// it and an optional ring pulsing around it.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::status_dot::{Status, StatusDot};
+/// #
+/// let status_dot = StatusDot::new(Status::Online).label("Online").pulse(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct StatusDot<Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The status shown.
+    status: Status,
+    /// The label next to the dot.
+    label: Option<String>,
+    /// Whether a ring pulses around the dot.
+    pulse: bool,
+    /// The diameter of the dot.
+    size: f32,
+    /// The space between the dot and the label.
+    spacing: f32,
+    text_size: f32,
+    font: Font,
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`StatusDot`].
+#[derive(Debug, Default)]
+struct State {
+    /// The progress of the pulse, from 0 to 1.
+    pulse: Timeline,
+}
+
+impl<Theme> StatusDot<Theme>
+where
+    Theme: StyleSheet,
+{
+    /// Creates a new [`StatusDot`] showing the `status`.
+    #[must_use]
+    pub fn new(status: Status) -> Self {
+        Self {
+            status,
+            label: None,
+            pulse: false,
+            size: settings::scaled(10.0),
+            spacing: settings::spaced(6.0),
+            text_size: settings::scaled(14.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the label shown next to the dot of the [`StatusDot`].
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets whether a ring pulses around the dot of the [`StatusDot`], to
+    /// draw attention to it. The ring extends beyond the bounds of the dot,
+    /// and is not shown when the [`settings`](crate::settings) ask for
+    /// reduced motion.
+    #[must_use]
+    pub fn pulse(mut self, pulse: bool) -> Self {
+        self.pulse = pulse;
+        self
+    }
+
+    /// Sets the diameter of the dot of the [`StatusDot`].
+    #[must_use]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the space between the dot and the label of the [`StatusDot`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the label of the [`StatusDot`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the label of the [`StatusDot`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`StatusDot`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the text of the label.
+    fn text<'b>(&self, content: &'b str, bounds: Size) -> Text<'b, Font> {
+        Text {
+            content,
+            bounds,
+            size: Pixels(self.text_size),
+            line_height: LineHeight::default(),
+            font: self.font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+}
+
+/// Fills a circle of the given `diameter` centered on `center`.
+fn fill_circle<Renderer>(
+    renderer: &mut Renderer,
+    center: Point,
+    diameter: f32,
+    color: Color,
+    border: Border,
+) where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        Quad {
+            bounds: Rectangle::new(
+                Point::new(center.x - diameter / 2.0, center.y - diameter / 2.0),
+                Size::new(diameter, diameter),
+            ),
+            border: Border {
+                radius: (diameter / 2.0).into(),
+                ..border
+            },
+            shadow: Shadow::default(),
+        },
+        color,
+    );
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for StatusDot<Theme>
+where
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let size = self.label.as_ref().map_or_else(
+            || Size::new(self.size, self.size),
+            |label| {
+                let label =
+                    Renderer::Paragraph::with_text(self.text(label, Size::INFINITY)).min_bounds();
+
+                Size::new(
+                    self.size + self.spacing + label.width,
+                    self.size.max(label.height),
+                )
+            },
+        );
+
+        Node::new(limits.resolve(Length::Shrink, Length::Shrink, size))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !self.pulse || settings::reduced_motion() {
+            state.pulse.stop();
+            return event::Status::Ignored;
+        }
+
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            state.pulse.cycle(PULSE_PERIOD, now);
+            animation::request_frame(shell, now);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.active(&self.style, self.status);
+        let center = Point::new(bounds.x + self.size / 2.0, bounds.center_y());
+
+        if self.pulse && !settings::reduced_motion() {
+            let t = state.pulse.progress();
+            let diameter = self.size * (PULSE_SCALE - 1.0).mul_add(t, 1.0);
+
+            fill_circle(
+                renderer,
+                center,
+                diameter,
+                Color {
+                    a: appearance.pulse_color.a * (1.0 - t),
+                    ..appearance.pulse_color
+                },
+                Border::default(),
+            );
+        }
+
+        fill_circle(
+            renderer,
+            center,
+            self.size,
+            appearance.dot_color,
+            Border {
+                width: appearance.border_width,
+                color: appearance.border_color,
+                ..Border::default()
+            },
+        );
+
+        if let Some(label) = &self.label {
+            let x = bounds.x + self.size + self.spacing;
+            let label_bounds = Rectangle {
+                x,
+                width: (bounds.x + bounds.width - x).max(0.0),
+                ..bounds
+            };
+
+            renderer.fill_text(
+                self.text(label, label_bounds.size()),
+                Point::new(label_bounds.x, label_bounds.center_y()),
+                appearance.label_color,
+                label_bounds,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<StatusDot<Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + renderer::Renderer + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(status_dot: StatusDot<Theme>) -> Self {
+        Element::new(status_dot)
+    }
+}
@@ -0,0 +1,316 @@
+//! Override the default styling of the widgets of a part of the interface,
+//! like a compact and dark sidebar.
+//!
+//! *This API requires the following crate features to be activated: `style_scope`*
+
+use super::overlay::style_scope::StyleScopeOverlay;
+use crate::core::settings::{Corners, Density, Scope};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay, renderer,
+        widget::{Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    Color, Element, Event, Font, Length, Rectangle, Size, Vector,
+};
+
+/// The [`settings`](crate::settings) overridden inside a [`StyleScope`].
+///
+/// The settings left unset are inherited from the enclosing scope, or from the
+/// crate-wide [`settings`](crate::settings) outside any scope.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Overrides {
+    /// The scale of the default sizes.
+    scale: Option<f32>,
+    /// The density of the paddings and spacings.
+    density: Option<Density>,
+    /// The shape of the corners.
+    corners: Option<Corners>,
+    /// The default font.
+    font: Option<Font>,
+}
+
+impl Overrides {
+    /// Creates new [`Overrides`] inheriting all the settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the scale of the default text sizes, icon sizes, paddings and
+    /// spacings of the widgets.
+    #[must_use]
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Sets the [`Density`] of the widgets.
+    #[must_use]
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = Some(density);
+        self
+    }
+
+    /// Sets the [`Corners`] of the widgets.
+    #[must_use]
+    pub fn corners(mut self, corners: Corners) -> Self {
+        self.corners = Some(corners);
+        self
+    }
+
+    /// Sets the default font of the text of the widgets.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Calls `f` with the settings overridden on the current thread, and
+    /// restores them afterwards, even if `f` panics.
+    pub(crate) fn apply<T>(&self, f: impl FnOnce() -> T) -> T {
+        let _scope = Scope {
+            scale: self.scale,
+            density: self.density,
+            corners: self.corners,
+            font: self.font,
+            ..Scope::default()
+        }
+        .enter();
+
+        f()
+    }
+}
+
+/// A container overriding the default styling of the widgets of the crate
+/// inside it.
+///
+/// The content is built with the [`Overrides`] applied, so that the default
+/// sizes, paddings, spacings and fonts of its widgets follow them, and is laid
+/// out and drawn with them too, for the default corners of their appearances.
+/// A [`StyleScope`] can also draw its content with its own theme and text
+/// color. The sizes, fonts and styles set on a widget are kept.
+///
+/// The crate-wide settings are left unchanged: the overrides only apply on the
+/// thread building or handling the content, while it does.
+///
+/// # Example
+/// ```ignore
+/// # use iced::{widget::column, Theme};
+/// # use iced_aw::{style_scope::{Overrides, StyleScope}, Density};
+/// #
+/// let sidebar = StyleScope::new(
+///     Overrides::new().density(Density::Compact).scale(0.9),
+///     || column![/* ... */].into(),
+/// )
+/// .theme(Theme::Dark);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct StyleScope<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// The settings overridden inside the scope.
+    overrides: Overrides,
+    /// The theme of the content, if it is not the one of the application.
+    theme: Option<Theme>,
+    /// The default color of the text of the content.
+    text_color: Option<Color>,
+    /// The content of the scope.
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> StyleScope<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Creates a new [`StyleScope`] with the given [`Overrides`], building
+    /// its content with them applied.
+    pub fn new<F, E>(overrides: Overrides, content: F) -> Self
+    where
+        F: FnOnce() -> E,
+        E: Into<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self {
+            overrides,
+            theme: None,
+            text_color: None,
+            content: overrides.apply(|| content().into()),
+        }
+    }
+
+    /// Sets the theme the content of the [`StyleScope`] is drawn with,
+    /// instead of the one of the application.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Sets the default color of the text of the content of the
+    /// [`StyleScope`].
+    #[must_use]
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.text_color = Some(color);
+        self
+    }
+}
+
+/// Returns the renderer `style` with the text color of a scope, if it has one.
+pub(crate) fn scoped_style(style: &renderer::Style, text_color: Option<Color>) -> renderer::Style {
+    renderer::Style {
+        text_color: text_color.unwrap_or(style.text_color),
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for StyleScope<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.overrides.apply(|| {
+            self.content
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, limits)
+        })
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let content = &mut self.content;
+
+        self.overrides.apply(|| {
+            content.as_widget_mut().on_event(
+                &mut state.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            )
+        })
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.overrides.apply(|| {
+            self.content.as_widget().mouse_interaction(
+                &state.children[0],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            )
+        })
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.overrides.apply(|| {
+            self.content.as_widget().draw(
+                &state.children[0],
+                renderer,
+                self.theme.as_ref().unwrap_or(theme),
+                &scoped_style(style, self.text_color),
+                layout,
+                cursor,
+                viewport,
+            );
+        });
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.overrides.apply(|| {
+            self.content
+                .as_widget()
+                .operate(&mut state.children[0], layout, renderer, operation);
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let overrides = self.overrides;
+        let theme = self.theme.as_ref();
+        let text_color = self.text_color;
+
+        overrides
+            .apply(|| {
+                self.content.as_widget_mut().overlay(
+                    &mut state.children[0],
+                    layout,
+                    renderer,
+                    translation,
+                )
+            })
+            .map(|content| StyleScopeOverlay::new(content, overrides, theme, text_color).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<StyleScope<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a,
+{
+    fn from(style_scope: StyleScope<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(style_scope)
+    }
+}
@@ -6,25 +6,38 @@
 //! *This API requires the following crate features to be activated: `tab_bar`*
 
 pub mod tab_label;
-use crate::core::icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT};
+use crate::core::{
+    accessibility::{self, Action, Role},
+    animation::{self, Spring},
+    hit_test,
+    icons::{bootstrap::icon_to_string, Bootstrap, IconHandle, IconRenderer, BOOTSTRAP_FONT},
+    settings,
+};
 
 use iced::{
     advanced::{
         layout::{Limits, Node},
         renderer,
-        widget::Tree,
+        text::Paragraph,
+        widget::{
+            self,
+            operation::Focusable,
+            tree::{self, Tag},
+            Operation, Tree,
+        },
         Clipboard, Layout, Shell, Widget,
     },
-    alignment::{self, Horizontal, Vertical},
+    alignment::{Horizontal, Vertical},
     event,
+    keyboard::{self, key::Named},
     mouse::{self, Cursor},
     touch,
-    widget::{text, text::LineHeight, Column, Row, Text},
-    Alignment, Background, Border, Color, Element, Event, Font, Length, Pixels, Point, Rectangle,
-    Shadow, Size,
+    widget::{text, text::LineHeight},
+    window, Background, Border, Color, Command, Element, Event, Font, Length, Pixels, Point,
+    Rectangle, Shadow, Size, Vector,
 };
 
-use std::marker::PhantomData;
+use std::{any::Any, marker::PhantomData};
 
 pub use crate::style::tab_bar::{Appearance, StyleSheet};
 pub use tab_label::TabLabel;
@@ -109,12 +122,65 @@ where
     style: <Theme as StyleSheet>::Style,
     /// Where the icon is placed relative to text
     position: Position,
+    /// The id of the [`TabBar`].
+    id: Option<widget::Id>,
     #[allow(clippy::missing_docs_in_private_items)]
     _renderer: PhantomData<Renderer>,
 }
 
+/// The height of the indicator drawn under the active tab.
+const INDICATOR_HEIGHT: f32 = 3.0;
+
+/// The state of a [`TabBar`].
+struct State<P> {
+    /// Whether the [`TabBar`] is focused for the keyboard.
+    is_focused: bool,
+    /// The tab focused for the keyboard, if it is not the active tab.
+    focused_tab: Option<usize>,
+    /// The position of the indicator, as the index of the tab it is under.
+    indicator: Spring,
+    /// The shaped texts of the labels, reshaped only when their content, font
+    /// or size change.
+    labels: Vec<P>,
+    /// The bounds of the tabs, relative to the [`TabBar`] and ordered from
+    /// left to right, to find the tab under the cursor.
+    tabs: Vec<Rectangle>,
+    /// The bounds of the close buttons of the tabs, relative to the
+    /// [`TabBar`].
+    crosses: Vec<Rectangle>,
+    /// The scrolling of the tabs, when they are wider than the [`TabBar`].
+    scroll: Scroll,
+}
+
+/// The horizontal scrolling of the tabs of a [`TabBar`] wider than the bar.
+#[derive(Debug, Default)]
+struct Scroll {
+    /// The distance the tabs are scrolled to the left.
+    offset: f32,
+    /// The tab to scroll into view at the next layout.
+    reveal: Option<usize>,
+}
+
+impl<P> Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.focused_tab = None;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.focused_tab = None;
+    }
+}
+
 #[derive(Clone, Copy, Default)]
-/// The [`Position`] of the icon relative to text, this enum is only relative if [`TabLabel::IconText`] is used.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The [`Position`] of the icon relative to text, this enum is only relative if [`TabLabel::IconText`]
+/// or [`TabLabel::IconHandleText`] is used.
 pub enum Position {
     /// Icon is placed above of the text.
     Top,
@@ -129,7 +195,7 @@ pub enum Position {
 
 impl<Message, TabId, Theme, Renderer> TabBar<Message, TabId, Theme, Renderer>
 where
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Renderer: renderer::Renderer + IconRenderer,
     Theme: StyleSheet,
     TabId: Eq + Clone,
 {
@@ -168,15 +234,16 @@ where
             tab_width: Length::Fill,
             height: Length::Shrink,
             max_height: u32::MAX as f32,
-            icon_size: DEFAULT_ICON_SIZE,
-            text_size: DEFAULT_TEXT_SIZE,
-            close_size: DEFAULT_CLOSE_SIZE,
-            padding: DEFAULT_PADDING,
-            spacing: DEFAULT_SPACING,
+            icon_size: settings::scaled(DEFAULT_ICON_SIZE),
+            text_size: settings::scaled(DEFAULT_TEXT_SIZE),
+            close_size: settings::scaled(DEFAULT_CLOSE_SIZE),
+            padding: settings::spaced(DEFAULT_PADDING),
+            spacing: settings::spaced(DEFAULT_SPACING),
             font: None,
             text_font: None,
             style: <Theme as StyleSheet>::Style::default(),
             position: Position::default(),
+            id: None,
             _renderer: PhantomData,
         }
     }
@@ -223,8 +290,8 @@ where
     /// Sets the font of the icons of the
     /// [`TabLabel`](crate::tab_bar::TabLabel)s of the [`TabBar`].
     #[must_use]
-    pub fn icon_font(mut self, font: Font) -> Self {
-        self.font = Some(font);
+    pub fn icon_font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
         self
     }
 
@@ -235,6 +302,17 @@ where
         self
     }
 
+    /// Sets the id of the [`TabBar`], to focus it with an operation or to
+    /// scroll its tabs with [`scroll_to`].
+    ///
+    /// While the [`TabBar`] is focused, the arrow keys move the focus ring
+    /// between the tabs, and Enter or Space selects the focused tab.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the maximum height of the [`TabBar`].
     #[must_use]
     pub fn max_height(mut self, max_height: f32) -> Self {
@@ -318,6 +396,7 @@ where
 
     #[must_use]
     /// Sets the [`Position`] of the Icon next to Text, Only used in [`TabLabel::IconText`]
+    /// and [`TabLabel::IconHandleText`]
     pub fn set_position(mut self, position: Position) -> Self {
         self.position = position;
         self
@@ -325,8 +404,8 @@ where
 
     /// Sets the style of the [`TabBar`].
     #[must_use]
-    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
-        self.style = style;
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
         self
     }
 
@@ -336,182 +415,212 @@ where
         self.width = width;
         self
     }
+
+    /// Updates the shaped texts of the labels, reshaping only the texts whose
+    /// content, font or size changed since the last layout.
+    fn shape_labels(&self, labels: &mut Vec<Renderer::Paragraph>) {
+        labels.resize_with(self.tab_labels.len(), Renderer::Paragraph::default);
+
+        for (paragraph, tab_label) in labels.iter_mut().zip(&self.tab_labels) {
+            if let Some(text) = tab_label.text() {
+                paragraph.update(iced::advanced::text::Text {
+                    content: text,
+                    bounds: Size::INFINITY,
+                    size: Pixels(self.text_size),
+                    font: self.text_font.unwrap_or_default(),
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    line_height: LineHeight::Relative(1.3),
+                    shaping: iced::advanced::text::Shaping::Advanced,
+                });
+            }
+        }
+    }
 }
 
 impl<Message, TabId, Theme, Renderer> Widget<Message, Theme, Renderer>
     for TabBar<Message, TabId, Theme, Renderer>
 where
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Renderer: renderer::Renderer + IconRenderer,
     Theme: StyleSheet + text::StyleSheet,
     TabId: Eq + Clone,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            is_focused: false,
+            focused_tab: None,
+            indicator: Spring::new(self.active_tab as f32),
+            labels: Vec::new(),
+            tabs: Vec::new(),
+            crosses: Vec::new(),
+            scroll: Scroll::default(),
+        })
+    }
+
     fn size(&self) -> Size<Length> {
         Size::new(self.width, self.height)
     }
 
-    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
-        fn layout_icon<Theme, Renderer>(
-            icon: &char,
-            size: f32,
-            font: Option<Font>,
-        ) -> Text<'_, Theme, Renderer>
-        where
-            Renderer: iced::advanced::text::Renderer,
-            Renderer::Font: From<Font>,
-            Theme: iced::widget::text::StyleSheet,
-        {
-            Text::<Theme, Renderer>::new(icon.to_string())
-                .size(size)
-                .font(font.unwrap_or_default())
-                .horizontal_alignment(alignment::Horizontal::Center)
-                .vertical_alignment(alignment::Vertical::Center)
-                .shaping(iced::advanced::text::Shaping::Advanced)
-                .width(Length::Shrink)
-        }
+    fn layout(&self, tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let labels = &mut tree
+            .state
+            .downcast_mut::<State<Renderer::Paragraph>>()
+            .labels;
+        self.shape_labels(labels);
+
+        let limits = limits.loose().width(self.width).height(self.height);
+        let max = limits.max();
+        let padding = 2.0 * self.padding;
+        let close = if self.on_close.is_some() {
+            self.close_size * 1.3 + 1.0
+        } else {
+            0.0
+        };
+        let spacing = self.spacing * self.tab_labels.len().saturating_sub(1) as f32;
+        let fill_width = (max.width - spacing) / self.tab_labels.len().max(1) as f32;
 
-        fn layout_text<Theme, Renderer>(
-            text: &str,
-            size: f32,
-            font: Option<Font>,
-        ) -> Text<'_, Theme, Renderer>
-        where
-            Renderer: iced::advanced::text::Renderer,
-            Renderer::Font: From<Font>,
-            Theme: iced::widget::text::StyleSheet,
-        {
-            Text::<Theme, Renderer>::new(text)
-                .size(size)
-                .font(font.unwrap_or_default())
-                .horizontal_alignment(alignment::Horizontal::Center)
-                .vertical_alignment(alignment::Vertical::Center)
-                .shaping(text::Shaping::Advanced)
-                .width(Length::Shrink)
+        let mut x = 0.0;
+        let mut tabs: Vec<Node> = self
+            .tab_labels
+            .iter()
+            .zip(labels.iter())
+            .map(|(tab_label, text)| {
+                let (content, parts) = label_content(
+                    tab_label,
+                    self.position,
+                    |icon| {
+                        icon.measure::<Renderer>(
+                            self.icon_size,
+                            self.font.unwrap_or(BOOTSTRAP_FONT),
+                        )
+                    },
+                    text.min_bounds(),
+                );
+
+                let width = match self.tab_width {
+                    Length::Fixed(width) => width,
+                    Length::Fill | Length::FillPortion(_) if fill_width.is_finite() => fill_width,
+                    _ => content.width + close + padding,
+                };
+                let height = match self.height {
+                    Length::Shrink => content.height,
+                    _ if max.height.is_finite() => (max.height - padding).max(0.0),
+                    _ => content.height,
+                };
+
+                let label = Size::new((width - padding - close).max(0.0), height);
+                let offset = Vector::new(
+                    (label.width - content.width) / 2.0,
+                    (label.height - content.height) / 2.0,
+                );
+                let tab_height = height.max(close) + padding;
+
+                let mut children = vec![Node::with_children(
+                    label,
+                    parts
+                        .into_iter()
+                        .map(|part| part.translate(offset))
+                        .collect(),
+                )
+                .move_to(Point::new(self.padding, (tab_height - height) / 2.0))];
+
+                if self.on_close.is_some() {
+                    children.push(Node::new(Size::new(close, close)).move_to(Point::new(
+                        self.padding + label.width,
+                        (tab_height - close) / 2.0,
+                    )));
+                }
+
+                let tab = Node::with_children(Size::new(width, tab_height), children)
+                    .move_to(Point::new(x, 0.0));
+                x += width + self.spacing;
+
+                tab
+            })
+            .collect();
+
+        let content = Size::new(
+            (x - self.spacing).max(0.0),
+            tabs.iter().map(|tab| tab.size().height).fold(0.0, f32::max),
+        );
+        let size = limits.resolve(self.width, self.height, content);
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let scroll = &mut state.scroll;
+
+        if let Some(tab) = scroll.reveal.take().and_then(|index| tabs.get(index)) {
+            let bounds = tab.bounds();
+            scroll.offset = scroll
+                .offset
+                .max(bounds.x + bounds.width - size.width)
+                .min(bounds.x);
+        }
+        scroll.offset = scroll
+            .offset
+            .clamp(0.0, (content.width - size.width).max(0.0));
+
+        for tab in &mut tabs {
+            let y = (size.height - tab.size().height) / 2.0;
+            let position = tab.bounds().position();
+            tab.move_to_mut(Point::new(position.x - scroll.offset, y));
         }
 
-        let row = self
+        state.tabs.clear();
+        state.tabs.extend(tabs.iter().map(Node::bounds));
+        state.crosses.clear();
+        state.crosses.extend(tabs.iter().filter_map(|tab| {
+            let cross = tab.children().get(1)?;
+            Some(cross.bounds() + (tab.bounds().position() - Point::ORIGIN))
+        }));
+
+        Node::with_children(size, tabs)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        operation.focusable(state, self.id.as_ref());
+        operation.custom(&mut state.scroll, self.id.as_ref());
+
+        let tabs = self
             .tab_labels
             .iter()
-            .fold(Row::<Message, Theme, Renderer>::new(), |row, tab_label| {
-                let mut label_row = Row::new()
-                    .push(
-                        match tab_label {
-                            TabLabel::Icon(icon) => Column::new()
-                                .align_items(Alignment::Center)
-                                .push(layout_icon(icon, self.icon_size + 1.0, self.font)),
-
-                            TabLabel::Text(text) => Column::new()
-                                .padding(5.0)
-                                .align_items(Alignment::Center)
-                                .push(layout_text(text, self.text_size + 1.0, self.text_font)),
-
-                            TabLabel::IconText(icon, text) => {
-                                let mut column = Column::new().align_items(Alignment::Center);
-
-                                match self.position {
-                                    Position::Top => {
-                                        column = column
-                                            .push(layout_icon(
-                                                icon,
-                                                self.icon_size + 1.0,
-                                                self.font,
-                                            ))
-                                            .push(layout_text(
-                                                text,
-                                                self.text_size + 1.0,
-                                                self.text_font,
-                                            ));
-                                    }
-                                    Position::Right => {
-                                        column = column.push(
-                                            Row::new()
-                                                .align_items(Alignment::Center)
-                                                .push(layout_text(
-                                                    text,
-                                                    self.text_size + 1.0,
-                                                    self.text_font,
-                                                ))
-                                                .push(layout_icon(
-                                                    icon,
-                                                    self.icon_size + 1.0,
-                                                    self.font,
-                                                )),
-                                        );
-                                    }
-                                    Position::Left => {
-                                        column = column.push(
-                                            Row::new()
-                                                .align_items(Alignment::Center)
-                                                .push(layout_icon(
-                                                    icon,
-                                                    self.icon_size + 1.0,
-                                                    self.font,
-                                                ))
-                                                .push(layout_text(
-                                                    text,
-                                                    self.text_size + 1.0,
-                                                    self.text_font,
-                                                )),
-                                        );
-                                    }
-                                    Position::Bottom => {
-                                        column = column
-                                            .push(layout_text(
-                                                text,
-                                                self.text_size + 1.0,
-                                                self.text_font,
-                                            ))
-                                            .push(layout_icon(
-                                                icon,
-                                                self.icon_size + 1.0,
-                                                self.font,
-                                            ));
-                                    }
-                                }
-
-                                column
-                            }
-                        }
-                        .width(self.tab_width)
-                        .height(self.height),
-                    )
-                    .align_items(Alignment::Center)
-                    .padding(self.padding)
-                    .width(self.tab_width);
+            .enumerate()
+            .map(|(index, label)| {
+                let tab = accessibility::Node::new(Role::Tab)
+                    .selected(index == self.active_tab)
+                    .action(Action::Select);
+                let tab = match label.text() {
+                    Some(text) => tab.name(text),
+                    None => tab,
+                };
 
                 if self.on_close.is_some() {
-                    label_row = label_row.push(
-                        Row::new()
-                            .width(Length::Fixed(self.close_size * 1.3 + 1.0))
-                            .height(Length::Fixed(self.close_size * 1.3 + 1.0))
-                            .align_items(Alignment::Center),
-                    );
+                    tab.action(Action::Close)
+                } else {
+                    tab
                 }
-
-                row.push(label_row)
             })
-            .width(self.width)
-            .height(self.height)
-            .spacing(self.spacing)
-            .align_items(Alignment::Center);
-
-        let element: Element<Message, Theme, Renderer> = Element::new(row);
-        let tab_tree = if let Some(child_tree) = tree.children.get_mut(0) {
-            child_tree.diff(element.as_widget());
-            child_tree
-        } else {
-            let child_tree = Tree::new(element.as_widget());
-            tree.children.insert(0, child_tree);
-            &mut tree.children[0]
-        };
+            .collect();
+        let mut node = accessibility::Node::new(Role::TabList)
+            .action(Action::Focus)
+            .children(tabs);
 
-        element
-            .as_widget()
-            .layout(tab_tree, renderer, &limits.loose())
+        operation.custom(&mut node, self.id.as_ref());
     }
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: Cursor,
@@ -520,37 +629,67 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
         match event {
+            Event::Window(_id, window::Event::RedrawRequested(now)) => {
+                state.indicator.set_target(self.active_tab as f32);
+
+                if state.indicator.update(now) {
+                    animation::request_frame(shell, now);
+                }
+
+                event::Status::Ignored
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if state.is_focused && !self.tab_labels.is_empty() => {
+                let count = self.tab_labels.len();
+                let focused = state.focused_tab.unwrap_or(self.active_tab).min(count - 1);
+
+                match key {
+                    Named::ArrowRight | Named::ArrowDown => {
+                        state.focused_tab = Some((focused + 1) % count);
+                    }
+                    Named::ArrowLeft | Named::ArrowUp => {
+                        state.focused_tab = Some((focused + count - 1) % count);
+                    }
+                    Named::Home => state.focused_tab = Some(0),
+                    Named::End => state.focused_tab = Some(count - 1),
+                    Named::Enter | Named::Space => {
+                        state.focused_tab = None;
+                        shell.publish((self.on_select)(self.tab_indices[focused].clone()));
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                event::Status::Captured
+            }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if cursor
-                    .position()
-                    .map_or(false, |pos| layout.bounds().contains(pos))
-                {
-                    let tabs_map: Vec<bool> = layout
-                        .children()
-                        .map(|layout| {
-                            cursor
-                                .position()
-                                .map_or(false, |pos| layout.bounds().contains(pos))
-                        })
-                        .collect();
-
-                    if let Some(new_selected) = tabs_map.iter().position(|b| *b) {
-                        shell.publish(
-                            self.on_close
-                                .as_ref()
-                                .filter(|_on_close| {
-                                    let tab_layout = layout.children().nth(new_selected).expect("Native: Layout should have a tab layout at the selected index");
-                                    let cross_layout = tab_layout.children().nth(1).expect("Native: Layout should have a close layout");
-
-                                    cursor.position().map_or(false, |pos| cross_layout.bounds().contains(pos) )
-                                })
-                                .map_or_else(
-                                    || (self.on_select)(self.tab_indices[new_selected].clone()),
-                                    |on_close| (on_close)(self.tab_indices[new_selected].clone()),
-                                ),
-                        );
+                if !cursor.is_over(layout.bounds()) {
+                    state.unfocus();
+                }
+
+                let Some(pos) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+                let relative = pos - (layout.bounds().position() - Point::ORIGIN);
+                let bar = Rectangle::with_size(layout.bounds().size());
+
+                // The hit areas of the crosses may extend beyond their tabs.
+                if let Some(on_close) = self.on_close.as_ref() {
+                    if let Some(closed) = cross_at(&state.crosses, bar, relative) {
+                        shell.publish((on_close)(self.tab_indices[closed].clone()));
+                        return event::Status::Captured;
+                    }
+                }
+
+                if layout.bounds().contains(pos) {
+                    if let Some(new_selected) = hit_test::find_x(&state.tabs, |tab| *tab, relative)
+                    {
+                        shell.publish((self.on_select)(self.tab_indices[new_selected].clone()));
                         return event::Status::Captured;
                     }
                 }
@@ -562,36 +701,31 @@ where
 
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        state: &Tree,
         layout: Layout<'_>,
         cursor: Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        let children = layout.children();
-        let mut mouse_interaction = mouse::Interaction::default();
-
-        for layout in children {
-            let is_mouse_over = cursor
-                .position()
-                .map_or(false, |pos| layout.bounds().contains(pos));
-            let new_mouse_interaction = if is_mouse_over {
-                mouse::Interaction::Pointer
-            } else {
-                mouse::Interaction::default()
-            };
-
-            if new_mouse_interaction > mouse_interaction {
-                mouse_interaction = new_mouse_interaction;
-            }
-        }
+        let state = state.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let Some(pos) = cursor.position() else {
+            return mouse::Interaction::default();
+        };
+        let relative = pos - (layout.bounds().position() - Point::ORIGIN);
+        let bar = Rectangle::with_size(layout.bounds().size());
 
-        mouse_interaction
+        if (bar.contains(relative) && hit_test::find_x(&state.tabs, |tab| *tab, relative).is_some())
+            || (self.on_close.is_some() && cross_at(&state.crosses, bar, relative).is_some())
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
     }
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Theme,
         _style: &renderer::Style,
@@ -623,24 +757,163 @@ where
                 .unwrap_or_else(|| Color::TRANSPARENT.into()),
         );
 
-        for ((i, tab), layout) in self.tab_labels.iter().enumerate().zip(children) {
-            draw_tab(
-                renderer,
-                tab,
-                layout,
-                self.position,
-                theme,
-                &self.style,
-                i == self.get_active_tab_idx(),
-                cursor,
-                (self.font.unwrap_or(BOOTSTRAP_FONT), self.icon_size),
-                (self.text_font.unwrap_or_default(), self.text_size),
-                self.close_size,
-            );
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let focused_tab = state
+            .is_focused
+            .then(|| state.focused_tab.unwrap_or(self.active_tab));
+
+        let tabs: Vec<Rectangle> = layout.children().map(|tab| tab.bounds()).collect();
+
+        // The tabs scrolled out of the bar are clipped.
+        renderer.with_layer(bounds, |renderer| {
+            for ((i, tab), layout) in self.tab_labels.iter().enumerate().zip(children) {
+                draw_tab(
+                    renderer,
+                    tab,
+                    layout,
+                    self.position,
+                    theme,
+                    &self.style,
+                    i == self.get_active_tab_idx(),
+                    focused_tab == Some(i),
+                    cursor,
+                    (self.font.unwrap_or(BOOTSTRAP_FONT), self.icon_size),
+                    state.labels.get(i),
+                    self.close_size,
+                );
+            }
+
+            if let Some(color) = theme.active(&self.style, true).indicator_color {
+                draw_indicator(renderer, &tabs, state.indicator.position(), color);
+            }
+        });
+    }
+}
+
+/// Returns the index of the close button whose hit area contains `position`,
+/// among the `crosses` of the tabs ordered from left to right. The crosses
+/// scrolled out of the `bar` are ignored.
+fn cross_at(crosses: &[Rectangle], bar: Rectangle, position: Point) -> Option<usize> {
+    let candidates = hit_test::range_x(crosses, |cross| settings::hit_area(*cross), position.x);
+
+    settings::hit_part(&crosses[candidates.clone()], position)
+        .map(|index| candidates.start + index)
+        .filter(|&index| bar.intersects(&crosses[index]))
+}
+
+/// Returns the size of the content of a label and the nodes of its icon and
+/// its text, with the size of the icon given by `measure_icon` and the size
+/// of the shaped text.
+///
+/// The icon and the text placed side by side are wrapped in a row node, as
+/// expected by [`draw_tab`].
+fn label_content(
+    tab_label: &TabLabel,
+    position: Position,
+    measure_icon: impl Fn(&IconHandle) -> Size,
+    text: Size,
+) -> (Size, Vec<Node>) {
+    /// The padding around a label showing only a text.
+    const TEXT_PADDING: f32 = 5.0;
+
+    match (tab_label.icon(), tab_label.text()) {
+        (Some(icon), None) => {
+            let icon = measure_icon(&icon);
+
+            (icon, vec![Node::new(icon)])
+        }
+        (None, _) => (
+            Size::new(
+                text.width + 2.0 * TEXT_PADDING,
+                text.height + 2.0 * TEXT_PADDING,
+            ),
+            vec![Node::new(text).move_to(Point::new(TEXT_PADDING, TEXT_PADDING))],
+        ),
+        (Some(icon), Some(_)) => {
+            let icon = measure_icon(&icon);
+
+            match position {
+                Position::Top | Position::Bottom => {
+                    let width = icon.width.max(text.width);
+                    let (first, second) = if matches!(position, Position::Top) {
+                        (icon, text)
+                    } else {
+                        (text, icon)
+                    };
+
+                    (
+                        Size::new(width, first.height + second.height),
+                        vec![
+                            Node::new(first).move_to(Point::new((width - first.width) / 2.0, 0.0)),
+                            Node::new(second)
+                                .move_to(Point::new((width - second.width) / 2.0, first.height)),
+                        ],
+                    )
+                }
+                Position::Left | Position::Right => {
+                    let height = icon.height.max(text.height);
+                    let (first, second) = if matches!(position, Position::Left) {
+                        (icon, text)
+                    } else {
+                        (text, icon)
+                    };
+                    let size = Size::new(first.width + second.width, height);
+
+                    (
+                        size,
+                        vec![Node::with_children(
+                            size,
+                            vec![
+                                Node::new(first)
+                                    .move_to(Point::new(0.0, (height - first.height) / 2.0)),
+                                Node::new(second).move_to(Point::new(
+                                    first.width,
+                                    (height - second.height) / 2.0,
+                                )),
+                            ],
+                        )],
+                    )
+                }
+            }
         }
     }
 }
 
+/// Draws the indicator under the tabs with the given bounds, at the
+/// `position` of the tab it is under, between two tabs while it slides.
+fn draw_indicator<Renderer>(
+    renderer: &mut Renderer,
+    tabs: &[Rectangle],
+    position: f32,
+    color: Color,
+) where
+    Renderer: renderer::Renderer,
+{
+    let Some(last) = tabs.len().checked_sub(1) else {
+        return;
+    };
+
+    let position = position.clamp(0.0, last as f32);
+    let from = tabs[position.floor() as usize];
+    let to = tabs[(position.ceil() as usize).min(last)];
+    let t = position.fract();
+    let lerp = |a: f32, b: f32| (b - a).mul_add(t, a);
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: lerp(from.x, to.x),
+                y: lerp(from.y + from.height, to.y + to.height) - INDICATOR_HEIGHT,
+                width: lerp(from.width, to.width),
+                height: INDICATOR_HEIGHT,
+            },
+            border: Border::with_radius(INDICATOR_HEIGHT / 2.0),
+            shadow: Shadow::default(),
+        },
+        color,
+    );
+}
+
 /// Draws a tab.
 #[allow(
     clippy::borrowed_box,
@@ -655,12 +928,13 @@ fn draw_tab<Theme, Renderer>(
     theme: &Theme,
     style: &<Theme as StyleSheet>::Style,
     is_selected: bool,
+    is_focused: bool,
     cursor: Cursor,
     icon_data: (Font, f32),
-    text_data: (Font, f32),
+    text: Option<&Renderer::Paragraph>,
     close_size: f32,
 ) where
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Renderer: renderer::Renderer + IconRenderer,
     Theme: StyleSheet + text::StyleSheet,
 {
     fn icon_bound_rectangle(item: Option<Layout<'_>>) -> Rectangle {
@@ -675,7 +949,9 @@ fn draw_tab<Theme, Renderer>(
 
     let bounds = layout.bounds();
     let is_mouse_over = cursor.position().map_or(false, |pos| bounds.contains(pos));
-    let style = if is_mouse_over {
+    let style = if is_focused {
+        theme.focused(style, is_selected)
+    } else if is_mouse_over {
         theme.hovered(style, is_selected)
     } else {
         theme.active(style, is_selected)
@@ -700,47 +976,27 @@ fn draw_tab<Theme, Renderer>(
         style.tab_label_background,
     );
 
-    match tab {
-        TabLabel::Icon(icon) => {
+    match (tab.icon(), tab.text()) {
+        (Some(icon), None) => {
             let icon_bounds = icon_bound_rectangle(label_layout_children.next());
 
-            renderer.fill_text(
-                iced::advanced::text::Text {
-                    content: &icon.to_string(),
-                    bounds: Size::new(icon_bounds.width, icon_bounds.height),
-                    size: Pixels(icon_data.1),
-                    font: icon_data.0,
-                    horizontal_alignment: Horizontal::Center,
-                    vertical_alignment: Vertical::Center,
-                    line_height: LineHeight::Relative(1.3),
-                    shaping: iced::advanced::text::Shaping::Advanced,
-                },
-                Point::new(icon_bounds.center_x(), icon_bounds.center_y()),
-                style.icon_color,
+            icon.draw(
+                renderer,
                 icon_bounds,
+                icon_data.1,
+                icon_data.0,
+                style.icon_color,
             );
         }
 
-        TabLabel::Text(text) => {
+        (None, _) => {
             let text_bounds = text_bound_rectangle(label_layout_children.next());
 
-            renderer.fill_text(
-                iced::advanced::text::Text {
-                    content: &text[..],
-                    bounds: Size::new(text_bounds.width, text_bounds.height),
-                    size: Pixels(text_data.1),
-                    font: text_data.0,
-                    horizontal_alignment: Horizontal::Center,
-                    vertical_alignment: Vertical::Center,
-                    line_height: LineHeight::Relative(1.3),
-                    shaping: iced::advanced::text::Shaping::Advanced,
-                },
-                Point::new(text_bounds.center_x(), text_bounds.center_y()),
-                style.text_color,
-                text_bounds,
-            );
+            if let Some(text) = text {
+                renderer.fill_paragraph(text, text_bounds.center(), style.text_color, text_bounds);
+            }
         }
-        TabLabel::IconText(icon, text) => {
+        (Some(icon), Some(_)) => {
             let icon_bounds: Rectangle;
             let text_bounds: Rectangle;
 
@@ -771,43 +1027,23 @@ fn draw_tab<Theme, Renderer>(
                 }
             }
 
-            renderer.fill_text(
-                iced::advanced::text::Text {
-                    content: &icon.to_string(),
-                    bounds: Size::new(icon_bounds.width, icon_bounds.height),
-                    size: Pixels(icon_data.1),
-                    font: icon_data.0,
-                    horizontal_alignment: Horizontal::Center,
-                    vertical_alignment: Vertical::Center,
-                    line_height: LineHeight::Relative(1.3),
-                    shaping: iced::advanced::text::Shaping::Advanced,
-                },
-                Point::new(icon_bounds.center_x(), icon_bounds.center_y()),
-                style.icon_color,
+            icon.draw(
+                renderer,
                 icon_bounds,
+                icon_data.1,
+                icon_data.0,
+                style.icon_color,
             );
 
-            renderer.fill_text(
-                iced::advanced::text::Text {
-                    content: &text[..],
-                    bounds: Size::new(text_bounds.width, text_bounds.height),
-                    size: Pixels(text_data.1),
-                    font: text_data.0,
-                    horizontal_alignment: Horizontal::Center,
-                    vertical_alignment: Vertical::Center,
-                    line_height: LineHeight::Relative(1.3),
-                    shaping: iced::advanced::text::Shaping::Advanced,
-                },
-                Point::new(text_bounds.center_x(), text_bounds.center_y()),
-                style.text_color,
-                text_bounds,
-            );
+            if let Some(text) = text {
+                renderer.fill_paragraph(text, text_bounds.center(), style.text_color, text_bounds);
+            }
         }
     };
 
     if let Some(cross_layout) = children.next() {
         let cross_bounds = cross_layout.bounds();
-        let is_mouse_over_cross = cursor.is_over(cross_bounds);
+        let is_mouse_over_cross = cursor.is_over(settings::hit_area(cross_bounds));
 
         renderer.fill_text(
             iced::advanced::text::Text {
@@ -847,7 +1083,7 @@ fn draw_tab<Theme, Renderer>(
 impl<'a, Message, TabId, Theme, Renderer> From<TabBar<Message, TabId, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = iced::Font>,
+    Renderer: 'a + renderer::Renderer + IconRenderer,
     Theme: 'a + StyleSheet + text::StyleSheet,
     Message: 'a,
     TabId: 'a + Eq + Clone,
@@ -856,3 +1092,42 @@ where
         Element::new(tab_bar)
     }
 }
+
+/// Produces an [`Operation`] scrolling the tab at `index` into view in the
+/// [`TabBar`] with the given [`Id`](widget::Id).
+pub(crate) fn request<T>(target: widget::Id, index: usize) -> impl Operation<T> {
+    struct Apply {
+        target: widget::Id,
+        index: usize,
+    }
+
+    impl<T> Operation<T> for Apply {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if id != Some(&self.target) {
+                return;
+            }
+
+            if let Some(scroll) = state.downcast_mut::<Scroll>() {
+                scroll.reveal = Some(self.index);
+            }
+        }
+    }
+
+    Apply { target, index }
+}
+
+/// Produces a [`Command`] that scrolls the tab at `index` into view in the
+/// [`TabBar`] with the given [`Id`](widget::Id), when its tabs are wider than
+/// the bar.
+pub fn scroll_to<Message: 'static>(id: widget::Id, index: usize) -> Command<Message> {
+    Command::widget(request(id, index))
+}
@@ -2,6 +2,8 @@
 //!
 //! *This API requires the following crate features to be activated: `tab_bar`*
 
+use crate::core::icons::IconHandle;
+
 /// A [`TabLabel`] showing an icon and/or a text on a tab
 /// on a [`TabBar`](super::TabBar).
 #[allow(missing_debug_implementations)]
@@ -15,5 +17,32 @@ pub enum TabLabel {
 
     /// A [`TabLabel`] showing an icon and a text on the tab.
     IconText(char, String),
+
+    /// A [`TabLabel`] showing only an [`IconHandle`] on the tab, like an SVG.
+    IconHandle(IconHandle),
+
+    /// A [`TabLabel`] showing an [`IconHandle`] and a text on the tab.
+    IconHandleText(IconHandle, String),
     // TODO: Support any element as a label.
 }
+
+impl TabLabel {
+    /// Returns the icon shown on the tab, if any.
+    pub(crate) fn icon(&self) -> Option<IconHandle> {
+        match self {
+            Self::Icon(glyph) | Self::IconText(glyph, _) => Some(IconHandle::from(*glyph)),
+            Self::IconHandle(icon) | Self::IconHandleText(icon, _) => Some(icon.clone()),
+            Self::Text(_) => None,
+        }
+    }
+
+    /// Returns the text shown on the tab, if any.
+    pub(crate) fn text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) | Self::IconText(_, text) | Self::IconHandleText(_, text) => {
+                Some(text)
+            }
+            Self::Icon(_) | Self::IconHandle(_) => None,
+        }
+    }
+}
@@ -0,0 +1,1142 @@
+//! Display records in a table with sortable columns.
+//!
+//! *This API requires the following crate features to be activated: table*
+
+use crate::core::settings;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::{
+            tree::{self, Tag},
+            Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event, font,
+    mouse::{self, Cursor, ScrollDelta},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Color, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::collections::HashMap;
+use std::ops::Range;
+
+pub use crate::style::table::{Appearance, StyleSheet};
+
+/// The width of the scrollbar of a [`Table`].
+const SCROLLBAR_WIDTH: f32 = 8.0;
+/// The distance from the edge of a resizable column at which it can be resized.
+const RESIZE_HANDLE: f32 = 4.0;
+/// The minimum width of a resized column.
+const MIN_COLUMN_WIDTH: f32 = 24.0;
+/// The distance the cursor moves before a pressed header is dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+/// The pixels scrolled by a line of the mouse wheel.
+const LINE_HEIGHT: f32 = 60.0;
+
+/// The function building the content of a cell of a [`Table`] from the index
+/// of its row, the index of its column and the record of its row.
+pub type CellView<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer> =
+    dyn Fn(usize, usize, &'a T) -> Element<'a, Message, Theme, Renderer> + 'a;
+
+/// A column of a [`Table`].
+#[derive(Clone, Debug)]
+pub struct Column {
+    /// The label of the header of the column.
+    pub header: String,
+    /// The width of the column.
+    pub width: ColumnWidth,
+    /// Whether the records can be sorted by the column.
+    pub sortable: bool,
+}
+
+/// The width of a [`Column`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColumnWidth {
+    /// The column has a fixed width.
+    Fixed(f32),
+    /// The column fills a share of the space left by the other columns,
+    /// proportional to the given portion.
+    Fill(u16),
+    /// The column has the given width, and can be resized by dragging the
+    /// edge of its header.
+    Resizable(f32),
+}
+
+/// The order in which the records of a [`Table`] are sorted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortOrder {
+    /// The records are sorted in ascending order.
+    Ascending,
+    /// The records are sorted in descending order.
+    Descending,
+}
+
+impl SortOrder {
+    /// Returns the opposite [`SortOrder`].
+    #[must_use]
+    pub fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+impl Column {
+    /// Creates a new [`Column`] with the given header, filling the space left
+    /// by the other columns.
+    pub fn new(header: impl Into<String>) -> Self {
+        Self {
+            header: header.into(),
+            width: ColumnWidth::Fill(1),
+            sortable: false,
+        }
+    }
+
+    /// Sets the width of the [`Column`].
+    #[must_use]
+    pub fn width(mut self, width: ColumnWidth) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets whether the records can be sorted by the [`Column`].
+    #[must_use]
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// A table showing records in rows, with a header row for its columns.
+///
+/// The content of the cells is built by the `view` closure, for the rows
+/// shown only, so that the table can show thousands of records. The records
+/// are sorted by the application, in response to the message produced when a
+/// sortable header is clicked.
+///
+/// The columns can be resized by dragging the edges of their headers, and
+/// reordered by dragging their headers. The table reports these changes
+/// through [`on_resize`](Table::on_resize) and [`on_reorder`](Table::on_reorder),
+/// so that the application can update and persist its columns.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::text;
+/// # use iced_aw::table::{Column, ColumnWidth, SortOrder, Table};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Sort(usize, SortOrder),
+///     Select(usize),
+/// }
+///
+/// let people = vec![("Ada", 36), ("Alan", 41)];
+///
+/// let table = Table::new(
+///     vec![
+///         Column::new("Name").sortable(true),
+///         Column::new("Age").width(ColumnWidth::Fixed(60.0)),
+///     ],
+///     &people,
+///     |_row, column, (name, age)| match column {
+///         0 => text(name).into(),
+///         _ => text(age).into(),
+///     },
+/// )
+/// .on_sort(Message::Sort)
+/// .on_select(Message::Select);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The columns of the table.
+    columns: Vec<Column>,
+    /// The records shown in the rows.
+    rows: &'a [T],
+    /// The function building the content of the cells.
+    view: Box<CellView<'a, T, Message, Theme, Renderer>>,
+    /// The column and order by which the records are sorted.
+    sort: Option<(usize, SortOrder)>,
+    /// The function producing the message when a sortable header is clicked.
+    on_sort: Option<Box<dyn Fn(usize, SortOrder) -> Message + 'a>>,
+    /// The index of the selected row.
+    selected: Option<usize>,
+    /// The function producing the message when a row is clicked.
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The function producing the message when a column is resized.
+    on_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    /// The function producing the message when a column is moved.
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    row_height: f32,
+    header_height: f32,
+    /// The horizontal space between the content of the cells and their edges.
+    padding: f32,
+    text_size: f32,
+    font: Font,
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`Table`].
+#[derive(Debug, Default)]
+struct TableState {
+    /// The vertical scroll offset of the rows.
+    offset: f32,
+    /// The widths of the resized columns, until the application takes
+    /// them over.
+    resized: HashMap<usize, f32>,
+    /// The column being resized, with the cursor position and the width of
+    /// the column when resizing started.
+    resizing: Option<(usize, f32, f32)>,
+    /// The distance between the top of the scrollbar and the cursor dragging it.
+    scrollbar_grab: Option<f32>,
+    /// The index of the pressed header, with the position of the cursor
+    /// when it was pressed.
+    pressed_header: Option<(usize, f32)>,
+    /// The position of the cursor dragging the pressed header.
+    dragged_header: Option<f32>,
+    /// The rows shown.
+    shown: Range<usize>,
+    /// The state and layout of the content of the cells shown, relative to the table.
+    cells: HashMap<(usize, usize), (Tree, Node)>,
+    /// The bounds, the scroll offset and the widths of the columns the cells
+    /// were last laid out with, until the content of the table changes.
+    laid_out: Option<(Rectangle, f32, Vec<f32>)>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Table<'a, T, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Table`] with the given columns, showing the `rows`
+    /// with the cells built by `view`.
+    pub fn new(
+        columns: Vec<Column>,
+        rows: &'a [T],
+        view: impl Fn(usize, usize, &'a T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            columns,
+            rows,
+            view: Box::new(view),
+            sort: None,
+            on_sort: None,
+            selected: None,
+            on_select: None,
+            on_resize: None,
+            on_reorder: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: settings::spaced(32.0),
+            header_height: 32.0,
+            padding: settings::spaced(8.0),
+            text_size: settings::scaled(16.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the column and order by which the records are sorted, shown by
+    /// an indicator in the header.
+    #[must_use]
+    pub fn sort(mut self, sort: Option<(usize, SortOrder)>) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Sets the function producing the message when a sortable header is
+    /// clicked, with the index of the column and the new order.
+    #[must_use]
+    pub fn on_sort(mut self, on_sort: impl Fn(usize, SortOrder) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the index of the selected row.
+    #[must_use]
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the function producing the message when a row is clicked.
+    #[must_use]
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the function producing the message when a resizable column is
+    /// resized, with the index of the column and its new width.
+    ///
+    /// The application is expected to update the [`ColumnWidth::Resizable`]
+    /// width of the column, which the table then follows.
+    #[must_use]
+    pub fn on_resize(mut self, on_resize: impl Fn(usize, f32) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the function producing the message when a header is dragged to
+    /// another position, with the index of the column and its new index.
+    ///
+    /// The application is expected to move the column, as well as the cells
+    /// built for it, as with [`Vec::remove`] followed by [`Vec::insert`].
+    #[must_use]
+    pub fn on_reorder(mut self, on_reorder: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the width of the [`Table`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of the rows of the [`Table`].
+    #[must_use]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the height of the header row of the [`Table`].
+    #[must_use]
+    pub fn header_height(mut self, header_height: f32) -> Self {
+        self.header_height = header_height;
+        self
+    }
+
+    /// Sets the horizontal space between the content of the cells and their edges.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the headers of the [`Table`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the headers of the [`Table`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the bounds of the rows in the bounds of the table.
+    fn body(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            y: bounds.y + self.header_height,
+            height: (bounds.height - self.header_height).max(0.0),
+            ..bounds
+        }
+    }
+
+    /// Returns the largest scroll offset of the rows in the given body.
+    fn max_offset(&self, body: Rectangle) -> f32 {
+        (self.row_height * self.rows.len() as f32 - body.height).max(0.0)
+    }
+
+    /// Returns the bounds of the scrollbar thumb in the given body, if the
+    /// rows overflow it.
+    fn scrollbar(&self, body: Rectangle, offset: f32) -> Option<Rectangle> {
+        let max_offset = self.max_offset(body);
+
+        (max_offset > 0.0).then(|| {
+            let content = self.row_height * self.rows.len() as f32;
+            let height = (body.height * body.height / content).max(SCROLLBAR_WIDTH * 2.0);
+
+            Rectangle {
+                x: body.x + body.width - SCROLLBAR_WIDTH,
+                y: body.y + (body.height - height) * offset / max_offset,
+                width: SCROLLBAR_WIDTH,
+                height,
+            }
+        })
+    }
+
+    /// Computes the widths of the columns in the given width.
+    fn widths(&self, state: &TableState, width: f32) -> Vec<f32> {
+        let mut portions = 0.0;
+        let mut fixed = 0.0;
+        let widths: Vec<Option<f32>> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| match column.width {
+                ColumnWidth::Fixed(width) => Some(width),
+                ColumnWidth::Resizable(width) => {
+                    Some(state.resized.get(&i).copied().unwrap_or(width))
+                }
+                ColumnWidth::Fill(portion) => {
+                    portions += f32::from(portion);
+                    None
+                }
+            })
+            .inspect(|width| fixed += width.unwrap_or(0.0))
+            .collect();
+
+        let space = (width - SCROLLBAR_WIDTH - fixed).max(0.0);
+
+        widths
+            .iter()
+            .zip(&self.columns)
+            .map(|(width, column)| match (width, column.width) {
+                (Some(width), _) => *width,
+                (None, ColumnWidth::Fill(portion)) if portions > 0.0 => {
+                    space * f32::from(portion) / portions
+                }
+                _ => 0.0,
+            })
+            .collect()
+    }
+
+    /// Returns the horizontal ranges of the columns of the given bounds.
+    fn column_spans(&self, state: &TableState, bounds: Rectangle) -> Vec<(f32, f32)> {
+        let mut x = bounds.x;
+
+        self.widths(state, bounds.width)
+            .into_iter()
+            .map(|width| {
+                let span = (x, width);
+                x += width;
+                span
+            })
+            .collect()
+    }
+
+    /// Returns the resizable column whose edge is under the cursor.
+    fn resize_handle_at(
+        &self,
+        state: &TableState,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<usize> {
+        let position = cursor.position_over(Rectangle {
+            height: self.header_height,
+            ..bounds
+        })?;
+
+        self.column_spans(state, bounds)
+            .iter()
+            .zip(&self.columns)
+            .position(|((x, width), column)| {
+                matches!(column.width, ColumnWidth::Resizable(_))
+                    && (position.x - (x + width)).abs() <= RESIZE_HANDLE
+            })
+    }
+
+    /// Returns the column whose header is under the cursor.
+    fn header_at(&self, state: &TableState, bounds: Rectangle, cursor: Cursor) -> Option<usize> {
+        let position = cursor.position_over(Rectangle {
+            height: self.header_height,
+            ..bounds
+        })?;
+
+        self.column_spans(state, bounds)
+            .iter()
+            .position(|(x, width)| position.x >= *x && position.x < x + width)
+    }
+
+    /// Returns whether the header of the `column` reacts to the cursor.
+    fn header_interactive(&self, column: usize) -> bool {
+        self.on_reorder.is_some() || (self.on_sort.is_some() && self.columns[column].sortable)
+    }
+
+    /// Returns the index the dragged `column` moves to when dropped at `x`.
+    fn drop_index(&self, state: &TableState, bounds: Rectangle, column: usize, x: f32) -> usize {
+        self.column_spans(state, bounds)
+            .iter()
+            .enumerate()
+            .filter(|(i, (start, width))| *i != column && start + width / 2.0 < x)
+            .count()
+    }
+
+    /// Moves the widths of the resized columns along with a moved column.
+    fn move_resized(resized: &mut HashMap<usize, f32>, from: usize, to: usize) {
+        let mut order: Vec<usize> = (0..=from.max(to)).collect();
+        let column = order.remove(from);
+        order.insert(to, column);
+
+        *resized = resized
+            .drain()
+            .map(|(i, width)| {
+                (
+                    order.iter().position(|&moved| moved == i).unwrap_or(i),
+                    width,
+                )
+            })
+            .collect();
+    }
+
+    /// Returns the row under the cursor.
+    fn row_at(&self, state: &TableState, bounds: Rectangle, cursor: Cursor) -> Option<usize> {
+        let body = self.body(bounds);
+        let position = cursor.position_over(body)?;
+        let row = ((position.y - body.y + state.offset) / self.row_height) as usize;
+
+        (row < self.rows.len()).then_some(row)
+    }
+
+    /// Returns the rows shown in the given body.
+    fn rows_in(&self, state: &TableState, body: Rectangle) -> Range<usize> {
+        let start = (state.offset / self.row_height) as usize;
+        let end = ((state.offset + body.height) / self.row_height).ceil() as usize;
+
+        start.min(self.rows.len())..end.min(self.rows.len())
+    }
+
+    /// Builds, diffs and lays out the content of the cells of the rows
+    /// shown in the given bounds, dropping the content of the others.
+    fn update_cells(&self, state: &mut TableState, renderer: &Renderer, bounds: Rectangle) {
+        let body = self.body(bounds);
+        state.offset = state.offset.clamp(0.0, self.max_offset(body));
+
+        let key = (bounds, state.offset, self.widths(state, bounds.width));
+        if state.laid_out.as_ref() == Some(&key) {
+            return;
+        }
+
+        state.shown = self.rows_in(state, body);
+
+        let shown = state.shown.clone();
+        let widths = &key.2;
+        state.cells.retain(|(row, _), _| shown.contains(row));
+
+        for row in shown {
+            let mut x = 0.0;
+
+            for (column, width) in widths.iter().enumerate() {
+                let element = (self.view)(row, column, &self.rows[row]);
+                let (tree, node) = state
+                    .cells
+                    .entry((row, column))
+                    .or_insert_with(|| (Tree::new(&element), Node::default()));
+
+                tree.diff(&element);
+
+                let limits = Limits::new(
+                    Size::ZERO,
+                    Size::new((width - self.padding * 2.0).max(0.0), self.row_height),
+                );
+                let cell = element.as_widget().layout(tree, renderer, &limits);
+                let y = self.header_height + self.row_height * row as f32 - state.offset
+                    + (self.row_height - cell.size().height) / 2.0;
+
+                *node = cell.move_to(Point::new(x + self.padding, y));
+                x += width;
+            }
+        }
+
+        state.laid_out = Some(key);
+    }
+
+    /// Draws the label and the sort indicator of the header of the `column`.
+    fn draw_header(
+        &self,
+        renderer: &mut Renderer,
+        appearance: &Appearance,
+        column: usize,
+        cell: Rectangle,
+    ) {
+        let indicator = match self.sort {
+            Some((sorted, SortOrder::Ascending)) if sorted == column => Some("▲"),
+            Some((sorted, SortOrder::Descending)) if sorted == column => Some("▼"),
+            _ => None,
+        };
+        let label_width = if indicator.is_some() {
+            (cell.width - self.padding * 2.0 - self.text_size).max(0.0)
+        } else {
+            (cell.width - self.padding * 2.0).max(0.0)
+        };
+
+        renderer.fill_text(
+            Text {
+                content: &self.columns[column].header,
+                bounds: Size::new(label_width, cell.height),
+                size: Pixels(self.text_size),
+                line_height: LineHeight::default(),
+                font: Font {
+                    weight: font::Weight::Bold,
+                    ..self.font
+                },
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: Shaping::Advanced,
+            },
+            Point::new(cell.x + self.padding, cell.center_y()),
+            appearance.header_text_color,
+            Rectangle {
+                x: cell.x + self.padding,
+                width: label_width,
+                ..cell
+            },
+        );
+
+        if let Some(indicator) = indicator {
+            renderer.fill_text(
+                Text {
+                    content: indicator,
+                    bounds: Size::new(self.text_size, cell.height),
+                    size: Pixels(self.text_size * 0.7),
+                    line_height: LineHeight::default(),
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: Shaping::Advanced,
+                },
+                Point::new(
+                    cell.x + cell.width - self.padding - self.text_size / 2.0,
+                    cell.center_y(),
+                ),
+                appearance.header_text_color,
+                cell,
+            );
+        }
+    }
+
+    fn fill(renderer: &mut Renderer, bounds: Rectangle, background: impl Into<iced::Background>) {
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            background,
+        );
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Table<'_, T, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<TableState>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(TableState::default())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<TableState>().laid_out = None;
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let intrinsic_width = self
+            .columns
+            .iter()
+            .map(|column| match column.width {
+                ColumnWidth::Fixed(width) | ColumnWidth::Resizable(width) => width,
+                ColumnWidth::Fill(_) => MIN_COLUMN_WIDTH,
+            })
+            .sum::<f32>()
+            + SCROLLBAR_WIDTH;
+        let intrinsic_height = self.header_height + self.row_height * self.rows.len() as f32;
+
+        let size = limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::new(intrinsic_width, intrinsic_height),
+        );
+
+        self.update_cells(
+            tree.state.downcast_mut::<TableState>(),
+            renderer,
+            Rectangle::new(Point::ORIGIN, size),
+        );
+
+        Node::new(size)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let body = self.body(bounds);
+        let table_state = state.state.downcast_mut::<TableState>();
+
+        // Resizing a column and dragging the scrollbar take over the cursor.
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some((column, start_x, start_width)) = table_state.resizing {
+                    let width = (start_width + position.x - start_x).max(MIN_COLUMN_WIDTH);
+                    let _ = table_state.resized.insert(column, width);
+                    if let Some(on_resize) = &self.on_resize {
+                        shell.publish(on_resize(column, width));
+                    }
+                    self.update_cells(table_state, renderer, bounds);
+                    shell.invalidate_layout();
+                    return event::Status::Captured;
+                }
+
+                if let Some((_, origin)) = table_state.pressed_header {
+                    if table_state.dragged_header.is_some()
+                        || (self.on_reorder.is_some()
+                            && (position.x - origin).abs() > DRAG_THRESHOLD)
+                    {
+                        table_state.dragged_header = Some(position.x);
+                        return event::Status::Captured;
+                    }
+                }
+
+                if let Some(grab) = table_state.scrollbar_grab {
+                    if let Some(thumb) = self.scrollbar(body, table_state.offset) {
+                        let track = body.height - thumb.height;
+                        let ratio = if track > 0.0 {
+                            (position.y - grab - body.y) / track
+                        } else {
+                            0.0
+                        };
+                        table_state.offset = ratio.clamp(0.0, 1.0) * self.max_offset(body);
+                        self.update_cells(table_state, renderer, bounds);
+                    }
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if table_state.resizing.is_some() || table_state.scrollbar_grab.is_some() =>
+            {
+                // The application owns the widths it is told about.
+                if let (Some((column, ..)), Some(_)) = (table_state.resizing, &self.on_resize) {
+                    let _ = table_state.resized.remove(&column);
+                }
+                table_state.resizing = None;
+                table_state.scrollbar_grab = None;
+                return event::Status::Captured;
+            }
+
+            _ => {}
+        }
+
+        // The content of the cells gets the event first, so that its buttons
+        // do not select the row.
+        self.update_cells(table_state, renderer, bounds);
+        let offset = Vector::new(bounds.x, bounds.y);
+        let cell_cursor = if cursor.is_over(body) {
+            cursor
+        } else {
+            Cursor::Unavailable
+        };
+        let mut status = event::Status::Ignored;
+
+        for ((row, column), (tree, node)) in &mut table_state.cells {
+            let captured = (self.view)(*row, *column, &self.rows[*row])
+                .as_widget_mut()
+                .on_event(
+                    tree,
+                    event.clone(),
+                    Layout::with_offset(offset, node),
+                    cell_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+
+            if captured == event::Status::Captured {
+                status = event::Status::Captured;
+            }
+        }
+
+        if shell.is_layout_invalid() {
+            table_state.laid_out = None;
+        }
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let y = match delta {
+                    ScrollDelta::Lines { y, .. } => y * LINE_HEIGHT,
+                    ScrollDelta::Pixels { y, .. } => y,
+                };
+                let offset = (table_state.offset - y).clamp(0.0, self.max_offset(body));
+
+                if (offset - table_state.offset).abs() > f32::EPSILON {
+                    table_state.offset = offset;
+                    self.update_cells(table_state, renderer, bounds);
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(column) = self.resize_handle_at(table_state, bounds, cursor) {
+                    let width = self.widths(table_state, bounds.width)[column];
+                    let x = cursor.position().map_or(0.0, |position| position.x);
+                    table_state.resizing = Some((column, x, width));
+                    return event::Status::Captured;
+                }
+
+                if let (Some(thumb), Some(position)) = (
+                    self.scrollbar(body, table_state.offset),
+                    cursor.position_over(Rectangle {
+                        x: body.x + body.width - SCROLLBAR_WIDTH,
+                        width: SCROLLBAR_WIDTH,
+                        ..body
+                    }),
+                ) {
+                    // Pressing the track outside of the thumb centers the thumb on the cursor.
+                    let grab = if thumb.contains(position) {
+                        position.y - thumb.y
+                    } else {
+                        thumb.height / 2.0
+                    };
+                    table_state.scrollbar_grab = Some(grab);
+                    return event::Status::Captured;
+                }
+
+                if let Some(column) = self.header_at(table_state, bounds, cursor) {
+                    if self.header_interactive(column) {
+                        let x = cursor.position().map_or(0.0, |position| position.x);
+                        table_state.pressed_header = Some((column, x));
+                    }
+                    return event::Status::Captured;
+                }
+
+                if let (Some(on_select), Some(row)) =
+                    (&self.on_select, self.row_at(table_state, bounds, cursor))
+                {
+                    shell.publish(on_select(row));
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some((pressed, _)) = table_state.pressed_header.take() {
+                    if let Some(x) = table_state.dragged_header.take() {
+                        let index = self.drop_index(table_state, bounds, pressed, x);
+
+                        if let (Some(on_reorder), true) = (&self.on_reorder, index != pressed) {
+                            Self::move_resized(&mut table_state.resized, pressed, index);
+                            shell.publish(on_reorder(pressed, index));
+                        }
+                    } else if let (Some(on_sort), Some(column)) =
+                        (&self.on_sort, self.header_at(table_state, bounds, cursor))
+                    {
+                        if column == pressed && self.columns[column].sortable {
+                            let order = match self.sort {
+                                Some((sorted, order)) if sorted == column => order.reversed(),
+                                _ => SortOrder::Ascending,
+                            };
+                            shell.publish(on_sort(column, order));
+                        }
+                    }
+                    return event::Status::Captured;
+                }
+            }
+
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let table_state = state.state.downcast_ref::<TableState>();
+
+        if table_state.resizing.is_some()
+            || self.resize_handle_at(table_state, bounds, cursor).is_some()
+        {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        if table_state.dragged_header.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if let Some(column) = self.header_at(table_state, bounds, cursor) {
+            return if self.header_interactive(column) {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            };
+        }
+
+        let offset = Vector::new(bounds.x, bounds.y);
+        let interaction = table_state
+            .cells
+            .iter()
+            .map(|((row, column), (tree, node))| {
+                (self.view)(*row, *column, &self.rows[*row])
+                    .as_widget()
+                    .mouse_interaction(
+                        tree,
+                        Layout::with_offset(offset, node),
+                        cursor,
+                        viewport,
+                        renderer,
+                    )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction != mouse::Interaction::default() {
+            return interaction;
+        }
+
+        if self.on_select.is_some() && self.row_at(table_state, bounds, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let body = self.body(bounds);
+        let table_state = state.state.downcast_ref::<TableState>();
+        let appearance = theme.style(&self.style);
+        let spans = self.column_spans(table_state, bounds);
+        let hovered = self.row_at(table_state, bounds, cursor);
+        let offset = Vector::new(bounds.x, bounds.y);
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                    ..Border::default()
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        renderer.with_layer(body, |renderer| {
+            for row in table_state.shown.clone() {
+                let row_bounds = Rectangle {
+                    y: body.y + self.row_height * row as f32 - table_state.offset,
+                    height: self.row_height,
+                    ..body
+                };
+                let selected = self.selected == Some(row);
+
+                if selected {
+                    Self::fill(renderer, row_bounds, appearance.selected_background);
+                } else if hovered == Some(row) && self.on_select.is_some() {
+                    Self::fill(renderer, row_bounds, appearance.hovered_background);
+                }
+
+                Self::fill(
+                    renderer,
+                    Rectangle {
+                        y: row_bounds.y + row_bounds.height - 1.0,
+                        height: 1.0,
+                        ..row_bounds
+                    },
+                    appearance.divider_color,
+                );
+
+                let style = renderer::Style {
+                    text_color: if selected {
+                        appearance.selected_text_color
+                    } else {
+                        appearance.text_color
+                    },
+                };
+
+                for (column, (x, width)) in spans.iter().enumerate() {
+                    let cell = Rectangle {
+                        x: *x,
+                        width: *width,
+                        ..row_bounds
+                    };
+                    let Some((tree, node)) = table_state.cells.get(&(row, column)) else {
+                        continue;
+                    };
+                    let element = (self.view)(row, column, &self.rows[row]);
+
+                    renderer.with_layer(cell, |renderer| {
+                        element.as_widget().draw(
+                            tree,
+                            renderer,
+                            theme,
+                            &style,
+                            Layout::with_offset(offset, node),
+                            cursor,
+                            viewport,
+                        );
+                    });
+                }
+            }
+
+            if let Some(thumb) = self.scrollbar(body, table_state.offset) {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: thumb,
+                        border: Border {
+                            radius: (SCROLLBAR_WIDTH / 2.0).into(),
+                            ..Border::default()
+                        },
+                        shadow: Shadow::default(),
+                    },
+                    appearance.scrollbar_color,
+                );
+            }
+        });
+
+        let header = Rectangle {
+            height: self.header_height,
+            ..bounds
+        };
+        Self::fill(renderer, header, appearance.header_background);
+
+        for (column, (x, width)) in spans.iter().enumerate() {
+            let cell = Rectangle {
+                x: *x,
+                width: *width,
+                ..header
+            };
+
+            if column > 0 {
+                Self::fill(
+                    renderer,
+                    Rectangle { width: 1.0, ..cell },
+                    appearance.divider_color,
+                );
+            }
+
+            self.draw_header(renderer, &appearance, column, cell);
+        }
+
+        // The dragged header follows the cursor, above a marker where it would be dropped.
+        if let (Some((column, origin)), Some(x)) =
+            (table_state.pressed_header, table_state.dragged_header)
+        {
+            let index = self.drop_index(table_state, bounds, column, x);
+            let mut others = spans.iter().enumerate().filter(|(i, _)| *i != column);
+            let marker = others.clone().nth(index).map_or_else(
+                || {
+                    others
+                        .next_back()
+                        .map_or(bounds.x, |(_, (x, width))| x + width)
+                },
+                |(_, (x, _))| *x,
+            );
+
+            Self::fill(
+                renderer,
+                Rectangle {
+                    x: marker - 1.0,
+                    width: 2.0,
+                    ..header
+                },
+                appearance.header_text_color,
+            );
+
+            let (start, width) = spans[column];
+            let cell = Rectangle {
+                x: start + x - origin,
+                width,
+                ..header
+            };
+
+            renderer.with_layer(bounds, |renderer| {
+                Self::fill(renderer, cell, appearance.hovered_background);
+                self.draw_header(renderer, &appearance, column, cell);
+            });
+        }
+
+        Self::fill(
+            renderer,
+            Rectangle {
+                y: header.y + header.height - 1.0,
+                height: 1.0,
+                ..header
+            },
+            Color {
+                a: 0.5,
+                ..appearance.header_text_color
+            },
+        );
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Table<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a,
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(table: Table<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(table)
+    }
+}
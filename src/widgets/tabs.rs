@@ -8,7 +8,9 @@
 
 pub mod tab_bar_position;
 pub use crate::tab_bar::Position;
-use crate::{style::tab_bar::StyleSheet, widgets::tab_bar::TabBar, TabLabel};
+use crate::{
+    core::icons::IconRenderer, style::tab_bar::StyleSheet, widgets::tab_bar::TabBar, TabLabel,
+};
 
 use iced::{
     advanced::{
@@ -80,7 +82,7 @@ where
 
 impl<'a, Message, TabId, Theme, Renderer> Tabs<'a, Message, TabId, Theme, Renderer>
 where
-    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Renderer: 'a + renderer::Renderer + IconRenderer,
     Theme: StyleSheet + text::StyleSheet,
     TabId: Eq + Clone,
 {
@@ -164,7 +166,7 @@ where
     /// [`TabLabel`](super::tab_bar::TabLabel)s of the
     /// [`TabBar`](super::tab_bar::TabBar).
     #[must_use]
-    pub fn icon_font(mut self, font: Font) -> Self {
+    pub fn icon_font(mut self, font: impl Into<Font>) -> Self {
         self.tab_bar = self.tab_bar.icon_font(font);
         self
     }
@@ -177,6 +179,14 @@ where
         self
     }
 
+    /// Sets the id of the [`TabBar`](super::tab_bar::TabBar), to focus it
+    /// with an operation.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<iced::advanced::widget::Id>) -> Self {
+        self.tab_bar = self.tab_bar.id(id);
+        self
+    }
+
     /// Sets the message that will be produced when the close icon of a tab
     /// on the [`TabBar`] is pressed.
     ///
@@ -291,7 +301,7 @@ where
 impl<'a, Message, TabId, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Tabs<'a, Message, TabId, Theme, Renderer>
 where
-    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Renderer: renderer::Renderer + IconRenderer,
     Theme: StyleSheet + text::StyleSheet,
     TabId: Eq + Clone,
 {
@@ -416,7 +426,7 @@ where
         };
 
         let status_tab_bar = self.tab_bar.on_event(
-            &mut Tree::empty(),
+            &mut state.children[0],
             event.clone(),
             tab_bar_layout,
             cursor,
@@ -466,7 +476,7 @@ where
 
         let mut mouse_interaction = mouse::Interaction::default();
         let new_mouse_interaction = self.tab_bar.mouse_interaction(
-            &Tree::empty(),
+            &state.children[0],
             tab_bar_layout,
             cursor,
             viewport,
@@ -526,7 +536,7 @@ where
         };
 
         self.tab_bar.draw(
-            &Tree::empty(),
+            &state.children[0],
             renderer,
             theme,
             style,
@@ -596,13 +606,25 @@ where
         operation: &mut dyn Operation<Message>,
     ) {
         let active_tab = self.tab_bar.get_active_tab_idx();
+        let mut children = layout.children();
+        let (tab_bar_layout, tab_content_layout) = match self.tab_bar_position {
+            TabBarPosition::Top => (children.next(), children.next()),
+            TabBarPosition::Bottom => {
+                let tab_content_layout = children.next();
+                (children.next(), tab_content_layout)
+            }
+        };
+
         operation.container(None, layout.bounds(), &mut |operation| {
+            self.tab_bar.operate(
+                &mut tree.children[0],
+                tab_bar_layout.expect("Native: Layout should have a TabBar layout"),
+                renderer,
+                operation,
+            );
             self.tabs[active_tab].as_widget().operate(
                 &mut tree.children[1].children[active_tab],
-                layout
-                    .children()
-                    .nth(1)
-                    .expect("TabBar is 0th child, contents are 1st node"),
+                tab_content_layout.expect("Native: Layout should have a tab content layout"),
                 renderer,
                 operation,
             );
@@ -613,7 +635,7 @@ where
 impl<'a, Message, TabId, Theme, Renderer> From<Tabs<'a, Message, TabId, Theme, Renderer>>
     for Element<'a, Message, Theme, Renderer>
 where
-    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Renderer: 'a + renderer::Renderer + IconRenderer,
     Theme: 'a + StyleSheet + text::StyleSheet,
     Message: 'a,
     TabId: 'a + Eq + Clone,
@@ -4,6 +4,7 @@
 
 /// A [`TabBarPosition`] for defining the position of a [`TabBar`](crate::native::tab_bar::TabBar).
 #[derive(Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_debug_implementations)]
 pub enum TabBarPosition {
     /// A [`TabBarPosition`] for placing the [`TabBar`](crate::native::tab_bar::TabBar)
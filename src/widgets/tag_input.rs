@@ -0,0 +1,660 @@
+//! Type tags and show them as removable chips.
+//!
+//! *This API requires the following crate features to be activated: `tag_input`*
+
+use crate::core::{
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    settings,
+};
+use crate::widgets::overlay::TagInputOverlay;
+
+use iced::{
+    advanced::{
+        clipboard,
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Quad},
+        text::{self, Paragraph, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event,
+    keyboard::{self, key::Named},
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Element, Event, Font, Length, Padding, Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::marker::PhantomData;
+
+pub use crate::style::tag_input::{Appearance, StyleSheet};
+
+/// The default padding of a [`TagInput`].
+const DEFAULT_PADDING: f32 = 5.0;
+/// The default space between the chips of a [`TagInput`].
+const DEFAULT_SPACING: f32 = 4.0;
+/// The horizontal padding of a chip.
+const CHIP_PADDING: f32 = 6.0;
+/// The smallest width left for typing after the chips.
+const INPUT_MIN_WIDTH: f32 = 60.0;
+/// The largest number of suggestions shown at once.
+const MAX_SUGGESTIONS: usize = 8;
+
+/// A field where typed text becomes removable chips.
+///
+/// Pressing Enter or typing a comma turns the typed text into a tag, unless
+/// it is empty or already present. Backspace on an empty field removes the
+/// last tag. When suggestions are given, the ones containing the typed text
+/// are listed below the field.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::tag_input::TagInput;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TagsChanged(Vec<String>),
+/// }
+///
+/// let tags = vec![String::from("rust")];
+///
+/// let tag_input = TagInput::new("Add a tag", &tags, Message::TagsChanged)
+///     .suggestions(vec![String::from("gui"), String::from("iced")]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TagInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// The current tags.
+    tags: Vec<String>,
+    /// The text shown while there are no tags and nothing is typed.
+    placeholder: String,
+    /// The function producing the message when the tags change.
+    on_change: Box<dyn Fn(Vec<String>) -> Message + 'a>,
+    /// The tags suggested while typing.
+    suggestions: Vec<String>,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: f32,
+    font: Font,
+    /// The style of the [`TagInput`].
+    style: <Theme as StyleSheet>::Style,
+    #[allow(clippy::missing_docs_in_private_items)]
+    renderer: PhantomData<Renderer>,
+}
+
+/// The state of a [`TagInput`].
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    /// The text typed since the last tag.
+    pub(crate) draft: String,
+    /// Whether the field receives the typed text.
+    pub(crate) focused: bool,
+    /// The suggestion selected with the arrow keys or the cursor.
+    pub(crate) highlighted: Option<usize>,
+}
+
+impl<'a, Message, Theme, Renderer> TagInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`TagInput`].
+    ///
+    /// It expects:
+    ///   * the placeholder shown while the field is empty
+    ///   * the current tags
+    ///   * a function that will be called when a tag is added or removed. It
+    ///     receives all the tags and must produce a `Message`.
+    pub fn new<F>(placeholder: &str, tags: &[String], on_change: F) -> Self
+    where
+        F: 'a + Fn(Vec<String>) -> Message,
+    {
+        Self {
+            tags: tags.to_vec(),
+            placeholder: placeholder.to_owned(),
+            on_change: Box::new(on_change),
+            suggestions: Vec::new(),
+            width: Length::Fill,
+            padding: settings::spaced(DEFAULT_PADDING).into(),
+            spacing: settings::spaced(DEFAULT_SPACING),
+            text_size: settings::scaled(16.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+            renderer: PhantomData,
+        }
+    }
+
+    /// Sets the tags suggested while typing.
+    #[must_use]
+    pub fn suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Sets the width of the [`TagInput`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the [`TagInput`].
+    #[must_use]
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the space between the chips of the [`TagInput`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the [`TagInput`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`TagInput`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`TagInput`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the height of a chip.
+    fn chip_height(&self) -> f32 {
+        LineHeight::default()
+            .to_absolute(Pixels(self.text_size))
+            .0
+            .round()
+    }
+
+    /// Returns the width of `content` written in `font` at `size`.
+    fn text_width(content: &str, size: f32, font: Font) -> f32 {
+        Renderer::Paragraph::with_text(Text {
+            content,
+            bounds: Size::INFINITY,
+            size: Pixels(size),
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Top,
+            shaping: Shaping::Advanced,
+        })
+        .min_width()
+    }
+
+    /// Returns the text of `content` centered vertically in `bounds`.
+    fn text(content: &str, bounds: Size, font: Font, size: f32) -> Text<'_, Font> {
+        Text {
+            content,
+            bounds,
+            size: Pixels(size),
+            line_height: LineHeight::default(),
+            font,
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: Shaping::Advanced,
+        }
+    }
+
+    /// Returns the bounds of the button removing the chip with the given bounds.
+    fn remove_button(&self, chip: Rectangle) -> Rectangle {
+        let size = self.text_size * 0.75;
+
+        Rectangle::new(
+            Point::new(
+                chip.x + chip.width - CHIP_PADDING - size,
+                chip.center_y() - size / 2.0,
+            ),
+            Size::new(size, size),
+        )
+    }
+
+    /// Returns the suggestions containing the typed text that are not tags yet.
+    fn matches(&self, draft: &str) -> Vec<String> {
+        let draft = draft.trim().to_lowercase();
+
+        if draft.is_empty() {
+            return Vec::new();
+        }
+
+        self.suggestions
+            .iter()
+            .filter(|suggestion| {
+                suggestion.to_lowercase().contains(&draft) && !self.tags.contains(suggestion)
+            })
+            .take(MAX_SUGGESTIONS)
+            .cloned()
+            .collect()
+    }
+
+    /// Turns `tag` into a new tag and clears the typed text.
+    fn add(&mut self, state: &mut State, tag: &str, shell: &mut Shell<'_, Message>) {
+        if let Some(tags) = with_tag(&self.tags, tag) {
+            self.tags = tags;
+            shell.publish((self.on_change)(self.tags.clone()));
+        }
+
+        state.draft.clear();
+        state.highlighted = None;
+        shell.invalidate_layout();
+    }
+
+    /// Removes the tag at `index`.
+    fn remove(&mut self, index: usize, shell: &mut Shell<'_, Message>) {
+        if index < self.tags.len() {
+            let _ = self.tags.remove(index);
+            shell.publish((self.on_change)(self.tags.clone()));
+            shell.invalidate_layout();
+        }
+    }
+
+    /// Types `text`, where commas and new lines turn the typed text into a tag.
+    fn insert(&mut self, state: &mut State, text: &str, shell: &mut Shell<'_, Message>) {
+        for c in text.chars() {
+            if c == ',' || c == '\n' {
+                let draft = std::mem::take(&mut state.draft);
+                self.add(state, &draft, shell);
+            } else if !c.is_control() {
+                state.draft.push(c);
+            }
+        }
+
+        state.highlighted = None;
+        shell.invalidate_layout();
+    }
+}
+
+/// Returns `tags` with `tag` added, unless it is blank or already present.
+pub(crate) fn with_tag(tags: &[String], tag: &str) -> Option<Vec<String>> {
+    let tag = tag.trim();
+
+    if tag.is_empty() || tags.iter().any(|existing| existing == tag) {
+        return None;
+    }
+
+    let mut tags = tags.to_vec();
+    tags.push(tag.to_owned());
+    Some(tags)
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TagInput<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let state = tree.state.downcast_ref::<State>();
+        let limits = limits.width(self.width);
+        let inner_width = (limits.max().width - self.padding.horizontal()).max(0.0);
+        let chip_height = self.chip_height();
+
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let mut used_width: f32 = 0.0;
+        let mut place = |width: f32, fill: bool| {
+            if x > 0.0 && x + width > inner_width {
+                x = 0.0;
+                y += chip_height + self.spacing;
+            }
+
+            let width = if fill {
+                (inner_width - x).max(width.min(inner_width))
+            } else {
+                width.min(inner_width)
+            };
+            let node = Node::new(Size::new(width, chip_height))
+                .move_to(Point::new(self.padding.left + x, self.padding.top + y));
+
+            used_width = used_width.max(x + width);
+            x += width + self.spacing;
+            node
+        };
+
+        let mut children: Vec<Node> = self
+            .tags
+            .iter()
+            .map(|tag| {
+                let width = CHIP_PADDING * 2.5
+                    + Self::text_width(tag, self.text_size, self.font)
+                    + self.text_size * 0.75;
+                place(width, false)
+            })
+            .collect();
+
+        let typed = if state.draft.is_empty() && self.tags.is_empty() {
+            &self.placeholder
+        } else {
+            &state.draft
+        };
+        let input_width =
+            (Self::text_width(typed, self.text_size, self.font) + 2.0).max(INPUT_MIN_WIDTH);
+        children.push(place(input_width, true));
+
+        let size = Size::new(
+            used_width + self.padding.horizontal(),
+            y + chip_height + self.padding.vertical(),
+        );
+
+        Node::with_children(
+            limits
+                .height(Length::Shrink)
+                .resolve(self.width, Length::Shrink, size),
+            children,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    state.focused = false;
+                    state.highlighted = None;
+                    return event::Status::Ignored;
+                };
+
+                state.focused = true;
+
+                if let Some(index) = layout
+                    .children()
+                    .take(self.tags.len())
+                    .position(|chip| self.remove_button(chip.bounds()).contains(position))
+                {
+                    self.remove(index, shell);
+                }
+
+                event::Status::Captured
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) if state.focused => {
+                match key.as_ref() {
+                    keyboard::Key::Named(Named::Enter) => {
+                        let tag = state
+                            .highlighted
+                            .and_then(|index| self.matches(&state.draft).into_iter().nth(index))
+                            .unwrap_or_else(|| state.draft.clone());
+                        self.add(state, &tag, shell);
+                    }
+                    keyboard::Key::Named(Named::Backspace) => {
+                        if state.draft.pop().is_none() {
+                            if let Some(last) = self.tags.len().checked_sub(1) {
+                                self.remove(last, shell);
+                            }
+                        }
+                        state.highlighted = None;
+                        shell.invalidate_layout();
+                    }
+                    keyboard::Key::Named(Named::Escape) => {
+                        state.focused = false;
+                        state.highlighted = None;
+                    }
+                    keyboard::Key::Named(named @ (Named::ArrowDown | Named::ArrowUp)) => {
+                        let count = self.matches(&state.draft).len();
+
+                        if count == 0 {
+                            return event::Status::Ignored;
+                        }
+
+                        state.highlighted = Some(match (state.highlighted, named) {
+                            (None, Named::ArrowDown) => 0,
+                            (None, _) => count - 1,
+                            (Some(index), Named::ArrowDown) => (index + 1) % count,
+                            (Some(index), _) => (index + count - 1) % count,
+                        });
+                    }
+                    keyboard::Key::Character("v") if modifiers.command() => {
+                        if let Some(content) = clipboard.read(clipboard::Kind::Standard) {
+                            self.insert(state, &content, shell);
+                        }
+                    }
+                    _ => match text {
+                        Some(text) if !modifiers.command() => self.insert(state, &text, shell),
+                        _ => return event::Status::Ignored,
+                    },
+                }
+
+                event::Status::Captured
+            }
+
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return mouse::Interaction::default();
+        };
+
+        if layout
+            .children()
+            .take(self.tags.len())
+            .any(|chip| self.remove_button(chip.bounds()).contains(position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::Text
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = if state.focused {
+            theme.focused(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: appearance.border_radius.into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            let mut children = layout.children();
+
+            for (tag, chip) in self.tags.iter().zip(children.by_ref()) {
+                let chip = chip.bounds();
+                let remove = self.remove_button(chip);
+
+                renderer.fill_quad(
+                    Quad {
+                        bounds: chip,
+                        border: Border::with_radius(appearance.chip_border_radius),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.chip_background,
+                );
+
+                let label = Rectangle {
+                    x: chip.x + CHIP_PADDING,
+                    width: (remove.x - chip.x - CHIP_PADDING * 1.5).max(0.0),
+                    ..chip
+                };
+                renderer.fill_text(
+                    Self::text(tag, label.size(), self.font, self.text_size),
+                    Point::new(label.x, label.center_y()),
+                    appearance.chip_text_color,
+                    label,
+                );
+
+                renderer.fill_text(
+                    Text {
+                        horizontal_alignment: Horizontal::Center,
+                        ..Self::text(
+                            &icon_to_string(Bootstrap::X),
+                            remove.size(),
+                            BOOTSTRAP_FONT,
+                            remove.height,
+                        )
+                    },
+                    remove.center(),
+                    appearance.chip_text_color,
+                    remove,
+                );
+            }
+
+            let Some(input) = children.next().map(|input| input.bounds()) else {
+                return;
+            };
+
+            if state.draft.is_empty() && self.tags.is_empty() {
+                renderer.fill_text(
+                    Self::text(&self.placeholder, input.size(), self.font, self.text_size),
+                    Point::new(input.x, input.center_y()),
+                    appearance.placeholder_color,
+                    input,
+                );
+            } else {
+                renderer.fill_text(
+                    Self::text(&state.draft, input.size(), self.font, self.text_size),
+                    Point::new(input.x, input.center_y()),
+                    appearance.text_color,
+                    input,
+                );
+            }
+
+            if state.focused {
+                let caret_x = if state.draft.is_empty() {
+                    0.0
+                } else {
+                    Self::text_width(&state.draft, self.text_size, self.font)
+                };
+
+                renderer.fill_quad(
+                    Quad {
+                        bounds: Rectangle::new(
+                            Point::new(input.x + caret_x.min(input.width - 1.0), input.y),
+                            Size::new(1.0, input.height),
+                        ),
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.text_color,
+                );
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.focused {
+            return None;
+        }
+
+        let matches = self.matches(&state.draft);
+
+        if matches.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(overlay::Element::new(Box::new(TagInputOverlay::new(
+            state,
+            &self.tags,
+            &*self.on_change,
+            matches,
+            bounds.position() + translation,
+            bounds.size(),
+            self.text_size,
+            self.font,
+            &self.style,
+        ))))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TagInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(tag_input: TagInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tag_input)
+    }
+}
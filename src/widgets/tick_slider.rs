@@ -0,0 +1,705 @@
+//! Select a value on a slider with tick marks and labels.
+//!
+//! *This API requires the following crate features to be activated: `tick_slider`*
+
+use crate::core::settings;
+
+use crate::widgets::overlay::TickSliderOverlay;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    mouse::{self, Cursor},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    Border, Color, Element, Event, Font, Length, Pixels, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::{fmt::Display, ops::RangeInclusive};
+
+pub use crate::style::tick_slider::{Appearance, StyleSheet};
+
+/// The default height of the rail of a [`TickSlider`].
+const DEFAULT_HEIGHT: f32 = 22.0;
+/// The number of steps moved by the Page Up and Page Down keys.
+const PAGE_STEPS: f64 = 10.0;
+/// The length of the minor tick marks.
+const TICK_LENGTH: f32 = 4.0;
+/// The length of the major tick marks.
+const MAJOR_TICK_LENGTH: f32 = 8.0;
+/// The space between the tick marks and the labels.
+const LABEL_GAP: f32 = 2.0;
+/// The smallest space between two tick marks drawing the minor ones.
+const MIN_TICK_SPACING: f32 = 3.0;
+/// The largest number of tick marks.
+const MAX_TICKS: usize = 10_000;
+
+/// A slider selecting a value, with tick marks at its steps and optional
+/// labels under its major tick marks.
+///
+/// While the handle is dragged, a bubble above it shows the value. The value
+/// snaps to the step, or to the tick marks with
+/// [`snap_to_ticks`](Self::snap_to_ticks). A pressed handle can be moved
+/// with the arrow keys, as well as with Page Up, Page Down, Home and End.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::tick_slider::TickSlider;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     VolumeChanged(f32),
+/// }
+///
+/// let slider = TickSlider::new(0.0..=100.0, 40.0, Message::VolumeChanged)
+///     .ticks(10.0)
+///     .major_ticks(5)
+///     .labels(true)
+///     .snap_to_ticks(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TickSlider<'a, T, Message, Theme = iced::Theme>
+where
+    Theme: StyleSheet,
+{
+    /// The range of the values.
+    range: RangeInclusive<T>,
+    /// The value.
+    value: T,
+    /// The step the value snaps to.
+    step: T,
+    /// The space between the tick marks, the step by default.
+    ticks: Option<T>,
+    /// The number of tick marks from a major one to the next one, or zero
+    /// when no tick mark is major.
+    major_ticks: usize,
+    /// Whether the values of the major tick marks are shown under them.
+    labels: bool,
+    /// Whether the value snaps to the tick marks rather than to the step.
+    snap_to_ticks: bool,
+    /// Whether the value is shown above the handle while dragging.
+    bubble: bool,
+    /// The function formatting the labels and the value of the bubble.
+    format: Option<Box<dyn Fn(T) -> String + 'a>>,
+    /// The function producing the message when the value changes.
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    /// The optional message produced when the handle is released.
+    on_release: Option<Message>,
+    width: Length,
+    height: f32,
+    text_size: f32,
+    font: Font,
+    /// The style of the [`TickSlider`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`TickSlider`].
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the handle is dragged.
+    dragging: bool,
+    /// Whether the handle is moved by the keyboard.
+    focused: bool,
+}
+
+impl<'a, T, Message, Theme> TickSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`TickSlider`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current value of the slider
+    ///   * a function that will be called when the handle is moved. It
+    ///     receives the new value and must produce a `Message`.
+    pub fn new<F>(range: RangeInclusive<T>, value: T, on_change: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        let value = if value < *range.start() {
+            *range.start()
+        } else if value > *range.end() {
+            *range.end()
+        } else {
+            value
+        };
+
+        Self {
+            range,
+            value,
+            step: T::from(1),
+            ticks: None,
+            major_ticks: 0,
+            labels: false,
+            snap_to_ticks: false,
+            bubble: true,
+            format: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: DEFAULT_HEIGHT,
+            text_size: settings::scaled(12.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the step the value snaps to.
+    #[must_use]
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the space between the tick marks, the step by default.
+    #[must_use]
+    pub fn ticks(mut self, every: impl Into<T>) -> Self {
+        self.ticks = Some(every.into());
+        self
+    }
+
+    /// Makes every `every`-th tick mark major, starting with the first one.
+    #[must_use]
+    pub fn major_ticks(mut self, every: usize) -> Self {
+        self.major_ticks = every;
+        self
+    }
+
+    /// Sets whether the values of the major tick marks are shown under them.
+    #[must_use]
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets whether the value snaps to the tick marks rather than to the step.
+    #[must_use]
+    pub fn snap_to_ticks(mut self, snap_to_ticks: bool) -> Self {
+        self.snap_to_ticks = snap_to_ticks;
+        self
+    }
+
+    /// Sets whether the value is shown above the handle while dragging.
+    #[must_use]
+    pub fn bubble(mut self, bubble: bool) -> Self {
+        self.bubble = bubble;
+        self
+    }
+
+    /// Sets the function formatting the labels and the value shown while
+    /// dragging.
+    #[must_use]
+    pub fn format(mut self, format: impl Fn(T) -> String + 'a) -> Self {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the message produced when the handle is released.
+    #[must_use]
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`TickSlider`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the rail of the [`TickSlider`], above its tick
+    /// marks and labels.
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the labels and of the value shown while dragging.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the labels and of the value shown while dragging.
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`TickSlider`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<T, Message, Theme> TickSlider<'_, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + Display,
+    Theme: StyleSheet,
+{
+    /// Returns the start and the end of the range.
+    fn bounds_of_range(&self) -> (f64, f64) {
+        ((*self.range.start()).into(), (*self.range.end()).into())
+    }
+
+    /// Returns the space between the tick marks.
+    fn tick_spacing(&self) -> f64 {
+        self.ticks.unwrap_or(self.step).into()
+    }
+
+    /// Returns the increment the value snaps to.
+    fn increment(&self) -> f64 {
+        if self.snap_to_ticks {
+            self.tick_spacing()
+        } else {
+            self.step.into()
+        }
+    }
+
+    /// Returns the values of the tick marks, and whether they are major.
+    fn tick_values(&self) -> impl Iterator<Item = (f64, bool)> {
+        let (start, end) = self.bounds_of_range();
+        let spacing = self.tick_spacing();
+        let major_ticks = self.major_ticks;
+
+        let count = if spacing > 0.0 && end >= start {
+            // The tolerance keeps the tick mark at the end of the range.
+            (((end - start) / spacing + 1e-9).floor() as usize).min(MAX_TICKS) + 1
+        } else {
+            0
+        };
+
+        (0..count).map(move |i| {
+            (
+                start + i as f64 * spacing,
+                major_ticks > 0 && i % major_ticks == 0,
+            )
+        })
+    }
+
+    /// Returns whether the labels are shown.
+    fn has_labels(&self) -> bool {
+        self.labels && self.major_ticks > 0
+    }
+
+    /// Returns the height of the labels.
+    fn label_height(&self) -> f32 {
+        LineHeight::default().to_absolute(Pixels(self.text_size)).0
+    }
+
+    /// Returns the height of the whole [`TickSlider`].
+    fn total_height(&self) -> f32 {
+        let labels = if self.has_labels() {
+            LABEL_GAP + self.label_height()
+        } else {
+            0.0
+        };
+
+        self.height + MAJOR_TICK_LENGTH + labels
+    }
+
+    /// Returns the bounds of the rail area of the [`TickSlider`].
+    fn rail_bounds(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            height: self.height,
+            ..bounds
+        }
+    }
+
+    /// Returns the text of `value`.
+    fn label(&self, value: f64) -> String {
+        // The rounding hides the error of the computed tick values, like
+        // `0.30000000000000004`.
+        let Some(value) = T::from_f64((value * 1e9).round() / 1e9) else {
+            return String::new();
+        };
+
+        self.format
+            .as_ref()
+            .map_or_else(|| value.to_string(), |format| format(value))
+    }
+
+    /// Returns the horizontal position of `value` on the rail of the given bounds,
+    /// inset by the radius of the handle.
+    fn position(&self, bounds: Rectangle, radius: f32, value: f64) -> f32 {
+        let (start, end) = self.bounds_of_range();
+        let rail = (bounds.width - radius * 2.0).max(0.0);
+        let ratio = if end > start {
+            ((value - start) / (end - start)) as f32
+        } else {
+            0.0
+        };
+
+        bounds.x + radius + rail * ratio
+    }
+
+    /// Returns the value at the horizontal position `x` of the rail, snapped.
+    fn value_at(&self, bounds: Rectangle, radius: f32, x: f32) -> f64 {
+        let (start, end) = self.bounds_of_range();
+        let rail = (bounds.width - radius * 2.0).max(1.0);
+        let ratio = f64::from(((x - bounds.x - radius) / rail).clamp(0.0, 1.0));
+
+        self.snap(start + ratio * (end - start))
+    }
+
+    /// Snaps `value` to the increment and to the range.
+    fn snap(&self, value: f64) -> f64 {
+        let (start, end) = self.bounds_of_range();
+        let increment = self.increment();
+
+        let snapped = if increment > 0.0 {
+            ((value - start) / increment).round() * increment + start
+        } else {
+            value
+        };
+
+        snapped.clamp(start, end)
+    }
+
+    /// Moves the handle to `value`, and publishes it if it changed.
+    fn set(&mut self, value: f64, shell: &mut Shell<'_, Message>) {
+        if (value - self.value.into()).abs() <= f64::EPSILON {
+            return;
+        }
+
+        let Some(value) = T::from_f64(value) else {
+            return;
+        };
+
+        self.value = value;
+        shell.publish((self.on_change)(value));
+    }
+
+    /// Returns the bounds of the handle, for the given radius.
+    fn handle_bounds(&self, bounds: Rectangle, radius: f32) -> Rectangle {
+        let rail = self.rail_bounds(bounds);
+        let x = self.position(rail, radius, self.value.into());
+
+        Rectangle::new(
+            Point::new(x - radius, rail.center_y() - radius),
+            Size::new(radius * 2.0, radius * 2.0),
+        )
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TickSlider<'_, T, Message, Theme>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + Display,
+    Message: Clone,
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Fixed(self.total_height()))
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        let height = Length::Fixed(self.total_height());
+
+        Node::new(
+            limits
+                .width(self.width)
+                .height(height)
+                .resolve(self.width, height, Size::ZERO),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let rail = self.rail_bounds(bounds);
+        // The handle is inset by its default radius, the style being unknown
+        // here.
+        let radius = Appearance::default().handle_radius;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    let value = self.value_at(rail, radius, position.x);
+
+                    state.dragging = true;
+                    state.focused = true;
+
+                    self.set(value, shell);
+                    return event::Status::Captured;
+                }
+
+                state.focused = false;
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.dragging =>
+            {
+                let value = self.value_at(rail, radius, position.x);
+                self.set(value, shell);
+                return event::Status::Captured;
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.dragging =>
+            {
+                state.dragging = false;
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+                return event::Status::Captured;
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if state.focused => {
+                let (start, end) = self.bounds_of_range();
+                let increment = self.increment();
+                let value = self.value.into();
+
+                let target = match key {
+                    keyboard::key::Named::ArrowLeft | keyboard::key::Named::ArrowDown => {
+                        value - increment
+                    }
+                    keyboard::key::Named::ArrowRight | keyboard::key::Named::ArrowUp => {
+                        value + increment
+                    }
+                    keyboard::key::Named::PageDown => value - increment * PAGE_STEPS,
+                    keyboard::key::Named::PageUp => value + increment * PAGE_STEPS,
+                    keyboard::key::Named::Home => start,
+                    keyboard::key::Named::End => end,
+                    _ => return event::Status::Ignored,
+                };
+
+                let target = self.snap(target);
+                self.set(target, shell);
+                return event::Status::Captured;
+            }
+
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State>().dragging {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let appearance = if state.dragging {
+            theme.dragging(&self.style)
+        } else if cursor.is_over(bounds) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+        let radius = Appearance::default().handle_radius;
+        let rail_bounds = self.rail_bounds(bounds);
+        let x = self.position(rail_bounds, radius, self.value.into());
+        let rail_y = rail_bounds.center_y() - appearance.rail_width / 2.0;
+        let rail = |x: f32, width: f32| Quad {
+            bounds: Rectangle::new(
+                Point::new(x, rail_y),
+                Size::new(width, appearance.rail_width),
+            ),
+            border: Border::with_radius(appearance.rail_width / 2.0),
+            shadow: Shadow::default(),
+        };
+
+        renderer.fill_quad(
+            rail(bounds.x + radius, (bounds.width - radius * 2.0).max(0.0)),
+            appearance.rail_color,
+        );
+        renderer.fill_quad(
+            rail(bounds.x + radius, x - bounds.x - radius),
+            appearance.filled_color,
+        );
+
+        let (start, end) = self.bounds_of_range();
+        let spacing = self.tick_spacing();
+        let rail_length = (bounds.width - radius * 2.0).max(0.0);
+        let draw_minor =
+            end > start && (spacing / (end - start)) as f32 * rail_length >= MIN_TICK_SPACING;
+        let ticks_y = rail_bounds.y + rail_bounds.height;
+
+        for (value, major) in self.tick_values() {
+            if !major && !draw_minor {
+                continue;
+            }
+
+            let tick_x = self.position(rail_bounds, radius, value);
+            let (length, color) = if major {
+                (MAJOR_TICK_LENGTH, appearance.major_tick_color)
+            } else {
+                (TICK_LENGTH, appearance.tick_color)
+            };
+
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle::new(
+                        Point::new(tick_x - 0.5, ticks_y),
+                        Size::new(1.0, length),
+                    ),
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                color,
+            );
+
+            if major && self.has_labels() {
+                let label = self.label(value);
+                let label_bounds = Rectangle::new(
+                    Point::new(
+                        tick_x - bounds.width / 2.0,
+                        ticks_y + MAJOR_TICK_LENGTH + LABEL_GAP,
+                    ),
+                    Size::new(bounds.width, self.label_height()),
+                );
+
+                renderer.fill_text(
+                    Text {
+                        content: &label,
+                        bounds: label_bounds.size(),
+                        size: Pixels(self.text_size),
+                        line_height: LineHeight::default(),
+                        font: self.font,
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                        shaping: Shaping::Advanced,
+                    },
+                    label_bounds.center(),
+                    appearance.label_color,
+                    label_bounds,
+                );
+            }
+        }
+
+        let handle_radius = appearance.handle_radius;
+
+        if state.focused {
+            let ring = handle_radius + 3.0;
+            renderer.fill_quad(
+                Quad {
+                    bounds: Rectangle::new(
+                        Point::new(x - ring, rail_bounds.center_y() - ring),
+                        Size::new(ring * 2.0, ring * 2.0),
+                    ),
+                    border: Border::with_radius(ring),
+                    shadow: Shadow::default(),
+                },
+                appearance.focus_color,
+            );
+        }
+
+        renderer.fill_quad(
+            Quad {
+                bounds: self.handle_bounds(bounds, handle_radius),
+                border: Border {
+                    radius: handle_radius.into(),
+                    width: appearance.handle_border_width,
+                    color: appearance.handle_border_color,
+                },
+                shadow: Shadow {
+                    color: Color::from_rgba(0.0, 0.0, 0.0, 0.15),
+                    offset: Vector::new(0.0, 1.0),
+                    blur_radius: 2.0,
+                },
+            },
+            appearance.handle_color,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        if !self.bubble || !tree.state.downcast_ref::<State>().dragging {
+            return None;
+        }
+
+        let handle = self.handle_bounds(layout.bounds(), Appearance::default().handle_radius);
+
+        Some(overlay::Element::new(Box::new(TickSliderOverlay::new(
+            self.label(self.value.into()),
+            handle + translation,
+            self.text_size,
+            self.font,
+            &self.style,
+        ))))
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<TickSlider<'a, T, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a + Copy + Into<f64> + num_traits::FromPrimitive + Display,
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(slider: TickSlider<'a, T, Message, Theme>) -> Self {
+        Element::new(slider)
+    }
+}
@@ -3,13 +3,17 @@
 //! *This API requires the following crate features to be activated: `time_picker`*
 
 use super::overlay::time_picker::{self, TimePickerOverlay, TimePickerOverlayButtons};
+use crate::core::accessibility::{self, Action, Role};
 
 use chrono::Local;
 use iced::{
     advanced::{
         layout::{Limits, Node},
         overlay, renderer,
-        widget::tree::{self, Tag, Tree},
+        widget::{
+            self,
+            tree::{self, Tag, Tree},
+        },
         Clipboard, Layout, Shell, Widget,
     },
     event,
@@ -28,6 +32,7 @@ use iced::{
 pub use crate::{
     core::time::{Period, Time},
     style::time_picker::{Appearance, StyleSheet},
+    widgets::overlay::time_picker::Focus,
 };
 
 //TODO: Remove ignore when Null is updated. Temp fix for Test runs
@@ -62,6 +67,8 @@ where
 {
     /// Show the picker.
     show_picker: bool,
+    /// The id of the [`TimePicker`].
+    id: Option<widget::Id>,
     /// The time to show.
     time: Time,
     /// The underlying element.
@@ -78,6 +85,8 @@ where
     use_24h: bool,
     /// Toggle the use of the seconds of the [`TimePickerOverlay`].
     show_seconds: bool,
+    /// The parts of the overlay reached with Tab, in order.
+    tab_order: Vec<Focus>,
 }
 
 impl<'a, Message, Theme> TimePicker<'a, Message, Theme>
@@ -108,6 +117,7 @@ where
     {
         Self {
             show_picker,
+            id: None,
             time: time.into(),
             underlay: underlay.into(),
             on_cancel,
@@ -116,6 +126,7 @@ where
             overlay_state: TimePickerOverlayButtons::default().into(),
             use_24h: false,
             show_seconds: false,
+            tab_order: Vec::new(),
         }
     }
 
@@ -126,10 +137,31 @@ where
         self
     }
 
+    /// Sets the id of the [`TimePicker`], to focus its overlay with an
+    /// operation while it is shown. The focused overlay takes the keyboard
+    /// input, and Tab moves the focus between its parts.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the parts of the overlay of the [`TimePicker`] reached with Tab, in
+    /// order. The parts left out are skipped; by default all of them are
+    /// reached.
+    #[must_use]
+    pub fn tab_order(mut self, tab_order: impl IntoIterator<Item = Focus>) -> Self {
+        self.tab_order = tab_order
+            .into_iter()
+            .filter(|part| *part != Focus::None)
+            .collect();
+        self
+    }
+
     /// Sets the style of the [`TimePicker`].
     #[must_use]
-    pub fn style(mut self, style: <Theme as StyleSheet>::Style) -> Self {
-        self.style = style;
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
         self
     }
 
@@ -264,6 +296,29 @@ where
         );
     }
 
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        let mut node = accessibility::Node::new(Role::TimePicker)
+            .name(self.time.to_string())
+            .expanded(self.show_picker);
+
+        if self.show_picker {
+            let picker_state: &mut State = state.state.downcast_mut();
+            operation.focusable(&mut picker_state.overlay_state, self.id.as_ref());
+            operation.custom(&mut node.action(Action::Focus), self.id.as_ref());
+        } else {
+            operation.custom(&mut node, self.id.as_ref());
+            self.underlay
+                .as_widget()
+                .operate(&mut state.children[0], layout, renderer, operation);
+        }
+    }
+
     fn overlay<'b>(
         &'b mut self,
         state: &'b mut Tree,
@@ -294,6 +349,7 @@ where
                 self.style.clone(),
                 &mut state.children[1],
             )
+            .tab_order(&self.tab_order)
             .overlay(),
         )
     }
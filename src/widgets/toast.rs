@@ -0,0 +1,520 @@
+//! Show transient notifications stacked in a corner of the window.
+//!
+//! *This API requires the following crate features to be activated: toast*
+
+use super::overlay::toast::ToastOverlay;
+
+use crate::core::{
+    animation::{self, Timeline},
+    settings,
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay::{self, Group},
+        renderer, text,
+        widget::{
+            tree::{self, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    window, Element, Event, Length, Rectangle, Size, Vector,
+};
+use std::time::{Duration, Instant};
+
+pub use crate::style::toast::{Appearance, StyleSheet};
+
+/// The duration of the enter and exit animations of a [`Toast`].
+const ANIMATION_DURATION: Duration = Duration::from_millis(200);
+
+/// A transient notification shown by a [`ToastManager`].
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::toast::{Severity, Toast};
+/// # use std::time::Duration;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Undo,
+/// }
+///
+/// let toast = Toast::new("File deleted")
+///     .body("notes.txt was moved to the trash.")
+///     .severity(Severity::Warning)
+///     .timeout(Duration::from_secs(8))
+///     .action("Undo", Message::Undo);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Toast<Message> {
+    /// The title of the [`Toast`].
+    pub title: String,
+    /// The body of the [`Toast`], shown under the title.
+    pub body: Option<String>,
+    /// The severity of the [`Toast`], setting its accent color.
+    pub severity: Severity,
+    /// The duration after which the [`Toast`] is dismissed, if any.
+    pub timeout: Option<Duration>,
+    /// The label and message of the action button of the [`Toast`].
+    pub action: Option<(String, Message)>,
+}
+
+/// The severity of a [`Toast`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// The toast informs the user.
+    #[default]
+    Info,
+    /// The toast reports a success.
+    Success,
+    /// The toast warns the user.
+    Warning,
+    /// The toast reports an error.
+    Error,
+}
+
+/// The corner of the window in which the toasts of a [`ToastManager`] are stacked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Corner {
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    #[default]
+    BottomRight,
+}
+
+impl<Message> Toast<Message> {
+    /// Creates a new [`Toast`] with the given title, dismissed after five seconds.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: None,
+            severity: Severity::default(),
+            timeout: Some(Duration::from_secs(5)),
+            action: None,
+        }
+    }
+
+    /// Sets the body of the [`Toast`].
+    #[must_use]
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Sets the severity of the [`Toast`].
+    #[must_use]
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Sets the duration after which the [`Toast`] is dismissed.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Keeps the [`Toast`] shown until it is closed by the user.
+    #[must_use]
+    pub fn persistent(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+
+    /// Sets the action button of the [`Toast`]. Pressing it publishes the
+    /// message and dismisses the [`Toast`].
+    #[must_use]
+    pub fn action(mut self, label: impl Into<String>, message: Message) -> Self {
+        self.action = Some((label.into(), message));
+        self
+    }
+}
+
+/// A container showing the toasts of the application over its content.
+///
+/// The application owns the queue of toasts. Once a toast was dismissed, by
+/// its timeout or by the user, `on_dismiss` is called with its index and the
+/// application is expected to remove it from the queue.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::toast::{Toast, ToastManager};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Dismiss(usize),
+/// }
+///
+/// let toasts = vec![Toast::new("Saved")];
+///
+/// let manager = ToastManager::new(Text::new("Content"), &toasts, Message::Dismiss);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ToastManager<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The underlying element.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The queue of toasts.
+    toasts: &'a [Toast<Message>],
+    /// The function producing the message when a toast was dismissed.
+    on_dismiss: Box<dyn Fn(usize) -> Message + 'a>,
+    /// The corner in which the toasts are stacked.
+    corner: Corner,
+    /// The maximum number of toasts shown at once, the others waiting in the queue.
+    max_visible: usize,
+    /// The width of the toasts.
+    width: f32,
+    /// The space between the toasts.
+    spacing: f32,
+    /// The space between the toasts and the edges of the window.
+    padding: f32,
+    /// The text size of the toasts.
+    text_size: f32,
+    /// The style of the toasts.
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`ToastManager`].
+#[derive(Debug, Default)]
+pub struct State {
+    /// The state of each toast of the queue.
+    pub(crate) toasts: Vec<ToastState>,
+}
+
+/// The state of a [`Toast`] of a [`ToastManager`].
+#[derive(Debug, Default)]
+pub(crate) struct ToastState {
+    /// The progress of the enter animation, from 0 (hidden) to 1 (shown).
+    pub(crate) enter: Timeline,
+    /// Whether the toast was shown.
+    shown: bool,
+    /// The moment the toast times out.
+    expires_at: Option<Instant>,
+    /// Whether the toast is being dismissed.
+    pub(crate) leaving: bool,
+    /// Whether the toast was dismissed, until the application removes it.
+    dismissed: bool,
+}
+
+impl State {
+    /// Returns the indices of the toasts shown at once, the others waiting in the queue.
+    pub(crate) fn visible(&self, max_visible: usize) -> Vec<usize> {
+        self.toasts
+            .iter()
+            .enumerate()
+            .filter(|(_, toast)| !toast.dismissed)
+            .map(|(i, _)| i)
+            .take(max_visible)
+            .collect()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> ToastManager<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`ToastManager`] showing the `toasts` over the `content`.
+    ///
+    /// It expects:
+    ///     * the content [`Element`] over which the toasts are shown.
+    ///     * the queue of toasts, owned by the application.
+    ///     * the function producing the message when the toast at the given index was dismissed.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        toasts: &'a [Toast<Message>],
+        on_dismiss: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            toasts,
+            on_dismiss: Box::new(on_dismiss),
+            corner: Corner::default(),
+            max_visible: 5,
+            width: 320.0,
+            spacing: settings::spaced(8.0),
+            padding: settings::spaced(16.0),
+            text_size: settings::scaled(16.0),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the corner of the window in which the toasts are stacked.
+    #[must_use]
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Sets the maximum number of toasts shown at once, the others waiting in the queue.
+    #[must_use]
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible;
+        self
+    }
+
+    /// Sets the width of the toasts.
+    #[must_use]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the space between the toasts.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the space between the toasts and the edges of the window.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the toasts.
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the style of the toasts.
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ToastManager<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer + text::Renderer<Font = iced::Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            toasts: self.toasts.iter().map(|_| ToastState::default()).collect(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // The application removes the dismissed toasts from its queue, and
+        // pushes the new ones at its end.
+        while state.toasts.len() > self.toasts.len() {
+            let index = state
+                .toasts
+                .iter()
+                .position(|toast| toast.dismissed)
+                .unwrap_or(state.toasts.len() - 1);
+            let _ = state.toasts.remove(index);
+        }
+        state
+            .toasts
+            .resize_with(self.toasts.len(), ToastState::default);
+
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(_id, window::Event::RedrawRequested(now)) = event {
+            let toast_state = state.state.downcast_mut::<State>();
+            let mut animating = false;
+            let mut next_expiry: Option<Instant> = None;
+
+            for i in toast_state.visible(self.max_visible) {
+                let toast = &mut toast_state.toasts[i];
+
+                if !toast.shown {
+                    toast.shown = true;
+                    toast.expires_at = self.toasts[i].timeout.map(|timeout| now + timeout);
+                }
+
+                if toast.expires_at.is_some_and(|expires_at| now >= expires_at) {
+                    toast.leaving = true;
+                }
+
+                let target = if toast.leaving { 0.0 } else { 1.0 };
+                let _ = toast.enter.advance_to(target, ANIMATION_DURATION, now);
+
+                if toast.leaving && toast.enter.progress() <= 0.0 {
+                    toast.dismissed = true;
+                    shell.publish((self.on_dismiss)(i));
+                }
+
+                if toast.leaving || toast.enter.progress() < 1.0 {
+                    animating = true;
+                } else if let Some(expires_at) = toast.expires_at {
+                    next_expiry = Some(next_expiry.map_or(expires_at, |next| next.min(expires_at)));
+                }
+            }
+
+            if animating {
+                animation::request_frame(shell, now);
+            } else if let Some(next_expiry) = next_expiry {
+                shell.request_redraw(window::RedrawRequest::At(next_expiry));
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut state.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &state.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &state.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate<'b>(
+        &'b self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut state.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut group = Group::new();
+
+        if let Some(content) = self.content.as_widget_mut().overlay(
+            &mut state.children[0],
+            layout,
+            renderer,
+            translation,
+        ) {
+            group = group.push(content);
+        }
+
+        let toast_state = state.state.downcast_mut::<State>();
+        let visible = toast_state.visible(self.max_visible);
+
+        if !visible.is_empty() {
+            group = group.push(overlay::Element::new(Box::new(ToastOverlay::new(
+                toast_state,
+                self.toasts,
+                visible,
+                self.corner,
+                self.width,
+                self.spacing,
+                self.padding,
+                self.text_size,
+                &self.style,
+            ))));
+        }
+
+        Some(group.overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ToastManager<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer + text::Renderer<Font = iced::Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(manager: ToastManager<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(manager)
+    }
+}
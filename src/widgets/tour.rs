@@ -0,0 +1,385 @@
+//! Guide the user through a sequence of highlighted widgets, like an
+//! onboarding tour.
+//!
+//! *This API requires the following crate features to be activated: `tour`*
+
+use crate::core::settings;
+
+use super::overlay::tour::TourOverlay;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay::{self, Group},
+        renderer, text,
+        widget::{Id, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    widget::container,
+    Element, Event, Font, Length, Rectangle, Size, Vector,
+};
+
+pub use crate::style::tour::{Appearance, StyleSheet};
+
+/// A step of a [`Tour`], explaining the widget it highlights.
+#[derive(Clone, Debug)]
+pub struct TourStep {
+    /// The id of the container holding the highlighted widget.
+    pub target: container::Id,
+    /// The title of the step.
+    pub title: String,
+    /// The explanation of the step.
+    pub text: String,
+}
+
+impl TourStep {
+    /// Creates a new [`TourStep`] highlighting the container with the
+    /// `target` id.
+    pub fn new(target: container::Id, title: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            target,
+            title: title.into(),
+            text: text.into(),
+        }
+    }
+}
+
+/// The labels of the buttons of a [`Tour`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TourLabels {
+    /// The label of the button going to the previous step.
+    pub back: String,
+    /// The label of the button going to the next step.
+    pub next: String,
+    /// The label of the button ending the tour early.
+    pub skip: String,
+    /// The label of the button of the last step.
+    pub done: String,
+}
+
+impl Default for TourLabels {
+    fn default() -> Self {
+        Self {
+            back: String::from("Back"),
+            next: String::from("Next"),
+            skip: String::from("Skip"),
+            done: String::from("Done"),
+        }
+    }
+}
+
+/// A widget showing a tour over its content: a sequence of steps each
+/// highlighting a widget of the content, cut out of a dimmed backdrop, next
+/// to a popover explaining it.
+///
+/// The highlighted widgets are found by the ids of their
+/// [`Container`](iced::widget::Container)s. The popover is centered when the
+/// target of a step is not found. The highlighted widget stays interactive,
+/// while the rest of the application is blocked until the tour ends.
+///
+/// The application keeps the current step, receiving the next one when the
+/// user moves through the tour, or `None` when the tour is skipped or done.
+/// The arrow keys and Enter move through the steps, and Escape skips the tour.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::{button, container, text};
+/// # use iced_aw::tour::{Tour, TourStep};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TourStep(Option<usize>),
+/// }
+///
+/// let steps = vec![
+///     TourStep::new(container::Id::new("save"), "Save", "Saves the document."),
+///     TourStep::new(container::Id::new("share"), "Share", "Shares it with others."),
+/// ];
+///
+/// let tour = Tour::new(content, &steps, Some(0), Message::TourStep);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Tour<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// The content of the application.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The steps of the tour.
+    steps: &'a [TourStep],
+    /// The index of the current step, or `None` when the tour is not shown.
+    current: Option<usize>,
+    /// The function producing the message with the next step.
+    on_step: Box<dyn Fn(Option<usize>) -> Message + 'a>,
+    /// The labels of the buttons.
+    labels: TourLabels,
+    /// The width of the popover.
+    popover_width: f32,
+    padding: f32,
+    text_size: f32,
+    font: Font,
+    /// The style of the [`Tour`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Theme, Renderer> Tour<'a, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`Tour`] of the `steps` over the `content`, showing the
+    /// `current` step and producing a message with the next one when the
+    /// user moves through the tour.
+    pub fn new<C, F>(content: C, steps: &'a [TourStep], current: Option<usize>, on_step: F) -> Self
+    where
+        C: Into<Element<'a, Message, Theme, Renderer>>,
+        F: 'a + Fn(Option<usize>) -> Message,
+    {
+        Self {
+            content: content.into(),
+            steps,
+            current,
+            on_step: Box::new(on_step),
+            labels: TourLabels::default(),
+            popover_width: 280.0,
+            padding: settings::spaced(12.0),
+            text_size: settings::scaled(14.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the labels of the buttons of the [`Tour`].
+    #[must_use]
+    pub fn labels(mut self, labels: TourLabels) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets the width of the popover of the [`Tour`].
+    #[must_use]
+    pub fn popover_width(mut self, popover_width: f32) -> Self {
+        self.popover_width = popover_width;
+        self
+    }
+
+    /// Sets the padding of the popover of the [`Tour`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`Tour`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`Tour`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Tour`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// An [`Operation`] finding the bounds of the container with an id.
+struct FindBounds<'a> {
+    /// The id of the container.
+    id: &'a Id,
+    /// The bounds of the container, once found.
+    bounds: Option<Rectangle>,
+}
+
+impl<T> Operation<T> for FindBounds<'_> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if self.bounds.is_some() {
+            return;
+        }
+
+        if id == Some(self.id) {
+            self.bounds = Some(bounds);
+        } else {
+            operate_on_children(self);
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tour<'_, Message, Theme, Renderer>
+where
+    Renderer: text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let step = self.current.filter(|&step| step < self.steps.len());
+
+        let target = step.and_then(|step| {
+            let id = Id::from(self.steps[step].target.clone());
+            let mut find = FindBounds {
+                id: &id,
+                bounds: None,
+            };
+            self.content
+                .as_widget()
+                .operate(&mut tree.children[0], layout, renderer, &mut find);
+
+            find.bounds.map(|bounds| bounds + translation)
+        });
+
+        let mut children = Vec::new();
+
+        if let Some(content) = self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        ) {
+            children.push(content);
+        }
+
+        if let Some(step) = step {
+            children.push(overlay::Element::new(Box::new(TourOverlay::new(
+                &self.steps[step],
+                step,
+                self.steps.len(),
+                target,
+                &*self.on_step,
+                &self.labels,
+                self.popover_width,
+                self.padding,
+                self.text_size,
+                self.font,
+                &self.style,
+            ))));
+        }
+
+        (!children.is_empty()).then(|| Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tour<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(tour: Tour<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tour)
+    }
+}
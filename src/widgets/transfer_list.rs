@@ -0,0 +1,528 @@
+//! Move options between two lists, like available and chosen ones.
+//!
+//! *This API requires the following crate features to be activated: `transfer_list`*
+
+use crate::core::{
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    settings,
+};
+use crate::style::selection_list::StyleSheet;
+use crate::widgets::selection_list::{list::ListState, SelectionList};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::Horizontal,
+    event,
+    mouse::{self, Cursor},
+    widget::{button, container, scrollable, text, text_input, Button, Column, Row, Space, Text},
+    Alignment, Element, Event, Font, Length, Rectangle, Size,
+};
+use std::{fmt::Display, hash::Hash};
+
+/// A side of a [`TransferList`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Side {
+    /// The list of the available options.
+    Available,
+    /// The list of the chosen options.
+    Chosen,
+}
+
+impl Side {
+    /// Returns the index of the side.
+    fn index(self) -> usize {
+        match self {
+            Self::Available => 0,
+            Self::Chosen => 1,
+        }
+    }
+}
+
+/// An action of the lists and of the buttons of a [`TransferList`].
+#[derive(Clone, Debug)]
+enum Action {
+    /// The selected options of a side changed.
+    Select(Side, Vec<usize>),
+    /// The given options of a side are moved to the other side.
+    Move(Side, Vec<usize>),
+    /// The selected options of a side are moved to the other side.
+    MoveSelected(Side),
+    /// Every option of a side is moved to the other side.
+    MoveAll(Side),
+}
+
+/// The function producing the message with the available and the chosen options.
+type OnChange<'a, T, Message> = Box<dyn Fn(Vec<T>, Vec<T>) -> Message + 'a>;
+
+/// Two lists of options, the available ones and the chosen ones, with
+/// buttons moving the selected options or all of them between the lists.
+///
+/// Several options are selected with Ctrl-click and Shift-click, and a
+/// double click moves an option to the other list. Moved options are added
+/// at the end of the other list.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::TransferList;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Transferred(Vec<String>, Vec<String>),
+/// }
+///
+/// let available = vec![String::from("Rust"), String::from("C")];
+/// let chosen = vec![String::from("Python")];
+///
+/// let transfer_list = TransferList::new(&available, &chosen, Message::Transferred)
+///     .titles("Available", "Chosen");
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TransferList<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    T: Clone + Display + Eq + Hash,
+    Renderer: renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    /// The available options.
+    available: &'a [T],
+    /// The chosen options.
+    chosen: &'a [T],
+    /// The function producing the message when options are moved.
+    on_change: OnChange<'a, T, Message>,
+    /// The titles shown above the lists.
+    titles: Option<(String, String)>,
+    width: Length,
+    height: Length,
+    spacing: f32,
+    text_size: f32,
+    padding: f32,
+    font: Font,
+    /// The style of the lists.
+    style: <Theme as StyleSheet>::Style,
+    #[allow(clippy::missing_docs_in_private_items)]
+    renderer: std::marker::PhantomData<Renderer>,
+}
+
+/// The state of a [`TransferList`].
+#[derive(Debug, Default)]
+struct State {
+    /// The indices of the selected options of each side.
+    selected: [Vec<usize>; 2],
+}
+
+impl<'a, T, Message, Theme, Renderer> TransferList<'a, T, Message, Theme, Renderer>
+where
+    T: 'static + Clone + Display + Eq + Hash,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Theme: 'a
+        + StyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet
+        + text_input::StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet,
+{
+    /// Creates a new [`TransferList`] of the `available` and `chosen`
+    /// options, producing a message with both lists when options are moved.
+    pub fn new<F>(available: &'a [T], chosen: &'a [T], on_change: F) -> Self
+    where
+        F: 'a + Fn(Vec<T>, Vec<T>) -> Message,
+    {
+        Self {
+            available,
+            chosen,
+            on_change: Box::new(on_change),
+            titles: None,
+            width: Length::Fill,
+            height: Length::Fixed(240.0),
+            spacing: settings::spaced(8.0),
+            text_size: settings::scaled(14.0),
+            padding: settings::spaced(5.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+            renderer: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the titles shown above the available and the chosen options.
+    #[must_use]
+    pub fn titles(mut self, available: impl Into<String>, chosen: impl Into<String>) -> Self {
+        self.titles = Some((available.into(), chosen.into()));
+        self
+    }
+
+    /// Sets the width of the [`TransferList`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the lists of the [`TransferList`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the space between the lists and the buttons.
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the [`TransferList`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the padding of the options of the [`TransferList`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the font of the [`TransferList`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the lists of the [`TransferList`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the options of a side.
+    fn options(&self, side: Side) -> &'a [T] {
+        match side {
+            Side::Available => self.available,
+            Side::Chosen => self.chosen,
+        }
+    }
+
+    /// Builds the lists and the buttons, enabling the buttons moving the
+    /// selected options of the sides having some.
+    fn content(&self, has_selection: [bool; 2]) -> Element<'a, Action, Theme, Renderer> {
+        let list = |side: Side| {
+            let options = SelectionList::new_with(
+                self.options(side),
+                move |index, _| Action::Select(side, vec![index]),
+                self.text_size,
+                self.padding,
+                self.style.clone(),
+                None,
+                self.font,
+            )
+            .on_multi_select(move |indices| Action::Select(side, indices))
+            .on_double_click(move |index, _| Action::Move(side, vec![index]))
+            .height(Length::Fill);
+
+            let mut column = Column::new().spacing(4).width(Length::Fill);
+            if let Some((available, chosen)) = &self.titles {
+                column = column.push(
+                    Text::new(match side {
+                        Side::Available => available.clone(),
+                        Side::Chosen => chosen.clone(),
+                    })
+                    .size(self.text_size)
+                    .font(self.font),
+                );
+            }
+            column.push(options)
+        };
+
+        let button = |icon: Bootstrap, action: Option<Action>| {
+            Button::new(
+                Text::new(icon_to_string(icon))
+                    .font(BOOTSTRAP_FONT)
+                    .size(self.text_size)
+                    .width(Length::Fill)
+                    .horizontal_alignment(Horizontal::Center),
+            )
+            .width(self.text_size * 2.5)
+            .on_press_maybe(action)
+        };
+        let some_if = |condition: bool, action: Action| condition.then_some(action);
+
+        let buttons = Column::new()
+            .spacing(self.spacing / 2.0)
+            .push(Space::with_height(Length::Fill))
+            .push(button(
+                Bootstrap::ChevronRight,
+                some_if(has_selection[0], Action::MoveSelected(Side::Available)),
+            ))
+            .push(button(
+                Bootstrap::ChevronDoubleRight,
+                some_if(!self.available.is_empty(), Action::MoveAll(Side::Available)),
+            ))
+            .push(button(
+                Bootstrap::ChevronLeft,
+                some_if(has_selection[1], Action::MoveSelected(Side::Chosen)),
+            ))
+            .push(button(
+                Bootstrap::ChevronDoubleLeft,
+                some_if(!self.chosen.is_empty(), Action::MoveAll(Side::Chosen)),
+            ))
+            .push(Space::with_height(Length::Fill));
+
+        Row::new()
+            .spacing(self.spacing)
+            .align_items(Alignment::Center)
+            .width(self.width)
+            .height(self.height)
+            .push(list(Side::Available))
+            .push(buttons)
+            .push(list(Side::Chosen))
+            .into()
+    }
+
+    /// Returns whether each side has selected options.
+    fn has_selection(&self, state: &State) -> [bool; 2] {
+        [Side::Available, Side::Chosen].map(|side| {
+            state.selected[side.index()]
+                .iter()
+                .any(|&index| index < self.options(side).len())
+        })
+    }
+
+    /// Moves the options of `side` at `indices` to the other side, and
+    /// publishes the resulting lists.
+    fn transfer(&self, side: Side, indices: &[usize], shell: &mut Shell<'_, Message>) {
+        let from = self.options(side);
+        let to = self.options(match side {
+            Side::Available => Side::Chosen,
+            Side::Chosen => Side::Available,
+        });
+
+        let (moved, kept): (Vec<_>, Vec<_>) = from
+            .iter()
+            .enumerate()
+            .partition(|(index, _)| indices.contains(index));
+        if moved.is_empty() {
+            return;
+        }
+
+        let kept: Vec<T> = kept.into_iter().map(|(_, option)| option.clone()).collect();
+        let mut to = to.to_vec();
+        to.extend(moved.into_iter().map(|(_, option)| option.clone()));
+
+        shell.publish(match side {
+            Side::Available => (self.on_change)(kept, to),
+            Side::Chosen => (self.on_change)(to, kept),
+        });
+    }
+}
+
+/// Clears the selection of the list of the selection list in `tree`, whose
+/// options are moved.
+fn clear_selection(tree: &mut Tree) {
+    // The tree of a selection list holds its container, holding its
+    // scrollable, holding its list.
+    if let Some(list) = tree
+        .children
+        .get_mut(0)
+        .and_then(|container| container.children.get_mut(0))
+        .and_then(|scrollable| scrollable.children.get_mut(0))
+    {
+        let state = list.state.downcast_mut::<ListState>();
+        state.selected_options.clear();
+        state.anchor = None;
+        state.last_selected_index = None;
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TransferList<'a, T, Message, Theme, Renderer>
+where
+    T: 'static + Clone + Display + Eq + Hash,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Theme: 'a
+        + StyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet
+        + text_input::StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(self.content([false; 2]))]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let has_selection = self.has_selection(tree.state.downcast_ref::<State>());
+        tree.diff_children(&[&self.content(has_selection)]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let has_selection = self.has_selection(tree.state.downcast_ref::<State>());
+        let content =
+            self.content(has_selection)
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(content_layout) = layout.children().next() else {
+            return event::Status::Ignored;
+        };
+        let has_selection = self.has_selection(tree.state.downcast_ref::<State>());
+
+        let mut actions = Vec::new();
+        let mut local_shell = Shell::new(&mut actions);
+        let status = self.content(has_selection).as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if local_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        for action in actions {
+            let state = tree.state.downcast_mut::<State>();
+
+            let (side, indices) = match action {
+                Action::Select(side, indices) => {
+                    state.selected[side.index()] = indices;
+                    continue;
+                }
+                Action::Move(side, indices) => (side, indices),
+                Action::MoveSelected(side) => (side, state.selected[side.index()].clone()),
+                Action::MoveAll(side) => (side, (0..self.options(side).len()).collect()),
+            };
+
+            self.transfer(side, &indices, shell);
+            state.selected[side.index()].clear();
+
+            // The lists are the first and the last children of the row.
+            let row = &mut tree.children[0];
+            let list_index = match side {
+                Side::Available => 0,
+                Side::Chosen => row.children.len() - 1,
+            };
+            if let Some(list) = row.children.get_mut(list_index) {
+                if let Some(selection_list) = list.children.last_mut() {
+                    clear_selection(selection_list);
+                }
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let Some(content_layout) = layout.children().next() else {
+            return mouse::Interaction::default();
+        };
+        let has_selection = self.has_selection(tree.state.downcast_ref::<State>());
+
+        self.content(has_selection).as_widget().mouse_interaction(
+            &tree.children[0],
+            content_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let Some(content_layout) = layout.children().next() else {
+            return;
+        };
+        let has_selection = self.has_selection(tree.state.downcast_ref::<State>());
+
+        self.content(has_selection).as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor,
+            viewport,
+        );
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<TransferList<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'static + Clone + Display + Eq + Hash,
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Theme: 'a
+        + StyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet
+        + text_input::StyleSheet
+        + button::StyleSheet
+        + text::StyleSheet,
+{
+    fn from(transfer_list: TransferList<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(transfer_list)
+    }
+}
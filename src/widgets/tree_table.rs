@@ -0,0 +1,537 @@
+//! Display hierarchical records in a table whose first column is a tree.
+//!
+//! *This API requires the following crate features to be activated: `tree_table`*
+
+use crate::core::{
+    icons::{bootstrap::icon_to_string, Bootstrap, BOOTSTRAP_FONT},
+    settings,
+    tree_table::{paths_to_depth, visible_nodes, Compare, VisibleNode},
+};
+use crate::widgets::table::Table;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer,
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    widget::{text, MouseArea, Row, Space, Text},
+    Alignment, Element, Event, Font, Length, Rectangle, Size,
+};
+use std::{cmp::Ordering, collections::HashSet};
+
+pub use crate::{
+    core::tree_table::TreeNode,
+    style::table::{Appearance, StyleSheet},
+    widgets::table::{Column, ColumnWidth, SortOrder},
+};
+
+/// An action of the table of a [`TreeTable`].
+enum Action<Message> {
+    /// The node at the path is expanded or collapsed.
+    Toggle(Vec<usize>),
+    /// The visible row at the index is clicked.
+    Select(usize),
+    /// The header of a sortable column is clicked.
+    Sort(usize, SortOrder),
+    /// The content of a cell produced a message.
+    Cell(Message),
+}
+
+/// The function comparing two records of a [`TreeTable`] by the column at the
+/// given index.
+type CompareBy<'a, T> = dyn Fn(&T, &T, usize) -> Ordering + 'a;
+
+/// The function building the content of a cell of a [`TreeTable`] from the
+/// record of its row and the index of its column.
+pub type NodeView<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer> =
+    dyn Fn(&'a T, usize) -> Element<'a, Message, Theme, Renderer> + 'a;
+
+/// A table showing hierarchical records, whose first column shows the nodes
+/// of their trees with buttons expanding and collapsing them.
+///
+/// The nodes are identified by their paths: the indices of the node and of
+/// its ancestors among their siblings, from the root. The sorting of
+/// [`sort`](TreeTable::sort) is applied among the siblings only, keeping the
+/// children under their parent, and the paths keep the indices of the nodes
+/// as given to the tree table.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::text;
+/// # use iced_aw::tree_table::{Column, SortOrder, TreeNode, TreeTable};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Sort(usize, SortOrder),
+///     Select(Vec<usize>),
+/// }
+///
+/// let files = vec![TreeNode::new(("src", 30)).push(TreeNode::new(("lib.rs", 10)))];
+///
+/// let tree_table = TreeTable::new(
+///     vec![Column::new("Name").sortable(true), Column::new("Size").sortable(true)],
+///     &files,
+///     |(name, size), column| match column {
+///         0 => text(name).into(),
+///         _ => text(size).into(),
+///     },
+/// )
+/// .compare(|a, b, column| match column {
+///     0 => a.0.cmp(b.0),
+///     _ => a.1.cmp(&b.1),
+/// })
+/// .on_sort(Message::Sort)
+/// .on_select(Message::Select);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TreeTable<'a, T, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The columns of the table.
+    columns: Vec<Column>,
+    /// The roots of the trees of records.
+    roots: &'a [TreeNode<T>],
+    /// The function building the content of the cells.
+    view: Box<NodeView<'a, T, Message, Theme, Renderer>>,
+    /// The function comparing two records by a column.
+    compare: Option<Box<CompareBy<'a, T>>>,
+    /// The column and order by which the siblings are sorted.
+    sort: Option<(usize, SortOrder)>,
+    /// The function producing the message when a sortable header is clicked.
+    on_sort: Option<Box<dyn Fn(usize, SortOrder) -> Message + 'a>>,
+    /// The path of the selected node.
+    selected: Option<Vec<usize>>,
+    /// The function producing the message when a row is clicked.
+    on_select: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    /// The depth down to which the nodes are expanded at first.
+    expand_depth: usize,
+    /// The indentation of a level of the tree.
+    indent: f32,
+    width: Length,
+    height: Length,
+    row_height: f32,
+    header_height: f32,
+    padding: f32,
+    text_size: f32,
+    font: Font,
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The state of a [`TreeTable`].
+#[derive(Debug, Default)]
+struct State {
+    /// The paths of the nodes expanded by the user.
+    expanded: HashSet<Vec<usize>>,
+}
+
+impl<'a, T, Message, Theme, Renderer> TreeTable<'a, T, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet + text::StyleSheet,
+{
+    /// Creates a new [`TreeTable`] with the given columns, showing the trees
+    /// of `roots` with the cells built by `view`.
+    pub fn new(
+        columns: Vec<Column>,
+        roots: &'a [TreeNode<T>],
+        view: impl Fn(&'a T, usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            columns,
+            roots,
+            view: Box::new(view),
+            compare: None,
+            sort: None,
+            on_sort: None,
+            selected: None,
+            on_select: None,
+            expand_depth: 0,
+            indent: 16.0,
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: settings::spaced(32.0),
+            header_height: 32.0,
+            padding: settings::spaced(8.0),
+            text_size: settings::scaled(16.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the function comparing two records by the column at the given
+    /// index, used to sort the siblings by the column of
+    /// [`sort`](Self::sort).
+    #[must_use]
+    pub fn compare(mut self, compare: impl Fn(&T, &T, usize) -> Ordering + 'a) -> Self {
+        self.compare = Some(Box::new(compare));
+        self
+    }
+
+    /// Sets the column and order by which the siblings are sorted, shown by
+    /// an indicator in the header.
+    #[must_use]
+    pub fn sort(mut self, sort: Option<(usize, SortOrder)>) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Sets the function producing the message when a sortable header is
+    /// clicked, with the index of the column and the new order.
+    #[must_use]
+    pub fn on_sort(mut self, on_sort: impl Fn(usize, SortOrder) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the path of the selected node.
+    #[must_use]
+    pub fn selected(mut self, selected: Option<Vec<usize>>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the function producing the message with the path of the node
+    /// of a clicked row.
+    #[must_use]
+    pub fn on_select(mut self, on_select: impl Fn(Vec<usize>) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the depth down to which the nodes are expanded at first, a depth
+    /// of 1 expanding the roots only. Nothing is expanded by default.
+    #[must_use]
+    pub fn expand_depth(mut self, expand_depth: usize) -> Self {
+        self.expand_depth = expand_depth;
+        self
+    }
+
+    /// Sets the indentation of a level of the tree.
+    #[must_use]
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the width of the [`TreeTable`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`TreeTable`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the height of the rows of the [`TreeTable`].
+    #[must_use]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the height of the header row of the [`TreeTable`].
+    #[must_use]
+    pub fn header_height(mut self, header_height: f32) -> Self {
+        self.header_height = header_height;
+        self
+    }
+
+    /// Sets the horizontal space between the content of the cells and their edges.
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the headers and of the expand buttons of the
+    /// [`TreeTable`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the headers of the [`TreeTable`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`TreeTable`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the visible rows, with the siblings sorted.
+    fn rows(&self, expanded: &HashSet<Vec<usize>>) -> Vec<VisibleNode<'a, T>> {
+        let compare = self
+            .compare
+            .as_ref()
+            .zip(self.sort)
+            .map(|(compare, (column, order))| {
+                move |a: &T, b: &T| match order {
+                    SortOrder::Ascending => compare(a, b, column),
+                    SortOrder::Descending => compare(a, b, column).reverse(),
+                }
+            });
+
+        visible_nodes(
+            self.roots,
+            expanded,
+            compare.as_ref().map(|compare| compare as &Compare<'_, T>),
+        )
+    }
+
+    /// Builds the table of the visible `rows`.
+    fn content<'b>(
+        &'b self,
+        rows: &'b [VisibleNode<'a, T>],
+    ) -> Element<'b, Action<Message>, Theme, Renderer> {
+        let selected = self
+            .selected
+            .as_ref()
+            .and_then(|selected| rows.iter().position(|row| &row.path == selected));
+
+        let mut table = Table::new(
+            self.columns.clone(),
+            rows,
+            move |_, column, row: &'b VisibleNode<'a, T>| {
+                let cell = (self.view)(&row.node.value, column).map(Action::Cell);
+                if column > 0 {
+                    return cell;
+                }
+
+                let toggle: Element<'b, Action<Message>, Theme, Renderer> =
+                    if row.node.children.is_empty() {
+                        Space::with_width(self.text_size).into()
+                    } else {
+                        Element::from(
+                            MouseArea::new(
+                                Text::new(icon_to_string(if row.expanded {
+                                    Bootstrap::ChevronDown
+                                } else {
+                                    Bootstrap::ChevronRight
+                                }))
+                                .font(BOOTSTRAP_FONT)
+                                .size(self.text_size * 0.75)
+                                .width(self.text_size),
+                            )
+                            .on_press(row.path.clone()),
+                        )
+                        .map(Action::Toggle)
+                    };
+
+                Row::new()
+                    .align_items(Alignment::Center)
+                    .spacing(self.padding / 2.0)
+                    .push(Space::with_width(self.indent * row.depth() as f32))
+                    .push(toggle)
+                    .push(cell)
+                    .into()
+            },
+        )
+        .sort(self.sort)
+        .selected(selected)
+        .width(self.width)
+        .height(self.height)
+        .row_height(self.row_height)
+        .header_height(self.header_height)
+        .padding(self.padding)
+        .text_size(self.text_size)
+        .font(self.font)
+        .style(self.style.clone());
+
+        if self.on_sort.is_some() {
+            table = table.on_sort(Action::Sort);
+        }
+        if self.on_select.is_some() {
+            table = table.on_select(Action::Select);
+        }
+
+        table.into()
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TreeTable<'a, T, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet + text::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            expanded: paths_to_depth(self.roots, self.expand_depth),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        let rows = self.rows(&paths_to_depth(self.roots, self.expand_depth));
+        vec![Tree::new(self.content(&rows))]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let rows = self.rows(&tree.state.downcast_ref::<State>().expanded);
+        tree.diff_children(&[&self.content(&rows)]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let rows = self.rows(&tree.state.downcast_ref::<State>().expanded);
+        let content =
+            self.content(&rows)
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, limits);
+
+        Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let Some(content_layout) = layout.children().next() else {
+            return event::Status::Ignored;
+        };
+        let rows = self.rows(&tree.state.downcast_ref::<State>().expanded);
+
+        let mut actions = Vec::new();
+        let mut local_shell = Shell::new(&mut actions);
+        let status = self.content(&rows).as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if local_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        for action in actions {
+            match action {
+                Action::Toggle(path) => {
+                    let expanded = &mut tree.state.downcast_mut::<State>().expanded;
+                    if !expanded.remove(&path) {
+                        let _ = expanded.insert(path);
+                    }
+                    shell.invalidate_layout();
+                }
+                Action::Select(index) => {
+                    if let (Some(on_select), Some(row)) = (&self.on_select, rows.get(index)) {
+                        shell.publish(on_select(row.path.clone()));
+                    }
+                }
+                Action::Sort(column, order) => {
+                    if let Some(on_sort) = &self.on_sort {
+                        shell.publish(on_sort(column, order));
+                    }
+                }
+                Action::Cell(message) => shell.publish(message),
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let Some(content_layout) = layout.children().next() else {
+            return mouse::Interaction::default();
+        };
+        let rows = self.rows(&tree.state.downcast_ref::<State>().expanded);
+
+        let content = self.content(&rows);
+        content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let Some(content_layout) = layout.children().next() else {
+            return;
+        };
+        let rows = self.rows(&tree.state.downcast_ref::<State>().expanded);
+
+        self.content(&rows).as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor,
+            viewport,
+        );
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<TreeTable<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a,
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + iced::advanced::text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet + text::StyleSheet,
+{
+    fn from(tree_table: TreeTable<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(tree_table)
+    }
+}
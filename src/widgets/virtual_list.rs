@@ -0,0 +1,687 @@
+//! A list only creating the widgets of its visible items.
+//!
+//! *This API requires the following crate features to be activated: `virtual_list`*
+
+use crate::core::settings;
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        overlay,
+        renderer::{self, Quad},
+        widget::{
+            tree::{self, Tag, Tree},
+            Id, Operation,
+        },
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    mouse::{self, Cursor},
+    touch, Border, Command, Element, Event, Length, Point, Rectangle, Shadow, Size, Vector,
+};
+use std::{any::Any, cell::RefCell, ops::Range};
+
+pub use crate::style::virtual_list::{Appearance, StyleSheet};
+
+/// The smallest height of the scroller of a [`VirtualList`].
+const MIN_SCROLLER_HEIGHT: f32 = 16.0;
+
+/// The function producing the elements of a range of items of a [`VirtualList`].
+type Producer<'a, Message, Theme, Renderer> =
+    Box<dyn Fn(Range<usize>) -> Vec<Element<'a, Message, Theme, Renderer>> + 'a>;
+
+/// The elements produced for a range of items of a [`VirtualList`].
+struct Items<'a, Message, Theme, Renderer> {
+    /// The range of the produced items.
+    range: Range<usize>,
+    /// The elements of the produced items.
+    elements: Vec<Element<'a, Message, Theme, Renderer>>,
+}
+
+/// A vertical list of `count` items of the same height, only creating the
+/// widgets of the visible items.
+///
+/// The elements are asked to the producer for the range of the visible items,
+/// widened by an [`overscan`](VirtualList::overscan) margin on both sides, so
+/// that lists of millions of items stay as cheap as the few rows on screen.
+/// The producer must return one element per index of the range, in order.
+///
+/// The list is scrolled with the mouse wheel and its scrollbar, or with the
+/// [`scroll_to`], [`snap_to`] and [`scroll_to_item`] commands given its
+/// [`Id`](VirtualList::id).
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::VirtualList;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+/// }
+///
+/// let lines: Vec<String> = (0..100_000).map(|i| format!("Line {i}")).collect();
+///
+/// let list = VirtualList::new(lines.len(), |range| {
+///     lines[range].iter().map(|line| Text::new(line).into()).collect()
+/// })
+/// .item_height(24.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// The number of items.
+    count: usize,
+    /// The function producing the elements of a range of items.
+    producer: Producer<'a, Message, Theme, Renderer>,
+    /// The elements produced for the items shown last.
+    items: RefCell<Items<'a, Message, Theme, Renderer>>,
+    item_height: f32,
+    /// The number of items created outside of the view on each side.
+    overscan: usize,
+    width: Length,
+    height: Length,
+    scrollbar_width: f32,
+    /// The id of the [`VirtualList`], used by the scroll commands.
+    id: Option<Id>,
+    /// The style of the [`VirtualList`].
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// The scroll offset of a [`VirtualList`].
+#[derive(Clone, Copy, Debug)]
+enum Offset {
+    /// An offset in pixels from the top.
+    Absolute(f32),
+    /// A fraction of the scrollable height, kept as items are added.
+    Relative(f32),
+    /// The offset showing an item at the top.
+    Item(usize),
+}
+
+impl Offset {
+    /// Returns the offset in pixels, at most `max`.
+    fn absolute(self, max: f32, item_height: f32) -> f32 {
+        match self {
+            Self::Absolute(offset) => offset,
+            Self::Relative(fraction) => fraction * max,
+            Self::Item(index) => item_height * index as f32,
+        }
+        .clamp(0.0, max)
+    }
+}
+
+/// The state of a [`VirtualList`].
+#[derive(Debug)]
+struct State {
+    /// The scroll offset.
+    offset: Offset,
+    /// The range of the items the children trees belong to.
+    range: Range<usize>,
+    /// Where the scroller is held from its top while being dragged.
+    grab: Option<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> VirtualList<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`VirtualList`] of `count` items whose elements are
+    /// given by `producer` for the ranges of items shown.
+    pub fn new<F>(count: usize, producer: F) -> Self
+    where
+        F: 'a + Fn(Range<usize>) -> Vec<Element<'a, Message, Theme, Renderer>>,
+    {
+        Self {
+            count,
+            producer: Box::new(producer),
+            items: RefCell::new(Items {
+                range: 0..0,
+                elements: Vec::new(),
+            }),
+            item_height: settings::spaced(32.0),
+            overscan: 4,
+            width: Length::Fill,
+            height: Length::Fill,
+            scrollbar_width: 8.0,
+            id: None,
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the height of the items of the [`VirtualList`].
+    #[must_use]
+    pub fn item_height(mut self, item_height: f32) -> Self {
+        self.item_height = item_height.max(1.0);
+        self
+    }
+
+    /// Sets the number of items created outside of the view on each side,
+    /// keeping the items ready while scrolling.
+    #[must_use]
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`VirtualList`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the width of the scrollbar of the [`VirtualList`].
+    #[must_use]
+    pub fn scrollbar_width(mut self, scrollbar_width: f32) -> Self {
+        self.scrollbar_width = scrollbar_width;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`VirtualList`].
+    ///
+    /// The [`Id`] allows the list to be scrolled programmatically with
+    /// [`scroll_to`], [`snap_to`] and [`scroll_to_item`].
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the style of the [`VirtualList`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the largest scroll offset of the list in a view of `height`.
+    fn max_offset(&self, height: f32) -> f32 {
+        (self.item_height * self.count as f32 - height).max(0.0)
+    }
+
+    /// Returns the range of the items to create for a view of `height`
+    /// scrolled by `offset`.
+    fn window(&self, offset: f32, height: f32) -> Range<usize> {
+        let first = (offset / self.item_height).floor() as usize;
+        let last = ((offset + height) / self.item_height).ceil() as usize;
+        let end = last.saturating_add(self.overscan).min(self.count);
+
+        first.saturating_sub(self.overscan).min(end)..end
+    }
+
+    /// Produces the elements of the items in `range`, and moves the children
+    /// trees along so that each item keeps its state.
+    fn sync(&self, tree: &mut Tree, range: Range<usize>) {
+        let mut items = self.items.borrow_mut();
+        let produced = items.range != range;
+
+        if produced {
+            items.elements = (self.producer)(range.clone());
+            items.range = range.clone();
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        if !produced && state.range == range {
+            return;
+        }
+
+        let old = std::mem::replace(&mut state.range, range.clone());
+        let mut children = std::mem::take(&mut tree.children);
+
+        tree.children = range
+            .zip(&items.elements)
+            .map(|(index, element)| {
+                index
+                    .checked_sub(old.start)
+                    .filter(|_| old.contains(&index))
+                    .and_then(|position| children.get_mut(position))
+                    .map_or_else(
+                        || Tree::new(element),
+                        |child| {
+                            let mut child = std::mem::replace(child, Tree::empty());
+                            child.diff(element);
+                            child
+                        },
+                    )
+            })
+            .collect();
+    }
+
+    /// Returns the bounds of the scrollbar and of its scroller for a list in
+    /// `bounds` scrolled by `offset`, if the items do not fit.
+    fn scrollbar(&self, bounds: Rectangle, offset: f32) -> Option<(Rectangle, Rectangle)> {
+        let content = self.item_height * self.count as f32;
+        if content <= bounds.height {
+            return None;
+        }
+
+        let track = Rectangle {
+            x: bounds.x + bounds.width - self.scrollbar_width,
+            width: self.scrollbar_width,
+            ..bounds
+        };
+        let height = (bounds.height * bounds.height / content)
+            .max(MIN_SCROLLER_HEIGHT)
+            .min(track.height);
+        let scroller = Rectangle {
+            y: track.y + (track.height - height) * offset / (content - bounds.height),
+            height,
+            ..track
+        };
+
+        Some((track, scroller))
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for VirtualList<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            offset: Offset::Absolute(0.0),
+            range: 0..0,
+            grab: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        Vec::new()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let range = &tree.state.downcast_ref::<State>().range;
+        let range = range.start.min(self.count)..range.end.min(self.count);
+
+        self.sync(tree, range);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::new(0.0, self.item_height * self.count as f32),
+        );
+
+        // A relative offset stays relative so that a list snapped to its end
+        // follows the items added to it.
+        let state = tree.state.downcast_mut::<State>();
+        let offset = state
+            .offset
+            .absolute(self.max_offset(size.height), self.item_height);
+        if !matches!(state.offset, Offset::Relative(_)) {
+            state.offset = Offset::Absolute(offset);
+        }
+
+        let range = self.window(offset, size.height);
+        self.sync(tree, range.clone());
+
+        let items = self.items.borrow();
+        let limits = Limits::new(Size::ZERO, Size::new(size.width, self.item_height));
+        let children = items
+            .elements
+            .iter()
+            .zip(&mut tree.children)
+            .zip(range)
+            .map(|((element, tree), index)| {
+                element
+                    .as_widget()
+                    .layout(tree, renderer, &limits)
+                    .move_to(Point::new(0.0, self.item_height * index as f32 - offset))
+            })
+            .collect();
+
+        Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let max = self.max_offset(bounds.height);
+        let state = tree.state.downcast_mut::<State>();
+        let offset = state.offset.absolute(max, self.item_height);
+        let scrollbar = self.scrollbar(bounds, offset);
+
+        // The scroller is dragged first, leaving the items out.
+        if let (Some(grab), Some((track, scroller))) = (state.grab, scrollbar) {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                    let fraction = (position.y - grab - track.y) / (track.height - scroller.height);
+                    state.offset = Offset::Absolute((fraction * max).clamp(0.0, max));
+                    shell.invalidate_layout();
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) => state.grab = None,
+                _ => return event::Status::Ignored,
+            }
+            return event::Status::Captured;
+        }
+
+        if let Some((track, scroller)) = scrollbar {
+            if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) = event
+            {
+                if let Some(position) = cursor.position_over(track) {
+                    // A press outside of the scroller moves its center under the cursor.
+                    let grab = if scroller.contains(position) {
+                        position.y - scroller.y
+                    } else {
+                        let fraction = (position.y - scroller.height / 2.0 - track.y)
+                            / (track.height - scroller.height);
+                        state.offset = Offset::Absolute((fraction * max).clamp(0.0, max));
+                        shell.invalidate_layout();
+                        scroller.height / 2.0
+                    };
+                    state.grab = Some(grab);
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        let content_cursor = if cursor.is_over(bounds) {
+            cursor
+        } else {
+            Cursor::Unavailable
+        };
+        let status = self
+            .items
+            .get_mut()
+            .elements
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((element, tree), layout)| {
+                element.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    content_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &bounds,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * self.item_height,
+                    mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                let state = tree.state.downcast_mut::<State>();
+
+                state.offset = Offset::Absolute((offset - delta).clamp(0.0, max));
+                shell.invalidate_layout();
+                event::Status::Captured
+            }
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let offset = state
+            .offset
+            .absolute(self.max_offset(bounds.height), self.item_height);
+        let over_scrollbar = self
+            .scrollbar(bounds, offset)
+            .is_some_and(|(track, _)| cursor.is_over(track));
+
+        if state.grab.is_some() || over_scrollbar || !cursor.is_over(bounds) {
+            return mouse::Interaction::default();
+        }
+
+        self.items
+            .borrow()
+            .elements
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((element, tree), layout)| {
+                element
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, &bounds, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let offset = state
+            .offset
+            .absolute(self.max_offset(bounds.height), self.item_height);
+        let scrollbar = self.scrollbar(bounds, offset);
+        let appearance =
+            if state.grab.is_some() || scrollbar.is_some_and(|(track, _)| cursor.is_over(track)) {
+                theme.hovered(&self.style)
+            } else {
+                theme.active(&self.style)
+            };
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                Quad {
+                    bounds,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                },
+                background,
+            );
+        }
+
+        let Some(visible) = bounds.intersection(viewport) else {
+            return;
+        };
+        let content_cursor = if cursor.is_over(bounds) {
+            cursor
+        } else {
+            Cursor::Unavailable
+        };
+
+        renderer.with_layer(visible, |renderer| {
+            for ((element, tree), layout) in self
+                .items
+                .borrow()
+                .elements
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+            {
+                if layout.bounds().intersects(&visible) {
+                    element.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        style,
+                        layout,
+                        content_cursor,
+                        &visible,
+                    );
+                }
+            }
+
+            if let Some((track, scroller)) = scrollbar {
+                renderer.fill_quad(
+                    Quad {
+                        bounds: track,
+                        border: Border::with_radius(self.scrollbar_width / 2.0),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.scrollbar_background,
+                );
+                renderer.fill_quad(
+                    Quad {
+                        bounds: scroller,
+                        border: Border::with_radius(self.scrollbar_width / 2.0),
+                        shadow: Shadow::default(),
+                    },
+                    appearance.scroller_color,
+                );
+            }
+        });
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.custom(tree.state.downcast_mut::<State>(), self.id.as_ref());
+
+        operation.container(self.id.as_ref(), layout.bounds(), &mut |operation| {
+            self.items
+                .borrow()
+                .elements
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((element, tree), layout)| {
+                    element
+                        .as_widget()
+                        .operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.items.get_mut().elements,
+            tree,
+            layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<VirtualList<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer,
+    Theme: 'a + StyleSheet,
+{
+    fn from(list: VirtualList<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(list)
+    }
+}
+
+/// Produces an [`Operation`] setting the offset of the [`VirtualList`] with
+/// the given [`Id`].
+fn request<T>(target: Id, offset: Offset) -> impl Operation<T> {
+    struct Apply {
+        target: Id,
+        offset: Offset,
+    }
+
+    impl<T> Operation<T> for Apply {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            if id != Some(&self.target) {
+                return;
+            }
+
+            if let Some(state) = state.downcast_mut::<State>() {
+                state.offset = self.offset;
+            }
+        }
+    }
+
+    Apply { target, offset }
+}
+
+/// Produces a [`Command`] that scrolls the [`VirtualList`] with the given
+/// [`Id`] to `offset` pixels from its top.
+pub fn scroll_to<Message: 'static>(id: Id, offset: f32) -> Command<Message> {
+    Command::widget(request(id, Offset::Absolute(offset)))
+}
+
+/// Produces a [`Command`] that snaps the [`VirtualList`] with the given [`Id`]
+/// to a `fraction` of its scrollable height, from `0.0` at the top to `1.0` at
+/// the bottom.
+///
+/// The list stays snapped as items are added, so a chat snapped to `1.0`
+/// keeps showing its last messages.
+pub fn snap_to<Message: 'static>(id: Id, fraction: f32) -> Command<Message> {
+    Command::widget(request(id, Offset::Relative(fraction.clamp(0.0, 1.0))))
+}
+
+/// Produces a [`Command`] that scrolls the [`VirtualList`] with the given
+/// [`Id`] to show the item at `index` at its top.
+pub fn scroll_to_item<Message: 'static>(id: Id, index: usize) -> Command<Message> {
+    Command::widget(request(id, Offset::Item(index)))
+}
@@ -0,0 +1,546 @@
+//! Pick an item on a scrolling wheel, like a number or a month.
+//!
+//! *This API requires the following crate features to be activated: `wheel_picker`*
+
+use crate::core::{
+    animation, settings,
+    wheel::{clamp_offset, item_at, nearest_row},
+};
+
+use iced::{
+    advanced::{
+        layout::{Limits, Node},
+        renderer::{self, Quad},
+        text::{self, Text},
+        widget::tree::{self, Tag, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment::{Horizontal, Vertical},
+    event, keyboard,
+    mouse::{self, Cursor, ScrollDelta},
+    touch,
+    widget::text::{LineHeight, Shaping},
+    window, Border, Color, Element, Event, Font, Length, Pixels, Rectangle, Shadow, Size,
+};
+use std::time::{Duration, Instant};
+
+pub use crate::style::wheel_picker::{Appearance, StyleSheet};
+
+/// The rate at which a flung wheel slows down.
+const FRICTION: f32 = 3.0;
+/// The rate at which a wheel snaps onto the closest row.
+const SNAP_SPEED: f32 = 14.0;
+/// The speed, in rows per second, under which a flung wheel snaps.
+const MIN_VELOCITY: f32 = 1.0;
+/// The delay after which the last drag movement no longer flings the wheel.
+const FLING_TIMEOUT: Duration = Duration::from_millis(80);
+/// The distance under which a press and release select the pressed row.
+const TAP_DISTANCE: f32 = 4.0;
+
+/// A wheel of items scrolled like a drum, coming to rest with an item in
+/// its selection band.
+///
+/// The wheel can be dragged, flung with momentum, scrolled and moved with
+/// the arrow keys while hovered, and always snaps onto an item. A looping
+/// wheel wraps around from its last item to its first one.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::wheel_picker::WheelPicker;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     MonthSelected(&'static str),
+/// }
+///
+/// const MONTHS: [&str; 12] = [
+///     "January", "February", "March", "April", "May", "June",
+///     "July", "August", "September", "October", "November", "December",
+/// ];
+///
+/// let wheel_picker = WheelPicker::new(&MONTHS, Some("March"), Message::MonthSelected)
+///     .visible_rows(5)
+///     .looping(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct WheelPicker<'a, T, Message, Theme = iced::Theme>
+where
+    T: Clone + PartialEq + ToString,
+    Theme: StyleSheet,
+{
+    /// The items of the wheel.
+    items: &'a [T],
+    /// The selected item.
+    selected: Option<T>,
+    /// The function producing the message when the wheel rests on an item.
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    /// The number of rows shown.
+    visible_rows: usize,
+    /// Whether the wheel wraps around.
+    looping: bool,
+    width: Length,
+    row_height: f32,
+    text_size: f32,
+    font: Font,
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// A drag of a [`WheelPicker`].
+#[derive(Debug)]
+struct Drag {
+    /// The vertical position of the cursor when the drag started.
+    start_y: f32,
+    /// The offset of the wheel when the drag started.
+    start_offset: f32,
+    /// The last vertical position of the cursor.
+    last_y: f32,
+    /// The time of the last movement of the cursor.
+    last_time: Instant,
+}
+
+/// The state of a [`WheelPicker`].
+#[derive(Debug, Default)]
+struct State {
+    /// The row at the center of the wheel, fractional while it moves.
+    offset: f32,
+    /// The speed of the wheel, in rows per second.
+    velocity: f32,
+    /// The row the wheel is snapping onto.
+    target: Option<f32>,
+    /// The drag of the wheel, if any.
+    drag: Option<Drag>,
+    /// The time of the last frame of the animation.
+    last_update: Option<Instant>,
+    /// The item the wheel rested on, until the application selects it.
+    pending: Option<usize>,
+}
+
+impl<'a, T, Message, Theme> WheelPicker<'a, T, Message, Theme>
+where
+    T: Clone + PartialEq + ToString,
+    Theme: StyleSheet,
+{
+    /// Creates a new [`WheelPicker`] of the `items`, resting on the
+    /// `selected` one and producing the message of `on_select` with the
+    /// item it comes to rest on.
+    pub fn new<F>(items: &'a [T], selected: Option<T>, on_select: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        Self {
+            items,
+            selected,
+            on_select: Box::new(on_select),
+            visible_rows: 5,
+            looping: false,
+            width: Length::Fill,
+            row_height: settings::spaced(32.0),
+            text_size: settings::scaled(18.0),
+            font: settings::font(),
+            style: <Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the number of rows shown by the [`WheelPicker`], preferably odd
+    /// so that the selection band is in the middle row.
+    #[must_use]
+    pub fn visible_rows(mut self, visible_rows: usize) -> Self {
+        self.visible_rows = visible_rows.max(1);
+        self
+    }
+
+    /// Sets whether the [`WheelPicker`] wraps around from its last item to
+    /// its first one.
+    #[must_use]
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Sets the width of the [`WheelPicker`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the rows of the [`WheelPicker`].
+    #[must_use]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the text size of the [`WheelPicker`].
+    #[must_use]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Sets the font of the [`WheelPicker`].
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`WheelPicker`].
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the index of the selected item.
+    fn selected_index(&self) -> Option<usize> {
+        self.selected
+            .as_ref()
+            .and_then(|selected| self.items.iter().position(|item| item == selected))
+    }
+
+    /// Advances the animation of the wheel to `now`, and returns the item
+    /// it came to rest on, if it differs from the selected one.
+    fn animate(
+        &self,
+        state: &mut State,
+        now: Instant,
+        shell: &mut Shell<'_, Message>,
+    ) -> Option<usize> {
+        if state.drag.is_some() {
+            return None;
+        }
+
+        let count = self.items.len();
+        let selected = self.selected_index();
+
+        if state.pending == selected || state.pending.is_some_and(|index| index >= count) {
+            state.pending = None;
+        }
+
+        let elapsed = state
+            .last_update
+            .map_or(0.0, |last| (now - last).as_secs_f32());
+        let mut settled = None;
+
+        if state.velocity != 0.0 {
+            state.offset += state.velocity * elapsed;
+            state.velocity *= (-FRICTION * elapsed).exp();
+
+            let clamped = clamp_offset(state.offset, count, self.looping);
+            if (clamped - state.offset).abs() > f32::EPSILON || state.velocity.abs() < MIN_VELOCITY
+            {
+                state.offset = clamped;
+                state.velocity = 0.0;
+                state.target = Some(state.offset.round());
+            }
+        } else if let Some(target) = state.target {
+            if (target - state.offset).abs() > 0.001 {
+                state.offset += (target - state.offset) * (elapsed * SNAP_SPEED).min(1.0);
+            } else {
+                state.target = None;
+                let index = item_at(target as i64, count, self.looping);
+                // A looping wheel comes back to its first turn.
+                state.offset = index.map_or(target, |index| index as f32);
+
+                if index.is_some() && index != selected {
+                    state.pending = index;
+                    settled = index;
+                }
+            }
+        } else if let (None, Some(selected)) = (state.pending, selected) {
+            // Follow the item selected by the application.
+            let row = nearest_row(state.offset, selected, count, self.looping);
+            if (state.offset - row).abs() > f32::EPSILON {
+                state.target = Some(row);
+            }
+        }
+
+        if state.velocity != 0.0 || state.target.is_some() {
+            state.last_update = Some(now);
+            animation::request_frame(shell, now);
+        } else {
+            state.last_update = None;
+        }
+
+        settled
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for WheelPicker<'_, T, Message, Theme>
+where
+    T: Clone + PartialEq + ToString,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+    Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            offset: self.selected_index().unwrap_or_default() as f32,
+            ..State::default()
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.width(self.width).resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(100.0, self.row_height * self.visible_rows as f32),
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let count = self.items.len();
+        let state = tree.state.downcast_mut::<State>();
+
+        if count == 0 {
+            return event::Status::Ignored;
+        }
+
+        let clamp = |offset: f32| clamp_offset(offset, count, self.looping);
+        state.offset = clamp(state.offset);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let rows = match delta {
+                    ScrollDelta::Lines { y, .. } => y,
+                    ScrollDelta::Pixels { y, .. } => y / self.row_height,
+                };
+
+                state.offset = clamp(state.offset - rows);
+                state.velocity = 0.0;
+                state.target = Some(state.offset.round());
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    state.drag = Some(Drag {
+                        start_y: position.y,
+                        start_offset: state.offset,
+                        last_y: position.y,
+                        last_time: Instant::now(),
+                    });
+                    state.velocity = 0.0;
+                    state.target = None;
+
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = &mut state.drag {
+                    let now = Instant::now();
+                    let elapsed = now.duration_since(drag.last_time).as_secs_f32();
+
+                    if elapsed > 0.0 {
+                        state.velocity = (drag.last_y - position.y) / self.row_height / elapsed;
+                    }
+
+                    drag.last_y = position.y;
+                    drag.last_time = now;
+                    state.offset =
+                        clamp(drag.start_offset + (drag.start_y - position.y) / self.row_height);
+
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if let Some(drag) = state.drag.take() {
+                    if (drag.last_y - drag.start_y).abs() < TAP_DISTANCE {
+                        // A tap selects the tapped row.
+                        let row =
+                            state.offset + (drag.last_y - bounds.center_y()) / self.row_height;
+                        state.velocity = 0.0;
+                        state.target = Some(clamp(row.round()));
+                    } else if drag.last_time.elapsed() > FLING_TIMEOUT
+                        || state.velocity.abs() < MIN_VELOCITY
+                    {
+                        state.velocity = 0.0;
+                        state.target = Some(state.offset.round());
+                    }
+
+                    state.last_update = None;
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) if cursor.is_over(bounds) && state.drag.is_none() => {
+                let step = match key {
+                    keyboard::key::Named::ArrowUp => -1.0,
+                    keyboard::key::Named::ArrowDown => 1.0,
+                    _ => return event::Status::Ignored,
+                };
+                let row = state.target.unwrap_or_else(|| state.offset.round());
+
+                state.velocity = 0.0;
+                state.target = Some(clamp(row + step));
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+
+            Event::Window(_id, window::Event::RedrawRequested(now)) => {
+                if let Some(index) = self.animate(state, now, shell) {
+                    shell.publish((self.on_select)(self.items[index].clone()));
+                }
+            }
+
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State>().drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let count = self.items.len();
+        let appearance = if state.drag.is_some() || cursor.is_over(bounds) {
+            theme.hovered(&self.style)
+        } else {
+            theme.active(&self.style)
+        };
+
+        renderer.fill_quad(
+            Quad {
+                bounds,
+                border: Border {
+                    radius: appearance.border_radius.into(),
+                    width: appearance.border_width,
+                    color: appearance.border_color,
+                },
+                shadow: Shadow::default(),
+            },
+            appearance.background,
+        );
+
+        renderer.fill_quad(
+            Quad {
+                bounds: Rectangle {
+                    y: bounds.center_y() - self.row_height / 2.0,
+                    height: self.row_height,
+                    ..bounds
+                },
+                border: Border::with_radius(appearance.border_radius),
+                shadow: Shadow::default(),
+            },
+            appearance.selection_background,
+        );
+
+        let offset = clamp_offset(state.offset, count, self.looping);
+        let half = self.visible_rows as f32 / 2.0;
+
+        renderer.with_layer(bounds, |renderer| {
+            let first = (offset - half).floor() as i64;
+            let last = (offset + half).ceil() as i64;
+
+            for row in first..=last {
+                let Some(index) = item_at(row, count, self.looping) else {
+                    continue;
+                };
+                let distance = row as f32 - offset;
+                // The rows shrink and fade out towards the edges of the wheel.
+                let fade = (distance.abs() / half).min(1.0);
+                let color = if distance.abs() < 0.5 {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                };
+                let row_bounds = Rectangle {
+                    y: bounds.center_y() + distance * self.row_height - self.row_height / 2.0,
+                    height: self.row_height,
+                    ..bounds
+                };
+
+                renderer.fill_text(
+                    Text {
+                        content: &self.items[index].to_string(),
+                        bounds: row_bounds.size(),
+                        size: Pixels(self.text_size * (1.0 - 0.2 * fade)),
+                        line_height: LineHeight::default(),
+                        font: self.font,
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                        shaping: Shaping::Advanced,
+                    },
+                    row_bounds.center(),
+                    Color {
+                        a: color.a * (1.0 - 0.7 * fade),
+                        ..color
+                    },
+                    row_bounds,
+                );
+            }
+        });
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<WheelPicker<'a, T, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a + Clone + PartialEq + ToString,
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + text::Renderer<Font = Font>,
+    Theme: 'a + StyleSheet,
+{
+    fn from(wheel_picker: WheelPicker<'a, T, Message, Theme>) -> Self {
+        Element::new(wheel_picker)
+    }
+}
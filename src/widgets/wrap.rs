@@ -12,15 +12,25 @@ use iced::{
     mouse::{self, Cursor},
     Alignment, Element, Event, Length, Padding, Point, Rectangle, Size, Vector,
 };
-use std::marker::PhantomData;
+use std::{marker::PhantomData, ops::Range};
 
 /// A container that distributes its contents horizontally.
 #[allow(missing_debug_implementations)]
 pub struct Wrap<'a, Message, Direction, Theme = iced::Theme, Renderer = iced::Renderer> {
     /// The elements to distribute.
     pub elements: Vec<Element<'a, Message, Theme, Renderer>>,
-    /// The alignment of the [`Wrap`].
+    /// The alignment of the elements on the cross axis of their line.
     pub alignment: Alignment,
+    /// The distribution of the elements along each line of the [`Wrap`].
+    pub justify_content: Justify,
+    /// The alignment of the lines on the cross axis of the [`Wrap`].
+    pub align_content: Alignment,
+    /// Whether the elements are laid out from the last to the first.
+    pub reverse: bool,
+    /// Whether the [`Wrap`] flows from right to left.
+    pub rtl: bool,
+    /// The sizing of the elements of the [`Wrap`].
+    pub item_sizing: ItemSizing,
     /// The width of the [`Wrap`].
     pub width: Length,
     /// The height of the [`Wrap`].
@@ -138,13 +148,53 @@ impl<'a, Message, Renderer, Direction, Theme> Wrap<'a, Message, Direction, Theme
         self
     }
 
-    /// Sets the alignment of the [`Wrap`].
+    /// Sets the alignment of the elements on the cross axis of their line.
     #[must_use]
     pub const fn align_items(mut self, align: Alignment) -> Self {
         self.alignment = align;
         self
     }
 
+    /// Sets the distribution of the elements along each line of the [`Wrap`].
+    #[must_use]
+    pub const fn justify_content(mut self, justify: Justify) -> Self {
+        self.justify_content = justify;
+        self
+    }
+
+    /// Sets the alignment of the lines on the cross axis of the [`Wrap`].
+    ///
+    /// This only has an effect if the [`Wrap`] is larger than its lines.
+    #[must_use]
+    pub const fn align_content(mut self, align: Alignment) -> Self {
+        self.align_content = align;
+        self
+    }
+
+    /// Sets whether the elements of the [`Wrap`] are laid out from the last to the first.
+    #[must_use]
+    pub const fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Sets whether the [`Wrap`] flows from right to left.
+    ///
+    /// A horizontal [`Wrap`] fills its lines from the right, a vertical
+    /// [`Wrap`] places its first column on the right.
+    #[must_use]
+    pub const fn rtl(mut self, rtl: bool) -> Self {
+        self.rtl = rtl;
+        self
+    }
+
+    /// Sets the sizing of the elements of the [`Wrap`].
+    #[must_use]
+    pub const fn item_sizing(mut self, sizing: ItemSizing) -> Self {
+        self.item_sizing = sizing;
+        self
+    }
+
     /// Pushes an [`Element`] to the [`Wrap`].
     #[must_use]
     pub fn push<E>(mut self, element: E) -> Self
@@ -238,6 +288,7 @@ where
             .iter()
             .zip(&state.children)
             .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
             .map(|((child, state), layout)| {
                 child
                     .as_widget()
@@ -262,6 +313,9 @@ where
             .iter()
             .zip(&state.children)
             .zip(layout.children())
+            // The children outside of the viewport, like the ones scrolled
+            // out of a scrollable, are not drawn.
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
         {
             child
                 .as_widget()
@@ -324,6 +378,11 @@ impl<'a, Message, Renderer, Direction, Theme> Default
         Self {
             elements: vec![],
             alignment: Alignment::Start,
+            justify_content: Justify::Start,
+            align_content: Alignment::Start,
+            reverse: false,
+            rtl: false,
+            item_sizing: ItemSizing::Natural,
             width: Length::Shrink,
             height: Length::Shrink,
             max_width: 4_294_967_295.0,
@@ -353,82 +412,7 @@ where
     #[allow(clippy::inline_always)]
     #[inline(always)]
     fn inner_layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
-        let padding = Padding::from(self.padding);
-        let spacing = self.spacing;
-        let line_spacing = self.line_spacing;
-        #[allow(clippy::cast_precision_loss)] // TODO: possible precision loss
-        let line_minimal_length = self.line_minimal_length;
-        let limits = limits
-            .shrink(padding)
-            .width(self.width)
-            .height(self.height)
-            .max_width(self.max_width)
-            .max_height(self.max_height);
-        let max_width = limits.max().width;
-
-        let mut children = tree.children.iter_mut();
-        let mut curse = padding.left;
-        let mut deep_curse = padding.left;
-        let mut current_line_height = line_minimal_length;
-        let mut max_main = curse;
-        let mut align = vec![];
-        let mut start = 0;
-        let mut end = 0;
-        let mut nodes: Vec<Node> = self
-            .elements
-            .iter()
-            .map(|elem| {
-                let node_limit = Limits::new(
-                    Size::new(limits.min().width, line_minimal_length),
-                    limits.max(),
-                );
-                let mut node = elem.as_widget().layout(
-                    children.next().expect("wrap missing expected child"),
-                    renderer,
-                    &node_limit,
-                );
-
-                let size = node.size();
-
-                let offset_init = size.width + spacing;
-                let offset = curse + offset_init;
-
-                if offset > max_width {
-                    deep_curse += current_line_height + line_spacing;
-                    align.push((start..end, current_line_height));
-                    start = end;
-                    end += 1;
-                    current_line_height = line_minimal_length;
-                    node.move_to_mut(Point::new(padding.left, deep_curse));
-                    curse = offset_init + padding.left;
-                } else {
-                    node.move_to_mut(Point::new(curse, deep_curse));
-                    curse = offset;
-                    end += 1;
-                }
-                current_line_height = current_line_height.max(size.height);
-                max_main = max_main.max(curse);
-
-                node
-            })
-            .collect();
-        if end != start {
-            align.push((start..end, current_line_height));
-        }
-        for (range, max_length) in align {
-            nodes[range].iter_mut().for_each(|node| {
-                let size = node.size();
-                let space = Size::new(size.width, max_length);
-                node.align_mut(Alignment::Start, self.alignment, space);
-            });
-        }
-        let (width, height) = (
-            max_main - padding.left,
-            deep_curse - padding.left + current_line_height,
-        );
-        let size = limits.resolve(self.width, self.height, Size::new(width, height));
-
-        Node::with_children(size.expand(padding), nodes)
+        self.flow_layout(Axis::Horizontal, tree, renderer, limits)
     }
 }
 
@@ -440,87 +424,259 @@ where
     #[allow(clippy::inline_always)]
     #[inline(always)]
     fn inner_layout(&self, tree: &mut Tree, renderer: &Renderer, limits: &Limits) -> Node {
+        self.flow_layout(Axis::Vertical, tree, renderer, limits)
+    }
+}
+
+/// The axis along which the lines of a [`Wrap`] run.
+#[derive(Clone, Copy, Debug)]
+enum Axis {
+    /// Lines run from left to right.
+    Horizontal,
+    /// Lines run from top to bottom.
+    Vertical,
+}
+
+impl Axis {
+    /// Returns the length of the given [`Size`] along the main axis.
+    const fn main(self, size: Size) -> f32 {
+        match self {
+            Self::Horizontal => size.width,
+            Self::Vertical => size.height,
+        }
+    }
+
+    /// Returns the length of the given [`Size`] along the cross axis.
+    const fn cross(self, size: Size) -> f32 {
+        match self {
+            Self::Horizontal => size.height,
+            Self::Vertical => size.width,
+        }
+    }
+
+    /// Builds a [`Size`] from its main and cross lengths.
+    const fn size(self, main: f32, cross: f32) -> Size {
+        match self {
+            Self::Horizontal => Size::new(main, cross),
+            Self::Vertical => Size::new(cross, main),
+        }
+    }
+
+    /// Builds a [`Point`] from its main and cross coordinates.
+    const fn point(self, main: f32, cross: f32) -> Point {
+        match self {
+            Self::Horizontal => Point::new(main, cross),
+            Self::Vertical => Point::new(cross, main),
+        }
+    }
+}
+
+/// A line of a [`Wrap`].
+#[derive(Debug)]
+struct Line {
+    /// The range of the nodes in this line, in layout order.
+    range: Range<usize>,
+    /// The length of the line along the main axis.
+    main: f32,
+    /// The length of the line along the cross axis.
+    cross: f32,
+    /// The length of the largest node of the line along the main axis.
+    item_main: f32,
+}
+
+/// Returns the offset of an item of length `length` aligned in `space`.
+fn align_offset(alignment: Alignment, space: f32, length: f32) -> f32 {
+    match alignment {
+        Alignment::Start => 0.0,
+        Alignment::Center => (space - length).max(0.0) / 2.0,
+        Alignment::End => (space - length).max(0.0),
+    }
+}
+
+impl<Message, Direction, Theme, Renderer> Wrap<'_, Message, Direction, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    /// Distributes the elements of the [`Wrap`] in lines along the given [`Axis`].
+    fn flow_layout(
+        &self,
+        axis: Axis,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &Limits,
+    ) -> Node {
         let padding = Padding::from(self.padding);
-        let spacing = self.spacing;
-        let line_spacing = self.line_spacing;
-        #[allow(clippy::cast_precision_loss)] // TODO: possible precision loss
-        let line_minimal_length = self.line_minimal_length;
         let limits = limits
             .shrink(padding)
             .width(self.width)
             .height(self.height)
             .max_width(self.max_width)
             .max_height(self.max_height);
-        let max_height = limits.max().height;
-
-        let mut children = tree.children.iter_mut();
-        let mut curse = padding.left;
-        let mut wide_curse = padding.left;
-        let mut current_line_width = line_minimal_length;
-        let mut max_main = curse;
-        let mut align = vec![];
-        let mut start = 0;
-        let mut end = 0;
+        let max_main = axis.main(limits.max());
+        let node_limits = Limits::new(
+            axis.size(axis.main(limits.min()), self.line_minimal_length),
+            limits.max(),
+        );
+
         let mut nodes: Vec<Node> = self
             .elements
             .iter()
-            .map(|elem| {
-                let node_limit = Limits::new(
-                    Size::new(line_minimal_length, limits.min().height),
-                    limits.max(),
-                );
-                let mut node = elem.as_widget().layout(
-                    children.next().expect("wrap missing expected child"),
-                    renderer,
-                    &node_limit,
-                );
-
-                let size = node.size();
+            .zip(tree.children.iter_mut())
+            .map(|(elem, tree)| elem.as_widget().layout(tree, renderer, &node_limits))
+            .collect();
 
-                let offset_init = size.height + spacing;
-                let offset = curse + offset_init;
-
-                if offset > max_height {
-                    wide_curse += current_line_width + line_spacing;
-                    align.push((start..end, current_line_width));
-                    start = end;
-                    end += 1;
-                    current_line_width = line_minimal_length;
-                    node = node.move_to(Point::new(wide_curse, padding.left));
-                    curse = offset_init + padding.left;
-                } else {
-                    node = node.move_to(Point::new(wide_curse, curse));
-                    end += 1;
-                    curse = offset;
+        let count = nodes.len();
+        let order = |position: usize| {
+            if self.reverse {
+                count - 1 - position
+            } else {
+                position
+            }
+        };
+
+        let largest = nodes.iter().fold(Size::ZERO, |largest, node| {
+            let size = node.size();
+            Size::new(
+                largest.width.max(size.width),
+                largest.height.max(size.height),
+            )
+        });
+        let empty_line = |position: usize| match self.item_sizing {
+            ItemSizing::Uniform => Line {
+                range: position..position,
+                main: 0.0,
+                cross: self.line_minimal_length.max(axis.cross(largest)),
+                item_main: axis.main(largest),
+            },
+            ItemSizing::Natural | ItemSizing::UniformPerLine => Line {
+                range: position..position,
+                main: 0.0,
+                cross: self.line_minimal_length,
+                item_main: 0.0,
+            },
+        };
+
+        let mut lines: Vec<Line> = Vec::new();
+        let mut line = empty_line(0);
+        for position in 0..count {
+            let size = nodes[order(position)].size();
+            let length = |line: &Line| match self.item_sizing {
+                ItemSizing::Natural if line.range.is_empty() => axis.main(size),
+                ItemSizing::Natural => line.main + self.spacing + axis.main(size),
+                ItemSizing::UniformPerLine | ItemSizing::Uniform => {
+                    let items = line.range.len() as f32;
+                    let item_main = line.item_main.max(axis.main(size));
+                    (items + 1.0) * item_main + items * self.spacing
                 }
-                current_line_width = current_line_width.max(size.width);
-                max_main = max_main.max(curse);
+            };
 
-                node
-            })
-            .collect();
-        if end != start {
-            align.push((start..end, current_line_width));
+            // The spacing after the element must fit too.
+            if !line.range.is_empty() && length(&line) + self.spacing > max_main {
+                lines.push(std::mem::replace(&mut line, empty_line(position)));
+            }
+
+            line.main = length(&line);
+            line.item_main = line.item_main.max(axis.main(size));
+            line.cross = line.cross.max(axis.cross(size));
+            line.range.end = position + 1;
+        }
+        if !line.range.is_empty() {
+            lines.push(line);
         }
 
-        for (range, max_length) in align {
-            nodes[range].iter_mut().for_each(|node| {
-                let size = node.size();
-                let space = Size::new(max_length, size.height);
-                node.align_mut(self.alignment, Alignment::Start, space);
-            });
+        if self.item_sizing != ItemSizing::Natural {
+            for line in &lines {
+                let size = axis.size(line.item_main, line.cross);
+                let limits = Limits::new(size, size);
+                for position in line.range.clone() {
+                    let index = order(position);
+                    nodes[index] = self.elements[index].as_widget().layout(
+                        &mut tree.children[index],
+                        renderer,
+                        &limits,
+                    );
+                }
+            }
         }
 
-        let (width, height) = (
-            wide_curse - padding.left + current_line_width,
-            max_main - padding.left,
+        let content_main = lines.iter().map(|line| line.main).fold(0.0, f32::max);
+        let content_cross = lines.iter().map(|line| line.cross).sum::<f32>()
+            + self.line_spacing * lines.len().saturating_sub(1) as f32;
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            axis.size(content_main, content_cross),
         );
-        let size = limits.resolve(self.width, self.height, Size::new(width, height));
 
-        Node::with_children(size.expand(padding), nodes)
+        let (main_start, cross_start) = match axis {
+            Axis::Horizontal => (padding.left, padding.top),
+            Axis::Vertical => (padding.top, padding.left),
+        };
+        let mut cross_offset =
+            cross_start + align_offset(self.align_content, axis.cross(size), content_cross);
+
+        for line in &lines {
+            let free = (axis.main(size) - line.main).max(0.0);
+            let items = line.range.len();
+            let (mut main_offset, gap) = match self.justify_content {
+                Justify::Center => (free / 2.0, self.spacing),
+                Justify::End => (free, self.spacing),
+                Justify::SpaceBetween if items > 1 => {
+                    (0.0, self.spacing + free / (items - 1) as f32)
+                }
+                Justify::Start | Justify::SpaceBetween => (0.0, self.spacing),
+            };
+            main_offset += main_start;
+
+            for position in line.range.clone() {
+                let node = &mut nodes[order(position)];
+                let size = node.size();
+                let cross = align_offset(self.alignment, line.cross, axis.cross(size));
+                node.move_to_mut(axis.point(main_offset, cross_offset + cross));
+                main_offset += axis.main(size) + gap;
+            }
+
+            cross_offset += line.cross + self.line_spacing;
+        }
+
+        let size = size.expand(padding);
+        if self.rtl {
+            for node in &mut nodes {
+                let bounds = node.bounds();
+                node.move_to_mut(Point::new(size.width - bounds.x - bounds.width, bounds.y));
+            }
+        }
+
+        Node::with_children(size, nodes)
     }
 }
 
+/// The sizing of the elements of a [`Wrap`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ItemSizing {
+    /// Each element keeps its own size.
+    #[default]
+    Natural,
+    /// Each element takes the size of the largest element of its line.
+    UniformPerLine,
+    /// Each element takes the size of the largest element of the [`Wrap`].
+    Uniform,
+}
+
+/// The distribution of the elements along each line of a [`Wrap`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Justify {
+    /// The elements are packed at the start of the line.
+    #[default]
+    Start,
+    /// The elements are packed at the center of the line.
+    Center,
+    /// The elements are packed at the end of the line.
+    End,
+    /// The free space is distributed between the elements of the line.
+    SpaceBetween,
+}
+
 /// An optional directional attribute of the [`Wrap`](crate::Wrap).
 pub mod direction {
     /// An vertical direction of the [`Wrap`](crate::Wrap).